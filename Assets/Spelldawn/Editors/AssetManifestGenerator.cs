@@ -0,0 +1,53 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#nullable enable
+
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using UnityEditor;
+using UnityEditor.AddressableAssets;
+using UnityEditor.AddressableAssets.Settings;
+using UnityEngine;
+
+namespace Spelldawn.Editors
+{
+  /// <summary>
+  /// Writes the address of every addressable asset to a manifest file, which is used by the
+  /// 'validate_assets' tool to check that all card asset references exist.
+  /// </summary>
+  public static class AssetManifestGenerator
+  {
+    const string ManifestPath = "Assets/AddressableAssetsData/AssetManifest.txt";
+
+    [MenuItem("Tools/Generate Asset Manifest")]
+    public static void Generate()
+    {
+      var entries = new List<AddressableAssetEntry>();
+      foreach (var group in AddressableAssetSettingsDefaultObject.Settings.groups)
+      {
+        foreach (var entry in group.entries)
+        {
+          entry.GatherAllAssets(entries, includeSelf: true, recurseAll: true, includeSubObjects: false);
+        }
+      }
+
+      var addresses = entries.Select(e => e.address).Distinct().OrderBy(a => a).ToList();
+      File.WriteAllLines(ManifestPath, addresses);
+      AssetDatabase.Refresh();
+      Debug.Log($"Wrote {addresses.Count} addresses to {ManifestPath}");
+    }
+  }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+using System.Collections.Generic;
 using Spelldawn.Protos;
 using Spelldawn.Utils;
 using TMPro;
@@ -28,6 +29,7 @@ namespace Spelldawn.Game
     [SerializeField] ActionSymbol _left = null!;
     [SerializeField] ActionSymbol _center = null!;
     [SerializeField] ActionSymbol _right = null!;
+    [SerializeField] TextMeshPro? _bonus;
 
     public uint AvailableActions => _availableActions;
 
@@ -44,6 +46,29 @@ namespace Spelldawn.Game
     public void RenderActionTrackerView(ActionTrackerView actionTrackerView)
     {
       SetAvailableActions(actionTrackerView.AvailableActionCount);
+      SetBonusActions(actionTrackerView.BonusActionCount, actionTrackerView.NextTurnActionCount);
+    }
+
+    void SetBonusActions(uint bonusActions, uint nextTurnActions)
+    {
+      if (_bonus == null)
+      {
+        return;
+      }
+
+      var parts = new List<string>();
+      if (bonusActions > 0)
+      {
+        parts.Add($"+{bonusActions}");
+      }
+
+      if (nextTurnActions > 0)
+      {
+        parts.Add($"next turn +{nextTurnActions}");
+      }
+
+      _bonus.gameObject.SetActive(parts.Count > 0);
+      _bonus.text = string.Join("\n", parts);
     }
 
     public void SpendActions(uint amount)
@@ -57,6 +57,7 @@ namespace Spelldawn.Game
     [SerializeField] Icon _bottomRightIcon = null!;
     [SerializeField] Icon _bottomLeftIcon = null!;
     [SerializeField] Icon _arenaIcon = null!;
+    [SerializeField] SpriteRenderer? _schemeProgressFill;
     [SerializeField] bool _isRevealed;
     [SerializeField] float _dragStartScreenZ;
     [SerializeField] Vector3 _dragStartPosition;
@@ -79,8 +80,10 @@ namespace Spelldawn.Game
     bool? _serverCanPlay;
     bool? _serverRevealedInArena;
     ISet<RoomIdentifier>? _validRoomTargets;
+    ISet<RoomIdentifier>? _highlightedRooms;
     ObjectPosition? _releasePosition;
     Node? _supplementalInfo;
+    IReadOnlyList<KeywordTooltip> _keywordTooltips = new List<KeywordTooltip>();
     ArrowService.Type? _arrowOnDrag;
 
     [Serializable]
@@ -114,6 +117,8 @@ namespace Spelldawn.Game
 
     public Node? SupplementalInfo => _supplementalInfo;
 
+    public IReadOnlyList<KeywordTooltip> KeywordTooltips => _keywordTooltips;
+
     public ObjectDisplay ContainedObjects => Errors.CheckNotNull(_containedObjectsDisplay);
 
     public Sequence? Render(
@@ -184,8 +189,10 @@ namespace Spelldawn.Game
       result._serverCanPlay = _serverCanPlay;
       result._serverRevealedInArena = _serverRevealedInArena;
       result._validRoomTargets = _validRoomTargets;
+      result._highlightedRooms = _highlightedRooms;
       result._releasePosition = _releasePosition;
       result._supplementalInfo = _supplementalInfo;
+      result._keywordTooltips = _keywordTooltips;
       result.Registry = Registry;
       return result;
     }
@@ -301,6 +308,11 @@ namespace Spelldawn.Game
           Registry.ArenaService.ShowRoomSelectorForMousePosition(_validRoomTargets);
         }
 
+        if (_highlightedRooms is { Count: > 0 })
+        {
+          Registry.ArenaService.HighlightRooms(_highlightedRooms);
+        }
+
         if (_arrowOnDrag is { } arrow)
         {
           gameObject.SetActive(false);
@@ -421,6 +433,7 @@ namespace Spelldawn.Game
       }
 
       UpdateIcons(card.CardIcons, GameContext.RenderArenaCard());
+      UpdateSchemeProgress(card.SchemeProgress, GameContext.RenderArenaCard());
       UpdateRevealedToOpponent(GameContext.RenderArenaCard());
     }
 
@@ -432,6 +445,7 @@ namespace Spelldawn.Game
       _validRoomTargets = null;
       _serverCanPlay = false;
       _arrowOnDrag = null;
+      _highlightedRooms = revealed.Targeting?.HighlightedRooms.ToHashSet();
 
       switch (revealed.Targeting?.TargetingCase)
       {
@@ -446,13 +460,7 @@ namespace Spelldawn.Game
         case CardTargeting.TargetingOneofCase.ArrowTargetRoom:
           _validRoomTargets = revealed.Targeting.ArrowTargetRoom.ValidRooms.ToHashSet();
           _serverCanPlay = _validRoomTargets.Count > 0;
-          _arrowOnDrag = revealed.Targeting.ArrowTargetRoom.Arrow switch
-          {
-            TargetingArrow.Red => ArrowService.Type.Red,
-            TargetingArrow.Blue => ArrowService.Type.Blue,
-            TargetingArrow.Green => ArrowService.Type.Green,
-            _ => null
-          };
+          _arrowOnDrag = ArrowService.TypeForArrow(revealed.Targeting.ArrowTargetRoom.Arrow);
           break;
         default:
           _validRoomTargets = null;
@@ -470,6 +478,8 @@ namespace Spelldawn.Game
         _supplementalInfo = info;
       }
 
+      _keywordTooltips = revealed.KeywordTooltips;
+
       _cardBack.gameObject.SetActive(value: false);
       _cardFront.gameObject.SetActive(value: true);
       Registry.AssetService.AssignSprite(_image, revealed.Image, referenceWidth: _referenceImageWidth);
@@ -516,6 +526,22 @@ namespace Spelldawn.Game
       SetCardIcon(_arenaIcon, cardIcons?.ArenaIcon, inArena);
     }
 
+    void UpdateSchemeProgress(SchemeProgress? progress, bool inArena)
+    {
+      if (!_schemeProgressFill)
+      {
+        return;
+      }
+
+      var show = inArena && progress is { RequiredLevel: > 0 };
+      _schemeProgressFill!.gameObject.SetActive(show);
+      if (show)
+      {
+        var fraction = Mathf.Clamp01(progress!.CurrentLevel / (float)progress.RequiredLevel);
+        _schemeProgressFill.transform.localScale = new Vector3(fraction, 1, 1);
+      }
+    }
+
     void UpdateRevealedToOpponent(bool inArena)
     {
       if (inArena && _serverRevealedInArena != true)
@@ -538,6 +564,7 @@ namespace Spelldawn.Game
         Registry.AssetService.AssignSprite(icon.Background, cardIcon.Background);
         icon.Background.transform.localScale = (cardIcon.BackgroundScale ?? 1.0f) * Vector3.one;
         icon.Text.text = cardIcon.Text;
+        icon.Text.color = cardIcon.TextColor == null ? Color.white : Mason.ToUnityColor(cardIcon.TextColor);
       }
       
       iconContainer.gameObject.SetActive(show);
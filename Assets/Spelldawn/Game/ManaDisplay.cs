@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+using System.Collections.Generic;
+using System.Linq;
 using Spelldawn.Protos;
 using Spelldawn.Services;
 using Spelldawn.Utils;
@@ -46,7 +48,7 @@ namespace Spelldawn.Game
     public void RenderManaDisplay(ManaView manaView)
     {
       SetMana(manaView.BaseMana);
-      SetBonusMana(manaView.BonusMana);
+      SetBonusMana(manaView.BonusMana, manaView.Pools);
     }
 
     public void DisableAnimation()
@@ -81,7 +83,7 @@ namespace Spelldawn.Game
       _manaText.text = "" + _currentMana;
     }
 
-    void SetBonusMana(uint bonusMana)
+    void SetBonusMana(uint bonusMana, IEnumerable<ManaPoolView> pools)
     {
       Errors.CheckNonNegative(bonusMana);
 
@@ -94,7 +96,8 @@ namespace Spelldawn.Game
       _currentBonusMana = bonusMana;
 
       _bonusManaText.gameObject.SetActive(bonusMana > 0);
-      _bonusManaText.text = "" + _currentBonusMana;
+      var poolText = string.Join(" ", pools.Select(pool => $"{pool.Mana}{pool.Icon}"));
+      _bonusManaText.text = poolText == "" ? "" + _currentBonusMana : poolText;
     }
 
     void OnMouseDown()
@@ -97,6 +97,7 @@ namespace Spelldawn.Masonry
     static void ApplyNode(Registry registry, Node node, VisualElement element)
     {
       element.name = node.Name;
+      element.tooltip = node.Hotkey?.Label ?? "";
 
       ApplyStyle(registry, element, node.Style);
 
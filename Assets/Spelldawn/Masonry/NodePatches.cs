@@ -0,0 +1,104 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+using System.Collections.Generic;
+using Spelldawn.Protos;
+using UnityEngine;
+
+#nullable enable
+
+namespace Spelldawn.Masonry
+{
+  /// <summary>
+  /// Applies NodePatches sent by the server to the most recent Node it sent for an interface element.
+  /// </summary>
+  public static class NodePatches
+  {
+    /// <summary>
+    /// Returns the node described by either a full <paramref name="node"/> or a list of
+    /// <paramref name="patches"/> to apply to <paramref name="previous"/>, the last node received for this
+    /// element. Returns null if neither was provided or if the patches could not be applied.
+    /// </summary>
+    public static Node? Receive(Node? previous, Node? node, NodePatchList? patches)
+    {
+      if (patches == null)
+      {
+        return node;
+      }
+
+      if (previous == null)
+      {
+        Debug.LogError("No previous node to patch");
+        return null;
+      }
+
+      var result = previous.Clone();
+      foreach (var patch in patches.Patches)
+      {
+        result = Apply(result, patch);
+        if (result == null)
+        {
+          Debug.LogError($"Unable to apply node patch {patch}");
+          return null;
+        }
+      }
+
+      return result;
+    }
+
+    /// <summary>
+    /// Applies <paramref name="patch"/> to <paramref name="root"/>, returning the resulting root node or null if
+    /// the patch is not valid for this tree.
+    /// </summary>
+    static Node? Apply(Node root, NodePatch patch)
+    {
+      if (patch.PatchTypeCase == NodePatch.PatchTypeOneofCase.Replace && patch.Path.Count == 0)
+      {
+        return patch.Replace.Clone();
+      }
+
+      Node? parent = null;
+      var target = root;
+      foreach (var index in patch.Path)
+      {
+        if (index >= target.Children.Count)
+        {
+          return null;
+        }
+
+        parent = target;
+        target = target.Children[(int)index];
+      }
+
+      switch (patch.PatchTypeCase)
+      {
+        case NodePatch.PatchTypeOneofCase.Replace:
+          parent!.Children[(int)patch.Path[patch.Path.Count - 1]] = patch.Replace.Clone();
+          return root;
+        case NodePatch.PatchTypeOneofCase.TruncateChildren:
+          while (target.Children.Count > patch.TruncateChildren)
+          {
+            target.Children.RemoveAt(target.Children.Count - 1);
+          }
+
+          return root;
+        case NodePatch.PatchTypeOneofCase.AppendChild:
+          target.Children.Add(patch.AppendChild.Clone());
+          return root;
+        default:
+          return null;
+      }
+    }
+  }
+}
@@ -66,6 +66,22 @@ namespace Spelldawn.Protos {
     static readonly grpc::Marshaller<global::Spelldawn.Protos.CommandList> __Marshaller_spelldawn_CommandList = grpc::Marshallers.Create(__Helper_SerializeMessage, context => __Helper_DeserializeMessage(context, global::Spelldawn.Protos.CommandList.Parser));
     [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
     static readonly grpc::Marshaller<global::Spelldawn.Protos.GameRequest> __Marshaller_spelldawn_GameRequest = grpc::Marshallers.Create(__Helper_SerializeMessage, context => __Helper_DeserializeMessage(context, global::Spelldawn.Protos.GameRequest.Parser));
+    [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+    static readonly grpc::Marshaller<global::Spelldawn.Protos.AuthenticateRequest> __Marshaller_spelldawn_AuthenticateRequest = grpc::Marshallers.Create(__Helper_SerializeMessage, context => __Helper_DeserializeMessage(context, global::Spelldawn.Protos.AuthenticateRequest.Parser));
+    [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+    static readonly grpc::Marshaller<global::Spelldawn.Protos.AuthenticateResponse> __Marshaller_spelldawn_AuthenticateResponse = grpc::Marshallers.Create(__Helper_SerializeMessage, context => __Helper_DeserializeMessage(context, global::Spelldawn.Protos.AuthenticateResponse.Parser));
+    [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+    static readonly grpc::Marshaller<global::Spelldawn.Protos.FetchCardCatalogRequest> __Marshaller_spelldawn_FetchCardCatalogRequest = grpc::Marshallers.Create(__Helper_SerializeMessage, context => __Helper_DeserializeMessage(context, global::Spelldawn.Protos.FetchCardCatalogRequest.Parser));
+    [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+    static readonly grpc::Marshaller<global::Spelldawn.Protos.CardCatalog> __Marshaller_spelldawn_CardCatalog = grpc::Marshallers.Create(__Helper_SerializeMessage, context => __Helper_DeserializeMessage(context, global::Spelldawn.Protos.CardCatalog.Parser));
+    [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+    static readonly grpc::Marshaller<global::Spelldawn.Protos.FetchLeaderboardRequest> __Marshaller_spelldawn_FetchLeaderboardRequest = grpc::Marshallers.Create(__Helper_SerializeMessage, context => __Helper_DeserializeMessage(context, global::Spelldawn.Protos.FetchLeaderboardRequest.Parser));
+    [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+    static readonly grpc::Marshaller<global::Spelldawn.Protos.LeaderboardPage> __Marshaller_spelldawn_LeaderboardPage = grpc::Marshallers.Create(__Helper_SerializeMessage, context => __Helper_DeserializeMessage(context, global::Spelldawn.Protos.LeaderboardPage.Parser));
+    [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+    static readonly grpc::Marshaller<global::Spelldawn.Protos.FetchRecordingsRequest> __Marshaller_spelldawn_FetchRecordingsRequest = grpc::Marshallers.Create(__Helper_SerializeMessage, context => __Helper_DeserializeMessage(context, global::Spelldawn.Protos.FetchRecordingsRequest.Parser));
+    [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+    static readonly grpc::Marshaller<global::Spelldawn.Protos.RecordingList> __Marshaller_spelldawn_RecordingList = grpc::Marshallers.Create(__Helper_SerializeMessage, context => __Helper_DeserializeMessage(context, global::Spelldawn.Protos.RecordingList.Parser));
 
     [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
     static readonly grpc::Method<global::Spelldawn.Protos.ConnectRequest, global::Spelldawn.Protos.CommandList> __Method_Connect = new grpc::Method<global::Spelldawn.Protos.ConnectRequest, global::Spelldawn.Protos.CommandList>(
@@ -83,6 +99,38 @@ namespace Spelldawn.Protos {
         __Marshaller_spelldawn_GameRequest,
         __Marshaller_spelldawn_CommandList);
 
+    [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+    static readonly grpc::Method<global::Spelldawn.Protos.AuthenticateRequest, global::Spelldawn.Protos.AuthenticateResponse> __Method_Authenticate = new grpc::Method<global::Spelldawn.Protos.AuthenticateRequest, global::Spelldawn.Protos.AuthenticateResponse>(
+        grpc::MethodType.Unary,
+        __ServiceName,
+        "Authenticate",
+        __Marshaller_spelldawn_AuthenticateRequest,
+        __Marshaller_spelldawn_AuthenticateResponse);
+
+    [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+    static readonly grpc::Method<global::Spelldawn.Protos.FetchCardCatalogRequest, global::Spelldawn.Protos.CardCatalog> __Method_FetchCardCatalog = new grpc::Method<global::Spelldawn.Protos.FetchCardCatalogRequest, global::Spelldawn.Protos.CardCatalog>(
+        grpc::MethodType.Unary,
+        __ServiceName,
+        "FetchCardCatalog",
+        __Marshaller_spelldawn_FetchCardCatalogRequest,
+        __Marshaller_spelldawn_CardCatalog);
+
+    [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+    static readonly grpc::Method<global::Spelldawn.Protos.FetchLeaderboardRequest, global::Spelldawn.Protos.LeaderboardPage> __Method_FetchLeaderboard = new grpc::Method<global::Spelldawn.Protos.FetchLeaderboardRequest, global::Spelldawn.Protos.LeaderboardPage>(
+        grpc::MethodType.Unary,
+        __ServiceName,
+        "FetchLeaderboard",
+        __Marshaller_spelldawn_FetchLeaderboardRequest,
+        __Marshaller_spelldawn_LeaderboardPage);
+
+    [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+    static readonly grpc::Method<global::Spelldawn.Protos.FetchRecordingsRequest, global::Spelldawn.Protos.RecordingList> __Method_FetchRecordings = new grpc::Method<global::Spelldawn.Protos.FetchRecordingsRequest, global::Spelldawn.Protos.RecordingList>(
+        grpc::MethodType.Unary,
+        __ServiceName,
+        "FetchRecordings",
+        __Marshaller_spelldawn_FetchRecordingsRequest,
+        __Marshaller_spelldawn_RecordingList);
+
     /// <summary>Service descriptor</summary>
     public static global::Google.Protobuf.Reflection.ServiceDescriptor Descriptor
     {
@@ -118,6 +166,54 @@ namespace Spelldawn.Protos {
         throw new grpc::RpcException(new grpc::Status(grpc::StatusCode.Unimplemented, ""));
       }
 
+      /// <summary>
+      /// Issue a session token for a player.
+      /// </summary>
+      /// <param name="request">The request received from the client.</param>
+      /// <param name="context">The context of the server-side call handler being invoked.</param>
+      /// <returns>The response to send back to the client (wrapped by a task).</returns>
+      [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+      public virtual global::System.Threading.Tasks.Task<global::Spelldawn.Protos.AuthenticateResponse> Authenticate(global::Spelldawn.Protos.AuthenticateRequest request, grpc::ServerCallContext context)
+      {
+        throw new grpc::RpcException(new grpc::Status(grpc::StatusCode.Unimplemented, ""));
+      }
+
+      /// <summary>
+      /// Fetch static metadata for every card in the game.
+      /// </summary>
+      /// <param name="request">The request received from the client.</param>
+      /// <param name="context">The context of the server-side call handler being invoked.</param>
+      /// <returns>The response to send back to the client (wrapped by a task).</returns>
+      [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+      public virtual global::System.Threading.Tasks.Task<global::Spelldawn.Protos.CardCatalog> FetchCardCatalog(global::Spelldawn.Protos.FetchCardCatalogRequest request, grpc::ServerCallContext context)
+      {
+        throw new grpc::RpcException(new grpc::Status(grpc::StatusCode.Unimplemented, ""));
+      }
+
+      /// <summary>
+      /// Fetch one page of the ranked leaderboard.
+      /// </summary>
+      /// <param name="request">The request received from the client.</param>
+      /// <param name="context">The context of the server-side call handler being invoked.</param>
+      /// <returns>The response to send back to the client (wrapped by a task).</returns>
+      [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+      public virtual global::System.Threading.Tasks.Task<global::Spelldawn.Protos.LeaderboardPage> FetchLeaderboard(global::Spelldawn.Protos.FetchLeaderboardRequest request, grpc::ServerCallContext context)
+      {
+        throw new grpc::RpcException(new grpc::Status(grpc::StatusCode.Unimplemented, ""));
+      }
+
+      /// <summary>
+      /// Fetch scenario recordings for client visual tests.
+      /// </summary>
+      /// <param name="request">The request received from the client.</param>
+      /// <param name="context">The context of the server-side call handler being invoked.</param>
+      /// <returns>The response to send back to the client (wrapped by a task).</returns>
+      [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+      public virtual global::System.Threading.Tasks.Task<global::Spelldawn.Protos.RecordingList> FetchRecordings(global::Spelldawn.Protos.FetchRecordingsRequest request, grpc::ServerCallContext context)
+      {
+        throw new grpc::RpcException(new grpc::Status(grpc::StatusCode.Unimplemented, ""));
+      }
+
     }
 
     /// <summary>Client for Spelldawn</summary>
@@ -219,6 +315,198 @@ namespace Spelldawn.Protos {
       {
         return CallInvoker.AsyncUnaryCall(__Method_PerformAction, null, options, request);
       }
+      /// <summary>
+      /// Issue a session token for a player.
+      /// </summary>
+      /// <param name="request">The request to send to the server.</param>
+      /// <param name="headers">The initial metadata to send with the call. This parameter is optional.</param>
+      /// <param name="deadline">An optional deadline for the call. The call will be cancelled if deadline is hit.</param>
+      /// <param name="cancellationToken">An optional token for canceling the call.</param>
+      /// <returns>The response received from the server.</returns>
+      [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+      public virtual global::Spelldawn.Protos.AuthenticateResponse Authenticate(global::Spelldawn.Protos.AuthenticateRequest request, grpc::Metadata headers = null, global::System.DateTime? deadline = null, global::System.Threading.CancellationToken cancellationToken = default(global::System.Threading.CancellationToken))
+      {
+        return Authenticate(request, new grpc::CallOptions(headers, deadline, cancellationToken));
+      }
+      /// <summary>
+      /// Issue a session token for a player.
+      /// </summary>
+      /// <param name="request">The request to send to the server.</param>
+      /// <param name="options">The options for the call.</param>
+      /// <returns>The response received from the server.</returns>
+      [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+      public virtual global::Spelldawn.Protos.AuthenticateResponse Authenticate(global::Spelldawn.Protos.AuthenticateRequest request, grpc::CallOptions options)
+      {
+        return CallInvoker.BlockingUnaryCall(__Method_Authenticate, null, options, request);
+      }
+      /// <summary>
+      /// Issue a session token for a player.
+      /// </summary>
+      /// <param name="request">The request to send to the server.</param>
+      /// <param name="headers">The initial metadata to send with the call. This parameter is optional.</param>
+      /// <param name="deadline">An optional deadline for the call. The call will be cancelled if deadline is hit.</param>
+      /// <param name="cancellationToken">An optional token for canceling the call.</param>
+      /// <returns>The call object.</returns>
+      [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+      public virtual grpc::AsyncUnaryCall<global::Spelldawn.Protos.AuthenticateResponse> AuthenticateAsync(global::Spelldawn.Protos.AuthenticateRequest request, grpc::Metadata headers = null, global::System.DateTime? deadline = null, global::System.Threading.CancellationToken cancellationToken = default(global::System.Threading.CancellationToken))
+      {
+        return AuthenticateAsync(request, new grpc::CallOptions(headers, deadline, cancellationToken));
+      }
+      /// <summary>
+      /// Issue a session token for a player.
+      /// </summary>
+      /// <param name="request">The request to send to the server.</param>
+      /// <param name="options">The options for the call.</param>
+      /// <returns>The call object.</returns>
+      [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+      public virtual grpc::AsyncUnaryCall<global::Spelldawn.Protos.AuthenticateResponse> AuthenticateAsync(global::Spelldawn.Protos.AuthenticateRequest request, grpc::CallOptions options)
+      {
+        return CallInvoker.AsyncUnaryCall(__Method_Authenticate, null, options, request);
+      }
+      /// <summary>
+      /// Fetch static metadata for every card in the game.
+      /// </summary>
+      /// <param name="request">The request to send to the server.</param>
+      /// <param name="headers">The initial metadata to send with the call. This parameter is optional.</param>
+      /// <param name="deadline">An optional deadline for the call. The call will be cancelled if deadline is hit.</param>
+      /// <param name="cancellationToken">An optional token for canceling the call.</param>
+      /// <returns>The response received from the server.</returns>
+      [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+      public virtual global::Spelldawn.Protos.CardCatalog FetchCardCatalog(global::Spelldawn.Protos.FetchCardCatalogRequest request, grpc::Metadata headers = null, global::System.DateTime? deadline = null, global::System.Threading.CancellationToken cancellationToken = default(global::System.Threading.CancellationToken))
+      {
+        return FetchCardCatalog(request, new grpc::CallOptions(headers, deadline, cancellationToken));
+      }
+      /// <summary>
+      /// Fetch static metadata for every card in the game.
+      /// </summary>
+      /// <param name="request">The request to send to the server.</param>
+      /// <param name="options">The options for the call.</param>
+      /// <returns>The response received from the server.</returns>
+      [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+      public virtual global::Spelldawn.Protos.CardCatalog FetchCardCatalog(global::Spelldawn.Protos.FetchCardCatalogRequest request, grpc::CallOptions options)
+      {
+        return CallInvoker.BlockingUnaryCall(__Method_FetchCardCatalog, null, options, request);
+      }
+      /// <summary>
+      /// Fetch static metadata for every card in the game.
+      /// </summary>
+      /// <param name="request">The request to send to the server.</param>
+      /// <param name="headers">The initial metadata to send with the call. This parameter is optional.</param>
+      /// <param name="deadline">An optional deadline for the call. The call will be cancelled if deadline is hit.</param>
+      /// <param name="cancellationToken">An optional token for canceling the call.</param>
+      /// <returns>The call object.</returns>
+      [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+      public virtual grpc::AsyncUnaryCall<global::Spelldawn.Protos.CardCatalog> FetchCardCatalogAsync(global::Spelldawn.Protos.FetchCardCatalogRequest request, grpc::Metadata headers = null, global::System.DateTime? deadline = null, global::System.Threading.CancellationToken cancellationToken = default(global::System.Threading.CancellationToken))
+      {
+        return FetchCardCatalogAsync(request, new grpc::CallOptions(headers, deadline, cancellationToken));
+      }
+      /// <summary>
+      /// Fetch static metadata for every card in the game.
+      /// </summary>
+      /// <param name="request">The request to send to the server.</param>
+      /// <param name="options">The options for the call.</param>
+      /// <returns>The call object.</returns>
+      [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+      public virtual grpc::AsyncUnaryCall<global::Spelldawn.Protos.CardCatalog> FetchCardCatalogAsync(global::Spelldawn.Protos.FetchCardCatalogRequest request, grpc::CallOptions options)
+      {
+        return CallInvoker.AsyncUnaryCall(__Method_FetchCardCatalog, null, options, request);
+      }
+      /// <summary>
+      /// Fetch one page of the ranked leaderboard.
+      /// </summary>
+      /// <param name="request">The request to send to the server.</param>
+      /// <param name="headers">The initial metadata to send with the call. This parameter is optional.</param>
+      /// <param name="deadline">An optional deadline for the call. The call will be cancelled if deadline is hit.</param>
+      /// <param name="cancellationToken">An optional token for canceling the call.</param>
+      /// <returns>The response received from the server.</returns>
+      [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+      public virtual global::Spelldawn.Protos.LeaderboardPage FetchLeaderboard(global::Spelldawn.Protos.FetchLeaderboardRequest request, grpc::Metadata headers = null, global::System.DateTime? deadline = null, global::System.Threading.CancellationToken cancellationToken = default(global::System.Threading.CancellationToken))
+      {
+        return FetchLeaderboard(request, new grpc::CallOptions(headers, deadline, cancellationToken));
+      }
+      /// <summary>
+      /// Fetch one page of the ranked leaderboard.
+      /// </summary>
+      /// <param name="request">The request to send to the server.</param>
+      /// <param name="options">The options for the call.</param>
+      /// <returns>The response received from the server.</returns>
+      [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+      public virtual global::Spelldawn.Protos.LeaderboardPage FetchLeaderboard(global::Spelldawn.Protos.FetchLeaderboardRequest request, grpc::CallOptions options)
+      {
+        return CallInvoker.BlockingUnaryCall(__Method_FetchLeaderboard, null, options, request);
+      }
+      /// <summary>
+      /// Fetch one page of the ranked leaderboard.
+      /// </summary>
+      /// <param name="request">The request to send to the server.</param>
+      /// <param name="headers">The initial metadata to send with the call. This parameter is optional.</param>
+      /// <param name="deadline">An optional deadline for the call. The call will be cancelled if deadline is hit.</param>
+      /// <param name="cancellationToken">An optional token for canceling the call.</param>
+      /// <returns>The call object.</returns>
+      [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+      public virtual grpc::AsyncUnaryCall<global::Spelldawn.Protos.LeaderboardPage> FetchLeaderboardAsync(global::Spelldawn.Protos.FetchLeaderboardRequest request, grpc::Metadata headers = null, global::System.DateTime? deadline = null, global::System.Threading.CancellationToken cancellationToken = default(global::System.Threading.CancellationToken))
+      {
+        return FetchLeaderboardAsync(request, new grpc::CallOptions(headers, deadline, cancellationToken));
+      }
+      /// <summary>
+      /// Fetch one page of the ranked leaderboard.
+      /// </summary>
+      /// <param name="request">The request to send to the server.</param>
+      /// <param name="options">The options for the call.</param>
+      /// <returns>The call object.</returns>
+      [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+      public virtual grpc::AsyncUnaryCall<global::Spelldawn.Protos.LeaderboardPage> FetchLeaderboardAsync(global::Spelldawn.Protos.FetchLeaderboardRequest request, grpc::CallOptions options)
+      {
+        return CallInvoker.AsyncUnaryCall(__Method_FetchLeaderboard, null, options, request);
+      }
+      /// <summary>
+      /// Fetch scenario recordings for client visual tests.
+      /// </summary>
+      /// <param name="request">The request to send to the server.</param>
+      /// <param name="headers">The initial metadata to send with the call. This parameter is optional.</param>
+      /// <param name="deadline">An optional deadline for the call. The call will be cancelled if deadline is hit.</param>
+      /// <param name="cancellationToken">An optional token for canceling the call.</param>
+      /// <returns>The response received from the server.</returns>
+      [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+      public virtual global::Spelldawn.Protos.RecordingList FetchRecordings(global::Spelldawn.Protos.FetchRecordingsRequest request, grpc::Metadata headers = null, global::System.DateTime? deadline = null, global::System.Threading.CancellationToken cancellationToken = default(global::System.Threading.CancellationToken))
+      {
+        return FetchRecordings(request, new grpc::CallOptions(headers, deadline, cancellationToken));
+      }
+      /// <summary>
+      /// Fetch scenario recordings for client visual tests.
+      /// </summary>
+      /// <param name="request">The request to send to the server.</param>
+      /// <param name="options">The options for the call.</param>
+      /// <returns>The response received from the server.</returns>
+      [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+      public virtual global::Spelldawn.Protos.RecordingList FetchRecordings(global::Spelldawn.Protos.FetchRecordingsRequest request, grpc::CallOptions options)
+      {
+        return CallInvoker.BlockingUnaryCall(__Method_FetchRecordings, null, options, request);
+      }
+      /// <summary>
+      /// Fetch scenario recordings for client visual tests.
+      /// </summary>
+      /// <param name="request">The request to send to the server.</param>
+      /// <param name="headers">The initial metadata to send with the call. This parameter is optional.</param>
+      /// <param name="deadline">An optional deadline for the call. The call will be cancelled if deadline is hit.</param>
+      /// <param name="cancellationToken">An optional token for canceling the call.</param>
+      /// <returns>The call object.</returns>
+      [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+      public virtual grpc::AsyncUnaryCall<global::Spelldawn.Protos.RecordingList> FetchRecordingsAsync(global::Spelldawn.Protos.FetchRecordingsRequest request, grpc::Metadata headers = null, global::System.DateTime? deadline = null, global::System.Threading.CancellationToken cancellationToken = default(global::System.Threading.CancellationToken))
+      {
+        return FetchRecordingsAsync(request, new grpc::CallOptions(headers, deadline, cancellationToken));
+      }
+      /// <summary>
+      /// Fetch scenario recordings for client visual tests.
+      /// </summary>
+      /// <param name="request">The request to send to the server.</param>
+      /// <param name="options">The options for the call.</param>
+      /// <returns>The call object.</returns>
+      [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
+      public virtual grpc::AsyncUnaryCall<global::Spelldawn.Protos.RecordingList> FetchRecordingsAsync(global::Spelldawn.Protos.FetchRecordingsRequest request, grpc::CallOptions options)
+      {
+        return CallInvoker.AsyncUnaryCall(__Method_FetchRecordings, null, options, request);
+      }
       /// <summary>Creates a new instance of client from given <c>ClientBaseConfiguration</c>.</summary>
       [global::System.CodeDom.Compiler.GeneratedCode("grpc_csharp_plugin", null)]
       protected override SpelldawnClient NewInstance(ClientBaseConfiguration configuration)
@@ -234,7 +522,11 @@ namespace Spelldawn.Protos {
     {
       return grpc::ServerServiceDefinition.CreateBuilder()
           .AddMethod(__Method_Connect, serviceImpl.Connect)
-          .AddMethod(__Method_PerformAction, serviceImpl.PerformAction).Build();
+          .AddMethod(__Method_PerformAction, serviceImpl.PerformAction)
+          .AddMethod(__Method_Authenticate, serviceImpl.Authenticate)
+          .AddMethod(__Method_FetchCardCatalog, serviceImpl.FetchCardCatalog)
+          .AddMethod(__Method_FetchLeaderboard, serviceImpl.FetchLeaderboard)
+          .AddMethod(__Method_FetchRecordings, serviceImpl.FetchRecordings).Build();
     }
 
     /// <summary>Register service method with a service binder with or without implementation. Useful when customizing the  service binding logic.
@@ -246,6 +538,10 @@ namespace Spelldawn.Protos {
     {
       serviceBinder.AddMethod(__Method_Connect, serviceImpl == null ? null : new grpc::ServerStreamingServerMethod<global::Spelldawn.Protos.ConnectRequest, global::Spelldawn.Protos.CommandList>(serviceImpl.Connect));
       serviceBinder.AddMethod(__Method_PerformAction, serviceImpl == null ? null : new grpc::UnaryServerMethod<global::Spelldawn.Protos.GameRequest, global::Spelldawn.Protos.CommandList>(serviceImpl.PerformAction));
+      serviceBinder.AddMethod(__Method_Authenticate, serviceImpl == null ? null : new grpc::UnaryServerMethod<global::Spelldawn.Protos.AuthenticateRequest, global::Spelldawn.Protos.AuthenticateResponse>(serviceImpl.Authenticate));
+      serviceBinder.AddMethod(__Method_FetchCardCatalog, serviceImpl == null ? null : new grpc::UnaryServerMethod<global::Spelldawn.Protos.FetchCardCatalogRequest, global::Spelldawn.Protos.CardCatalog>(serviceImpl.FetchCardCatalog));
+      serviceBinder.AddMethod(__Method_FetchLeaderboard, serviceImpl == null ? null : new grpc::UnaryServerMethod<global::Spelldawn.Protos.FetchLeaderboardRequest, global::Spelldawn.Protos.LeaderboardPage>(serviceImpl.FetchLeaderboard));
+      serviceBinder.AddMethod(__Method_FetchRecordings, serviceImpl == null ? null : new grpc::UnaryServerMethod<global::Spelldawn.Protos.FetchRecordingsRequest, global::Spelldawn.Protos.RecordingList>(serviceImpl.FetchRecordings));
     }
 
   }
@@ -57,6 +57,10 @@ namespace Spelldawn.Services
     [SerializeField] bool _currentlyHandlingAction;
     readonly Queue<ClientAction> _actionQueue = new();
     PlayerIdentifier? _playerIdentifier;
+    string? _sessionToken;
+
+    // Identifies this client instance, allowing the same player to connect from multiple devices
+    readonly string _clientId = Guid.NewGuid().ToString();
     bool _attemptReconnect;
 
     public bool Active => _currentlyHandlingAction || _actionQueue.Count > 0;
@@ -139,16 +143,34 @@ namespace Spelldawn.Services
     {
       _registry.DocumentService.Loading = true;
 
-      var request = new ConnectRequest
+      try
       {
-        PlayerId = Errors.CheckNotNull(_playerIdentifier),
-      };
+        if (_sessionToken == null)
+        {
+          var authentication = await _client.Value.AuthenticateAsync(new AuthenticateRequest
+          {
+            PlayerId = Errors.CheckNotNull(_playerIdentifier),
+            PlayerSecret = PlayerPrefs.GetString(Preferences.PlayerSecret, ""),
+          });
+          if (authentication.PlayerSecret != "")
+          {
+            PlayerPrefs.SetString(Preferences.PlayerSecret, authentication.PlayerSecret);
+            PlayerPrefs.Save();
+          }
 
-      // TODO: Android in particular seems to hang for multiple minutes when the server can't be reached?
-      using var call = _client.Value.Connect(request);
+          _sessionToken = authentication.SessionToken;
+        }
+
+        var request = new ConnectRequest
+        {
+          PlayerId = Errors.CheckNotNull(_playerIdentifier),
+          SessionToken = _sessionToken,
+          ClientId = _clientId,
+        };
+
+        // TODO: Android in particular seems to hang for multiple minutes when the server can't be reached?
+        using var call = _client.Value.Connect(request);
 
-      try
-      {
         while (await call.ResponseStream.MoveNext())
         {
           if (this != null)
@@ -163,6 +185,12 @@ namespace Spelldawn.Services
       }
       catch (RpcException e)
       {
+        if (e.StatusCode == StatusCode.Unauthenticated)
+        {
+          // Request a new session token on the next connection attempt
+          _sessionToken = null;
+        }
+
         _registry.DocumentService.Loading = true;
         _attemptReconnect = true;
         if (!DoNotLogRpcErrors.ShouldSkipLoggingRpcErrors)
@@ -192,6 +220,8 @@ namespace Spelldawn.Services
       {
         Action = action,
         PlayerId = Errors.CheckNotNull(_playerIdentifier),
+        SessionToken = _sessionToken ?? "",
+        ClientId = _clientId,
       };
       request.OpenPanels.AddRange(_registry.DocumentService.OpenPanels);
 
@@ -228,6 +258,12 @@ namespace Spelldawn.Services
 
           yield return _registry.CommandService.HandleCommands(task.GetResult());
           break;
+        case StatusCode.FailedPrecondition:
+          // The server rejected this action, reconnect to discard any optimistic updates.
+          _attemptReconnect = true;
+          var error = ServerErrorFromTrailers(call.GetTrailers());
+          Debug.LogWarning($"Action rejected: {error?.Code} -- {error?.Message ?? call.GetStatus().Detail}");
+          break;
         default:
           _registry.DocumentService.Loading = true;
           _attemptReconnect = true;
@@ -242,6 +278,12 @@ namespace Spelldawn.Services
       _currentlyHandlingAction = false;
     }
 
+    static ServerError? ServerErrorFromTrailers(Metadata trailers)
+    {
+      var details = trailers.FirstOrDefault(entry => entry.Key == "grpc-status-details-bin");
+      return details == null ? null : ServerError.Parser.ParseFrom(details.ValueBytes);
+    }
+
     /// <summary>
     /// Immediate action handling, without waiting for the queue. This is needed to avoid things that feel
     /// broken, like waiting for animations before closing a panel.
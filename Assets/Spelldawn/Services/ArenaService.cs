@@ -34,6 +34,10 @@ namespace Spelldawn.Services
     [SerializeField] Registry _registry = null!;
     [SerializeField] LinearObjectDisplay _leftItems = null!;
     [SerializeField] LinearObjectDisplay _rightItems = null!;
+
+    // Optional dedicated display for Champion allies. Allies share the right item display if this is not
+    // configured in the scene.
+    [SerializeField] LinearObjectDisplay? _allies;
     [SerializeField] List<Room> _rooms = null!;
     [SerializeField] AssetReferenceGameObject _initiateRaidPrefabReference = null!;
     [SerializeField] AssetReferenceGameObject _levelUpRoomPrefabReference = null!;
@@ -44,10 +48,12 @@ namespace Spelldawn.Services
     
     public ObjectDisplay LeftItems => _leftItems;
     public ObjectDisplay RightIems => _rightItems;
+    public ObjectDisplay Allies => _allies ? _allies! : _rightItems;
 
     public bool RoomsOnBottom { get; private set; }
 
     readonly RaycastHit[] _raycastHitsTempBuffer = new RaycastHit[8];
+    static readonly Color RoomHighlightColor = new(1f, 1f, 1f, 0.35f);
 
     public IEnumerator Initialize()
     {
@@ -78,6 +84,7 @@ namespace Spelldawn.Services
     {
       ClientItemLocation.Left => _leftItems,
       ClientItemLocation.Right => _rightItems,
+      ClientItemLocation.Allies => Allies,
       _ => throw new ArgumentOutOfRangeException(nameof(location), location, null)
     };
 
@@ -89,6 +96,8 @@ namespace Spelldawn.Services
           return _leftItems.AddObject(card, animate);
         case ClientItemLocation.Right:
           return _rightItems.AddObject(card, animate);
+        case ClientItemLocation.Allies:
+          return Allies.AddObject(card, animate);
         default:
           Debug.LogError($"Unknown item location: {position.ItemLocation}");
           return _rightItems.AddObject(card, animate);
@@ -111,6 +120,7 @@ namespace Spelldawn.Services
           if (validRooms.Contains(selector.RoomId))
           {
             selector.SpriteRenderer.enabled = true;
+            selector.SpriteRenderer.color = Color.white;
             _curentRoomSelector = selector;
             break;
           }
@@ -120,13 +130,31 @@ namespace Spelldawn.Services
       Array.Clear(_raycastHitsTempBuffer, 0, _raycastHitsTempBuffer.Length);
     }
 
+    /// <summary>
+    /// Dimly highlights the provided rooms, e.g. to show legal targets while a card is being dragged. The
+    /// currently-selected room, if any, keeps its full highlight.
+    /// </summary>
+    public void HighlightRooms(ISet<RoomIdentifier> rooms)
+    {
+      foreach (var room in _rooms)
+      {
+        if (room != _curentRoomSelector && rooms.Contains(room.RoomId))
+        {
+          room.SpriteRenderer.enabled = true;
+          room.SpriteRenderer.color = RoomHighlightColor;
+        }
+      }
+    }
+
     public void HideRoomSelector()
     {
-      if (_curentRoomSelector)
+      foreach (var room in _rooms)
       {
-        _curentRoomSelector!.SpriteRenderer.enabled = false;
-        _curentRoomSelector = null;
+        room.SpriteRenderer.enabled = false;
+        room.SpriteRenderer.color = Color.white;
       }
+
+      _curentRoomSelector = null;
     }
 
     public IEnumerator HandleVisitRoom(VisitRoomCommand command)
@@ -161,6 +189,10 @@ namespace Spelldawn.Services
 
       SetObjectDisplayActive(_leftItems, command);
       SetObjectDisplayActive(_rightItems, command);
+      if (_allies)
+      {
+        SetObjectDisplayActive(_allies!, command);
+      }
 
       SetGameObjectsEnabledForPlayer(PlayerName.User, command);
       SetGameObjectsEnabledForPlayer(PlayerName.Opponent, command);
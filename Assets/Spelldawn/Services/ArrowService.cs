@@ -13,8 +13,10 @@
 // limitations under the License.
 
 using System;
+using System.Collections.Generic;
 using Spelldawn.Assets;
 using Spelldawn.Game;
+using Spelldawn.Protos;
 using UnityEngine;
 
 #nullable enable
@@ -48,6 +50,7 @@ namespace Spelldawn.Services
     [SerializeField] GameObject _placeholderHeadPrefab = null!;
     [SerializeField] GameObject _placeholderPiecePrefab = null!;
     IArrowDelegate? _delegate;
+    readonly List<Arrow> _displayedArrows = new();
 
     void Start()
     {
@@ -109,6 +112,45 @@ namespace Spelldawn.Services
       _blueArrow.gameObject.SetActive(false);
     }
 
+    /// <summary>
+    /// Shows static arrows between game objects, replacing any arrows shown by a previous command.
+    /// </summary>
+    public void HandleDisplayArrows(DisplayArrowsCommand command)
+    {
+      ClearDisplayedArrows();
+      foreach (var displayed in command.Arrows)
+      {
+        if (TypeForArrow(displayed.Color) is not { } type)
+        {
+          continue;
+        }
+
+        var arrow = Instantiate(ArrowForType(type), transform);
+        arrow.Source = _registry.ObjectPositionService.Find(displayed.Source).transform.position;
+        arrow.Target = _registry.ObjectPositionService.Find(displayed.Target).transform.position;
+        arrow.gameObject.SetActive(true);
+        _displayedArrows.Add(arrow);
+      }
+    }
+
+    public void ClearDisplayedArrows()
+    {
+      foreach (var arrow in _displayedArrows)
+      {
+        Destroy(arrow.gameObject);
+      }
+
+      _displayedArrows.Clear();
+    }
+
+    public static Type? TypeForArrow(TargetingArrow arrow) => arrow switch
+    {
+      TargetingArrow.Red => Type.Red,
+      TargetingArrow.Blue => Type.Blue,
+      TargetingArrow.Green => Type.Green,
+      _ => null
+    };
+
     Arrow ArrowForType(Type type) => type switch
     {
       Type.Red => _redArrow,
@@ -139,6 +139,12 @@ namespace Spelldawn.Services
           case GameCommand.CommandOneofCase.RenderScreenOverlay:
             LoadNodeAssets(requests, command.RenderScreenOverlay.Node);
             break;
+          case GameCommand.CommandOneofCase.ShowToast:
+            LoadNodeAssets(requests, command.ShowToast.Content);
+            break;
+          case GameCommand.CommandOneofCase.DisplayEmote:
+            LoadNodeAssets(requests, command.DisplayEmote.Content);
+            break;
           case GameCommand.CommandOneofCase.None:
           default:
             break;
@@ -215,6 +221,7 @@ namespace Spelldawn.Services
       {
         LoadNodeAssets(requests, panel.Node);
         LoadNodeAssets(requests, panel.ScreenOverlay);
+        LoadNodePatchesAssets(requests, panel.NodePatches);
       }
     }
 
@@ -224,6 +231,7 @@ namespace Spelldawn.Services
       if (mainControls != null)
       {
         LoadNodeAssets(requests, mainControls.Node);
+        LoadNodePatchesAssets(requests, mainControls.NodePatches);
 
         foreach (var controlNode in mainControls.CardAnchorNodes)
         {
@@ -232,6 +240,18 @@ namespace Spelldawn.Services
       }
     }
 
+    void LoadNodePatchesAssets(IDictionary<string, AsyncOperationHandle> requests, NodePatchList? patches)
+    {
+      if (patches != null)
+      {
+        foreach (var patch in patches.Patches)
+        {
+          LoadNodeAssets(requests, patch.Replace);
+          LoadNodeAssets(requests, patch.AppendChild);
+        }
+      }
+    }
+
     void LoadNodeAssets(IDictionary<string, AsyncOperationHandle> requests, Node? node)
     {
       if (node != null)
@@ -59,7 +59,8 @@ namespace Spelldawn.Services
           // being targeted), then it cannot be info zoomed and the long-press browser is used instead.
           return displayable.Parent == _registry.RaidService.RaidParticipants ||
                  displayable.Parent == _registry.ArenaService.LeftItems ||
-                 displayable.Parent == _registry.ArenaService.RightIems;
+                 displayable.Parent == _registry.ArenaService.RightIems ||
+                 displayable.Parent == _registry.ArenaService.Allies;
         case GameContext.Browser:
         case GameContext.RewardBrowser:
         case GameContext.LongPressBrowser:
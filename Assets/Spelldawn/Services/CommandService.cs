@@ -90,6 +90,7 @@ namespace Spelldawn.Services
             yield return new WaitForSeconds(DataUtils.ToSeconds(command.Delay.Duration, 0));
             break;
           case GameCommand.CommandOneofCase.UpdateGameView:
+            _registry.ArrowService.ClearDisplayedArrows();
             yield return HandleUpdateGameView(command.UpdateGameView.Game, command.UpdateGameView.Animate);
             break;
           case GameCommand.CommandOneofCase.VisitRoom:
@@ -134,9 +135,21 @@ namespace Spelldawn.Services
           case GameCommand.CommandOneofCase.RenderScreenOverlay:
             _registry.DocumentService.SetScreenOverlay(command.RenderScreenOverlay.Node);
             break;
+          case GameCommand.CommandOneofCase.ShowToast:
+            _registry.DocumentService.ShowToast(command.ShowToast);
+            break;
+          case GameCommand.CommandOneofCase.DisplayEmote:
+            _registry.DocumentService.DisplayEmote(command.DisplayEmote);
+            break;
+          case GameCommand.CommandOneofCase.UpdatePreferences:
+            _registry.SettingsService.HandleUpdatePreferences(command.UpdatePreferences);
+            break;
           case GameCommand.CommandOneofCase.UpdateInterface:
             yield return _registry.UpdateInterfaceService.HandleUpdate(command.UpdateInterface);
             break;
+          case GameCommand.CommandOneofCase.DisplayArrows:
+            _registry.ArrowService.HandleDisplayArrows(command.DisplayArrows);
+            break;
           case GameCommand.CommandOneofCase.Conditional:
             yield return HandleConditionalCommand(command.Conditional);
             break;
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 using System;
+using System.Collections;
 using System.Collections.Generic;
 using System.Linq;
 using Spelldawn.Game;
@@ -44,6 +45,14 @@ namespace Spelldawn.Services
     readonly List<InterfacePanelAddress> _openPanels = new();
     readonly Dictionary<InterfacePanelAddress, InterfacePanel> _panelCache = new();
     readonly HashSet<InterfacePanelAddress> _waitingFor = new();
+
+    // Most recent nodes received from the server, used as the base for NodePatches
+    readonly Dictionary<InterfacePanelAddress, Node> _lastPanelNodes = new();
+    Node? _lastMainControls;
+
+    // Keyboard shortcuts for buttons in the current main controls
+    readonly List<(KeyCode, ClientAction)> _hotkeys = new();
+
     InterfacePanelAddress? _switchTo;
     VisualElement _mainControls = null!;
     VisualElement _cardControls = null!;
@@ -52,6 +61,9 @@ namespace Spelldawn.Services
     BottomSheet _bottomSheet = null!;
     VisualElement? _loading;
     VisualElement _screenOverlay = null!;
+    VisualElement _toast = null!;
+    VisualElement _userEmote = null!;
+    VisualElement _opponentEmote = null!;
     Node? _screenOverlayNode;
     Coroutine? _autoRefresh;
     float _rotateAngle;
@@ -83,6 +95,9 @@ namespace Spelldawn.Services
       _document.rootVisualElement.Add(_bottomSheet);
       CreateLoadingSpinner();
       AddRoot("ScreenOverlay", out _screenOverlay);
+      AddRoot("Toast", out _toast);
+      AddRoot("UserEmote", out _userEmote);
+      AddRoot("OpponentEmote", out _opponentEmote);
       AddRoot("InfoZoom", out _infoZoom);
     }
 
@@ -93,6 +108,18 @@ namespace Spelldawn.Services
         _rotateAngle = (_rotateAngle + (Time.deltaTime * 600)) % 360;
         _loading.style.rotate = new Rotate(Angle.Degrees(_rotateAngle));
       }
+
+      if (!IsAnyPanelOpen())
+      {
+        foreach (var (keyCode, action) in _hotkeys)
+        {
+          if (Input.GetKeyDown(keyCode))
+          {
+            _registry.ActionService.HandleAction(action);
+            break;
+          }
+        }
+      }
     }
 
     void CreateLoadingSpinner()
@@ -331,7 +358,21 @@ namespace Spelldawn.Services
           Loading = false;
         }
 
-        _panelCache[panel.Address] = panel;
+        var node = NodePatches.Receive(
+          _lastPanelNodes.GetValueOrDefault(panel.Address),
+          panel.Node,
+          panel.NodePatches);
+        if (node != null)
+        {
+          _lastPanelNodes[panel.Address] = node;
+        }
+
+        _panelCache[panel.Address] = new InterfacePanel
+        {
+          Address = panel.Address,
+          Node = node,
+          ScreenOverlay = panel.ScreenOverlay
+        };
       }
 
       if (_waitingFor.Count == 0)
@@ -344,13 +385,48 @@ namespace Spelldawn.Services
 
     public void RenderMainControls(InterfaceMainControls? mainControls)
     {
+      var node = mainControls == null
+        ? null
+        : NodePatches.Receive(_lastMainControls, mainControls.Node, mainControls.NodePatches);
+      if (node != null)
+      {
+        _lastMainControls = node;
+      }
+
       Reconcile(
         ref _mainControls,
-        MainControls(mainControls?.Node));
+        MainControls(node));
 
       Reconcile(
         ref _cardControls,
         CardAnchors(mainControls?.CardAnchorNodes ?? Enumerable.Empty<CardAnchorNode>()));
+
+      _hotkeys.Clear();
+      AddHotkeys(node);
+      foreach (var anchorNode in mainControls?.CardAnchorNodes ?? Enumerable.Empty<CardAnchorNode>())
+      {
+        AddHotkeys(anchorNode.Node);
+      }
+    }
+
+    void AddHotkeys(Node? node)
+    {
+      if (node == null)
+      {
+        return;
+      }
+
+      if (node.Hotkey is { } hotkey &&
+          node.EventHandlers?.OnClick is { } onClick &&
+          Enum.TryParse<KeyCode>(hotkey.KeyCode, out var keyCode))
+      {
+        _hotkeys.Add((keyCode, onClick));
+      }
+
+      foreach (var child in node.Children)
+      {
+        AddHotkeys(child);
+      }
     }
 
     public void AddRequestFields(StandardAction action)
@@ -368,6 +444,39 @@ namespace Spelldawn.Services
       UpdateScreenOverlay();
     }
 
+    public void ShowToast(ShowToastCommand command)
+    {
+      StartCoroutine(ShowToastAsync(command));
+    }
+
+    IEnumerator ShowToastAsync(ShowToastCommand command)
+    {
+      Reconcile(ref _toast, command.Content);
+      yield return new WaitForSeconds(DataUtils.ToSeconds(command.Duration, 3000));
+      Reconcile(ref _toast, new Node());
+    }
+
+    public void DisplayEmote(DisplayEmoteCommand command)
+    {
+      StartCoroutine(DisplayEmoteAsync(command));
+    }
+
+    IEnumerator DisplayEmoteAsync(DisplayEmoteCommand command)
+    {
+      if (command.Player == PlayerName.User)
+      {
+        Reconcile(ref _userEmote, command.Content);
+        yield return new WaitForSeconds(DataUtils.ToSeconds(command.Duration, 3000));
+        Reconcile(ref _userEmote, new Node());
+      }
+      else
+      {
+        Reconcile(ref _opponentEmote, command.Content);
+        yield return new WaitForSeconds(DataUtils.ToSeconds(command.Duration, 3000));
+        Reconcile(ref _opponentEmote, new Node());
+      }
+    }
+
     void RenderPanels()
     {
       Reconcile(
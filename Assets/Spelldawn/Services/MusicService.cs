@@ -51,16 +51,18 @@ namespace Spelldawn.Services
     /// <summary>Sets audio source volume by reading the value of the music volume PlayerPref</summary>
     public void SyncVolume()
     {
-      if (PlayerPrefs.HasKey(Preferences.MusicVolume))
-      {
-        _audioSourceA.volume = PlayerPrefs.GetFloat(Preferences.MusicVolume);
-        _audioSourceB.volume = PlayerPrefs.GetFloat(Preferences.MusicVolume);             
-      }
-      else
+      _audioSourceA.volume = Volume();
+      _audioSourceB.volume = Volume();
+    }
+
+    static float Volume()
+    {
+      if (PlayerPrefs.GetInt(Preferences.MuteAudio) != 0)
       {
-        _audioSourceA.volume = 0.25f;
-        _audioSourceB.volume = 0.25f;
+        return 0f;
       }
+
+      return PlayerPrefs.HasKey(Preferences.MusicVolume) ? PlayerPrefs.GetFloat(Preferences.MusicVolume) : 0.25f;
     }
 
     public void SetMusicState(MusicState state)
@@ -94,7 +96,7 @@ namespace Spelldawn.Services
         _currentAudioSource = _currentAudioSource == _audioSourceA ? _audioSourceB : _audioSourceA;
         _currentAudioSource.clip = track;
         _currentAudioSource.volume = 0f;
-        _currentAudioSource.DOFade(PlayerPrefs.GetFloat(Preferences.MusicVolume), 1.0f);
+        _currentAudioSource.DOFade(Volume(), 1.0f);
         _currentAudioSource.Play();
       }
     }
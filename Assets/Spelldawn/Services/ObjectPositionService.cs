@@ -200,6 +200,8 @@ namespace Spelldawn.Services
           RevealedCardsBrowserSize.Large => _registry.RevealedCardsBrowserLarge,
           _ => throw new ArgumentOutOfRangeException()
         },
+        ObjectPosition.PositionOneofCase.GlobalModifier =>
+          _registry.GlobalModifierPosition,
         _ => throw new ArgumentOutOfRangeException()
       };
     }
@@ -100,6 +100,9 @@ namespace Spelldawn.Services
     [SerializeField] CurveObjectDisplay _revealedCardsBrowserLarge = null!;
     public CurveObjectDisplay RevealedCardsBrowserLarge => _revealedCardsBrowserLarge;    
 
+    [SerializeField] StackObjectDisplay _globalModifierPosition = null!;
+    public StackObjectDisplay GlobalModifierPosition => _globalModifierPosition;
+
     [SerializeField] CardBrowser _cardBrowser = null!;
     public CardBrowser CardBrowser => _cardBrowser;
 
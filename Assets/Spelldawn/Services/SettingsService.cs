@@ -14,6 +14,8 @@
 
 #nullable enable
 
+using Spelldawn.Protos;
+using Spelldawn.Utils;
 using UnityEngine;
 
 namespace Spelldawn.Services
@@ -25,6 +27,25 @@ namespace Spelldawn.Services
     public void SyncPreferences()
     {
       _registry.MusicService.SyncVolume();
+      _registry.MainAudioSource.volume = PlayerPrefs.GetInt(Preferences.MuteAudio) != 0
+        ? 0f
+        : PlayerPrefs.GetFloat(Preferences.SoundEffectsVolume, 1.0f);
+    }
+
+    /// <summary>Overwrites local preferences with the values stored on the server.</summary>
+    public void HandleUpdatePreferences(UpdatePreferencesCommand command)
+    {
+      foreach (var preference in command.FloatPreferences)
+      {
+        PlayerPrefs.SetFloat(preference.Key, preference.Value);
+      }
+
+      foreach (var preference in command.BooleanPreferences)
+      {
+        PlayerPrefs.SetInt(preference.Key, preference.Value ? 1 : 0);
+      }
+
+      SyncPreferences();
     }
   }
 }
\ No newline at end of file
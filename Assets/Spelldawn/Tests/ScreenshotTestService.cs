@@ -35,6 +35,9 @@ namespace Spelldawn.Tests
   {
     // Set a filename here to pause the test before and after it runs
     static readonly string? DebugPauseOn = null;
+
+    // Must match FORMAT_VERSION in crates/server/src/recordings.rs
+    const uint RecordingFormatVersion = 1;
     
     public Registry Registry { get; private set; } = null!;
     string _directory = null!;
@@ -91,10 +94,18 @@ namespace Spelldawn.Tests
 
     IEnumerator RunTest(TextAsset textAsset)
     {
-      var list = CommandList.Parser.ParseDelimitedFrom(new MemoryStream(textAsset.bytes));
+      var recording = ScenarioRecording.Parser.ParseDelimitedFrom(new MemoryStream(textAsset.bytes));
+      if (recording.FormatVersion != RecordingFormatVersion)
+      {
+        Debug.LogError($"ERROR: Recording {recording.Name} has format version {recording.FormatVersion}, " +
+                       $"expected {RecordingFormatVersion}. Re-run the server tests to regenerate it.");
+        Quit(1);
+        yield break;
+      }
 
+      Debug.Log($"Running {recording.Name}: {recording.Description}");
       var count = 100;
-      foreach (var command in list.Commands)
+      foreach (var command in recording.Commands.Commands)
       {
         var fileName = $"{textAsset.name}_{count}.png";
         if (ShouldHandle(command.CommandCase) && ShouldPause(fileName))
@@ -19,5 +19,8 @@ namespace Spelldawn.Utils
   public static class Preferences
   {
     public const string MusicVolume = "MusicVolume";
+    public const string SoundEffectsVolume = "SoundEffectsVolume";
+    public const string MuteAudio = "MuteAudio";
+    public const string PlayerSecret = "PlayerSecret";
   }
 }
\ No newline at end of file
@@ -21,22 +21,27 @@ pub mod legal_actions;
 use anyhow::Result;
 use data::card_definition::AbilityType;
 use data::card_state::CardPosition;
-use data::delegates::{
-    AbilityActivated, ActivateAbilityEvent, CardPlayed, CastCardEvent, DrawCardActionEvent,
-};
-use data::game::{GamePhase, GameState, MulliganDecision};
-use data::game_actions::{CardTarget, GameAction, PromptAction};
+use data::delegates::{AbilityActivated, ActivateAbilityEvent, CardPlayed, DrawCardActionEvent};
+use data::game::{GamePhase, GameState, MulliganDecision, ReactionTrigger};
+use data::game_actions::{CardTarget, GameAction, GamePrompt, PromptAction};
+use data::history::HistoryEvent;
 use data::primitives::{AbilityId, CardId, RoomId, Side};
 use data::updates::{GameUpdate, InitiatedBy};
 use raids::RaidDataExt;
 use rules::mana::ManaPurpose;
 use rules::{card_prompt, dispatch, flags, mana, mutations, queries};
 use tracing::{info, instrument};
-use with_error::{fail, verify, WithError};
+use with_error::{fail, verify_code, ErrorCode, WithError};
 
 /// Top level dispatch function responsible for mutating [GameState] in response
 /// to all [GameAction]s
 pub fn handle_game_action(game: &mut GameState, user_side: Side, action: GameAction) -> Result<()> {
+    game.delegate_trace.clear();
+    handle_action_impl(game, user_side, action)?;
+    raids::start_queued_raid(game)
+}
+
+fn handle_action_impl(game: &mut GameState, user_side: Side, action: GameAction) -> Result<()> {
     match action {
         GameAction::PromptAction(prompt_action) => {
             handle_prompt_action(game, user_side, prompt_action)
@@ -65,15 +70,32 @@ pub fn can_take_action(game: &GameState, side: Side) -> bool {
         _ => {}
     };
 
+    if game.data.reaction_window.is_some() {
+        return side == Side::Champion;
+    }
+
     match &game.data.raid {
         Some(raid) => side == raid.phase().active_side(),
         None => side == game.data.turn.side,
     }
 }
 
+/// Fails with [ErrorCode::NotYourTurn] if `user_side` is attempting to take a
+/// basic game action during their opponent's turn.
+fn verify_turn(game: &GameState, user_side: Side) -> Result<()> {
+    verify_code!(
+        !flags::is_opponents_turn(game, user_side),
+        ErrorCode::NotYourTurn,
+        "It is not the {:?} player's turn",
+        user_side
+    );
+    Ok(())
+}
+
 fn handle_resign_action(game: &mut GameState, side: Side) -> Result<()> {
     info!(?side, "handle_resign_action");
     if !matches!(game.data.phase, GamePhase::GameOver { .. }) {
+        game.record_update(|| GameUpdate::PlayerConceded(side));
         mutations::game_over(game, side.opponent())?;
     }
     Ok(())
@@ -86,8 +108,9 @@ fn handle_mulligan_decision(
     decision: MulliganDecision,
 ) -> Result<()> {
     info!(?user_side, ?decision, "handle_mulligan_decision");
-    verify!(
+    verify_code!(
         flags::can_make_mulligan_decision(game, user_side),
+        ErrorCode::IllegalAction,
         "Cannot make mulligan decision for {:?}",
         user_side
     );
@@ -106,7 +129,8 @@ fn handle_mulligan_decision(
         MulliganDecision::Keep => {}
         MulliganDecision::Mulligan => {
             mutations::shuffle_into_deck(game, user_side, &hand)?;
-            mutations::draw_cards(game, user_side, 5)?;
+            let hand_size = game.data.config.rules.starting_hand_size;
+            mutations::draw_cards(game, user_side, hand_size)?;
         }
     }
 
@@ -120,8 +144,10 @@ fn handle_mulligan_decision(
 #[instrument(skip(game))]
 fn draw_card_action(game: &mut GameState, user_side: Side) -> Result<()> {
     info!(?user_side, "draw_card_action");
-    verify!(
+    verify_turn(game, user_side)?;
+    verify_code!(
         flags::can_take_draw_card_action(game, user_side),
+        ErrorCode::IllegalAction,
         "Cannot draw card for {:?}",
         user_side
     );
@@ -147,17 +173,33 @@ fn play_card_action(
     target: CardTarget,
 ) -> Result<()> {
     info!(?user_side, ?card_id, ?target, "play_card_action");
-    verify!(
+    let reaction = flags::in_reaction_window(game, user_side);
+    if !reaction {
+        verify_turn(game, user_side)?;
+    }
+    verify_code!(
+        game.card(card_id).position() != CardPosition::Hand(user_side)
+            || !flags::enters_play_face_up(game, card_id)
+            || flags::can_pay_card_cost(game, card_id),
+        ErrorCode::InsufficientMana,
+        "Cannot pay for card {:?}",
+        card_id
+    );
+    verify_code!(
         flags::can_take_play_card_action(game, user_side, card_id, target),
+        ErrorCode::IllegalAction,
         "Cannot play card {:?}",
         card_id
     );
 
-    let card = game.card(card_id);
-    let definition = rules::get(card.name);
+    let definition = rules::card_definition(game, card_id);
     mutations::move_card(game, card_id, CardPosition::Played(user_side, target))?;
 
-    mutations::spend_action_points(game, user_side, definition.cost.actions)?;
+    if reaction {
+        mutations::close_reaction_window(game);
+    } else {
+        mutations::spend_action_points(game, user_side, definition.cost.actions)?;
+    }
 
     if flags::enters_play_face_up(game, card_id) {
         let amount = queries::mana_cost(game, card_id).with_error(|| "Card has no mana cost")?;
@@ -169,15 +211,24 @@ fn play_card_action(
         game.record_update(|| GameUpdate::PlayCardFaceUp(user_side, card_id));
     }
 
-    dispatch::invoke_event(game, CastCardEvent(CardPlayed { card_id, target }))?;
-    mutations::move_card(
-        game,
-        card_id,
-        queries::played_position(game, user_side, card_id, target)?,
-    )?;
+    game.add_history_event(HistoryEvent::PlayCard(user_side, card_id));
+    if definition.cost.variable_mana {
+        let available = mana::get(game, user_side, ManaPurpose::PayForCard(card_id));
+        game.player_mut(user_side).prompt = Some(GamePrompt::choose_x(card_id, target, available));
+        return Ok(());
+    }
 
-    mutations::check_end_turn(game)?;
-    Ok(())
+    if definition.mode_ids(card_id).next().is_some() {
+        game.player_mut(user_side).prompt =
+            Some(GamePrompt::choose_mode(definition.mode_ids(card_id), target));
+        return Ok(());
+    }
+
+    mutations::resolve_played_card(
+        game,
+        user_side,
+        CardPlayed { card_id, target, mode: None, x: None },
+    )
 }
 
 /// The basic game action to activate an ability of a card in play.
@@ -189,15 +240,24 @@ fn activate_ability_action(
     target: CardTarget,
 ) -> Result<()> {
     info!(?user_side, ?ability_id, "activate_ability_action");
-    verify!(
+    verify_turn(game, user_side)?;
+    verify_code!(
+        queries::ability_mana_cost(game, ability_id).is_none_or(
+            |cost| cost <= mana::get(game, user_side, ManaPurpose::ActivateAbility(ability_id))
+        ),
+        ErrorCode::InsufficientMana,
+        "Cannot pay for ability {:?}",
+        ability_id
+    );
+    verify_code!(
         flags::can_take_activate_ability_action(game, user_side, ability_id, target),
+        ErrorCode::IllegalAction,
         "Cannot activate ability {:?}",
         ability_id
     );
 
     game.ability_state.entry(ability_id).or_default().currently_resolving = true;
-    let card = game.card(ability_id.card_id);
-    let cost = match &rules::get(card.name).ability(ability_id.index).ability_type {
+    let cost = match &rules::ability_definition(game, ability_id).ability_type {
         AbilityType::Activated(cost, _) => cost,
         _ => fail!("Ability is not an activated ability"),
     };
@@ -210,6 +270,7 @@ fn activate_ability_action(
     if let Some(custom_cost) = &cost.custom_cost {
         (custom_cost.pay)(game, ability_id)?;
     }
+    mutations::record_ability_activation(game, ability_id, &cost.limit);
     game.record_update(|| GameUpdate::AbilityActivated(user_side, ability_id));
     dispatch::invoke_event(game, ActivateAbilityEvent(AbilityActivated { ability_id, target }))?;
 
@@ -223,8 +284,10 @@ fn activate_ability_action(
 #[instrument(skip(game))]
 fn gain_mana_action(game: &mut GameState, user_side: Side) -> Result<()> {
     info!(?user_side, "gain_mana_action");
-    verify!(
+    verify_turn(game, user_side)?;
+    verify_code!(
         flags::can_take_gain_mana_action(game, user_side),
+        ErrorCode::IllegalAction,
         "Cannot gain mana for {:?}",
         user_side
     );
@@ -236,8 +299,16 @@ fn gain_mana_action(game: &mut GameState, user_side: Side) -> Result<()> {
 
 fn level_up_room_action(game: &mut GameState, user_side: Side, room_id: RoomId) -> Result<()> {
     info!(?user_side, "level_up_room_action");
-    verify!(
+    verify_turn(game, user_side)?;
+    verify_code!(
+        mana::get(game, user_side, ManaPurpose::LevelUpRoom(room_id)) > 0,
+        ErrorCode::InsufficientMana,
+        "Cannot pay to level up room for {:?}",
+        user_side
+    );
+    verify_code!(
         flags::can_take_level_up_room_action(game, user_side, room_id),
+        ErrorCode::IllegalAction,
         "Cannot level up room for {:?}",
         user_side
     );
@@ -245,12 +316,19 @@ fn level_up_room_action(game: &mut GameState, user_side: Side, room_id: RoomId)
     mana::spend(game, user_side, ManaPurpose::LevelUpRoom(room_id), 1)?;
     game.record_update(|| GameUpdate::LevelUpRoom(room_id, InitiatedBy::GameAction));
     mutations::level_up_room(game, room_id)?;
+    mutations::check_reaction_window(game, ReactionTrigger::LevelUpRoom(room_id))?;
     mutations::check_end_turn(game)?;
     Ok(())
 }
 
 fn spend_action_point_action(game: &mut GameState, user_side: Side) -> Result<()> {
-    verify!(flags::in_main_phase(game, user_side), "Cannot spend action point for {:?}", user_side);
+    verify_turn(game, user_side)?;
+    verify_code!(
+        flags::in_main_phase(game, user_side),
+        ErrorCode::IllegalAction,
+        "Cannot spend action point for {:?}",
+        user_side
+    );
     mutations::spend_action_points(game, user_side, 1)?;
     mutations::check_end_turn(game)?;
     Ok(())
@@ -259,8 +337,9 @@ fn spend_action_point_action(game: &mut GameState, user_side: Side) -> Result<()
 /// Handles a [PromptAction] for the `user_side` player. Clears active prompts.
 fn handle_prompt_action(game: &mut GameState, user_side: Side, action: PromptAction) -> Result<()> {
     if let Some(prompt) = &game.player(user_side).prompt {
-        verify!(
+        verify_code!(
             prompt.responses.iter().any(|p| *p == action),
+            ErrorCode::IllegalAction,
             "Unexpected action {:?} received",
             action
         );
@@ -19,7 +19,10 @@ use std::iter;
 use anyhow::Result;
 use data::card_definition::{AbilityType, TargetRequirement};
 use data::game::{GamePhase, GameState, MulliganDecision};
-use data::game_actions::{CardTarget, CardTargetKind, GameAction, PromptAction};
+use data::game_actions::{
+    AccessPhaseAction, CardPromptAction, CardTarget, CardTargetKind, EncounterAction, GameAction,
+    PromptAction,
+};
 use data::primitives::{AbilityId, CardId, RoomId, Side};
 use rules::{flags, queries};
 use with_error::fail;
@@ -50,12 +53,20 @@ pub fn evaluate<'a>(
     }
 
     if let Some(prompt) = &game.player(side).prompt {
+        let reactions = flags::in_reaction_window(game, side)
+            .then(|| game.hand(side).flat_map(move |c| legal_card_actions(game, side, c.id)))
+            .into_iter()
+            .flatten();
         return Ok(Box::new(
-            prompt.responses.iter().map(|prompt| GameAction::PromptAction(*prompt)),
+            prompt
+                .responses
+                .iter()
+                .map(|prompt| GameAction::PromptAction(*prompt))
+                .chain(reactions),
         ));
     }
 
-    if let Some(actions) = raids::current_actions(game, side).expect("Current Actions") {
+    if let Some(actions) = raids::current_actions(game, side)? {
         return Ok(Box::new(actions.into_iter().map(GameAction::PromptAction)));
     }
 
@@ -72,6 +83,11 @@ pub fn evaluate<'a>(
                         .map(GameAction::LevelUpRoom),
                 )
                 .chain(game.hand(side).flat_map(move |c| legal_card_actions(game, side, c.id)))
+                .chain(
+                    game.all_cards()
+                        .filter(move |c| c.controller() == side && c.position().in_play())
+                        .flat_map(move |c| legal_card_actions(game, side, c.id)),
+                )
                 .chain(flags::can_take_draw_card_action(game, side).then_some(GameAction::DrawCard))
                 .chain(
                     flags::can_take_gain_mana_action(game, side).then_some(GameAction::GainMana),
@@ -82,6 +98,78 @@ pub fn evaluate<'a>(
     }
 }
 
+/// Equivalent to [evaluate], but annotates each action with a cheap heuristic
+/// prior weight from [prior], for use by e.g. rollout policies which prefer to
+/// sample promising actions.
+pub fn evaluate_with_priors<'a>(
+    game: &'a GameState,
+    side: Side,
+) -> Result<Box<dyn Iterator<Item = (GameAction, f64)> + 'a>> {
+    Ok(Box::new(evaluate(game, side)?.map(move |action| (action, prior(game, action)))))
+}
+
+/// Returns a positive heuristic weight for taking `action` in the given game,
+/// where higher values indicate actions which are more likely to be good.
+///
+/// This is intended to be fast to compute and does not simulate the action.
+pub fn prior(game: &GameState, action: GameAction) -> f64 {
+    match action {
+        GameAction::PromptAction(prompt) => match prompt {
+            PromptAction::MulliganDecision(MulliganDecision::Keep) => 2.0,
+            PromptAction::MulliganDecision(MulliganDecision::Mulligan) => 1.0,
+            PromptAction::EncounterAction(EncounterAction::UseWeaponAbility(..)) => 3.0,
+            PromptAction::EncounterAction(EncounterAction::UseCombinedWeapons(..)) => 3.0,
+            PromptAction::EncounterAction(EncounterAction::ActivateAbility(..)) => 1.5,
+            PromptAction::EncounterAction(EncounterAction::NoWeapon) => 1.0,
+            PromptAction::EncounterAction(EncounterAction::Retreat) => 0.1,
+            PromptAction::EncounterAction(EncounterAction::CardAction(card_action))
+            | PromptAction::CardAction(card_action) => card_action_prior(card_action),
+            PromptAction::AccessPhaseAction(AccessPhaseAction::ScoreCard(_)) => 10.0,
+            PromptAction::AccessPhaseAction(AccessPhaseAction::DestroyCard(..)) => 2.0,
+            PromptAction::AccessPhaseAction(AccessPhaseAction::EndRaid) => 1.0,
+        },
+        GameAction::Resign => 0.01,
+        GameAction::PlayCard(card_id, _) => {
+            if rules::card_definition(game, card_id).config.stats.scheme_points.is_some() {
+                4.0
+            } else {
+                3.0
+            }
+        }
+        GameAction::ActivateAbility(..) => 2.0,
+        GameAction::InitiateRaid(room_id) => {
+            // Raids which have been taxed by the Overlord are less appealing
+            let cost = queries::initiate_raid_cost(game, room_id);
+            let total = cost.actions.max(1) + cost.mana.unwrap_or_default();
+            2.0 / f64::from(total)
+        }
+        GameAction::LevelUpRoom(_) => 3.0,
+        GameAction::DrawCard => 1.5,
+        GameAction::GainMana => 1.0,
+        GameAction::SpendActionPoint => 0.1,
+    }
+}
+
+fn card_action_prior(action: CardPromptAction) -> f64 {
+    match action {
+        CardPromptAction::LoseMana(..) | CardPromptAction::LoseActions(..) => 2.0,
+        CardPromptAction::EndRaid => 1.0,
+        CardPromptAction::TakeDamage(..) => 1.0,
+        CardPromptAction::TakeDamageEndRaid(..) => 0.5,
+        CardPromptAction::TutorCard(..) => 1.0,
+        CardPromptAction::PayUpkeep(..) => 2.0,
+        CardPromptAction::DiscardProject(..) => 0.5,
+        CardPromptAction::UnveilProject(..) => 2.0,
+        CardPromptAction::EndUnveilWindow => 1.0,
+        CardPromptAction::ConfirmRetreat => 0.5,
+        CardPromptAction::CancelRetreat => 1.0,
+        CardPromptAction::DiscardToHandSize(..) => 1.0,
+        CardPromptAction::PassReaction => 1.0,
+        CardPromptAction::ChooseMode(..) => 1.0,
+        CardPromptAction::ChooseX(.., x) => 1.0 + f64::from(x),
+    }
+}
+
 /// Builds an iterator over all possible 'play card' and 'activate ability'
 /// actions for the provided card.
 fn legal_card_actions(
@@ -89,35 +177,13 @@ fn legal_card_actions(
     side: Side,
     card_id: CardId,
 ) -> impl Iterator<Item = GameAction> + '_ {
-    let target_kind = queries::card_target_kind(game, card_id);
-
-    // Iterator combining pattern suggested by *the* Niko Matsakis
-    // https://stackoverflow.com/a/52064434/298036
-    let play_in_room = if target_kind == CardTargetKind::Room {
-        Some(enum_iterator::all::<RoomId>().filter_map(move |room_id| {
-            if flags::can_take_play_card_action(game, side, card_id, CardTarget::Room(room_id)) {
-                Some(GameAction::PlayCard(card_id, CardTarget::Room(room_id)))
-            } else {
-                None
-            }
-        }))
-    } else {
-        None
-    };
-
-    let play_card = if target_kind == CardTargetKind::None
-        && flags::can_take_play_card_action(game, side, card_id, CardTarget::None)
-    {
-        Some(iter::once(GameAction::PlayCard(card_id, CardTarget::None)))
-    } else {
-        None
-    };
-
     let activated = rules::card_definition(game, card_id)
         .ability_ids(card_id)
         .flat_map(move |ability_id| legal_ability_actions(game, side, ability_id));
 
-    play_in_room.into_iter().flatten().chain(play_card.into_iter().flatten()).chain(activated)
+    card_targets(game, side, card_id)
+        .map(move |target| GameAction::PlayCard(card_id, target))
+        .chain(activated)
 }
 
 /// Builds an iterator over all possible 'activate ability' actions for the
@@ -127,35 +193,47 @@ fn legal_ability_actions(
     side: Side,
     ability_id: AbilityId,
 ) -> impl Iterator<Item = GameAction> + '_ {
-    let ability = rules::ability_definition(game, ability_id);
-    let mut activate = None;
-    let mut target_rooms = None;
-
-    if let AbilityType::Activated(_, targeting) = &ability.ability_type {
-        match targeting {
-            TargetRequirement::None => {
-                if flags::can_take_activate_ability_action(game, side, ability_id, CardTarget::None)
-                {
-                    activate =
-                        Some(iter::once(GameAction::ActivateAbility(ability_id, CardTarget::None)))
-                }
-            }
-            TargetRequirement::TargetRoom(_) => {
-                target_rooms = Some(enum_iterator::all::<RoomId>().filter_map(move |room_id| {
-                    if flags::can_take_activate_ability_action(
-                        game,
-                        side,
-                        ability_id,
-                        CardTarget::Room(room_id),
-                    ) {
-                        Some(GameAction::ActivateAbility(ability_id, CardTarget::Room(room_id)))
-                    } else {
-                        None
-                    }
-                }))
-            }
-        }
-    }
+    ability_targets(game, side, ability_id)
+        .map(move |target| GameAction::ActivateAbility(ability_id, target))
+}
 
-    activate.into_iter().flatten().chain(target_rooms.into_iter().flatten())
+/// Returns an iterator over the [CardTarget]s with which the `side` player can
+/// currently play the `card_id` card.
+pub fn card_targets(
+    game: &GameState,
+    side: Side,
+    card_id: CardId,
+) -> impl Iterator<Item = CardTarget> + '_ {
+    possible_targets(Some(queries::card_target_kind(game, card_id)))
+        .filter(move |target| flags::can_take_play_card_action(game, side, card_id, *target))
+}
+
+/// Returns an iterator over the [CardTarget]s with which the `side` player can
+/// currently activate the `ability_id` ability. Returns an empty iterator for
+/// non-activated abilities.
+pub fn ability_targets(
+    game: &GameState,
+    side: Side,
+    ability_id: AbilityId,
+) -> impl Iterator<Item = CardTarget> + '_ {
+    let kind = match &rules::ability_definition(game, ability_id).ability_type {
+        AbilityType::Activated(_, TargetRequirement::None) => Some(CardTargetKind::None),
+        AbilityType::Activated(_, TargetRequirement::TargetRoom(_)) => Some(CardTargetKind::Room),
+        _ => None,
+    };
+
+    possible_targets(kind).filter(move |target| {
+        flags::can_take_activate_ability_action(game, side, ability_id, *target)
+    })
+}
+
+/// Iterator over every [CardTarget] of the given [CardTargetKind], legal or
+/// not.
+fn possible_targets(kind: Option<CardTargetKind>) -> impl Iterator<Item = CardTarget> {
+    // Iterator combining pattern suggested by *the* Niko Matsakis
+    // https://stackoverflow.com/a/52064434/298036
+    let none = (kind == Some(CardTargetKind::None)).then_some(CardTarget::None);
+    let rooms = (kind == Some(CardTargetKind::Room))
+        .then(|| enum_iterator::all::<RoomId>().map(CardTarget::Room));
+    none.into_iter().chain(rooms.into_iter().flatten())
 }
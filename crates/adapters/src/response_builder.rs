@@ -14,15 +14,31 @@
 
 use std::collections::HashMap;
 
+use data::player_data::{AnimationSpeed, IconMode};
 use data::primitives::Side;
 use protos::spelldawn::game_command::Command;
 use protos::spelldawn::{
-    CardIdentifier, GameView, ObjectPosition, PlayerName, UpdateGameViewCommand,
+    CardIdentifier, GameView, Node, ObjectPosition, PlayerName, ShowToastCommand, TimeValue,
+    UpdateGameViewCommand,
 };
 
+/// Client positions of cards, keyed by card identifier
+pub type CardPositions = HashMap<CardIdentifier, ObjectPosition>;
+
 pub struct ResponseState {
     pub animate: bool,
     pub is_final_update: bool,
+    /// Palette to use for card icons, based on the user's settings
+    pub icon_mode: IconMode,
+    /// Speed at which animations should play, based on the user's settings
+    pub animation_speed: AnimationSpeed,
+    /// Whether to assign keyboard shortcuts to prompt responses, based on the
+    /// user's settings
+    pub show_hotkeys: bool,
+    /// Whether the user is watching this game without playing in it. Only
+    /// information which is public to both players is shown to spectators and
+    /// no game controls are rendered.
+    pub spectator: bool,
 }
 
 pub struct ResponseBuilder {
@@ -32,7 +48,7 @@ pub struct ResponseBuilder {
 
     /// Tracks the positions of client cards as of the most recently-seen
     /// snapshot. Can be used to customize animation behavior.
-    pub last_snapshot_positions: HashMap<CardIdentifier, ObjectPosition>,
+    pub last_snapshot_positions: CardPositions,
 }
 
 impl ResponseBuilder {
@@ -44,6 +60,15 @@ impl ResponseBuilder {
         self.commands.push(command);
     }
 
+    /// Displays `content` to the user as a transient notification for
+    /// `milliseconds`.
+    pub fn push_toast(&mut self, content: Option<Node>, milliseconds: u32) {
+        self.commands.push(Command::ShowToast(ShowToastCommand {
+            content,
+            duration: Some(TimeValue { milliseconds }),
+        }));
+    }
+
     pub fn push_game_view(&mut self, game: GameView) {
         for card in &game.cards {
             if let (Some(id), Some(position)) = (card.card_id, card.card_position.clone()) {
@@ -57,6 +82,12 @@ impl ResponseBuilder {
         }));
     }
 
+    /// Returns the duration of an animation which normally lasts for
+    /// `milliseconds`, adjusted for the user's animation speed.
+    pub fn duration(&self, milliseconds: u32) -> TimeValue {
+        TimeValue { milliseconds: self.state.animation_speed.scale(milliseconds) }
+    }
+
     pub fn to_player_name(&self, side: Side) -> i32 {
         if side == self.user_side {
             PlayerName::User as i32
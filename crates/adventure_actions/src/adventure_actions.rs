@@ -14,11 +14,23 @@
 
 //! Implements game rules for the 'adventure' deckbuilding/drafting game mode
 
+use adventure_generator::card_generator;
 use anyhow::Result;
-use data::adventure::{AdventureChoiceScreen, AdventureState, Coins, TileEntity, TilePosition};
+use data::adventure::{
+    AdventureChoiceScreen, AdventureState, Coins, EventOutcome, JournalEntry, TileEntity,
+    TilePosition,
+};
 use data::adventure_action::AdventureAction;
+use data::card_name::CardName;
 use with_error::{fail, verify};
 
+/// Stamina spent to explore a tile
+pub const EXPLORE_STAMINA_COST: u32 = 2;
+
+/// Stamina spent to take a tile action such as investigating a random event.
+/// Resting at a camp is free.
+pub const TILE_ACTION_STAMINA_COST: u32 = 1;
+
 /// Handles an incoming [AdventureAction] and produces a client response.
 pub fn handle_adventure_action(state: &mut AdventureState, action: &AdventureAction) -> Result<()> {
     match action {
@@ -28,6 +40,8 @@ pub fn handle_adventure_action(state: &mut AdventureState, action: &AdventureAct
         AdventureAction::DraftCard(index) => handle_draft(state, *index),
         AdventureAction::VisitShop(position) => handle_visit_shop(state, *position),
         AdventureAction::BuyCard(position, index) => handle_buy_card(state, *position, *index),
+        AdventureAction::TileAction(position) => handle_tile_action(state, *position),
+        AdventureAction::DismissEvent => handle_dismiss_event(state),
     }
 }
 
@@ -46,8 +60,12 @@ fn handle_explore(state: &mut AdventureState, position: TilePosition) -> Result<
     };
 
     spend_coins(state, cost)?;
+    spend_stamina(state, EXPLORE_STAMINA_COST)?;
     state.revealed_regions.insert(region);
+    state.reveal(position, adventure_generator::EXPLORE_REVEAL_RADIUS);
     state.tile_mut(position)?.entity = None;
+    state.journal.push(JournalEntry::Explored { position, cost });
+    check_exhausted(state);
 
     Ok(())
 }
@@ -62,6 +80,10 @@ fn handle_initiate_draft(state: &mut AdventureState, position: TilePosition) ->
     };
 
     spend_coins(state, cost)?;
+    let choices = card_generator::draft_choices(state);
+    if let TileEntity::Draft { data, .. } = state.tile_entity_mut(position)? {
+        *data = choices;
+    }
     state.choice_screen = Some(AdventureChoiceScreen::Draft(position));
 
     Ok(())
@@ -72,13 +94,16 @@ fn handle_draft(state: &mut AdventureState, index: usize) -> Result<()> {
         fail!("No active draft!");
     };
 
-    let TileEntity::Draft { data, ..} = state.tile_entity(*position)? else {
+    let TileEntity::Draft { data, .. } = state.tile_entity(*position)? else {
         fail!("Invalid draft position");
     };
 
     verify!(index < data.choices.len(), "Index out of bounds!");
     let choice = data.choices[index];
+    let offered_rare =
+        data.choices.iter().any(|c| card_generator::is_rare(rules::get(c.card).rarity));
 
+    state.draft_pity = if offered_rare { 0 } else { state.draft_pity + 1 };
     state
         .collection
         .entry(choice.card)
@@ -86,6 +111,7 @@ fn handle_draft(state: &mut AdventureState, index: usize) -> Result<()> {
         .or_insert(choice.quantity);
     state.tile_mut(*position)?.entity = None;
     state.choice_screen = None;
+    state.journal.push(JournalEntry::DraftedCard { card: choice.card, quantity: choice.quantity });
     Ok(())
 }
 
@@ -94,7 +120,10 @@ fn handle_visit_shop(state: &mut AdventureState, position: TilePosition) -> Resu
         fail!("Expected shop entity")
     };
 
-    data.visited = true;
+    if !data.visited {
+        data.visited = true;
+        state.journal.push(JournalEntry::VisitedShop(position));
+    }
     Ok(())
 }
 
@@ -114,22 +143,110 @@ fn handle_buy_card(state: &mut AdventureState, position: TilePosition, index: us
         .and_modify(|i| *i += choice.quantity)
         .or_insert(choice.quantity);
     spend_coins(state, choice.cost)?;
+    state.journal.push(JournalEntry::BoughtCard {
+        card: choice.card,
+        quantity: choice.quantity,
+        cost: choice.cost,
+    });
+
+    Ok(())
+}
+
+fn handle_tile_action(state: &mut AdventureState, position: TilePosition) -> Result<()> {
+    verify_no_mandatory_choice(state)?;
+    verify_revealed(state, position)?;
+
+    match state.tile_entity(position)? {
+        TileEntity::Event { .. } => handle_event(state, position),
+        TileEntity::Camp { stamina } => {
+            let stamina = *stamina;
+            state.stamina += stamina;
+            state.tile_mut(position)?.entity = None;
+            state.journal.push(JournalEntry::Rested { position, stamina });
+            Ok(())
+        }
+        _ => fail!("Expected tile action entity"),
+    }
+}
+
+fn handle_event(state: &mut AdventureState, position: TilePosition) -> Result<()> {
+    let TileEntity::Event { data } = state.tile_entity(position)? else {
+        fail!("Expected event entity")
+    };
+
+    let outcome = data.outcome;
+    spend_stamina(state, TILE_ACTION_STAMINA_COST)?;
+    let lost_card = match outcome {
+        EventOutcome::GainCoins(coins) => {
+            state.coins += coins;
+            None
+        }
+        EventOutcome::Ambush(coins) => {
+            state.coins -= coins.min(state.coins);
+            None
+        }
+        EventOutcome::LoseCard => lose_random_card(state),
+    };
 
+    if let TileEntity::Event { data } = state.tile_entity_mut(position)? {
+        data.lost_card = lost_card;
+    }
+    state.journal.push(JournalEntry::Event { position, outcome, lost_card });
+    state.choice_screen = Some(AdventureChoiceScreen::Event(position));
     Ok(())
 }
 
+fn handle_dismiss_event(state: &mut AdventureState) -> Result<()> {
+    let Some(AdventureChoiceScreen::Event(position)) = state.choice_screen else {
+        fail!("No active event!");
+    };
+
+    state.tile_mut(position)?.entity = None;
+    state.choice_screen = None;
+    check_exhausted(state);
+    Ok(())
+}
+
+/// Removes one copy of a random card from the adventure collection, returning
+/// the removed card if any.
+fn lose_random_card(state: &mut AdventureState) -> Option<CardName> {
+    // Sort for deterministic selection when the adventure has a seeded rng
+    let mut owned = state.collection.keys().copied().collect::<Vec<_>>();
+    owned.sort_by_key(|name| name.to_string());
+    let card = state.config.choose(owned.into_iter())?;
+    if let Some(count) = state.collection.get_mut(&card) {
+        *count -= 1;
+        if *count == 0 {
+            state.collection.remove(&card);
+        }
+    }
+    Some(card)
+}
+
 fn spend_coins(state: &mut AdventureState, coins: Coins) -> Result<()> {
     verify!(state.coins >= coins, "Insufficient coins available");
     state.coins -= coins;
     Ok(())
 }
 
+fn spend_stamina(state: &mut AdventureState, stamina: u32) -> Result<()> {
+    verify!(state.stamina >= stamina, "Insufficient stamina available");
+    state.stamina -= stamina;
+    Ok(())
+}
+
+/// Ends the adventure if the player has run out of stamina and is not
+/// currently looking at a choice screen.
+fn check_exhausted(state: &mut AdventureState) {
+    if state.stamina == 0 && state.choice_screen.is_none() {
+        state.journal.push(JournalEntry::Exhausted);
+        state.choice_screen = Some(AdventureChoiceScreen::AdventureOver);
+    }
+}
+
 /// Raise an error if the given [TilePosition] has not yet been explored
 fn verify_revealed(state: &AdventureState, position: TilePosition) -> Result<()> {
-    verify!(
-        state.revealed_regions.contains(&state.tile(position)?.region_id),
-        "Given tile position has not been revealed"
-    );
+    verify!(state.is_revealed(state.tile(position)?), "Given tile position has not been revealed");
     Ok(())
 }
 
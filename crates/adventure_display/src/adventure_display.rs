@@ -16,9 +16,13 @@
 
 pub mod adventure_over_panel;
 pub mod adventure_panels;
+pub mod camp_prompt_panel;
 pub mod draft_panel;
 pub mod draft_prompt_panel;
+pub mod event_panel;
+pub mod event_prompt_panel;
 pub mod explore_panel;
+pub mod journal_panel;
 pub mod shop_panel;
 pub mod shop_prompt_panel;
 
@@ -38,6 +42,7 @@ use with_error::fail;
 
 use crate::adventure_over_panel::AdventureOverPanel;
 use crate::draft_panel::DraftPanel;
+use crate::event_panel::EventPanel;
 
 /// Returns a sequence of game Commands to display the provided
 /// [AdventureState].
@@ -46,8 +51,14 @@ pub fn render(state: &AdventureState) -> Result<Vec<Command>> {
         tiles: state
             .tiles
             .iter()
-            .filter(|(_, tile)| state.revealed_regions.contains(&tile.region_id))
-            .map(|(position, state)| render_tile(*position, state))
+            .filter(|(_, tile)| state.is_visible(tile))
+            .map(|(position, tile)| {
+                if state.is_revealed(tile) {
+                    render_tile(*position, tile)
+                } else {
+                    render_clouded_tile(*position, tile)
+                }
+            })
             .collect(),
     })];
 
@@ -88,14 +99,22 @@ pub fn render_adventure_choice_screen(
                 address: PanelAddress::DraftCard,
             }
         }
+        AdventureChoiceScreen::Event(position) => {
+            let TileEntity::Event { data } = state.tile_entity(*position)? else {
+                fail!("Expected event at indicated position")
+            };
+
+            RenderedChoiceScreen {
+                panel: EventPanel { data }.build_panel(),
+                address: PanelAddress::AdventureEvent,
+            }
+        }
     })
 }
 
 fn render_tile(position: TilePosition, tile: &TileState) -> WorldMapTile {
     let mut sprites = vec![WorldMapSprite {
-        sprite_address: Some(SpriteAddress {
-            address: format!("DavidBaumgart/WorldTiles.spriteatlas[{}]", tile.sprite),
-        }),
+        sprite_address: Some(tile_sprite_address(tile)),
         color: None,
         anchor_offset: None,
         scale: None,
@@ -145,6 +164,26 @@ fn render_tile(position: TilePosition, tile: &TileState) -> WorldMapTile {
     }
 }
 
+/// Renders a tile which is still covered by fog of war. Only the darkened
+/// terrain is shown, and the tile cannot be visited.
+fn render_clouded_tile(position: TilePosition, tile: &TileState) -> WorldMapTile {
+    WorldMapTile {
+        sprites: vec![WorldMapSprite {
+            sprite_address: Some(tile_sprite_address(tile)),
+            color: Some(design::GRAY_700),
+            anchor_offset: None,
+            scale: None,
+        }],
+        position: Some(adapters::map_position(position)),
+        on_visit: None,
+        tile_type: MapTileType::Obstacle.into(),
+    }
+}
+
+fn tile_sprite_address(tile: &TileState) -> SpriteAddress {
+    SpriteAddress { address: format!("DavidBaumgart/WorldTiles.spriteatlas[{}]", tile.sprite) }
+}
+
 fn visit_action_for_entity(entity: &TileEntity, position: TilePosition) -> Panels {
     let result = match entity {
         TileEntity::Shop { data } if data.visited => Panels::open(PanelAddress::Shop(position)),
@@ -168,6 +207,12 @@ fn sprite_address_for_entity(entity: &TileEntity) -> SpriteAddress {
             TileEntity::Shop { .. } => {
                 "RainbowArt/CleanFlatIcon/png_128/icon/icon_architecture/icon_architecture_6.png"
             }
+            TileEntity::Event { .. } => {
+                "RainbowArt/CleanFlatIcon/png_128/icon/icon_app/icon_app_110.png"
+            }
+            TileEntity::Camp { .. } => {
+                "RainbowArt/CleanFlatIcon/png_128/icon/icon_architecture/icon_architecture_12.png"
+            }
         }
         .to_string(),
     }
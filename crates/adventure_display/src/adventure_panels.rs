@@ -21,7 +21,9 @@ use panel_address::{Panel, PanelAddress};
 use protos::spelldawn::InterfacePanel;
 use with_error::{fail, WithError};
 
+use crate::camp_prompt_panel::{CampPromptPanel, CAMP_IMAGE};
 use crate::draft_prompt_panel::DraftPromptPanel;
+use crate::event_prompt_panel::{EventPromptPanel, EVENT_IMAGE};
 use crate::explore_panel::ExplorePanel;
 use crate::shop_prompt_panel::ShopPromptPanel;
 
@@ -38,9 +40,9 @@ pub fn render_tile_prompt_panel(
 
     let tile = adventure.tiles.get(&position).with_error(|| "Tile not found")?;
     let Some(entity) = &tile.entity else {
-        // Entity does not exist, e.g. because it has been cleared after activation. This
-        // is fine, just render nothing.
-        return Ok(None)
+        // Entity does not exist, e.g. because it has been cleared after activation.
+        // This is fine, just render nothing.
+        return Ok(None);
     };
 
     Ok(match entity {
@@ -51,6 +53,11 @@ pub fn render_tile_prompt_panel(
             DraftPromptPanel { cost: *cost, address, position }.build_panel()
         }
         TileEntity::Shop { .. } => ShopPromptPanel { address, position }.build_panel(),
+        TileEntity::Event { .. } => EventPromptPanel { address, position }.build_panel(),
+        TileEntity::Camp { stamina } => {
+            CampPromptPanel { stamina: *stamina, current: adventure.stamina, address, position }
+                .build_panel()
+        }
     })
 }
 
@@ -72,11 +79,14 @@ pub fn render_tile_loading_panel(
             "TPR/EnvironmentsHQ/Castles, Towers & Keeps/Images/Store/SceneryStore_outside_1",
         )
         .build(),
+        TileEntity::Event { .. } => FullScreenLoading::new(EVENT_IMAGE).build(),
+        TileEntity::Camp { .. } => FullScreenLoading::new(CAMP_IMAGE).build(),
     };
 
     Ok(Some(InterfacePanel {
         address: Some(PanelAddress::TileLoading(position).into()),
         node,
         screen_overlay: None,
+        node_patches: None,
     }))
 }
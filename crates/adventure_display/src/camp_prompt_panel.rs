@@ -0,0 +1,65 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::button::{Button, ButtonType};
+use core_ui::panels::Panels;
+use core_ui::prelude::*;
+use core_ui::prompt_panel::PromptPanel;
+use core_ui::{actions, style};
+use data::adventure::TilePosition;
+use data::adventure_action::AdventureAction;
+use data::user_actions::UserAction;
+use panel_address::{Panel, PanelAddress};
+
+pub struct CampPromptPanel {
+    /// Stamina restored by resting at this camp
+    pub stamina: u32,
+    /// Player's current stamina
+    pub current: u32,
+    pub address: PanelAddress,
+    pub position: TilePosition,
+}
+
+impl Panel for CampPromptPanel {
+    fn address(&self) -> PanelAddress {
+        self.address
+    }
+}
+
+impl Component for CampPromptPanel {
+    fn build(self) -> Option<Node> {
+        PromptPanel::new()
+            .image(style::sprite(CAMP_IMAGE))
+            .prompt(format!(
+                "A warm hearth offers a place to rest. Stamina: {}, resting restores {}",
+                self.current, self.stamina
+            ))
+            .buttons(vec![
+                Button::new("Rest")
+                    .action(actions::close_and(
+                        self.address,
+                        UserAction::AdventureAction(AdventureAction::TileAction(self.position)),
+                    ))
+                    .layout(Layout::new().margin(Edge::All, 8.px())),
+                Button::new("Close")
+                    .button_type(ButtonType::Secondary)
+                    .action(Panels::close(self.address))
+                    .layout(Layout::new().margin(Edge::All, 8.px())),
+            ])
+            .build()
+    }
+}
+
+pub const CAMP_IMAGE: &str =
+    "TPR/EnvironmentsHQ/Castles, Towers & Keeps/Images/Library/SceneryLibrary_inside_1";
@@ -21,6 +21,7 @@ use core_ui::style;
 use core_ui::text::Text;
 use data::adventure::DraftData;
 use data::adventure_action::AdventureAction;
+use data::text::PreviewState;
 use deck_card::{CardHeight, DeckCard};
 use panel_address::{Panel, PanelAddress};
 use protos::spelldawn::FlexJustify;
@@ -45,6 +46,7 @@ impl<'a> Component for DraftPanel<'a> {
                         .style(Style::new().margin(Edge::All, 32.px()))
                         .child(
                             DeckCard::new(choice.card)
+                                .preview(PreviewState::example())
                                 .layout(Layout::new().margin(Edge::All, 8.px()))
                                 .height(CardHeight::vh(50.0)),
                         )
@@ -0,0 +1,85 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::button::Button;
+use core_ui::design::FontSize;
+use core_ui::full_screen_image::FullScreenImage;
+use core_ui::panels::Panels;
+use core_ui::prelude::*;
+use core_ui::style;
+use core_ui::text::Text;
+use data::adventure::{EventData, EventOutcome};
+use data::adventure_action::AdventureAction;
+use panel_address::{Panel, PanelAddress};
+use protos::spelldawn::{FlexAlign, FlexJustify};
+
+use crate::event_prompt_panel::EVENT_IMAGE;
+
+/// Displays the outcome of a random event after its effects have been applied
+pub struct EventPanel<'a> {
+    pub data: &'a EventData,
+}
+
+impl<'a> Panel for EventPanel<'a> {
+    fn address(&self) -> PanelAddress {
+        PanelAddress::AdventureEvent
+    }
+}
+
+impl<'a> Component for EventPanel<'a> {
+    fn build(self) -> Option<Node> {
+        FullScreenImage::new()
+            .image(style::sprite(EVENT_IMAGE))
+            .content(
+                Column::new("EventPanel")
+                    .style(
+                        Style::new()
+                            .flex_grow(1.0)
+                            .align_items(FlexAlign::Center)
+                            .justify_content(FlexJustify::Center),
+                    )
+                    .child(Text::new(self.description()).font_size(FontSize::Headline))
+                    .child(
+                        Button::new("Continue")
+                            .layout(Layout::new().margin(Edge::Top, 16.px()))
+                            .action(
+                                Panels::close(self.address()).action(AdventureAction::DismissEvent),
+                            ),
+                    ),
+            )
+            .build()
+    }
+}
+
+impl<'a> EventPanel<'a> {
+    fn description(&self) -> String {
+        match self.data.outcome {
+            EventOutcome::GainCoins(coins) => {
+                format!("You discover an abandoned cache containing {} coins", coins.0)
+            }
+            EventOutcome::Ambush(coins) => {
+                format!(
+                    "Bandits leap from the shadows! You escape, but lose up to {} coins",
+                    coins.0
+                )
+            }
+            EventOutcome::LoseCard => match self.data.lost_card {
+                Some(card) => {
+                    format!("A thief slips away with your copy of {}", card.displayed_name())
+                }
+                None => "A thief searches your pack, but finds nothing of value".to_string(),
+            },
+        }
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Displays a chronological list of the events which have occurred during the
+//! current adventure
+
+use core_ui::button::Button;
+use core_ui::design::FontSize;
+use core_ui::panel_window::PanelWindow;
+use core_ui::panels;
+use core_ui::prelude::*;
+use core_ui::scroll_view::ScrollView;
+use core_ui::text::Text;
+use data::adventure::{AdventureState, EventOutcome, JournalEntry};
+use data::leaderboard::RunSeed;
+use panel_address::{Panel, PanelAddress};
+use protos::spelldawn::{FlexAlign, FlexJustify};
+
+pub struct AdventureJournalPanel<'a> {
+    pub adventure: &'a AdventureState,
+}
+
+impl<'a> Panel for AdventureJournalPanel<'a> {
+    fn address(&self) -> PanelAddress {
+        PanelAddress::AdventureJournal
+    }
+}
+
+impl<'a> Component for AdventureJournalPanel<'a> {
+    fn build(self) -> Option<Node> {
+        PanelWindow::new(self.address(), 800.px(), 600.px())
+            .title("Journal")
+            .show_close_button(true)
+            .content(
+                Column::new("Journal")
+                    .style(Style::new().width(100.pct()))
+                    .child(
+                        Text::new(format!("Stamina: {}", self.adventure.stamina))
+                            .font_size(FontSize::Body),
+                    )
+                    .child(self.adventure.config.seed.map(seed_row))
+                    .child(
+                        ScrollView::new("JournalScroll")
+                            .style(
+                                Style::new()
+                                    .width(100.pct())
+                                    .height(400.px())
+                                    .align_items(FlexAlign::FlexStart),
+                            )
+                            .child(if self.adventure.journal.is_empty() {
+                                Some(
+                                    Text::new("Nothing has happened yet").font_size(FontSize::Body),
+                                )
+                            } else {
+                                None
+                            })
+                            .children(self.adventure.journal.iter().enumerate().map(
+                                |(i, entry)| {
+                                    Text::new(format!("{}. {}", i + 1, describe(entry)))
+                                        .font_size(FontSize::Body)
+                                },
+                            )),
+                    ),
+            )
+            .build()
+    }
+}
+
+/// Displays the seed of a seeded run so it can be shared with other players
+fn seed_row(seed: u64) -> impl Component {
+    Row::new("Seed")
+        .style(
+            Style::new()
+                .width(100.pct())
+                .justify_content(FlexJustify::SpaceBetween)
+                .align_items(FlexAlign::Center),
+        )
+        .child(Text::new(format!("Seed: {seed}")).font_size(FontSize::Body))
+        .child(
+            Button::new("Leaderboard")
+                .action(panels::open(PanelAddress::Leaderboard(RunSeed::Custom(seed)))),
+        )
+}
+
+/// Returns a human-readable description of a [JournalEntry].
+pub fn describe(entry: &JournalEntry) -> String {
+    match entry {
+        JournalEntry::Explored { position, cost } => {
+            format!("Explored ({}, {}) for {} coins", position.x, position.y, cost.0)
+        }
+        JournalEntry::VisitedShop(position) => {
+            format!("Visited the shop at ({}, {})", position.x, position.y)
+        }
+        JournalEntry::DraftedCard { card, quantity } => {
+            format!("Drafted {}x {}", quantity, card.displayed_name())
+        }
+        JournalEntry::BoughtCard { card, quantity, cost } => {
+            format!("Bought {}x {} for {} coins", quantity, card.displayed_name(), cost.0)
+        }
+        JournalEntry::BattleWon(_) => "Won a battle".to_string(),
+        JournalEntry::CoinsEarned(coins) => format!("Earned {} coins", coins.0),
+        JournalEntry::Event { outcome, lost_card, .. } => match (outcome, lost_card) {
+            (EventOutcome::GainCoins(coins), _) => format!("Found {} coins in a cache", coins.0),
+            (EventOutcome::Ambush(coins), _) => {
+                format!("Ambushed by bandits, losing up to {} coins", coins.0)
+            }
+            (EventOutcome::LoseCard, Some(card)) => {
+                format!("Lost {} to a thief", card.displayed_name())
+            }
+            (EventOutcome::LoseCard, None) => "Escaped a thief empty-handed".to_string(),
+        },
+        JournalEntry::Rested { position, stamina } => {
+            format!("Rested at ({}, {}), restoring {} stamina", position.x, position.y, stamina)
+        }
+        JournalEntry::Exhausted => "Ran out of stamina".to_string(),
+    }
+}
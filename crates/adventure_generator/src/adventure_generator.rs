@@ -15,12 +15,14 @@
 //! Generates world maps for the 'adventure' game mode
 
 pub mod card_generator;
+pub mod event_generator;
 
 use std::collections::{HashMap, HashSet};
 
 use cards::decklists;
 use data::adventure::{
-    AdventureConfiguration, AdventureState, Coins, RegionId, TileEntity, TilePosition, TileState,
+    AdventureConfiguration, AdventureState, Coins, DraftData, RegionId, TileEntity, TilePosition,
+    TileState, STARTING_STAMINA,
 };
 
 const TOP_LEFT: u8 = 0b00100000;
@@ -32,6 +34,17 @@ const LEFT: u8 = 0b00000001;
 
 pub const STARTING_COINS: Coins = Coins(500);
 
+/// Tiles within this many steps of the map origin are revealed at the start of
+/// an adventure.
+pub const STARTING_REVEAL_RADIUS: u32 = 4;
+
+/// Tiles within this many steps of an explored tile are revealed by the
+/// 'explore' action.
+pub const EXPLORE_REVEAL_RADIUS: u32 = 3;
+
+/// Stamina restored by resting at a camp
+pub const CAMP_STAMINA: u32 = 4;
+
 /// Builds a new random 'adventure' mode world map
 pub fn new_adventure(mut config: AdventureConfiguration) -> AdventureState {
     let mut tiles = HashMap::new();
@@ -41,7 +54,7 @@ pub fn new_adventure(mut config: AdventureConfiguration) -> AdventureState {
     add_tile(&mut tiles, -1, 2, "hexPlainsCold03");
     add_tile(&mut tiles, 0, 2, "hexMarsh00");
     add_tile(&mut tiles, 1, 2, "hexPlainsHalflingVillage00");
-    add_tile(&mut tiles, 2, 2, "hexDirtInn00");
+    add_with_entity(&mut tiles, 2, 2, "hexDirtInn00", TileEntity::Camp { stamina: CAMP_STAMINA });
     add_with_road_and_entity(
         &mut tiles,
         3,
@@ -76,7 +89,7 @@ pub fn new_adventure(mut config: AdventureConfiguration) -> AdventureState {
         0,
         -1,
         "hexMountain03",
-        TileEntity::Draft { cost: Coins(25), data: card_generator::draft_choices(&mut config) },
+        TileEntity::Draft { cost: Coins(25), data: DraftData::default() },
     );
     add_tile(&mut tiles, 1, -1, "hexPlainsFarm00");
     add_with_road(&mut tiles, 2, -1, "hexPlains00", road(TOP_LEFT | BOTTOM_RIGHT, 0));
@@ -91,7 +104,13 @@ pub fn new_adventure(mut config: AdventureConfiguration) -> AdventureState {
     );
     add_tile(&mut tiles, -1, -2, "hexSwamp00");
     add_tile(&mut tiles, 0, -2, "hexSwamp03");
-    add_tile(&mut tiles, 1, -2, "hexForestBroadleaf00");
+    add_with_entity(
+        &mut tiles,
+        1,
+        -2,
+        "hexForestBroadleaf00",
+        TileEntity::Event { data: event_generator::event_data(&mut config) },
+    );
     add_tile(&mut tiles, 2, -2, "hexHills02");
     add_with_road(&mut tiles, 3, -2, "hexPlains00", road(TOP_LEFT | BOTTOM_RIGHT, 1));
     add_tile(&mut tiles, 4, -2, "hexJungle00");
@@ -103,16 +122,21 @@ pub fn new_adventure(mut config: AdventureConfiguration) -> AdventureState {
     let side = config.side;
     let player_id = config.player_id;
 
-    AdventureState {
+    let mut result = AdventureState {
         side,
         choice_screen: None,
         coins: STARTING_COINS,
+        stamina: STARTING_STAMINA,
         tiles,
         revealed_regions,
         config,
         deck: decklists::canonical_deck(player_id, side),
         collection: HashMap::new(),
-    }
+        draft_pity: 0,
+        journal: vec![],
+    };
+    result.reveal(TilePosition::new(0, 0), STARTING_REVEAL_RADIUS);
+    result
 }
 
 fn hidden_tiles(region_id: RegionId) -> HashMap<TilePosition, TileState> {
@@ -12,28 +12,112 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use data::adventure::{AdventureConfiguration, CardChoice, Coins, DraftData, ShopData};
+use data::adventure::{
+    AdventureConfiguration, AdventureState, CardChoice, Coins, DraftData, ShopData,
+};
 use data::card_name::CardName;
-use data::primitives::{Rarity, Side};
+use data::draft::{DraftPlayer, DraftState, DRAFT_PICKS, PACK_SIZE, PICKS_PER_PACK};
+use data::player_name::PlayerId;
+use data::primitives::{GameId, Rarity, School, Side};
 use data::set_name::SetName;
 
-/// Generates options for drafting a card during an adventure
-pub fn draft_choices(config: &mut AdventureConfiguration) -> DraftData {
+/// Number of cards offered in each draft
+const DRAFT_CHOICE_COUNT: usize = 3;
+
+/// A card of [Rarity::Rare] or better is guaranteed to be offered at least once
+/// within this many consecutive drafts.
+pub const DRAFT_PITY_THRESHOLD: u32 = 4;
+
+/// Relative likelihood of each rarity being selected for a draft choice.
+const RARITY_WEIGHTS: [(Rarity, u32); 4] =
+    [(Rarity::Common, 70), (Rarity::Rare, 22), (Rarity::Exalted, 6), (Rarity::Epic, 2)];
+
+/// Generates options for drafting a card during an adventure.
+///
+/// Each choice is assigned a rarity via [RARITY_WEIGHTS], falling back to
+/// common cards if no card of the selected rarity is available. If the player
+/// has gone [DRAFT_PITY_THRESHOLD] - 1 drafts without being offered a rare
+/// card, one choice is replaced with a rare card if possible.
+pub fn draft_choices(state: &mut AdventureState) -> DraftData {
+    let config = &mut state.config;
+    let mut choices: Vec<CardName> = vec![];
+    for _ in 0..DRAFT_CHOICE_COUNT {
+        let rarity = config.choose_weighted(&RARITY_WEIGHTS, |(_, weight)| *weight).map(|r| r.0);
+        let selected = rarity
+            .and_then(|rarity| choose_card(config, &choices, |r| r == rarity))
+            .or_else(|| choose_card(config, &choices, |r| r == Rarity::Common));
+        choices.extend(selected);
+    }
+
+    let has_rare = choices.iter().any(|name| is_rare(rules::get(*name).rarity));
+    if !has_rare && state.draft_pity + 1 >= DRAFT_PITY_THRESHOLD && !choices.is_empty() {
+        if let Some(rare) = choose_card(config, &choices, is_rare) {
+            choices[0] = rare;
+        }
+    }
+
     DraftData {
-        choices: config
-            .choose_multiple(3, common_cards(config.side))
+        choices: choices
             .into_iter()
             .map(|name| CardChoice { quantity: 1, card: name, cost: Coins(0), sold: false })
             .collect(),
     }
 }
 
+/// Generates a pack of [PACK_SIZE] distinct cards for the standalone draft game
+/// mode. Rarities are selected via [RARITY_WEIGHTS], drawing from the same card
+/// pool as adventure drafts.
+pub fn draft_pack(config: &mut AdventureConfiguration) -> Vec<CardName> {
+    let mut pack: Vec<CardName> = vec![];
+    for _ in 0..PACK_SIZE {
+        let rarity = config.choose_weighted(&RARITY_WEIGHTS, |(_, weight)| *weight).map(|r| r.0);
+        let selected = rarity
+            .and_then(|rarity| choose_card(config, &pack, |r| r == rarity))
+            .or_else(|| choose_card(config, &pack, |r| r == Rarity::Common));
+        pack.extend(selected);
+    }
+    pack
+}
+
+/// Creates a new [DraftState] between two players and generates each player's
+/// first pack. The Overlord player picks first.
+pub fn new_draft(id: GameId, overlord: PlayerId, champion: PlayerId) -> DraftState {
+    let new_player = |player_id: PlayerId, side: Side| {
+        let mut config = AdventureConfiguration::new(player_id, side);
+        DraftPlayer { id: player_id, pack: draft_pack(&mut config), picks: vec![], config }
+    };
+    DraftState {
+        id,
+        overlord: new_player(overlord, Side::Overlord),
+        champion: new_player(champion, Side::Champion),
+        active_side: Side::Overlord,
+    }
+}
+
+/// Replaces the `side` player's pack with a newly-generated one once they have
+/// made [PICKS_PER_PACK] picks from it, or empties it once they have made all
+/// of their picks.
+pub fn refresh_pack(draft: &mut DraftState, side: Side) {
+    let player = draft.player_mut(side);
+    if player.picks.len() >= DRAFT_PICKS {
+        player.pack.clear();
+    } else if player.picks.len().is_multiple_of(PICKS_PER_PACK) {
+        player.pack = draft_pack(&mut player.config);
+    }
+}
+
+/// Returns true if the provided [Rarity] is Rare or better, for the purpose of
+/// tracking [AdventureState::draft_pity].
+pub fn is_rare(rarity: Rarity) -> bool {
+    matches!(rarity, Rarity::Rare | Rarity::Exalted | Rarity::Epic)
+}
+
 /// Generates options for buying from a shop during an adventure
 pub fn shop_options(config: &mut AdventureConfiguration) -> ShopData {
     ShopData {
         visited: false,
         choices: config
-            .choose_multiple(5, common_cards(config.side))
+            .choose_multiple(5, card_pool(config, |rarity| rarity == Rarity::Common))
             .into_iter()
             .map(|name| CardChoice {
                 quantity: config.gen_range(1..=3),
@@ -45,12 +129,33 @@ pub fn shop_options(config: &mut AdventureConfiguration) -> ShopData {
     }
 }
 
-fn common_cards(side: Side) -> impl Iterator<Item = CardName> {
+/// Randomly selects a card matching `rarity` from the [card_pool] for this
+/// adventure which is not already present in `existing`.
+fn choose_card(
+    config: &mut AdventureConfiguration,
+    existing: &[CardName],
+    rarity: impl Fn(Rarity) -> bool,
+) -> Option<CardName> {
+    let pool =
+        card_pool(config, rarity).filter(|name| !existing.contains(name)).collect::<Vec<_>>();
+    config.choose(pool.into_iter())
+}
+
+/// Cards which can be offered as rewards in this adventure: cards in the
+/// current set for the adventure's side and school with a rarity matching the
+/// `rarity` predicate.
+fn card_pool(
+    config: &AdventureConfiguration,
+    rarity: impl Fn(Rarity) -> bool,
+) -> impl Iterator<Item = CardName> {
+    let (side, school) = (config.side, config.school);
     rules::all_cards()
         .filter(move |definition| {
             definition.sets.contains(&SetName::Core2024)
-                && definition.rarity == Rarity::Common
+                && rarity(definition.rarity)
                 && definition.side == side
+                && school
+                    .is_none_or(|s| definition.school == s || definition.school == School::Neutral)
         })
         .map(|definition| definition.name)
 }
@@ -0,0 +1,33 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Generates random events for adventure map tiles
+
+use data::adventure::{AdventureConfiguration, Coins, EventData, EventOutcome};
+
+/// Possible random event outcomes along with the relative likelihood of each
+/// outcome being selected.
+const OUTCOME_WEIGHTS: [(EventOutcome, u32); 3] = [
+    (EventOutcome::GainCoins(Coins(75)), 50),
+    (EventOutcome::Ambush(Coins(50)), 30),
+    (EventOutcome::LoseCard, 20),
+];
+
+/// Selects an outcome for a random event tile via [OUTCOME_WEIGHTS].
+pub fn event_data(config: &mut AdventureConfiguration) -> EventData {
+    let outcome = config
+        .choose_weighted(&OUTCOME_WEIGHTS, |(_, weight)| *weight)
+        .map_or(OUTCOME_WEIGHTS[0].0, |(outcome, _)| *outcome);
+    EventData { outcome, lost_card: None }
+}
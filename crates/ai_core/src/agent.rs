@@ -59,6 +59,28 @@ where
     /// state. Should attempt to return a result before the [AgentConfig]'s
     /// `deadline`.
     fn pick_action(&self, config: AgentConfig, node: &TNode) -> Result<TNode::Action>;
+
+    /// Equivalent to [Self::pick_action], but additionally reports the
+    /// candidate actions this agent considered. By default no candidates are
+    /// reported.
+    fn explain_action(
+        &self,
+        config: AgentConfig,
+        node: &TNode,
+    ) -> Result<Explanation<TNode::Action>> {
+        Ok(Explanation { chosen: self.pick_action(config, node)?, candidates: vec![] })
+    }
+}
+
+/// Describes how an agent selected an action, for use in tuning agent
+/// behavior. See [Agent::explain_action].
+#[derive(Debug, Clone)]
+pub struct Explanation<TAction> {
+    /// The action the agent selected.
+    pub chosen: TAction,
+    /// Actions the agent considered along with their scores, where higher
+    /// scores are better for the acting player.
+    pub candidates: Vec<(TAction, f64)>,
 }
 
 /// A tuple of various pieces needed to perform agent action selection.
@@ -130,4 +152,25 @@ where
         let node = (self.combiner)(node, self.predictor, &self.evaluator)?;
         self.selector.pick_action(deadline, &node, &self.evaluator, player)
     }
+
+    /// Scores each candidate action by applying it and running this agent's
+    /// [StateEvaluator] on the resulting game state.
+    fn explain_action(
+        &self,
+        config: AgentConfig,
+        node: &TNode,
+    ) -> Result<Explanation<TNode::Action>> {
+        let chosen = self.pick_action(config, node)?;
+        let player = node.current_turn()?;
+        let node = (self.combiner)(node, self.predictor, &self.evaluator)?;
+        let candidates = node
+            .legal_actions(player)?
+            .map(|action| {
+                let mut child = node.make_copy();
+                child.execute_action(player, action)?;
+                Ok((action, f64::from(self.evaluator.evaluate(&child, player)?)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Explanation { chosen, candidates })
+    }
 }
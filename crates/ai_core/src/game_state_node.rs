@@ -66,6 +66,16 @@ pub trait GameStateNode {
         player: Self::PlayerName,
     ) -> Result<Box<dyn Iterator<Item = Self::Action> + 'a>>;
 
+    /// Equivalent to [Self::legal_actions], but annotates each action with a
+    /// positive heuristic prior weight, where higher values indicate more
+    /// promising actions. By default all actions are weighted equally.
+    fn legal_actions_with_priors<'a>(
+        &'a self,
+        player: Self::PlayerName,
+    ) -> Result<Box<dyn Iterator<Item = (Self::Action, f64)> + 'a>> {
+        Ok(Box::new(self.legal_actions(player)?.map(|action| (action, 1.0))))
+    }
+
     /// Apply the result of a given action to this game state.
     fn execute_action(&mut self, player: Self::PlayerName, action: Self::Action) -> Result<()>;
 }
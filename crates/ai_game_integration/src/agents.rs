@@ -14,19 +14,25 @@
 
 use ai_core::agent::{Agent, AgentConfig, AgentData};
 use ai_core::compound_evaluator::CompoundEvaluator;
-use ai_monte_carlo::monte_carlo::{MonteCarloAlgorithm, RandomPlayoutEvaluator};
+use ai_monte_carlo::monte_carlo::{
+    MonteCarloAlgorithm, PriorPlayoutEvaluator, RandomPlayoutEvaluator,
+};
 use ai_monte_carlo::uct1::Uct1;
 use ai_tree_search::alpha_beta::AlphaBetaAlgorithm;
 use ai_tree_search::minimax::MinimaxAlgorithm;
 use anyhow::Result;
+use data::agent_definition::AgentExplanation;
 use data::game_actions::GameAction;
 use data::player_name::NamedPlayer;
+use data::primitives::Side;
+use tracing::{info, instrument};
 use with_error::fail;
 
 use crate::evaluators::{
     CardsInHandEvaluator, CardsInPlayEvaluator, LevelCountersEvaluator, ManaDifferenceEvaluator,
-    ScoreEvaluator,
+    ScoreEvaluator, WeaponDurabilityEvaluator,
 };
+use crate::scripted_agent::ScriptedAgent;
 use crate::state_node::SpelldawnState;
 
 pub fn get(name: NamedPlayer) -> Box<dyn Agent<SpelldawnState>> {
@@ -52,6 +58,7 @@ pub fn get(name: NamedPlayer) -> Box<dyn Agent<SpelldawnState>> {
                     (5, Box::new(CardsInHandEvaluator {})),
                     (15, Box::new(CardsInPlayEvaluator {})),
                     (20, Box::new(LevelCountersEvaluator {})),
+                    (5, Box::new(WeaponDurabilityEvaluator {})),
                 ],
             },
         )),
@@ -60,9 +67,51 @@ pub fn get(name: NamedPlayer) -> Box<dyn Agent<SpelldawnState>> {
             MonteCarloAlgorithm { child_score_algorithm: Uct1 {} },
             RandomPlayoutEvaluator {},
         )),
+        NamedPlayer::TestUct1Priors => Box::new(AgentData::omniscient(
+            "UCT1_PRIORS",
+            MonteCarloAlgorithm { child_score_algorithm: Uct1 {} },
+            PriorPlayoutEvaluator {},
+        )),
+        NamedPlayer::AdventureBossWarden | NamedPlayer::AdventureBossRaider => {
+            Box::new(ScriptedAgent {
+                name: "SCRIPTED",
+                rules: name.script().expect("Decision table for scripted agent"),
+            })
+        }
     }
 }
 
+/// Selects an action for `side` using `agent`.
+///
+/// If [data::game::GameConfiguration::explain_agents] is enabled for this game,
+/// additionally records an [AgentExplanation] describing the actions the agent
+/// considered in the game's `agent_log`.
+#[instrument(skip_all, fields(game_id = ?game.id, ?side, agent = agent.name()))]
+pub fn pick_action(
+    agent: &dyn Agent<SpelldawnState>,
+    config: AgentConfig,
+    game: &mut SpelldawnState,
+    side: Side,
+) -> Result<GameAction> {
+    if !game.data.config.explain_agents {
+        let action = agent.pick_action(config, game)?;
+        info!(?action, "agent_picked_action");
+        return Ok(action);
+    }
+
+    let explanation = agent.explain_action(config, game)?;
+    let turn = game.data.turn.turn_number;
+    game.agent_log.push(AgentExplanation {
+        turn,
+        side,
+        agent: agent.name().to_string(),
+        chosen: explanation.chosen,
+        candidates: explanation.candidates,
+    });
+    info!(action = ?explanation.chosen, "agent_picked_action");
+    Ok(explanation.chosen)
+}
+
 pub struct NoActionAgent {}
 
 impl Agent<SpelldawnState> for NoActionAgent {
@@ -15,8 +15,8 @@
 use ai_core::state_evaluator::StateEvaluator;
 use anyhow::Result;
 use data::primitives::Side;
-use rules::mana;
 use rules::mana::ManaPurpose;
+use rules::{mana, queries};
 
 use crate::state_node::SpelldawnState;
 
@@ -69,3 +69,22 @@ impl StateEvaluator<SpelldawnState> for LevelCountersEvaluator {
             .sum::<u32>() as i32)
     }
 }
+
+/// Values the remaining uses of the Champion's weapons with limited durability,
+/// so that agents avoid spending durability when it is not needed.
+pub struct WeaponDurabilityEvaluator {}
+
+impl StateEvaluator<SpelldawnState> for WeaponDurabilityEvaluator {
+    fn evaluate(&self, game: &SpelldawnState, side: Side) -> Result<i32> {
+        if side == Side::Overlord {
+            return Ok(0);
+        }
+
+        Ok(game
+            .cards(side)
+            .iter()
+            .filter(|c| c.position().in_play())
+            .filter_map(|c| queries::remaining_durability(game, c.id))
+            .sum::<u32>() as i32)
+    }
+}
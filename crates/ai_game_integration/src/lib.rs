@@ -16,4 +16,5 @@
 
 pub mod agents;
 pub mod evaluators;
+pub mod scripted_agent;
 pub mod state_node;
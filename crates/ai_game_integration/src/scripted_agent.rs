@@ -0,0 +1,149 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An agent which follows a fixed decision table instead of searching, used to
+//! give adventure bosses distinct personalities.
+
+use std::cmp::Ordering;
+
+use actions::legal_actions;
+use ai_core::agent::{Agent, AgentConfig, Explanation};
+use ai_core::game_state_node::{GameStateNode, GameStatus};
+use anyhow::Result;
+use data::agent_definition::{ScriptCondition, ScriptRule, ScriptedAction};
+use data::game::GameState;
+use data::game_actions::GameAction;
+use data::primitives::{CardId, CardType, RoomId, Side};
+use rules::mana::ManaPurpose;
+use rules::{mana, queries};
+use with_error::{fail, WithError};
+
+use crate::state_node::SpelldawnState;
+
+/// Agent which takes the action selected by the first applicable [ScriptRule]
+/// in its decision table.
+///
+/// When no rule applies (including for all prompt responses, e.g. during
+/// raids), picks the legal action with the highest [legal_actions::prior].
+pub struct ScriptedAgent {
+    pub name: &'static str,
+    pub rules: &'static [ScriptRule],
+}
+
+impl Agent<SpelldawnState> for ScriptedAgent {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn pick_action(&self, _: AgentConfig, node: &SpelldawnState) -> Result<GameAction> {
+        let side = match node.status() {
+            GameStatus::InProgress { current_turn } => current_turn,
+            _ => fail!("Game is over"),
+        };
+        let legal = legal_actions::evaluate_with_priors(node, side)?.collect::<Vec<_>>();
+
+        for rule in self.rules {
+            if condition_met(node, side, rule.condition) {
+                if let Some(action) = select_action(node, rule.action, &legal) {
+                    return Ok(action);
+                }
+            }
+        }
+
+        legal
+            .into_iter()
+            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+            .map(|(action, _)| action)
+            .with_error(|| "No legal actions")
+    }
+
+    /// Reports each legal action along with its [legal_actions::prior].
+    fn explain_action(
+        &self,
+        config: AgentConfig,
+        node: &SpelldawnState,
+    ) -> Result<Explanation<GameAction>> {
+        let chosen = self.pick_action(config, node)?;
+        let side = node.current_turn()?;
+        let candidates = legal_actions::evaluate_with_priors(node, side)?.collect();
+        Ok(Explanation { chosen, candidates })
+    }
+}
+
+fn condition_met(game: &GameState, side: Side, condition: ScriptCondition) -> bool {
+    match condition {
+        ScriptCondition::Always => true,
+        ScriptCondition::ManaAtLeast(mana) => {
+            mana::get(game, side, ManaPurpose::AllSources) >= mana
+        }
+        ScriptCondition::ActionsAtLeast(actions) => game.player(side).actions >= actions,
+        ScriptCondition::HandSizeAtLeast(size) => game.hand(side).count() >= size,
+    }
+}
+
+/// Returns the legal action matching `scripted`, if any.
+fn select_action(
+    game: &GameState,
+    scripted: ScriptedAction,
+    legal: &[(GameAction, f64)],
+) -> Option<GameAction> {
+    let mut actions = legal.iter().map(|(action, _)| *action);
+    match scripted {
+        ScriptedAction::PlayCheapest(card_type) => playable(legal, game, card_type)
+            .min_by_key(|(card_id, _)| mana_cost(game, *card_id))
+            .map(|(_, action)| action),
+        ScriptedAction::PlayMostExpensive(card_type) => playable(legal, game, card_type)
+            .rev()
+            .max_by_key(|(card_id, _)| mana_cost(game, *card_id))
+            .map(|(_, action)| action),
+        ScriptedAction::Raid(room_id) => {
+            actions.find(|action| *action == GameAction::InitiateRaid(room_id))
+        }
+        ScriptedAction::LevelUpRoom => actions
+            .filter_map(|action| match action {
+                GameAction::LevelUpRoom(room_id) => Some(room_id),
+                _ => None,
+            })
+            .rev()
+            .max_by_key(|room_id| room_level(game, *room_id))
+            .map(GameAction::LevelUpRoom),
+        ScriptedAction::DrawCard => actions.find(|action| *action == GameAction::DrawCard),
+        ScriptedAction::GainMana => actions.find(|action| *action == GameAction::GainMana),
+    }
+}
+
+/// Legal [GameAction::PlayCard] actions for cards of the given type, along
+/// with the card being played.
+fn playable<'a>(
+    legal: &'a [(GameAction, f64)],
+    game: &'a GameState,
+    card_type: CardType,
+) -> impl DoubleEndedIterator<Item = (CardId, GameAction)> + 'a {
+    legal.iter().filter_map(move |(action, _)| match action {
+        GameAction::PlayCard(card_id, _)
+            if rules::card_definition(game, *card_id).card_type == card_type =>
+        {
+            Some((*card_id, *action))
+        }
+        _ => None,
+    })
+}
+
+fn mana_cost(game: &GameState, card_id: CardId) -> u32 {
+    queries::mana_cost(game, card_id).unwrap_or_default()
+}
+
+fn room_level(game: &GameState, room_id: RoomId) -> u32 {
+    game.occupants(room_id).map(|card| card.data.card_level).sum()
+}
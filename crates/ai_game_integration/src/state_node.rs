@@ -66,6 +66,13 @@ impl GameStateNode for SpelldawnState {
         legal_actions::evaluate(self, player)
     }
 
+    fn legal_actions_with_priors<'a>(
+        &'a self,
+        player: Side,
+    ) -> Result<Box<dyn Iterator<Item = (GameAction, f64)> + 'a>> {
+        legal_actions::evaluate_with_priors(self, player)
+    }
+
     fn execute_action(&mut self, player: Side, action: GameAction) -> Result<()> {
         actions::handle_game_action(self, player, action)
     }
@@ -28,7 +28,7 @@ use ai_core::state_evaluator::StateEvaluator;
 use anyhow::Result;
 use petgraph::prelude::{EdgeRef, NodeIndex};
 use petgraph::{Direction, Graph};
-use rand::prelude::IteratorRandom;
+use rand::prelude::{IteratorRandom, SliceRandom};
 use with_error::WithError;
 
 use crate::child_score::{ChildScoreAlgorithm, SelectionMode};
@@ -65,6 +65,32 @@ impl<TState: GameStateNode> StateEvaluator<TState> for RandomPlayoutEvaluator {
     }
 }
 
+/// Plays out a game until a terminal state is reached, selecting actions
+/// randomly in proportion to their heuristic prior weights as returned by
+/// [GameStateNode::legal_actions_with_priors].
+pub struct PriorPlayoutEvaluator {}
+
+impl<TState: GameStateNode> StateEvaluator<TState> for PriorPlayoutEvaluator {
+    fn evaluate(&self, input: &TState, player: TState::PlayerName) -> Result<i32> {
+        let mut game = input.make_copy();
+        loop {
+            match game.status() {
+                GameStatus::Completed { winner } => {
+                    return Ok(if winner == player { 1 } else { -1 });
+                }
+                GameStatus::InProgress { current_turn } => {
+                    let actions = game.legal_actions_with_priors(current_turn)?.collect::<Vec<_>>();
+                    let (action, _) = actions
+                        .choose_weighted(&mut rand::thread_rng(), |(_, weight)| *weight)
+                        .ok()
+                        .with_error(|| "No actions found")?;
+                    game.execute_action(current_turn, *action)?;
+                }
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 struct SearchNode<TState: GameStateNode> {
     /// Player who acted to create this node
@@ -17,6 +17,8 @@
 pub mod rexard_images;
 
 use core_ui::design::FontColor;
+use data::cosmetics::{BoardSkin, CardBack};
+use data::player_data::IconMode;
 use data::primitives::{CardType, Lineage, Rarity, School, Side};
 use data::special_effects::{
     FantasyEventSounds, FireworksSound, Projectile, SoundEffect, TimedEffect,
@@ -34,6 +36,8 @@ pub enum CardIconType {
     Shield,
     LevelRequirement,
     Points,
+    Charges,
+    Durability,
 }
 
 /// Returns the background scale multiplier to use for a [CardIconType]
@@ -48,31 +52,50 @@ pub fn background_scale(icon_type: CardIconType) -> Option<f32> {
     })
 }
 
-/// Address for a given [CardIconType]
-pub fn card_icon(icon_type: CardIconType) -> SpriteAddress {
+/// Address for a given [CardIconType], using the palette for the provided
+/// [IconMode].
+pub fn card_icon(icon_type: CardIconType, mode: IconMode) -> SpriteAddress {
     SpriteAddress {
         address: format!(
             "{}.png",
-            match icon_type {
-                CardIconType::LevelCounter => {
+            match (mode, icon_type) {
+                (IconMode::HighContrast, CardIconType::Mana) => {
+                    "LittleSweetDaemon/TCG_Card_Fantasy_Design/Icons/Icon_Mana_Color_04"
+                }
+                (IconMode::HighContrast, CardIconType::Health) => {
+                    "LittleSweetDaemon/TCG_Card_Elemental_Design/Heart_Icons/Heart_Icons_Color_1"
+                }
+                (IconMode::HighContrast, CardIconType::Attack) => {
+                    "LittleSweetDaemon/TCG_Card_Elemental_Design/Attack_Icons/Attack_Icons_Color_1"
+                }
+                (IconMode::HighContrast, CardIconType::Shield) => {
+                    "LittleSweetDaemon/TCG_Card_Elemental_Design/Number_Icons/Number_Icons_Color_1"
+                }
+                (_, CardIconType::LevelCounter) => {
                     "LittleSweetDaemon/TCG_Card_Elemental_Design/Number_Icons/Number_Icons_Color_3"
                 }
-                CardIconType::Mana => {
+                (_, CardIconType::Mana) => {
                     "LittleSweetDaemon/TCG_Card_Fantasy_Design/Icons/Icon_Mana_Color_01"
                 }
-                CardIconType::Health => {
+                (_, CardIconType::Health) => {
                     "LittleSweetDaemon/TCG_Card_Elemental_Design/Heart_Icons/Heart_Icons_Color_5"
                 }
-                CardIconType::Attack => {
+                (_, CardIconType::Attack) => {
                     "LittleSweetDaemon/TCG_Card_Elemental_Design/Attack_Icons/Attack_Icons_Color_4"
                 }
-                CardIconType::Shield => {
+                (_, CardIconType::Shield) => {
                     "LittleSweetDaemon/TCG_Card_Elemental_Design/Number_Icons/Number_Icons_Color_6"
                 }
-                CardIconType::LevelRequirement => {
+                (_, CardIconType::LevelRequirement) => {
                     "LittleSweetDaemon/TCG_Card_Elemental_Design/Number_Back/Number_Back_Color_3"
                 }
-                CardIconType::Points => {
+                (_, CardIconType::Charges) => {
+                    "LittleSweetDaemon/TCG_Card_Elemental_Design/Number_Icons/Number_Icons_Color_2"
+                }
+                (_, CardIconType::Durability) => {
+                    "LittleSweetDaemon/TCG_Card_Elemental_Design/Number_Icons/Number_Icons_Color_4"
+                }
+                (_, CardIconType::Points) => {
                     "LittleSweetDaemon/TCG_Card_Elemental_Design/Card_Color_07/Back_Card_Color_07/Back_Card_Color_07_Logo_Crystal"
                 }
             }
@@ -80,6 +103,15 @@ pub fn card_icon(icon_type: CardIconType) -> SpriteAddress {
     }
 }
 
+/// Color for the text displayed on card icons in the provided [IconMode], or
+/// None to use the default color.
+pub fn card_icon_text_color(mode: IconMode) -> Option<FlexColor> {
+    match mode {
+        IconMode::Standard => None,
+        IconMode::HighContrast => Some(FontColor::HighContrastCardIcon.into()),
+    }
+}
+
 /// Address for the frame of a player's identity card image
 pub fn identity_card_frame(side: Side) -> SpriteAddress {
     SpriteAddress { address: format!("{}.png", identity_card_frame_string(side)) }
@@ -92,24 +124,50 @@ fn identity_card_frame_string(side: Side) -> &'static str {
     }
 }
 
-/// Address for the back of a card of a given [School]
-pub fn card_back(school: School) -> SpriteAddress {
+/// Address for a given [CardBack]
+pub fn card_back(card_back: CardBack) -> SpriteAddress {
     SpriteAddress {
         address: format!(
             "{}.png",
-            match school {
-                School::Law => {
+            match card_back {
+                CardBack::Steampunk => {
                     "LittleSweetDaemon/TCG_Card_Fantasy_Design/Backs/Back_Steampunk_Style_Color_1"
                 }
-                School::Neutral => {
+                CardBack::Lovecraft => {
                     "LittleSweetDaemon/TCG_Card_Fantasy_Design/Backs/Back_Lovecraft_Style_Color_1"
                 }
-                School::Shadow => {
+                CardBack::Daemon => {
                     "LittleSweetDaemon/TCG_Card_Fantasy_Design/Backs/Back_Daemon_Style_Color_1"
                 }
-                School::Primal => {
+                CardBack::Elf => {
                     "LittleSweetDaemon/TCG_Card_Fantasy_Design/Backs/Back_Elf_Style_Color_1"
                 }
+                CardBack::Gilded => {
+                    "LittleSweetDaemon/TCG_Card_Fantasy_Design/Backs/Back_Steampunk_Style_Color_4"
+                }
+                CardBack::Abyssal => {
+                    "LittleSweetDaemon/TCG_Card_Fantasy_Design/Backs/Back_Lovecraft_Style_Color_3"
+                }
+            }
+        ),
+    }
+}
+
+/// Address for the background image of a given [BoardSkin]
+pub fn board_skin(board_skin: BoardSkin) -> SpriteAddress {
+    SpriteAddress {
+        address: format!(
+            "{}.png",
+            match board_skin {
+                BoardSkin::Library => {
+                    "TPR/EnvironmentsHQ/Castles, Towers & Keeps/Images/Library/SceneryLibrary_inside_1"
+                }
+                BoardSkin::Storefront => {
+                    "TPR/EnvironmentsHQ/Castles, Towers & Keeps/Images/Store/SceneryStore_outside_1"
+                }
+                BoardSkin::SnowMountain => {
+                    "TPR/EnvironmentsHQ/Dungeons, Shrines & Altars/Images/MountainTomb/ScenerySnowMountain_1"
+                }
             }
         ),
     }
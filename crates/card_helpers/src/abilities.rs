@@ -16,7 +16,9 @@
 
 use data::card_definition::{Ability, AbilityType, Cost, TargetRequirement};
 use data::card_state::CardPosition;
-use data::delegates::{Delegate, EventDelegate, QueryDelegate, RaidOutcome, Scope};
+use data::delegates::{
+    CombinedWeapons, Delegate, EventDelegate, QueryDelegate, RaidOutcome, Scope,
+};
 use data::game::GameState;
 use data::primitives::{AbilityId, AttackValue, CardId, ManaValue};
 use data::text::{AbilityText, DamageWord, Keyword, RulesTextContext, Sentence, TextToken};
@@ -32,7 +34,8 @@ pub fn encounter_boost() -> Ability {
     Ability {
         text: AbilityText::TextFn(|context| {
             let boost = match context {
-                RulesTextContext::Default(definition) => definition.config.stats.attack_boost,
+                RulesTextContext::Default(definition)
+                | RulesTextContext::Preview(definition, _) => definition.config.stats.attack_boost,
                 RulesTextContext::Game(game, card) => queries::attack_boost(game, card.id),
             }
             .unwrap_or_default();
@@ -52,6 +55,23 @@ pub fn encounter_boost() -> Ability {
     }
 }
 
+/// Encounter ability which gives the targeted weapon +`N` attack for the
+/// remainder of the current encounter by paying `cost`.
+pub fn weapon_boost_this_encounter<const N: AttackValue>(cost: Cost<AbilityId>) -> Ability {
+    Ability {
+        text: text!["Give a weapon", add_number(N), "Attack this encounter"],
+        ability_type: AbilityType::EncounterActivated(cost),
+        delegates: vec![Delegate::AttackValue(QueryDelegate::new(
+            |g, s, card_id| {
+                face_up_in_play(g, s, card_id)
+                    && g.ability_state(s.ability_id())
+                        .is_some_and(|state| state.encounter_weapon == Some(*card_id))
+            },
+            |_, _, _, attack| attack + N,
+        ))],
+    }
+}
+
 /// Store `N` mana in this card when played. Move it to the discard pile when
 /// the stored mana is depleted.
 pub fn store_mana_on_play<const N: ManaValue>() -> Ability {
@@ -97,6 +117,33 @@ pub fn combat_deal_damage<const N: u32>() -> Ability {
     }
 }
 
+/// Trap ability which deals `N` damage to the Champion player when this card
+/// is accessed during a raid, before they can score or destroy it.
+pub fn trap_deal_damage<const N: u32>() -> Ability {
+    Ability {
+        text: text![Keyword::Trap, Keyword::DealDamage(DamageWord::DealStart, N), "."],
+        ability_type: AbilityType::Standard,
+        delegates: vec![on_accessed(|g, s, _| {
+            reveal_trap(g, s);
+            mutations::deal_damage(g, s, N)
+        })],
+    }
+}
+
+/// Allows this weapon to be used together with another weapon during an
+/// encounter, splitting the health of the minion between them.
+pub fn dual_wield() -> Ability {
+    simple_ability(
+        text!["May be combined with another weapon during an encounter."],
+        Delegate::CanCombineWeapons(QueryDelegate {
+            requirement: |g, s, weapons: &CombinedWeapons| {
+                face_up_in_play(g, s, weapons) && weapons.includes(s.card_id())
+            },
+            transformation: |_, _, _, current| current.with_override(true),
+        }),
+    )
+}
+
 /// Minion combat ability which ends the current raid in failure.
 pub fn end_raid() -> Ability {
     Ability {
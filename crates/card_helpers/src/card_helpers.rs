@@ -20,14 +20,16 @@ pub mod text_macro;
 
 use anyhow::Result;
 use data::card_definition::{
-    Ability, AbilityType, AttackBoost, CardStats, Cost, CustomCost, SchemePoints, SpecialEffects,
+    Ability, AbilityType, ActivationLimit, AttackBoost, CardStats, Cost, SchemePoints,
+    SpecialEffects,
 };
 use data::card_state::CardPosition;
 use data::delegates::{
-    AbilityActivated, CardPlayed, Delegate, EventDelegate, MutationFn, QueryDelegate, RaidEnded,
-    RaidStart, RequirementFn, Scope, TransformationFn, UsedWeapon,
+    AbilityActivated, CardDestroyed, CardPlayed, DamageAbsorbed, Delegate, EventDelegate,
+    MutationFn, QueryDelegate, RaidEnded, RaidRetreat, RaidStart, RaidStep, RaidStepData,
+    RequirementFn, Scope, TransformationFn, UsedWeapon,
 };
-use data::game::GameState;
+use data::game::{GameState, TurnData};
 use data::game_actions::{CardPromptAction, CardTarget};
 use data::primitives::{
     AbilityId, ActionCount, AttackValue, CardId, HasAbilityId, HasCardId, HealthValue, ManaValue,
@@ -35,7 +37,7 @@ use data::primitives::{
 };
 use data::special_effects::Projectile;
 use data::text::{AbilityText, NumericOperator, TextToken};
-use data::updates::{GameUpdate, InitiatedBy};
+use data::updates::{GameUpdate, InitiatedBy, UpdatePriority};
 use data::utils;
 use rules::mana::ManaPurpose;
 use rules::{mana, mutations, queries};
@@ -63,13 +65,80 @@ pub fn text_only_ability(text: AbilityText) -> Ability {
 
 /// A [Cost] which requires no mana and `actions` action points.
 pub fn actions(actions: ActionCount) -> Cost<AbilityId> {
-    Cost { mana: None, actions, custom_cost: None }
+    Cost {
+        mana: None,
+        actions,
+        custom_cost: None,
+        limit: ActivationLimit::default(),
+        variable_mana: false,
+    }
+}
+
+/// A [Cost] for an encounter ability, which requires `mana` and no action
+/// points.
+pub fn encounter_cost(mana: ManaValue) -> Cost<AbilityId> {
+    Cost {
+        mana: Some(mana),
+        actions: 0,
+        custom_cost: None,
+        limit: ActivationLimit::default(),
+        variable_mana: false,
+    }
+}
+
+/// A [Cost] which requires `actions` action points and can only be activated
+/// once per turn.
+pub fn once_per_turn_cost(actions: ActionCount) -> Cost<AbilityId> {
+    Cost {
+        mana: None,
+        actions,
+        custom_cost: None,
+        limit: ActivationLimit { once_per_turn: true, ..ActivationLimit::default() },
+        variable_mana: false,
+    }
+}
+
+/// A [Cost] which requires `actions` action points and can only be activated
+/// `charges` times while its card is in play.
+pub fn charges_cost(actions: ActionCount, charges: u32) -> Cost<AbilityId> {
+    Cost {
+        mana: None,
+        actions,
+        custom_cost: None,
+        limit: ActivationLimit { charges: Some(charges), ..ActivationLimit::default() },
+        variable_mana: false,
+    }
+}
+
+/// A [Cost] which requires `actions` action points and cannot be activated
+/// again for `cooldown` of its owner's turns, including the current one.
+pub fn cooldown_cost(actions: ActionCount, cooldown: u32) -> Cost<AbilityId> {
+    Cost {
+        mana: None,
+        actions,
+        custom_cost: None,
+        limit: ActivationLimit { cooldown, ..ActivationLimit::default() },
+        variable_mana: false,
+    }
 }
 
 /// Provides the cost for a card, with 1 action point required and `mana` mana
 /// points
 pub fn cost(mana: ManaValue) -> Cost<CardId> {
-    Cost { mana: Some(mana), actions: 1, custom_cost: None }
+    Cost {
+        mana: Some(mana),
+        actions: 1,
+        custom_cost: None,
+        limit: ActivationLimit::default(),
+        variable_mana: false,
+    }
+}
+
+/// Provides the cost for a card with a variable mana cost, with 1 action point
+/// required and `mana` mana points plus an additional amount X chosen when the
+/// card is played.
+pub fn x_cost(mana: ManaValue) -> Cost<CardId> {
+    Cost { variable_mana: true, ..cost(mana) }
 }
 
 /// [Cost] for an identity card
@@ -79,22 +148,13 @@ pub fn identity_cost() -> Cost<CardId> {
 
 /// [Cost] for a scheme card
 pub fn scheme_cost() -> Cost<CardId> {
-    Cost { mana: None, actions: 1, custom_cost: None }
-}
-
-/// A [CustomCost] which allows an ability to be activated once per turn.
-///
-/// Stores turn data in ability state. Never returns `None`.
-pub fn once_per_turn_cost() -> Option<CustomCost<AbilityId>> {
-    Some(CustomCost {
-        can_pay: |game, ability_id| {
-            utils::is_false(|| Some(game.ability_state(ability_id)?.turn? == game.data.turn))
-        },
-        pay: |game, ability_id| {
-            game.ability_state_mut(ability_id).turn = Some(game.data.turn);
-            Ok(())
-        },
-    })
+    Cost {
+        mana: None,
+        actions: 1,
+        custom_cost: None,
+        limit: ActivationLimit::default(),
+        variable_mana: false,
+    }
 }
 
 /// Creates a standard [Ability] with a single [Delegate].
@@ -113,12 +173,25 @@ pub fn face_up_in_play<T>(game: &GameState, scope: Scope, _: &T) -> bool {
     card.is_face_up() && card.position().in_play()
 }
 
+/// RequirementFn that this delegate's card is currently face up & in play and
+/// that the [TurnData] in question belongs to the card's controller.
+pub fn face_up_in_play_on_own_turn(game: &GameState, scope: Scope, turn: &TurnData) -> bool {
+    turn.side == scope.controller(game) && face_up_in_play(game, scope, turn)
+}
+
 /// RequirementFn that this delegate's card is currently face down & in play
 pub fn face_down_in_play<T>(game: &GameState, scope: Scope, _: &T) -> bool {
     let card = game.card(scope.card_id());
     card.is_face_down() && card.position().in_play()
 }
 
+/// RequirementFn that this delegate's card is currently in the shared global
+/// modifier slot. Global modifiers typically use this requirement to hook
+/// queries for both players.
+pub fn global_modifier_active<T>(game: &GameState, scope: Scope, _: &T) -> bool {
+    game.card(scope.card_id()).position() == CardPosition::GlobalModifier
+}
+
 /// RequirementFn that this delegate's card is currently in its owner's score
 /// pile
 pub fn scored_by_owner<T>(game: &GameState, scope: Scope, _: &T) -> bool {
@@ -137,6 +210,12 @@ pub fn this_ability(_game: &GameState, scope: Scope, ability_id: &impl HasAbilit
     scope.ability_id() == ability_id.ability_id()
 }
 
+/// A RequirementFn which restricts delegates to only listen to a card being
+/// played with their own [AbilityType::Mode] ability as the chosen mode.
+pub fn chosen_mode(_game: &GameState, scope: Scope, played: &CardPlayed) -> bool {
+    played.mode == Some(scope.ability_id())
+}
+
 /// A RequirementFn which checks if the current `raid_id` matches the stored
 /// [RaidId] for this `scope`.
 pub fn matching_raid<T>(game: &GameState, scope: Scope, _: &T) -> bool {
@@ -145,6 +224,13 @@ pub fn matching_raid<T>(game: &GameState, scope: Scope, _: &T) -> bool {
     })
 }
 
+/// A RequirementFn which matches when the Champion approaches the outermost
+/// defender of the room being raided.
+pub fn approaching_outermost_defender(game: &GameState, _: Scope, data: &RaidStepData) -> bool {
+    matches!(data.step, RaidStep::ApproachDefender(defender_id)
+        if game.defender_list(data.target).last() == Some(&defender_id))
+}
+
 /// Predicate checking if a room is an inner room
 pub fn is_inner_room(room_id: RoomId) -> bool {
     room_id == RoomId::Vault || room_id == RoomId::Sanctum || room_id == RoomId::Crypts
@@ -156,6 +242,14 @@ pub fn alert(game: &mut GameState, scope: Scope) {
     game.record_update(|| GameUpdate::AbilityTriggered(scope.ability_id()));
 }
 
+/// Pushes a [GameUpdate] revealing the card represented by [Scope] as a
+/// triggered trap. Played back before other updates caused by the trap.
+pub fn reveal_trap(game: &mut GameState, scope: Scope) {
+    game.record_update_with_priority(UpdatePriority::High, || {
+        GameUpdate::TrapTriggered(scope.card_id())
+    });
+}
+
 /// Invokes [alert] if the provided `number` is not zero.
 pub fn alert_if_nonzero(game: &mut GameState, scope: Scope, number: u32) {
     if number > 0 {
@@ -168,6 +262,22 @@ pub fn on_cast(mutation: MutationFn<CardPlayed>) -> Delegate {
     Delegate::CastCard(EventDelegate { requirement: this_card, mutation })
 }
 
+/// A delegate which triggers when a modal card is cast with this ability as
+/// the chosen mode
+pub fn on_mode_chosen(mutation: MutationFn<CardPlayed>) -> Delegate {
+    Delegate::CastCard(EventDelegate { requirement: chosen_mode, mutation })
+}
+
+/// Creates an [AbilityType::Mode] ability for a modal card with the given
+/// button `label` and `text`, which invokes `mutation` when chosen.
+pub fn mode(label: &'static str, text: AbilityText, mutation: MutationFn<CardPlayed>) -> Ability {
+    Ability {
+        text,
+        ability_type: AbilityType::Mode(label),
+        delegates: vec![on_mode_chosen(mutation)],
+    }
+}
+
 /// A [Delegate] which triggers when an ability is activated
 pub fn on_activated(mutation: MutationFn<AbilityActivated>) -> Delegate {
     Delegate::ActivateAbility(EventDelegate { requirement: this_ability, mutation })
@@ -187,6 +297,18 @@ pub fn at_dusk(mutation: MutationFn<TurnNumber>) -> Delegate {
     Delegate::Dusk(EventDelegate { requirement: face_up_in_play, mutation })
 }
 
+/// A delegate which triggers at the end of the owning player's turn if a card
+/// is face up in play
+pub fn at_end_of_turn(mutation: MutationFn<TurnData>) -> Delegate {
+    Delegate::TurnEnd(EventDelegate { requirement: face_up_in_play_on_own_turn, mutation })
+}
+
+/// A delegate which triggers at the start of the owning player's main phase
+/// if a card is face up in play
+pub fn at_main_phase_begin(mutation: MutationFn<TurnData>) -> Delegate {
+    Delegate::MainPhaseBegin(EventDelegate { requirement: face_up_in_play_on_own_turn, mutation })
+}
+
 /// A minion delegate which triggers when it is encountered
 pub fn on_encountered(mutation: MutationFn<CardId>) -> Delegate {
     Delegate::EncounterMinion(EventDelegate { requirement: this_card, mutation })
@@ -246,6 +368,32 @@ pub fn on_raid_ended(
     Delegate::RaidEnd(EventDelegate { requirement, mutation })
 }
 
+/// A delegate which fires when the Champion retreats from a raid
+pub fn on_champion_retreat(
+    requirement: RequirementFn<RaidRetreat>,
+    mutation: MutationFn<RaidRetreat>,
+) -> Delegate {
+    Delegate::ChampionRetreat(EventDelegate { requirement, mutation })
+}
+
+/// A delegate which fires before each [RaidStep] of a raid. See
+/// [Delegate::BeforeRaidStep].
+pub fn before_raid_step(
+    requirement: RequirementFn<RaidStepData>,
+    mutation: MutationFn<RaidStepData>,
+) -> Delegate {
+    Delegate::BeforeRaidStep(EventDelegate { requirement, mutation })
+}
+
+/// A delegate which fires after each [RaidStep] of a raid. See
+/// [Delegate::AfterRaidStep].
+pub fn after_raid_step(
+    requirement: RequirementFn<RaidStepData>,
+    mutation: MutationFn<RaidStepData>,
+) -> Delegate {
+    Delegate::AfterRaidStep(EventDelegate { requirement, mutation })
+}
+
 /// A delegate which fires when a raid ends in success
 pub fn on_raid_success(
     requirement: RequirementFn<RaidId>,
@@ -267,6 +415,46 @@ pub fn on_calculate_health(transformation: TransformationFn<CardId, HealthValue>
     Delegate::HealthValue(QueryDelegate { requirement: this_card, transformation })
 }
 
+/// Delegates which replace the Champion's access during a raid with
+/// `mutation` when `requirement` matches. The raid then ends in success.
+pub fn replace_access(
+    requirement: RequirementFn<RaidId>,
+    mutation: MutationFn<RaidId>,
+) -> Vec<Delegate> {
+    vec![
+        Delegate::ShouldReplaceAccess(QueryDelegate {
+            requirement,
+            transformation: |_, _, _, flag| flag.with_override(true),
+        }),
+        Delegate::ReplaceAccess(EventDelegate { requirement, mutation }),
+    ]
+}
+
+/// Delegates which replace the destruction of a card with `mutation` when
+/// `requirement` matches. The card remains in play.
+pub fn replace_destruction(
+    requirement: RequirementFn<CardId>,
+    mutation: MutationFn<CardId>,
+) -> Vec<Delegate> {
+    vec![
+        Delegate::ShouldReplaceDestruction(QueryDelegate {
+            requirement,
+            transformation: |_, _, _, flag| flag.with_override(true),
+        }),
+        Delegate::ReplaceDestruction(EventDelegate { requirement, mutation }),
+    ]
+}
+
+/// Delegate which fires when its card is destroyed
+pub fn on_destroyed(mutation: MutationFn<CardDestroyed>) -> Delegate {
+    Delegate::DestroyCard(EventDelegate { requirement: this_card, mutation })
+}
+
+/// Delegate which fires when its card absorbs damage dealt to the Champion
+pub fn on_damage_absorbed(mutation: MutationFn<DamageAbsorbed>) -> Delegate {
+    Delegate::DamageAbsorbed(EventDelegate { requirement: this_card, mutation })
+}
+
 pub fn add_vault_access<const N: u32>(requirement: RequirementFn<RaidId>) -> Delegate {
     Delegate::VaultAccessCount(QueryDelegate {
         requirement,
@@ -375,6 +563,24 @@ pub fn unveil_at_dusk() -> Delegate {
     })
 }
 
+/// Prevents a project from being unveiled by paying its cost, e.g. because it
+/// is only turned face up by its own abilities.
+pub fn cannot_unveil() -> Delegate {
+    Delegate::CanUnveil(QueryDelegate {
+        requirement: this_card,
+        transformation: |_g, _, _, current| current.with_override(false),
+    })
+}
+
+/// Requires a face-up project to pay `N` mana at the start of each Overlord
+/// turn, or else be discarded.
+pub fn upkeep_cost<const N: ManaValue>() -> Delegate {
+    Delegate::UpkeepCost(QueryDelegate {
+        requirement: this_card,
+        transformation: |_g, _, _, current| Some(current.unwrap_or(0) + N),
+    })
+}
+
 /// Delegate to store mana in a card when it is unveiled
 pub fn store_mana_on_unveil<const N: u32>() -> Delegate {
     when_unveiled(|g, s, _| {
@@ -0,0 +1,89 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Card definitions for the Ally card type
+
+use assets::rexard_images;
+use assets::rexard_images::RexardPack;
+use card_helpers::text_macro::text;
+use card_helpers::*;
+use data::card_definition::{CardConfig, CardDefinition};
+use data::card_name::CardName;
+use data::delegates::{Delegate, QueryDelegate};
+use data::primitives::{CardType, Rarity, School, Side};
+use data::set_name::SetName;
+use rules::{mana, mutations};
+
+pub fn loyal_squire() -> CardDefinition {
+    CardDefinition {
+        name: CardName::LoyalSquire,
+        sets: vec![SetName::Core2024],
+        cost: cost(1),
+        image: rexard_images::get(RexardPack::MonstersAvatars, "41"),
+        card_type: CardType::Ally,
+        side: Side::Champion,
+        school: School::Law,
+        rarity: Rarity::Common,
+        abilities: vec![simple_ability(
+            text!["When this ally is destroyed, gain", mana_text(1), "."],
+            on_destroyed(|g, _, _| {
+                mana::gain(g, Side::Champion, 1);
+                Ok(())
+            }),
+        )],
+        config: CardConfig { stats: health(2), ..CardConfig::default() },
+    }
+}
+
+pub fn devoted_acolyte() -> CardDefinition {
+    CardDefinition {
+        name: CardName::DevotedAcolyte,
+        sets: vec![SetName::Core2024],
+        cost: cost(2),
+        image: rexard_images::get(RexardPack::MonstersAvatars, "58"),
+        card_type: CardType::Ally,
+        side: Side::Champion,
+        school: School::Law,
+        rarity: Rarity::Common,
+        abilities: vec![simple_ability(
+            text!["Whenever this ally absorbs damage, draw a card."],
+            on_damage_absorbed(|g, _, _| {
+                mutations::draw_cards(g, Side::Champion, 1)?;
+                Ok(())
+            }),
+        )],
+        config: CardConfig { stats: health(1), ..CardConfig::default() },
+    }
+}
+
+pub fn temple_guardian() -> CardDefinition {
+    CardDefinition {
+        name: CardName::TempleGuardian,
+        sets: vec![SetName::Core2024],
+        cost: cost(3),
+        image: rexard_images::get(RexardPack::MonstersAvatars, "63"),
+        card_type: CardType::Ally,
+        side: Side::Champion,
+        school: School::Law,
+        rarity: Rarity::Common,
+        abilities: vec![simple_ability(
+            text!["This ally can only absorb damage during a raid."],
+            Delegate::CanAbsorbDamage(QueryDelegate {
+                requirement: |g, s, card_id| this_card(g, s, card_id) && g.data.raid.is_none(),
+                transformation: |_, _, _, flag| flag.with_override(false),
+            }),
+        )],
+        config: CardConfig { stats: health(4), ..CardConfig::default() },
+    }
+}
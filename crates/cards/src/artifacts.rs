@@ -18,17 +18,15 @@ use assets::rexard_images;
 use assets::rexard_images::{RexardArtifactType, RexardPack};
 use card_helpers::text_macro::text;
 use card_helpers::{abilities, *};
-use data::card_definition::{
-    Ability, AbilityType, CardConfig, CardDefinition, Cost, TargetRequirement,
-};
+use data::card_definition::{Ability, AbilityType, CardConfig, CardDefinition, TargetRequirement};
 use data::card_name::CardName;
 use data::delegates::{Delegate, EventDelegate};
 use data::primitives::{CardType, Rarity, School, Side};
 use data::set_name::SetName;
 use data::text::{Keyword, Sentence};
 use data::utils;
-use rules::mutations;
 use rules::mutations::OnZeroStored;
+use rules::{helpers, mutations};
 
 pub fn lodestone() -> CardDefinition {
     CardDefinition {
@@ -201,7 +199,7 @@ pub fn magical_resonator() -> CardDefinition {
                     "Use this ability only once per turn."
                 ],
                 ability_type: AbilityType::Activated(
-                    Cost { mana: None, actions: 1, custom_cost: once_per_turn_cost() },
+                    once_per_turn_cost(1),
                     TargetRequirement::None,
                 ),
                 delegates: vec![on_activated(|g, _s, activated| {
@@ -238,3 +236,45 @@ pub fn dark_grimoire() -> CardDefinition {
         config: CardConfig::default(),
     }
 }
+
+pub fn forge_hammer() -> CardDefinition {
+    CardDefinition {
+        name: CardName::ForgeHammer,
+        sets: vec![SetName::Core2024],
+        cost: cost(2),
+        image: rexard_images::get(RexardPack::MiningIcons, "MiningIcons_12_b"),
+        card_type: CardType::Artifact,
+        side: Side::Champion,
+        school: School::Law,
+        rarity: Rarity::Common,
+        abilities: vec![simple_ability(
+            text!["Your weapons cost", mana_text(1), "less to play."],
+            helpers::reduce_cost::<1>(|g, s, card_id| {
+                helpers::own_cards_while_in_play(g, s, card_id)
+                    && helpers::is_type(g, *card_id, CardType::Weapon)
+            }),
+        )],
+        config: CardConfig::default(),
+    }
+}
+
+pub fn channeling_orb() -> CardDefinition {
+    CardDefinition {
+        name: CardName::ChannelingOrb,
+        sets: vec![SetName::Core2024],
+        cost: cost(2),
+        image: rexard_images::get(RexardPack::MagicItems, "orb_02_b"),
+        card_type: CardType::Artifact,
+        side: Side::Champion,
+        school: School::Law,
+        rarity: Rarity::Common,
+        abilities: vec![simple_ability(
+            text!["Your spells cost", mana_text(1), "less to play."],
+            helpers::reduce_cost::<1>(|g, s, card_id| {
+                helpers::own_cards_while_in_play(g, s, card_id)
+                    && helpers::is_type(g, *card_id, CardType::ChampionSpell)
+            }),
+        )],
+        config: CardConfig::default(),
+    }
+}
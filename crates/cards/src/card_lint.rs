@@ -0,0 +1,157 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Consistency checks for [CardDefinition]s which can't be expressed in the
+//! type system. Run via `cargo run --bin lint_cards` and in tests.
+
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+use data::card_definition::{Ability, AbilityType, CardDefinition};
+use data::card_name::CardName;
+use data::primitives::{CardType, Rarity};
+use data::set_name::SetName;
+use data::text::{AbilityText, RulesTextContext, TextToken};
+
+/// A problem found with a single card's definition
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CardLintError {
+    pub name: CardName,
+    pub message: String,
+}
+
+impl Display for CardLintError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}: {}", self.name, self.message)
+    }
+}
+
+/// Lints every card registered via `initialize::run()`, returning errors
+/// sorted by card name.
+pub fn lint_all_cards() -> Vec<CardLintError> {
+    let mut result = rules::all_cards().flat_map(lint).collect::<Vec<_>>();
+    result.sort_by_key(|error| error.name);
+    result
+}
+
+/// Returns all problems found with the provided [CardDefinition].
+pub fn lint(definition: &CardDefinition) -> Vec<CardLintError> {
+    let mut messages = vec![];
+    let stats = &definition.config.stats;
+
+    if definition.sets.is_empty() && definition.card_type != CardType::Identity {
+        messages.push("Non-identity card must belong to at least one set".to_string());
+    }
+
+    if definition.rarity == Rarity::None && !definition.sets.contains(&SetName::Test) {
+        messages.push("Card must have a rarity unless it is a test card".to_string());
+    }
+
+    if definition.card_type == CardType::Minion && definition.config.lineage.is_none() {
+        messages.push("Minion must have a lineage".to_string());
+    }
+
+    if definition.card_type == CardType::Ally && stats.health.is_none() {
+        messages.push("Ally must have health".to_string());
+    }
+
+    if definition.card_type == CardType::Scheme && stats.scheme_points.is_none() {
+        messages.push("Scheme must have scheme_points".to_string());
+    }
+
+    let has_encounter_ability =
+        definition.abilities.iter().any(|a| matches!(a.ability_type, AbilityType::Encounter));
+    if has_encounter_ability && stats.attack_boost.is_none() {
+        messages.push("Encounter ability requires an attack_boost".to_string());
+    }
+    if stats.attack_boost.is_some() && !has_encounter_ability {
+        messages.push("attack_boost has no encounter ability to activate it".to_string());
+    }
+
+    let modes = definition
+        .abilities
+        .iter()
+        .filter(|a| matches!(a.ability_type, AbilityType::Mode(_)))
+        .count();
+    match modes {
+        0 => {}
+        1 => messages.push("Modal card must have at least two modes".to_string()),
+        _ if !definition.card_type.is_spell() => {
+            messages.push("Only spells can have modes".to_string())
+        }
+        _ => {}
+    }
+
+    if definition.cost.variable_mana {
+        if !definition.card_type.is_spell() {
+            messages.push("Only spells can have a variable mana cost".to_string());
+        }
+        if modes > 0 {
+            messages.push("Modal card cannot have a variable mana cost".to_string());
+        }
+    }
+
+    for (index, ability) in definition.abilities.iter().enumerate() {
+        messages.extend(
+            lint_ability(definition, ability)
+                .into_iter()
+                .map(|message| format!("Ability {}: {}", index, message)),
+        );
+    }
+
+    messages.into_iter().map(|message| CardLintError { name: definition.name, message }).collect()
+}
+
+fn lint_ability(definition: &CardDefinition, ability: &Ability) -> Vec<String> {
+    let mut result = vec![];
+    if ability.delegates.is_empty() && !matches!(ability.ability_type, AbilityType::TextOnly) {
+        result.push("Ability has no delegates, use AbilityType::TextOnly for text".to_string());
+    }
+
+    let tokens = match &ability.text {
+        AbilityText::Text(tokens) => tokens.clone(),
+        AbilityText::TextFn(function) => function(&RulesTextContext::Default(definition)),
+    };
+    let text_costs = tokens
+        .iter()
+        .filter_map(|token| if let TextToken::Cost(cost) = token { Some(cost) } else { None })
+        .collect::<Vec<_>>();
+
+    match &ability.ability_type {
+        AbilityType::Activated(..) | AbilityType::EncounterActivated(_)
+            if !text_costs.is_empty() =>
+        {
+            result.push(
+                "Activated ability cost is rendered from its Cost, remove the cost from its text"
+                    .to_string(),
+            );
+        }
+        AbilityType::Encounter => {
+            if let Some(boost) = definition.config.stats.attack_boost {
+                let matches = text_costs.iter().all(|cost| {
+                    cost.iter().all(|t| !matches!(t, TextToken::Mana(m) if *m != boost.cost))
+                });
+                if !matches {
+                    result.push(format!(
+                        "Encounter text cost does not match attack_boost cost of {}",
+                        boost.cost
+                    ));
+                }
+            }
+        }
+        _ => {}
+    }
+
+    result
+}
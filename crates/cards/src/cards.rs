@@ -21,7 +21,9 @@ use data::card_definition::{CardConfig, CardDefinition};
 use data::card_name::CardName;
 use data::primitives::{CardType, Rarity, School, Side};
 
+pub mod allies;
 pub mod artifacts;
+pub mod card_lint;
 pub mod champion_identities;
 pub mod champion_spells;
 pub mod decklists;
@@ -35,11 +37,17 @@ pub mod test_cards;
 pub mod weapons;
 
 pub fn no_identity(name: CardName, side: Side, school: School) -> CardDefinition {
+    boss_identity(name, side, school, "22")
+}
+
+/// Identity with no abilities and a custom portrait, used by named AI
+/// opponents.
+pub fn boss_identity(name: CardName, side: Side, school: School, portrait: &str) -> CardDefinition {
     CardDefinition {
         name,
         sets: vec![],
         cost: identity_cost(),
-        image: rexard_images::get(RexardPack::MonstersAvatars, "22"),
+        image: rexard_images::get(RexardPack::MonstersAvatars, portrait),
         card_type: CardType::Identity,
         side,
         school,
@@ -27,3 +27,7 @@ pub fn no_identity_shadow() -> CardDefinition {
 pub fn no_identity_primal() -> CardDefinition {
     crate::no_identity(CardName::NoIdentityChampionPrimal, Side::Champion, School::Primal)
 }
+
+pub fn the_raider() -> CardDefinition {
+    crate::boss_identity(CardName::TheRaider, Side::Champion, School::Primal, "63")
+}
@@ -19,7 +19,8 @@ use card_helpers::{text, *};
 use data::card_definition::{Ability, AbilityType, CardConfig, CardDefinition, TargetRequirement};
 use data::card_name::CardName;
 use data::delegates::{Delegate, QueryDelegate};
-use data::primitives::{CardType, Rarity, RoomId, School, Side};
+use data::game::{GameState, ManaPool};
+use data::primitives::{CardId, CardType, Rarity, RoomId, School, Side};
 use data::set_name::SetName;
 use rules::{flags, mana, mutations};
 
@@ -114,7 +115,7 @@ pub fn charged_strike() -> CardDefinition {
             text!("Initiate a raid.", "Gain", mana_text(5), "to spend during that raid."),
             on_cast(|g, s, play_card| {
                 initiate_raid_with_callback(g, s, play_card.target, |game, raid_id| {
-                    mana::add_raid_specific_mana(game, s.side(), raid_id, 5);
+                    mana::add_to_pool(game, s.side(), ManaPool::Raid(raid_id), 5);
                 })
             }),
         )],
@@ -147,11 +148,11 @@ pub fn stealth_mission() -> CardDefinition {
             ability_type: AbilityType::Standard,
             delegates: vec![
                 on_cast(|g, s, play_card| initiate_raid(g, s, play_card.target)),
-                Delegate::ManaCost(QueryDelegate {
+                Delegate::ManaCostAdjustment(QueryDelegate {
                     requirement: matching_raid,
                     transformation: |g, _s, card_id, current| {
                         if rules::card_definition(g, *card_id).card_type == CardType::Minion {
-                            current.map(|current| current + 3)
+                            current + 3
                         } else {
                             current
                         }
@@ -188,3 +189,91 @@ pub fn preparation() -> CardDefinition {
         config: CardConfig::default(),
     }
 }
+
+pub fn embezzle() -> CardDefinition {
+    CardDefinition {
+        name: CardName::Embezzle,
+        sets: vec![SetName::Core2024],
+        cost: cost(3),
+        image: rexard_images::spell(1, "SpellBook01_62"),
+        card_type: CardType::ChampionSpell,
+        side: Side::Champion,
+        school: School::Law,
+        rarity: Rarity::Common,
+        abilities: vec![simple_ability(
+            text!("Take control of each project in target room"),
+            on_cast(|g, s, played| {
+                for card_id in overlord_projects(g, played.target.room_id()?) {
+                    mutations::change_control(g, card_id, s.side())?;
+                }
+                Ok(())
+            }),
+        )],
+        config: CardConfig {
+            custom_targeting: Some(TargetRequirement::TargetRoom(|game, _, room_id| {
+                !overlord_projects(game, room_id).is_empty()
+            })),
+            ..CardConfig::default()
+        },
+    }
+}
+
+pub fn crossroads() -> CardDefinition {
+    CardDefinition {
+        name: CardName::Crossroads,
+        sets: vec![SetName::Core2024],
+        cost: cost(1),
+        image: rexard_images::spell(1, "SpellBook01_33"),
+        card_type: CardType::ChampionSpell,
+        side: Side::Champion,
+        school: School::Law,
+        rarity: Rarity::Common,
+        abilities: vec![
+            mode("Gain Mana", text!("Gain", mana_text(5), "."), |g, s, _| {
+                mana::gain(g, s.side(), 5);
+                Ok(())
+            }),
+            mode("Draw Cards", text!("Draw 2 cards."), |g, s, _| {
+                mutations::draw_cards(g, s.side(), 2).map(|_| ())
+            }),
+        ],
+        config: CardConfig::default(),
+    }
+}
+
+/// Projects in `room_id` which are currently controlled by the Overlord
+fn overlord_projects(game: &GameState, room_id: RoomId) -> Vec<CardId> {
+    game.occupants(room_id)
+        .filter(|c| {
+            c.controller() == Side::Overlord
+                && rules::card_definition(game, c.id).card_type == CardType::Project
+        })
+        .map(|c| c.id)
+        .collect()
+}
+
+pub fn sunburst() -> CardDefinition {
+    CardDefinition {
+        name: CardName::Sunburst,
+        sets: vec![SetName::Core2024],
+        cost: cost(7),
+        image: rexard_images::spell(1, "SpellBook01_58"),
+        card_type: CardType::ChampionSpell,
+        side: Side::Champion,
+        school: School::Law,
+        rarity: Rarity::Common,
+        abilities: vec![simple_ability(
+            text!("Destroy all minions defending target room"),
+            on_cast(|g, _, played| {
+                let minions = g.defender_list(played.target.room_id()?);
+                mutations::destroy_cards(g, minions)
+            }),
+        )],
+        config: CardConfig {
+            custom_targeting: Some(TargetRequirement::TargetRoom(|game, _, room_id| {
+                game.defenders_unordered(room_id).next().is_some()
+            })),
+            ..CardConfig::default()
+        },
+    }
+}
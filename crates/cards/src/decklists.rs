@@ -17,11 +17,13 @@ use std::collections::HashMap;
 use actions;
 use anyhow::Result;
 use data::card_name::CardName;
+use data::cosmetics::DeckCosmetics;
 use data::deck::Deck;
 use data::game::{GameConfiguration, GameState, MulliganDecision};
 use data::game_actions::{GameAction, PromptAction};
 use data::player_name::{NamedPlayer, PlayerId};
 use data::primitives::{DeckIndex, GameId, Side};
+use data::updates::{UpdateQueue, Updates};
 use maplit::hashmap;
 use once_cell::sync::Lazy;
 use rules::{dispatch, mutations};
@@ -34,6 +36,7 @@ pub static EMPTY_OVERLORD: Lazy<Deck> = Lazy::new(|| Deck {
     side: Side::Overlord,
     identity: CardName::TestOverlordIdentity,
     cards: HashMap::new(),
+    cosmetics: DeckCosmetics::default(),
 });
 
 /// Standard Overlord deck for use in tests
@@ -61,6 +64,7 @@ pub static CANONICAL_OVERLORD: Lazy<Deck> = Lazy::new(|| Deck {
         CardName::Stormcaller => 2,
         CardName::FireGoblin => 2
     },
+    cosmetics: DeckCosmetics::default(),
 });
 
 /// Empty Champion deck for use in tests
@@ -71,6 +75,7 @@ pub static EMPTY_CHAMPION: Lazy<Deck> = Lazy::new(|| Deck {
     side: Side::Champion,
     identity: CardName::TestChampionIdentity,
     cards: HashMap::new(),
+    cosmetics: DeckCosmetics::default(),
 });
 
 /// Standard Champion deck for use in tests
@@ -98,6 +103,61 @@ pub static CANONICAL_CHAMPION: Lazy<Deck> = Lazy::new(|| Deck {
         CardName::EtherealBlade => 2,
         CardName::BowOfTheAlliance => 2
     },
+    cosmetics: DeckCosmetics::default(),
+});
+
+/// Minion-heavy Overlord deck used by [NamedPlayer::AdventureBossWarden]
+pub static WARDEN_OVERLORD: Lazy<Deck> = Lazy::new(|| Deck {
+    index: DeckIndex { value: 0 },
+    name: "The Warden".to_string(),
+    owner_id: PlayerId::Named(NamedPlayer::AdventureBossWarden),
+    side: Side::Overlord,
+    identity: CardName::TheWarden,
+    cards: hashmap! {
+        CardName::GoldMine => 3,
+        CardName::ActivateReinforcements => 2,
+        CardName::ResearchProject => 2,
+        CardName::Gemcarver => 2,
+        CardName::Coinery => 2,
+        CardName::OverwhelmingPower => 2,
+        CardName::GatheringDark => 3,
+        CardName::TimeGolem => 1,
+        CardName::TemporalStalker => 3,
+        CardName::ShadowLurker => 3,
+        CardName::SphinxOfWintersBreath => 3,
+        CardName::BridgeTroll => 3,
+        CardName::Stormcaller => 3,
+        CardName::FireGoblin => 2
+    },
+    cosmetics: DeckCosmetics::default(),
+});
+
+/// Weapon-heavy Champion deck used by [NamedPlayer::AdventureBossRaider]
+pub static RAIDER_CHAMPION: Lazy<Deck> = Lazy::new(|| Deck {
+    index: DeckIndex { value: 1 },
+    name: "The Raider".to_string(),
+    owner_id: PlayerId::Named(NamedPlayer::AdventureBossRaider),
+    side: Side::Champion,
+    identity: CardName::TheRaider,
+    cards: hashmap! {
+        CardName::Meditation => 2,
+        CardName::CoupDeGrace => 3,
+        CardName::ChargedStrike => 3,
+        CardName::ArcaneRecovery => 3,
+        CardName::StealthMission => 3,
+        CardName::Preparation => 2,
+        CardName::InvisibilityRing => 1,
+        CardName::Accumulator => 1,
+        CardName::MageGloves => 1,
+        CardName::SkysReach => 2,
+        CardName::MagicalResonator => 2,
+        CardName::DarkGrimoire => 1,
+        CardName::MaraudersAxe => 3,
+        CardName::KeenHalberd => 3,
+        CardName::EtherealBlade => 2,
+        CardName::BowOfTheAlliance => 2
+    },
+    cosmetics: DeckCosmetics::default(),
 });
 
 /// Returns a canonical deck associated with the given [PlayerId].
@@ -112,11 +172,42 @@ pub fn canonical_deck(player_id: PlayerId, side: Side) -> Deck {
 /// Creates a new deterministic game using the canonical decklists, deals
 /// opening hands and resolves mulligans.
 pub fn canonical_game() -> Result<GameState> {
+    new_canonical_game(GameConfiguration {
+        deterministic: true,
+        simulation: true,
+        ..GameConfiguration::default()
+    })
+}
+
+/// Runs [canonical_game] forward by applying each `(Side, GameAction)` pair in
+/// `script` in order, with update tracking enabled. Returns an error if any
+/// action is not legal.
+///
+/// The `on_action` callback is invoked after each action with the resulting
+/// [GameState], whose update queue contains only the updates produced by that
+/// action, e.g. so that the caller can render them to client commands.
+pub fn replay_canonical_game(
+    script: &[(Side, GameAction)],
+    mut on_action: impl FnMut(&GameState) -> Result<()>,
+) -> Result<GameState> {
+    let mut game = new_canonical_game(GameConfiguration {
+        deterministic: true,
+        ..GameConfiguration::default()
+    })?;
+    for (side, action) in script {
+        game.updates = UpdateQueue::new(Updates::Push);
+        actions::handle_game_action(&mut game, *side, *action)?;
+        on_action(&game)?;
+    }
+    Ok(game)
+}
+
+fn new_canonical_game(config: GameConfiguration) -> Result<GameState> {
     let mut game = GameState::new(
         GameId::new(0),
         CANONICAL_OVERLORD.clone(),
         CANONICAL_CHAMPION.clone(),
-        GameConfiguration { deterministic: true, simulation: true },
+        config,
     );
 
     dispatch::populate_delegate_cache(&mut game);
@@ -135,9 +226,12 @@ pub fn canonical_game() -> Result<GameState> {
     Ok(game)
 }
 
-/// Looks up the [Deck] for a named player.
+/// Looks up the [Deck] for a named player. Players without a custom deck for
+/// the given side use the canonical deck.
 pub fn deck_for_player(player: NamedPlayer, side: Side) -> Deck {
-    // (Eventually different named players will have different decks)
-    let id = PlayerId::Named(player);
-    canonical_deck(id, side)
+    match (player, side) {
+        (NamedPlayer::AdventureBossWarden, Side::Overlord) => WARDEN_OVERLORD.clone(),
+        (NamedPlayer::AdventureBossRaider, Side::Champion) => RAIDER_CHAMPION.clone(),
+        _ => canonical_deck(PlayerId::Named(player), side),
+    }
 }
@@ -3,11 +3,14 @@
 use rules::DEFINITIONS;
 
 use crate::{
-    artifacts, champion_identities, champion_spells, minions, overlord_identities, overlord_spells,
-    projects, schemes, test_cards, weapons,
+    allies, artifacts, champion_identities, champion_spells, minions, overlord_identities,
+    overlord_spells, projects, schemes, test_cards, weapons,
 };
 
 pub fn run() {
+    DEFINITIONS.insert(allies::loyal_squire);
+    DEFINITIONS.insert(allies::devoted_acolyte);
+    DEFINITIONS.insert(allies::temple_guardian);
     DEFINITIONS.insert(artifacts::lodestone);
     DEFINITIONS.insert(artifacts::invisibility_ring);
     DEFINITIONS.insert(artifacts::accumulator);
@@ -15,15 +18,21 @@ pub fn run() {
     DEFINITIONS.insert(artifacts::skys_reach);
     DEFINITIONS.insert(artifacts::magical_resonator);
     DEFINITIONS.insert(artifacts::dark_grimoire);
+    DEFINITIONS.insert(artifacts::forge_hammer);
+    DEFINITIONS.insert(artifacts::channeling_orb);
     DEFINITIONS.insert(champion_identities::no_identity_law);
     DEFINITIONS.insert(champion_identities::no_identity_shadow);
     DEFINITIONS.insert(champion_identities::no_identity_primal);
+    DEFINITIONS.insert(champion_identities::the_raider);
     DEFINITIONS.insert(champion_spells::arcane_recovery);
     DEFINITIONS.insert(champion_spells::meditation);
     DEFINITIONS.insert(champion_spells::coup_de_grace);
     DEFINITIONS.insert(champion_spells::charged_strike);
     DEFINITIONS.insert(champion_spells::stealth_mission);
     DEFINITIONS.insert(champion_spells::preparation);
+    DEFINITIONS.insert(champion_spells::sunburst);
+    DEFINITIONS.insert(champion_spells::embezzle);
+    DEFINITIONS.insert(champion_spells::crossroads);
     DEFINITIONS.insert(minions::time_golem);
     DEFINITIONS.insert(minions::temporal_stalker);
     DEFINITIONS.insert(minions::shadow_lurker);
@@ -34,12 +43,17 @@ pub fn run() {
     DEFINITIONS.insert(overlord_identities::no_identity_law);
     DEFINITIONS.insert(overlord_identities::no_identity_shadow);
     DEFINITIONS.insert(overlord_identities::no_identity_primal);
+    DEFINITIONS.insert(overlord_identities::the_warden);
     DEFINITIONS.insert(overlord_spells::gathering_dark);
     DEFINITIONS.insert(overlord_spells::overwhelming_power);
     DEFINITIONS.insert(overlord_spells::forced_march);
+    DEFINITIONS.insert(overlord_spells::corrosion);
+    DEFINITIONS.insert(overlord_spells::torment);
     DEFINITIONS.insert(projects::gemcarver);
     DEFINITIONS.insert(projects::coinery);
     DEFINITIONS.insert(projects::spike_trap);
+    DEFINITIONS.insert(projects::summoning_circle);
+    DEFINITIONS.insert(projects::iron_tithe);
     DEFINITIONS.insert(schemes::gold_mine);
     DEFINITIONS.insert(schemes::activate_reinforcements);
     DEFINITIONS.insert(schemes::research_project);
@@ -71,6 +85,14 @@ pub fn run() {
     DEFINITIONS.insert(test_cards::deal_damage_end_raid);
     DEFINITIONS.insert(test_cards::test_card_stored_mana);
     DEFINITIONS.insert(test_cards::test_attack_weapon);
+    DEFINITIONS.insert(test_cards::test_champion_tutor);
+    DEFINITIONS.insert(test_cards::test_overlord_tutor);
+    DEFINITIONS.insert(test_cards::test_activated_ability_charges);
+    DEFINITIONS.insert(test_cards::test_activated_ability_cooldown);
+    DEFINITIONS.insert(test_cards::test_project_upkeep);
+    DEFINITIONS.insert(test_cards::test_global_modifier_raid_cost);
+    DEFINITIONS.insert(test_cards::test_global_modifier_hand_size);
+    DEFINITIONS.insert(test_cards::test_reaction);
     DEFINITIONS.insert(weapons::marauders_axe);
     DEFINITIONS.insert(weapons::keen_halberd);
     DEFINITIONS.insert(weapons::ethereal_blade);
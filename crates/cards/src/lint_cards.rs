@@ -0,0 +1,33 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Checks all card definitions for consistency problems, see [card_lint].
+
+use anyhow::Result;
+use cards::{card_lint, initialize};
+use with_error::fail;
+
+fn main() -> Result<()> {
+    initialize::run();
+    let errors = card_lint::lint_all_cards();
+    if !errors.is_empty() {
+        for error in &errors {
+            println!("{}", error);
+        }
+        fail!("Found {} card definition errors", errors.len());
+    }
+
+    println!("All card definitions passed");
+    Ok(())
+}
@@ -27,3 +27,7 @@ pub fn no_identity_shadow() -> CardDefinition {
 pub fn no_identity_primal() -> CardDefinition {
     crate::no_identity(CardName::NoIdentityOverlordPrimal, Side::Overlord, School::Primal)
 }
+
+pub fn the_warden() -> CardDefinition {
+    crate::boss_identity(CardName::TheWarden, Side::Overlord, School::Law, "41")
+}
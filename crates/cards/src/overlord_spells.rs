@@ -18,7 +18,8 @@ use assets::rexard_images;
 use card_helpers::{text, *};
 use data::card_definition::{CardConfig, CardDefinition, TargetRequirement};
 use data::card_name::CardName;
-use data::primitives::{CardType, Rarity, School, Side};
+use data::card_state::CardPosition;
+use data::primitives::{CardType, ItemLocation, Rarity, School, Side};
 use data::set_name::SetName;
 use rules::{flags, mana, mutations};
 
@@ -105,3 +106,45 @@ pub fn forced_march() -> CardDefinition {
         },
     }
 }
+
+pub fn corrosion() -> CardDefinition {
+    CardDefinition {
+        name: CardName::Corrosion,
+        sets: vec![SetName::Core2024],
+        cost: cost(4),
+        image: rexard_images::spell(1, "SpellBook01_45"),
+        card_type: CardType::OverlordSpell,
+        side: Side::Overlord,
+        school: School::Law,
+        rarity: Rarity::Common,
+        abilities: vec![simple_ability(
+            text!("Destroy all of the Champion's artifacts"),
+            on_cast(|g, _, _| {
+                let artifacts = g.card_list_for_position(
+                    Side::Champion,
+                    CardPosition::ArenaItem(ItemLocation::Artifacts),
+                );
+                mutations::destroy_cards(g, artifacts)
+            }),
+        )],
+        config: CardConfig::default(),
+    }
+}
+
+pub fn torment() -> CardDefinition {
+    CardDefinition {
+        name: CardName::Torment,
+        sets: vec![SetName::Core2024],
+        cost: x_cost(1),
+        image: rexard_images::spell(1, "SpellBook01_14"),
+        card_type: CardType::OverlordSpell,
+        side: Side::Overlord,
+        school: School::Law,
+        rarity: Rarity::Common,
+        abilities: vec![simple_ability(
+            text!("Deal X damage."),
+            on_cast(|g, s, played| mutations::deal_damage(g, s, played.x.unwrap_or_default())),
+        )],
+        config: CardConfig::default(),
+    }
+}
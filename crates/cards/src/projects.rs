@@ -22,8 +22,8 @@ use data::card_name::CardName;
 use data::primitives::{CardType, Rarity, School, Side};
 use data::set_name::SetName;
 use data::text::{Keyword, Sentence};
-use rules::mutations;
 use rules::mutations::OnZeroStored;
+use rules::{helpers, mutations};
 
 pub fn gemcarver() -> CardDefinition {
     CardDefinition {
@@ -49,9 +49,11 @@ pub fn gemcarver() -> CardDefinition {
                     "When empty, draw a card."
                 ],
                 at_dusk(|g, s, _| {
+                    // Control reverts to the owner if this card is sacrificed
+                    let controller = s.controller(g);
                     mutations::take_stored_mana(g, s.card_id(), 3, OnZeroStored::Sacrifice)?;
                     if g.card(s.card_id()).data.stored_mana == 0 {
-                        mutations::draw_cards(g, s.side(), 1)?;
+                        mutations::draw_cards(g, controller, 1)?;
                     }
 
                     // TODO: Consider not alerting on the first turn to avoid two popups
@@ -86,6 +88,7 @@ pub fn coinery() -> CardDefinition {
                 delegates: vec![
                     activate_while_face_down(),
                     face_down_ability_cost(),
+                    cannot_unveil(),
                     on_activated(|g, s, _| {
                         if mutations::unveil_project_for_free(g, s.card_id())? {
                             add_stored_mana(g, s.card_id(), 15);
@@ -112,21 +115,67 @@ pub fn spike_trap() -> CardDefinition {
         rarity: Rarity::Common,
         abilities: vec![
             abilities::level_up(),
-            simple_ability(
-                text![
+            Ability {
+                text: text![
                     Keyword::Trap,
                     "If this card is in play, deal 2 damage plus 1 per level counter"
                 ],
-                on_accessed(|g, s, _| {
-                    if g.card(s.card_id()).position().in_play() {
-                        mutations::deal_damage(g, s, 2 + g.card(s.card_id()).data.card_level)?;
-                        alert(g, s);
-                    }
+                ability_type: AbilityType::Standard,
+                delegates: vec![
+                    cannot_unveil(),
+                    on_accessed(|g, s, _| {
+                        if g.card(s.card_id()).position().in_play() {
+                            reveal_trap(g, s);
+                            mutations::deal_damage(g, s, 2 + g.card(s.card_id()).data.card_level)?;
+                        }
 
-                    Ok(())
-                }),
-            ),
+                        Ok(())
+                    }),
+                ],
+            },
         ],
         config: CardConfig::default(),
     }
 }
+
+pub fn summoning_circle() -> CardDefinition {
+    CardDefinition {
+        name: CardName::SummoningCircle,
+        sets: vec![SetName::Core2024],
+        cost: cost(2),
+        image: rexard_images::get(RexardPack::MiningIcons, "MiningIcons_22_b"),
+        card_type: CardType::Project,
+        side: Side::Overlord,
+        school: School::Law,
+        rarity: Rarity::Common,
+        abilities: vec![simple_ability(
+            text!["Your minions cost", mana_text(1), "less to summon."],
+            helpers::reduce_cost::<1>(|g, s, card_id| {
+                helpers::own_cards_while_in_play(g, s, card_id)
+                    && helpers::is_type(g, *card_id, CardType::Minion)
+            }),
+        )],
+        config: CardConfig::default(),
+    }
+}
+
+pub fn iron_tithe() -> CardDefinition {
+    CardDefinition {
+        name: CardName::IronTithe,
+        sets: vec![SetName::Core2024],
+        cost: cost(1),
+        image: rexard_images::get(RexardPack::LootIcons, "coins_b_07"),
+        card_type: CardType::Project,
+        side: Side::Overlord,
+        school: School::Law,
+        rarity: Rarity::Common,
+        abilities: vec![simple_ability(
+            text!["The Champion's weapons cost", mana_text(1), "more to play."],
+            helpers::increase_cost::<1>(|g, s, card_id| {
+                helpers::opponent_cards_while_in_play(g, s, card_id)
+                    && helpers::is_type(g, *card_id, CardType::Weapon)
+            }),
+        )],
+        config: CardConfig::default(),
+    }
+}
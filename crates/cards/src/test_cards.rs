@@ -14,16 +14,19 @@
 
 use card_helpers::{abilities, text, *};
 use data::card_definition::{
-    Ability, AbilityType, AttackBoost, CardConfig, CardDefinition, CardStats, SchemePoints,
-    SpecialEffects,
+    Ability, AbilityType, AttackBoost, CardConfig, CardDefinition, CardStats, Cost, SchemePoints,
+    SpecialEffects, TargetRequirement,
 };
 use data::card_name::CardName;
-use data::primitives::{CardType, HealthValue, Lineage, ManaValue, Rarity, School, Side, Sprite};
+use data::delegates::{Delegate, QueryDelegate};
+use data::primitives::{
+    AbilityId, CardSubtype, CardType, HealthValue, Lineage, ManaValue, Rarity, School, Side, Sprite,
+};
 use data::set_name::SetName;
 use data::special_effects::{Projectile, TimedEffect};
-use data::text::{Keyword, Sentence};
-use rules::mutations;
+use data::text::{AbilityText, Keyword, Sentence};
 use rules::mutations::OnZeroStored;
+use rules::{mana, mutations};
 
 pub const MINION_COST: ManaValue = 3;
 pub const WEAPON_COST: ManaValue = 3;
@@ -445,3 +448,128 @@ pub fn test_attack_weapon() -> CardDefinition {
         ..test_champion_spell()
     }
 }
+
+pub fn test_champion_tutor() -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestChampionTutor,
+        abilities: vec![tutor_ability()],
+        ..test_champion_spell()
+    }
+}
+
+pub fn test_overlord_tutor() -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestOverlordTutor,
+        abilities: vec![tutor_ability()],
+        ..test_overlord_spell()
+    }
+}
+
+pub fn test_activated_ability_charges() -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestActivatedAbilityCharges,
+        cost: cost(ARTIFACT_COST),
+        card_type: CardType::Artifact,
+        abilities: vec![gain_mana_ability(
+            text!["Gain", mana_text(1), ".", "Use this ability only twice."],
+            charges_cost(1, 2),
+        )],
+        config: CardConfig::default(),
+        ..test_champion_spell()
+    }
+}
+
+pub fn test_activated_ability_cooldown() -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestActivatedAbilityCooldown,
+        cost: cost(ARTIFACT_COST),
+        card_type: CardType::Artifact,
+        abilities: vec![gain_mana_ability(
+            text!["Gain", mana_text(1), ".", "Use this ability only every other turn."],
+            cooldown_cost(1, 2),
+        )],
+        config: CardConfig::default(),
+        ..test_champion_spell()
+    }
+}
+
+pub fn test_project_upkeep() -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestProjectUpkeep,
+        cost: cost(1),
+        card_type: CardType::Project,
+        abilities: vec![simple_ability(text!["Upkeep", mana_text(1)], upkeep_cost::<1>())],
+        config: CardConfig::default(),
+        ..test_overlord_spell()
+    }
+}
+
+pub fn test_global_modifier_raid_cost() -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestGlobalModifierRaidCost,
+        cost: cost(1),
+        card_type: CardType::GlobalModifier,
+        abilities: vec![simple_ability(
+            text!["Raids cost an additional", actions_text(1)],
+            Delegate::InitiateRaidCost(QueryDelegate {
+                requirement: global_modifier_active,
+                transformation: |_, _, _, current| Cost { actions: current.actions + 1, ..current },
+            }),
+        )],
+        config: CardConfig::default(),
+        ..test_overlord_spell()
+    }
+}
+
+pub fn test_global_modifier_hand_size() -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestGlobalModifierHandSize,
+        cost: cost(1),
+        card_type: CardType::GlobalModifier,
+        abilities: vec![simple_ability(
+            text!["Each player's maximum hand size is increased by 2"],
+            Delegate::MaximumHandSize(QueryDelegate {
+                requirement: global_modifier_active,
+                transformation: |_, _, _, current| current + 2,
+            }),
+        )],
+        config: CardConfig::default(),
+        ..test_champion_spell()
+    }
+}
+
+pub fn test_reaction() -> CardDefinition {
+    CardDefinition {
+        name: CardName::TestReaction,
+        abilities: vec![simple_ability(
+            text!("Gain", mana_text(2)),
+            on_cast(|g, s, _| {
+                mana::gain(g, s.side(), 2);
+                Ok(())
+            }),
+        )],
+        config: CardConfig { subtypes: vec![CardSubtype::Reaction], ..CardConfig::default() },
+        ..test_champion_spell()
+    }
+}
+
+fn gain_mana_ability(text: AbilityText, cost: Cost<AbilityId>) -> Ability {
+    Ability {
+        text,
+        ability_type: AbilityType::Activated(cost, TargetRequirement::None),
+        delegates: vec![on_activated(|g, s, _| {
+            mana::gain(g, s.side(), 1);
+            Ok(())
+        })],
+    }
+}
+
+fn tutor_ability() -> Ability {
+    simple_ability(
+        text!("Search your deck for a card and put it into your hand"),
+        on_cast(|g, s, _| {
+            let candidates = g.deck(s.side()).map(|c| c.id).collect::<Vec<_>>();
+            mutations::set_tutor_prompt(g, s.side(), &candidates)
+        }),
+    )
+}
@@ -0,0 +1,87 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Validates that every asset referenced by a [CardDefinition] exists in the
+//! asset manifest generated by the Unity editor via 'Tools > Generate Asset
+//! Manifest'. Prints the missing addresses and exits with an error if any are
+//! not found, which would otherwise show up as blank card art at runtime.
+//!
+//! Usage: `cargo run --bin validate_assets [manifest path]`
+
+use std::collections::{BTreeSet, HashSet};
+use std::env;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+use anyhow::{Context, Result};
+use cards::initialize;
+use data::card_definition::CardDefinition;
+use with_error::fail;
+
+const DEFAULT_MANIFEST_PATH: &str = "Assets/AddressableAssetsData/AssetManifest.txt";
+
+fn main() -> Result<()> {
+    let path = env::args().nth(1).unwrap_or_else(|| DEFAULT_MANIFEST_PATH.to_string());
+    println!("Validating card assets against {}", path);
+    let manifest = read_manifest(&path)?;
+
+    initialize::run();
+    let mut missing = BTreeSet::new();
+    for definition in rules::all_cards() {
+        for address in asset_addresses(definition) {
+            if !manifest.contains(&address) {
+                missing.insert(format!("{:?}: {}", definition.name, address));
+            }
+        }
+    }
+
+    if !missing.is_empty() {
+        for line in &missing {
+            println!("Missing asset {}", line);
+        }
+        fail!("Found {} missing card assets", missing.len());
+    }
+
+    println!("All card assets found");
+    Ok(())
+}
+
+/// Reads the set of asset addresses in the manifest file at `path`, one per
+/// line.
+fn read_manifest(path: &str) -> Result<HashSet<String>> {
+    let file = File::open(path).with_context(|| {
+        format!("Unable to open {}, run 'Tools > Generate Asset Manifest' in Unity", path)
+    })?;
+    let mut result = HashSet::new();
+    for l in BufReader::new(file).lines() {
+        let line = l?;
+        if !line.trim().is_empty() {
+            result.insert(line.trim().to_string());
+        }
+    }
+    Ok(result)
+}
+
+/// Returns the addresses of all assets referenced by a card
+fn asset_addresses(definition: &CardDefinition) -> Vec<String> {
+    let effects = &definition.config.special_effects;
+    let mut result = vec![definition.image.address.clone()];
+    if let Some(projectile) = effects.projectile {
+        result.push(assets::projectile(projectile).address);
+    }
+    if let Some(effect) = effects.additional_hit {
+        result.push(assets::timed_effect(effect).address);
+    }
+    result
+}
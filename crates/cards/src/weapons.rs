@@ -53,11 +53,11 @@ pub fn marauders_axe() -> CardDefinition {
                         save_turn(g, s);
                         Ok(())
                     }),
-                    Delegate::ManaCost(QueryDelegate {
+                    Delegate::ManaCostAdjustment(QueryDelegate {
                         requirement: this_card,
                         transformation: |g, s, _, value| {
                             if utils::is_true(|| Some(g.ability_state(s)?.turn? == g.data.turn)) {
-                                value.map(|v| v.saturating_sub(2))
+                                value - 2
                             } else {
                                 value
                             }
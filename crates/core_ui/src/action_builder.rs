@@ -21,7 +21,7 @@ use crate::actions;
 use crate::actions::InterfaceAction;
 
 /// Helper to construct a [StandardAction], a client-opaque serialized action.
-#[derive(Default, Clone)]
+#[derive(Debug, Default, Clone)]
 pub struct ActionBuilder {
     action: Option<UserAction>,
     update: Vec<Command>,
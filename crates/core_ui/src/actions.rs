@@ -16,6 +16,7 @@ use std::collections::HashMap;
 use std::fmt::Debug;
 
 use data::adventure_action::AdventureAction;
+use data::draft::DraftAction;
 use data::game_actions::{GameAction, PromptAction};
 use data::user_actions::{DebugAction, UserAction};
 use protos::spelldawn::client_action::Action;
@@ -111,6 +112,16 @@ impl InterfaceAction for AdventureAction {
     }
 }
 
+impl InterfaceAction for DraftAction {
+    fn as_client_action(&self) -> Action {
+        Action::StandardAction(StandardAction {
+            payload: payload(UserAction::DraftAction(*self)),
+            update: None,
+            request_fields: HashMap::new(),
+        })
+    }
+}
+
 impl InterfaceAction for Command {
     fn as_client_action(&self) -> Action {
         Action::StandardAction(StandardAction {
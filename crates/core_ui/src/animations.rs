@@ -174,6 +174,23 @@ impl ElementUpdate for AnimateToElement {
     }
 }
 
+/// Immediately applies the non-default properties of a [Style] to an element
+pub struct ApplyStyle {
+    style: Style,
+}
+
+impl ApplyStyle {
+    pub fn new(style: Style) -> Self {
+        Self { style }
+    }
+}
+
+impl ElementUpdate for ApplyStyle {
+    fn build(self) -> Update {
+        Update::ApplyStyle(self.style.wrapped_style())
+    }
+}
+
 pub struct AnimateStyle {
     property: Property,
     animation: ElementAnimation,
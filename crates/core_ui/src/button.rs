@@ -17,6 +17,7 @@ use protos::spelldawn::{FlexAlign, FlexJustify, FlexPosition, TextAlign};
 
 use crate::actions::{InterfaceAction, NoAction};
 use crate::design::{Font, FontColor, FontSize};
+use crate::hotkey::Hotkey;
 use crate::prelude::*;
 use crate::style;
 use crate::style::WidthMode;
@@ -46,6 +47,7 @@ pub struct Button {
     two_lines: bool,
     width_mode: WidthMode,
     disabled: bool,
+    hotkey: Option<Hotkey>,
 }
 
 impl Button {
@@ -59,6 +61,7 @@ impl Button {
             two_lines: false,
             width_mode: WidthMode::Constrained,
             disabled: false,
+            hotkey: None,
         }
     }
 
@@ -96,6 +99,12 @@ impl Button {
         self.disabled = disabled;
         self
     }
+
+    /// Keyboard shortcut which activates this button
+    pub fn hotkey(mut self, hotkey: Option<Hotkey>) -> Self {
+        self.hotkey = hotkey;
+        self
+    }
 }
 
 impl Component for Button {
@@ -123,6 +132,7 @@ impl Component for Button {
                     .image_slice(Edge::Horizontal, 16.px()),
             )
             .on_click(self.action.as_client_action())
+            .hotkey(self.hotkey)
             .child(
                 Text::new(self.label)
                     .font_size(if self.two_lines {
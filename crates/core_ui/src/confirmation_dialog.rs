@@ -0,0 +1,129 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A panel asking the user to confirm a destructive action before it is
+//! performed.
+
+use data::user_actions::UserAction;
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::{FlexAlign, FlexJustify, InterfacePanelAddress, WhiteSpace};
+
+use crate::action_builder::ActionBuilder;
+use crate::button::{Button, ButtonType};
+use crate::design::FontSize;
+use crate::panel_window::PanelWindow;
+use crate::panels;
+use crate::prelude::*;
+use crate::text::Text;
+
+/// Displays a description of the consequences of an action along with
+/// 'Cancel' and 'Confirm' buttons. The action is only sent to the server once
+/// the user clicks 'Confirm', which also closes this panel.
+pub struct ConfirmationDialog {
+    address: InterfacePanelAddress,
+    title: String,
+    description: String,
+    confirm_label: String,
+    action: Option<UserAction>,
+    update: Vec<Command>,
+}
+
+impl ConfirmationDialog {
+    pub fn new(address: impl Into<InterfacePanelAddress>) -> Self {
+        Self {
+            address: address.into(),
+            title: "Are you sure?".to_string(),
+            description: String::new(),
+            confirm_label: "Confirm".to_string(),
+            action: None,
+            update: vec![],
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Explains what will happen if the user confirms.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    pub fn confirm_label(mut self, label: impl Into<String>) -> Self {
+        self.confirm_label = label.into();
+        self
+    }
+
+    /// Server action to perform when the user confirms.
+    pub fn action(mut self, action: impl Into<UserAction>) -> Self {
+        self.action = Some(action.into());
+        self
+    }
+
+    /// Adds a client update to perform immediately when the user confirms.
+    pub fn update(mut self, command: impl Into<Command>) -> Self {
+        self.update.push(command.into());
+        self
+    }
+}
+
+impl Component for ConfirmationDialog {
+    fn build(self) -> Option<Node> {
+        let mut confirm = ActionBuilder::new().update(panels::close(self.address.clone()));
+        if let Some(action) = self.action {
+            confirm = confirm.action(action);
+        }
+        for command in self.update {
+            confirm = confirm.update(command);
+        }
+
+        PanelWindow::new(self.address.clone(), 768.px(), 512.px())
+            .title(self.title)
+            .content(
+                Column::new("Confirmation")
+                    .style(
+                        Style::new()
+                            .width(100.pct())
+                            .height(100.pct())
+                            .align_items(FlexAlign::Center)
+                            .justify_content(FlexJustify::SpaceBetween),
+                    )
+                    .child(
+                        Text::new(self.description)
+                            .font_size(FontSize::Headline)
+                            .white_space(WhiteSpace::Normal)
+                            .layout(Layout::new().margin(Edge::All, 16.px())),
+                    )
+                    .child(
+                        Row::new("ConfirmationButtons")
+                            .child(
+                                Button::new("Cancel")
+                                    .name(element_names::CANCEL_BUTTON)
+                                    .button_type(ButtonType::Secondary)
+                                    .action(panels::close(self.address))
+                                    .layout(Layout::new().margin(Edge::All, 16.px())),
+                            )
+                            .child(
+                                Button::new(self.confirm_label)
+                                    .name(element_names::CONFIRM_BUTTON)
+                                    .action(confirm)
+                                    .layout(Layout::new().margin(Edge::All, 16.px())),
+                            ),
+                    ),
+            )
+            .build()
+    }
+}
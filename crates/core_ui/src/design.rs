@@ -78,6 +78,7 @@ pub enum BackgroundColor {
     DeckCardNameOverlay,
     TilePanelOverlay,
     CoinCountOverlay,
+    Toast,
 }
 
 impl From<BackgroundColor> for FlexColor {
@@ -92,6 +93,7 @@ impl From<BackgroundColor> for FlexColor {
             BackgroundColor::DeckCardNameOverlay => BLACK_ALPHA_50,
             BackgroundColor::TilePanelOverlay => BLACK_ALPHA_75,
             BackgroundColor::CoinCountOverlay => BLACK_ALPHA_50,
+            BackgroundColor::Toast => BLACK_ALPHA_75,
         }
     }
 }
@@ -109,6 +111,9 @@ pub enum FontColor {
     ConstructCardTitle,
     CardCost,
     CoinCount,
+    PreviewValue,
+    HighContrastCardIcon,
+    Warning,
 }
 
 impl From<FontColor> for FlexColor {
@@ -125,6 +130,9 @@ impl From<FontColor> for FlexColor {
             FontColor::ConstructCardTitle => PINK_700,
             FontColor::CardCost => WHITE,
             FontColor::CoinCount => YELLOW_700,
+            FontColor::PreviewValue => BLUE_700,
+            FontColor::HighContrastCardIcon => BLACK,
+            FontColor::Warning => RED_600,
         }
     }
 }
@@ -136,6 +144,7 @@ pub enum FontSize {
     ButtonIcon,
     PanelTitle,
     PromptContext,
+    PromptPreview,
     SupplementalInfo,
     Headline,
     Body,
@@ -153,6 +162,7 @@ impl From<FontSize> for Dimension {
             FontSize::ButtonIcon => 48,
             FontSize::PanelTitle => 48,
             FontSize::PromptContext => 48,
+            FontSize::PromptPreview => 24,
             FontSize::SupplementalInfo => 28,
             FontSize::Headline => 36,
             FontSize::Body => 28,
@@ -19,6 +19,7 @@ use protos::spelldawn::{EventHandlers, FlexDirection, FlexStyle, Node};
 
 use crate::actions::InterfaceAction;
 use crate::component::{Component, ComponentObject};
+use crate::hotkey::Hotkey;
 use crate::style::Style;
 
 /// Renders a [Flexbox] which lays out its children horizontally, from left to
@@ -114,6 +115,13 @@ pub trait HasRenderNode: Sized {
         self.render_node().event_handlers = Some(EventHandlers { on_click: Some(action.build()) });
         self
     }
+
+    /// Keyboard shortcut which invokes this component's [Self::on_click]
+    /// action
+    fn hotkey(mut self, hotkey: Option<Hotkey>) -> Self {
+        self.render_node().hotkey = hotkey.map(Hotkey::build);
+        self
+    }
 }
 
 pub trait HasNodeChildren: HasRenderNode {
@@ -0,0 +1,51 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Keyboard shortcuts for activating interface elements
+
+use protos::spelldawn::NodeHotkey;
+
+/// Identifies a key which can be pressed to activate an interface element
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Hotkey {
+    Space,
+    /// A number key, from 1 to 9
+    Number(u32),
+}
+
+impl Hotkey {
+    /// Returns the hotkey for the `index`-th numbered response to a prompt,
+    /// starting from 0, or None if there is no number key for this index.
+    pub fn for_index(index: usize) -> Option<Self> {
+        (index < 9).then(|| Self::Number(index as u32 + 1))
+    }
+
+    /// Short description of this key to display as a hint
+    pub fn label(&self) -> String {
+        match self {
+            Self::Space => "Space".to_string(),
+            Self::Number(n) => n.to_string(),
+        }
+    }
+
+    pub fn build(self) -> NodeHotkey {
+        NodeHotkey {
+            key_code: match self {
+                Self::Space => "Space".to_string(),
+                Self::Number(n) => format!("Alpha{n}"),
+            },
+            label: self.label(),
+        }
+    }
+}
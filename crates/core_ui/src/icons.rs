@@ -25,7 +25,11 @@ pub const REFRESH: &str = "\u{f2f9}";
 pub const NEXT_PAGE: &str = "\u{f061}";
 pub const PREVIOUS_PAGE: &str = "\u{f060}";
 pub const COINS: &str = "\u{f51e}";
+pub const STAMINA: &str = "\u{f6ec}";
 pub const BULLET: &str = "\u{2022}";
 pub const BUG: &str = "\u{f188}";
 pub const BARS: &str = "\u{f0c9}";
 pub const DECK: &str = "\u{f02d}";
+pub const MAGIC: &str = "\u{f0d0}";
+pub const RAID: &str = "\u{f6d9}";
+pub const ARTIFACT: &str = "\u{f7d9}";
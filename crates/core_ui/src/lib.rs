@@ -21,14 +21,17 @@ pub mod bottom_sheet_content;
 pub mod button;
 pub mod component;
 pub mod conditional;
+pub mod confirmation_dialog;
 pub mod design;
 pub mod draggable;
 pub mod drop_target;
 pub mod flexbox;
 pub mod full_screen_image;
 pub mod full_screen_loading;
+pub mod hotkey;
 pub mod icons;
 pub mod list_cell;
+pub mod node_diff;
 pub mod panel_window;
 pub mod panels;
 pub mod prelude;
@@ -39,3 +42,4 @@ pub mod slider;
 pub mod style;
 pub mod text;
 pub mod text_field;
+pub mod virtual_list;
@@ -0,0 +1,125 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Computes targeted updates between two versions of a [Node] tree, allowing
+//! the client to update large interface elements without receiving them in
+//! full.
+
+use anyhow::Result;
+use prost::Message;
+use protos::spelldawn::node_patch::PatchType;
+use protos::spelldawn::{Node, NodePatch, NodePatchList};
+use with_error::WithError;
+
+/// Returns a list of [NodePatch]es which transform `old` into `new` when
+/// passed to [apply], or None if sending `new` in full would be no larger than
+/// sending the patches.
+///
+/// An empty list is returned if the two trees are identical.
+pub fn diff(old: &Node, new: &Node) -> Option<Vec<NodePatch>> {
+    let mut patches = vec![];
+    diff_node(&mut vec![], old, new, &mut patches);
+    let size = patches.iter().map(Message::encoded_len).sum::<usize>();
+    (size < new.encoded_len()).then_some(patches)
+}
+
+/// Applies a sequence of [NodePatch]es produced by [diff] to `node`.
+pub fn apply(node: &mut Node, patches: &[NodePatch]) -> Result<()> {
+    for patch in patches {
+        let mut target = &mut *node;
+        for index in &patch.path {
+            target = target
+                .children
+                .get_mut(*index as usize)
+                .with_error(|| format!("Child {} not found for patch {:?}", index, patch.path))?;
+        }
+
+        match patch.patch_type.as_ref().with_error(|| "Expected patch_type")? {
+            PatchType::Replace(replacement) => *target = replacement.clone(),
+            PatchType::TruncateChildren(length) => target.children.truncate(*length as usize),
+            PatchType::AppendChild(child) => target.children.push(child.clone()),
+        }
+    }
+    Ok(())
+}
+
+/// Updates `previous`, the most recent node received for some interface
+/// element, from either a full `node` or a list of `patches` to apply to it.
+///
+/// Returns the resulting node, or None if neither was provided.
+pub fn receive(
+    previous: &mut Option<Node>,
+    node: Option<Node>,
+    patches: Option<NodePatchList>,
+) -> Result<Option<Node>> {
+    if let Some(list) = patches {
+        let base = previous.as_mut().with_error(|| "No previous node to patch")?;
+        apply(base, &list.patches)?;
+    } else if node.is_some() {
+        *previous = node;
+    } else {
+        return Ok(None);
+    }
+    Ok(previous.clone())
+}
+
+fn diff_node(path: &mut Vec<u32>, old: &Node, new: &Node, patches: &mut Vec<NodePatch>) {
+    if old == new {
+        return;
+    }
+
+    if !same_attributes(old, new) {
+        patches.push(patch(path, PatchType::Replace(new.clone())));
+        return;
+    }
+
+    for (i, (old_child, new_child)) in old.children.iter().zip(&new.children).enumerate() {
+        path.push(i as u32);
+        diff_node(path, old_child, new_child, patches);
+        path.pop();
+    }
+
+    if new.children.len() < old.children.len() {
+        patches.push(patch(path, PatchType::TruncateChildren(new.children.len() as u32)));
+    }
+
+    for child in new.children.iter().skip(old.children.len()) {
+        patches.push(patch(path, PatchType::AppendChild(child.clone())));
+    }
+}
+
+/// Returns true if two nodes are identical, ignoring their children.
+fn same_attributes(old: &Node, new: &Node) -> bool {
+    let Node {
+        name,
+        node_type,
+        children: _,
+        event_handlers,
+        style,
+        hover_style,
+        pressed_style,
+        hotkey,
+    } = old;
+    *name == new.name
+        && *node_type == new.node_type
+        && *event_handlers == new.event_handlers
+        && *style == new.style
+        && *hover_style == new.hover_style
+        && *pressed_style == new.pressed_style
+        && *hotkey == new.hotkey
+}
+
+fn patch(path: &[u32], patch_type: PatchType) -> NodePatch {
+    NodePatch { path: path.to_vec(), patch_type: Some(patch_type) }
+}
@@ -0,0 +1,206 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use protos::spelldawn::client_action::Action;
+use protos::spelldawn::{FlexAlign, FlexJustify};
+
+use crate::actions::InterfaceAction;
+use crate::button::{IconButton, IconButtonType};
+use crate::icons;
+use crate::prelude::*;
+
+/// Where to display the page controls of a [VirtualList].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PageControls {
+    /// Previous page to the left of the list, next page to the right.
+    Horizontal,
+    /// Previous page above the list, next page below.
+    Vertical,
+}
+
+/// Displays a window of `page_size` items from a larger list, along with
+/// buttons to move between pages.
+///
+/// Only the items within the current window are rendered. Each item is wrapped
+/// in a container named based on its position within the full list, so element
+/// names stay stable as the window moves.
+pub struct VirtualList<'a> {
+    name: String,
+    len: usize,
+    offset: usize,
+    page_size: usize,
+    row_size: Option<usize>,
+    controls: PageControls,
+    button_type: IconButtonType,
+    item: Box<dyn Fn(usize) -> Option<Node> + 'a>,
+    placeholder: Option<Box<dyn Fn() -> Option<Node> + 'a>>,
+    page_action: Option<Box<dyn Fn(usize) -> Action + 'a>>,
+}
+
+impl<'a> VirtualList<'a> {
+    /// Creates a new list with `len` total items.
+    pub fn new(name: impl Into<String>, len: usize) -> Self {
+        Self {
+            name: name.into(),
+            len,
+            offset: 0,
+            page_size: 8,
+            row_size: None,
+            controls: PageControls::Horizontal,
+            button_type: IconButtonType::NavBrown,
+            item: Box::new(|_| None),
+            placeholder: None,
+            page_action: None,
+        }
+    }
+
+    /// Index of the first item to display.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Maximum number of items to display at once.
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// Arranges items in rows of `row_size` items each. By default items are
+    /// displayed in a single column.
+    pub fn row_size(mut self, row_size: usize) -> Self {
+        self.row_size = Some(row_size.max(1));
+        self
+    }
+
+    pub fn controls(mut self, controls: PageControls) -> Self {
+        self.controls = controls;
+        self
+    }
+
+    pub fn button_type(mut self, button_type: IconButtonType) -> Self {
+        self.button_type = button_type;
+        self
+    }
+
+    /// Renders the item at the provided index within the full list.
+    pub fn item<C: Component>(mut self, item: impl Fn(usize) -> C + 'a) -> Self {
+        self.item = Box::new(move |index| item(index).build());
+        self
+    }
+
+    /// Component used to fill out a partially-filled final row, so that rows
+    /// keep a consistent size.
+    pub fn placeholder<C: Component>(mut self, placeholder: impl Fn() -> C + 'a) -> Self {
+        self.placeholder = Some(Box::new(move || placeholder().build()));
+        self
+    }
+
+    /// Action to take in order to display the page starting at the provided
+    /// offset. Page controls are not shown if no action is provided.
+    pub fn page_action<A: InterfaceAction>(mut self, action: impl Fn(usize) -> A + 'a) -> Self {
+        self.page_action = Some(Box::new(move |offset| action(offset).as_client_action()));
+        self
+    }
+
+    /// Offset of the final page of this list.
+    fn last_page(&self) -> usize {
+        self.len.saturating_sub(1) / self.page_size * self.page_size
+    }
+
+    fn page_button(&self, name: &str, icon: &str, offset: Option<usize>) -> impl Component {
+        let style = match self.controls {
+            PageControls::Horizontal => Style::new()
+                .min_width(96.px())
+                .flex_shrink(0.0)
+                .justify_content(FlexJustify::Center),
+            PageControls::Vertical => {
+                Style::new().min_height(64.px()).flex_shrink(0.0).align_items(FlexAlign::Center)
+            }
+        };
+
+        Column::new(format!("{}{}", self.name, name)).style(style).child(offset.and_then(
+            |offset| {
+                self.page_action.as_ref().map(|action| {
+                    IconButton::new(icon).button_type(self.button_type).action(action(offset))
+                })
+            },
+        ))
+    }
+
+    fn item_container(&self, index: usize) -> Column {
+        Column::new(format!("{}Item{}", self.name, index))
+            .style(Style::new().align_items(FlexAlign::Center))
+            .child_node((self.item)(index))
+    }
+
+    fn content(&self, start: usize, end: usize) -> Column {
+        let content = Column::new(format!("{}Content", self.name)).style(
+            Style::new()
+                .flex_grow(1.0)
+                .align_items(FlexAlign::Center)
+                .justify_content(FlexJustify::Center),
+        );
+
+        let Some(row_size) = self.row_size else {
+            return content.children((start..end).map(|index| self.item_container(index)));
+        };
+
+        content.children((start..end).step_by(row_size).map(|row_start| {
+            let row_end = (row_start + row_size).min(end);
+            let mut row = Row::new(format!("{}Row{}", self.name, row_start / row_size))
+                .style(
+                    Style::new()
+                        .flex_grow(1.0)
+                        .align_items(FlexAlign::Center)
+                        .justify_content(FlexJustify::Center),
+                )
+                .children((row_start..row_end).map(|index| self.item_container(index)));
+            if let Some(placeholder) = &self.placeholder {
+                for _ in row_end..row_start + row_size {
+                    row = row.child_node(placeholder());
+                }
+            }
+            row
+        }))
+    }
+}
+
+impl<'a> Component for VirtualList<'a> {
+    fn build(self) -> Option<Node> {
+        let offset = self.offset.min(self.last_page());
+        let end = (offset + self.page_size).min(self.len);
+        let previous = (offset > 0).then(|| offset.saturating_sub(self.page_size));
+        let next = (end < self.len).then_some(end);
+
+        let previous_button = self.page_button("PreviousPage", icons::PREVIOUS_PAGE, previous);
+        let next_button = self.page_button("NextPage", icons::NEXT_PAGE, next);
+        let content = self.content(offset, end);
+
+        match self.controls {
+            PageControls::Horizontal => Row::new(self.name.clone())
+                .style(Style::new().flex_grow(1.0).align_items(FlexAlign::Stretch))
+                .child(previous_button)
+                .child(content)
+                .child(next_button)
+                .build(),
+            PageControls::Vertical => Column::new(self.name.clone())
+                .style(Style::new().flex_grow(1.0).align_items(FlexAlign::Stretch))
+                .child(previous_button)
+                .child(content)
+                .child(next_button)
+                .build(),
+        }
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Achievements are goals which players can complete across games, earning
+//! cosmetic rewards.
+
+use std::collections::HashMap;
+
+use enum_iterator::Sequence;
+use serde::{Deserialize, Serialize};
+
+use crate::cosmetics::{Cosmetic, RaidEffect, VictoryEffect};
+use crate::game::{GamePhase, GameState, TurnData};
+use crate::history::HistoryEvent;
+use crate::primitives::Side;
+
+/// Identifies an achievement
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize, Deserialize, Sequence,
+)]
+pub enum AchievementName {
+    /// Win a game
+    FirstVictory,
+    /// Win a game as the Champion without taking any damage
+    FlawlessVictory,
+    /// Score 3 schemes in a single turn
+    TripleScheme,
+    /// Initiate 4 raids in a single turn
+    Relentless,
+}
+
+impl AchievementName {
+    pub fn displayed_name(&self) -> &'static str {
+        match self {
+            AchievementName::FirstVictory => "First Victory",
+            AchievementName::FlawlessVictory => "Flawless Victory",
+            AchievementName::TripleScheme => "Master Plan",
+            AchievementName::Relentless => "Relentless",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            AchievementName::FirstVictory => "Win a game",
+            AchievementName::FlawlessVictory => "Win a game as the Champion without taking damage",
+            AchievementName::TripleScheme => "Score 3 schemes in one turn",
+            AchievementName::Relentless => "Initiate 4 raids in one turn",
+        }
+    }
+
+    /// Cosmetic item granted when this achievement is earned, if any
+    pub fn reward(&self) -> Option<Cosmetic> {
+        match self {
+            AchievementName::FirstVictory => Some(Cosmetic::Raid(RaidEffect::Lightning)),
+            AchievementName::FlawlessVictory => Some(Cosmetic::Victory(VictoryEffect::Fireworks)),
+            AchievementName::TripleScheme => Some(Cosmetic::Victory(VictoryEffect::ArcaneNova)),
+            AchievementName::Relentless => Some(Cosmetic::Raid(RaidEffect::SwordStorm)),
+        }
+    }
+
+    /// Returns true if the `side` player has completed this achievement in the
+    /// provided game, which must have ended.
+    pub fn is_earned(&self, game: &GameState, side: Side) -> bool {
+        let GamePhase::GameOver { winner } = game.data.phase else {
+            return false;
+        };
+
+        match self {
+            AchievementName::FirstVictory => winner == side,
+            AchievementName::FlawlessVictory => {
+                winner == side
+                    && side == Side::Champion
+                    && !game
+                        .history
+                        .iter()
+                        .any(|e| matches!(e.event, HistoryEvent::DealDamage(n) if n > 0))
+            }
+            AchievementName::TripleScheme => {
+                max_per_turn(
+                    game,
+                    |event| matches!(event, HistoryEvent::ScoreCard(s, _) if s == side),
+                ) >= 3
+            }
+            AchievementName::Relentless => {
+                side == Side::Champion
+                    && max_per_turn(game, |event| matches!(event, HistoryEvent::RaidBegin(_))) >= 4
+            }
+        }
+    }
+}
+
+/// Returns all achievements which the `side` player has completed in the
+/// provided game.
+pub fn evaluate(game: &GameState, side: Side) -> Vec<AchievementName> {
+    enum_iterator::all::<AchievementName>().filter(|a| a.is_earned(game, side)).collect()
+}
+
+/// Returns the largest number of history events matching `predicate` which
+/// occurred during a single turn of this game.
+fn max_per_turn(game: &GameState, predicate: impl Fn(HistoryEvent) -> bool) -> usize {
+    let mut counts = HashMap::<TurnData, usize>::new();
+    for entry in game.history.iter().filter(|entry| predicate(entry.event)) {
+        *counts.entry(entry.turn).or_default() += 1;
+    }
+    counts.values().copied().max().unwrap_or_default()
+}
@@ -19,8 +19,8 @@ use derive_more::{
     Add, AddAssign, Display, Div, DivAssign, From, Into, Mul, MulAssign, Sub, SubAssign, Sum,
 };
 use rand::distributions::uniform::{SampleRange, SampleUniform};
-use rand::prelude::IteratorRandom;
-use rand::Rng;
+use rand::prelude::{IteratorRandom, SliceRandom};
+use rand::{Rng, SeedableRng};
 use rand_xoshiro::Xoshiro256StarStar;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
@@ -29,7 +29,7 @@ use with_error::WithError;
 use crate::card_name::CardName;
 use crate::deck::Deck;
 use crate::player_name::PlayerId;
-use crate::primitives::Side;
+use crate::primitives::{GameId, School, Side};
 
 /// Identifies a set of tiles which can be revealed via the 'explore' action.
 pub type RegionId = u32;
@@ -38,6 +38,7 @@ pub type RegionId = u32;
     Debug,
     Display,
     Copy,
+    Default,
     Clone,
     PartialEq,
     Eq,
@@ -59,6 +60,10 @@ pub type RegionId = u32;
 )]
 pub struct Coins(pub u32);
 
+/// Stamina available at the start of an adventure. Exploring and taking tile
+/// actions spend stamina, and the adventure ends when it runs out.
+pub const STARTING_STAMINA: u32 = 10;
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
 pub enum AdventureStatus {
     InProgress,
@@ -75,6 +80,21 @@ impl TilePosition {
     pub fn new(x: i32, y: i32) -> Self {
         Self { x, y }
     }
+
+    /// Returns the number of steps required to move between two tiles.
+    ///
+    /// Positions are 'odd-r' hex offset coordinates, i.e. odd-numbered rows are
+    /// shifted half a tile to the right.
+    pub fn distance(self, other: TilePosition) -> u32 {
+        let (q1, r1) = self.axial();
+        let (q2, r2) = other.axial();
+        let (dq, dr) = (q1 - q2, r1 - r2);
+        dq.unsigned_abs().max(dr.unsigned_abs()).max((dq + dr).unsigned_abs())
+    }
+
+    fn axial(self) -> (i32, i32) {
+        (self.x - (self.y - (self.y & 1)) / 2, self.y)
+    }
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
@@ -85,7 +105,8 @@ pub struct CardChoice {
     pub sold: bool,
 }
 
-/// Data for rendering the draft screen
+/// Data for rendering the draft screen. Choices are generated when a draft is
+/// initiated.
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct DraftData {
     pub choices: Vec<CardChoice>,
@@ -98,13 +119,49 @@ pub struct ShopData {
     pub choices: Vec<CardChoice>,
 }
 
+/// Possible results of visiting a random event tile
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+pub enum EventOutcome {
+    /// The player finds a cache of coins
+    GainCoins(Coins),
+    /// A random card is removed from the player's adventure collection
+    LoseCard,
+    /// The player is ambushed by bandits and loses up to this many coins
+    Ambush(Coins),
+}
+
+/// Data for rendering the random event screen
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventData {
+    /// Outcome of this event, selected when the map is generated
+    pub outcome: EventOutcome,
+    /// Card which was removed from the player's collection by a
+    /// [EventOutcome::LoseCard] event, if any
+    pub lost_card: Option<CardName>,
+}
+
 /// Possible events/actions which can take place on a tile, represented by map
 /// icons
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TileEntity {
-    Explore { region: RegionId, cost: Coins },
-    Draft { cost: Coins, data: DraftData },
-    Shop { data: ShopData },
+    Explore {
+        region: RegionId,
+        cost: Coins,
+    },
+    Draft {
+        cost: Coins,
+        data: DraftData,
+    },
+    Shop {
+        data: ShopData,
+    },
+    Event {
+        data: EventData,
+    },
+    /// Restores the indicated amount of stamina when the player rests here
+    Camp {
+        stamina: u32,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -116,14 +173,43 @@ pub struct TileState {
     pub entity: Option<TileEntity>,
 
     pub region_id: RegionId,
+
+    /// True if this tile is still covered by fog of war. Hidden tiles are
+    /// displayed as clouds and cannot be interacted with.
+    #[serde(default)]
+    pub hidden: bool,
 }
 
 impl TileState {
     pub fn with_sprite(address: impl Into<String>) -> Self {
-        TileState { sprite: address.into(), road: None, entity: None, region_id: 1 }
+        TileState { sprite: address.into(), road: None, entity: None, region_id: 1, hidden: true }
     }
 }
 
+/// A notable event which occurred during an adventure, recorded in
+/// [AdventureState::journal].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+pub enum JournalEntry {
+    /// Paid to explore a tile, revealing a new region of the map
+    Explored { position: TilePosition, cost: Coins },
+    /// Visited a shop for the first time
+    VisitedShop(TilePosition),
+    /// Picked a card from a draft
+    DraftedCard { card: CardName, quantity: u32 },
+    /// Bought a card from a shop
+    BoughtCard { card: CardName, quantity: u32, cost: Coins },
+    /// Won a game while on this adventure
+    BattleWon(GameId),
+    /// Earned coins outside of the adventure map, e.g. from a quest reward
+    CoinsEarned(Coins),
+    /// Resolved a random event at the given position
+    Event { position: TilePosition, outcome: EventOutcome, lost_card: Option<CardName> },
+    /// Rested at the camp at the given position, restoring stamina
+    Rested { position: TilePosition, stamina: u32 },
+    /// Ran out of stamina, ending the adventure
+    Exhausted,
+}
+
 /// Represents an active choice screen within an adventure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum AdventureChoiceScreen {
@@ -131,6 +217,8 @@ pub enum AdventureChoiceScreen {
     AdventureOver,
     /// Pick one card of a set of draft options
     Draft(TilePosition),
+    /// View the outcome of a random event
+    Event(TilePosition),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -138,16 +226,43 @@ pub struct AdventureConfiguration {
     pub player_id: PlayerId,
     /// Side the user is playing as in this adventure
     pub side: Side,
+    /// School of the identity the user has chosen for this adventure, if any.
+    /// Card rewards are restricted to this school and [School::Neutral] when
+    /// specified.
+    #[serde(default)]
+    pub school: Option<School>,
     /// Optionally, a random number generator for this adventure to use. This
     /// generator is serializable, so the state will be deterministic even
     /// across different sessions. If not specified, `rand::thread_rng()` is
     /// used instead and behavior is not deterministic.
     pub rng: Option<Xoshiro256StarStar>,
+    /// Seed used to initialize `rng` for a seeded run, if any. Adventures
+    /// started with the same seed and side generate identical maps and card
+    /// offers, and their results are recorded on a per-seed leaderboard.
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// If true, every tile on the map is visible regardless of exploration
+    /// state. Intended for testing.
+    #[serde(default)]
+    pub reveal_full_map: bool,
 }
 
 impl AdventureConfiguration {
     pub fn new(player_id: PlayerId, side: Side) -> Self {
-        Self { player_id, side, rng: None }
+        Self { player_id, side, school: None, rng: None, seed: None, reveal_full_map: false }
+    }
+
+    /// Creates a configuration which uses a deterministic random number
+    /// generator initialized from `seed`. Unlike [Self::seeded], results are
+    /// not recorded on a leaderboard.
+    pub fn deterministic(player_id: PlayerId, side: Side, seed: u64) -> Self {
+        Self { rng: Some(Xoshiro256StarStar::seed_from_u64(seed)), ..Self::new(player_id, side) }
+    }
+
+    /// Creates a configuration for a seeded run which uses a deterministic
+    /// random number generator initialized from `seed`.
+    pub fn seeded(player_id: PlayerId, side: Side, seed: u64) -> Self {
+        Self { seed: Some(seed), ..Self::deterministic(player_id, side, seed) }
     }
 
     pub fn choose<I>(&mut self, iterator: I) -> Option<I::Item>
@@ -172,6 +287,21 @@ impl AdventureConfiguration {
         }
     }
 
+    /// Picks an element of `items` at random, where the probability of each
+    /// element being selected is proportional to the value returned by
+    /// `weight`. Returns None if `items` is empty or all weights are zero.
+    pub fn choose_weighted<'a, T>(
+        &mut self,
+        items: &'a [T],
+        weight: impl Fn(&T) -> u32,
+    ) -> Option<&'a T> {
+        if self.rng.is_some() {
+            items.choose_weighted(self.rng.as_mut().expect("rng"), weight).ok()
+        } else {
+            items.choose_weighted(&mut rand::thread_rng(), weight).ok()
+        }
+    }
+
     pub fn gen_range<T, R>(&mut self, range: R) -> T
     where
         T: SampleUniform,
@@ -193,6 +323,10 @@ pub struct AdventureState {
     pub side: Side,
     /// Coin count, used to purchase more cards for deck
     pub coins: Coins,
+    /// Remaining stamina, spent to explore and take tile actions. The
+    /// adventure ends when this reaches zero.
+    #[serde(default = "default_stamina")]
+    pub stamina: u32,
     /// Currently active mandatory choice screen, if any.
     pub choice_screen: Option<AdventureChoiceScreen>,
     /// States of world map tiles
@@ -206,6 +340,15 @@ pub struct AdventureState {
     /// Cards collected by this player during this adventure
     #[serde_as(as = "Vec<(_, _)>")]
     pub collection: HashMap<CardName, u32>,
+    /// Number of consecutive drafts this player has completed without being
+    /// offered a card of Rare or better rarity. Used to guarantee that rare
+    /// cards are eventually offered.
+    #[serde(default)]
+    pub draft_pity: u32,
+    /// Chronological record of notable events which have occurred during this
+    /// adventure
+    #[serde(default)]
+    pub journal: Vec<JournalEntry>,
     /// Customization options for this adventure
     pub config: AdventureConfiguration,
 }
@@ -231,4 +374,33 @@ impl AdventureState {
     pub fn tile_entity_mut(&mut self, position: TilePosition) -> Result<&mut TileEntity> {
         self.tile_mut(position)?.entity.as_mut().with_error(|| "Expected tile entity")
     }
+
+    /// Returns true if the given tile is in a region the player can see.
+    /// Tiles in visible regions may still be hidden by fog of war, see
+    /// [Self::is_revealed].
+    pub fn is_visible(&self, tile: &TileState) -> bool {
+        self.config.reveal_full_map || self.revealed_regions.contains(&tile.region_id)
+    }
+
+    /// Returns true if the given tile is visible and not covered by fog of
+    /// war.
+    pub fn is_revealed(&self, tile: &TileState) -> bool {
+        self.config.reveal_full_map || (self.is_visible(tile) && !tile.hidden)
+    }
+
+    /// Removes fog of war from all tiles in visible regions within `radius`
+    /// steps of `center`.
+    pub fn reveal(&mut self, center: TilePosition, radius: u32) {
+        for (position, tile) in self.tiles.iter_mut() {
+            if self.revealed_regions.contains(&tile.region_id)
+                && center.distance(*position) <= radius
+            {
+                tile.hidden = false;
+            }
+        }
+    }
+}
+
+fn default_stamina() -> u32 {
+    STARTING_STAMINA
 }
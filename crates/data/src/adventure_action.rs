@@ -34,6 +34,11 @@ pub enum AdventureAction {
     VisitShop(TilePosition),
     /// Draft the purchase at the indicated index on a shop screen
     BuyCard(TilePosition, usize),
+    /// Perform the action for the entity at the indicated position, e.g.
+    /// applying the effects of a random event
+    TileAction(TilePosition),
+    /// Close the random event outcome screen
+    DismissEvent,
 }
 
 impl From<AdventureAction> for UserAction {
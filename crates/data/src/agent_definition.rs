@@ -16,6 +16,9 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::game_actions::GameAction;
+use crate::primitives::{ActionCount, CardType, ManaValue, RoomId, Side, TurnNumber};
+
 /// Identifies different possible Game State Predictors. See the 'agents' crate
 /// for more information.
 #[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq, Hash)]
@@ -40,3 +43,75 @@ pub struct AgentData {
     pub name: AgentName,
     pub state_predictor: GameStatePredictorName,
 }
+
+/// Record of the candidate actions an AI agent considered before acting,
+/// stored in [crate::game::GameState::agent_log] when
+/// [crate::game::GameConfiguration::explain_agents] is enabled.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AgentExplanation {
+    pub turn: TurnNumber,
+    pub side: Side,
+    /// Debug name of the agent
+    pub agent: String,
+    pub chosen: GameAction,
+    /// Candidate actions and their scores, where higher scores are better for
+    /// the acting player.
+    pub candidates: Vec<(GameAction, f64)>,
+}
+
+/// Condition under which a [ScriptRule] applies, evaluated from the perspective
+/// of the scripted player.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub enum ScriptCondition {
+    Always,
+    ManaAtLeast(ManaValue),
+    ActionsAtLeast(ActionCount),
+    HandSizeAtLeast(usize),
+}
+
+/// Kind of game action selected by a [ScriptRule].
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub enum ScriptedAction {
+    /// Play the card of this type in hand with the lowest mana cost
+    PlayCheapest(CardType),
+    /// Play the card of this type in hand with the highest mana cost
+    PlayMostExpensive(CardType),
+    /// Initiate a raid on this room
+    Raid(RoomId),
+    /// Level up the room with the most level counters already on it
+    LevelUpRoom,
+    DrawCard,
+    GainMana,
+}
+
+/// One entry in the decision table for a scripted agent. Rules are checked in
+/// order, and the first rule whose condition is met and whose action is
+/// currently legal is taken.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ScriptRule {
+    pub condition: ScriptCondition,
+    pub action: ScriptedAction,
+}
+
+impl ScriptRule {
+    pub const fn new(condition: ScriptCondition, action: ScriptedAction) -> Self {
+        Self { condition, action }
+    }
+}
+
+/// Adventure boss which defends its rooms with the cheapest available minions
+/// and then advances schemes.
+pub const WARDEN: &[ScriptRule] = &[
+    ScriptRule::new(ScriptCondition::Always, ScriptedAction::PlayCheapest(CardType::Minion)),
+    ScriptRule::new(ScriptCondition::Always, ScriptedAction::PlayCheapest(CardType::Scheme)),
+    ScriptRule::new(ScriptCondition::ManaAtLeast(1), ScriptedAction::LevelUpRoom),
+    ScriptRule::new(ScriptCondition::Always, ScriptedAction::GainMana),
+];
+
+/// Adventure boss which saves up mana and then raids the Sanctum.
+pub const RAIDER: &[ScriptRule] = &[
+    ScriptRule::new(ScriptCondition::ManaAtLeast(8), ScriptedAction::Raid(RoomId::Sanctum)),
+    ScriptRule::new(ScriptCondition::Always, ScriptedAction::PlayCheapest(CardType::Weapon)),
+    ScriptRule::new(ScriptCondition::HandSizeAtLeast(5), ScriptedAction::GainMana),
+    ScriptRule::new(ScriptCondition::Always, ScriptedAction::DrawCard),
+];
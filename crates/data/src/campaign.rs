@@ -0,0 +1,94 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Meta-progression which persists across adventures. Completing adventures
+//! unlocks new cards and starting identities.
+
+use serde::{Deserialize, Serialize};
+
+use crate::card_name::CardName;
+use crate::primitives::{School, Side};
+
+/// A reward granted for completing adventures
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Unlock {
+    /// Copies of a card added to the player's collection
+    Card(CardName, u32),
+    /// A starting identity for future adventures on the given side
+    Identity(Side, School),
+}
+
+impl Unlock {
+    pub fn description(&self) -> String {
+        match self {
+            Unlock::Card(card, quantity) => format!("{}x {}", quantity, card.displayed_name()),
+            Unlock::Identity(side, school) => format!("{school:?} {side:?} Identity"),
+        }
+    }
+}
+
+/// Unlocks granted upon completing the indicated number of adventures
+const CAMPAIGN_UNLOCKS: [(u32, Unlock); 8] = [
+    (1, Unlock::Card(CardName::Lodestone, 1)),
+    (2, Unlock::Identity(Side::Champion, School::Law)),
+    (2, Unlock::Identity(Side::Overlord, School::Law)),
+    (3, Unlock::Card(CardName::InvisibilityRing, 1)),
+    (3, Unlock::Card(CardName::TimeGolem, 1)),
+    (5, Unlock::Identity(Side::Champion, School::Shadow)),
+    (5, Unlock::Identity(Side::Overlord, School::Shadow)),
+    (8, Unlock::Identity(Side::Champion, School::Primal)),
+];
+
+/// Returns the unlocks granted when a player completes their `completed`-th
+/// adventure.
+pub fn unlocks_for(completed: u32) -> Vec<Unlock> {
+    CAMPAIGN_UNLOCKS.iter().filter(|(n, _)| *n == completed).map(|(_, unlock)| *unlock).collect()
+}
+
+/// Returns the identity card used for a starting identity. There are no
+/// neutral identities, so [School::Neutral] uses the primal identity.
+pub fn identity_card(side: Side, school: School) -> CardName {
+    match (side, school) {
+        (Side::Overlord, School::Law) => CardName::NoIdentityOverlordLaw,
+        (Side::Overlord, School::Shadow) => CardName::NoIdentityOverlordShadow,
+        (Side::Overlord, School::Primal | School::Neutral) => CardName::NoIdentityOverlordPrimal,
+        (Side::Champion, School::Law) => CardName::NoIdentityChampionLaw,
+        (Side::Champion, School::Shadow) => CardName::NoIdentityChampionShadow,
+        (Side::Champion, School::Primal | School::Neutral) => CardName::NoIdentityChampionPrimal,
+    }
+}
+
+/// A player's progress through the campaign
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CampaignProgress {
+    /// Number of adventures this player has completed
+    pub adventures_completed: u32,
+    /// Starting identities this player has unlocked, oldest first
+    pub identities: Vec<(Side, School)>,
+    /// Unlocks granted by the most recently completed adventure, displayed on
+    /// the unlock celebration panel
+    pub recent_unlocks: Vec<Unlock>,
+}
+
+impl CampaignProgress {
+    /// Identity card new adventures on the given side start with, if the
+    /// player has unlocked one. The most recent unlock is used.
+    pub fn starting_identity(&self, side: Side) -> Option<CardName> {
+        self.identities
+            .iter()
+            .rev()
+            .find(|(s, _)| *s == side)
+            .map(|(side, school)| identity_card(*side, *school))
+    }
+}
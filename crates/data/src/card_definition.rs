@@ -28,8 +28,8 @@ use crate::delegates::Delegate;
 use crate::game::GameState;
 use crate::primitives::{
     AbilityId, AbilityIndex, ActionCount, AttackValue, BreachValue, CardId, CardSubtype, CardType,
-    HealthValue, LevelValue, Lineage, ManaValue, PointsValue, Rarity, RoomId, School, ShieldValue,
-    Side, Sprite,
+    DurabilityValue, HealthValue, LevelValue, Lineage, ManaValue, PointsValue, Rarity, RoomId,
+    School, ShieldValue, Side, Sprite,
 };
 use crate::set_name::SetName;
 use crate::special_effects::{Projectile, TimedEffect};
@@ -68,14 +68,41 @@ pub struct Cost<T> {
     /// A custom cost or requirement to play this card/activate this ability.
     /// See [CustomCost].
     pub custom_cost: Option<CustomCost<T>>,
+    /// Restrictions on how often an ability can be activated. Ignored for
+    /// card costs. See [ActivationLimit].
+    pub limit: ActivationLimit,
+    /// If true, the player additionally chooses an amount of mana X to spend
+    /// when playing this card, bounded by their available mana. `mana` is paid
+    /// in addition to X. Ignored for ability costs.
+    pub variable_mana: bool,
 }
 
 impl<T> Default for Cost<T> {
     fn default() -> Self {
-        Self { mana: None, actions: 1, custom_cost: None }
+        Self {
+            mana: None,
+            actions: 1,
+            custom_cost: None,
+            limit: ActivationLimit::default(),
+            variable_mana: false,
+        }
     }
 }
 
+/// Restrictions on how frequently an activated ability can be used, tracked
+/// via the ability's [crate::card_state::AbilityState].
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct ActivationLimit {
+    /// Total number of times this ability can be activated while its card
+    /// remains in play, or None for no limit.
+    pub charges: Option<u32>,
+    /// If true, this ability can only be activated once per turn.
+    pub once_per_turn: bool,
+    /// Number of its owner's turns, including the turn in which it was
+    /// activated, during which this ability cannot be activated again.
+    pub cooldown: u32,
+}
+
 /// An activated ability used by Weapons to increase their attack value by
 /// paying a mana cost during a raid encounter. Can be used any number of times.
 #[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, Default)]
@@ -111,6 +138,9 @@ pub struct CardStats {
     pub attack_boost: Option<AttackBoost>,
     /// Level Requirement & points for scoring this card
     pub scheme_points: Option<SchemePoints>,
+    /// Number of times a weapon can be used to defeat a minion before it is
+    /// discarded. Weapons without durability can be used any number of times.
+    pub durability: Option<DurabilityValue>,
 }
 
 pub type RoomPredicate<T> = fn(&GameState, T, RoomId) -> bool;
@@ -147,8 +177,21 @@ pub enum AbilityType {
     /// Activated abilities have an associated cost in order to be used.
     Activated(Cost<AbilityId>, TargetRequirement<AbilityId>),
 
+    /// Abilities which the Champion can activate during a raid encounter by
+    /// paying their cost, targeting one of their weapons. Offered alongside
+    /// weapon choices in the encounter prompt. Can be activated once per
+    /// encounter.
+    EncounterActivated(Cost<AbilityId>),
+
     /// Abilities which have no effect, but simply provide additional card text.
     TextOnly,
+
+    /// One of the options of a modal card. When a card with modes is played,
+    /// its controller chooses exactly one mode via a prompt before the card
+    /// resolves, and the chosen mode is recorded in the
+    /// [crate::delegates::CardPlayed] event. The provided string is used as the
+    /// label for the button which selects this mode.
+    Mode(&'static str),
 }
 
 /// Abilities are the unit of action in Spelldawn. Their behavior is provided by
@@ -209,4 +252,11 @@ impl CardDefinition {
     pub fn ability_ids(&self, card_id: CardId) -> impl Iterator<Item = AbilityId> {
         (0..self.abilities.len()).map(move |i| AbilityId::new(card_id, i))
     }
+
+    /// Iterator over the [AbilityId]s of the [AbilityType::Mode] abilities of a
+    /// card, in order.
+    pub fn mode_ids(&self, card_id: CardId) -> impl Iterator<Item = AbilityId> + '_ {
+        self.ability_ids(card_id)
+            .filter(|id| matches!(self.ability(id.index).ability_type, AbilityType::Mode(_)))
+    }
 }
@@ -83,6 +83,38 @@ pub enum CardName {
     TestMinionDealDamageEndRaid,
     TestCardStoredMana,
     TestAttackWeapon,
+    /// Champion spell which puts any card from the Champion's deck into their
+    /// hand
+    TestChampionTutor,
+    /// Overlord spell which puts any card from the Overlord's deck into their
+    /// hand
+    TestOverlordTutor,
+    /// Artifact with an activated ability to gain 1 mana which can be used
+    /// twice
+    TestActivatedAbilityCharges,
+    /// Artifact with an activated ability to gain 1 mana which cannot be used
+    /// again until the Champion's next turn after the following one
+    TestActivatedAbilityCooldown,
+    /// Project which costs 1 mana to unveil and has an upkeep cost of 1 mana
+    TestProjectUpkeep,
+    /// Overlord global modifier which makes raids cost 1 additional action
+    TestGlobalModifierRaidCost,
+    /// Champion global modifier which increases each player's maximum hand
+    /// size by 2
+    TestGlobalModifierHandSize,
+    /// Champion spell with the Reaction subtype which costs 1 mana and gains 2
+    /// mana when played
+    TestReaction,
+    /// Reserved names for cards defined at runtime by tests, see
+    /// `rules::synthetic_cards`
+    TestSynthetic1,
+    TestSynthetic2,
+    TestSynthetic3,
+    TestSynthetic4,
+    TestSynthetic5,
+    TestSynthetic6,
+    TestSynthetic7,
+    TestSynthetic8,
 
     // No Identity
     NoIdentityOverlordLaw,
@@ -92,6 +124,10 @@ pub enum CardName {
     NoIdentityChampionShadow,
     NoIdentityChampionPrimal,
 
+    // Adventure Bosses
+    TheWarden,
+    TheRaider,
+
     // Playtest 0
     ArcaneRecovery,
     Lodestone,
@@ -126,6 +162,30 @@ pub enum CardName {
     BridgeTroll,
     Stormcaller,
     FireGoblin,
+
+    // Cost Auras
+    ForgeHammer,
+    ChannelingOrb,
+    SummoningCircle,
+    IronTithe,
+
+    // Sweepers
+    Corrosion,
+    Sunburst,
+
+    // Control
+    Embezzle,
+
+    // Modal
+    Crossroads,
+
+    // Variable cost
+    Torment,
+
+    // Allies
+    LoyalSquire,
+    DevotedAcolyte,
+    TempleGuardian,
 }
 
 impl CardName {
@@ -150,6 +210,22 @@ impl CardName {
         self.displayed_name().starts_with("Test")
     }
 
+    /// Returns true if this is a reserved name for a card defined at runtime
+    /// by a test
+    pub fn is_synthetic(&self) -> bool {
+        matches!(
+            self,
+            Self::TestSynthetic1
+                | Self::TestSynthetic2
+                | Self::TestSynthetic3
+                | Self::TestSynthetic4
+                | Self::TestSynthetic5
+                | Self::TestSynthetic6
+                | Self::TestSynthetic7
+                | Self::TestSynthetic8
+        )
+    }
+
     pub fn is_null_identity(&self) -> bool {
         self.displayed_name().starts_with("No Identity")
     }
@@ -26,7 +26,8 @@ use crate::card_name::CardName;
 use crate::game::TurnData;
 use crate::game_actions::CardTarget;
 use crate::primitives::{
-    BoostCount, CardId, ItemLocation, LevelValue, ManaValue, RaidId, RoomId, RoomLocation, Side,
+    BoostCount, CardId, DurabilityValue, HealthValue, ItemLocation, LevelValue, ManaValue, RaidId,
+    RoomId, RoomLocation, Side,
 };
 
 /// State for an ability within a game
@@ -37,6 +38,23 @@ pub struct AbilityState {
     pub currently_resolving: bool,
     pub raid_id: Option<RaidId>,
     pub turn: Option<TurnData>,
+    /// Number of times this ability has been activated since its card entered
+    /// play, used to track remaining charges.
+    #[serde(default)]
+    pub charges_spent: u32,
+    /// True if this ability has been activated during the current turn.
+    /// Cleared at the start of each turn.
+    #[serde(default)]
+    pub used_this_turn: bool,
+    /// Number of its owner's turns remaining before this ability can be
+    /// activated again.
+    #[serde(default)]
+    pub cooldown: u32,
+    /// Weapon targeted by this ability during the current raid encounter, if
+    /// it is an encounter ability which has been activated. Cleared when the
+    /// encounter ends.
+    #[serde(default)]
+    pub encounter_weapon: Option<CardId>,
 }
 
 /// Identifies the location of a card during an active game
@@ -65,6 +83,12 @@ pub enum CardPosition {
     /// Marks the identity card for a side. The first identity (by sorting key)
     /// is the primary identity for a player.
     Identity(Side),
+    /// The shared slot for a global modifier card which affects both players.
+    /// At most one card can occupy this position at a time.
+    GlobalModifier,
+    /// A token card which has left play and ceased to exist. Cards in this
+    /// position are not displayed and their delegates are not invoked.
+    Banished,
 }
 
 impl CardPosition {
@@ -73,9 +97,13 @@ impl CardPosition {
         self.into()
     }
 
-    /// Returns true if this card is in a room or has been played as an item
+    /// Returns true if this card is in a room, has been played as an item, or
+    /// is the current global modifier
     pub fn in_play(&self) -> bool {
-        matches!(self.kind(), CardPositionKind::Room | CardPositionKind::ArenaItem)
+        matches!(
+            self.kind(),
+            CardPositionKind::Room | CardPositionKind::ArenaItem | CardPositionKind::GlobalModifier
+        )
     }
 
     /// Returns true if this card is in a room
@@ -117,6 +145,11 @@ impl CardPosition {
         self.kind() == CardPositionKind::Scored
     }
 
+    /// Returns true if this card is a token which has ceased to exist
+    pub fn is_banished(&self) -> bool {
+        self.kind() == CardPositionKind::Banished
+    }
+
     /// True if this card is an identity card
     pub fn is_identity(&self) -> bool {
         self.kind() == CardPositionKind::Identity
@@ -135,6 +168,24 @@ pub struct CardData {
     pub stored_mana: ManaValue,
     /// When was the last time this card entered the arena, if ever?
     pub last_entered_play: Option<TurnData>,
+    /// When was the last time this card's upkeep cost was paid, if ever?
+    #[serde(default)]
+    pub last_upkeep: Option<TurnData>,
+    /// How many points of durability has this weapon spent since entering
+    /// play?
+    #[serde(default)]
+    pub durability_spent: DurabilityValue,
+    /// How many points of damage has this ally absorbed since entering play?
+    #[serde(default)]
+    pub damage_absorbed: HealthValue,
+    /// Player who currently controls this card, if it is not controlled by its
+    /// owner. Reset when the card leaves play.
+    #[serde(default)]
+    pub controller: Option<Side>,
+    /// Is this card a temporary copy of another card, created during the game?
+    /// Tokens cease to exist when they leave play.
+    #[serde(default)]
+    pub is_token: bool,
     /// Is this card face-up?
     is_face_up: bool,
     /// Is this card revealed to the [CardId.side] user?
@@ -182,10 +233,19 @@ impl CardState {
         }
     }
 
+    /// Player who owns this card. Cards always move to their owner's hand,
+    /// deck, discard pile, or score pile.
     pub fn side(&self) -> Side {
         self.id.side
     }
 
+    /// Player who currently controls this card, who can activate its
+    /// abilities and receives the benefits of its effects. This is the owner
+    /// unless control has been changed via `mutations::change_control`.
+    pub fn controller(&self) -> Side {
+        self.data.controller.unwrap_or(self.id.side)
+    }
+
     /// Where this card is located in the game.
     pub fn position(&self) -> CardPosition {
         self.position
@@ -0,0 +1,109 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Aggregate statistics describing how individual cards perform across
+//! completed games, used for balance analysis. Only games played by players
+//! who have enabled [crate::player_data::PlayerSettings::share_card_statistics]
+//! contribute to these statistics.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::card_name::CardName;
+use crate::game::GameState;
+use crate::history::HistoryEvent;
+use crate::primitives::{Side, TurnNumber};
+
+/// Aggregate statistics for a single card
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CardStatistics {
+    pub card_name: CardName,
+    /// Number of games in which a player drew at least one copy of this card
+    pub games_drawn: u32,
+    /// Number of those games which that player went on to win
+    pub wins_when_drawn: u32,
+    /// Number of times this card was played from hand
+    pub times_played: u32,
+    /// Sum of the turn numbers on which this card was played
+    pub total_turn_played: u64,
+}
+
+impl CardStatistics {
+    /// Statistics for a card which has not appeared in any recorded game
+    pub fn new(card_name: CardName) -> Self {
+        Self {
+            card_name,
+            games_drawn: 0,
+            wins_when_drawn: 0,
+            times_played: 0,
+            total_turn_played: 0,
+        }
+    }
+
+    /// Adds one player's usage of this card in a completed game
+    pub fn record(&mut self, usage: &CardUsage, won: bool) {
+        if usage.drawn {
+            self.games_drawn += 1;
+            if won {
+                self.wins_when_drawn += 1;
+            }
+        }
+        self.times_played += usage.turns_played.len() as u32;
+        self.total_turn_played += usage.turns_played.iter().map(|t| *t as u64).sum::<u64>();
+    }
+
+    /// Fraction of games won when this card was drawn, between 0.0 and 1.0.
+    /// None if the card has never been drawn.
+    pub fn win_rate_when_drawn(&self) -> Option<f64> {
+        (self.games_drawn > 0).then(|| self.wins_when_drawn as f64 / self.games_drawn as f64)
+    }
+
+    /// Mean turn number on which this card was played. None if the card has
+    /// never been played.
+    pub fn average_turn_played(&self) -> Option<f64> {
+        (self.times_played > 0).then(|| self.total_turn_played as f64 / self.times_played as f64)
+    }
+}
+
+/// Describes how one player used a card during a single game
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct CardUsage {
+    /// True if the player drew at least one copy of this card
+    pub drawn: bool,
+    /// Turn numbers on which a copy of this card was played
+    pub turns_played: Vec<TurnNumber>,
+}
+
+/// Summarizes the `side` player's usage of each of their cards from the
+/// game's history. Cards which were neither drawn nor played are omitted.
+pub fn card_usage(game: &GameState, side: Side) -> HashMap<CardName, CardUsage> {
+    let mut result = HashMap::<CardName, CardUsage>::new();
+    for entry in &game.history {
+        match entry.event {
+            HistoryEvent::DrawCard(s, card_id) if s == side => {
+                result.entry(game.card(card_id).name).or_default().drawn = true;
+            }
+            HistoryEvent::PlayCard(s, card_id) if s == side => {
+                result
+                    .entry(game.card(card_id).name)
+                    .or_default()
+                    .turns_played
+                    .push(entry.turn.turn_number);
+            }
+            _ => {}
+        }
+    }
+    result
+}
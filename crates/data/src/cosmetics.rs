@@ -0,0 +1,236 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cosmetic customizations which change the appearance of game events without
+//! affecting game rules.
+
+use std::collections::HashSet;
+
+use enum_iterator::Sequence;
+use serde::{Deserialize, Serialize};
+
+use crate::primitives::School;
+use crate::special_effects::{FantasyEventSounds, FireworksSound, SoundEffect, TimedEffect};
+
+/// Alternate fanfare displayed when the owner of a deck wins a game
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum VictoryEffect {
+    Fireworks,
+    ArcaneNova,
+}
+
+impl VictoryEffect {
+    pub fn displayed_name(&self) -> &'static str {
+        match self {
+            VictoryEffect::Fireworks => "Fireworks",
+            VictoryEffect::ArcaneNova => "Arcane Nova",
+        }
+    }
+
+    pub fn timed_effect(&self) -> TimedEffect {
+        match self {
+            VictoryEffect::Fireworks => TimedEffect::HovlMagicHit(4),
+            VictoryEffect::ArcaneNova => TimedEffect::HovlMagicHit(11),
+        }
+    }
+
+    pub fn sound(&self) -> SoundEffect {
+        match self {
+            VictoryEffect::Fireworks => SoundEffect::Fireworks(FireworksSound::RocketExplodeLarge),
+            VictoryEffect::ArcaneNova => SoundEffect::FantasyEvents(FantasyEventSounds::Positive1),
+        }
+    }
+}
+
+/// Alternate effect displayed when the owner of a deck initiates a raid
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum RaidEffect {
+    Lightning,
+    SwordStorm,
+}
+
+impl RaidEffect {
+    pub fn displayed_name(&self) -> &'static str {
+        match self {
+            RaidEffect::Lightning => "Lightning",
+            RaidEffect::SwordStorm => "Sword Storm",
+        }
+    }
+
+    pub fn timed_effect(&self) -> TimedEffect {
+        match self {
+            RaidEffect::Lightning => TimedEffect::HovlMagicHit(7),
+            RaidEffect::SwordStorm => TimedEffect::HovlSwordSlash(2),
+        }
+    }
+}
+
+/// Identifies a cosmetic item which a player can own
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum Cosmetic {
+    Victory(VictoryEffect),
+    Raid(RaidEffect),
+}
+
+impl Cosmetic {
+    pub fn displayed_name(&self) -> &'static str {
+        match self {
+            Cosmetic::Victory(effect) => effect.displayed_name(),
+            Cosmetic::Raid(effect) => effect.displayed_name(),
+        }
+    }
+
+    /// Short text describing when this cosmetic is displayed
+    pub fn description(&self) -> &'static str {
+        match self {
+            Cosmetic::Victory(_) => "Victory fanfare, displayed when you win a game",
+            Cosmetic::Raid(_) => "Raid effect, displayed when you initiate a raid",
+        }
+    }
+}
+
+/// Cosmetic items equipped for a given deck. Items which are not specified use
+/// the standard effects.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct DeckCosmetics {
+    pub victory_effect: Option<VictoryEffect>,
+    pub raid_effect: Option<RaidEffect>,
+}
+
+impl DeckCosmetics {
+    /// Returns true if `cosmetic` is currently equipped.
+    pub fn is_equipped(&self, cosmetic: Cosmetic) -> bool {
+        match cosmetic {
+            Cosmetic::Victory(effect) => self.victory_effect == Some(effect),
+            Cosmetic::Raid(effect) => self.raid_effect == Some(effect),
+        }
+    }
+
+    /// Equips the provided [Cosmetic], replacing any existing item of the same
+    /// type.
+    pub fn equip(&mut self, cosmetic: Cosmetic) {
+        match cosmetic {
+            Cosmetic::Victory(effect) => self.victory_effect = Some(effect),
+            Cosmetic::Raid(effect) => self.raid_effect = Some(effect),
+        }
+    }
+
+    /// Removes the provided [Cosmetic] if it is currently equipped.
+    pub fn unequip(&mut self, cosmetic: Cosmetic) {
+        if self.is_equipped(cosmetic) {
+            match cosmetic {
+                Cosmetic::Victory(_) => self.victory_effect = None,
+                Cosmetic::Raid(_) => self.raid_effect = None,
+            }
+        }
+    }
+}
+
+/// Alternate design for the backs of a player's cards
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize, Deserialize, Sequence,
+)]
+pub enum CardBack {
+    Steampunk,
+    Lovecraft,
+    Daemon,
+    Elf,
+    Gilded,
+    Abyssal,
+}
+
+impl CardBack {
+    pub fn displayed_name(&self) -> &'static str {
+        match self {
+            CardBack::Steampunk => "Steampunk",
+            CardBack::Lovecraft => "Lovecraft",
+            CardBack::Daemon => "Daemon",
+            CardBack::Elf => "Elf",
+            CardBack::Gilded => "Gilded",
+            CardBack::Abyssal => "Abyssal",
+        }
+    }
+
+    /// Card back displayed for a player whose identity belongs to the
+    /// indicated [School] if they have not selected one.
+    pub fn for_school(school: School) -> Self {
+        match school {
+            School::Law => CardBack::Steampunk,
+            School::Neutral => CardBack::Lovecraft,
+            School::Shadow => CardBack::Daemon,
+            School::Primal => CardBack::Elf,
+        }
+    }
+
+    /// Standard card backs are available to all players without being
+    /// unlocked.
+    pub fn is_standard(&self) -> bool {
+        !matches!(self, CardBack::Gilded | CardBack::Abyssal)
+    }
+}
+
+/// Alternate backdrop displayed behind a player's side of the arena
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize, Deserialize, Sequence,
+)]
+pub enum BoardSkin {
+    Library,
+    Storefront,
+    SnowMountain,
+}
+
+impl BoardSkin {
+    pub fn displayed_name(&self) -> &'static str {
+        match self {
+            BoardSkin::Library => "Library",
+            BoardSkin::Storefront => "Storefront",
+            BoardSkin::SnowMountain => "Snow Mountain",
+        }
+    }
+
+    /// Standard board skins are available to all players without being
+    /// unlocked.
+    pub fn is_standard(&self) -> bool {
+        matches!(self, BoardSkin::Library)
+    }
+}
+
+/// Card back and board skin selected by a player. Items which are not
+/// specified use the standard appearance.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct PlayerAppearance {
+    pub card_back: Option<CardBack>,
+    pub board_skin: Option<BoardSkin>,
+}
+
+/// Card backs and board skins a player has unlocked, along with their current
+/// selection.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PlayerCosmetics {
+    pub card_backs: HashSet<CardBack>,
+    pub board_skins: HashSet<BoardSkin>,
+    pub equipped: PlayerAppearance,
+}
+
+impl PlayerCosmetics {
+    /// Returns true if the player can equip the provided [CardBack].
+    pub fn owns_card_back(&self, card_back: CardBack) -> bool {
+        card_back.is_standard() || self.card_backs.contains(&card_back)
+    }
+
+    /// Returns true if the player can equip the provided [BoardSkin].
+    pub fn owns_board_skin(&self, board_skin: BoardSkin) -> bool {
+        board_skin.is_standard() || self.board_skins.contains(&board_skin)
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Dust is earned by disenchanting cards and spent to craft new cards for a
+//! player's collection.
+
+use derive_more::{Add, AddAssign, Display, From, Into, Sub, SubAssign};
+use serde::{Deserialize, Serialize};
+
+use crate::primitives::Rarity;
+
+/// Maximum number of copies of a card a player can own via crafting
+pub const MAX_CRAFTED_COPIES: u32 = 3;
+
+/// Number of cards shown on each page of the crafting panel
+pub const CRAFTING_PAGE_SIZE: usize = 8;
+
+/// Currency for crafting cards
+#[derive(
+    Debug,
+    Display,
+    Copy,
+    Default,
+    Clone,
+    PartialEq,
+    Eq,
+    Ord,
+    PartialOrd,
+    From,
+    Add,
+    Sub,
+    AddAssign,
+    SubAssign,
+    Into,
+    Serialize,
+    Deserialize,
+)]
+pub struct Dust(pub u32);
+
+/// Dust required to craft one copy of a card of the given [Rarity], or None if
+/// cards of this rarity cannot be crafted.
+pub fn craft_cost(rarity: Rarity) -> Option<Dust> {
+    match rarity {
+        Rarity::Common => Some(Dust(40)),
+        Rarity::Rare => Some(Dust(100)),
+        Rarity::Exalted => Some(Dust(400)),
+        Rarity::Epic => Some(Dust(1600)),
+        Rarity::None => None,
+    }
+}
+
+/// Dust gained by disenchanting one copy of a card of the given [Rarity], or
+/// None if cards of this rarity cannot be disenchanted.
+pub fn disenchant_value(rarity: Rarity) -> Option<Dust> {
+    match rarity {
+        Rarity::Common => Some(Dust(5)),
+        Rarity::Rare => Some(Dust(20)),
+        Rarity::Exalted => Some(Dust(100)),
+        Rarity::Epic => Some(Dust(400)),
+        Rarity::None => None,
+    }
+}
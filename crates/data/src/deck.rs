@@ -21,6 +21,7 @@ use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
 use crate::card_name::CardName;
+use crate::cosmetics::DeckCosmetics;
 use crate::player_name::PlayerId;
 use crate::primitives::{DeckIndex, Side};
 
@@ -41,6 +42,9 @@ pub struct Deck {
     /// How many (non-identity) cards with each name are present in this deck?
     #[serde_as(as = "Vec<(_, _)>")]
     pub cards: HashMap<CardName, u32>,
+    /// Cosmetic items displayed while playing with this deck
+    #[serde(default)]
+    pub cosmetics: DeckCosmetics,
 }
 
 impl Deck {
@@ -0,0 +1,39 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Debugging record of the event delegates which fired while handling the most
+//! recent game action, used to diagnose trigger-ordering bugs.
+
+use serde::{Deserialize, Serialize};
+
+use crate::primitives::AbilityId;
+
+/// A single event delegate invocation, stored in
+/// [crate::game::GameState::delegate_trace] when
+/// [crate::game::GameConfiguration::trace_delegates] is enabled.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct DelegateTraceEntry {
+    /// Debug name of the event, e.g. "Dawn"
+    pub event: String,
+    /// Ability which owns the delegate, i.e. its scope
+    pub ability_id: AbilityId,
+    /// Debug representation of the event data passed to the delegate
+    pub data: String,
+    /// Whether the delegate's requirement was met, and thus whether its
+    /// mutation ran
+    pub requirement: bool,
+    /// Description of the changes made by the delegate's mutation. None if the
+    /// mutation did not run.
+    pub mutation: Option<String>,
+}
@@ -68,18 +68,17 @@ use anyhow::Result;
 use enum_kinds::EnumKind;
 use macros::DelegateEnum;
 
-use crate::card_definition::AttackBoost;
 #[allow(unused)] // Used in rustdocs
 use crate::card_definition::CardStats;
+use crate::card_definition::{AttackBoost, Cost};
 #[allow(unused)] // Used in rustdocs
-use crate::card_definition::Cost;
-#[allow(unused)] // Used in rustdocs
-use crate::card_state::{CardData, CardPosition};
-use crate::game::GameState;
+use crate::card_state::{CardData, CardPosition, CardState};
+use crate::game::{GameState, TurnData};
 use crate::game_actions::{CardPromptAction, CardTarget, PromptAction};
 use crate::primitives::{
-    AbilityId, ActionCount, AttackValue, BoostCount, BoostData, BreachValue, CardId, HasAbilityId,
-    HasCardId, HealthValue, ManaValue, RaidId, RoomId, ShieldValue, Side, TurnNumber,
+    AbilityId, ActionCount, AttackValue, BoostCount, BoostData, BreachValue, CardId,
+    DurabilityValue, HasAbilityId, HasCardId, HealthValue, ManaValue, RaidId, RoomId, ShieldValue,
+    Side, TurnNumber,
 };
 
 /// Identifies the context for a given request to a delegate: which player,
@@ -100,6 +99,13 @@ impl Scope {
         self.card_id().side
     }
 
+    /// Player who currently controls the card which owns this scope, see
+    /// [CardState::controller]. Effects which benefit the player using a card
+    /// should apply to this player rather than [Self::side].
+    pub fn controller(&self, game: &GameState) -> Side {
+        game.card(self.card_id()).controller()
+    }
+
     /// Ability which owns this scope
     pub fn ability_id(&self) -> AbilityId {
         self.ability_id
@@ -214,6 +220,12 @@ impl From<Flag> for bool {
 pub struct CardPlayed {
     pub card_id: CardId,
     pub target: CardTarget,
+    /// Mode chosen for this card, if it is a modal card. See
+    /// [crate::card_definition::AbilityType::Mode].
+    pub mode: Option<AbilityId>,
+    /// Amount of mana paid for X, if this card has a variable mana cost. See
+    /// [crate::card_definition::Cost::variable_mana].
+    pub x: Option<ManaValue>,
 }
 
 impl HasCardId for CardPlayed {
@@ -241,6 +253,19 @@ impl HasAbilityId for AbilityActivated {
     }
 }
 
+/// Event data when an encounter ability is activated targeting a weapon
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub struct EncounterAbilityActivated {
+    pub ability_id: AbilityId,
+    pub weapon_id: CardId,
+}
+
+impl HasAbilityId for EncounterAbilityActivated {
+    fn ability_id(&self) -> AbilityId {
+        self.ability_id
+    }
+}
+
 /// Event data for when a card is moved
 #[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
 pub struct CardMoved {
@@ -250,6 +275,23 @@ pub struct CardMoved {
     pub new_position: CardPosition,
 }
 
+/// Event data when a card is destroyed as part of a group of cards which are
+/// destroyed simultaneously, see `mutations::destroy_cards`.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct CardDestroyed {
+    /// Card which was destroyed
+    pub card_id: CardId,
+    /// All cards destroyed by the same effect, including `card_id`, in the
+    /// order their events are fired.
+    pub destroyed: Vec<CardId>,
+}
+
+impl HasCardId for CardDestroyed {
+    fn card_id(&self) -> CardId {
+        self.card_id
+    }
+}
+
 /// Event data for encounters between cards
 #[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
 pub struct CardEncounter {
@@ -265,6 +307,27 @@ impl CardEncounter {
     }
 }
 
+/// Data for the Champion using two weapons together in a single encounter,
+/// splitting the attack value required to defeat the `target` minion between
+/// them.
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub struct CombinedWeapons {
+    pub first: CardId,
+    pub second: CardId,
+    pub target: CardId,
+}
+
+impl CombinedWeapons {
+    pub fn new(first: CardId, second: CardId, target: CardId) -> Self {
+        Self { first, second, target }
+    }
+
+    /// Returns true if `card_id` is one of the two weapons being combined.
+    pub fn includes(&self, card_id: CardId) -> bool {
+        self.first == card_id || self.second == card_id
+    }
+}
+
 /// Event data when a raid is initiated
 #[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
 pub struct RaidStart {
@@ -281,6 +344,12 @@ pub struct UsedWeapon {
     pub mana_spent: ManaValue,
 }
 
+impl HasCardId for UsedWeapon {
+    fn card_id(&self) -> CardId {
+        self.weapon_id
+    }
+}
+
 /// Event data when a card is scored
 #[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
 pub struct ScoreCard {
@@ -314,6 +383,64 @@ impl From<RaidEnded> for RaidId {
     }
 }
 
+/// The discrete steps of a raid, in the order in which they occur. Each step
+/// is surrounded by a [Delegate::BeforeRaidStep] and a
+/// [Delegate::AfterRaidStep] event.
+///
+/// A step which is ended early because the raid ends does not receive its
+/// `AfterRaidStep` event.
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub enum RaidStep {
+    /// The raid has been initiated. Surrounds the [Delegate::RaidStart] event.
+    Begin,
+    /// The Champion approaches the provided defender, before the Overlord
+    /// has the opportunity to summon it.
+    ApproachDefender(CardId),
+    /// The provided defender is encountered. The `AfterRaidStep` event fires
+    /// once the Champion has resolved the encounter.
+    EncounterDefender(CardId),
+    /// All defenders have been passed and the access phase is starting.
+    /// Surrounds the [Delegate::RaidAccessStart] event.
+    AccessStart,
+    /// The provided card is accessed. Surrounds the [Delegate::CardAccess]
+    /// event.
+    AccessCard(CardId),
+    /// The raid is ending with the given outcome. Surrounds the
+    /// [Delegate::RaidEnd] event.
+    End(RaidOutcome),
+}
+
+/// Event data for a [RaidStep] of an ongoing raid
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub struct RaidStepData {
+    pub raid_id: RaidId,
+    pub target: RoomId,
+    pub step: RaidStep,
+}
+
+impl From<RaidStepData> for RaidId {
+    fn from(this: RaidStepData) -> Self {
+        this.raid_id
+    }
+}
+
+/// Event data when the Champion retreats from a raid
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub struct RaidRetreat {
+    pub raid_id: RaidId,
+    pub target: RoomId,
+    /// Defender being encountered when the Champion retreated
+    pub defender_id: CardId,
+    /// Position of `defender_id` within the room's defenders
+    pub encounter: usize,
+}
+
+impl From<RaidRetreat> for RaidId {
+    fn from(this: RaidRetreat) -> Self {
+        this.raid_id
+    }
+}
+
 #[derive(PartialEq, Eq, Hash, Debug, Clone)]
 pub struct DealtDamage {
     pub source: AbilityId,
@@ -327,6 +454,23 @@ impl HasAbilityId for DealtDamage {
     }
 }
 
+/// Event data when an ally absorbs damage which would otherwise have been dealt
+/// to the Champion, see `mutations::deal_damage`.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct DamageAbsorbed {
+    /// Ally which absorbed the damage
+    pub card_id: CardId,
+    /// Ability which dealt the damage
+    pub source: AbilityId,
+    pub amount: u32,
+}
+
+impl HasCardId for DamageAbsorbed {
+    fn card_id(&self) -> CardId {
+        self.card_id
+    }
+}
+
 /// Actions to show the Champion during combat in addition to their weapon
 /// actions
 #[derive(Clone, Debug)]
@@ -345,10 +489,21 @@ pub struct MinionCombatPrompt {
 #[derive(EnumKind, DelegateEnum, Clone)]
 #[enum_kind(DelegateKind, derive(Hash))]
 pub enum Delegate {
+    /// Either player's turn begins. Invoked before [Self::Dawn] or
+    /// [Self::Dusk].
+    TurnBegin(EventDelegate<TurnData>),
     /// The Champion's turn begins
     Dawn(EventDelegate<TurnNumber>),
     /// The Overlord's turn begins
     Dusk(EventDelegate<TurnNumber>),
+    /// A player is about to draw their card for the turn. Invoked after
+    /// action points for the turn have been assigned.
+    DrawPhase(EventDelegate<TurnData>),
+    /// A player has drawn their card for the turn and can now take actions.
+    MainPhaseBegin(EventDelegate<TurnData>),
+    /// A player has no action points remaining and their turn is about to end.
+    /// Invoked before the player discards down to their maximum hand size.
+    TurnEnd(EventDelegate<TurnData>),
     /// A card is moved from a Deck position to a Hand position
     DrawCard(EventDelegate<CardId>),
     /// A user takes the explicit 'draw card' game action
@@ -360,12 +515,24 @@ pub enum Delegate {
     EnterPlay(EventDelegate<CardId>),
     /// A card ability with a cost is activated
     ActivateAbility(EventDelegate<AbilityActivated>),
+    /// An encounter ability is activated during a raid, targeting a weapon
+    ActivateEncounterAbility(EventDelegate<EncounterAbilityActivated>),
     /// A project card is unveiled (turned face up by paying its cost)
     UnveilProject(EventDelegate<CardId>),
     /// A minion card is turned face up.
     SummonMinion(EventDelegate<CardId>),
     /// A card is moved to a new position
     MoveCard(EventDelegate<CardMoved>),
+    /// Control of a card in play has changed, see [CardState::controller].
+    ControlChanged(EventDelegate<CardId>),
+    /// A card has been destroyed and moved to its owner's discard pile. Invoked
+    /// once per card after every card destroyed by the same effect has left
+    /// play.
+    DestroyCard(EventDelegate<CardDestroyed>),
+    /// The destruction of a card has been replaced, as determined by
+    /// [Delegate::ShouldReplaceDestruction]. Invoked instead of moving the card
+    /// to the discard pile; the card remains in play.
+    ReplaceDestruction(EventDelegate<CardId>),
     /// A card is scored by the Overlord
     OverlordScoreCard(EventDelegate<CardId>),
     /// A card is scored by the Champion
@@ -395,10 +562,21 @@ pub enum Delegate {
     /// The card with the provided `card_id` has been accessed during a raid (in
     /// any zone), but not yet scored/acted on.
     CardAccess(EventDelegate<CardId>),
+    /// The Champion's access for a raid has been replaced, as determined by
+    /// [Delegate::ShouldReplaceAccess]. Invoked instead of accessing any cards,
+    /// after which the raid ends in success.
+    ReplaceAccess(EventDelegate<RaidId>),
     /// A Raid is completed, either successfully or unsuccessfully.
     ///
     /// Note that this is invoked before `game.data.raid` is cleared.
     RaidEnd(EventDelegate<RaidEnded>),
+    /// A [RaidStep] of an ongoing raid is about to occur.
+    BeforeRaidStep(EventDelegate<RaidStepData>),
+    /// A [RaidStep] of an ongoing raid has completed.
+    AfterRaidStep(EventDelegate<RaidStepData>),
+    /// The Champion has retreated from an encounter. Invoked before the raid
+    /// ends in failure.
+    ChampionRetreat(EventDelegate<RaidRetreat>),
     /// A raid has ended in failure.
     RaidFailure(EventDelegate<RaidId>),
     /// A raid has ended in success.
@@ -408,6 +586,9 @@ pub enum Delegate {
     /// Damage has been dealt to the Champion player (in the form of discarded
     /// cards).
     DealtDamage(EventDelegate<DealtDamage>),
+    /// An ally has absorbed damage instead of the Champion player, as
+    /// determined by [Delegate::CanAbsorbDamage].
+    DamageAbsorbed(EventDelegate<DamageAbsorbed>),
 
     /// Query whether the indicated player can currently take the basic game
     /// action to spend an action point to draw a card.
@@ -432,19 +613,43 @@ pub enum Delegate {
     ///
     /// Note that Scheme cards can be leveled up by default.
     CanLevelUpCard(QueryDelegate<CardId, Flag>),
+    /// Can the indicated face-down project card currently be unveiled by paying
+    /// its cost?
+    CanUnveil(QueryDelegate<CardId, Flag>),
     /// Can the source card (typically a weapon) take an encounter action
     /// against the target card (typically a minion) during a raid?
     CanEncounterTarget(QueryDelegate<CardEncounter, Flag>),
     /// Can the source card (typically a weapon) apply an encounter
     /// action to defeat the target target (typically a minion) during a raid?
     CanDefeatTarget(QueryDelegate<CardEncounter, Flag>),
-
-    /// Query the current mana cost of a card. Invoked with [Cost::mana].
+    /// Can the Champion use two weapons together to defeat a minion during a
+    /// raid, splitting its health between them? Invoked with false.
+    CanCombineWeapons(QueryDelegate<CombinedWeapons, Flag>),
+    /// Should the Champion's access during this raid be replaced with the
+    /// [Delegate::ReplaceAccess] event? Invoked with false.
+    ShouldReplaceAccess(QueryDelegate<RaidId, Flag>),
+    /// Should the destruction of this card be replaced with the
+    /// [Delegate::ReplaceDestruction] event? Invoked with false.
+    ShouldReplaceDestruction(QueryDelegate<CardId, Flag>),
+    /// Can this card currently absorb damage which would be dealt to the
+    /// Champion? Invoked with true for face-up allies in play which have
+    /// health remaining.
+    CanAbsorbDamage(QueryDelegate<CardId, Flag>),
+
+    /// Query the net change to the mana cost of a card from cost-modification
+    /// effects. Invoked with 0. Adjustments from all sources are summed before
+    /// being added to [Cost::mana], and the result cannot be less than zero.
+    ManaCostAdjustment(QueryDelegate<CardId, i32>),
+    /// Query the current mana cost of a card. Invoked with [Cost::mana] after
+    /// applying [Self::ManaCostAdjustment].
     ManaCost(QueryDelegate<CardId, Option<ManaValue>>),
     /// Query the current mana cost of an ability. Invoked with [Cost::mana].
     AbilityManaCost(QueryDelegate<AbilityId, Option<ManaValue>>),
     /// Query the current mana cost of a card. Invoked with [Cost::actions].
     ActionCost(QueryDelegate<CardId, ActionCount>),
+    /// Query the mana a face-up project must pay at the start of each Overlord
+    /// turn to remain in play. Invoked with None.
+    UpkeepCost(QueryDelegate<CardId, Option<ManaValue>>),
     /// Query the current attack value of a card. Invoked with
     /// [CardStats::base_attack] or 0.
     AttackValue(QueryDelegate<CardId, AttackValue>),
@@ -460,17 +665,36 @@ pub enum Delegate {
     /// Gets the current [AttackBoost] of a card. Invoked with
     /// [CardStats::attack_boost] if one is present.
     AttackBoost(QueryDelegate<CardId, AttackBoost>),
+    /// Gets the current durability of a weapon. Invoked with
+    /// [CardStats::durability] if one is present. Increasing this value
+    /// restores uses of the weapon.
+    Durability(QueryDelegate<CardId, DurabilityValue>),
+    /// Should using a weapon spend one point of its durability? Invoked with
+    /// true for weapons with durability.
+    ConsumesDurability(QueryDelegate<UsedWeapon, Flag>),
     /// Get the current boost count of a card. Invoked with the value of
     /// [CardData::boost_count].
     BoostCount(QueryDelegate<CardId, BoostCount>),
     /// Get the number of actions a player gets at the start of their turn.
     StartOfTurnActions(QueryDelegate<Side, ActionCount>),
+    /// Get the cost the Champion must pay to take the basic game action to
+    /// initiate a raid on the target room. Invoked with a [Cost] of 1 action
+    /// and no mana. A `custom_cost` replaces any custom cost added by a
+    /// previous delegate.
+    InitiateRaidCost(QueryDelegate<RoomId, Cost<RoomId>>),
     /// Gets the number of cards the Champion player can access from the Vault
     /// during this raid
     VaultAccessCount(QueryDelegate<RaidId, u32>),
     /// Gets the number of cards the Champion player can access from the Sanctum
     /// during this raid
     SanctumAccessCount(QueryDelegate<RaidId, u32>),
+    /// Gets the number of cards the Champion player can access from the Crypts
+    /// during this raid. Invoked with the number of cards in the Crypts. The
+    /// most recently discarded cards are accessed first.
+    CryptsAccessCount(QueryDelegate<RaidId, u32>),
+    /// Gets the number of cards the Champion player can access from an outer
+    /// room during this raid. Invoked with the number of cards in the room.
+    RoomAccessCount(QueryDelegate<RaidId, u32>),
     /// Queries the maximum hand size of a player. Invoked with the default
     /// maximum hand size.
     MaximumHandSize(QueryDelegate<Side, u32>),
@@ -0,0 +1,127 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Data types for the standalone 'draft' game mode, in which two players
+//! alternate picking cards from generated packs and then play a game using the
+//! drafted decks.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use with_error::{fail, verify};
+
+use crate::adventure::AdventureConfiguration;
+use crate::card_name::CardName;
+use crate::player_name::PlayerId;
+use crate::primitives::{GameId, Side};
+
+/// Number of cards each player picks over the course of a draft
+pub const DRAFT_PICKS: usize = 15;
+
+/// Number of cards offered in each pack
+pub const PACK_SIZE: usize = 5;
+
+/// Number of picks a player makes from each pack before it is discarded and a
+/// new pack is generated.
+pub const PICKS_PER_PACK: usize = 3;
+
+/// Number of copies of each picked card added to the drafted deck
+pub const COPIES_PER_PICK: u32 = 3;
+
+/// Action to request a new draft against an opponent
+#[derive(Eq, PartialEq, Hash, Debug, Copy, Clone, Serialize, Deserialize)]
+pub struct NewDraftAction {
+    /// Side the requesting player will draft & play as
+    pub side: Side,
+    /// Opponent to draft against. The draft begins once this player has also
+    /// requested a draft against the requesting player, or immediately if they
+    /// are a named AI player.
+    pub opponent: PlayerId,
+}
+
+/// Actions which can be taken during an ongoing draft
+#[derive(Eq, PartialEq, Hash, Debug, Copy, Clone, Serialize, Deserialize)]
+pub enum DraftAction {
+    /// Pick the card at the indicated index within the player's current pack
+    PickCard(usize),
+}
+
+/// State for one participant in a draft
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DraftPlayer {
+    pub id: PlayerId,
+    /// Cards currently available for this player to pick
+    pub pack: Vec<CardName>,
+    /// Cards this player has picked so far, in pick order
+    pub picks: Vec<CardName>,
+    /// Configuration used to generate packs for this player. Packs are drawn
+    /// from the same card pool as adventure drafts.
+    pub config: AdventureConfiguration,
+}
+
+/// Shared state of an ongoing draft between two players
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DraftState {
+    /// Identifies this draft. The game created once the draft completes reuses
+    /// this ID.
+    pub id: GameId,
+    pub overlord: DraftPlayer,
+    pub champion: DraftPlayer,
+    /// Player who is currently picking a card
+    pub active_side: Side,
+}
+
+impl DraftState {
+    pub fn player(&self, side: Side) -> &DraftPlayer {
+        match side {
+            Side::Overlord => &self.overlord,
+            Side::Champion => &self.champion,
+        }
+    }
+
+    pub fn player_mut(&mut self, side: Side) -> &mut DraftPlayer {
+        match side {
+            Side::Overlord => &mut self.overlord,
+            Side::Champion => &mut self.champion,
+        }
+    }
+
+    /// Returns the [Side] the `player_id` player is drafting for, or an error
+    /// if they are not participating in this draft.
+    pub fn side(&self, player_id: PlayerId) -> Result<Side> {
+        if self.overlord.id == player_id {
+            Ok(Side::Overlord)
+        } else if self.champion.id == player_id {
+            Ok(Side::Champion)
+        } else {
+            fail!("Player {:?} is not participating in draft {:?}", player_id, self.id)
+        }
+    }
+
+    /// True once both players have made all of their picks
+    pub fn is_complete(&self) -> bool {
+        self.overlord.picks.len() >= DRAFT_PICKS && self.champion.picks.len() >= DRAFT_PICKS
+    }
+
+    /// Moves the card at `index` in the `side` player's pack into their picks
+    /// and passes the turn to the opponent. Does not generate a new pack.
+    pub fn pick(&mut self, side: Side, index: usize) -> Result<CardName> {
+        verify!(self.active_side == side, "Not {:?}'s turn to pick", side);
+        let player = self.player_mut(side);
+        verify!(index < player.pack.len(), "Invalid pick index {}", index);
+        let card = player.pack.remove(index);
+        player.picks.push(card);
+        self.active_side = side.opponent();
+        Ok(card)
+    }
+}
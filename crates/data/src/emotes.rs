@@ -0,0 +1,41 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Emotes are short predefined messages which players can send to their
+//! opponent during a game.
+
+use enum_iterator::Sequence;
+use serde::{Deserialize, Serialize};
+
+/// Identifies a message which can be sent to the opponent
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize, Sequence)]
+pub enum Emote {
+    Greetings,
+    WellPlayed,
+    Thanks,
+    Oops,
+    Threaten,
+}
+
+impl Emote {
+    pub fn text(&self) -> &'static str {
+        match self {
+            Emote::Greetings => "Greetings!",
+            Emote::WellPlayed => "Well played.",
+            Emote::Thanks => "Thanks!",
+            Emote::Oops => "Oops.",
+            Emote::Threaten => "You will not survive this!",
+        }
+    }
+}
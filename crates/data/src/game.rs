@@ -16,7 +16,7 @@
 
 #![allow(clippy::use_self)] // Required to use EnumKind
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use anyhow::Result;
 use rand_xoshiro::rand_core::SeedableRng;
@@ -25,33 +25,66 @@ use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use with_error::WithError;
 
+use crate::agent_definition::AgentExplanation;
+use crate::card_definition::CardDefinition;
+use crate::card_name::CardName;
 use crate::card_state::{AbilityState, CardPosition, CardPositionKind, CardState};
+use crate::cosmetics::{DeckCosmetics, PlayerAppearance};
 use crate::deck::Deck;
+use crate::delegate_trace::DelegateTraceEntry;
 use crate::delegates::DelegateCache;
 use crate::game_actions::GamePrompt;
+use crate::history::{HistoryEntry, HistoryEvent};
+use crate::player_data::AutomationPreferences;
 use crate::player_name::PlayerId;
 use crate::primitives::{
-    AbilityId, ActionCount, CardId, GameId, HasAbilityId, ItemLocation, ManaValue, PointsValue,
-    RaidId, RoomId, RoomLocation, Side, TurnNumber,
+    AbilityId, ActionCount, CardId, GameId, HasAbilityId, HealthValue, ItemLocation, ManaValue,
+    MatchId, PointsValue, RaidId, RoomId, RoomLocation, Side, TurnNumber,
 };
-use crate::updates::{GameUpdate, UpdateStep, UpdateTracker, Updates};
+use crate::puzzles::PuzzleId;
+use crate::updates::{GameUpdate, UpdatePriority, UpdateQueue, UpdateStep, Updates};
 
-/// Mana to be spent only during the `raid_id` raid
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SpecificRaidMana {
-    pub raid_id: RaidId,
-    pub mana: ManaValue,
+/// Identifies a pool of mana which can only be used for a restricted set of
+/// purposes.
+///
+/// Pools are ordered from most to least restrictive, which is the order in
+/// which they are spent, see `mana::spend`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum ManaPool {
+    /// Mana to be spent only during the indicated raid
+    Raid(RaidId),
+    /// Mana to be spent only to play artifacts or activate their abilities
+    Artifacts,
+    /// Mana to be spent only during any raid
+    AnyRaid,
 }
 
 /// Stores a player's mana, both a general-purpose pool and various
 /// restricted-purpose pools.
+#[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ManaState {
     /// General mana, can be used for any purpose.
     pub base_mana: ManaValue,
 
-    /// Mana which can be used only during a specific raid.
-    pub specific_raid_mana: Option<SpecificRaidMana>,
+    /// Mana which can only be used for the purposes described by its
+    /// [ManaPool].
+    #[serde_as(as = "Vec<(_, _)>")]
+    #[serde(default)]
+    pub pools: BTreeMap<ManaPool, ManaValue>,
+}
+
+/// Action points granted to a player in addition to their standard
+/// start-of-turn action count.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, Eq, PartialEq)]
+pub struct BonusActions {
+    /// Bonus action points which have been granted during the current turn,
+    /// including any carried over from the previous turn. These are included
+    /// in the player's available `actions`.
+    pub this_turn: ActionCount,
+    /// Action points to add to the player's available actions at the start of
+    /// their next turn.
+    pub next_turn: ActionCount,
 }
 
 /// State of a player within a game, containing their score and available
@@ -63,15 +96,48 @@ pub struct PlayerState {
     pub actions: ActionCount,
     pub score: PointsValue,
 
+    /// Extra action points granted to this player by card effects
+    #[serde(default)]
+    pub bonus_actions: BonusActions,
+
     /// A choice this player is facing in resolving a card ability. Takes
     /// precedence over other choices such as raid actions.
     pub prompt: Option<GamePrompt>,
+
+    /// Cosmetic items equipped on the deck this player is using
+    #[serde(default)]
+    pub cosmetics: DeckCosmetics,
+
+    /// Card back and board skin this player has selected
+    #[serde(default)]
+    pub appearance: PlayerAppearance,
+
+    /// Raid prompts this player has chosen to resolve automatically
+    #[serde(default)]
+    pub automation: AutomationPreferences,
+
+    /// Time at which this player was detected to have lost their connection
+    /// to the game, in seconds since the Unix epoch, if they are currently
+    /// disconnected.
+    #[serde(default)]
+    pub disconnected: Option<u64>,
 }
 
 impl PlayerState {
     /// Create an empty player state.
     pub fn new(id: PlayerId) -> Self {
-        Self { id, mana_state: ManaState::default(), actions: 0, score: 0, prompt: None }
+        Self {
+            id,
+            mana_state: ManaState::default(),
+            actions: 0,
+            score: 0,
+            bonus_actions: BonusActions::default(),
+            prompt: None,
+            cosmetics: DeckCosmetics::default(),
+            appearance: PlayerAppearance::default(),
+            automation: AutomationPreferences::default(),
+            disconnected: None,
+        }
     }
 }
 
@@ -125,7 +191,7 @@ pub struct RaidData {
 }
 
 /// Describes options for this game & the set of rules it is using.
-#[derive(Debug, Clone, Default, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GameConfiguration {
     /// If true, all random choices within this game will be made
     /// deterministically using a seeded random number generator. Useful for
@@ -133,6 +199,90 @@ pub struct GameConfiguration {
     pub deterministic: bool,
     /// Whether to run in simulation mode and thus disable update tracking
     pub simulation: bool,
+    /// Number of seconds a disconnected player has to reconnect before they
+    /// automatically forfeit the game. If None, players never forfeit due to
+    /// disconnection.
+    #[serde(default)]
+    pub disconnect_grace_period: Option<u64>,
+    /// Puzzle this game was created from, if any. The puzzle's goal and
+    /// constraints are enforced during play.
+    #[serde(default)]
+    pub puzzle: Option<PuzzleId>,
+    /// If true, AI agents in this game record the candidate actions they
+    /// considered to [GameState::agent_log].
+    #[serde(default)]
+    pub explain_agents: bool,
+    /// Rule changes applied to this game, e.g. by an adventure boss battle.
+    #[serde(default)]
+    pub modifiers: Vec<GameModifier>,
+    /// Basic rule values for this game, which may be customized via a custom
+    /// game preset.
+    #[serde(default)]
+    pub rules: GameRules,
+    /// Best-of-three match this game is part of, if any
+    #[serde(default)]
+    pub match_id: Option<MatchId>,
+    /// If true, the result of this game updates both players' ratings.
+    #[serde(default)]
+    pub ranked: bool,
+    /// If true, both players' hands and remaining decks are revealed to both
+    /// players once the game ends.
+    #[serde(default)]
+    pub reveal_on_game_over: bool,
+    /// If true, event delegates which fire while handling each game action are
+    /// recorded to [GameState::delegate_trace].
+    #[serde(default)]
+    pub trace_delegates: bool,
+}
+
+/// Basic numeric rule values for a game. Standard games use the default
+/// values, custom games allow both players to agree on different ones.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct GameRules {
+    /// Mana each player has when the game begins
+    pub starting_mana: ManaValue,
+    /// Number of cards each player draws for their opening hand, both
+    /// initially and after a mulligan.
+    pub starting_hand_size: u32,
+    /// Score a player must reach in order to win the game
+    pub points_to_win: PointsValue,
+}
+
+impl Default for GameRules {
+    fn default() -> Self {
+        Self { starting_mana: 5, starting_hand_size: 5, points_to_win: 7 }
+    }
+}
+
+/// A change to the standard rules of the game which applies for the duration
+/// of a single game. Modifiers are implemented via delegates which are
+/// registered automatically when a game is created.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum GameModifier {
+    /// The indicated player gains this much additional mana at the start of
+    /// their first turn.
+    BonusStartingMana(Side, ManaValue),
+    /// All minions have this much additional health.
+    MinionHealth(HealthValue),
+}
+
+/// Overlord action which gives the Champion an opportunity to respond by
+/// playing a reaction card. See `mutations::check_reaction_window`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Hash)]
+pub enum ReactionTrigger {
+    /// The Overlord summoned the indicated minion
+    SummonMinion(CardId),
+    /// The Overlord leveled up the indicated room via the basic game action
+    LevelUpRoom(RoomId),
+}
+
+/// Window during the Overlord's turn in which the Champion may play a card
+/// with the `Reaction` subtype. The Overlord cannot act until the Champion
+/// responds or passes.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Hash)]
+pub struct ReactionWindow {
+    /// Action which opened this window
+    pub trigger: ReactionTrigger,
 }
 
 /// Mulligan decision a player made for their opening hand
@@ -174,6 +324,30 @@ pub struct TurnData {
     pub turn_number: TurnNumber,
 }
 
+/// Steps of a player's turn, in the order in which they occur.
+///
+/// Each turn begins with [TurnStep::Begin], during which `TurnBegin` and then
+/// `Dawn`/`Dusk` are invoked. Action points are assigned before the
+/// [TurnStep::Draw] step, during which `DrawPhase` is invoked and the
+/// player draws their card for the turn. `MainPhaseBegin` is then invoked at
+/// the start of [TurnStep::Main], before the Overlord's unveil window. Once
+/// the player has no action points left, `TurnEnd` is invoked during
+/// [TurnStep::End], before the player discards down to their maximum hand
+/// size and the next player's turn begins.
+///
+/// Steps can only advance forward within a turn, see
+/// `mutations::advance_turn_step`.
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, Eq, PartialEq, Hash, Ord, PartialOrd,
+)]
+pub enum TurnStep {
+    #[default]
+    Begin,
+    Draw,
+    Main,
+    End,
+}
+
 /// High level status of a game, including e.g. whose turn it is
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum GamePhase {
@@ -190,12 +364,28 @@ pub struct GameData {
     pub phase: GamePhase,
     /// Identifies current game turn
     pub turn: TurnData,
+    /// Current step within the turn identified by `turn`
+    #[serde(default)]
+    pub turn_step: TurnStep,
     /// Data about an ongoing raid, if any
     pub raid: Option<RaidData>,
     /// Counter to create unique IDs for raids within this game
     pub next_raid_id: u32,
     /// Game options
     pub config: GameConfiguration,
+    /// Rooms to raid, in order, once the current raid ends. See
+    /// `mutations::queue_raid`.
+    #[serde(default)]
+    pub queued_raids: Vec<RoomId>,
+    /// Panic message from an internal error which occurred while handling an
+    /// action in this game, if any. Quarantined games do not accept further
+    /// actions.
+    #[serde(default)]
+    pub quarantined: Option<String>,
+    /// Open window in which the Champion may respond to an Overlord action,
+    /// if any.
+    #[serde(default)]
+    pub reaction_window: Option<ReactionWindow>,
 }
 
 /// State for an individual room
@@ -214,9 +404,9 @@ pub struct GameState {
     /// General game state & configuration
     pub data: GameData,
     /// Used to track changes to game state in order to update the client. See
-    /// [UpdateTracker] for more information.
+    /// [UpdateQueue] for more information.
     #[serde(skip)]
-    pub updates: UpdateTracker,
+    pub updates: UpdateQueue,
     /// Cards for the overlord player. In general, code should use one of the
     /// helper methods below instead of accessing this directly.
     pub overlord_cards: Vec<CardState>,
@@ -234,6 +424,18 @@ pub struct GameState {
     #[serde_as(as = "Vec<(_, _)>")]
     #[serde(default)]
     pub room_state: HashMap<RoomId, RoomState>,
+    /// Notable events which have occurred during this game. See
+    /// [Self::add_history_event].
+    #[serde(default)]
+    pub history: Vec<HistoryEntry>,
+    /// Explanations of AI agent decisions, see
+    /// [GameConfiguration::explain_agents].
+    #[serde(default)]
+    pub agent_log: Vec<AgentExplanation>,
+    /// Event delegates which fired while handling the most recent game action,
+    /// see [GameConfiguration::trace_delegates].
+    #[serde(default)]
+    pub delegate_trace: Vec<DelegateTraceEntry>,
     /// Next sorting key to use for card moves. Automatically updated by
     /// [Self::next_sorting_key] and [Self::move_card_internal].
     next_sorting_key: u32,
@@ -246,6 +448,19 @@ pub struct GameState {
     /// order to improve performance
     #[serde(skip)]
     pub delegate_cache: DelegateCache,
+    /// Optional lookup table for the definitions of cards in this game in
+    /// order to improve performance, see [Self::definition].
+    #[serde(skip)]
+    pub definitions: DefinitionIndex,
+}
+
+/// Definitions for each card in a game, indexed in parallel with the
+/// [GameState] card vectors so that looking up a card's definition does not
+/// require hashing its [crate::card_name::CardName].
+#[derive(Clone, Debug, Default)]
+pub struct DefinitionIndex {
+    pub overlord: Vec<&'static CardDefinition>,
+    pub champion: Vec<&'static CardDefinition>,
 }
 
 impl GameState {
@@ -260,75 +475,136 @@ impl GameState {
         champion_deck: Deck,
         config: GameConfiguration,
     ) -> Self {
+        let updates =
+            UpdateQueue::new(if config.simulation { Updates::Ignore } else { Updates::Push });
+        let rng = if config.deterministic {
+            Some(Xoshiro256StarStar::seed_from_u64(314159265358979323))
+        } else {
+            None
+        };
         Self {
             id,
             data: GameData {
                 phase: GamePhase::ResolveMulligans(MulliganData::default()),
                 turn: TurnData { side: Side::Overlord, turn_number: 0 },
+                turn_step: TurnStep::Begin,
                 raid: None,
                 next_raid_id: 1,
                 config,
+                queued_raids: vec![],
+                quarantined: None,
+                reaction_window: None,
             },
             overlord_cards: Self::make_deck(&overlord_deck, Side::Overlord),
             champion_cards: Self::make_deck(&champion_deck, Side::Champion),
-            overlord: PlayerState::new(overlord_deck.owner_id),
-            champion: PlayerState::new(champion_deck.owner_id),
+            overlord: PlayerState {
+                cosmetics: overlord_deck.cosmetics,
+                ..PlayerState::new(overlord_deck.owner_id)
+            },
+            champion: PlayerState {
+                cosmetics: champion_deck.cosmetics,
+                ..PlayerState::new(champion_deck.owner_id)
+            },
             ability_state: HashMap::new(),
             room_state: HashMap::new(),
-            updates: UpdateTracker::new(if config.simulation {
-                Updates::Ignore
-            } else {
-                Updates::Push
-            }),
+            history: vec![],
+            agent_log: vec![],
+            delegate_trace: vec![],
+            updates,
             next_sorting_key: 1,
             delegate_cache: DelegateCache::default(),
-            rng: if config.deterministic {
-                Some(Xoshiro256StarStar::seed_from_u64(314159265358979323))
-            } else {
-                None
-            },
+            definitions: DefinitionIndex::default(),
+            rng,
         }
     }
 
+    /// Records a [HistoryEvent] as having occurred during the current turn.
+    pub fn add_history_event(&mut self, event: HistoryEvent) {
+        self.history.push(HistoryEntry { turn: self.data.turn, event });
+    }
+
+    /// Returns all [HistoryEvent]s recorded so far in this game, in the order
+    /// in which they occurred.
+    pub fn history_events(&self) -> impl Iterator<Item = HistoryEvent> + '_ {
+        self.history.iter().map(|entry| entry.event)
+    }
+
+    /// Records a [GameUpdate] with [UpdatePriority::Normal]. See [UpdateQueue].
     pub fn record_update(&mut self, update: impl FnOnce() -> GameUpdate) {
+        self.record_update_with_priority(UpdatePriority::Normal, update);
+    }
+
+    /// Records a [GameUpdate] which should be played back according to the
+    /// provided [UpdatePriority] relative to other updates recorded since the
+    /// last snapshot point.
+    pub fn record_update_with_priority(
+        &mut self,
+        priority: UpdatePriority,
+        update: impl FnOnce() -> GameUpdate,
+    ) {
         if self.updates.state == Updates::Push {
-            // Snapshot current game state, omit things that aren't important for display
-            // logic.
-            let clone = Self {
-                id: self.id,
-                data: self.data.clone(),
-                updates: UpdateTracker::new(Updates::Ignore),
-                overlord_cards: self.overlord_cards.clone(),
-                champion_cards: self.champion_cards.clone(),
-                overlord: self.overlord.clone(),
-                champion: self.champion.clone(),
-                ability_state: self.ability_state.clone(),
-                room_state: self.room_state.clone(),
-                next_sorting_key: self.next_sorting_key,
-                rng: None,
-                delegate_cache: DelegateCache::default(),
-            };
-
-            self.updates.steps.push(UpdateStep { snapshot: clone, update: update() });
+            let snapshot = self.update_snapshot();
+            self.updates.steps.push(UpdateStep { snapshot, update: Some(update()), priority });
         }
     }
 
-    /// Makes a clone of the game state without including the [UpdateTracker]
+    /// Records a snapshot of the current game state with no associated
+    /// animation. Updates recorded before this point will always be displayed
+    /// before updates recorded after it.
+    pub fn record_snapshot_point(&mut self) {
+        if self.updates.state == Updates::Push {
+            let snapshot = self.update_snapshot();
+            self.updates.steps.push(UpdateStep {
+                snapshot,
+                update: None,
+                priority: UpdatePriority::Normal,
+            });
+        }
+    }
+
+    /// Makes a clone of the game state without including the [UpdateQueue]
     /// data.
     pub fn clone_without_updates(&self) -> Self {
         Self {
             id: self.id,
             data: self.data.clone(),
-            updates: UpdateTracker::default(),
+            updates: UpdateQueue::default(),
             overlord_cards: self.overlord_cards.clone(),
             champion_cards: self.champion_cards.clone(),
             overlord: self.overlord.clone(),
             champion: self.champion.clone(),
             ability_state: self.ability_state.clone(),
             room_state: self.room_state.clone(),
+            history: self.history.clone(),
+            agent_log: self.agent_log.clone(),
+            delegate_trace: self.delegate_trace.clone(),
             next_sorting_key: self.next_sorting_key,
             rng: self.rng.clone(),
             delegate_cache: self.delegate_cache.clone(),
+            definitions: self.definitions.clone(),
+        }
+    }
+
+    /// Snapshots current game state for the [UpdateQueue], omitting things
+    /// that aren't important for display logic.
+    fn update_snapshot(&self) -> Self {
+        Self {
+            id: self.id,
+            data: self.data.clone(),
+            updates: UpdateQueue::new(Updates::Ignore),
+            overlord_cards: self.overlord_cards.clone(),
+            champion_cards: self.champion_cards.clone(),
+            overlord: self.overlord.clone(),
+            champion: self.champion.clone(),
+            ability_state: self.ability_state.clone(),
+            room_state: self.room_state.clone(),
+            history: self.history.clone(),
+            agent_log: vec![],
+            delegate_trace: vec![],
+            next_sorting_key: self.next_sorting_key,
+            rng: None,
+            delegate_cache: DelegateCache::default(),
+            definitions: self.definitions.clone(),
         }
     }
 
@@ -354,6 +630,19 @@ impl GameState {
         &self.cards(card_id.side)[card_id.index]
     }
 
+    /// Looks up the [CardDefinition] for a card via this game's
+    /// [DefinitionIndex]. Returns None if the index has not been populated, in
+    /// which case the definition must be looked up by
+    /// [crate::card_name::CardName] instead.
+    pub fn definition(&self, card_id: CardId) -> Option<&'static CardDefinition> {
+        match card_id.side {
+            Side::Overlord => &self.definitions.overlord,
+            Side::Champion => &self.definitions.champion,
+        }
+        .get(card_id.index)
+        .copied()
+    }
+
     /// Mutable version of [Self::card]
     pub fn card_mut(&mut self, card_id: CardId) -> &mut CardState {
         &mut self.cards_mut(card_id.side)[card_id.index]
@@ -391,6 +680,17 @@ impl GameState {
         }
     }
 
+    /// Adds a new card named `name` to the game for the `side` player, placing
+    /// it into their deck. Returns the [CardId] of the new card.
+    ///
+    /// Generally use `mutations::create_copy` instead of calling this method
+    /// directly, delegates for the new card are not registered by this method.
+    pub fn add_card_internal(&mut self, side: Side, name: CardName) -> CardId {
+        let card_id = CardId::new(side, self.cards(side).len());
+        self.cards_mut(side).push(CardState::new(card_id, name, false /* is_identity */));
+        card_id
+    }
+
     /// Returns a monotonically-increasing sorting key for object positions in
     /// this game.
     pub fn next_sorting_key(&mut self) -> u32 {
@@ -512,6 +812,17 @@ impl GameState {
         self.cards_in_position(Side::Champion, CardPosition::ArenaItem(ItemLocation::Artifacts))
     }
 
+    /// Champion cards which have been played as allies, in an unspecified
+    /// order
+    pub fn allies(&self) -> impl Iterator<Item = &CardState> {
+        self.cards_in_position(Side::Champion, CardPosition::ArenaItem(ItemLocation::Allies))
+    }
+
+    /// The card currently occupying the shared global modifier slot, if any.
+    pub fn global_modifier(&self) -> Option<&CardState> {
+        self.all_cards().find(|c| c.position() == CardPosition::GlobalModifier)
+    }
+
     /// All Card IDs present in this game.
     ///
     /// Overlord cards in an unspecified order followed by Champion cards in
@@ -583,6 +894,7 @@ impl GameState {
 
 #[cfg(test)]
 mod tests {
+    use std::borrow::Cow;
     use std::collections::HashSet;
 
     use super::*;
@@ -637,6 +949,156 @@ mod tests {
         assert_eq!(vec![mortal, infernal, abyssal], hand(&g));
     }
 
+    #[test]
+    fn updates_ordered_by_priority() {
+        let mut g = test_game(vec![], vec![]);
+        let card_id = CardId::new(Side::Overlord, 0);
+        g.record_update(|| GameUpdate::UnveilProject(card_id));
+        g.record_update_with_priority(UpdatePriority::Deferred, || {
+            GameUpdate::GameOver(Side::Overlord)
+        });
+        g.record_update_with_priority(UpdatePriority::High, || GameUpdate::SummonMinion(card_id));
+        assert_eq!(
+            vec![
+                Some(GameUpdate::SummonMinion(card_id)),
+                Some(GameUpdate::UnveilProject(card_id)),
+                Some(GameUpdate::GameOver(Side::Overlord))
+            ],
+            ordered_updates(&g)
+        );
+    }
+
+    #[test]
+    fn duplicate_updates_removed() {
+        let mut g = test_game(vec![], vec![]);
+        let card_id = CardId::new(Side::Overlord, 0);
+        g.record_update(|| GameUpdate::SummonMinion(card_id));
+        g.record_update(|| GameUpdate::StartTurn(Side::Champion));
+        g.record_update(|| GameUpdate::SummonMinion(card_id));
+        assert_eq!(
+            vec![
+                Some(GameUpdate::SummonMinion(card_id)),
+                Some(GameUpdate::StartTurn(Side::Champion))
+            ],
+            ordered_updates(&g)
+        );
+    }
+
+    #[test]
+    fn updates_not_reordered_across_snapshot_point() {
+        let mut g = test_game(vec![], vec![]);
+        let card_id = CardId::new(Side::Overlord, 0);
+        g.record_update_with_priority(UpdatePriority::Deferred, || {
+            GameUpdate::SummonMinion(card_id)
+        });
+        g.record_snapshot_point();
+        g.record_update_with_priority(UpdatePriority::High, || GameUpdate::UnveilProject(card_id));
+        g.record_update(|| GameUpdate::SummonMinion(card_id));
+        assert_eq!(
+            vec![
+                Some(GameUpdate::SummonMinion(card_id)),
+                None,
+                Some(GameUpdate::UnveilProject(card_id)),
+                Some(GameUpdate::SummonMinion(card_id))
+            ],
+            ordered_updates(&g)
+        );
+    }
+
+    #[test]
+    fn reordered_snapshots_never_move_backwards() {
+        let mut g = test_game(vec![], vec![]);
+        let card_id = CardId::new(Side::Overlord, 0);
+        g.data.turn.turn_number = 1;
+        g.record_update_with_priority(UpdatePriority::Deferred, || {
+            GameUpdate::StartTurn(Side::Overlord)
+        });
+        g.data.turn.turn_number = 2;
+        g.record_update(|| GameUpdate::SummonMinion(card_id));
+        g.data.turn.turn_number = 3;
+        g.record_update(|| GameUpdate::UnveilProject(card_id));
+        let turns = g
+            .updates
+            .ordered_steps()
+            .iter()
+            .map(|step| step.snapshot.data.turn.turn_number)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![2, 3, 3], turns);
+    }
+
+    #[test]
+    fn consecutive_draws_coalesced() {
+        let mut g = test_game(vec![], vec![]);
+        let (first, second) = (CardId::new(Side::Champion, 0), CardId::new(Side::Champion, 1));
+        g.record_update(|| GameUpdate::DrawCards(Side::Champion, vec![first]));
+        g.data.turn.turn_number = 2;
+        g.record_update(|| GameUpdate::DrawCards(Side::Champion, vec![second]));
+        assert_eq!(
+            vec![Some(GameUpdate::DrawCards(Side::Champion, vec![first, second]))],
+            ordered_updates(&g)
+        );
+        let turns = g
+            .updates
+            .ordered_steps()
+            .iter()
+            .map(|step| step.snapshot.data.turn.turn_number)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![2], turns);
+    }
+
+    #[test]
+    fn draws_for_different_sides_not_coalesced() {
+        let mut g = test_game(vec![], vec![]);
+        let (overlord, champion) = (CardId::new(Side::Overlord, 0), CardId::new(Side::Champion, 0));
+        g.record_update(|| GameUpdate::DrawCards(Side::Overlord, vec![overlord]));
+        g.record_update(|| GameUpdate::DrawCards(Side::Champion, vec![champion]));
+        assert_eq!(
+            vec![
+                Some(GameUpdate::DrawCards(Side::Overlord, vec![overlord])),
+                Some(GameUpdate::DrawCards(Side::Champion, vec![champion]))
+            ],
+            ordered_updates(&g)
+        );
+    }
+
+    #[test]
+    fn draws_not_coalesced_across_snapshot_point() {
+        let mut g = test_game(vec![], vec![]);
+        let (first, second) = (CardId::new(Side::Champion, 0), CardId::new(Side::Champion, 1));
+        g.record_update(|| GameUpdate::DrawCards(Side::Champion, vec![first]));
+        g.record_snapshot_point();
+        g.record_update(|| GameUpdate::DrawCards(Side::Champion, vec![second]));
+        assert_eq!(
+            vec![
+                Some(GameUpdate::DrawCards(Side::Champion, vec![first])),
+                None,
+                Some(GameUpdate::DrawCards(Side::Champion, vec![second]))
+            ],
+            ordered_updates(&g)
+        );
+    }
+
+    #[test]
+    fn consecutive_snapshot_points_collapsed() {
+        let mut g = test_game(vec![], vec![]);
+        for turn_number in 1..=3 {
+            g.data.turn.turn_number = turn_number;
+            g.record_snapshot_point();
+        }
+        let steps = g.updates.ordered_steps();
+        assert_eq!(1, steps.len());
+        assert!(steps[0].update.is_none());
+        assert_eq!(3, steps[0].snapshot.data.turn.turn_number);
+    }
+
+    fn ordered_updates(game: &GameState) -> Vec<Option<GameUpdate>> {
+        game.updates
+            .ordered_steps()
+            .into_iter()
+            .map(|step| step.update.map(Cow::into_owned))
+            .collect()
+    }
+
     fn test_game(overlord: Vec<CardName>, champion: Vec<CardName>) -> GameState {
         GameState::new(
             GameId::new(0),
@@ -647,6 +1109,7 @@ mod tests {
                 owner_id: PlayerId::Named(NamedPlayer::TestNoAction),
                 identity: CardName::TestOverlordIdentity,
                 cards: overlord.into_iter().map(|name| (name, 1)).collect(),
+                cosmetics: DeckCosmetics::default(),
             },
             Deck {
                 index: DeckIndex::new(1),
@@ -655,6 +1118,7 @@ mod tests {
                 owner_id: PlayerId::Named(NamedPlayer::TestNoAction),
                 identity: CardName::TestOverlordIdentity,
                 cards: champion.into_iter().map(|name| (name, 1)).collect(),
+                cosmetics: DeckCosmetics::default(),
             },
             GameConfiguration { deterministic: true, ..GameConfiguration::default() },
         )
@@ -16,6 +16,8 @@
 
 #![allow(clippy::use_self)] // Required to use EnumKind
 
+use std::iter;
+
 use anyhow::{anyhow, Result};
 use enum_kinds::EnumKind;
 use serde::{Deserialize, Serialize};
@@ -31,6 +33,16 @@ pub enum EncounterAction {
     /// Custom card action, resolved and then treated equivalently to 'no
     /// weapon'
     CardAction(CardPromptAction),
+    /// The Champion asks to retreat from the raid. Shows a confirmation
+    /// prompt, see [GamePrompt::confirm_retreat].
+    Retreat,
+    /// (first_weapon_id, second_weapon_id, target_id). Use two weapons
+    /// together to defeat a minion, see [crate::delegates::CombinedWeapons].
+    UseCombinedWeapons(CardId, CardId, CardId),
+    /// (ability_id, weapon_id). Activate an
+    /// [crate::card_definition::AbilityType::EncounterActivated] ability
+    /// targeting a weapon, then continue the current encounter.
+    ActivateAbility(AbilityId, CardId),
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Hash)]
@@ -43,6 +55,24 @@ pub enum AccessPhaseAction {
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum PromptContext {
     RaidAdvance,
+    /// Choose a card from among several displayed options
+    ChooseCard,
+    /// Pay the upkeep cost of a project or discard it
+    Upkeep,
+    /// Choose face-down projects to unveil at the start of the Overlord's turn
+    UnveilProjects,
+    /// Confirm retreating from the current raid
+    Retreat,
+    /// Discard the indicated number of cards to reach maximum hand size at the
+    /// end of the turn
+    DiscardToHandSize(usize),
+    /// Play a reaction card in response to an Overlord action
+    Reaction,
+    /// Choose one mode of a modal card which is being played
+    ChooseMode,
+    /// Choose the amount of mana to pay for X when playing a card with a
+    /// variable mana cost
+    ChooseX,
 }
 
 /// A choice which can be made as part of an ability of an individual card
@@ -60,6 +90,37 @@ pub enum CardPromptAction {
     TakeDamage(AbilityId, u32),
     /// Deal damage and end the current raid
     TakeDamageEndRaid(AbilityId, u32),
+    /// A player moves a card from their deck into their hand and then
+    /// shuffles their deck
+    TutorCard(Side, CardId),
+    /// The Overlord pays the upkeep cost of a project to keep it in play
+    PayUpkeep(CardId, ManaValue),
+    /// The Overlord discards a project instead of paying its upkeep cost
+    DiscardProject(CardId),
+    /// The Overlord pays a face-down project's cost to turn it face up
+    UnveilProject(CardId),
+    /// The Overlord declines to unveil any further projects this turn
+    EndUnveilWindow,
+    /// The Champion retreats from the current encounter, ending the raid in
+    /// failure
+    ConfirmRetreat,
+    /// The Champion decides not to retreat and returns to the current
+    /// encounter
+    CancelRetreat,
+    /// A player discards a card from their hand at the end of their turn
+    /// because they are over their maximum hand size
+    DiscardToHandSize(Side, CardId),
+    /// The Champion declines to play a reaction card, closing the current
+    /// reaction window
+    PassReaction,
+    /// A player chooses the indicated mode of a modal card they are playing
+    /// with the given target, see
+    /// [crate::card_definition::AbilityType::Mode].
+    ChooseMode(AbilityId, CardTarget),
+    /// A player pays the indicated amount of mana for X while playing a card
+    /// with a variable mana cost with the given target, see
+    /// [crate::card_definition::Cost::variable_mana].
+    ChooseX(CardId, CardTarget, ManaValue),
 }
 
 /// An action which can be taken in the user interface, typically embedded
@@ -94,6 +155,135 @@ impl GamePrompt {
             responses: actions.into_iter().map(PromptAction::CardAction).collect(),
         }
     }
+
+    /// Prompt for the Overlord to pay `cost` mana to keep the `card_id`
+    /// project in play, or else discard it.
+    pub fn upkeep(card_id: CardId, cost: ManaValue) -> Self {
+        Self {
+            context: Some(PromptContext::Upkeep),
+            responses: vec![
+                PromptAction::CardAction(CardPromptAction::PayUpkeep(card_id, cost)),
+                PromptAction::CardAction(CardPromptAction::DiscardProject(card_id)),
+            ],
+        }
+    }
+
+    /// Prompt for the Overlord to unveil any of the face-down `projects`, or to
+    /// continue their turn.
+    pub fn unveil_window(projects: &[CardId]) -> Self {
+        Self {
+            context: Some(PromptContext::UnveilProjects),
+            responses: projects
+                .iter()
+                .map(|card_id| PromptAction::CardAction(CardPromptAction::UnveilProject(*card_id)))
+                .chain(iter::once(PromptAction::CardAction(CardPromptAction::EndUnveilWindow)))
+                .collect(),
+        }
+    }
+
+    /// Prompt for the Champion to pass on playing a reaction card. Reaction
+    /// cards themselves are played via the basic play card action.
+    pub fn reaction_window() -> Self {
+        Self {
+            context: Some(PromptContext::Reaction),
+            responses: vec![PromptAction::CardAction(CardPromptAction::PassReaction)],
+        }
+    }
+
+    /// Prompt for a player to choose one of the `modes` of a modal card they
+    /// are playing with the given `target`.
+    pub fn choose_mode(modes: impl Iterator<Item = AbilityId>, target: CardTarget) -> Self {
+        Self {
+            context: Some(PromptContext::ChooseMode),
+            responses: modes
+                .map(|mode| PromptAction::CardAction(CardPromptAction::ChooseMode(mode, target)))
+                .collect(),
+        }
+    }
+
+    /// Prompt for a player to choose a value between 0 and `maximum` for X
+    /// while playing the `card_id` card with the given `target`.
+    pub fn choose_x(card_id: CardId, target: CardTarget, maximum: ManaValue) -> Self {
+        Self {
+            context: Some(PromptContext::ChooseX),
+            responses: (0..=maximum)
+                .map(|x| PromptAction::CardAction(CardPromptAction::ChooseX(card_id, target, x)))
+                .collect(),
+        }
+    }
+
+    /// Prompt for the Champion to confirm retreating from the current raid.
+    pub fn confirm_retreat() -> Self {
+        Self {
+            context: Some(PromptContext::Retreat),
+            responses: vec![
+                PromptAction::CardAction(CardPromptAction::ConfirmRetreat),
+                PromptAction::CardAction(CardPromptAction::CancelRetreat),
+            ],
+        }
+    }
+
+    /// Prompt for the `side` player to pick one of the `cards` in their deck
+    /// to put into their hand.
+    pub fn tutor(side: Side, cards: &[CardId]) -> Self {
+        Self {
+            context: Some(PromptContext::ChooseCard),
+            responses: cards
+                .iter()
+                .map(|card_id| {
+                    PromptAction::CardAction(CardPromptAction::TutorCard(side, *card_id))
+                })
+                .collect(),
+        }
+    }
+
+    /// Prompt for the `side` player to discard one of the cards in their
+    /// `hand`, which currently contains `excess` more cards than their maximum
+    /// hand size.
+    pub fn discard_to_hand_size(side: Side, hand: &[CardId], excess: usize) -> Self {
+        Self {
+            context: Some(PromptContext::DiscardToHandSize(excess)),
+            responses: hand
+                .iter()
+                .map(|card_id| {
+                    PromptAction::CardAction(CardPromptAction::DiscardToHandSize(side, *card_id))
+                })
+                .collect(),
+        }
+    }
+
+    /// Cards which can be selected via [CardPromptAction::TutorCard] or
+    /// [CardPromptAction::DiscardToHandSize] responses to this prompt.
+    pub fn card_choices(&self) -> impl Iterator<Item = CardId> + '_ {
+        self.responses.iter().filter_map(|response| match response {
+            PromptAction::CardAction(
+                CardPromptAction::TutorCard(_, card_id)
+                | CardPromptAction::DiscardToHandSize(_, card_id),
+            ) => Some(*card_id),
+            _ => None,
+        })
+    }
+}
+
+/// Predicted consequences of selecting a [PromptAction] for the player making
+/// the choice, computed by simulating the action on a copy of the game.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct PromptPreview {
+    /// Mana the player will lose
+    pub mana_lost: ManaValue,
+    /// Action points the player will lose
+    pub actions_lost: ActionCount,
+    /// Number of cards which will be moved to the player's discard pile
+    pub cards_discarded: usize,
+    /// True if the current raid will end
+    pub raid_ends: bool,
+}
+
+impl PromptPreview {
+    /// Returns true if this preview has no consequences to display
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
 }
 
 /// Possible targets for the 'play card' action. Note that many types of targets
@@ -0,0 +1,88 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Structured record of notable events which occurred during a game. Unlike
+//! [crate::updates::GameUpdate], history entries are persisted with the game
+//! and are available after the game ends.
+
+use serde::{Deserialize, Serialize};
+
+use crate::adventure::Coins;
+use crate::game::{GameState, TurnData};
+use crate::primitives::{CardId, ManaValue, RoomId, Side};
+
+/// An event which occurred during a game
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum HistoryEvent {
+    /// The Champion initiated a raid on the indicated room
+    RaidBegin(RoomId),
+    /// A raid on the indicated room ended successfully for the Champion
+    RaidSuccess(RoomId),
+    /// A player played the indicated card from their hand
+    PlayCard(Side, CardId),
+    /// The Champion took the indicated amount of damage
+    DealDamage(u32),
+    /// A player scored the indicated scheme card
+    ScoreCard(Side, CardId),
+    /// The game ended with the indicated player winning
+    GameOver(Side),
+    /// The Champion retreated from a raid on the indicated room
+    RaidRetreat(RoomId),
+    /// A player spent the indicated amount of mana
+    SpendMana(Side, ManaValue),
+    /// A player drew the indicated card into their hand
+    DrawCard(Side, CardId),
+}
+
+/// A [HistoryEvent] along with the turn in which it occurred
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub turn: TurnData,
+    pub event: HistoryEvent,
+}
+
+/// Statistics describing a completed game from one player's perspective
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MatchSummary {
+    /// Number of raids the Champion initiated
+    pub raids: u32,
+    /// Number of schemes this player scored
+    pub schemes_scored: u32,
+    /// Total damage dealt to the Champion
+    pub damage_dealt: u32,
+    /// Total mana this player spent
+    pub mana_spent: ManaValue,
+    /// Coins this player earned from the game, e.g. from completing quests.
+    /// Added to the player's adventure if one is active.
+    pub coins_earned: Coins,
+}
+
+impl MatchSummary {
+    /// Computes a summary of the `side` player's game from its history.
+    /// Does not include [Self::coins_earned], which is determined by the
+    /// server after the game ends.
+    pub fn new(game: &GameState, side: Side) -> Self {
+        let mut result = Self::default();
+        for event in game.history_events() {
+            match event {
+                HistoryEvent::RaidBegin(_) => result.raids += 1,
+                HistoryEvent::ScoreCard(s, _) if s == side => result.schemes_scored += 1,
+                HistoryEvent::DealDamage(amount) => result.damage_dealt += amount,
+                HistoryEvent::SpendMana(s, amount) if s == side => result.mana_spent += amount,
+                _ => {}
+            }
+        }
+        result
+    }
+}
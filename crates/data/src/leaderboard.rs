@@ -0,0 +1,55 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Results of seeded adventure runs, ranked per seed
+
+use serde::{Deserialize, Serialize};
+
+use crate::adventure::Coins;
+use crate::player_name::PlayerId;
+use crate::primitives::Side;
+
+/// Identifies the seed for a seeded adventure run
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum RunSeed {
+    /// The shared seed for the current day, see [daily_seed]
+    Daily,
+    /// A specific seed, e.g. one shared between players
+    Custom(u64),
+}
+
+/// Result of one completed seeded adventure run
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LeaderboardEntry {
+    pub player_id: PlayerId,
+    pub side: Side,
+    /// Coins the player held when the adventure ended
+    pub coins: Coins,
+    /// Number of games the player won during the adventure
+    pub battles_won: u32,
+}
+
+/// Returns the seed used for the daily run on the given day. All players
+/// receive the same seed on a given day.
+pub fn daily_seed(day: u64) -> u64 {
+    // Mix the day number so daily seeds are unlikely to collide with small
+    // hand-picked custom seeds
+    day.wrapping_mul(0x9E37_79B9_7F4A_7C15)
+}
+
+/// Orders leaderboard entries from best to worst: most coins first, with ties
+/// broken by battles won.
+pub fn rank(entries: &mut [LeaderboardEntry]) {
+    entries.sort_by(|a, b| b.coins.cmp(&a.coins).then(b.battles_won.cmp(&a.battles_won)));
+}
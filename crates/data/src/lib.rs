@@ -14,20 +14,35 @@
 
 //! Data types used to represent game state
 
+pub mod achievements;
 pub mod adventure;
 pub mod adventure_action;
 pub mod agent_definition;
+pub mod campaign;
 pub mod card_definition;
 pub mod card_name;
 pub mod card_state;
+pub mod card_stats;
+pub mod cosmetics;
+pub mod crafting;
 pub mod deck;
+pub mod delegate_trace;
 pub mod delegates;
+pub mod draft;
+pub mod emotes;
 pub mod game;
 pub mod game_actions;
+pub mod history;
+pub mod leaderboard;
+pub mod match_state;
+pub mod notification;
 pub mod player_data;
 pub mod player_name;
 pub mod primitives;
+pub mod puzzles;
+pub mod quests;
 pub mod random;
+pub mod ratings;
 pub mod set_name;
 pub mod special_effects;
 pub mod text;
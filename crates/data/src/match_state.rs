@@ -0,0 +1,98 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! State for best-of-three matches, which group several games between the same
+//! two players
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use with_error::WithError;
+
+use crate::deck::Deck;
+use crate::player_name::PlayerId;
+use crate::primitives::{GameId, MatchId};
+use crate::user_actions::GamePreset;
+
+/// Number of game wins required to win a match
+pub const GAMES_TO_WIN_MATCH: u32 = 2;
+
+/// One of the two participants in a match
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchPlayer {
+    pub id: PlayerId,
+    /// Deck this player uses in games where they are the Overlord
+    pub overlord_deck: Deck,
+    /// Deck this player uses in games where they are the Champion
+    pub champion_deck: Deck,
+    /// Number of games this player has won in this match
+    pub wins: u32,
+}
+
+/// A series of games between two players, which ends once one player has won
+/// [GAMES_TO_WIN_MATCH] games. Players swap sides after each game.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchState {
+    pub id: MatchId,
+    /// The two players in this match. The first player is the Overlord in
+    /// the first game.
+    pub players: [MatchPlayer; 2],
+    /// Rule options used for every game in this match
+    pub preset: GamePreset,
+    /// Games created for this match so far, in the order they were played
+    pub games: Vec<GameId>,
+}
+
+impl MatchState {
+    /// Looks up a participant in this match
+    pub fn player(&self, player_id: PlayerId) -> Result<&MatchPlayer> {
+        self.players
+            .iter()
+            .find(|p| p.id == player_id)
+            .with_error(|| format!("Player {player_id:?} not in match {:?}", self.id))
+    }
+
+    /// Mutable equivalent of [Self::player]
+    pub fn player_mut(&mut self, player_id: PlayerId) -> Result<&mut MatchPlayer> {
+        let id = self.id;
+        self.players
+            .iter_mut()
+            .find(|p| p.id == player_id)
+            .with_error(|| format!("Player {player_id:?} not in match {id:?}"))
+    }
+
+    /// Returns the participant who is not `player_id`
+    pub fn opponent(&self, player_id: PlayerId) -> Result<&MatchPlayer> {
+        self.player(player_id)?;
+        Ok(if self.players[0].id == player_id { &self.players[1] } else { &self.players[0] })
+    }
+
+    /// Player who has won this match, if any
+    pub fn winner(&self) -> Option<PlayerId> {
+        self.players.iter().find(|p| p.wins >= GAMES_TO_WIN_MATCH).map(|p| p.id)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.winner().is_some()
+    }
+
+    /// Returns the (Overlord, Champion) players for the next game to be
+    /// created in this match. Sides alternate with each game.
+    pub fn next_sides(&self) -> (&MatchPlayer, &MatchPlayer) {
+        if self.games.len().is_multiple_of(2) {
+            (&self.players[0], &self.players[1])
+        } else {
+            (&self.players[1], &self.players[0])
+        }
+    }
+}
@@ -0,0 +1,35 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Transient messages displayed to a player outside of the normal flow of the
+//! game.
+
+use serde::{Deserialize, Serialize};
+
+use crate::quests::Quest;
+
+/// A message displayed to a player as a toast, e.g. to announce that a quest
+/// was completed.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Notification {
+    /// The player completed a daily quest and was awarded its coins
+    QuestCompleted(Quest),
+    /// The player's opponent resigned from their game
+    OpponentConceded,
+    /// The player's game was ended because of an internal server error
+    GameQuarantined,
+    /// The player's game was ended without a result because both players
+    /// disconnected from it
+    GameAbandoned,
+}
@@ -12,24 +12,37 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use with_error::WithError;
 
-use crate::adventure::AdventureState;
+use crate::achievements::AchievementName;
+use crate::adventure::{AdventureState, Coins};
+use crate::campaign::CampaignProgress;
 use crate::card_name::CardName;
+use crate::cosmetics::{Cosmetic, PlayerCosmetics};
+use crate::crafting::Dust;
 use crate::deck::Deck;
+use crate::draft::{DraftState, NewDraftAction};
+use crate::history::MatchSummary;
+use crate::notification::Notification;
 use crate::player_name::PlayerId;
-use crate::primitives::{DeckId, DeckIndex, GameId};
+use crate::primitives::{DeckId, DeckIndex, GameId, MatchId, Side, TurnNumber};
+use crate::puzzles::PuzzleId;
+use crate::quests::QuestLog;
 use crate::tutorial::TutorialData;
+use crate::user_actions::GamePreset;
 
 /// Data for a player's request to create a new game
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NewGameRequest {
     pub deck_id: DeckIndex,
+    /// Rule options the player has accepted for this game
+    #[serde(default)]
+    pub preset: GamePreset,
 }
 
 /// Represents the state of a game the player is participating in.
@@ -39,6 +52,135 @@ pub enum PlayerState {
     RequestedGame(NewGameRequest),
     /// The player is currently playing in the [GameId] game.
     Playing(GameId),
+    /// The player has requested to start a draft and is waiting for their
+    /// opponent to join.
+    RequestedDraft(NewDraftAction),
+    /// The player is participating in an ongoing draft. Both participants store
+    /// a copy of the shared [DraftState].
+    Drafting(Box<DraftState>),
+}
+
+/// Music volume for players who have not configured one, as a percentage
+pub const DEFAULT_MUSIC_VOLUME: u32 = 25;
+
+/// Sound effect volume for players who have not configured one, as a
+/// percentage
+pub const DEFAULT_SOUND_EFFECTS_VOLUME: u32 = 100;
+
+/// User-configurable options for a player
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerSettings {
+    /// If true, emotes sent by opponents are not displayed
+    pub mute_emotes: bool,
+    /// Volume of background music, as a percentage between 0 and 100
+    #[serde(default = "default_music_volume")]
+    pub music_volume: u32,
+    /// Volume of sound effects, as a percentage between 0 and 100
+    #[serde(default = "default_sound_effects_volume")]
+    pub sound_effects_volume: u32,
+    /// If true, no music or sound effects are played
+    #[serde(default)]
+    pub mute_audio: bool,
+    /// Palette used to display card icons
+    #[serde(default)]
+    pub icon_mode: IconMode,
+    /// Speed at which in-game animations are played
+    #[serde(default)]
+    pub animation_speed: AnimationSpeed,
+    /// If true, this player's completed games contribute to anonymous
+    /// per-card statistics used for balance analysis
+    #[serde(default)]
+    pub share_card_statistics: bool,
+    /// If true, prompt responses are not given keyboard shortcuts
+    #[serde(default)]
+    pub disable_hotkeys: bool,
+}
+
+/// Options allowing a player to skip raid prompts which do not involve a
+/// meaningful choice. Copied into the player's game state when a game is
+/// created.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct AutomationPreferences {
+    /// Continue past minion encounters automatically when no weapon can be
+    /// used and the minion has no combat choices, instead of prompting.
+    pub auto_continue_encounters: bool,
+    /// End raids automatically once no accessed card can be scored or
+    /// destroyed.
+    pub auto_end_raid: bool,
+    /// Always prompt at the access step of a raid so accessed cards can be
+    /// inspected. Takes precedence over [Self::auto_end_raid] until every
+    /// accessed card has been scored or destroyed.
+    pub always_stop_at_access: bool,
+}
+
+/// Palette used to display the mana, attack, health and shield icons on cards
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum IconMode {
+    #[default]
+    Standard,
+    /// High-contrast icons which are distinguishable without relying on color
+    HighContrast,
+}
+
+/// Speed at which game animations are displayed to a player
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum AnimationSpeed {
+    /// Skip animations entirely, immediately displaying the result of each
+    /// action
+    Off,
+    /// Play animations at double speed
+    Fast,
+    #[default]
+    Normal,
+}
+
+impl AnimationSpeed {
+    /// Scales an animation duration for this speed
+    pub fn scale(self, milliseconds: u32) -> u32 {
+        match self {
+            Self::Off => 0,
+            Self::Fast => milliseconds / 2,
+            Self::Normal => milliseconds,
+        }
+    }
+}
+
+impl Default for PlayerSettings {
+    fn default() -> Self {
+        Self {
+            mute_emotes: false,
+            music_volume: DEFAULT_MUSIC_VOLUME,
+            sound_effects_volume: DEFAULT_SOUND_EFFECTS_VOLUME,
+            mute_audio: false,
+            icon_mode: IconMode::Standard,
+            animation_speed: AnimationSpeed::Normal,
+            share_card_statistics: false,
+            disable_hotkeys: false,
+        }
+    }
+}
+
+fn default_music_volume() -> u32 {
+    DEFAULT_MUSIC_VOLUME
+}
+
+fn default_sound_effects_volume() -> u32 {
+    DEFAULT_SOUND_EFFECTS_VOLUME
+}
+
+/// Summary of the outcome of a completed game, from one player's perspective
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchRecord {
+    pub game_id: GameId,
+    /// Side this player was representing in the game
+    pub side: Side,
+    pub opponent: PlayerId,
+    pub won: bool,
+    /// Turn number on which the game ended
+    pub turn_number: TurnNumber,
+    /// Statistics describing this player's game
+    #[serde(default)]
+    pub summary: MatchSummary,
 }
 
 /// Represents a player's stored data.
@@ -60,6 +202,54 @@ pub struct PlayerData {
     pub collection: HashMap<CardName, u32>,
     /// Data related to this player's tutorial progress
     pub tutorial: TutorialData,
+    /// Cosmetic items this player has unlocked
+    #[serde(default)]
+    pub cosmetics: HashSet<Cosmetic>,
+    /// Achievements this player has earned
+    #[serde(default)]
+    pub achievements: HashSet<AchievementName>,
+    /// User-configurable options
+    #[serde(default)]
+    pub settings: PlayerSettings,
+    /// Results of games this player has completed, oldest first
+    #[serde(default)]
+    pub match_history: Vec<MatchRecord>,
+    /// Puzzles this player has solved
+    #[serde(default)]
+    pub completed_puzzles: HashSet<PuzzleId>,
+    /// This player's daily quests
+    #[serde(default)]
+    pub quests: QuestLog,
+    /// Coins earned outside of an adventure, e.g. from quest rewards. Added to
+    /// the player's starting coins when they begin their next adventure.
+    #[serde(default)]
+    pub coins: Coins,
+    /// Card backs and board skins this player has unlocked and selected
+    #[serde(default)]
+    pub player_cosmetics: PlayerCosmetics,
+    /// Incremented each time this player's data is written, used to invalidate
+    /// cached panels.
+    #[serde(default)]
+    pub revision: u64,
+    /// Notifications generated while this player was not connected, displayed
+    /// the next time they connect.
+    #[serde(default)]
+    pub notifications: Vec<Notification>,
+    /// Progress through the adventure campaign, which persists across
+    /// adventures
+    #[serde(default)]
+    pub campaign: CampaignProgress,
+    /// Best-of-three match this player is participating in, if any. Cleared
+    /// once the match has a winner.
+    #[serde(default)]
+    pub current_match: Option<MatchId>,
+    /// Dust available to craft new cards, earned by disenchanting cards from
+    /// the player's collection.
+    #[serde(default)]
+    pub dust: Dust,
+    /// Raid prompts this player has chosen to resolve automatically
+    #[serde(default)]
+    pub automation: AutomationPreferences,
 }
 
 impl PlayerData {
@@ -71,6 +261,20 @@ impl PlayerData {
             adventure: None,
             collection: HashMap::default(),
             tutorial: TutorialData::default(),
+            cosmetics: HashSet::default(),
+            achievements: HashSet::default(),
+            settings: PlayerSettings::default(),
+            match_history: vec![],
+            completed_puzzles: HashSet::default(),
+            quests: QuestLog::default(),
+            coins: Coins::default(),
+            player_cosmetics: PlayerCosmetics::default(),
+            revision: 0,
+            notifications: vec![],
+            campaign: CampaignProgress::default(),
+            current_match: None,
+            dust: Dust::default(),
+            automation: AutomationPreferences::default(),
         }
     }
 
@@ -84,10 +288,18 @@ impl PlayerData {
         self.adventure.as_mut().with_error(|| "Expected active adventure")
     }
 
-    /// Returns the [DeckIndex] this player requested to use for a new game.
-    pub fn requested_deck_id(&self) -> Option<DeckIndex> {
+    /// Returns the [NewGameRequest] this player is waiting on, if any.
+    pub fn requested_game(&self) -> Option<&NewGameRequest> {
         match &self.state {
-            Some(PlayerState::RequestedGame(request)) => Some(request.deck_id),
+            Some(PlayerState::RequestedGame(request)) => Some(request),
+            _ => None,
+        }
+    }
+
+    /// Returns the [DraftState] for this player's ongoing draft, if any.
+    pub fn draft(&self) -> Option<&DraftState> {
+        match &self.state {
+            Some(PlayerState::Drafting(draft)) => Some(draft),
             _ => None,
         }
     }
@@ -107,6 +319,17 @@ impl PlayerData {
     pub fn deck_mut(&mut self, deck_id: DeckIndex) -> Result<&mut Deck> {
         self.decks.get_mut(deck_id.value as usize).with_error(|| "Deck not found")
     }
+
+    /// Number of copies of `card` owned by this player
+    pub fn owned_copies(&self, card: CardName) -> u32 {
+        self.collection.get(&card).copied().unwrap_or_default()
+    }
+
+    /// Largest number of copies of `card` included in any one of this
+    /// player's saved decks. Copies in use cannot be disenchanted.
+    pub fn copies_in_decks(&self, card: CardName) -> u32 {
+        self.decks.iter().filter_map(|deck| deck.cards.get(&card)).copied().max().unwrap_or(0)
+    }
 }
 
 /// Returns the [GameId] an optional [PlayerData] is currently playing in, if
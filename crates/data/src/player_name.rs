@@ -20,6 +20,9 @@ use serde::{Deserialize, Serialize};
 use strum_macros::Display;
 use with_error::fail;
 
+use crate::agent_definition;
+use crate::agent_definition::ScriptRule;
+
 /// Identifies a player across different games
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub enum PlayerId {
@@ -40,6 +43,14 @@ impl PlayerId {
             _ => fail!("Expected PlayerId::Database"),
         }
     }
+
+    /// Name to display for this player to other players
+    pub fn displayed_name(&self) -> String {
+        match self {
+            PlayerId::Database(key) => format!("Player {key}"),
+            PlayerId::Named(name) => name.displayed_name(),
+        }
+    }
 }
 
 /// Identifies a named AI player
@@ -52,10 +63,37 @@ pub enum NamedPlayer {
     TestAlphaBetaScores,
     TestAlphaBetaHeuristics,
     TestUct1,
+    TestUct1Priors,
+    AdventureBossWarden,
+    AdventureBossRaider,
 }
 
 impl NamedPlayer {
     pub fn displayed_name(&self) -> String {
         format!("{}", self).from_case(Case::Pascal).to_case(Case::Title)
     }
+
+    /// Flavor text introducing this player, displayed when a game against them
+    /// begins.
+    pub fn intro(&self) -> Option<&'static str> {
+        match self {
+            Self::AdventureBossWarden => Some(
+                "The Warden has guarded these vaults for a thousand years. No thief has \
+                 ever left them alive.",
+            ),
+            Self::AdventureBossRaider => {
+                Some("The Raider's blades are hungry, and your sanctum is the nearest feast.")
+            }
+            _ => None,
+        }
+    }
+
+    /// Decision table for this player if it is controlled by a scripted agent
+    pub fn script(&self) -> Option<&'static [ScriptRule]> {
+        match self {
+            Self::AdventureBossWarden => Some(agent_definition::WARDEN),
+            Self::AdventureBossRaider => Some(agent_definition::RAIDER),
+            _ => None,
+        }
+    }
 }
@@ -30,6 +30,7 @@ pub type HealthValue = u32;
 pub type AttackValue = u32;
 pub type ShieldValue = u32;
 pub type BreachValue = u32;
+pub type DurabilityValue = u32;
 pub type BoostCount = u32;
 pub type LevelValue = u32;
 
@@ -81,6 +82,30 @@ impl fmt::Debug for GameId {
     }
 }
 
+/// Identifies a best-of-three match. Shares its value with the [GameId] of the
+/// first game in the match.
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub struct MatchId {
+    pub value: u64,
+}
+
+impl MatchId {
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Byte array representation of this ID
+    pub fn key(&self) -> [u8; 8] {
+        self.value.to_be_bytes()
+    }
+}
+
+impl fmt::Debug for MatchId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
 /// The two players in a game: Overlord & Champion
 #[derive(PartialEq, Eq, Hash, Copy, Clone, Serialize, Deserialize, Ord, PartialOrd, Sequence)]
 pub enum Side {
@@ -231,7 +256,7 @@ impl From<AbilityId> for GameObjectId {
 }
 
 /// Uniquely identifies a raid within a given game
-#[derive(PartialEq, Eq, Hash, Copy, Clone, Serialize, Deserialize)]
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Serialize, Deserialize, Ord, PartialOrd)]
 pub struct RaidId(pub u32);
 
 impl fmt::Debug for RaidId {
@@ -301,6 +326,7 @@ pub enum RoomLocation {
 pub enum ItemLocation {
     Weapons,
     Artifacts,
+    Allies,
 }
 
 /// The Possible lineages of weapons and minions. Minions can only be
@@ -335,11 +361,18 @@ pub enum CardType {
     ChampionSpell,
     Weapon,
     Artifact,
+    /// Champion permanent which occupies the ally zone of the arena and can
+    /// absorb damage dealt to the Champion
+    Ally,
 
     Scheme,
     OverlordSpell,
     Project,
     Minion,
+
+    /// Card which occupies the shared global modifier slot and affects both
+    /// players
+    GlobalModifier,
 }
 
 impl CardType {
@@ -352,6 +385,9 @@ impl CardType {
 #[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, Serialize, Deserialize)]
 pub enum CardSubtype {
     Silvered,
+    /// Can be played in response to certain Overlord actions during their
+    /// turn, see `GameData::reaction_window`
+    Reaction,
 }
 
 /// Describes a boost ability activation
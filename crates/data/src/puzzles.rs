@@ -0,0 +1,98 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Puzzles are pre-built game situations with a specific goal, such as winning
+//! the game this turn.
+
+use enum_iterator::Sequence;
+use serde::{Deserialize, Serialize};
+
+use crate::game::TurnData;
+use crate::primitives::{Side, TurnNumber};
+
+/// Identifies a puzzle
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize, Deserialize, Sequence,
+)]
+pub enum PuzzleId {
+    /// Overlord puzzle: score a nearly-complete scheme
+    FinalScheme,
+    /// Champion puzzle: raid an undefended room to steal the winning points
+    DesperateRaid,
+}
+
+impl PuzzleId {
+    pub fn displayed_name(&self) -> &'static str {
+        match self {
+            PuzzleId::FinalScheme => "Final Scheme",
+            PuzzleId::DesperateRaid => "Desperate Raid",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            PuzzleId::FinalScheme => "Your scheme is almost complete. Finish it.",
+            PuzzleId::DesperateRaid => "The Overlord has left a scheme unguarded.",
+        }
+    }
+}
+
+/// Condition a player must satisfy to solve a puzzle
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum PuzzleGoal {
+    /// Win the game before the end of the player's Nth turn, counting the
+    /// turn on which the puzzle begins as turn 1.
+    WinWithinTurns(TurnNumber),
+}
+
+impl PuzzleGoal {
+    pub fn description(&self) -> String {
+        match self {
+            PuzzleGoal::WinWithinTurns(1) => "Win this turn".to_string(),
+            PuzzleGoal::WinWithinTurns(turns) => format!("Win within {} turns", turns),
+        }
+    }
+
+    /// Returns true if the `side` player can no longer achieve this goal as of
+    /// the provided `turn`, assuming the puzzle started on turn 0.
+    pub fn is_expired(&self, side: Side, turn: TurnData) -> bool {
+        // Within a turn number, the Overlord plays before the Champion
+        let order = |side: Side, turn_number: TurnNumber| {
+            turn_number * 2 + if side == Side::Champion { 1 } else { 0 }
+        };
+        match self {
+            PuzzleGoal::WinWithinTurns(turns) => {
+                order(turn.side, turn.turn_number) > order(side, turns.saturating_sub(1))
+            }
+        }
+    }
+}
+
+/// Restrictions on the actions a player may take while solving a puzzle
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum PuzzleConstraint {
+    /// The player may not take the 'draw card' action
+    NoDrawCards,
+    /// The player may not take the 'gain mana' action
+    NoGainMana,
+}
+
+impl PuzzleConstraint {
+    pub fn description(&self) -> &'static str {
+        match self {
+            PuzzleConstraint::NoDrawCards => "You may not draw cards",
+            PuzzleConstraint::NoGainMana => "You may not gain mana",
+        }
+    }
+}
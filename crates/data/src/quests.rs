@@ -0,0 +1,105 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Daily quests are goals which players can complete across games, earning
+//! coins which can be spent in adventures.
+
+use rand::prelude::IteratorRandom;
+use rand::SeedableRng;
+use rand_xoshiro::Xoshiro256StarStar;
+use serde::{Deserialize, Serialize};
+
+use crate::adventure::Coins;
+use crate::primitives::School;
+
+/// Number of quests offered to each player per day
+pub const DAILY_QUEST_COUNT: usize = 3;
+
+/// Number of seconds in one quest day
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Identifies the type of goal a quest requires
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum QuestKind {
+    /// Win games
+    WinGames,
+    /// Complete successful raids as the Champion
+    WinRaids,
+    /// Play cards of the indicated school
+    PlayCards(School),
+}
+
+impl QuestKind {
+    pub fn description(&self, target: u32) -> String {
+        match self {
+            QuestKind::WinGames if target == 1 => "Win a game".to_string(),
+            QuestKind::WinGames => format!("Win {} games", target),
+            QuestKind::WinRaids => format!("Win {} raids", target),
+            QuestKind::PlayCards(school) => format!("Play {} {:?} cards", target, school),
+        }
+    }
+}
+
+/// A goal a player is currently working towards
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Quest {
+    pub kind: QuestKind,
+    /// Amount of progress required to complete this quest
+    pub target: u32,
+    pub progress: u32,
+    /// Coins awarded when this quest is completed
+    pub reward: Coins,
+}
+
+impl Quest {
+    pub fn new(kind: QuestKind, target: u32, reward: Coins) -> Self {
+        Self { kind, target, progress: 0, reward }
+    }
+
+    pub fn description(&self) -> String {
+        self.kind.description(self.target)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.progress >= self.target
+    }
+}
+
+/// A player's quests for the current day
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct QuestLog {
+    /// Day on which these quests were generated, see [day].
+    pub day: u64,
+    pub quests: Vec<Quest>,
+}
+
+/// Returns the quest day containing `timestamp`, in seconds since the Unix
+/// epoch.
+pub fn day(timestamp: u64) -> u64 {
+    timestamp / SECONDS_PER_DAY
+}
+
+/// Generates the daily quests for a given day. All players receive the same
+/// quests on a given day.
+pub fn generate(day: u64) -> Vec<Quest> {
+    let templates = [
+        Quest::new(QuestKind::WinGames, 1, Coins(75)),
+        Quest::new(QuestKind::WinRaids, 3, Coins(50)),
+        Quest::new(QuestKind::PlayCards(School::Law), 5, Coins(50)),
+        Quest::new(QuestKind::PlayCards(School::Shadow), 5, Coins(50)),
+        Quest::new(QuestKind::PlayCards(School::Primal), 5, Coins(50)),
+    ];
+    let mut rng = Xoshiro256StarStar::seed_from_u64(day);
+    templates.into_iter().choose_multiple(&mut rng, DAILY_QUEST_COUNT)
+}
@@ -0,0 +1,67 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Elo ratings for players, updated after each ranked game
+
+use serde::{Deserialize, Serialize};
+
+use crate::player_name::PlayerId;
+
+/// Rating assigned to players before they have played a ranked game
+pub const STARTING_RATING: i32 = 1200;
+
+/// Maximum rating change from a single game
+pub const K_FACTOR: f64 = 32.0;
+
+/// Number of entries shown on each page of the ranked leaderboard
+pub const LEADERBOARD_PAGE_SIZE: usize = 10;
+
+/// A player's rating and ranked game record
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PlayerRating {
+    pub player_id: PlayerId,
+    pub rating: i32,
+    pub games_played: u32,
+    pub wins: u32,
+}
+
+impl PlayerRating {
+    /// Rating for a player who has not yet played a ranked game
+    pub fn new(player_id: PlayerId) -> Self {
+        Self { player_id, rating: STARTING_RATING, games_played: 0, wins: 0 }
+    }
+}
+
+/// Probability that a player rated `rating` beats an opponent rated
+/// `opponent`, between 0.0 and 1.0.
+pub fn expected_score(rating: i32, opponent: i32) -> f64 {
+    1.0 / (1.0 + 10f64.powf((opponent - rating) as f64 / 400.0))
+}
+
+/// Updates both players' ratings for a ranked game won by `winner`. The
+/// winner gains exactly as many points as the loser loses.
+pub fn record_result(winner: &mut PlayerRating, loser: &mut PlayerRating) {
+    let change = (K_FACTOR * (1.0 - expected_score(winner.rating, loser.rating))).round() as i32;
+    winner.rating += change;
+    loser.rating -= change;
+    winner.wins += 1;
+    winner.games_played += 1;
+    loser.games_played += 1;
+}
+
+/// Orders ratings from best to worst: highest rating first, with ties broken
+/// by wins.
+pub fn rank(ratings: &mut [PlayerRating]) {
+    ratings.sort_by(|a, b| b.rating.cmp(&a.rating).then(b.wins.cmp(&a.wins)));
+}
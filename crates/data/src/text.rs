@@ -23,12 +23,47 @@ use crate::card_definition::{CardDefinition, Cost};
 use crate::card_name::CardName;
 use crate::card_state::{CardData, CardState};
 use crate::game::GameState;
-use crate::primitives::{ActionCount, BreachValue, CardId, ManaValue};
+use crate::primitives::{ActionCount, BoostCount, BreachValue, CardId, LevelValue, ManaValue};
+
+/// Example card state used to render dynamic values in rules text outside of
+/// an active game, e.g. in the deck editor or draft panels.
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy, Default)]
+pub struct PreviewState {
+    pub card_level: LevelValue,
+    pub boost_count: BoostCount,
+    pub stored_mana: ManaValue,
+}
+
+impl PreviewState {
+    /// Example values displayed for cards in the deck editor and draft
+    /// panels. Only values which do not add icons to every card are populated.
+    pub fn example() -> Self {
+        Self::default().boost_count(1)
+    }
+
+    pub fn card_level(mut self, card_level: LevelValue) -> Self {
+        self.card_level = card_level;
+        self
+    }
+
+    pub fn boost_count(mut self, boost_count: BoostCount) -> Self {
+        self.boost_count = boost_count;
+        self
+    }
+
+    pub fn stored_mana(mut self, stored_mana: ManaValue) -> Self {
+        self.stored_mana = stored_mana;
+        self
+    }
+}
 
 /// Provides the context in which rules text is being evaluated, i.e. during an
 /// active game or in a deck editor.
 pub enum RulesTextContext<'a> {
     Default(&'a CardDefinition),
+    /// Evaluates rules text outside of a game using example values from a
+    /// [PreviewState].
+    Preview(&'a CardDefinition, &'a PreviewState),
     Game(&'a GameState, &'a CardState),
 }
 
@@ -36,22 +71,61 @@ impl<'a> RulesTextContext<'a> {
     pub fn card_name(&self) -> CardName {
         match self {
             RulesTextContext::Default(definition) => definition.name,
+            RulesTextContext::Preview(definition, _) => definition.name,
             RulesTextContext::Game(_, card) => card.name,
         }
     }
 
     pub fn card_data(&self) -> Option<&CardData> {
         match self {
-            RulesTextContext::Default(_) => None,
+            RulesTextContext::Default(_) | RulesTextContext::Preview(..) => None,
             RulesTextContext::Game(_, card) => Some(&card.data),
         }
     }
 
+    /// Returns the [PreviewState] for this context, if any.
+    pub fn preview_state(&self) -> Option<&PreviewState> {
+        match self {
+            RulesTextContext::Preview(_, preview) => Some(preview),
+            _ => None,
+        }
+    }
+
+    /// Level counters on this card, either in the active game or in the
+    /// [PreviewState].
+    pub fn card_level(&self) -> LevelValue {
+        match self {
+            RulesTextContext::Default(_) => 0,
+            RulesTextContext::Preview(_, preview) => preview.card_level,
+            RulesTextContext::Game(_, card) => card.data.card_level,
+        }
+    }
+
+    /// Boost activations for this card, either in the active game or in the
+    /// [PreviewState].
+    pub fn boost_count(&self) -> BoostCount {
+        match self {
+            RulesTextContext::Default(_) => 0,
+            RulesTextContext::Preview(_, preview) => preview.boost_count,
+            RulesTextContext::Game(_, card) => card.data.boost_count,
+        }
+    }
+
+    /// Mana stored in this card, either in the active game or in the
+    /// [PreviewState].
+    pub fn stored_mana(&self) -> ManaValue {
+        match self {
+            RulesTextContext::Default(_) => 0,
+            RulesTextContext::Preview(_, preview) => preview.stored_mana,
+            RulesTextContext::Game(_, card) => card.data.stored_mana,
+        }
+    }
+
     /// Invokes the provided `game` function to product a value in the active
     /// game context, otherwise returns some `default`.
     pub fn query_or<T>(&self, default: T, game: impl Fn(&GameState, CardId) -> T) -> T {
         match self {
-            RulesTextContext::Default(_) => default,
+            RulesTextContext::Default(_) | RulesTextContext::Preview(..) => default,
             RulesTextContext::Game(state, card) => game(state, card.id),
         }
     }
@@ -87,6 +161,10 @@ pub enum TextToken {
     Keyword(Keyword),
     Reminder(String),
     Cost(Vec<Self>),
+    /// A value computed from the card's current state, e.g. via
+    /// [RulesTextContext::stored_mana]. Highlighted when rendered with a
+    /// [PreviewState].
+    Computed(Box<Self>),
 }
 
 #[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
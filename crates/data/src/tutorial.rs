@@ -21,15 +21,31 @@ pub enum TutorialMessageKey {
     DeckEditor,
 }
 
+/// Steps of the new player onboarding flow which have been completed
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Hash)]
+pub enum TutorialFlag {
+    /// The player has received the starter collection and decks
+    ReceivedStarterCollection,
+    /// The player has been placed into the tutorial adventure
+    StartedTutorialAdventure,
+}
+
 /// Data model for the player's progress through the game's tutorial
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct TutorialData {
     /// Which tutorial messages has the user seen?
     pub seen: HashSet<TutorialMessageKey>,
+    /// Which onboarding steps has the user completed?
+    #[serde(default)]
+    pub flags: HashSet<TutorialFlag>,
 }
 
 impl TutorialData {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn has_flag(&self, flag: TutorialFlag) -> bool {
+        self.flags.contains(&flag)
+    }
 }
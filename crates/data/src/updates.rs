@@ -12,13 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::borrow::Cow;
+
 use crate::game::GameState;
 use crate::primitives::{AbilityId, CardId, GameObjectId, RoomId, Side};
 
 /// Indicates one game object targeted another with an effect.
 ///
 /// Typically represented in animation as a projectile being fired.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct TargetedInteraction {
     pub source: GameObjectId,
     pub target: GameObjectId,
@@ -35,7 +37,7 @@ pub enum InitiatedBy {
 
 /// Represents a change to the state of the game which should be translated
 /// into a client animation
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub enum GameUpdate {
     /// Indicates that a player's turn has started
     StartTurn(Side),
@@ -47,10 +49,10 @@ pub enum GameUpdate {
     AbilityTriggered(AbilityId),
     /// One or more cards have been drawn by the [Side] player.
     DrawCards(Side, Vec<CardId>),
-    /// A player has shuffled cards into their deck
-    ShuffleIntoDeck,
     /// A project card has been turned face-up.
     UnveilProject(CardId),
+    /// The Overlord has paid the upkeep cost to keep a project in play.
+    PayUpkeep(CardId),
     /// A minion card has been turned face-up.
     SummonMinion(CardId),
     /// The Overlord has leveled up a room
@@ -61,15 +63,51 @@ pub enum GameUpdate {
     TargetedInteraction(TargetedInteraction),
     /// A player has scored a card
     ScoreCard(Side, CardId),
+    /// One or more cards have been destroyed simultaneously
+    DestroyCards(Vec<CardId>),
+    /// A trap card has been triggered by being accessed during a raid
+    TrapTriggered(CardId),
     /// The game has ended and the indicated player has won
     GameOver(Side),
+    /// The indicated player has lost their connection to the game
+    PlayerDisconnected(Side),
+    /// The indicated player has resigned from the game
+    PlayerConceded(Side),
+}
+
+/// Controls the order in which [GameUpdate]s recorded between two snapshot
+/// points are played back. Updates with the same priority are played back in
+/// the order they were recorded.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub enum UpdatePriority {
+    /// Played before all other updates in the same segment, e.g. because it
+    /// explains the cause of subsequent updates.
+    High,
+    #[default]
+    Normal,
+    /// Played after all other updates in the same segment, e.g. because it
+    /// summarizes their outcome.
+    Deferred,
 }
 
 /// A step in the animation process
 #[derive(Debug, Clone)]
 pub struct UpdateStep {
+    /// State of the game at the time this step was recorded.
     pub snapshot: GameState,
-    pub update: GameUpdate,
+    /// Update to animate, or None if this step is a snapshot point which only
+    /// synchronizes game state.
+    pub update: Option<GameUpdate>,
+    pub priority: UpdatePriority,
+}
+
+/// A step to display, produced by [UpdateQueue::ordered_steps].
+#[derive(Debug, Clone)]
+pub struct OrderedStep<'a> {
+    pub snapshot: &'a GameState,
+    /// Update to animate. Owned if several recorded updates were combined into
+    /// this one.
+    pub update: Option<Cow<'a, GameUpdate>>,
 }
 
 /// Standard enum used by APIs to configure their update tracking behavior.
@@ -90,29 +128,112 @@ pub enum Updates {
 /// they took. The way this process works is that a snapshot of the game state
 /// is stored (to capture any mutations that occurred *before* the animation),
 /// and then the update is stored. During the animation process, the
-/// stored snapshots and [GameUpdate]s are played back sequentially.
+/// stored snapshots and [GameUpdate]s are played back via
+/// [Self::ordered_steps].
+///
+/// Code can also call [GameState::record_snapshot_point] to store a snapshot
+/// without any associated update. Snapshot points divide the queue into
+/// segments: within a segment, updates are reordered by [UpdatePriority] and
+/// duplicate updates are removed, but updates never move across a snapshot
+/// point.
+///
+/// Consecutive updates which can be animated together are coalesced into a
+/// single update, e.g. several [GameUpdate::DrawCards] for the same player, and
+/// consecutive snapshot points are collapsed into the last one. This keeps
+/// effects which repeat a mutation many times from producing a long series of
+/// separate animations.
 ///
 /// Many types of state changes are handled automatically by the game state
 /// snapshot system, so appending an update is only needed for custom
 /// animations. For example the system will correctly detect and animate a card
 /// which has moved to a new position.
 #[derive(Debug, Clone)]
-pub struct UpdateTracker {
+pub struct UpdateQueue {
     /// Used to globally disable or enable update tracking
     pub state: Updates,
-    /// List of update steps, either full snapshots of the game state or
-    /// individual mutations.
+    /// List of update steps in the order they were recorded.
     pub steps: Vec<UpdateStep>,
 }
 
-impl Default for UpdateTracker {
+impl Default for UpdateQueue {
     fn default() -> Self {
         Self { state: Updates::Ignore, steps: vec![] }
     }
 }
 
-impl UpdateTracker {
+impl UpdateQueue {
     pub fn new(updates: Updates) -> Self {
         Self { state: updates, steps: vec![] }
     }
+
+    /// Returns the steps in this queue in display order.
+    ///
+    /// Snapshots are always returned in the order they were recorded so that
+    /// the displayed game state never moves backwards. Each update is paired
+    /// with a snapshot taken no earlier than the one recorded alongside it.
+    pub fn ordered_steps(&self) -> Vec<OrderedStep<'_>> {
+        let mut result = vec![];
+        let mut start = 0;
+        while start < self.steps.len() {
+            let end = self.steps[start..]
+                .iter()
+                .position(|step| step.update.is_none())
+                .map_or(self.steps.len(), |offset| start + offset);
+            self.order_segment(start, end, &mut result);
+            if let Some(point) = self.steps.get(end) {
+                match result.last_mut() {
+                    Some(previous) if previous.update.is_none() => {
+                        previous.snapshot = &point.snapshot;
+                    }
+                    _ => result.push(OrderedStep { snapshot: &point.snapshot, update: None }),
+                }
+            }
+            start = end + 1;
+        }
+        result
+    }
+
+    /// Orders the updates in `steps[start..end]`, none of which are snapshot
+    /// points, appending them to `result`.
+    fn order_segment<'a>(&'a self, start: usize, end: usize, result: &mut Vec<OrderedStep<'a>>) {
+        let mut indices = (start..end).collect::<Vec<_>>();
+        indices.sort_by_key(|i| self.steps[*i].priority);
+
+        let segment_start = result.len();
+        let mut seen: Vec<&GameUpdate> = vec![];
+        let mut latest = start;
+        for i in indices {
+            let Some(update) = self.steps[i].update.as_ref() else { continue };
+            if seen.contains(&update) {
+                continue;
+            }
+            seen.push(update);
+            latest = latest.max(i);
+            let snapshot = &self.steps[latest].snapshot;
+
+            if result.len() > segment_start {
+                let previous = result.last_mut().expect("previous step");
+                if let Some(combined) = previous.update.as_deref().and_then(|p| coalesce(p, update))
+                {
+                    *previous = OrderedStep { snapshot, update: Some(Cow::Owned(combined)) };
+                    continue;
+                }
+            }
+
+            result.push(OrderedStep { snapshot, update: Some(Cow::Borrowed(update)) });
+        }
+    }
+}
+
+/// Combines `next` into the `previous` update if they can be displayed as a
+/// single animation, returning the combined update.
+fn coalesce(previous: &GameUpdate, next: &GameUpdate) -> Option<GameUpdate> {
+    match (previous, next) {
+        (GameUpdate::DrawCards(side, cards), GameUpdate::DrawCards(next_side, next_cards))
+            if side == next_side =>
+        {
+            Some(GameUpdate::DrawCards(*side, cards.iter().chain(next_cards).copied().collect()))
+        }
+        _ => None,
+    }
 }
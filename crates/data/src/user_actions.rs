@@ -16,9 +16,15 @@ use serde::{Deserialize, Serialize};
 
 use crate::adventure_action::AdventureAction;
 use crate::card_name::CardName;
+use crate::cosmetics::{BoardSkin, CardBack, Cosmetic};
+use crate::draft::{DraftAction, NewDraftAction};
+use crate::emotes::Emote;
+use crate::game::GameRules;
 use crate::game_actions::GameAction;
+use crate::player_data::{AnimationSpeed, AutomationPreferences, IconMode};
 use crate::player_name::{NamedPlayer, PlayerId};
 use crate::primitives::{ActionCount, DeckIndex, GameId, ManaValue, PointsValue, School, Side};
+use crate::puzzles::PuzzleId;
 
 #[derive(Eq, PartialEq, Hash, Debug, Copy, Clone, Default, Serialize, Deserialize)]
 pub struct NewGameDebugOptions {
@@ -28,6 +34,27 @@ pub struct NewGameDebugOptions {
     pub override_game_id: Option<GameId>,
 }
 
+/// Rule options for a custom game. Both players must request a game with the
+/// same preset before it can begin.
+#[derive(Eq, PartialEq, Hash, Debug, Copy, Clone, Default, Serialize, Deserialize)]
+pub struct GamePreset {
+    pub rules: GameRules,
+    /// If true, all game events will be non-random
+    pub deterministic: bool,
+    /// If true, play a best-of-three match with players swapping sides after
+    /// each game instead of a single game.
+    #[serde(default)]
+    pub best_of_three: bool,
+    /// If true, the result of this game updates both players' ratings. Ranked
+    /// games must use the standard [GameRules].
+    #[serde(default)]
+    pub ranked: bool,
+    /// If true, reveal both players' hands and remaining decks when the game
+    /// ends.
+    #[serde(default)]
+    pub reveal_on_game_over: bool,
+}
+
 /// Action to initiate a new game
 #[derive(Eq, PartialEq, Hash, Debug, Copy, Clone, Serialize, Deserialize)]
 pub struct NewGameAction {
@@ -37,6 +64,9 @@ pub struct NewGameAction {
     pub opponent: PlayerId,
     /// Debug configuration for this game
     pub debug_options: Option<NewGameDebugOptions>,
+    /// Rule options for this game
+    #[serde(default)]
+    pub preset: GamePreset,
 }
 
 /// Actions that can be taken from the debug panel, should not be exposed in
@@ -59,12 +89,34 @@ pub enum DebugAction {
     AddMana(ManaValue),
     AddActionPoints(ActionCount),
     AddScore(PointsValue),
+    /// Saves the current game state to the indicated slot, asking for
+    /// confirmation first if this would replace an existing save.
     SaveState(u64),
     LoadState(u64),
     SetNamedPlayer(Side, NamedPlayer),
 
     /// Gives the player copies of every card
     FullCollection,
+
+    /// Toggles [crate::game::GameConfiguration::explain_agents] for the current
+    /// game.
+    ToggleAgentExplanations,
+
+    /// Saves the current game state to the indicated slot, replacing any
+    /// existing save.
+    OverwriteSaveState(u64),
+
+    /// Panics while handling an action in the current game, in order to test
+    /// crash recovery.
+    Panic,
+
+    /// Toggles [crate::game::GameConfiguration::trace_delegates] for the
+    /// current game.
+    ToggleDelegateTrace,
+
+    /// Starts an exhibition game between the provided Overlord and Champion AI
+    /// agents, which the current player watches as a spectator.
+    StartExhibition(NamedPlayer, NamedPlayer),
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Hash)]
@@ -75,6 +127,10 @@ pub enum DeckEditorAction {
     AddToDeck(CardName),
     /// Remove one copy of a card from a deck
     RemoveFromDeck(CardName),
+    /// Equip an owned cosmetic item on a deck
+    EquipCosmetic(Cosmetic),
+    /// Remove an equipped cosmetic item from a deck
+    UnequipCosmetic(Cosmetic),
 }
 
 impl From<DeckEditorAction> for UserAction {
@@ -91,6 +147,8 @@ pub enum OldDeckEditorAction {
     AddToDeck(CardName, DeckIndex),
     /// Remove one copy of a card from a deck
     RemoveFromDeck(CardName, DeckIndex),
+    /// Permanently delete a deck
+    DeleteDeck(DeckIndex),
 }
 
 impl From<OldDeckEditorAction> for UserAction {
@@ -108,6 +166,12 @@ pub enum UserAction {
     /// Initiate a new adventure, generating a new map and replacing any
     /// existing adventure.
     NewAdventure(Side),
+    /// Initiate a new adventure using the shared seed for the current day,
+    /// replacing any existing adventure.
+    NewDailyAdventure(Side),
+    /// Initiate a new adventure using the seed entered in the seed text field,
+    /// replacing any existing adventure.
+    NewSeededAdventure(Side),
     /// Perform an action within an ongoing adventure
     AdventureAction(AdventureAction),
     /// Remove a player's current adventure, i.e. to stop displaying the
@@ -115,10 +179,23 @@ pub enum UserAction {
     /// adventure' action transitions it to its summary state.
     LeaveAdventure,
 
+    /// Request a new draft against an opponent, followed by a game using the
+    /// drafted decks.
+    NewDraft(NewDraftAction),
+    /// Perform an action within an ongoing draft
+    DraftAction(DraftAction),
+
     /// Create a new game (match between two players)
     NewGame(NewGameAction),
+    /// Start a new game set up as the indicated puzzle, replacing the
+    /// player's current game.
+    StartPuzzle(PuzzleId),
     /// Perform an action within a game.
     GameAction(GameAction),
+    /// Concede the game the player is currently playing in, awarding victory
+    /// to their opponent. Unlike the 'resign' game action, this can be
+    /// performed at any time.
+    Concede,
     /// Leave the game that the player is currently playing in. Typically
     /// invoked from the game over screen, the 'resign' action is used to
     /// end the game itself.
@@ -128,4 +205,36 @@ pub enum UserAction {
     DeckEditorAction(DeckEditorAction),
     /// Perform an action in the deck editor
     OldDeckEditorAction(OldDeckEditorAction),
+    /// Spend dust to add one copy of a card to the player's collection
+    CraftCard(CardName),
+    /// Remove one copy of a card from the player's collection in exchange for
+    /// dust
+    DisenchantCard(CardName),
+
+    /// Send an emote to the opponent in the current game
+    SendEmote(Emote),
+    /// Enable or disable display of emotes sent by opponents
+    SetMuteEmotes(bool),
+    /// Set the music volume, as a percentage between 0 and 100
+    SetMusicVolume(u32),
+    /// Set the sound effect volume, as a percentage between 0 and 100
+    SetSoundEffectsVolume(u32),
+    /// Enable or disable all music and sound effects
+    SetMuteAudio(bool),
+    /// Select the palette used to display card icons
+    SetIconMode(IconMode),
+    /// Select the speed at which in-game animations are played
+    SetAnimationSpeed(AnimationSpeed),
+    /// Opt in to or out of contributing games to card statistics
+    SetShareCardStatistics(bool),
+    /// Enable or disable keyboard shortcuts for prompt responses
+    SetDisableHotkeys(bool),
+    /// Select which raid prompts are resolved automatically
+    SetAutomationPreferences(AutomationPreferences),
+
+    /// Select an owned card back, or revert to the standard card back for the
+    /// player's school if `None`.
+    SetCardBack(Option<CardBack>),
+    /// Select an owned board skin, or revert to the standard arena if `None`.
+    SetBoardSkin(Option<BoardSkin>),
 }
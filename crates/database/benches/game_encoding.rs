@@ -0,0 +1,56 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use cards::{decklists, initialize};
+use criterion::measurement::WallTime;
+use criterion::{criterion_group, criterion_main, BenchmarkGroup, Criterion};
+use database::game_encoding;
+
+criterion_group!(benches, serialize, deserialize);
+criterion_main!(benches);
+
+fn configure(group: &mut BenchmarkGroup<WallTime>) {
+    initialize::run();
+    group.confidence_level(0.99).noise_threshold(0.025).measurement_time(Duration::from_secs(10));
+}
+
+pub fn serialize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("serialize");
+    configure(&mut group);
+    let game = decklists::canonical_game().unwrap();
+    group.bench_function("json", |b| {
+        b.iter(|| serde_json::to_vec(&game).unwrap());
+    });
+    group.bench_function("messagepack", |b| {
+        b.iter(|| game_encoding::encode(&game).unwrap());
+    });
+    group.finish();
+}
+
+pub fn deserialize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("deserialize");
+    configure(&mut group);
+    let game = decklists::canonical_game().unwrap();
+    let json = serde_json::to_vec(&game).unwrap();
+    let encoded = game_encoding::encode(&game).unwrap();
+    group.bench_function("json", |b| {
+        b.iter(|| game_encoding::decode(&json).unwrap());
+    });
+    group.bench_function("messagepack", |b| {
+        b.iter(|| game_encoding::decode(&encoded).unwrap());
+    });
+    group.finish();
+}
@@ -14,22 +14,40 @@
 
 //! Core database implementation, handles querying and storing game state.
 
+pub mod game_encoding;
+
 use std::sync::Mutex;
 
+use adapters::response_builder::CardPositions;
 use anyhow::Result;
+use data::card_name::CardName;
+use data::card_stats::CardStatistics;
 use data::game::GameState;
+use data::leaderboard::LeaderboardEntry;
+use data::match_state::MatchState;
 use data::player_data::PlayerData;
 use data::player_name::PlayerId;
-use data::primitives::GameId;
+use data::primitives::{GameId, MatchId};
+use data::ratings::PlayerRating;
 use once_cell::sync::Lazy;
 use prost::Message;
 use protos::spelldawn::player_identifier::PlayerIdentifierType;
-use protos::spelldawn::PlayerIdentifier;
+use protos::spelldawn::{CardIdentifier, ObjectPosition, PlayerIdentifier};
 use rules::dispatch;
 use serde_json::{de, ser};
 use sled::{Db, Tree};
+use tracing::instrument;
 use with_error::WithError;
 
+/// Card positions as stored in the database, along with the game they were
+/// recorded for. Each entry holds the protobuf encoding of a card identifier
+/// and its position.
+type StoredPositions = (GameId, Vec<(Vec<u8>, Vec<u8>)>);
+
+/// Key under which the session token signing key is stored in the
+/// 'credentials' table.
+const SIGNING_KEY: &str = "signing_key";
+
 static DATABASE_PATH: Lazy<Mutex<Option<String>>> = Lazy::new(|| Mutex::new(None));
 
 static DATABASE: Lazy<Db> = Lazy::new(|| {
@@ -72,6 +90,71 @@ pub trait Database: Send + Sync {
     /// existing mapping or storing a new randomly-generated ID for this
     /// identifier.
     fn adapt_player_identifier(&mut self, identifier: &PlayerIdentifier) -> Result<PlayerId>;
+
+    /// Look up the secret key used to sign session tokens, if one has been
+    /// created.
+    fn signing_key(&self) -> Result<Option<Vec<u8>>>;
+
+    /// Stores `key` as the secret key used to sign session tokens if no key
+    /// exists yet. Returns the key which is stored after this call.
+    fn create_signing_key(&mut self, key: &[u8]) -> Result<Vec<u8>>;
+
+    /// Look up the hash of the secret a player authenticates with, if one has
+    /// been created.
+    fn player_secret(&self, player_id: PlayerId) -> Result<Option<Vec<u8>>>;
+
+    /// Stores the hash of the secret a player authenticates with if the
+    /// player does not have one yet. Returns false if a secret already exists.
+    fn create_player_secret(&mut self, player_id: PlayerId, hash: &[u8]) -> Result<bool>;
+
+    /// Look up the recorded results of seeded adventure runs for the given
+    /// seed. Returns an empty list if no runs have been recorded.
+    fn leaderboard(&self, seed: u64) -> Result<Vec<LeaderboardEntry>>;
+
+    /// Store the results of seeded adventure runs for the given seed.
+    fn write_leaderboard(&mut self, seed: u64, entries: &[LeaderboardEntry]) -> Result<()>;
+
+    /// Look up an ongoing or completed [MatchState] by ID. It is an error to
+    /// look up an ID which does not exist.
+    fn match_state(&self, id: MatchId) -> Result<MatchState>;
+
+    /// Store a [MatchState] in the database based on its ID.
+    fn write_match(&mut self, state: &MatchState) -> Result<()>;
+
+    /// Look up a player's [PlayerRating], if they have played a ranked game.
+    fn rating(&self, player_id: PlayerId) -> Result<Option<PlayerRating>>;
+
+    /// Look up the ratings of every player who has played a ranked game, in
+    /// no particular order.
+    fn ratings(&self) -> Result<Vec<PlayerRating>>;
+
+    /// Store a [PlayerRating] in the database based on its player ID.
+    fn write_rating(&mut self, rating: &PlayerRating) -> Result<()>;
+
+    /// Look up the aggregate [CardStatistics] for a card, if any have been
+    /// recorded.
+    fn card_statistics(&self, card_name: CardName) -> Result<Option<CardStatistics>>;
+
+    /// Look up the statistics for every card which has been recorded, in no
+    /// particular order.
+    fn all_card_statistics(&self) -> Result<Vec<CardStatistics>>;
+
+    /// Store [CardStatistics] in the database based on their card name.
+    fn write_card_statistics(&mut self, statistics: &CardStatistics) -> Result<()>;
+
+    /// Look up the client card positions most recently sent to a player for
+    /// the indicated game. Returns an empty map if no positions have been
+    /// recorded for this game.
+    fn card_positions(&self, player_id: PlayerId, game_id: GameId) -> Result<CardPositions>;
+
+    /// Store the client card positions most recently sent to a player,
+    /// replacing any positions recorded for a previous game.
+    fn write_card_positions(
+        &mut self,
+        player_id: PlayerId,
+        game_id: GameId,
+        positions: &CardPositions,
+    ) -> Result<()>;
 }
 
 /// Database implementation based on the sled database
@@ -86,28 +169,30 @@ impl Database for SledDatabase {
     // layers for different things, e.g. an embedded database for game state vs a
     // cloud storage solution for collection management.
 
+    #[instrument(level = "debug", skip(self))]
     fn generate_game_id(&self) -> Result<GameId> {
         Ok(GameId::new(DATABASE.generate_id().with_error(|| "Error generating ID")?))
     }
 
+    #[instrument(level = "debug", skip(self))]
     fn has_game(&self, id: GameId) -> Result<bool> {
         games()?.contains_key(id.key()).with_error(|| format!("Error reading key {:?}", id))
     }
 
+    #[instrument(level = "debug", skip(self))]
     fn game(&self, id: GameId) -> Result<GameState> {
         let content = games()?
             .get(id.key())
             .with_error(|| format!("Error reading  game: {:?}", id))?
             .with_error(|| format!("Game not found: {:?}", id))?;
-        let mut game = de::from_slice(content.as_ref())
-            .with_error(|| format!("Error deserializing game {:?}", id))?;
+        let mut game = game_encoding::decode(content.as_ref())?;
         dispatch::populate_delegate_cache(&mut game);
         Ok(game)
     }
 
+    #[instrument(level = "debug", skip_all, fields(game_id = ?game.id))]
     fn write_game(&mut self, game: &GameState) -> Result<()> {
-        let serialized =
-            ser::to_vec(game).with_error(|| format!("Error serializing game {:?}", game.id))?;
+        let serialized = game_encoding::encode(game)?;
         let result = games()?
             .insert(game.id.key(), serialized)
             .map(|_| ()) // Ignore previously-set value
@@ -120,6 +205,7 @@ impl Database for SledDatabase {
         result
     }
 
+    #[instrument(level = "debug", skip(self))]
     fn player(&self, player_id: PlayerId) -> Result<Option<PlayerData>> {
         Ok(
             if let Some(content) = players()?
@@ -134,6 +220,7 @@ impl Database for SledDatabase {
         )
     }
 
+    #[instrument(level = "debug", skip_all, fields(player_id = ?player.id))]
     fn write_player(&mut self, player: &PlayerData) -> Result<()> {
         let serialized = ser::to_vec(player)
             .with_error(|| format!("Error serializing player {:?}", player.id))?;
@@ -149,6 +236,7 @@ impl Database for SledDatabase {
         result
     }
 
+    #[instrument(level = "debug", skip_all)]
     fn adapt_player_identifier(&mut self, identifier: &PlayerIdentifier) -> Result<PlayerId> {
         if let Some(PlayerIdentifierType::ServerIdentifier(bytes)) =
             &identifier.player_identifier_type
@@ -166,6 +254,255 @@ impl Database for SledDatabase {
             Ok(PlayerId::Database(result))
         }
     }
+
+    #[instrument(level = "debug", skip_all)]
+    fn signing_key(&self) -> Result<Option<Vec<u8>>> {
+        Ok(credentials()?
+            .get(SIGNING_KEY)
+            .with_error(|| "Error reading signing key")?
+            .map(|key| key.to_vec()))
+    }
+
+    #[instrument(level = "debug", skip_all)]
+    fn create_signing_key(&mut self, key: &[u8]) -> Result<Vec<u8>> {
+        let credentials = credentials()?;
+        let _existing = credentials
+            .compare_and_swap(SIGNING_KEY, None as Option<&[u8]>, Some(key))
+            .with_error(|| "Error writing signing key")?;
+        if self.flush_on_write {
+            DATABASE.flush()?;
+        }
+
+        self.signing_key()?.with_error(|| "Signing key not found")
+    }
+
+    #[instrument(level = "debug", skip(self))]
+    fn player_secret(&self, player_id: PlayerId) -> Result<Option<Vec<u8>>> {
+        let key = ser::to_vec(&player_id).with_error(|| "Error serializing player ID")?;
+        Ok(player_secrets()?
+            .get(key)
+            .with_error(|| format!("Error reading secret for {:?}", player_id))?
+            .map(|hash| hash.to_vec()))
+    }
+
+    #[instrument(level = "debug", skip(self, hash))]
+    fn create_player_secret(&mut self, player_id: PlayerId, hash: &[u8]) -> Result<bool> {
+        let key = ser::to_vec(&player_id).with_error(|| "Error serializing player ID")?;
+        let created = player_secrets()?
+            .compare_and_swap(key, None as Option<&[u8]>, Some(hash))
+            .with_error(|| format!("Error writing secret for {:?}", player_id))?
+            .is_ok();
+        if self.flush_on_write {
+            DATABASE.flush()?;
+        }
+
+        Ok(created)
+    }
+
+    #[instrument(level = "debug", skip(self))]
+    fn leaderboard(&self, seed: u64) -> Result<Vec<LeaderboardEntry>> {
+        Ok(
+            if let Some(content) = leaderboards()?
+                .get(seed.to_be_bytes())
+                .with_error(|| format!("Error reading leaderboard for seed {}", seed))?
+            {
+                de::from_slice(content.as_ref())
+                    .with_error(|| format!("Error deserializing leaderboard for seed {}", seed))?
+            } else {
+                vec![]
+            },
+        )
+    }
+
+    #[instrument(level = "debug", skip(self, entries))]
+    fn write_leaderboard(&mut self, seed: u64, entries: &[LeaderboardEntry]) -> Result<()> {
+        let serialized = ser::to_vec(entries)
+            .with_error(|| format!("Error serializing leaderboard for seed {}", seed))?;
+        leaderboards()?
+            .insert(seed.to_be_bytes(), serialized)
+            .with_error(|| format!("Error writing leaderboard for seed {}", seed))?;
+
+        if self.flush_on_write {
+            DATABASE.flush()?;
+        }
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip(self))]
+    fn match_state(&self, id: MatchId) -> Result<MatchState> {
+        let content = matches()?
+            .get(id.key())
+            .with_error(|| format!("Error reading match: {:?}", id))?
+            .with_error(|| format!("Match not found: {:?}", id))?;
+        de::from_slice(content.as_ref())
+            .with_error(|| format!("Error deserializing match {:?}", id))
+    }
+
+    #[instrument(level = "debug", skip_all, fields(match_id = ?state.id))]
+    fn write_match(&mut self, state: &MatchState) -> Result<()> {
+        let serialized =
+            ser::to_vec(state).with_error(|| format!("Error serializing match {:?}", state.id))?;
+        matches()?
+            .insert(state.id.key(), serialized)
+            .with_error(|| format!("Error writing match {:?}", state.id))?;
+
+        if self.flush_on_write {
+            DATABASE.flush()?;
+        }
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip(self))]
+    fn rating(&self, player_id: PlayerId) -> Result<Option<PlayerRating>> {
+        let key = ser::to_vec(&player_id)
+            .with_error(|| format!("Error serializing player ID {:?}", player_id))?;
+        Ok(
+            if let Some(content) = ratings()?
+                .get(key)
+                .with_error(|| format!("Error reading rating for {:?}", player_id))?
+            {
+                Some(
+                    de::from_slice(content.as_ref())
+                        .with_error(|| format!("Error deserializing rating for {:?}", player_id))?,
+                )
+            } else {
+                None
+            },
+        )
+    }
+
+    #[instrument(level = "debug", skip(self))]
+    fn ratings(&self) -> Result<Vec<PlayerRating>> {
+        ratings()?
+            .iter()
+            .map(|entry| {
+                let (_, content) = entry.with_error(|| "Error reading ratings")?;
+                de::from_slice(content.as_ref()).with_error(|| "Error deserializing rating")
+            })
+            .collect()
+    }
+
+    #[instrument(level = "debug", skip_all, fields(player_id = ?rating.player_id))]
+    fn write_rating(&mut self, rating: &PlayerRating) -> Result<()> {
+        let key = ser::to_vec(&rating.player_id)
+            .with_error(|| format!("Error serializing player ID {:?}", rating.player_id))?;
+        let serialized = ser::to_vec(rating)
+            .with_error(|| format!("Error serializing rating for {:?}", rating.player_id))?;
+        ratings()?
+            .insert(key, serialized)
+            .with_error(|| format!("Error writing rating for {:?}", rating.player_id))?;
+
+        if self.flush_on_write {
+            DATABASE.flush()?;
+        }
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip(self))]
+    fn card_statistics(&self, card_name: CardName) -> Result<Option<CardStatistics>> {
+        let key = ser::to_vec(&card_name)
+            .with_error(|| format!("Error serializing card name {:?}", card_name))?;
+        Ok(
+            if let Some(content) = card_statistics()?
+                .get(key)
+                .with_error(|| format!("Error reading statistics for {:?}", card_name))?
+            {
+                Some(
+                    de::from_slice(content.as_ref()).with_error(|| {
+                        format!("Error deserializing statistics for {:?}", card_name)
+                    })?,
+                )
+            } else {
+                None
+            },
+        )
+    }
+
+    #[instrument(level = "debug", skip(self))]
+    fn all_card_statistics(&self) -> Result<Vec<CardStatistics>> {
+        card_statistics()?
+            .iter()
+            .map(|entry| {
+                let (_, content) = entry.with_error(|| "Error reading card statistics")?;
+                de::from_slice(content.as_ref()).with_error(|| "Error deserializing statistics")
+            })
+            .collect()
+    }
+
+    #[instrument(level = "debug", skip_all, fields(card_name = ?statistics.card_name))]
+    fn write_card_statistics(&mut self, statistics: &CardStatistics) -> Result<()> {
+        let name = statistics.card_name;
+        let key =
+            ser::to_vec(&name).with_error(|| format!("Error serializing card name {:?}", name))?;
+        let serialized = ser::to_vec(statistics)
+            .with_error(|| format!("Error serializing statistics for {:?}", name))?;
+        card_statistics()?
+            .insert(key, serialized)
+            .with_error(|| format!("Error writing statistics for {:?}", name))?;
+
+        if self.flush_on_write {
+            DATABASE.flush()?;
+        }
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip(self))]
+    fn card_positions(&self, player_id: PlayerId, game_id: GameId) -> Result<CardPositions> {
+        let Some(content) = card_positions()?
+            .get(player_id.database_key()?)
+            .with_error(|| format!("Error reading card positions for {:?}", player_id))?
+        else {
+            return Ok(CardPositions::default());
+        };
+
+        let (stored_id, entries): StoredPositions = bincode::deserialize(content.as_ref())
+            .with_error(|| format!("Error deserializing card positions for {:?}", player_id))?;
+        if stored_id != game_id {
+            return Ok(CardPositions::default());
+        }
+
+        entries
+            .iter()
+            .map(|(id, position)| {
+                Ok((
+                    CardIdentifier::decode(id.as_slice())
+                        .with_error(|| "Error decoding card identifier")?,
+                    ObjectPosition::decode(position.as_slice())
+                        .with_error(|| "Error decoding card position")?,
+                ))
+            })
+            .collect()
+    }
+
+    #[instrument(level = "debug", skip(self, positions))]
+    fn write_card_positions(
+        &mut self,
+        player_id: PlayerId,
+        game_id: GameId,
+        positions: &CardPositions,
+    ) -> Result<()> {
+        // Protobuf messages do not implement serde traits, so each entry is
+        // stored in its protobuf encoding
+        let entries = positions
+            .iter()
+            .map(|(id, position)| (id.encode_to_vec(), position.encode_to_vec()))
+            .collect::<Vec<_>>();
+        let serialized = bincode::serialize(&(game_id, entries))
+            .with_error(|| format!("Error serializing card positions for {:?}", player_id))?;
+        card_positions()?
+            .insert(player_id.database_key()?, serialized)
+            .with_error(|| format!("Error writing card positions for {:?}", player_id))?;
+
+        if self.flush_on_write {
+            DATABASE.flush()?;
+        }
+
+        Ok(())
+    }
 }
 
 fn games() -> Result<Tree> {
@@ -179,3 +516,31 @@ fn players() -> Result<Tree> {
 fn player_ids() -> Result<Tree> {
     DATABASE.open_tree("player_ids").with_error(|| "Error opening the 'player_ids' table")
 }
+
+fn credentials() -> Result<Tree> {
+    DATABASE.open_tree("credentials").with_error(|| "Error opening the 'credentials' table")
+}
+
+fn player_secrets() -> Result<Tree> {
+    DATABASE.open_tree("player_secrets").with_error(|| "Error opening the 'player_secrets' table")
+}
+
+fn leaderboards() -> Result<Tree> {
+    DATABASE.open_tree("leaderboards").with_error(|| "Error opening the 'leaderboards' table")
+}
+
+fn matches() -> Result<Tree> {
+    DATABASE.open_tree("matches").with_error(|| "Error opening the 'matches' table")
+}
+
+fn ratings() -> Result<Tree> {
+    DATABASE.open_tree("ratings").with_error(|| "Error opening the 'ratings' table")
+}
+
+fn card_statistics() -> Result<Tree> {
+    DATABASE.open_tree("card_statistics").with_error(|| "Error opening the 'card_statistics' table")
+}
+
+fn card_positions() -> Result<Tree> {
+    DATABASE.open_tree("card_positions").with_error(|| "Error opening the 'card_positions' table")
+}
@@ -0,0 +1,62 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Binary encoding for stored [GameState]s.
+//!
+//! Games are written on every game action, so they use MessagePack instead of
+//! JSON, which is considerably faster to encode and smaller for a typical game
+//! (see `benches/game_encoding.rs`). Games stored as JSON by earlier versions
+//! can still be read.
+//!
+//! Structs are encoded as maps keyed by field name, so like JSON the encoding
+//! is self-describing: fields added with `#[serde(default)]` can be read from
+//! games stored before they existed, and [FORMAT_VERSION] only changes if the
+//! encoding itself changes. Games stored in the bincode layouts used by
+//! earlier versions cannot be read.
+
+use anyhow::Result;
+use data::game::GameState;
+use with_error::{fail, WithError};
+
+/// Leading byte of every encoded game, identifying the encoding of the data
+/// which follows.
+pub const FORMAT_VERSION: u8 = 21;
+
+/// JSON-encoded games always begin with the start of an object.
+const JSON_PREFIX: u8 = b'{';
+
+/// Encodes a [GameState] for storage.
+pub fn encode(game: &GameState) -> Result<Vec<u8>> {
+    let mut result = vec![FORMAT_VERSION];
+    rmp_serde::encode::write_named(&mut result, game)
+        .with_error(|| format!("Error serializing game {:?}", game.id))?;
+    Ok(result)
+}
+
+/// Decodes a [GameState] previously produced by [encode], or a legacy
+/// JSON-encoded game.
+///
+/// Does not populate the game's delegate cache.
+pub fn decode(bytes: &[u8]) -> Result<GameState> {
+    match bytes.first() {
+        Some(&FORMAT_VERSION) => {
+            rmp_serde::from_slice(&bytes[1..]).with_error(|| "Error deserializing game")
+        }
+        Some(&JSON_PREFIX) => {
+            serde_json::from_slice(bytes).with_error(|| "Error deserializing JSON game")
+        }
+        Some(version) => fail!("Unknown game format version {}", version),
+        None => fail!("Empty game data"),
+    }
+}
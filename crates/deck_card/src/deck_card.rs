@@ -30,7 +30,8 @@ use core_ui::draggable::Draggable;
 use core_ui::prelude::*;
 use core_ui::style;
 use data::card_name::CardName;
-use data::text::RulesTextContext;
+use data::player_data::IconMode;
+use data::text::{PreviewState, RulesTextContext};
 use element_names::ElementName;
 use protos::spelldawn::{BackgroundImageAutoSize, CardIcon, Dimension, FlexAlign, FlexPosition};
 use rules_text::card_icons;
@@ -64,6 +65,7 @@ pub struct DeckCard {
     layout: Layout,
     draggable: Option<Draggable>,
     quantity_element_name: ElementName,
+    preview: PreviewState,
 }
 
 impl DeckCard {
@@ -75,6 +77,7 @@ impl DeckCard {
             layout: Layout::default(),
             draggable: None,
             quantity_element_name: ElementName::new("Quantity"),
+            preview: PreviewState::default(),
         }
     }
 
@@ -102,12 +105,20 @@ impl DeckCard {
         self.quantity_element_name = quantity_element_name;
         self
     }
+
+    /// Example card state used to compute dynamic values in this card's rules
+    /// text and icons.
+    pub fn preview(mut self, preview: PreviewState) -> Self {
+        self.preview = preview;
+        self
+    }
 }
 
 impl Component for DeckCard {
     fn build(self) -> Option<Node> {
         let definition = rules::get(self.name);
-        let icons = card_icons::build(&RulesTextContext::Default(definition), definition, true);
+        let context = RulesTextContext::Preview(definition, &self.preview);
+        let icons = card_icons::build(&context, definition, true, IconMode::Standard);
 
         let result = Column::new(element_names::deck_card(self.name))
             .style(self.layout.to_style().align_items(FlexAlign::Center))
@@ -130,7 +141,7 @@ impl Component for DeckCard {
                 ),
             )
             .child(DeckCardName::new(definition, self.height))
-            .child(DeckCardText::new(definition, self.height))
+            .child(DeckCardText::new(definition, &self.preview, self.height))
             .child(icons.top_left_icon.map(|icon| {
                 DeckCardIcon::new(icon, self.height).name("TopLeftIcon").layout(
                     Layout::new()
@@ -144,6 +155,7 @@ impl Component for DeckCard {
                         background: Some(style::sprite("Sprites/QuantityBackground")),
                         text: Some(format!("{}x", self.quantity)),
                         background_scale: None,
+                        text_color: None,
                     },
                     self.height,
                 )
@@ -15,25 +15,33 @@
 use core_ui::prelude::*;
 use core_ui::text::Text;
 use data::card_definition::CardDefinition;
-use data::text::RulesTextContext;
+use data::text::{PreviewState, RulesTextContext};
 use protos::spelldawn::{FlexAlign, FlexJustify, FlexPosition, WhiteSpace};
 
 use crate::CardHeight;
 
 pub struct DeckCardText<'a> {
     definition: &'a CardDefinition,
+    preview: &'a PreviewState,
     card_height: CardHeight,
 }
 
 impl<'a> DeckCardText<'a> {
-    pub fn new(definition: &'a CardDefinition, card_height: CardHeight) -> Self {
-        Self { definition, card_height }
+    pub fn new(
+        definition: &'a CardDefinition,
+        preview: &'a PreviewState,
+        card_height: CardHeight,
+    ) -> Self {
+        Self { definition, preview, card_height }
     }
 }
 
 impl<'a> Component for DeckCardText<'a> {
     fn build(self) -> Option<Node> {
-        let text = rules_text::build(&RulesTextContext::Default(self.definition), self.definition);
+        let text = rules_text::build(
+            &RulesTextContext::Preview(self.definition, self.preview),
+            self.definition,
+        );
         Column::new("RulesText")
             .style(
                 Style::new()
@@ -12,8 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use assets;
-use assets::CardIconType;
+use assets::{self, CardIconType};
 use core_ui::action_builder::ActionBuilder;
 use core_ui::design::{BackgroundColor, Font, FontColor, FontSize, OVERLAY_BORDER};
 use core_ui::draggable::Draggable;
@@ -21,6 +20,7 @@ use core_ui::prelude::*;
 use core_ui::style::Corner;
 use core_ui::text::Text;
 use data::card_name::CardName;
+use data::player_data::IconMode;
 use deck_card::DeckCard;
 use protos::spelldawn::{
     BackgroundImageAutoSize, FlexAlign, FlexDirection, FlexJustify, FlexPosition, ImageScaleMode,
@@ -114,7 +114,7 @@ impl Component for CardListCardName {
                             .margin(Edge::Left, 12.px())
                             .margin(Edge::Right, 4.px())
                             .flex_shrink(0.0)
-                            .background_image(assets::card_icon(icon))
+                            .background_image(assets::card_icon(icon, IconMode::Standard))
                             .background_image_auto_size(BackgroundImageAutoSize::FromHeight)
                             .justify_content(FlexJustify::Center)
                             .align_items(FlexAlign::Center),
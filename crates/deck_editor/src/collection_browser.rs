@@ -20,16 +20,19 @@ use core_ui::animations::{
 use core_ui::conditional::Conditional;
 use core_ui::draggable::Draggable;
 use core_ui::drop_target::DropTarget;
+use core_ui::panels::Panels;
 use core_ui::prelude::*;
+use core_ui::virtual_list::VirtualList;
 use data::card_name::CardName;
 use data::deck::Deck;
 use data::player_data::PlayerData;
-use data::primitives::Side;
+use data::primitives::{DeckId, Side};
+use data::text::PreviewState;
 use data::user_actions::DeckEditorAction;
 use deck_card::deck_card_slot::DeckCardSlot;
 use deck_card::{CardHeight, DeckCard};
 use element_names::{CurrentDraggable, ElementName, TargetName};
-use panel_address::CollectionBrowserFilters;
+use panel_address::{CollectionBrowserFilters, DeckEditorData, PanelAddress};
 use protos::spelldawn::{FlexAlign, FlexDirection, FlexJustify};
 
 use crate::card_list;
@@ -60,39 +63,23 @@ pub struct CollectionBrowser<'a> {
 }
 
 impl<'a> CollectionBrowser<'a> {
-    fn card_row(&self, cards: Vec<&(CardName, u32)>) -> impl Component {
-        let empty_slots = if cards.len() < 4 { 4 - cards.len() } else { 0 };
-        Row::new("CardRow")
-            .style(
-                Style::new()
-                    .flex_grow(1.0)
-                    .align_items(FlexAlign::Center)
-                    .justify_content(FlexJustify::Center),
-            )
-            .children(cards.into_iter().map(|(n, quantity)| {
-                let card_name = *n;
-                let quantity_element = ElementName::new("Quantity");
-                DeckCardSlot::new(CardHeight::vh(36.0))
-                    .layout(Layout::new().margin(Edge::All, 16.px()))
-                    .card(Some(
-                        DeckCard::new(card_name)
-                            .quantity(*quantity)
-                            .quantity_element_name(quantity_element)
-                            .draggable(
-                                Draggable::new(card_name.to_string())
-                                    .drop_target(element_names::CARD_LIST)
-                                    .over_target_indicator(move || {
-                                        CardListCardName::new(card_name).build()
-                                    })
-                                    .on_drop(Some(self.drop_action(card_name)))
-                                    .hide_indicator_children(vec![quantity_element]),
-                            ),
-                    ))
-            }))
-            .children((0..empty_slots).map(|_| {
-                DeckCardSlot::new(CardHeight::vh(36.0))
-                    .layout(Layout::new().margin(Edge::All, 4.px()))
-            }))
+    fn card(&self, card_name: CardName, quantity: u32) -> impl Component {
+        let quantity_element = ElementName::new("Quantity");
+        DeckCardSlot::new(CardHeight::vh(36.0))
+            .layout(Layout::new().margin(Edge::All, 16.px()))
+            .card(Some(
+                DeckCard::new(card_name)
+                    .preview(PreviewState::example())
+                    .quantity(quantity)
+                    .quantity_element_name(quantity_element)
+                    .draggable(
+                        Draggable::new(card_name.to_string())
+                            .drop_target(element_names::CARD_LIST)
+                            .over_target_indicator(move || CardListCardName::new(card_name).build())
+                            .on_drop(Some(self.drop_action(card_name)))
+                            .hide_indicator_children(vec![quantity_element]),
+                    ),
+            ))
     }
 
     fn drop_action(&self, name: CardName) -> ActionBuilder {
@@ -138,18 +125,37 @@ impl<'a> Component for CollectionBrowser<'a> {
     fn build(self) -> Option<Node> {
         let mut cards = get_matching_cards(self.player, self.filters).collect::<Vec<_>>();
         sort_cards(&mut cards);
-        let row_one = cards.iter().skip(self.filters.offset).take(4).collect::<Vec<_>>();
-        let row_two = cards.iter().skip(self.filters.offset + 4).take(4).collect::<Vec<_>>();
         DropTarget::new(element_names::COLLECTION_BROWSER)
             .style(
                 Style::new()
                     .flex_direction(FlexDirection::Column)
                     .flex_grow(1.0)
-                    .align_items(FlexAlign::Center)
+                    .align_items(FlexAlign::Stretch)
                     .justify_content(FlexJustify::Center),
             )
-            .child(self.card_row(row_one))
-            .child(self.card_row(row_two))
+            .child(
+                VirtualList::new("CollectionBrowser", cards.len())
+                    .offset(self.filters.offset)
+                    .page_size(8)
+                    .row_size(4)
+                    .item(|index| self.card(cards[index].0, cards[index].1))
+                    .placeholder(|| {
+                        DeckCardSlot::new(CardHeight::vh(36.0))
+                            .layout(Layout::new().margin(Edge::All, 4.px()))
+                    })
+                    .page_action(|offset| {
+                        let current = PanelAddress::DeckEditor(DeckEditorData {
+                            deck_id: DeckId::Adventure,
+                            collection_filters: self.filters,
+                        });
+                        Panels::open(PanelAddress::DeckEditor(DeckEditorData {
+                            deck_id: DeckId::Adventure,
+                            collection_filters: CollectionBrowserFilters { offset },
+                        }))
+                        .and_close(current)
+                        .wait_to_load(true)
+                    }),
+            )
             .build()
     }
 }
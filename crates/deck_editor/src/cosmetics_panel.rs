@@ -0,0 +1,91 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Panel for equipping cosmetic items on a deck
+
+use core_ui::action_builder::ActionBuilder;
+use core_ui::button::{Button, ButtonType};
+use core_ui::design::FontSize;
+use core_ui::panel_window::PanelWindow;
+use core_ui::prelude::*;
+use core_ui::scroll_view::ScrollView;
+use core_ui::text::Text;
+use data::cosmetics::Cosmetic;
+use data::deck::Deck;
+use data::player_data::PlayerData;
+use data::user_actions::DeckEditorAction;
+use panel_address::{Panel, PanelAddress};
+use protos::spelldawn::{FlexAlign, FlexJustify};
+
+pub struct CosmeticsPanel<'a> {
+    pub player: &'a PlayerData,
+    pub deck: &'a Deck,
+}
+
+impl<'a> CosmeticsPanel<'a> {
+    fn row(&self, cosmetic: Cosmetic) -> impl Component {
+        let equipped = self.deck.cosmetics.is_equipped(cosmetic);
+        Row::new(format!("Cosmetic{cosmetic:?}"))
+            .style(
+                Style::new()
+                    .width(100.pct())
+                    .margin(Edge::Vertical, 8.px())
+                    .justify_content(FlexJustify::SpaceBetween)
+                    .align_items(FlexAlign::Center),
+            )
+            .child(
+                Column::new("Description")
+                    .style(Style::new().align_items(FlexAlign::FlexStart))
+                    .child(Text::new(cosmetic.displayed_name()).font_size(FontSize::Headline))
+                    .child(Text::new(cosmetic.description()).font_size(FontSize::Body)),
+            )
+            .child(
+                Button::new(if equipped { "Unequip" } else { "Equip" })
+                    .button_type(if equipped { ButtonType::Secondary } else { ButtonType::Primary })
+                    .action(ActionBuilder::new().action(if equipped {
+                        DeckEditorAction::UnequipCosmetic(cosmetic)
+                    } else {
+                        DeckEditorAction::EquipCosmetic(cosmetic)
+                    }))
+                    .layout(Layout::new().margin(Edge::Left, 16.px())),
+            )
+    }
+}
+
+impl<'a> Panel for CosmeticsPanel<'a> {
+    fn address(&self) -> PanelAddress {
+        PanelAddress::DeckCosmetics
+    }
+}
+
+impl<'a> Component for CosmeticsPanel<'a> {
+    fn build(self) -> Option<Node> {
+        let mut owned = self.player.cosmetics.iter().copied().collect::<Vec<_>>();
+        owned.sort_by_key(|cosmetic| cosmetic.displayed_name());
+
+        PanelWindow::new(self.address(), 800.px(), 600.px())
+            .title("Cosmetics")
+            .show_close_button(true)
+            .content(
+                ScrollView::new("CosmeticsScroll")
+                    .style(Style::new().width(100.pct()).height(450.px()))
+                    .child(owned.is_empty().then(|| {
+                        Text::new("Cosmetics are earned by completing achievements")
+                            .font_size(FontSize::Body)
+                    }))
+                    .children(owned.into_iter().map(|cosmetic| self.row(cosmetic))),
+            )
+            .build()
+    }
+}
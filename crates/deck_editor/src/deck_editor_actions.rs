@@ -16,7 +16,7 @@ use anyhow::Result;
 use data::player_data::PlayerData;
 use data::tutorial::TutorialMessageKey;
 use data::user_actions::DeckEditorAction;
-use with_error::{fail, WithError};
+use with_error::{fail, verify, WithError};
 
 pub fn handle(player: &mut PlayerData, action: DeckEditorAction) -> Result<()> {
     match action {
@@ -45,6 +45,13 @@ pub fn handle(player: &mut PlayerData, action: DeckEditorAction) -> Result<()> {
                 }
             }
         }
+        DeckEditorAction::EquipCosmetic(cosmetic) => {
+            verify!(player.cosmetics.contains(&cosmetic), "Cosmetic not owned {:?}", cosmetic);
+            player.adventure_mut()?.deck.cosmetics.equip(cosmetic);
+        }
+        DeckEditorAction::UnequipCosmetic(cosmetic) => {
+            player.adventure_mut()?.deck.cosmetics.unequip(cosmetic);
+        }
     }
     Ok(())
 }
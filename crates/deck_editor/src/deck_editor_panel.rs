@@ -12,20 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use core_ui::button::{IconButton, IconButtonType};
 use core_ui::full_screen_image::FullScreenImage;
-use core_ui::panels::Panels;
 use core_ui::prelude::*;
-use core_ui::{icons, style};
+use core_ui::style;
 use data::deck::Deck;
 use data::player_data::PlayerData;
-use data::primitives::DeckId;
-use panel_address::{CollectionBrowserFilters, DeckEditorData, Panel, PanelAddress};
-use protos::spelldawn::FlexJustify;
+use panel_address::{DeckEditorData, Panel, PanelAddress};
 use screen_overlay::ScreenOverlay;
 
 use crate::card_list::CardList;
-use crate::collection_browser::{self, CollectionBrowser};
+use crate::collection_browser::CollectionBrowser;
 
 pub const EDITOR_COLUMN_WIDTH: i32 = 25;
 
@@ -35,36 +31,6 @@ pub struct DeckEditorPanel<'a> {
     pub deck: &'a Deck,
 }
 
-impl<'a> DeckEditorPanel<'a> {
-    fn page_control(&self, show: bool, icon: impl Into<String>, subtract: bool) -> impl Component {
-        Column::new("PageControls")
-            .style(
-                Style::new()
-                    .min_width(96.px())
-                    .height(100.pct())
-                    .flex_shrink(0.0)
-                    .flex_grow(1.0)
-                    .justify_content(FlexJustify::Center),
-            )
-            .child(show.then(|| {
-                IconButton::new(icon).button_type(IconButtonType::NavBrown).action(
-                    Panels::open(PanelAddress::DeckEditor(DeckEditorData {
-                        deck_id: DeckId::Adventure,
-                        collection_filters: CollectionBrowserFilters {
-                            offset: if subtract {
-                                self.data.collection_filters.offset - 8
-                            } else {
-                                self.data.collection_filters.offset + 8
-                            },
-                        },
-                    }))
-                    .and_close(self.address())
-                    .wait_to_load(true),
-                )
-            }))
-    }
-}
-
 impl<'a> Panel for DeckEditorPanel<'a> {
     fn address(&self) -> PanelAddress {
         PanelAddress::DeckEditor(self.data)
@@ -73,6 +39,7 @@ impl<'a> Panel for DeckEditorPanel<'a> {
     fn screen_overlay(&self) -> Option<Node> {
         ScreenOverlay::new(self.player)
             .show_deck_button(false)
+            .show_cosmetics_button(true)
             .show_close_button(self.address())
             .build()
     }
@@ -86,28 +53,13 @@ impl<'a> Component for DeckEditorPanel<'a> {
             ))
             .content(
                 Row::new("DeckEditorPanel")
-                    .child(self.page_control(
-                        self.data.collection_filters.offset >= 8,
-                        icons::PREVIOUS_PAGE,
-                        true,
+                    .child(Column::new("Collection").style(Style::new().flex_grow(1.0)).child(
+                        CollectionBrowser {
+                            player: self.player,
+                            deck: self.deck,
+                            filters: self.data.collection_filters,
+                        },
                     ))
-                    .child(Column::new("Collection").child(CollectionBrowser {
-                        player: self.player,
-                        deck: self.deck,
-                        filters: self.data.collection_filters,
-                    }))
-                    .child(
-                        self.page_control(
-                            self.data.collection_filters.offset + 8
-                                < collection_browser::get_matching_cards(
-                                    self.player,
-                                    self.data.collection_filters,
-                                )
-                                .count(),
-                            icons::NEXT_PAGE,
-                            false,
-                        ),
-                    )
                     .child(CardList { deck: self.deck }),
             )
             .build()
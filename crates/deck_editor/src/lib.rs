@@ -16,6 +16,7 @@
 pub mod card_list;
 pub mod card_list_card_name;
 pub mod collection_browser;
+pub mod cosmetics_panel;
 pub mod deck_editor_actions;
 pub mod deck_editor_panel;
 pub mod deck_editor_prompt;
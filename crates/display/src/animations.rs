@@ -12,9 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use adapters;
 use adapters::response_builder::ResponseBuilder;
 use anyhow::Result;
+use assets;
+use core_ui::design::{BackgroundColor, FontSize};
+use core_ui::prelude::*;
+use core_ui::style::Corner;
+use core_ui::text::Text;
 use data::game::GameState;
+use data::notification::Notification;
 use data::primitives::{AbilityId, CardId, GameObjectId, RoomId, Side};
 use data::special_effects::{
     FantasyEventSounds, FireworksSound, Projectile, SoundEffect, TimedEffect,
@@ -26,13 +33,12 @@ use protos::spelldawn::object_position::Position;
 use protos::spelldawn::play_effect_position::EffectPosition;
 use protos::spelldawn::{
     CreateTokenCardCommand, DelayCommand, DisplayGameMessageCommand, FireProjectileCommand,
-    GameMessageType, GameObjectMove, MoveGameObjectsCommand, MusicState, PlayEffectCommand,
-    PlayEffectPosition, PlaySoundCommand, RoomVisitType, SetMusicCommand, TimeValue,
-    VisitRoomCommand,
+    FlexAlign, FlexPosition, GameMessageType, GameObjectMove, MoveGameObjectsCommand, MusicState,
+    PlayEffectCommand, PlayEffectPosition, PlaySoundCommand, RoomVisitType, SetMusicCommand,
+    TimeValue, VisitRoomCommand,
 };
-use {adapters, assets};
 
-use crate::{card_sync, positions};
+use crate::{card_sync, notifications, positions};
 
 pub fn render(
     builder: &mut ResponseBuilder,
@@ -57,10 +63,7 @@ pub fn render(
                 show_cards(builder, cards)
             }
         }
-        GameUpdate::ShuffleIntoDeck => {
-            // No animation, just acts as a snapshot point.
-        }
-        GameUpdate::UnveilProject(card_id) => {
+        GameUpdate::UnveilProject(card_id) | GameUpdate::PayUpkeep(card_id) => {
             if builder.user_side == Side::Champion {
                 show_cards(builder, &vec![*card_id])
             }
@@ -83,16 +86,55 @@ pub fn render(
                 // handled by the client's optimistic animation system.
                 initiate_raid(builder, *room_id)
             }
+            raid_effect(builder, snapshot);
         }
         GameUpdate::TargetedInteraction(interaction) => {
             targeted_interaction(builder, snapshot, interaction)
         }
         GameUpdate::ScoreCard(_, card_id) => score_card(builder, *card_id),
-        GameUpdate::GameOver(_side) => {} //game_over(builder, snapshot, *side)?,
+        GameUpdate::DestroyCards(cards) => destroy_cards(builder, cards),
+        GameUpdate::TrapTriggered(card_id) => trap_triggered(builder, *card_id),
+        GameUpdate::GameOver(side) => victory_effect(builder, snapshot, *side),
+        GameUpdate::PlayerDisconnected(side) => {
+            if builder.user_side != *side {
+                opponent_disconnected(builder, snapshot)
+            }
+        }
+        GameUpdate::PlayerConceded(side) => {
+            if builder.user_side != *side {
+                notifications::render(builder, Notification::OpponentConceded)
+            }
+        }
     }
     Ok(())
 }
 
+fn opponent_disconnected(builder: &mut ResponseBuilder, snapshot: &GameState) {
+    let mut toast = Column::new("DisconnectToast")
+        .style(
+            Style::new()
+                .position_type(FlexPosition::Absolute)
+                .position(Edge::Top, 120.px())
+                .align_self(FlexAlign::Center)
+                .align_items(FlexAlign::Center)
+                .padding(Edge::All, 16.px())
+                .background_color(BackgroundColor::Toast)
+                .border_radius(Corner::All, 12.px()),
+        )
+        .child(Text::new("Opponent Disconnected").font_size(FontSize::Headline));
+    if let Some(seconds) = snapshot.data.config.disconnect_grace_period {
+        toast = toast.child(
+            Text::new(format!(
+                "They will forfeit if they do not return within {} seconds",
+                seconds
+            ))
+            .font_size(FontSize::Body),
+        );
+    }
+
+    builder.push_toast(toast.build(), 5000);
+}
+
 fn start_turn(builder: &mut ResponseBuilder, side: Side) {
     builder.push(Command::DisplayGameMessage(DisplayGameMessageCommand {
         message_type: match side {
@@ -119,7 +161,7 @@ fn show_cards(builder: &mut ResponseBuilder, cards: &Vec<CardId>) {
             })
             .collect(),
         disable_animation: !builder.state.animate,
-        delay: Some(adapters::milliseconds(if is_large { 2000 } else { 1000 })),
+        delay: Some(builder.duration(if is_large { 2000 } else { 1000 })),
     }))
 }
 
@@ -145,7 +187,7 @@ fn show_ability(builder: &mut ResponseBuilder, snapshot: &GameState, ability_id:
         animate: builder.state.animate,
     }));
 
-    builder.push(delay(1500));
+    builder.push(delay(builder, 1500));
 }
 
 fn level_up_room(commands: &mut ResponseBuilder, target: RoomId) {
@@ -164,6 +206,38 @@ fn initiate_raid(commands: &mut ResponseBuilder, target: RoomId) {
     }));
 }
 
+/// Plays the raid initiation effect equipped on the Champion's deck, if any.
+fn raid_effect(builder: &mut ResponseBuilder, snapshot: &GameState) {
+    if let Some(effect) = snapshot.player(Side::Champion).cosmetics.raid_effect {
+        builder.push(play_effect(
+            builder,
+            effect.timed_effect(),
+            GameObjectId::Identity(Side::Champion),
+            PlayEffectOptions {
+                duration: Some(builder.duration(500)),
+                ..PlayEffectOptions::default()
+            },
+        ));
+    }
+}
+
+/// Plays the victory fanfare equipped on the winning player's deck, if any.
+fn victory_effect(builder: &mut ResponseBuilder, snapshot: &GameState, winner: Side) {
+    if let Some(effect) = snapshot.player(winner).cosmetics.victory_effect {
+        builder.push(play_effect(
+            builder,
+            effect.timed_effect(),
+            GameObjectId::Identity(winner),
+            PlayEffectOptions {
+                duration: Some(builder.duration(700)),
+                sound: Some(effect.sound()),
+                ..PlayEffectOptions::default()
+            },
+        ));
+        builder.push(delay(builder, 1000));
+    }
+}
+
 fn targeted_interaction(
     builder: &mut ResponseBuilder,
     snapshot: &GameState,
@@ -173,16 +247,17 @@ fn targeted_interaction(
         source_id: Some(adapters::game_object_identifier(builder, interaction.source)),
         target_id: Some(adapters::game_object_identifier(builder, interaction.target)),
         projectile: Some(assets::projectile(Projectile::Hovl(3))),
-        travel_duration: Some(adapters::milliseconds(300)),
-        wait_duration: Some(adapters::milliseconds(300)),
+        travel_duration: Some(builder.duration(300)),
+        wait_duration: Some(builder.duration(300)),
         ..FireProjectileCommand::default()
     };
-    apply_projectile(snapshot, &mut projectile, interaction);
+    apply_projectile(builder, snapshot, &mut projectile, interaction);
     builder.push(Command::FireProjectile(projectile));
 }
 
 /// Applies custom projectile effects for a targeted interaction.
 fn apply_projectile(
+    builder: &ResponseBuilder,
     snapshot: &GameState,
     command: &mut FireProjectileCommand,
     interaction: &TargetedInteraction,
@@ -194,7 +269,7 @@ fn apply_projectile(
         }
         if let Some(additional_hit) = effects.additional_hit {
             command.additional_hit = Some(assets::timed_effect(additional_hit));
-            command.additional_hit_delay = Some(adapters::milliseconds(100));
+            command.additional_hit_delay = Some(builder.duration(100));
         }
     }
 }
@@ -207,7 +282,7 @@ fn score_card(builder: &mut ResponseBuilder, card_id: CardId) {
         TimedEffect::HovlMagicHit(4),
         card_id,
         PlayEffectOptions {
-            duration: Some(adapters::milliseconds(700)),
+            duration: Some(builder.duration(700)),
             sound: Some(SoundEffect::Fireworks(FireworksSound::RocketExplodeLarge)),
             ..PlayEffectOptions::default()
         },
@@ -217,12 +292,46 @@ fn score_card(builder: &mut ResponseBuilder, card_id: CardId) {
         TimedEffect::HovlMagicHit(4),
         card_id,
         PlayEffectOptions {
-            duration: Some(adapters::milliseconds(300)),
+            duration: Some(builder.duration(300)),
+            sound: Some(SoundEffect::Fireworks(FireworksSound::RocketExplode)),
+            ..PlayEffectOptions::default()
+        },
+    ));
+    builder.push(delay(builder, 1000));
+}
+
+/// Plays a single shared effect for a group of cards destroyed at the same
+/// time, before the next snapshot moves them to the discard pile.
+fn destroy_cards(builder: &mut ResponseBuilder, cards: &[CardId]) {
+    for (i, card_id) in cards.iter().enumerate() {
+        builder.push(play_effect(
+            builder,
+            TimedEffect::HovlSwordSlash(1),
+            *card_id,
+            PlayEffectOptions {
+                duration: Some(builder.duration(500)),
+                // Only play the sound once for the whole group
+                sound: (i == 0).then_some(SoundEffect::Fireworks(FireworksSound::RocketExplode)),
+                ..PlayEffectOptions::default()
+            },
+        ));
+    }
+    builder.push(delay(builder, 500));
+}
+
+fn trap_triggered(builder: &mut ResponseBuilder, card_id: CardId) {
+    show_cards(builder, &vec![card_id]);
+    builder.push(play_effect(
+        builder,
+        TimedEffect::HovlSwordSlash(1),
+        card_id,
+        PlayEffectOptions {
+            duration: Some(builder.duration(500)),
             sound: Some(SoundEffect::Fireworks(FireworksSound::RocketExplode)),
             ..PlayEffectOptions::default()
         },
     ));
-    builder.push(delay(1000));
+    builder.push(delay(builder, 500));
 }
 
 #[derive(Debug, Default)]
@@ -247,13 +356,13 @@ fn play_effect(
             ))),
         }),
         scale: options.scale,
-        duration: Some(options.duration.unwrap_or_else(|| adapters::milliseconds(300))),
+        duration: Some(options.duration.unwrap_or_else(|| builder.duration(300))),
         sound: options.sound.map(assets::sound_effect),
     })
 }
 
-pub fn delay(milliseconds: u32) -> Command {
-    Command::Delay(DelayCommand { duration: Some(TimeValue { milliseconds }) })
+pub fn delay(builder: &ResponseBuilder, milliseconds: u32) -> Command {
+    Command::Delay(DelayCommand { duration: Some(builder.duration(milliseconds)) })
 }
 
 fn set_music(music_state: MusicState) -> Command {
@@ -0,0 +1,67 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Renders targeting arrows between cards during raid prompts, e.g. to connect
+//! weapons to the minion they can be used against.
+
+use adapters::response_builder::ResponseBuilder;
+use anyhow::Result;
+use data::game::GameState;
+use data::game_actions::{EncounterAction, PromptAction};
+use data::primitives::CardId;
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::{DisplayArrowsCommand, DisplayedArrow, TargetingArrow};
+
+/// Pushes a [DisplayArrowsCommand] connecting each weapon the user can
+/// currently use to its target, and each artifact with a usable encounter
+/// ability to the weapons it can target, if there are any such cards.
+pub fn render(builder: &mut ResponseBuilder, game: &GameState) -> Result<()> {
+    let mut pairs = vec![];
+    for action in raids::current_actions(game, builder.user_side)?.unwrap_or_default() {
+        match action {
+            PromptAction::EncounterAction(EncounterAction::UseWeaponAbility(weapon, target)) => {
+                pairs.push((weapon, target));
+            }
+            PromptAction::EncounterAction(EncounterAction::UseCombinedWeapons(
+                first,
+                second,
+                target,
+            )) => {
+                pairs.push((first, target));
+                pairs.push((second, target));
+            }
+            PromptAction::EncounterAction(EncounterAction::ActivateAbility(ability_id, weapon)) => {
+                pairs.push((ability_id.card_id, weapon));
+            }
+            _ => {}
+        }
+    }
+
+    pairs.sort();
+    pairs.dedup();
+    if !pairs.is_empty() {
+        let arrows = pairs.into_iter().map(|(source, target)| arrow(builder, source, target));
+        builder.push(Command::DisplayArrows(DisplayArrowsCommand { arrows: arrows.collect() }));
+    }
+
+    Ok(())
+}
+
+fn arrow(builder: &ResponseBuilder, source: CardId, target: CardId) -> DisplayedArrow {
+    DisplayedArrow {
+        source: Some(adapters::game_object_identifier(builder, source)),
+        target: Some(adapters::game_object_identifier(builder, target)),
+        color: TargetingArrow::Green.into(),
+    }
+}
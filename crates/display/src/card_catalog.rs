@@ -0,0 +1,66 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Serializes static card definitions so clients can cache the card database
+//! without a game in progress.
+
+use std::hash::Hasher;
+
+use data::card_definition::CardDefinition;
+use data::card_name::CardName;
+use data::player_data::IconMode;
+use data::text::RulesTextContext;
+use fnv::FnvHasher;
+use prost::Message;
+use protos::spelldawn::{CardCatalog, CatalogCard};
+use rules_text::card_icons;
+
+/// Builds a [CardCatalog] containing every non-test card in the game.
+pub fn build() -> CardCatalog {
+    CardCatalog {
+        cards: enum_iterator::all::<CardName>()
+            .filter(|name| !name.is_test_card())
+            .map(|name| catalog_card(rules::get(name)))
+            .collect(),
+    }
+}
+
+/// Returns a hash of the catalog entries for every card in the game, including
+/// test cards but excluding synthetic cards defined at runtime. Changes
+/// whenever a card's cost, stats, rules text or art changes.
+pub fn card_set_hash() -> String {
+    let mut hasher = FnvHasher::default();
+    for name in enum_iterator::all::<CardName>().filter(|name| !name.is_synthetic()) {
+        hasher.write(&catalog_card(rules::get(name)).encode_to_vec());
+    }
+    format!("{:016x}", hasher.finish())
+}
+
+fn catalog_card(definition: &CardDefinition) -> CatalogCard {
+    let context = RulesTextContext::Default(definition);
+    CatalogCard {
+        name: format!("{:?}", definition.name),
+        displayed_name: definition.name.displayed_name(),
+        mana_cost: definition.cost.mana,
+        action_cost: definition.cost.actions,
+        card_type: format!("{:?}", definition.card_type),
+        school: format!("{:?}", definition.school),
+        rarity: format!("{:?}", definition.rarity),
+        side: adapters::player_side(definition.side),
+        rules_text: Some(rules_text::build(&context, definition)),
+        card_icons: Some(card_icons::build(&context, definition, true, IconMode::Standard)),
+        image: Some(adapters::sprite(&definition.image)),
+        keyword_tooltips: rules_text::build_keyword_tooltips(&context, None),
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Builds the detailed card information displayed in the client's card
+//! inspector, e.g. on long-press.
+
+use data::card_state::CardState;
+use data::game::GameState;
+use data::text::RulesTextContext;
+use protos::spelldawn::{CardInfoCounter, CardInfoStat, CardInfoView};
+use rules::queries;
+
+/// Returns a [CardInfoView] describing the current state of `card`.
+pub fn build(game: &GameState, card: &CardState) -> CardInfoView {
+    let definition = rules::get(card.name);
+    let context = RulesTextContext::Game(game, card);
+    let stats = stats(game, card);
+    CardInfoView {
+        ability_text: definition
+            .abilities
+            .iter()
+            .map(|ability| rules_text::ability_text(&context, ability))
+            .collect(),
+        modifiers: stats
+            .iter()
+            .filter(|stat| stat.value != stat.base_value)
+            .map(|stat| {
+                format!("{:+} {}", i64::from(stat.value) - i64::from(stat.base_value), stat.label)
+            })
+            .collect(),
+        stats,
+        counters: [
+            ("Level", card.data.card_level),
+            ("Stored Mana", card.data.stored_mana),
+            ("Boosts", card.data.boost_count),
+        ]
+        .into_iter()
+        .filter(|(_, value)| *value > 0)
+        .map(|(label, value)| CardInfoCounter { label: label.to_string(), value })
+        .collect(),
+    }
+}
+
+fn stats(game: &GameState, card: &CardState) -> Vec<CardInfoStat> {
+    let definition = rules::get(card.name);
+    let config = &definition.config.stats;
+    let mut result = vec![];
+    if let Some(base) = definition.cost.mana {
+        let value = queries::mana_cost(game, card.id).unwrap_or(base);
+        result.push(stat("Mana Cost", value, base));
+    }
+    add_stat(
+        &mut result,
+        "Action Cost",
+        queries::action_cost(game, card.id),
+        definition.cost.actions,
+    );
+    add_stat(
+        &mut result,
+        "Attack",
+        queries::attack(game, card.id),
+        config.base_attack.unwrap_or(0),
+    );
+    add_stat(&mut result, "Health", queries::health(game, card.id), config.health.unwrap_or(0));
+    add_stat(&mut result, "Shield", queries::shield(game, card.id), config.shield.unwrap_or(0));
+    add_stat(&mut result, "Breach", queries::breach(game, card.id), config.breach.unwrap_or(0));
+    if let Some(durability) = config.durability {
+        let remaining = queries::remaining_durability(game, card.id).unwrap_or(durability);
+        result.push(stat("Durability", remaining, durability));
+    }
+    if let Some(points) = config.scheme_points {
+        result.push(stat("Level Requirement", points.level_requirement, points.level_requirement));
+        result.push(stat("Points", points.points, points.points));
+    }
+    result
+}
+
+/// Adds a stat to `result` if either its current or base value is nonzero.
+fn add_stat(result: &mut Vec<CardInfoStat>, label: &str, value: u32, base_value: u32) {
+    if value > 0 || base_value > 0 {
+        result.push(stat(label, value, base_value));
+    }
+}
+
+fn stat(label: &str, value: u32, base_value: u32) -> CardInfoStat {
+    CardInfoStat { label: label.to_string(), value, base_value }
+}
@@ -12,24 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use actions::legal_actions;
+use adapters;
 use adapters::response_builder::ResponseBuilder;
 use anyhow::Result;
+use assets;
 use data::card_definition::{AbilityType, TargetRequirement};
 use data::card_state::CardState;
 use data::game::GameState;
 use data::game_actions::CardTarget;
-use data::primitives::{AbilityId, CardType, ItemLocation, RoomId, RoomLocation};
+use data::primitives::{AbilityId, CardType, ItemLocation, RoomLocation};
 use data::text::RulesTextContext;
 use protos::spelldawn::card_targeting::Targeting;
 use protos::spelldawn::{
     ArrowTargetRoom, CardIcons, CardPrefab, CardTargeting, CardTitle, CardView, NoTargeting,
-    PlayInRoom, RevealedCardView, RulesText, TargetingArrow,
+    PlayInRoom, RevealedCardView, RulesText, SchemeProgress, TargetingArrow,
 };
 use rules::{flags, queries};
-use rules_text::card_icons;
-use {adapters, assets, rules_text};
+use rules_text::{self, card_icons};
 
-use crate::positions;
+use crate::{card_info, positions, visibility};
 
 pub fn card_view(
     builder: &ResponseBuilder,
@@ -37,23 +39,28 @@ pub fn card_view(
     card: &CardState,
 ) -> Result<CardView> {
     let definition = rules::get(card.name);
-    let revealed = card.is_revealed_to(builder.user_side);
+    let visibility = if builder.state.spectator {
+        visibility::spectator(game, card)
+    } else {
+        visibility::card(game, card, builder.user_side)
+    };
+    let revealed = visibility.is_revealed();
     Ok(CardView {
         card_id: Some(adapters::card_identifier(card.id)),
         card_position: Some(positions::convert(builder, game, card)?),
         prefab: CardPrefab::Standard.into(),
-        revealed_to_viewer: card.is_revealed_to(builder.user_side),
+        revealed_to_viewer: revealed,
         is_face_up: card.is_face_up(),
         card_icons: Some(card_icons::build(
             &RulesTextContext::Game(game, card),
             definition,
             revealed,
+            builder.state.icon_mode,
         )),
-        arena_frame: Some(assets::arena_frame(
-            definition.side,
-            definition.card_type,
-            definition.config.lineage,
-        )),
+        // The arena frame identifies card type and lineage
+        arena_frame: revealed.then(|| {
+            assets::arena_frame(definition.side, definition.card_type, definition.config.lineage)
+        }),
         face_down_arena_frame: Some(assets::face_down_arena_frame()),
         owning_player: builder.to_player_name(definition.side),
         revealed_card: revealed.then(|| revealed_card_view(builder, game, card)),
@@ -63,6 +70,21 @@ pub fn card_view(
             None
         },
         destroy_position: Some(positions::for_card(card, positions::deck(builder, card.side()))),
+        info_zoom: revealed.then(|| card_info::build(game, card)),
+        scheme_progress: if revealed { scheme_progress(game, card) } else { None },
+    })
+}
+
+/// Builds a [SchemeProgress] for a scheme card in a room, showing its current
+/// level relative to its level requirement.
+fn scheme_progress(game: &GameState, card: &CardState) -> Option<SchemeProgress> {
+    if !card.position().in_room() {
+        return None;
+    }
+    let points = rules::card_definition(game, card.id).config.stats.scheme_points?;
+    Some(SchemeProgress {
+        current_level: card.data.card_level,
+        required_level: points.level_requirement,
     })
 }
 
@@ -72,7 +94,10 @@ pub fn activated_ability_cards(
     card: &CardState,
 ) -> Vec<Result<CardView>> {
     let mut result = vec![];
-    if card.side() != builder.user_side || !card.position().in_play() {
+    if builder.state.spectator
+        || card.controller() != builder.user_side
+        || !card.position().in_play()
+    {
         return result;
     }
 
@@ -99,12 +124,12 @@ pub fn ability_card_view(
         is_face_up: false,
         card_icons: Some(CardIcons {
             top_left_icon: queries::ability_mana_cost(game, ability_id)
-                .map(card_icons::mana_card_icon),
+                .map(|cost| card_icons::mana_card_icon(cost, builder.state.icon_mode)),
             ..CardIcons::default()
         }),
         arena_frame: None,
         face_down_arena_frame: None,
-        owning_player: builder.to_player_name(ability_id.card_id.side),
+        owning_player: builder.to_player_name(game.card(ability_id.card_id).controller()),
         revealed_card: Some(revealed_ability_card_view(
             builder,
             game,
@@ -121,6 +146,8 @@ pub fn ability_card_view(
             ability_id,
             positions::parent_card(ability_id),
         )),
+        info_zoom: None,
+        scheme_progress: None,
     }
 }
 
@@ -143,25 +170,31 @@ fn revealed_card_view(
         targeting: Some(card_targeting(
             definition.config.custom_targeting.as_ref(),
             flags::enters_play_in_room(game, card.id),
-            |target| flags::can_take_play_card_action(game, builder.user_side, card.id, target),
+            legal_actions::card_targets(game, builder.user_side, card.id).collect(),
         )),
         on_release_position: Some(positions::for_sorting_key(
             positions::RELEASE_SORTING_KEY,
             match definition.card_type {
                 CardType::Weapon => positions::item(ItemLocation::Weapons),
                 CardType::Artifact => positions::item(ItemLocation::Artifacts),
+                CardType::Ally => positions::item(ItemLocation::Allies),
                 CardType::OverlordSpell => positions::staging(),
                 CardType::ChampionSpell => positions::staging(),
                 CardType::Minion => positions::unspecified_room(RoomLocation::Defender),
                 CardType::Project => positions::unspecified_room(RoomLocation::Occupant),
                 CardType::Scheme => positions::unspecified_room(RoomLocation::Occupant),
                 CardType::Identity => positions::staging(),
+                CardType::GlobalModifier => positions::global_modifier(),
             },
         )),
         supplemental_info: rules_text::build_supplemental_info(
             &RulesTextContext::Game(game, card),
             None,
         ),
+        keyword_tooltips: rules_text::build_keyword_tooltips(
+            &RulesTextContext::Game(game, card),
+            None,
+        ),
     }
 }
 
@@ -186,42 +219,56 @@ fn revealed_ability_card_view(
         rules_text: Some(RulesText {
             text: rules_text::ability_text(&RulesTextContext::Game(game, card), ability),
         }),
-        targeting: Some(card_targeting(target_requirement, false, |target| {
-            flags::can_take_activate_ability_action(game, ability_id.side(), ability_id, target)
-        })),
+        targeting: Some(card_targeting(
+            target_requirement,
+            false,
+            legal_actions::ability_targets(game, ability_id.side(), ability_id).collect(),
+        )),
         on_release_position: Some(positions::for_ability(game, ability_id, positions::staging())),
         supplemental_info: rules_text::build_supplemental_info(
             &RulesTextContext::Game(game, card),
             Some(ability_id.index),
         ),
+        keyword_tooltips: rules_text::build_keyword_tooltips(
+            &RulesTextContext::Game(game, card),
+            Some(ability_id.index),
+        ),
     }
 }
 
+/// Builds targeting data for a card or ability, given the list of
+/// currently-legal `targets` for it.
 fn card_targeting<T>(
     requirement: Option<&TargetRequirement<T>>,
     play_in_room: bool,
-    can_play: impl Fn(CardTarget) -> bool,
+    targets: Vec<CardTarget>,
 ) -> CardTargeting {
+    let valid_rooms = targets
+        .iter()
+        .filter_map(|target| match target {
+            CardTarget::Room(room_id) => Some(adapters::room_identifier(*room_id)),
+            CardTarget::None => None,
+        })
+        .collect::<Vec<_>>();
+
     CardTargeting {
         targeting: Some(match (requirement, play_in_room) {
             (None, false) | (Some(TargetRequirement::None), _) => {
-                Targeting::NoTargeting(NoTargeting { can_play: can_play(CardTarget::None) })
+                Targeting::NoTargeting(NoTargeting {
+                    can_play: targets.contains(&CardTarget::None),
+                })
             }
             (None, true) | (Some(TargetRequirement::TargetRoom(_)), _) => {
-                let valid = enum_iterator::all::<RoomId>()
-                    .filter(|room_id| can_play(CardTarget::Room(*room_id)))
-                    .map(adapters::room_identifier)
-                    .collect();
-
                 if play_in_room {
-                    Targeting::PlayInRoom(PlayInRoom { valid_rooms: valid })
+                    Targeting::PlayInRoom(PlayInRoom { valid_rooms: valid_rooms.clone() })
                 } else {
                     Targeting::ArrowTargetRoom(ArrowTargetRoom {
-                        valid_rooms: valid,
+                        valid_rooms: valid_rooms.clone(),
                         arrow: TargetingArrow::Red.into(),
                     })
                 }
             }
         }),
+        highlighted_rooms: valid_rooms,
     }
 }
@@ -0,0 +1,58 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Renders emotes sent between players
+
+use core_ui::design::{BackgroundColor, FontSize};
+use core_ui::prelude::*;
+use core_ui::style::Corner;
+use core_ui::text::Text;
+use data::emotes::Emote;
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::{DisplayEmoteCommand, FlexPosition, PlayerName};
+
+/// Returns a command to display `emote` as having been sent by the `player`
+/// player.
+pub fn render(emote: Emote, player: PlayerName) -> Command {
+    Command::DisplayEmote(DisplayEmoteCommand {
+        player: player.into(),
+        content: EmoteBubble { emote, player }.build(),
+        duration: Some(adapters::milliseconds(3000)),
+    })
+}
+
+struct EmoteBubble {
+    emote: Emote,
+    player: PlayerName,
+}
+
+impl Component for EmoteBubble {
+    fn build(self) -> Option<Node> {
+        Row::new(format!("Emote{:?}", self.player))
+            .style(
+                Style::new()
+                    .position_type(FlexPosition::Absolute)
+                    .position(
+                        if self.player == PlayerName::User { Edge::Bottom } else { Edge::Top },
+                        200.px(),
+                    )
+                    .position(Edge::Left, 64.px())
+                    .padding(Edge::All, 16.px())
+                    .background_color(BackgroundColor::Toast)
+                    .border_radius(Corner::All, 12.px()),
+            )
+            .child(Text::new(self.emote.text()).font_size(FontSize::Body))
+            .build()
+    }
+}
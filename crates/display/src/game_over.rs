@@ -25,14 +25,16 @@ pub fn check_game_over(builder: &mut ResponseBuilder, game: &GameState) {
             game_objects_enabled: false,
         }));
 
-        builder.push(Command::DisplayGameMessage(DisplayGameMessageCommand {
-            message_type: if winner == builder.user_side {
-                GameMessageType::Victory
-            } else {
-                GameMessageType::Defeat
-            }
-            .into(),
-        }));
+        if !builder.state.spectator {
+            builder.push(Command::DisplayGameMessage(DisplayGameMessageCommand {
+                message_type: if winner == builder.user_side {
+                    GameMessageType::Victory
+                } else {
+                    GameMessageType::Defeat
+                }
+                .into(),
+            }));
+        }
 
         builder.push(panels::open(PanelAddress::GameOver(GameOverData {
             game_id: game.id,
@@ -12,20 +12,47 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use anyhow::Result;
+use core_ui::node_diff;
+use dashmap::DashMap;
 use data::game::{GamePhase, GameState, MulliganDecision};
-use data::game_actions::{GamePrompt, PromptAction};
+use data::game_actions::{EncounterAction, GameAction, GamePrompt, PromptAction, PromptPreview};
+use data::player_name::PlayerId;
 use data::primitives::Side;
+use once_cell::sync::Lazy;
 use prompts::prompts;
-use protos::spelldawn::InterfaceMainControls;
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::{
+    CommandList, InterfaceMainControls, InterfacePanelAddress, Node, NodePatchList,
+};
+use rules::mana;
+use rules::mana::ManaPurpose;
+
+/// Interface nodes most recently sent to each player, used to compute patches
+/// for subsequent updates.
+static SENT_NODES: Lazy<DashMap<PlayerId, SentNodes>> = Lazy::new(DashMap::new);
+
+/// The most recent main controls and panel contents sent to a single player.
+#[derive(Default)]
+struct SentNodes {
+    main_controls: Option<Node>,
+    panels: HashMap<InterfacePanelAddress, Node>,
+}
 
 /// Returns a [InterfaceMainControls] to render the interface state for the
-/// provided `game`.
-pub fn render(game: &GameState, side: Side) -> Result<Option<InterfaceMainControls>> {
+/// provided `game`, with keyboard shortcuts for prompt responses if
+/// `show_hotkeys` is true.
+pub fn render(
+    game: &GameState,
+    side: Side,
+    show_hotkeys: bool,
+) -> Result<Option<InterfaceMainControls>> {
     if let Some(prompt) = &game.player(side).prompt {
-        return prompts::action_prompt(game, side, prompt);
+        return prompts::action_prompt(game, side, prompt, show_hotkeys, preview);
     } else if let Some(prompt) = raids::current_prompt(game, side)? {
-        return prompts::action_prompt(game, side, &prompt);
+        return prompts::action_prompt(game, side, &prompt, show_hotkeys, preview);
     } else if let GamePhase::ResolveMulligans(data) = &game.data.phase {
         if data.decision(side).is_none() {
             return prompts::action_prompt(
@@ -38,9 +65,111 @@ pub fn render(game: &GameState, side: Side) -> Result<Option<InterfaceMainContro
                         PromptAction::MulliganDecision(MulliganDecision::Mulligan),
                     ],
                 },
+                show_hotkeys,
+                preview,
             );
         }
     }
 
     Ok(None)
 }
+
+/// Computes a [PromptPreview] describing the consequences of selecting a card
+/// prompt `action` for the `side` player by simulating it on a copy of the
+/// game.
+///
+/// Returns None for actions which are not card prompt actions, if the
+/// simulation fails, or if there are no consequences to display.
+pub fn preview(game: &GameState, side: Side, action: PromptAction) -> Option<PromptPreview> {
+    if !matches!(
+        action,
+        PromptAction::CardAction(_) | PromptAction::EncounterAction(EncounterAction::CardAction(_))
+    ) {
+        return None;
+    }
+
+    let mut simulation = game.clone_without_updates();
+    actions::handle_game_action(&mut simulation, side, GameAction::PromptAction(action)).ok()?;
+
+    let result = PromptPreview {
+        mana_lost: mana::get(game, side, ManaPurpose::BaseMana).saturating_sub(mana::get(
+            &simulation,
+            side,
+            ManaPurpose::BaseMana,
+        )),
+        actions_lost: game.player(side).actions.saturating_sub(simulation.player(side).actions),
+        cards_discarded: simulation
+            .discard_pile(side)
+            .count()
+            .saturating_sub(game.discard_pile(side).count()),
+        raid_ends: game.data.raid.is_some() && simulation.data.raid.is_none(),
+    };
+
+    (!result.is_empty()).then_some(result)
+}
+
+/// Replaces interface nodes in `commands` with patches against the nodes most
+/// recently sent to `player_id`, wherever doing so reduces the payload size.
+///
+/// Must be invoked on every [CommandList] sent to this player's client in order
+/// to keep the record of sent nodes in sync with the client. Commands for named
+/// players are left unchanged, since they are agents without a client.
+pub fn diff_commands(player_id: PlayerId, commands: &mut CommandList) {
+    if let PlayerId::Named(_) = player_id {
+        return;
+    }
+
+    let mut sent = SENT_NODES.entry(player_id).or_default();
+    for command in commands.commands.iter_mut().filter_map(|c| c.command.as_mut()) {
+        match command {
+            Command::UpdateGameView(update) => {
+                if let Some(controls) = update.game.as_mut().and_then(|g| g.main_controls.as_mut())
+                {
+                    patch_node(
+                        &mut sent.main_controls,
+                        &mut controls.node,
+                        &mut controls.node_patches,
+                    );
+                }
+            }
+            Command::UpdatePanels(update) => {
+                for panel in &mut update.panels {
+                    let Some(address) = &panel.address else {
+                        continue;
+                    };
+                    let mut previous = sent.panels.remove(address);
+                    patch_node(&mut previous, &mut panel.node, &mut panel.node_patches);
+                    if let Some(node) = previous {
+                        sent.panels.insert(address.clone(), node);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Discards the record of nodes sent to `player_id`, causing the next update to
+/// each interface element to be sent in full. Should be invoked whenever this
+/// player connects a new client.
+pub fn reset_sent_nodes(player_id: PlayerId) {
+    SENT_NODES.remove(&player_id);
+}
+
+/// Replaces `node` with `patches` against `previous` if this is smaller, and
+/// then records `node` as the new `previous` node.
+fn patch_node(
+    previous: &mut Option<Node>,
+    node: &mut Option<Node>,
+    patches: &mut Option<NodePatchList>,
+) {
+    let Some(new) = node.take() else {
+        return;
+    };
+
+    match previous.as_ref().and_then(|p| node_diff::diff(p, &new)) {
+        Some(list) => *patches = Some(NodePatchList { patches: list }),
+        None => *node = Some(new.clone()),
+    }
+    *previous = Some(new);
+}
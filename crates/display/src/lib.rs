@@ -16,9 +16,15 @@
 //! understand
 
 pub mod animations;
+pub mod arrows;
+pub mod card_catalog;
+pub mod card_info;
 pub mod card_sync;
+pub mod emotes;
 pub mod game_over;
 pub mod interface;
+pub mod notifications;
 pub mod positions;
 pub mod render;
 pub mod sync;
+pub mod visibility;
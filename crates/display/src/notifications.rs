@@ -0,0 +1,77 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Renders transient notifications displayed to a player as toasts
+
+use adapters::response_builder::ResponseBuilder;
+use core_ui::design::{BackgroundColor, FontSize};
+use core_ui::prelude::*;
+use core_ui::style::Corner;
+use core_ui::text::Text;
+use data::notification::Notification;
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::{FlexAlign, FlexPosition, ShowToastCommand};
+
+/// Time for which notifications remain on screen
+const TOAST_MILLISECONDS: u32 = 3000;
+
+/// Displays `notification` to the user as part of a game response.
+pub fn render(builder: &mut ResponseBuilder, notification: Notification) {
+    builder.push_toast(NotificationToast { notification }.build(), TOAST_MILLISECONDS);
+}
+
+/// Returns a command to display `notification` outside of the normal game
+/// response flow, e.g. when a player connects.
+pub fn command(notification: Notification) -> Command {
+    Command::ShowToast(ShowToastCommand {
+        content: NotificationToast { notification }.build(),
+        duration: Some(adapters::milliseconds(TOAST_MILLISECONDS)),
+    })
+}
+
+struct NotificationToast {
+    notification: Notification,
+}
+
+impl Component for NotificationToast {
+    fn build(self) -> Option<Node> {
+        let toast = Column::new("NotificationToast").style(
+            Style::new()
+                .position_type(FlexPosition::Absolute)
+                .position(Edge::Top, 120.px())
+                .align_self(FlexAlign::Center)
+                .align_items(FlexAlign::Center)
+                .padding(Edge::All, 16.px())
+                .background_color(BackgroundColor::Toast)
+                .border_radius(Corner::All, 12.px()),
+        );
+
+        match self.notification {
+            Notification::QuestCompleted(quest) => toast
+                .child(Text::new("Quest Complete").font_size(FontSize::Body))
+                .child(Text::new(quest.description()).font_size(FontSize::Headline))
+                .child(Text::new(format!("+{} Coins", quest.reward.0)).font_size(FontSize::Body)),
+            Notification::OpponentConceded => toast
+                .child(Text::new("Opponent Conceded").font_size(FontSize::Headline))
+                .child(Text::new("You have won the game").font_size(FontSize::Body)),
+            Notification::GameQuarantined => toast
+                .child(Text::new("Game Ended").font_size(FontSize::Headline))
+                .child(Text::new("An unexpected error occurred").font_size(FontSize::Body)),
+            Notification::GameAbandoned => toast
+                .child(Text::new("Game Abandoned").font_size(FontSize::Headline))
+                .child(Text::new("Both players left the game").font_size(FontSize::Body)),
+        }
+        .build()
+    }
+}
@@ -24,16 +24,18 @@ use protos::spelldawn::object_position::Position;
 use protos::spelldawn::{
     ClientItemLocation, ClientRoomLocation, GameObjectPositions, ObjectPosition,
     ObjectPositionBrowser, ObjectPositionDeck, ObjectPositionDeckContainer,
-    ObjectPositionDiscardPile, ObjectPositionDiscardPileContainer, ObjectPositionHand,
-    ObjectPositionIdentity, ObjectPositionIdentityContainer, ObjectPositionIntoCard,
-    ObjectPositionItem, ObjectPositionRaid, ObjectPositionRevealedCards, ObjectPositionRoom,
-    ObjectPositionStaging, RevealedCardsBrowserSize, RoomIdentifier,
+    ObjectPositionDiscardPile, ObjectPositionDiscardPileContainer, ObjectPositionGlobalModifier,
+    ObjectPositionHand, ObjectPositionIdentity, ObjectPositionIdentityContainer,
+    ObjectPositionIntoCard, ObjectPositionItem, ObjectPositionRaid, ObjectPositionRevealedCards,
+    ObjectPositionRoom, ObjectPositionStaging, RevealedCardsBrowserSize, RoomIdentifier,
 };
 use raids::traits::RaidDisplayState;
 use raids::RaidDataExt;
 use rules::queries;
 use with_error::fail;
 
+use crate::visibility;
+
 pub const RELEASE_SORTING_KEY: u32 = 100;
 
 pub fn for_card(card: &CardState, position: Position) -> ObjectPosition {
@@ -83,6 +85,7 @@ pub fn item(location: ItemLocation) -> Position {
         item_location: match location {
             ItemLocation::Weapons => ClientItemLocation::Left,
             ItemLocation::Artifacts => ClientItemLocation::Right,
+            ItemLocation::Allies => ClientItemLocation::Allies,
         }
         .into(),
     })
@@ -135,6 +138,10 @@ pub fn revealed_cards(large: bool) -> Position {
     })
 }
 
+pub fn global_modifier() -> Position {
+    Position::GlobalModifier(ObjectPositionGlobalModifier {})
+}
+
 pub fn raid() -> Position {
     Position::Raid(ObjectPositionRaid {})
 }
@@ -175,10 +182,11 @@ fn adapt_position(
         CardPosition::DiscardPile(side) => discard(builder, side),
         CardPosition::Scored(side) | CardPosition::Identity(side) => identity(builder, side),
         CardPosition::Scoring => staging(),
+        CardPosition::GlobalModifier => global_modifier(),
         CardPosition::Played(side, target) => {
             card_release_position(builder, game, side, card_id, target)?
         }
-        CardPosition::DeckUnknown(_) => fail!("Invalid card position"),
+        CardPosition::DeckUnknown(_) | CardPosition::Banished => fail!("Invalid card position"),
     })
 }
 
@@ -218,7 +226,7 @@ pub fn ability_card_position(
         if utils::is_true(|| Some(game.ability_state.get(&ability_id)?.currently_resolving)) {
             staging()
         } else {
-            hand(builder, ability_id.side())
+            hand(builder, game.card(ability_id.card_id).controller())
         },
     )
 }
@@ -264,8 +272,9 @@ fn position_override(
         GamePhase::ResolveMulligans(mulligans) => {
             Ok(opening_hand_position_override(builder, game, card, mulligans))
         }
-        GamePhase::Play => raid_position_override(game, card.id.into()),
-        _ => Ok(None),
+        GamePhase::Play => Ok(card_choice_position_override(builder, game, card)
+            .or(raid_position_override(game, card.id.into())?)),
+        GamePhase::GameOver { .. } => Ok(game_over_position_override(builder, game, card)),
     }
 }
 
@@ -285,6 +294,44 @@ fn raid_position_override(game: &GameState, id: GameObjectId) -> Result<Option<O
     })
 }
 
+/// Displays cards which the user is being prompted to choose between in the
+/// revealed cards browser.
+fn card_choice_position_override(
+    builder: &ResponseBuilder,
+    game: &GameState,
+    card: &CardState,
+) -> Option<ObjectPosition> {
+    let prompt = game.player(builder.user_side).prompt.as_ref()?;
+    browser_position(
+        card.id.into(),
+        revealed_cards(true),
+        prompt.card_choices().map(GameObjectId::CardId).collect(),
+    )
+}
+
+/// Displays the remaining cards in both players' decks in the revealed cards
+/// browser once the game ends, if they are being revealed.
+fn game_over_position_override(
+    builder: &ResponseBuilder,
+    game: &GameState,
+    card: &CardState,
+) -> Option<ObjectPosition> {
+    if !card.position().in_deck() || !visibility::revealed_on_game_over(game, card) {
+        return None;
+    }
+
+    let (side, opponent) = (builder.user_side, builder.user_side.opponent());
+    let mut user_deck = game.deck(side).map(|c| c.id).collect::<Vec<_>>();
+    let mut opponent_deck = game.deck(opponent).map(|c| c.id).collect::<Vec<_>>();
+    user_deck.sort();
+    opponent_deck.sort();
+    browser_position(
+        card.id.into(),
+        revealed_cards(true),
+        user_deck.into_iter().chain(opponent_deck).map(GameObjectId::CardId).collect(),
+    )
+}
+
 fn opening_hand_position_override(
     builder: &ResponseBuilder,
     game: &GameState,
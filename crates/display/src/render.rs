@@ -12,18 +12,49 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use adapters::response_builder::{ResponseBuilder, ResponseState};
+use adapters::response_builder::{CardPositions, ResponseBuilder, ResponseState};
 use anyhow::Result;
 use data::game::GameState;
+use data::player_data::{AnimationSpeed, PlayerSettings};
 use data::primitives::Side;
 use protos::spelldawn::game_command::Command;
 use protos::spelldawn::{LoadSceneCommand, SceneLoadMode};
 
 use crate::{animations, game_over, sync};
 
-pub fn connect(game: &GameState, user_side: Side) -> Result<Vec<Command>> {
-    let mut builder =
-        ResponseBuilder::new(user_side, ResponseState { animate: false, is_final_update: true });
+pub fn connect(
+    game: &GameState,
+    user_side: Side,
+    settings: &PlayerSettings,
+) -> Result<Vec<Command>> {
+    reconnect(game, user_side, settings, CardPositions::default())
+}
+
+/// Equivalent to [connect] for a client which was previously displaying this
+/// game with cards in the provided `positions`, e.g. as recorded via
+/// [card_positions].
+///
+/// Cards animate from these positions to their current ones instead of
+/// jumping there, and animations which check the previous position of a card
+/// behave as they would have without the reconnection.
+pub fn reconnect(
+    game: &GameState,
+    user_side: Side,
+    settings: &PlayerSettings,
+    positions: CardPositions,
+) -> Result<Vec<Command>> {
+    let mut builder = ResponseBuilder::new(
+        user_side,
+        ResponseState {
+            animate: !positions.is_empty() && settings.animation_speed != AnimationSpeed::Off,
+            is_final_update: true,
+            icon_mode: settings.icon_mode,
+            animation_speed: settings.animation_speed,
+            show_hotkeys: !settings.disable_hotkeys,
+            spectator: false,
+        },
+    );
+    builder.last_snapshot_positions = positions;
     builder.push(Command::LoadScene(LoadSceneCommand {
         scene_name: "Game".to_string(),
         mode: SceneLoadMode::Single.into(),
@@ -34,13 +65,28 @@ pub fn connect(game: &GameState, user_side: Side) -> Result<Vec<Command>> {
     Ok(builder.commands)
 }
 
-pub fn render_updates(game: &GameState, user_side: Side) -> Result<Vec<Command>> {
-    let mut builder =
-        ResponseBuilder::new(user_side, ResponseState { animate: true, is_final_update: false });
+pub fn render_updates(
+    game: &GameState,
+    user_side: Side,
+    settings: &PlayerSettings,
+) -> Result<Vec<Command>> {
+    let mut builder = ResponseBuilder::new(
+        user_side,
+        ResponseState {
+            animate: settings.animation_speed != AnimationSpeed::Off,
+            is_final_update: false,
+            icon_mode: settings.icon_mode,
+            animation_speed: settings.animation_speed,
+            show_hotkeys: !settings.disable_hotkeys,
+            spectator: false,
+        },
+    );
 
-    for step in &game.updates.steps {
-        sync::run(&mut builder, &step.snapshot)?;
-        animations::render(&mut builder, &step.update, &step.snapshot)?;
+    for step in game.updates.ordered_steps() {
+        sync::run(&mut builder, step.snapshot)?;
+        if let Some(update) = &step.update {
+            animations::render(&mut builder, update, step.snapshot)?;
+        }
     }
 
     builder.state.is_final_update = true;
@@ -49,3 +95,66 @@ pub fn render_updates(game: &GameState, user_side: Side) -> Result<Vec<Command>>
 
     Ok(builder.commands)
 }
+
+/// Renders the full state of `game` for a spectator who is not playing in it,
+/// viewed from the perspective of the `viewpoint` player. Only information
+/// which is public to both players is included.
+pub fn spectate(
+    game: &GameState,
+    viewpoint: Side,
+    settings: &PlayerSettings,
+) -> Result<Vec<Command>> {
+    let mut builder = spectator_builder(viewpoint, settings, false);
+    builder.push(Command::LoadScene(LoadSceneCommand {
+        scene_name: "Game".to_string(),
+        mode: SceneLoadMode::Single.into(),
+        skip_if_current: false,
+    }));
+    sync::run(&mut builder, game)?;
+    game_over::check_game_over(&mut builder, game);
+    Ok(builder.commands)
+}
+
+/// Equivalent to [spectate] for a spectator who is already viewing this game,
+/// animating cards to their new positions.
+pub fn spectator_update(
+    game: &GameState,
+    viewpoint: Side,
+    settings: &PlayerSettings,
+) -> Result<Vec<Command>> {
+    let mut builder =
+        spectator_builder(viewpoint, settings, settings.animation_speed != AnimationSpeed::Off);
+    sync::run(&mut builder, game)?;
+    game_over::check_game_over(&mut builder, game);
+    Ok(builder.commands)
+}
+
+fn spectator_builder(viewpoint: Side, settings: &PlayerSettings, animate: bool) -> ResponseBuilder {
+    ResponseBuilder::new(
+        viewpoint,
+        ResponseState {
+            animate,
+            is_final_update: true,
+            icon_mode: settings.icon_mode,
+            animation_speed: settings.animation_speed,
+            show_hotkeys: false,
+            spectator: true,
+        },
+    )
+}
+
+/// Returns the client position of each card as of the last game view in
+/// `commands`, or None if `commands` do not update the game view.
+pub fn card_positions(commands: &[Command]) -> Option<CardPositions> {
+    commands.iter().rev().find_map(|command| match command {
+        Command::UpdateGameView(update) => Some(
+            update
+                .game
+                .iter()
+                .flat_map(|game| &game.cards)
+                .filter_map(|card| Some((card.card_id?, card.card_position.clone()?)))
+                .collect(),
+        ),
+        _ => None,
+    })
+}
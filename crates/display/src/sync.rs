@@ -14,23 +14,32 @@
 
 //! Converts a GameState into GameView updates
 
+use std::cmp;
+
+use adapters;
 use adapters::response_builder::ResponseBuilder;
 use anyhow::Result;
-use data::game::GameState;
+use assets;
+use core_ui::icons;
+use data::cosmetics::CardBack;
+use data::game::{GameState, ManaPool};
 use data::primitives::{RoomId, Side};
 use protos::spelldawn::{
-    ActionTrackerView, CardView, GameView, ManaView, PlayerInfo, PlayerView, ScoreView,
+    ActionTrackerView, CardView, GameView, ManaPoolView, ManaView, PlayerInfo, PlayerView,
+    ScoreView,
 };
 use rules::mana::ManaPurpose;
 use rules::{flags, mana};
-use {adapters, assets};
 
-use crate::{card_sync, interface, positions};
+use crate::{arrows, card_sync, interface, positions, visibility};
 
 pub fn run(builder: &mut ResponseBuilder, game: &GameState) -> Result<()> {
     let cards: Result<Vec<CardView>> = game
         .all_cards()
-        .filter(|c| !c.position().shuffled_into_deck())
+        .filter(|c| {
+            (!c.position().shuffled_into_deck() || visibility::revealed_on_game_over(game, c))
+                && !c.position().is_banished()
+        })
         .flat_map(|c| {
             let mut cards = card_sync::activated_ability_cards(builder, game, c);
             cards.push(card_sync::card_view(builder, game, c));
@@ -44,15 +53,19 @@ pub fn run(builder: &mut ResponseBuilder, game: &GameState) -> Result<()> {
         cards: cards?,
         raid_active: game.data.raid.is_some(),
         game_object_positions: Some(positions::game_object_positions(builder, game)?),
-        main_controls: if builder.state.is_final_update {
+        main_controls: if builder.state.is_final_update && !builder.state.spectator {
             // Only include controls on final update to ensure interface doesn't show
             // previous UI after click.
-            interface::render(game, builder.user_side)?
+            interface::render(game, builder.user_side, builder.state.show_hotkeys)?
         } else {
             None
         },
     });
 
+    if builder.state.is_final_update && !builder.state.spectator {
+        arrows::render(builder, game)?;
+    }
+
     Ok(())
 }
 
@@ -74,16 +87,37 @@ fn player_view(game: &GameState, side: Side) -> Result<PlayerView> {
                     .map(adapters::room_identifier)
                     .collect(),
             },
-            card_back: Some(assets::card_back(rules::get(identity.name).school)),
+            card_back: Some(assets::card_back(
+                game.player(side)
+                    .appearance
+                    .card_back
+                    .unwrap_or_else(|| CardBack::for_school(rules::get(identity.name).school)),
+            )),
+            board_skin: game.player(side).appearance.board_skin.map(assets::board_skin),
         }),
         score: Some(ScoreView { score: game.player(side).score }),
         mana: Some(ManaView {
             base_mana: mana::get(game, side, ManaPurpose::BaseMana),
             bonus_mana: mana::get(game, side, ManaPurpose::BonusForDisplay),
+            pools: mana::displayed_pools(game, side)
+                .map(|(pool, mana)| ManaPoolView { mana, icon: pool_icon(pool).to_string() })
+                .collect(),
         }),
         action_tracker: Some(ActionTrackerView {
             available_action_count: game.player(side).actions,
+            bonus_action_count: cmp::min(
+                game.player(side).bonus_actions.this_turn,
+                game.player(side).actions,
+            ),
+            next_turn_action_count: game.player(side).bonus_actions.next_turn,
         }),
         can_take_action: actions::can_take_action(game, side),
     })
 }
+
+fn pool_icon(pool: ManaPool) -> &'static str {
+    match pool {
+        ManaPool::Raid(_) | ManaPool::AnyRaid => icons::RAID,
+        ManaPool::Artifacts => icons::ARTIFACT,
+    }
+}
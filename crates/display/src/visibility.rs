@@ -0,0 +1,74 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Decides what each player may learn about a card. All card information sent
+//! to a client should be gated through this module, so that e.g. the identity
+//! of a face-down Overlord minion is never present in the Champion's command
+//! stream.
+
+use data::card_state::CardState;
+use data::game::{GamePhase, GameState};
+use data::primitives::Side;
+
+/// What a player is allowed to know about a given card.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CardVisibility {
+    /// The card's full identity is known: name, costs, stats, rules text, card
+    /// type, and lineage.
+    Revealed,
+    /// Only public state is known: the card's owner, position, face-up state,
+    /// and counters placed on it (e.g. levels).
+    Hidden,
+}
+
+impl CardVisibility {
+    pub fn is_revealed(self) -> bool {
+        self == Self::Revealed
+    }
+}
+
+/// Returns the [CardVisibility] of `card` for the `side` player. A player can
+/// always see cards they have taken control of.
+pub fn card(game: &GameState, card: &CardState, side: Side) -> CardVisibility {
+    if card.is_revealed_to(side)
+        || card.data.controller == Some(side)
+        || revealed_on_game_over(game, card)
+    {
+        CardVisibility::Revealed
+    } else {
+        CardVisibility::Hidden
+    }
+}
+
+/// Returns the [CardVisibility] of `card` for a spectator who is not playing in
+/// this game. Spectators can only see cards which have been revealed to both
+/// players.
+pub fn spectator(game: &GameState, card: &CardState) -> CardVisibility {
+    if (card.is_revealed_to(Side::Overlord) && card.is_revealed_to(Side::Champion))
+        || revealed_on_game_over(game, card)
+    {
+        CardVisibility::Revealed
+    } else {
+        CardVisibility::Hidden
+    }
+}
+
+/// Returns true if `card` is in a hand or deck and is revealed to both players
+/// because the game has ended, see
+/// [data::game::GameConfiguration::reveal_on_game_over].
+pub fn revealed_on_game_over(game: &GameState, card: &CardState) -> bool {
+    game.data.config.reveal_on_game_over
+        && matches!(game.data.phase, GamePhase::GameOver { .. })
+        && (card.position().in_hand() || card.position().in_deck())
+}
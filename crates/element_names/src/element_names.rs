@@ -81,8 +81,14 @@ pub static DECK_BUTTON: ElementName = global("DeckButton");
 
 pub static MENU_BUTTON: ElementName = global("MenuButton");
 
+pub static COSMETICS_BUTTON: ElementName = global("CosmeticsButton");
+
 pub static FEEDBACK_BUTTON: ElementName = global("FeedbackButton");
 
+pub static CONFIRM_BUTTON: ElementName = global("ConfirmButton");
+
+pub static CANCEL_BUTTON: ElementName = global("CancelButton");
+
 pub static CARD_LIST: ElementName = global("CardList");
 
 pub static COLLECTION_BROWSER: ElementName = global("CollectionBrowser");
@@ -99,6 +105,12 @@ pub fn card_list_card_name(name: CardName) -> ElementName {
     ElementName { tag: "CardListCardName", count: name as u64 }
 }
 
+/// Badge displaying `count` copies of a card in a deck. Badges for adjacent
+/// counts are rendered hidden so they can be swapped in optimistically.
+pub fn card_count(name: CardName, count: u32) -> ElementName {
+    ElementName { tag: "CardCount", count: ((name as u64) << 32) | u64::from(count) }
+}
+
 pub fn buy_card(name: CardName) -> ElementName {
     ElementName { tag: "BuyCard", count: name as u64 }
 }
@@ -0,0 +1,277 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Optional REST/JSON gateway which exposes player data to web tooling that
+//! cannot speak gRPC-web. Requests are resolved to a player via the same
+//! identifier and session token handling used by the gRPC service, and player
+//! data is loaded via the server's request handlers.
+//!
+//! Admin routes under `/admin` require the [ADMIN_TOKEN_HEADER] header to
+//! match the `SPELLDAWN_ADMIN_TOKEN` environment variable, and are disabled
+//! if that variable is not set.
+
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Extension, Json, Router};
+use data::achievements::AchievementName;
+use data::adventure::JournalEntry;
+use data::card_name::CardName;
+use data::card_stats::CardStatistics;
+use data::cosmetics::Cosmetic;
+use data::deck::Deck;
+use data::player_data::{MatchRecord, PlayerData, PlayerSettings};
+use data::player_name::PlayerId;
+use data::ratings::PlayerRating;
+use database::{Database, SledDatabase};
+use protos::spelldawn::player_identifier::PlayerIdentifierType;
+use protos::spelldawn::PlayerIdentifier;
+use serde::Serialize;
+use server::{auth, ratings, requests, telemetry};
+use subtle::ConstantTimeEq;
+use tracing::error;
+
+/// Header containing an identifier from Unity's social API
+pub const SOCIAL_IDENTIFIER_HEADER: &str = "x-spelldawn-social-identifier";
+
+/// Header containing a device identifier
+pub const DEVICE_IDENTIFIER_HEADER: &str = "x-spelldawn-device-identifier";
+
+/// Header containing a session token issued by the 'Authenticate' RPC
+pub const SESSION_TOKEN_HEADER: &str = "x-spelldawn-session-token";
+
+/// Header containing the shared secret required for admin routes
+pub const ADMIN_TOKEN_HEADER: &str = "x-spelldawn-admin-token";
+
+/// Serves the gateway on the provided address until the server exits.
+pub async fn serve(address: SocketAddr) -> Result<()> {
+    let router = router(
+        SledDatabase { flush_on_write: false },
+        env::var("SPELLDAWN_ADMIN_TOKEN").ok().filter(|token| !token.is_empty()),
+    );
+    axum::Server::bind(&address).serve(router.into_make_service()).await?;
+    Ok(())
+}
+
+/// Builds the gateway's routes, serving requests from `database`. Admin
+/// routes are disabled if no `admin_token` is provided.
+pub fn router<D: Database + 'static>(database: D, admin_token: Option<String>) -> Router {
+    Router::new()
+        .route("/v1/profile", get(profile::<D>))
+        .route("/v1/decks", get(decks::<D>))
+        .route("/v1/collection", get(collection::<D>))
+        .route("/v1/match_history", get(match_history::<D>))
+        .route("/v1/adventure_journal", get(adventure_journal::<D>))
+        .route("/admin/card_stats", get(card_stats::<D>))
+        .layer(Extension(Gateway {
+            database: Arc::new(Mutex::new(database)),
+            admin_token: admin_token.map(Arc::from),
+        }))
+}
+
+/// Public summary of a player's account
+#[derive(Debug, Clone, Serialize)]
+pub struct Profile {
+    pub id: PlayerId,
+    pub achievements: HashSet<AchievementName>,
+    pub cosmetics: HashSet<Cosmetic>,
+    pub settings: PlayerSettings,
+    pub in_game: bool,
+    /// Ranked rating, or the starting rating if this player has not yet played
+    /// a ranked game
+    pub rating: PlayerRating,
+}
+
+impl Profile {
+    fn new(player: PlayerData, rating: PlayerRating) -> Self {
+        Self {
+            id: player.id,
+            achievements: player.achievements,
+            cosmetics: player.cosmetics,
+            settings: player.settings,
+            in_game: player.state.is_some(),
+            rating,
+        }
+    }
+}
+
+/// Aggregate statistics for one card, exported for balance analysis
+#[derive(Debug, Clone, Serialize)]
+pub struct CardReport {
+    pub card_name: CardName,
+    pub games_drawn: u32,
+    pub win_rate_when_drawn: Option<f64>,
+    pub times_played: u32,
+    pub average_turn_played: Option<f64>,
+}
+
+impl From<CardStatistics> for CardReport {
+    fn from(statistics: CardStatistics) -> Self {
+        Self {
+            card_name: statistics.card_name,
+            games_drawn: statistics.games_drawn,
+            win_rate_when_drawn: statistics.win_rate_when_drawn(),
+            times_played: statistics.times_played,
+            average_turn_played: statistics.average_turn_played(),
+        }
+    }
+}
+
+/// Error returned to HTTP clients
+#[derive(Debug)]
+pub struct GatewayError {
+    status: StatusCode,
+    message: String,
+}
+
+impl IntoResponse for GatewayError {
+    fn into_response(self) -> Response {
+        (self.status, self.message).into_response()
+    }
+}
+
+/// State shared by all routes
+struct Gateway<D> {
+    database: Arc<Mutex<D>>,
+    admin_token: Option<Arc<str>>,
+}
+
+impl<D> Clone for Gateway<D> {
+    fn clone(&self) -> Self {
+        Self { database: self.database.clone(), admin_token: self.admin_token.clone() }
+    }
+}
+
+impl<D: Database> Gateway<D> {
+    /// Authenticates the player making a request and invokes `function` with
+    /// their [PlayerData].
+    fn with_player<T>(
+        &self,
+        headers: &HeaderMap,
+        function: impl FnOnce(&D, PlayerData) -> Result<T>,
+    ) -> Result<Json<T>, GatewayError> {
+        let identifier = player_identifier(headers).ok_or_else(|| GatewayError {
+            status: StatusCode::UNAUTHORIZED,
+            message: "Player identifier is required".to_string(),
+        })?;
+        let session_token = headers
+            .get(SESSION_TOKEN_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+        let mut database = self.database.lock().expect("database lock");
+        let player_id = auth::player_id(&mut *database, &Some(identifier), &session_token)
+            .map_err(|error| GatewayError {
+                status: StatusCode::UNAUTHORIZED,
+                message: format!("{:#}", error),
+            })?;
+        let player = requests::find_player(&*database, player_id).map_err(server_error)?;
+        function(&database, player).map(Json).map_err(server_error)
+    }
+
+    /// Verifies that the admin token header for a request matches the
+    /// configured token and invokes `function`.
+    fn with_admin<T>(
+        &self,
+        headers: &HeaderMap,
+        function: impl FnOnce(&D) -> Result<T>,
+    ) -> Result<Json<T>, GatewayError> {
+        let expected = self.admin_token.as_ref().ok_or_else(|| GatewayError {
+            status: StatusCode::NOT_FOUND,
+            message: "Admin routes are disabled".to_string(),
+        })?;
+        let token = headers.get(ADMIN_TOKEN_HEADER).map(|v| v.as_bytes()).unwrap_or_default();
+        if !bool::from(token.ct_eq(expected.as_bytes())) {
+            return Err(GatewayError {
+                status: StatusCode::FORBIDDEN,
+                message: "Invalid admin token".to_string(),
+            });
+        }
+        function(&self.database.lock().expect("database lock")).map(Json).map_err(server_error)
+    }
+}
+
+async fn profile<D: Database>(
+    Extension(gateway): Extension<Gateway<D>>,
+    headers: HeaderMap,
+) -> Result<Json<Profile>, GatewayError> {
+    gateway.with_player(&headers, |database, player| {
+        let rating = ratings::rating(database, player.id)?;
+        Ok(Profile::new(player, rating))
+    })
+}
+
+async fn decks<D: Database>(
+    Extension(gateway): Extension<Gateway<D>>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<Deck>>, GatewayError> {
+    gateway.with_player(&headers, |_, player| Ok(player.decks))
+}
+
+async fn collection<D: Database>(
+    Extension(gateway): Extension<Gateway<D>>,
+    headers: HeaderMap,
+) -> Result<Json<HashMap<CardName, u32>>, GatewayError> {
+    gateway.with_player(&headers, |_, player| Ok(player.collection))
+}
+
+async fn match_history<D: Database>(
+    Extension(gateway): Extension<Gateway<D>>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<MatchRecord>>, GatewayError> {
+    gateway.with_player(&headers, |_, player| Ok(player.match_history))
+}
+
+async fn adventure_journal<D: Database>(
+    Extension(gateway): Extension<Gateway<D>>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<JournalEntry>>, GatewayError> {
+    gateway.with_player(&headers, |_, player| {
+        Ok(player.adventure.map(|a| a.journal).unwrap_or_default())
+    })
+}
+
+async fn card_stats<D: Database>(
+    Extension(gateway): Extension<Gateway<D>>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<CardReport>>, GatewayError> {
+    gateway.with_admin(&headers, |database| {
+        Ok(telemetry::export(database)?.into_iter().map(CardReport::from).collect())
+    })
+}
+
+fn server_error(error: anyhow::Error) -> GatewayError {
+    error!(?error, "Gateway Error!");
+    GatewayError {
+        status: StatusCode::INTERNAL_SERVER_ERROR,
+        message: format!("Server Error: {:#}", error),
+    }
+}
+
+/// Reads a [PlayerIdentifier] from the request headers, if one was provided.
+pub fn player_identifier(headers: &HeaderMap) -> Option<PlayerIdentifier> {
+    let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok()).map(String::from);
+    let identifier_type = if let Some(social) = header(SOCIAL_IDENTIFIER_HEADER) {
+        PlayerIdentifierType::SocialIdentifier(social)
+    } else {
+        PlayerIdentifierType::DeviceIdentifier(header(DEVICE_IDENTIFIER_HEADER)?)
+    };
+    Some(PlayerIdentifier { player_identifier_type: Some(identifier_type) })
+}
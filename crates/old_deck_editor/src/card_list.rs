@@ -12,6 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use core_ui::action_builder::ActionBuilder;
+use core_ui::animations::{
+    self, AnimateStyle, AnimateToElement, DestroyElement, InterfaceAnimation,
+};
+use core_ui::conditional::Conditional;
 use core_ui::design::RED_900;
 use core_ui::drop_target::DropTarget;
 use core_ui::panels;
@@ -19,10 +24,13 @@ use core_ui::prelude::*;
 use data::card_name::CardName;
 use data::deck::Deck;
 use data::primitives::DeckIndex;
+use data::user_actions::OldDeckEditorAction;
+use element_names::CurrentDraggable;
 use panel_address::{CollectionBrowserFilters, OldDeckEditorData, PanelAddress};
-use protos::spelldawn::{FlexAlign, FlexDirection, StandardAction};
+use protos::spelldawn::animate_element_style::Property;
+use protos::spelldawn::{FlexAlign, FlexDirection, FlexVector2};
 
-use crate::deck_editor_card_title::DeckEditorCardTitle;
+use crate::deck_editor_card_title::{self, DeckEditorCardTitle};
 use crate::deck_editor_panel::EDITOR_COLUMN_WIDTH;
 use crate::deck_tile::DeckTile;
 use crate::editor_column_scroll::EditorColumnScroll;
@@ -68,7 +76,7 @@ impl<'a> Component for CardList<'a> {
     fn build(self) -> Option<Node> {
         EditorColumnScroll::new()
             .child(
-                DropTarget::new("CardList")
+                DropTarget::new(element_names::CARD_LIST)
                     .style(
                         Style::new()
                             .flex_direction(FlexDirection::Column)
@@ -82,18 +90,51 @@ impl<'a> Component for CardList<'a> {
                             deck: Some(self.deck.index),
                             show_edit_options: true,
                             collection_filters: CollectionBrowserFilters::default(),
+                            deck_list_offset: 0,
                         }),
                     )))
                     .children(sorted_deck(self.deck).into_iter().map(|(card_name, count)| {
                         DeckEditorCardTitle::new(*card_name)
                             .count(*count)
-                            .on_drop(Some(drop_action(*card_name, self.deck.index)))
+                            .on_drop(Some(drop_action(*card_name, *count, self.deck.index)))
                     })),
             )
             .build()
     }
 }
 
-fn drop_action(_name: CardName, _active_deck: DeckIndex) -> StandardAction {
-    StandardAction::default()
+/// Removes one copy of a card from `active_deck`, animating the dragged card
+/// back to its position in the collection browser if it is visible.
+fn drop_action(name: CardName, count: u32, active_deck: DeckIndex) -> ActionBuilder {
+    let action =
+        ActionBuilder::new().action(OldDeckEditorAction::RemoveFromDeck(name, active_deck)).update(
+            Conditional::if_exists(element_names::deck_card(name))
+                .then(
+                    InterfaceAnimation::new()
+                        .start(
+                            CurrentDraggable,
+                            AnimateToElement::new(element_names::deck_card(name)),
+                        )
+                        .insert(animations::default_duration(), CurrentDraggable, DestroyElement),
+                )
+                .or_else(
+                    InterfaceAnimation::new()
+                        .start(
+                            CurrentDraggable,
+                            AnimateStyle::new(Property::Scale(FlexVector2 { x: 0.1, y: 0.1 })),
+                        )
+                        .start(
+                            CurrentDraggable,
+                            AnimateToElement::new(element_names::COLLECTION_BROWSER),
+                        )
+                        .insert(animations::default_duration(), CurrentDraggable, DestroyElement),
+                ),
+        );
+
+    // The final copy is hidden via `remove_original` when dragged
+    if count > 1 {
+        action.update(deck_editor_card_title::update_count(name, count, count - 1))
+    } else {
+        action
+    }
 }
@@ -14,17 +14,25 @@
 
 use std::iter;
 
+use core_ui::action_builder::ActionBuilder;
+use core_ui::animations::{
+    self, AnimateToElement, CreateTargetAtIndex, DestroyElement, InterfaceAnimation,
+};
+use core_ui::conditional::Conditional;
 use core_ui::design::BLACK;
 use core_ui::drop_target::DropTarget;
 use core_ui::prelude::*;
 use data::card_name::CardName;
 use data::deck::Deck;
 use data::player_data::PlayerData;
+use data::user_actions::OldDeckEditorAction;
+use element_names::{CurrentDraggable, TargetName};
 use panel_address::CollectionBrowserFilters;
-use protos::spelldawn::{FlexAlign, FlexDirection, FlexJustify, StandardAction};
+use protos::spelldawn::{FlexAlign, FlexDirection, FlexJustify};
 
 use crate::deck_editor_card::DeckEditorCard;
 use crate::empty_card::EmptyCard;
+use crate::{card_list, deck_editor_card_title};
 
 /// Returns an iterator over cards owned by 'player' which match a given
 /// [CollectionBrowserFilters]
@@ -72,7 +80,7 @@ impl<'a> Component for CollectionBrowser<'a> {
         sort_cards(&mut cards);
         let row_one = cards.iter().skip(self.filters.offset).take(4).collect::<Vec<_>>();
         let row_two = cards.iter().skip(self.filters.offset + 4).take(4).collect::<Vec<_>>();
-        DropTarget::new("CollectionBrowser".to_string())
+        DropTarget::new(element_names::COLLECTION_BROWSER)
             .style(
                 Style::new()
                     .background_color(BLACK)
@@ -88,6 +96,40 @@ impl<'a> Component for CollectionBrowser<'a> {
     }
 }
 
-fn drop_action(_name: CardName, _open_deck: &Deck) -> StandardAction {
-    StandardAction::default()
+/// Adds a card to `open_deck`. If the card is already present in the card
+/// list, animates the dragged card into its entry and increments its count,
+/// otherwise creates a new entry at the card's sorted position.
+fn drop_action(name: CardName, open_deck: &Deck) -> ActionBuilder {
+    let action = ActionBuilder::new().action(OldDeckEditorAction::AddToDeck(name, open_deck.index));
+    let element_name = element_names::card_list_card_name(name);
+    match open_deck.cards.get(&name) {
+        Some(count) => action
+            .update(
+                InterfaceAnimation::new()
+                    .start(CurrentDraggable, AnimateToElement::new(element_name))
+                    .insert(animations::default_duration(), CurrentDraggable, DestroyElement),
+            )
+            .update(deck_editor_card_title::update_count(name, *count, count + 1)),
+        None => {
+            let target_name = TargetName(element_name);
+            action.update(
+                Conditional::if_exists(element_names::CARD_LIST).then(
+                    InterfaceAnimation::new()
+                        .start(
+                            CurrentDraggable,
+                            CreateTargetAtIndex::parent(element_names::CARD_LIST)
+                                // Offset by one for the deck tile at the top of
+                                // the card list.
+                                .index(card_list::position_for_card(open_deck, name) as u32 + 1)
+                                .name(target_name),
+                        )
+                        .start(
+                            CurrentDraggable,
+                            AnimateToElement::new(target_name).disable_height_half_offset(true),
+                        )
+                        .insert(animations::default_duration(), CurrentDraggable, DestroyElement),
+                ),
+            )
+        }
+    }
 }
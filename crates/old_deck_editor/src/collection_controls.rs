@@ -69,6 +69,13 @@ impl<'a> Component for CollectionControls<'a> {
                 Button::new("Filters")
                     .button_type(ButtonType::Secondary)
                     .width_mode(WidthMode::Flexible)
+                    .layout(Layout::new().margin(Edge::All, 8.px())),
+            )
+            .child(
+                Button::new("Crafting")
+                    .button_type(ButtonType::Secondary)
+                    .width_mode(WidthMode::Flexible)
+                    .action(panels::set(PanelAddress::Crafting(0)))
                     .layout(Layout::new().margin(Edge::Left, 8.px()).margin(Edge::Right, 16.px())),
             )
             .build()
@@ -0,0 +1,143 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Panel for crafting cards with dust and disenchanting unwanted cards from the
+//! player's collection
+
+use core_ui::button::{Button, ButtonType};
+use core_ui::design::FontSize;
+use core_ui::panel_window::PanelWindow;
+use core_ui::panels;
+use core_ui::prelude::*;
+use core_ui::text::Text;
+use data::card_name::CardName;
+use data::crafting::{self, CRAFTING_PAGE_SIZE, MAX_CRAFTED_COPIES};
+use data::player_data::PlayerData;
+use data::user_actions::UserAction;
+use panel_address::{OldDeckEditorData, Panel, PanelAddress};
+use protos::spelldawn::{FlexAlign, FlexJustify};
+
+/// Returns all cards which can be crafted, in alphabetical order
+pub fn craftable_cards() -> Vec<CardName> {
+    let mut cards = enum_iterator::all::<CardName>()
+        .filter(|name| !name.is_test_card())
+        .filter(|name| crafting::craft_cost(rules::get(*name).rarity).is_some())
+        .collect::<Vec<_>>();
+    cards.sort_by_key(|name| name.displayed_name());
+    cards
+}
+
+pub struct CraftingPanel<'a> {
+    pub player: &'a PlayerData,
+    /// Index of the first card on this page within [craftable_cards]
+    pub offset: u32,
+}
+
+impl<'a> Panel for CraftingPanel<'a> {
+    fn address(&self) -> PanelAddress {
+        PanelAddress::Crafting(self.offset)
+    }
+}
+
+impl<'a> Component for CraftingPanel<'a> {
+    fn build(self) -> Option<Node> {
+        let cards = craftable_cards();
+        let page_size = CRAFTING_PAGE_SIZE as u32;
+        let has_next = self.offset + page_size < cards.len() as u32;
+        PanelWindow::new(self.address(), 900.px(), 700.px())
+            .title("Crafting")
+            .content(
+                Column::new("Crafting")
+                    .style(Style::new().width(100.pct()))
+                    .child(
+                        Text::new(format!("Dust: {}", self.player.dust))
+                            .font_size(FontSize::Headline),
+                    )
+                    .children(
+                        cards
+                            .iter()
+                            .skip(self.offset as usize)
+                            .take(CRAFTING_PAGE_SIZE)
+                            .map(|name| self.card_row(*name)),
+                    )
+                    .child(
+                        Row::new("CraftingPages")
+                            .style(
+                                Style::new()
+                                    .width(100.pct())
+                                    .justify_content(FlexJustify::SpaceBetween),
+                            )
+                            .child(
+                                Button::new("Back")
+                                    .action(panels::set(PanelAddress::OldDeckEditor(
+                                        OldDeckEditorData::default(),
+                                    )))
+                                    .layout(Layout::new().margin(Edge::All, 16.px())),
+                            )
+                            .child((self.offset > 0).then(|| {
+                                page_button("Previous", self.offset.saturating_sub(page_size))
+                            }))
+                            .child(has_next.then(|| page_button("Next", self.offset + page_size))),
+                    ),
+            )
+            .build()
+    }
+}
+
+impl<'a> CraftingPanel<'a> {
+    fn card_row(&self, name: CardName) -> impl Component {
+        let rarity = rules::get(name).rarity;
+        let owned = self.player.owned_copies(name);
+        let cost = crafting::craft_cost(rarity).unwrap_or_default();
+        let value = crafting::disenchant_value(rarity).unwrap_or_default();
+        let can_craft = owned < MAX_CRAFTED_COPIES && self.player.dust >= cost;
+        let can_disenchant = owned > self.player.copies_in_decks(name);
+        Row::new(format!("Craft{name:?}"))
+            .style(
+                Style::new()
+                    .width(100.pct())
+                    .margin(Edge::Vertical, 4.px())
+                    .justify_content(FlexJustify::SpaceBetween)
+                    .align_items(FlexAlign::Center),
+            )
+            .child(
+                Text::new(format!("{} ({:?}) x{}", name.displayed_name(), rarity, owned))
+                    .font_size(FontSize::Body),
+            )
+            .child(
+                Row::new("Buttons")
+                    .child(
+                        Button::new(format!("Craft ({cost})"))
+                            .button_type(ButtonType::Primary)
+                            .action(UserAction::CraftCard(name))
+                            .disabled(!can_craft)
+                            .layout(Layout::new().margin(Edge::Horizontal, 8.px())),
+                    )
+                    .child(
+                        Button::new(format!("Disenchant (+{value})"))
+                            .button_type(ButtonType::Secondary)
+                            .action(UserAction::DisenchantCard(name))
+                            .disabled(!can_disenchant)
+                            .layout(Layout::new().margin(Edge::Horizontal, 8.px())),
+                    ),
+            )
+    }
+}
+
+fn page_button(label: &str, offset: u32) -> Button {
+    Button::new(label)
+        .button_type(ButtonType::Secondary)
+        .action(panels::set(PanelAddress::Crafting(offset)))
+        .layout(Layout::new().margin(Edge::All, 16.px()))
+}
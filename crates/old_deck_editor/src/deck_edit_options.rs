@@ -17,7 +17,7 @@ use core_ui::design::RED_900;
 use core_ui::panels;
 use core_ui::prelude::*;
 use data::deck::Deck;
-use panel_address::{CollectionBrowserFilters, OldDeckEditorData, PanelAddress};
+use panel_address::{CollectionBrowserFilters, Confirmation, OldDeckEditorData, PanelAddress};
 use protos::spelldawn::FlexAlign;
 
 use crate::deck_editor_panel::EDITOR_COLUMN_WIDTH;
@@ -52,6 +52,7 @@ impl<'a> Component for DeckEditOptions<'a> {
                         deck: Some(self.deck.index),
                         show_edit_options: false,
                         collection_filters: CollectionBrowserFilters::default(),
+                        deck_list_offset: 0,
                     }))),
             )
             .child(
@@ -62,6 +63,9 @@ impl<'a> Component for DeckEditOptions<'a> {
             .child(
                 Button::new("Delete")
                     .button_type(ButtonType::Secondary)
+                    .action(panels::open(PanelAddress::Confirm(Confirmation::DeleteDeck(
+                        self.deck.index,
+                    ))))
                     .layout(Layout::new().margin(Edge::All, 16.px())),
             )
             .build()
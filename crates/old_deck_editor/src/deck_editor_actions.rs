@@ -16,11 +16,12 @@ use std::collections::HashMap;
 
 use anyhow::Result;
 use data::card_name::CardName;
+use data::cosmetics::DeckCosmetics;
 use data::deck::Deck;
 use data::player_data::PlayerData;
 use data::primitives::{DeckIndex, School, Side};
 use data::user_actions::OldDeckEditorAction;
-use with_error::{fail, WithError};
+use with_error::{fail, verify, WithError};
 
 use crate::pick_deck_name;
 use crate::pick_deck_name::DECK_NAME_INPUT;
@@ -43,6 +44,7 @@ pub fn handle(
                 side,
                 identity: default_identity(side, school)?,
                 cards: HashMap::new(),
+                cosmetics: DeckCosmetics::default(),
             });
         }
         OldDeckEditorAction::AddToDeck(card_name, deck_id) => {
@@ -61,6 +63,14 @@ pub fn handle(
                 }
             }
         }
+        OldDeckEditorAction::DeleteDeck(deck_id) => {
+            verify!(deck_id.value < player.decks.len(), "Deck not found");
+            player.decks.remove(deck_id.value);
+            // Decks are identified by their position in the deck list
+            for (i, deck) in player.decks.iter_mut().enumerate() {
+                deck.index = DeckIndex::new(i);
+            }
+        }
     }
 
     Ok(())
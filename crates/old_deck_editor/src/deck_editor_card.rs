@@ -12,12 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use core_ui::action_builder::ActionBuilder;
 use core_ui::design::{FontSize, ORANGE_900};
 use core_ui::draggable::Draggable;
 use core_ui::prelude::*;
 use core_ui::text::Text;
 use data::card_name::CardName;
-use protos::spelldawn::{StandardAction, TextAlign};
+use protos::spelldawn::TextAlign;
 
 use crate::deck_editor_card_title::DeckEditorCardTitle;
 
@@ -32,7 +33,7 @@ pub const CARD_HEIGHT: f32 = 36.0;
 pub struct DeckEditorCard {
     layout: Layout,
     card_name: CardName,
-    on_drop: Option<StandardAction>,
+    on_drop: Option<ActionBuilder>,
 }
 
 impl DeckEditorCard {
@@ -45,7 +46,7 @@ impl DeckEditorCard {
         self
     }
 
-    pub fn on_drop(mut self, on_drop: Option<StandardAction>) -> Self {
+    pub fn on_drop(mut self, on_drop: Option<ActionBuilder>) -> Self {
         self.on_drop = on_drop;
         self
     }
@@ -53,8 +54,8 @@ impl DeckEditorCard {
 
 impl Component for DeckEditorCard {
     fn build(self) -> Option<Node> {
-        Draggable::new(self.card_name.to_string())
-            //.drop_targets(vec!["CardList"])
+        Draggable::new(element_names::deck_card(self.card_name))
+            .drop_target(element_names::CARD_LIST)
             .over_target_indicator(move || DeckEditorCardTitle::new(self.card_name).build())
             .on_drop(self.on_drop)
             .style(
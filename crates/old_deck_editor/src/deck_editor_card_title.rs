@@ -12,15 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use assets;
-use assets::CardIconType;
+use assets::{self, CardIconType};
+use core_ui::action_builder::ActionBuilder;
+use core_ui::animations::{ApplyStyle, InterfaceAnimation};
 use core_ui::design::{BackgroundColor, Font, FontColor, FontSize, PINK_900};
 use core_ui::draggable::Draggable;
 use core_ui::prelude::*;
 use core_ui::style::Corner;
 use core_ui::text::Text;
 use data::card_name::CardName;
-use protos::spelldawn::{FlexAlign, FlexDirection, FlexJustify, StandardAction};
+use data::player_data::IconMode;
+use protos::spelldawn::{FlexAlign, FlexDirection, FlexDisplayStyle, FlexJustify};
 
 use crate::deck_editor_card::DeckEditorCard;
 use crate::deck_editor_panel::EDITOR_COLUMN_WIDTH;
@@ -29,7 +31,7 @@ use crate::deck_editor_panel::EDITOR_COLUMN_WIDTH;
 pub struct DeckEditorCardTitle {
     layout: Layout,
     card_name: CardName,
-    on_drop: Option<StandardAction>,
+    on_drop: Option<ActionBuilder>,
     count: Option<u32>,
 }
 
@@ -43,7 +45,7 @@ impl DeckEditorCardTitle {
         self
     }
 
-    pub fn on_drop(mut self, on_drop: Option<StandardAction>) -> Self {
+    pub fn on_drop(mut self, on_drop: Option<ActionBuilder>) -> Self {
         self.on_drop = on_drop;
         self
     }
@@ -67,8 +69,8 @@ impl Component for DeckEditorCardTitle {
             _ => None,
         };
 
-        Draggable::new(format!("{}Title", self.card_name))
-            //.drop_targets(vec!["CollectionBrowser"])
+        Draggable::new(element_names::card_list_card_name(self.card_name))
+            .drop_target(element_names::COLLECTION_BROWSER)
             .over_target_indicator(move || DeckEditorCard::new(self.card_name).build())
             .on_drop(self.on_drop)
             .horizontal_drag_start_distance(100)
@@ -92,7 +94,7 @@ impl Component for DeckEditorCardTitle {
                             .height(44.px())
                             .margin(Edge::All, 8.px())
                             .flex_shrink(0.0)
-                            .background_image(assets::card_icon(icon))
+                            .background_image(assets::card_icon(icon, IconMode::Standard))
                             .justify_content(FlexJustify::Center)
                             .align_items(FlexAlign::Center),
                     )
@@ -119,21 +121,45 @@ impl Component for DeckEditorCardTitle {
                             .layout(Layout::new().margin(Edge::All, 0.px())),
                     ),
             )
-            .child(self.count.map(|c| {
-                Column::new("CardCount")
-                    .style(
-                        Style::new()
-                            .background_color(BackgroundColor::CardCount)
-                            .justify_content(FlexJustify::Center)
-                            .align_items(FlexAlign::Center)
-                            .flex_shrink(0.0)
-                            .margin(Edge::All, 8.px())
-                            .width(32.px())
-                            .height(32.px())
-                            .border_radius(Corner::All, 8.px()),
-                    )
-                    .child(Text::new(c.to_string()).font_size(FontSize::CardCount))
+            .children(self.count.into_iter().flat_map(|c| {
+                // Badges for adjacent counts are hidden until an optimistic
+                // update swaps them in, see [update_count].
+                [c.saturating_sub(1), c, c + 1]
+                    .into_iter()
+                    .filter(|count| *count > 0)
+                    .map(move |count| count_badge(self.card_name, count, count == c))
             }))
             .build()
     }
 }
+
+/// Optimistically replaces the displayed count for `card_name` in the card
+/// list with `to`, before the server renders the updated deck.
+pub fn update_count(card_name: CardName, from: u32, to: u32) -> InterfaceAnimation {
+    InterfaceAnimation::new()
+        .start(
+            element_names::card_count(card_name, from),
+            ApplyStyle::new(Style::new().display(FlexDisplayStyle::None)),
+        )
+        .start(
+            element_names::card_count(card_name, to),
+            ApplyStyle::new(Style::new().display(FlexDisplayStyle::Flex)),
+        )
+}
+
+fn count_badge(card_name: CardName, count: u32, visible: bool) -> impl Component {
+    Column::new(element_names::card_count(card_name, count))
+        .style(
+            Style::new()
+                .display(if visible { FlexDisplayStyle::Flex } else { FlexDisplayStyle::None })
+                .background_color(BackgroundColor::CardCount)
+                .justify_content(FlexJustify::Center)
+                .align_items(FlexAlign::Center)
+                .flex_shrink(0.0)
+                .margin(Edge::All, 8.px())
+                .width(32.px())
+                .height(32.px())
+                .border_radius(Corner::All, 8.px()),
+        )
+        .child(Text::new(count.to_string()).font_size(FontSize::CardCount))
+}
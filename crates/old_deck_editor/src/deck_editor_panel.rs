@@ -77,7 +77,7 @@ impl<'a> Component for OldDeckEditorPanel<'a> {
                             DeckEditOptions::new(deck).build()
                         }
                         Some(deck) => CardList::new(deck).build(),
-                        _ => DeckList::new(self.player, self.data.collection_filters).build(),
+                        _ => DeckList::new(self.player, self.data).build(),
                     })
                     .child(if self.data.collection_filters.offset < 8 {
                         None
@@ -92,6 +92,7 @@ impl<'a> Component for OldDeckEditorPanel<'a> {
                                             offset: self.data.collection_filters.offset - 8,
                                         },
                                         show_edit_options: false,
+                                        deck_list_offset: self.data.deck_list_offset,
                                     },
                                 )))
                                 .layout(
@@ -122,6 +123,7 @@ impl<'a> Component for OldDeckEditorPanel<'a> {
                                                 offset: self.data.collection_filters.offset + 8,
                                             },
                                             show_edit_options: false,
+                                            deck_list_offset: self.data.deck_list_offset,
                                         },
                                     )))
                                     .layout(
@@ -12,28 +12,32 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use core_ui::button::{Button, ButtonType};
+use core_ui::button::{Button, ButtonType, IconButtonType};
 use core_ui::design::{FontSize, RED_900};
 use core_ui::panels;
 use core_ui::prelude::*;
 use core_ui::text::Text;
+use core_ui::virtual_list::{PageControls, VirtualList};
 use data::player_data::PlayerData;
-use panel_address::{CollectionBrowserFilters, CreateDeckState, OldDeckEditorData, PanelAddress};
-use protos::spelldawn::{FlexAlign, FlexDirection};
+use panel_address::{CreateDeckState, OldDeckEditorData, PanelAddress};
+use protos::spelldawn::FlexAlign;
 
 use crate::deck_tile::DeckTile;
 use crate::editor_column_scroll::EditorColumnScroll;
 
+/// Number of decks to display on each page of the deck list
+const DECKS_PER_PAGE: usize = 6;
+
 /// Displays the decks owned by a player
 #[derive(Debug)]
 pub struct DeckList<'a> {
     player: &'a PlayerData,
-    filters: CollectionBrowserFilters,
+    data: OldDeckEditorData,
 }
 
 impl<'a> DeckList<'a> {
-    pub fn new(player: &'a PlayerData, filters: CollectionBrowserFilters) -> Self {
-        DeckList { player, filters }
+    pub fn new(player: &'a PlayerData, data: OldDeckEditorData) -> Self {
+        DeckList { player, data }
     }
 }
 
@@ -58,19 +62,33 @@ impl<'a> Component for DeckList<'a> {
                         Column::new("Decks")
                             .style(
                                 Style::new()
-                                    .flex_direction(FlexDirection::Column)
-                                    .align_items(FlexAlign::Center)
+                                    .align_items(FlexAlign::Stretch)
                                     .padding(Edge::All, 1.vw()),
                             )
-                            .children(decks.into_iter().map(|deck| {
-                                DeckTile::new(deck).action(panels::set(
-                                    PanelAddress::OldDeckEditor(OldDeckEditorData {
-                                        deck: Some(deck.index),
-                                        collection_filters: self.filters,
-                                        show_edit_options: false,
+                            .child(
+                                VirtualList::new("DeckTiles", decks.len())
+                                    .offset(self.data.deck_list_offset)
+                                    .page_size(DECKS_PER_PAGE)
+                                    .controls(PageControls::Vertical)
+                                    .button_type(IconButtonType::Secondary)
+                                    .item(|index| {
+                                        DeckTile::new(decks[index]).action(panels::set(
+                                            PanelAddress::OldDeckEditor(OldDeckEditorData {
+                                                deck: Some(decks[index].index),
+                                                show_edit_options: false,
+                                                ..self.data
+                                            }),
+                                        ))
+                                    })
+                                    .page_action(|offset| {
+                                        panels::set(PanelAddress::OldDeckEditor(
+                                            OldDeckEditorData {
+                                                deck_list_offset: offset,
+                                                ..self.data
+                                            },
+                                        ))
                                     }),
-                                ))
-                            })),
+                            ),
                     ),
             )
             .build()
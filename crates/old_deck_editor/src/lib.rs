@@ -17,6 +17,7 @@
 pub mod card_list;
 pub mod collection_browser;
 pub mod collection_controls;
+pub mod crafting_panel;
 pub mod deck_edit_options;
 pub mod deck_editor_actions;
 pub mod deck_editor_card;
@@ -16,8 +16,10 @@
 
 use core_ui::prelude::Component;
 use data::adventure::TilePosition;
-use data::player_name::PlayerId;
+use data::leaderboard::RunSeed;
+use data::player_name::{NamedPlayer, PlayerId};
 use data::primitives::{DeckId, DeckIndex, GameId, School, Side};
+use data::user_actions::GamePreset;
 use protos::spelldawn::{InterfacePanel, InterfacePanelAddress, Node};
 use serde::{Deserialize, Serialize};
 use serde_json::ser;
@@ -41,6 +43,7 @@ pub trait Panel: Component {
             address: Some(address),
             node: Some(node),
             screen_overlay,
+            node_patches: None,
         })
     }
 }
@@ -53,19 +56,104 @@ pub enum PanelAddress {
     Disclaimer,
     DebugPanel,
     GameMenu,
+    EmoteMenu,
+    RaidInfo,
     AdventureMenu,
+    AdventureJournal,
+    SeededRun,
+    Leaderboard(RunSeed),
+    Achievements,
     SetPlayerName(Side),
     DeckEditorPrompt,
     DeckEditorLoading,
     DeckEditor(DeckEditorData),
+    DeckCosmetics,
     OldDeckEditor(OldDeckEditorData),
     CreateDeck(CreateDeckState),
     GameOver(GameOverData),
+    OpponentIntro(NamedPlayer),
     TileLoading(TilePosition),
     TilePrompt(TilePosition),
     DraftCard,
     Shop(TilePosition),
     AdventureOver,
+    AdventureEvent,
+    CampaignUnlocks,
+    DraftLobby,
+    DraftPick,
+    Puzzles,
+    QuestLog,
+    PlayerCosmetics,
+    AgentLog,
+    DelegateTrace,
+    Confirm(Confirmation),
+    CustomGame(GamePreset),
+    /// Ranked leaderboard, starting from the entry at the given offset
+    Rankings(u32),
+    /// Card crafting, starting from the card at the given offset
+    Crafting(u32),
+}
+
+/// Data a panel's contents are derived from, used to determine when a rendered
+/// panel can be reused.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PanelDependency {
+    /// Panel contents depend only on the [PanelAddress].
+    None,
+    /// Panel contents depend on the requesting player's `PlayerData`.
+    Player,
+    /// Panel contents depend on the state of an ongoing game and cannot be
+    /// cached.
+    Game,
+    /// Panel contents depend on data shared between players, such as
+    /// leaderboards, and cannot be cached.
+    Server,
+}
+
+impl PanelAddress {
+    /// Returns the data this panel's contents are derived from.
+    pub fn dependency(&self) -> PanelDependency {
+        match self {
+            Self::MainMenu
+            | Self::About
+            | Self::Disclaimer
+            | Self::DebugPanel
+            | Self::GameMenu
+            | Self::EmoteMenu
+            | Self::AdventureMenu
+            | Self::SetPlayerName(_)
+            | Self::DeckEditorLoading
+            | Self::CreateDeck(_)
+            | Self::DraftLobby
+            | Self::SeededRun
+            | Self::OpponentIntro(_)
+            | Self::Confirm(_) => PanelDependency::None,
+            Self::Settings
+            | Self::Achievements
+            | Self::DeckEditorPrompt
+            | Self::DeckEditor(_)
+            | Self::DeckCosmetics
+            | Self::OldDeckEditor(_)
+            | Self::GameOver(_)
+            | Self::TileLoading(_)
+            | Self::TilePrompt(_)
+            | Self::DraftCard
+            | Self::Shop(_)
+            | Self::AdventureOver
+            | Self::AdventureEvent
+            | Self::CampaignUnlocks
+            | Self::AdventureJournal
+            | Self::DraftPick
+            | Self::Puzzles
+            | Self::QuestLog
+            | Self::PlayerCosmetics
+            | Self::Crafting(_) => PanelDependency::Player,
+            Self::RaidInfo | Self::AgentLog | Self::DelegateTrace => PanelDependency::Game,
+            Self::Leaderboard(_) | Self::CustomGame(_) | Self::Rankings(_) => {
+                PanelDependency::Server
+            }
+        }
+    }
 }
 
 impl From<PanelAddress> for InterfacePanelAddress {
@@ -103,6 +191,9 @@ pub struct OldDeckEditorData {
     pub show_edit_options: bool,
     /// Current collection browser view
     pub collection_filters: CollectionBrowserFilters,
+    /// Index of the first deck to show in the deck list
+    #[serde(default)]
+    pub deck_list_offset: usize,
 }
 
 /// Identifies which screen the user is on in the deck creation flow
@@ -118,3 +209,17 @@ pub struct GameOverData {
     pub game_id: GameId,
     pub winner: PlayerId,
 }
+
+/// Identifies a destructive action which requires the user to confirm it via a
+/// second click before it is performed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Confirmation {
+    /// Resign from the current game
+    Concede,
+    /// End the current adventure, forfeiting any progress
+    AbandonAdventure,
+    /// Permanently delete one of the player's decks
+    DeleteDeck(DeckIndex),
+    /// Replace the game state saved in a debug save slot
+    OverwriteSaveState(u64),
+}
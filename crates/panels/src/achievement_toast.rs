@@ -0,0 +1,60 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Notification displayed when a player earns an achievement
+
+use core_ui::design::{BackgroundColor, FontSize};
+use core_ui::prelude::*;
+use core_ui::style::Corner;
+use core_ui::text::Text;
+use data::achievements::AchievementName;
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::{FlexAlign, FlexPosition, ShowToastCommand, TimeValue};
+
+pub struct AchievementToast {
+    achievement: AchievementName,
+}
+
+impl AchievementToast {
+    pub fn new(achievement: AchievementName) -> Self {
+        Self { achievement }
+    }
+
+    /// Returns a command to display this toast to the user.
+    pub fn command(self) -> Command {
+        Command::ShowToast(ShowToastCommand {
+            content: self.build(),
+            duration: Some(TimeValue { milliseconds: 3000 }),
+        })
+    }
+}
+
+impl Component for AchievementToast {
+    fn build(self) -> Option<Node> {
+        Column::new("AchievementToast")
+            .style(
+                Style::new()
+                    .position_type(FlexPosition::Absolute)
+                    .position(Edge::Top, 120.px())
+                    .align_self(FlexAlign::Center)
+                    .align_items(FlexAlign::Center)
+                    .padding(Edge::All, 16.px())
+                    .background_color(BackgroundColor::Toast)
+                    .border_radius(Corner::All, 12.px()),
+            )
+            .child(Text::new("Achievement Earned").font_size(FontSize::Body))
+            .child(Text::new(self.achievement.displayed_name()).font_size(FontSize::Headline))
+            .build()
+    }
+}
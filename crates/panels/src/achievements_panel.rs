@@ -0,0 +1,80 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Lists all achievements along with whether the player has earned them
+
+use core_ui::design::{FontColor, FontSize};
+use core_ui::panel_window::PanelWindow;
+use core_ui::prelude::*;
+use core_ui::scroll_view::ScrollView;
+use core_ui::text::Text;
+use data::achievements::AchievementName;
+use data::player_data::PlayerData;
+use panel_address::{Panel, PanelAddress};
+use protos::spelldawn::{FlexAlign, FlexJustify};
+
+pub struct AchievementsPanel<'a> {
+    pub player: &'a PlayerData,
+}
+
+impl<'a> Panel for AchievementsPanel<'a> {
+    fn address(&self) -> PanelAddress {
+        PanelAddress::Achievements
+    }
+}
+
+impl<'a> Component for AchievementsPanel<'a> {
+    fn build(self) -> Option<Node> {
+        PanelWindow::new(self.address(), 800.px(), 600.px())
+            .title("Achievements")
+            .show_close_button(true)
+            .content(
+                ScrollView::new("AchievementsScroll")
+                    .style(Style::new().width(100.pct()).height(450.px()))
+                    .children(enum_iterator::all::<AchievementName>().map(|achievement| {
+                        achievement_row(
+                            achievement,
+                            self.player.achievements.contains(&achievement),
+                        )
+                    })),
+            )
+            .build()
+    }
+}
+
+fn achievement_row(achievement: AchievementName, earned: bool) -> impl Component {
+    Row::new(format!("Achievement{achievement:?}"))
+        .style(
+            Style::new()
+                .width(100.pct())
+                .margin(Edge::Vertical, 8.px())
+                .justify_content(FlexJustify::SpaceBetween)
+                .align_items(FlexAlign::Center),
+        )
+        .child(
+            Column::new("Description")
+                .style(Style::new().align_items(FlexAlign::FlexStart))
+                .child(Text::new(achievement.displayed_name()).font_size(FontSize::Headline))
+                .child(Text::new(achievement.description()).font_size(FontSize::Body))
+                .child(achievement.reward().map(|reward| {
+                    Text::new(format!("Reward: {}", reward.displayed_name()))
+                        .font_size(FontSize::Body)
+                })),
+        )
+        .child(
+            Text::new(if earned { "Earned" } else { "Locked" })
+                .font_size(FontSize::Headline)
+                .color(if earned { FontColor::CoinCount } else { FontColor::PrimaryText }),
+        )
+}
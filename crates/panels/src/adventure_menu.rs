@@ -12,11 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use core_ui::panels::{self, Panels};
 use core_ui::prelude::*;
-use core_ui::{actions, panels};
-use data::adventure_action::AdventureAction;
-use data::user_actions::UserAction;
-use panel_address::{Panel, PanelAddress};
+use panel_address::{Confirmation, Panel, PanelAddress};
 
 use crate::button_menu::ButtonMenu;
 
@@ -40,11 +38,11 @@ impl Component for AdventureMenu {
         ButtonMenu::new(self.address())
             .button(
                 "Abandon Adventure",
-                actions::close_and(
-                    self.address(),
-                    UserAction::AdventureAction(AdventureAction::AbandonAdventure),
-                ),
+                Panels::open(PanelAddress::Confirm(Confirmation::AbandonAdventure))
+                    .and_close(self.address()),
             )
+            .button("Journal", panels::open(PanelAddress::AdventureJournal))
+            .button("Achievements", panels::open(PanelAddress::Achievements))
             .button("Settings", panels::open(PanelAddress::Settings))
             .build()
     }
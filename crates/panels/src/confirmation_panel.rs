@@ -0,0 +1,71 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Asks the user to confirm a destructive action such as resigning a game or
+//! deleting a deck.
+
+use core_ui::confirmation_dialog::ConfirmationDialog;
+use core_ui::panels;
+use core_ui::prelude::*;
+use data::adventure_action::AdventureAction;
+use data::user_actions::{DebugAction, OldDeckEditorAction, UserAction};
+use panel_address::{Confirmation, OldDeckEditorData, Panel, PanelAddress};
+
+pub struct ConfirmationPanel {
+    confirmation: Confirmation,
+}
+
+impl ConfirmationPanel {
+    pub fn new(confirmation: Confirmation) -> Self {
+        Self { confirmation }
+    }
+}
+
+impl Panel for ConfirmationPanel {
+    fn address(&self) -> PanelAddress {
+        PanelAddress::Confirm(self.confirmation)
+    }
+}
+
+impl Component for ConfirmationPanel {
+    fn build(self) -> Option<Node> {
+        let dialog = ConfirmationDialog::new(self.address());
+        match self.confirmation {
+            Confirmation::Concede => dialog
+                .title("Resign")
+                .description("You will immediately lose the current game.")
+                .confirm_label("Resign")
+                .action(UserAction::Concede),
+            Confirmation::AbandonAdventure => dialog
+                .title("Abandon Adventure")
+                .description("Your adventure will end immediately and cannot be resumed.")
+                .confirm_label("Abandon")
+                .action(AdventureAction::AbandonAdventure),
+            Confirmation::DeleteDeck(index) => dialog
+                .title("Delete Deck")
+                .description("This deck will be permanently deleted. This cannot be undone.")
+                .confirm_label("Delete")
+                .action(OldDeckEditorAction::DeleteDeck(index))
+                .update(panels::set(PanelAddress::OldDeckEditor(OldDeckEditorData::default()))),
+            Confirmation::OverwriteSaveState(index) => dialog
+                .title("Overwrite Save")
+                .description(format!(
+                    "Slot {index} already contains a saved game, which will be replaced."
+                ))
+                .confirm_label("Overwrite")
+                .action(UserAction::Debug(DebugAction::OverwriteSaveState(index))),
+        }
+        .build()
+    }
+}
@@ -0,0 +1,208 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The custom game panel allows a player to choose rule options for a new game
+//! and then waits for their opponent to accept the same options.
+
+use core_ui::button::{Button, ButtonType};
+use core_ui::design::FontSize;
+use core_ui::panel_window::PanelWindow;
+use core_ui::prelude::*;
+use core_ui::text::Text;
+use core_ui::{actions, panels};
+use data::game::GameRules;
+use data::player_data::PlayerData;
+use data::player_name::{NamedPlayer, PlayerId};
+use data::primitives::DeckIndex;
+use data::ratings::PlayerRating;
+use data::user_actions::{GamePreset, NewGameAction, UserAction};
+use panel_address::{Panel, PanelAddress};
+use protos::spelldawn::{FlexAlign, FlexJustify};
+
+pub struct CustomGamePanel<'a> {
+    pub player: &'a PlayerData,
+    pub preset: GamePreset,
+    /// The player's current ranked rating
+    pub rating: PlayerRating,
+}
+
+impl<'a> Panel for CustomGamePanel<'a> {
+    fn address(&self) -> PanelAddress {
+        PanelAddress::CustomGame(self.preset)
+    }
+}
+
+impl<'a> Component for CustomGamePanel<'a> {
+    fn build(self) -> Option<Node> {
+        let waiting = matches!(
+            self.player.requested_game(),
+            Some(request) if request.preset == self.preset
+        );
+        PanelWindow::new(self.address(), 600.px(), 600.px())
+            .title("Custom Game")
+            .content(if waiting { waiting_content() } else { self.options_content() })
+            .build()
+    }
+}
+
+impl<'a> CustomGamePanel<'a> {
+    fn options_content(&self) -> Column {
+        let preset = self.preset;
+        let rules = preset.rules;
+        Column::new("CustomGameOptions")
+            .style(
+                Style::new()
+                    .width(100.pct())
+                    .align_items(FlexAlign::Stretch)
+                    .justify_content(FlexJustify::Center),
+            )
+            .child(
+                Text::new(format!("Your Rating: {}", self.rating.rating))
+                    .font_size(FontSize::Body)
+                    .layout(Layout::new().margin(Edge::All, 16.px())),
+            )
+            .child(
+                Button::new(if preset.ranked { "Ranked: On" } else { "Ranked: Off" })
+                    .button_type(ButtonType::Secondary)
+                    .action(panels::set(PanelAddress::CustomGame(GamePreset {
+                        ranked: !preset.ranked,
+                        rules: GameRules::default(),
+                        ..preset
+                    })))
+                    .layout(Layout::new().margin(Edge::All, 16.px())),
+            )
+            .child((!preset.ranked).then(|| {
+                value_row("Starting Mana", rules.starting_mana, 0, |value| GamePreset {
+                    rules: GameRules { starting_mana: value, ..rules },
+                    ..preset
+                })
+            }))
+            .child((!preset.ranked).then(|| {
+                value_row("Starting Hand Size", rules.starting_hand_size, 0, |value| GamePreset {
+                    rules: GameRules { starting_hand_size: value, ..rules },
+                    ..preset
+                })
+            }))
+            .child((!preset.ranked).then(|| {
+                value_row("Points to Win", rules.points_to_win, 1, |value| GamePreset {
+                    rules: GameRules { points_to_win: value, ..rules },
+                    ..preset
+                })
+            }))
+            .child(
+                Button::new(if preset.deterministic {
+                    "Deterministic: On"
+                } else {
+                    "Deterministic: Off"
+                })
+                .button_type(ButtonType::Secondary)
+                .action(panels::set(PanelAddress::CustomGame(GamePreset {
+                    deterministic: !preset.deterministic,
+                    ..preset
+                })))
+                .layout(Layout::new().margin(Edge::All, 16.px())),
+            )
+            .child(
+                Button::new(if preset.best_of_three {
+                    "Best of Three: On"
+                } else {
+                    "Best of Three: Off"
+                })
+                .button_type(ButtonType::Secondary)
+                .action(panels::set(PanelAddress::CustomGame(GamePreset {
+                    best_of_three: !preset.best_of_three,
+                    ..preset
+                })))
+                .layout(Layout::new().margin(Edge::All, 16.px())),
+            )
+            .child(
+                Button::new(if preset.reveal_on_game_over {
+                    "Reveal Cards at End: On"
+                } else {
+                    "Reveal Cards at End: Off"
+                })
+                .button_type(ButtonType::Secondary)
+                .action(panels::set(PanelAddress::CustomGame(GamePreset {
+                    reveal_on_game_over: !preset.reveal_on_game_over,
+                    ..preset
+                })))
+                .layout(Layout::new().margin(Edge::All, 16.px())),
+            )
+            .child(
+                Button::new("Accept")
+                    .button_type(ButtonType::Primary)
+                    .action(actions::close_and(
+                        self.address(),
+                        UserAction::NewGame(NewGameAction {
+                            opponent: PlayerId::Named(NamedPlayer::TestAlphaBetaHeuristics),
+                            deck_index: DeckIndex { value: 1 },
+                            debug_options: None,
+                            preset,
+                        }),
+                    ))
+                    .layout(Layout::new().margin(Edge::All, 16.px())),
+            )
+            .child(
+                Button::new("Back")
+                    .action(panels::set(PanelAddress::MainMenu))
+                    .layout(Layout::new().margin(Edge::All, 16.px())),
+            )
+    }
+}
+
+fn waiting_content() -> Column {
+    Column::new("CustomGameLobby")
+        .style(
+            Style::new()
+                .width(100.pct())
+                .align_items(FlexAlign::Center)
+                .justify_content(FlexJustify::Center),
+        )
+        .child(Text::new("Waiting for opponent to accept..."))
+}
+
+/// Row displaying a numeric rule value with buttons to change it. The value
+/// cannot be reduced below `minimum`.
+fn value_row(
+    label: &str,
+    value: u32,
+    minimum: u32,
+    preset: impl Fn(u32) -> GamePreset,
+) -> impl Component {
+    Row::new(format!("{label}Row"))
+        .style(
+            Style::new()
+                .margin(Edge::All, 16.px())
+                .justify_content(FlexJustify::SpaceBetween)
+                .align_items(FlexAlign::Center),
+        )
+        .child(Text::new(format!("{label}: {value}")).font_size(FontSize::Body))
+        .child(
+            Row::new("Buttons")
+                .child(
+                    Button::new("-")
+                        .button_type(ButtonType::Secondary)
+                        .action(panels::set(PanelAddress::CustomGame(preset(
+                            value.saturating_sub(1).max(minimum),
+                        ))))
+                        .layout(Layout::new().margin(Edge::Horizontal, 8.px())),
+                )
+                .child(
+                    Button::new("+")
+                        .button_type(ButtonType::Secondary)
+                        .action(panels::set(PanelAddress::CustomGame(preset(value + 1))))
+                        .layout(Layout::new().margin(Edge::Horizontal, 8.px())),
+                ),
+        )
+}
@@ -21,6 +21,7 @@ use core_ui::button::Button;
 use core_ui::panel_window::PanelWindow;
 use core_ui::prelude::*;
 use core_ui::{icons, panels};
+use data::player_name::NamedPlayer;
 use data::primitives::Side;
 use data::user_actions::DebugAction;
 use panel_address::{Panel, PanelAddress};
@@ -63,7 +64,7 @@ impl Component for DebugPanel {
                     .child(debug_button("Join Game", DebugAction::JoinGame))
                     .child(debug_button(
                         "Show Logs",
-                        vec![close, debug_command(DebugCommand::ShowLogs(()))],
+                        vec![close.clone(), debug_command(DebugCommand::ShowLogs(()))],
                     ))
                     .child(debug_button(format!("+10{}", icons::MANA), DebugAction::AddMana(10)))
                     .child(debug_button(
@@ -74,8 +75,8 @@ impl Component for DebugPanel {
                     .child(debug_button("Flip View", DebugAction::FlipViewpoint))
                     .child(debug_button(format!("{} 1", icons::SAVE), DebugAction::SaveState(1)))
                     .child(debug_button(format!("{} 1", icons::RESTORE), DebugAction::LoadState(1)))
-                    .child(debug_button(format!("{} 2", icons::SAVE), DebugAction::SaveState(1)))
-                    .child(debug_button(format!("{} 2", icons::RESTORE), DebugAction::LoadState(1)))
+                    .child(debug_button(format!("{} 2", icons::SAVE), DebugAction::SaveState(2)))
+                    .child(debug_button(format!("{} 2", icons::RESTORE), DebugAction::LoadState(2)))
                     .child(debug_button(format!("{} 3", icons::SAVE), DebugAction::SaveState(3)))
                     .child(debug_button(format!("{} 3", icons::RESTORE), DebugAction::LoadState(3)))
                     .child(debug_button(
@@ -86,7 +87,25 @@ impl Component for DebugPanel {
                         "Champion AI",
                         panels::open(PanelAddress::SetPlayerName(Side::Champion)),
                     ))
-                    .child(debug_button("Full Collection", DebugAction::FullCollection)),
+                    .child(debug_button("Full Collection", DebugAction::FullCollection))
+                    .child(debug_button("Explain AI", DebugAction::ToggleAgentExplanations))
+                    .child(debug_button("Panic", DebugAction::Panic))
+                    .child(debug_button(
+                        "Exhibition",
+                        DebugAction::StartExhibition(
+                            NamedPlayer::TestAlphaBetaHeuristics,
+                            NamedPlayer::TestAlphaBetaHeuristics,
+                        ),
+                    ))
+                    .child(debug_button(
+                        "AI Log",
+                        vec![close.clone(), panels::open(PanelAddress::AgentLog)],
+                    ))
+                    .child(debug_button("Trace Effects", DebugAction::ToggleDelegateTrace))
+                    .child(debug_button(
+                        "Effect Log",
+                        vec![close, panels::open(PanelAddress::DelegateTrace)],
+                    )),
             )
             .build()
     }
@@ -0,0 +1,54 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Panel shown while waiting for an opponent to join a draft
+
+use core_ui::panel_window::PanelWindow;
+use core_ui::prelude::*;
+use core_ui::text::Text;
+use panel_address::{Panel, PanelAddress};
+use protos::spelldawn::{FlexAlign, FlexJustify};
+
+#[derive(Debug, Default)]
+pub struct DraftLobbyPanel {}
+
+impl DraftLobbyPanel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Panel for DraftLobbyPanel {
+    fn address(&self) -> PanelAddress {
+        PanelAddress::DraftLobby
+    }
+}
+
+impl Component for DraftLobbyPanel {
+    fn build(self) -> Option<Node> {
+        PanelWindow::new(self.address(), 512.px(), 350.px())
+            .title("Draft")
+            .content(
+                Column::new("DraftLobby")
+                    .style(
+                        Style::new()
+                            .width(100.pct())
+                            .align_items(FlexAlign::Center)
+                            .justify_content(FlexJustify::Center),
+                    )
+                    .child(Text::new("Waiting for opponent...")),
+            )
+            .build()
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Panel for picking a card during a standalone draft
+
+use core_ui::button::Button;
+use core_ui::design::FontSize;
+use core_ui::full_screen_image::FullScreenImage;
+use core_ui::prelude::*;
+use core_ui::style;
+use core_ui::text::Text;
+use data::draft::{DraftAction, DraftState, DRAFT_PICKS};
+use data::primitives::Side;
+use data::text::PreviewState;
+use deck_card::{CardHeight, DeckCard};
+use panel_address::{Panel, PanelAddress};
+use protos::spelldawn::{FlexAlign, FlexJustify};
+
+pub struct DraftPickPanel<'a> {
+    pub draft: &'a DraftState,
+    /// Side of the player viewing this panel
+    pub side: Side,
+}
+
+impl<'a> Panel for DraftPickPanel<'a> {
+    fn address(&self) -> PanelAddress {
+        PanelAddress::DraftPick
+    }
+}
+
+impl<'a> Component for DraftPickPanel<'a> {
+    fn build(self) -> Option<Node> {
+        let player = self.draft.player(self.side);
+        let can_pick = self.draft.active_side == self.side;
+        let status = if can_pick {
+            format!("Pick {} of {}", player.picks.len() + 1, DRAFT_PICKS)
+        } else {
+            "Waiting for opponent to pick...".to_string()
+        };
+
+        FullScreenImage::new()
+            .image(style::sprite(
+                "TPR/EnvironmentsHQ/Dungeons, Shrines & Altars/Images/MountainTomb/ScenerySnowMountain_1",
+            ))
+            .content(
+                Column::new("DraftPickPanel")
+                    .style(Style::new().align_items(FlexAlign::Center))
+                    .child(Text::new(status).font_size(FontSize::Headline))
+                    .child(
+                        Row::new("Pack")
+                            .style(Style::new().justify_content(FlexJustify::Center))
+                            .children(player.pack.iter().enumerate().map(|(i, card)| {
+                                Column::new("Choice")
+                                    .style(Style::new().margin(Edge::All, 32.px()))
+                                    .child(
+                                        DeckCard::new(*card)
+                                            .preview(PreviewState::example())
+                                            .layout(Layout::new().margin(Edge::All, 8.px()))
+                                            .height(CardHeight::vh(50.0)),
+                                    )
+                                    .child(can_pick.then(|| {
+                                        Button::new("Pick")
+                                            .layout(
+                                                Layout::new()
+                                                    .margin(Edge::Horizontal, 8.px())
+                                                    .margin(Edge::Top, 16.px()),
+                                            )
+                                            .action(DraftAction::PickCard(i))
+                                    }))
+                            })),
+                    ),
+            )
+            .build()
+    }
+}
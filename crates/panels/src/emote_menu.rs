@@ -0,0 +1,51 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Menu for sending emotes to the opponent during a game
+
+use core_ui::actions;
+use core_ui::prelude::*;
+use data::emotes::Emote;
+use data::user_actions::UserAction;
+use panel_address::{Panel, PanelAddress};
+
+use crate::button_menu::ButtonMenu;
+
+#[derive(Default)]
+pub struct EmoteMenu {}
+
+impl EmoteMenu {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Panel for EmoteMenu {
+    fn address(&self) -> PanelAddress {
+        PanelAddress::EmoteMenu
+    }
+}
+
+impl Component for EmoteMenu {
+    fn build(self) -> Option<Node> {
+        enum_iterator::all::<Emote>()
+            .fold(ButtonMenu::new(self.address()).title("Emotes"), |menu, emote| {
+                menu.button(
+                    emote.text(),
+                    actions::close_and(self.address(), UserAction::SendEmote(emote)),
+                )
+            })
+            .build()
+    }
+}
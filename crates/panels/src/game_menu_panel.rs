@@ -18,11 +18,11 @@
 use core_ui::actions::InterfaceAction;
 use core_ui::button::{Button, ButtonType};
 use core_ui::panel_window::PanelWindow;
+use core_ui::panels;
+use core_ui::panels::Panels;
 use core_ui::prelude::*;
 use core_ui::style::WidthMode;
-use core_ui::{actions, panels};
-use data::game_actions::GameAction;
-use panel_address::{OldDeckEditorData, Panel, PanelAddress};
+use panel_address::{Confirmation, OldDeckEditorData, Panel, PanelAddress};
 use protos::spelldawn::{FlexAlign, FlexJustify};
 
 #[derive(Debug, Default)]
@@ -54,7 +54,15 @@ impl Component for GameMenuPanel {
                             .justify_content(FlexJustify::Center),
                     )
                     .child(menu_button("Close", panels::close(address)))
-                    .child(menu_button("Resign", actions::close_and(address, GameAction::Resign)))
+                    .child(menu_button(
+                        "Emotes",
+                        Panels::open(PanelAddress::EmoteMenu).and_close(address),
+                    ))
+                    .child(menu_button(
+                        "Resign",
+                        Panels::open(PanelAddress::Confirm(Confirmation::Concede))
+                            .and_close(address),
+                    ))
                     .child(menu_button(
                         "Deck Editor",
                         panels::set(PanelAddress::OldDeckEditor(OldDeckEditorData::default())),
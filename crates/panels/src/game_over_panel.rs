@@ -16,10 +16,12 @@
 
 use core_ui::actions;
 use core_ui::button::{Button, ButtonType};
+use core_ui::design::FontSize;
 use core_ui::panel_window::PanelWindow;
 use core_ui::prelude::*;
 use core_ui::style::WidthMode;
-use data::player_data::PlayerData;
+use core_ui::text::Text;
+use data::player_data::{MatchRecord, PlayerData};
 use data::user_actions::UserAction;
 use panel_address::{GameOverData, Panel, PanelAddress};
 use protos::spelldawn::{FlexAlign, FlexJustify};
@@ -38,7 +40,17 @@ impl<'a> Panel for GameOverPanel<'a> {
 
 impl<'a> Component for GameOverPanel<'a> {
     fn build(self) -> Option<Node> {
-        PanelWindow::new(self.address(), 512.px(), 350.px())
+        let record = self.player.match_history.iter().find(|r| r.game_id == self.data.game_id);
+        let title = if self.data.winner == self.player.id {
+            "Victory"
+        } else if record.is_some() {
+            "Defeat"
+        } else {
+            // Spectators have no record of games they did not play in
+            "Game Over"
+        };
+        PanelWindow::new(self.address(), 512.px(), 600.px())
+            .title(title)
             .content(
                 Column::new("Buttons")
                     .style(
@@ -47,14 +59,60 @@ impl<'a> Component for GameOverPanel<'a> {
                             .align_items(FlexAlign::Stretch)
                             .justify_content(FlexJustify::Center),
                     )
+                    .child(record.map(MatchSummaryView::new))
                     .child(
-                        Button::new("Main Menu")
-                            .action(actions::close_and(self.address(), UserAction::LeaveGame))
-                            .button_type(ButtonType::Primary)
-                            .width_mode(WidthMode::Flexible)
-                            .layout(Layout::new().margin(Edge::All, 16.px())),
+                        Button::new(if self.player.current_match.is_some() {
+                            "Next Game"
+                        } else {
+                            "Main Menu"
+                        })
+                        .action(actions::close_and(self.address(), UserAction::LeaveGame))
+                        .button_type(ButtonType::Primary)
+                        .width_mode(WidthMode::Flexible)
+                        .layout(Layout::new().margin(Edge::All, 16.px())),
                     ),
             )
             .build()
     }
 }
+
+/// Displays statistics about a completed game
+struct MatchSummaryView<'a> {
+    record: &'a MatchRecord,
+}
+
+impl<'a> MatchSummaryView<'a> {
+    fn new(record: &'a MatchRecord) -> Self {
+        Self { record }
+    }
+}
+
+impl<'a> Component for MatchSummaryView<'a> {
+    fn build(self) -> Option<Node> {
+        let summary = self.record.summary;
+        let mut rows = vec![
+            ("Turns", self.record.turn_number),
+            ("Raids", summary.raids),
+            ("Schemes Scored", summary.schemes_scored),
+            ("Damage Dealt", summary.damage_dealt),
+            ("Mana Spent", summary.mana_spent),
+        ];
+        if summary.coins_earned.0 > 0 {
+            rows.push(("Coins Earned", summary.coins_earned.0));
+        }
+
+        Column::new("MatchSummary")
+            .style(Style::new().margin(Edge::Horizontal, 32.px()))
+            .children(rows.into_iter().map(|(label, value)| {
+                Row::new(format!("{label}Row"))
+                    .style(
+                        Style::new()
+                            .justify_content(FlexJustify::SpaceBetween)
+                            .margin(Edge::Vertical, 4.px()),
+                    )
+                    .child(Text::new(label).font_size(FontSize::Body))
+                    .child(Text::new(value.to_string()).font_size(FontSize::Body))
+            }))
+            .build()
+    }
+}
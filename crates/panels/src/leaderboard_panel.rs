@@ -0,0 +1,80 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Ranks the results of seeded adventure runs which share a seed
+
+use core_ui::design::FontSize;
+use core_ui::panel_window::PanelWindow;
+use core_ui::prelude::*;
+use core_ui::scroll_view::ScrollView;
+use core_ui::text::Text;
+use data::leaderboard::{LeaderboardEntry, RunSeed};
+use panel_address::{Panel, PanelAddress};
+use protos::spelldawn::{FlexAlign, FlexJustify};
+
+pub struct LeaderboardPanel<'a> {
+    pub run: RunSeed,
+    pub seed: u64,
+    /// Entries to display, in ranked order
+    pub entries: &'a [LeaderboardEntry],
+}
+
+impl<'a> Panel for LeaderboardPanel<'a> {
+    fn address(&self) -> PanelAddress {
+        PanelAddress::Leaderboard(self.run)
+    }
+}
+
+impl<'a> Component for LeaderboardPanel<'a> {
+    fn build(self) -> Option<Node> {
+        PanelWindow::new(self.address(), 800.px(), 600.px())
+            .title(match self.run {
+                RunSeed::Daily => "Daily Leaderboard",
+                RunSeed::Custom(_) => "Leaderboard",
+            })
+            .show_close_button(true)
+            .content(
+                Column::new("Leaderboard")
+                    .style(Style::new().width(100.pct()))
+                    .child(Text::new(format!("Seed: {}", self.seed)).font_size(FontSize::Body))
+                    .child(if self.entries.is_empty() {
+                        Some(Text::new("No runs completed yet").font_size(FontSize::Body))
+                    } else {
+                        None
+                    })
+                    .child(
+                        ScrollView::new("LeaderboardScroll")
+                            .style(Style::new().width(100.pct()).height(400.px()))
+                            .children(self.entries.iter().enumerate().map(leaderboard_row)),
+                    ),
+            )
+            .build()
+    }
+}
+
+fn leaderboard_row((index, entry): (usize, &LeaderboardEntry)) -> impl Component {
+    Row::new(format!("Rank{}", index + 1))
+        .style(
+            Style::new()
+                .width(100.pct())
+                .margin(Edge::Vertical, 8.px())
+                .justify_content(FlexJustify::SpaceBetween)
+                .align_items(FlexAlign::Center),
+        )
+        .child(Text::new(format!("#{} {:?}", index + 1, entry.side)).font_size(FontSize::Headline))
+        .child(
+            Text::new(format!("{} Coins, {} Battles Won", entry.coins.0, entry.battles_won))
+                .font_size(FontSize::Body),
+        )
+}
@@ -16,13 +16,28 @@
 //! be opened or closed by the user, such as a game menu or window.
 
 pub mod about_panel;
+pub mod achievement_toast;
+pub mod achievements_panel;
 pub mod adventure_menu;
 pub mod button_menu;
+pub mod confirmation_panel;
+pub mod custom_game_panel;
 pub mod debug_panel;
 pub mod disclaimer_panel;
+pub mod draft_lobby_panel;
+pub mod draft_pick_panel;
+pub mod emote_menu;
 pub mod game_menu_panel;
 pub mod game_over_panel;
+pub mod leaderboard_panel;
 pub mod loading_panel;
 pub mod main_menu_panel;
+pub mod opponent_intro_panel;
+pub mod player_cosmetics_panel;
+pub mod puzzles_panel;
+pub mod quest_log_panel;
+pub mod rankings_panel;
+pub mod seeded_run_panel;
 pub mod set_player_name_panel;
 pub mod settings_panel;
+pub mod unlock_panel;
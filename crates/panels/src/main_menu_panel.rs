@@ -21,9 +21,10 @@ use core_ui::panel_window::PanelWindow;
 use core_ui::prelude::*;
 use core_ui::style::WidthMode;
 use core_ui::{actions, panels};
+use data::draft::NewDraftAction;
 use data::player_name::{NamedPlayer, PlayerId};
 use data::primitives::{DeckIndex, Side};
-use data::user_actions::{NewGameAction, UserAction};
+use data::user_actions::{GamePreset, NewGameAction, UserAction};
 use panel_address::{Panel, PanelAddress};
 use protos::spelldawn::{FlexAlign, FlexJustify};
 
@@ -62,9 +63,26 @@ impl Component for MainMenuPanel {
                                 opponent: PlayerId::Named(NamedPlayer::TestAlphaBetaHeuristics),
                                 deck_index: DeckIndex { value: 1 },
                                 debug_options: None,
+                                preset: GamePreset::default(),
                             }),
                         ),
                     ))
+                    .child(menu_button(
+                        "Draft",
+                        actions::close_and(
+                            PanelAddress::MainMenu,
+                            UserAction::NewDraft(NewDraftAction {
+                                side: Side::Champion,
+                                opponent: PlayerId::Named(NamedPlayer::TestAlphaBetaHeuristics),
+                            }),
+                        ),
+                    ))
+                    .child(menu_button(
+                        "Custom Game",
+                        panels::open(PanelAddress::CustomGame(GamePreset::default())),
+                    ))
+                    .child(menu_button("Puzzles", panels::open(PanelAddress::Puzzles)))
+                    .child(menu_button("Rankings", panels::open(PanelAddress::Rankings(0))))
                     .child(menu_button(
                         "New Adventure",
                         actions::close_and(
@@ -72,7 +90,11 @@ impl Component for MainMenuPanel {
                             UserAction::NewAdventure(Side::Champion),
                         ),
                     ))
+                    .child(menu_button("Seeded Run", panels::open(PanelAddress::SeededRun)))
                     .child(menu_button("Settings", panels::open(PanelAddress::Settings)))
+                    .child(menu_button("Quests", panels::open(PanelAddress::QuestLog)))
+                    .child(menu_button("Cosmetics", panels::open(PanelAddress::PlayerCosmetics)))
+                    .child(menu_button("Achievements", panels::open(PanelAddress::Achievements)))
                     .child(menu_button("About", panels::open(PanelAddress::About))),
             )
             .build()
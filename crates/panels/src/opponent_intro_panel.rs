@@ -0,0 +1,73 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Introduces a named AI opponent at the start of a game against them
+
+use core_ui::button::Button;
+use core_ui::design::{BackgroundColor, FontSize};
+use core_ui::panels::Panels;
+use core_ui::prelude::*;
+use core_ui::style;
+use core_ui::text::Text;
+use data::player_name::NamedPlayer;
+use panel_address::{Panel, PanelAddress};
+use protos::spelldawn::{FlexAlign, FlexJustify, FlexPosition, ImageScaleMode};
+
+pub const INTRO_IMAGE: &str =
+    "TPR/EnvironmentsHQ/Dungeons, Shrines & Altars/Images/MountainTomb/ScenerySnowMountain_1";
+
+pub struct OpponentIntroPanel {
+    pub opponent: NamedPlayer,
+}
+
+impl Panel for OpponentIntroPanel {
+    fn address(&self) -> PanelAddress {
+        PanelAddress::OpponentIntro(self.opponent)
+    }
+}
+
+impl Component for OpponentIntroPanel {
+    fn build(self) -> Option<Node> {
+        Column::new("OpponentIntro")
+            .style(
+                Style::new()
+                    .position_type(FlexPosition::Absolute)
+                    .position(Edge::All, 0.px())
+                    .background_image(style::sprite(INTRO_IMAGE))
+                    .background_image_scale_mode(ImageScaleMode::ScaleAndCrop)
+                    .align_items(FlexAlign::Center)
+                    .justify_content(FlexJustify::Center),
+            )
+            .child(
+                Column::new("Intro")
+                    .style(
+                        Style::new()
+                            .max_width(800.px())
+                            .padding(Edge::All, 32.px())
+                            .background_color(BackgroundColor::TilePanelOverlay)
+                            .align_items(FlexAlign::Center),
+                    )
+                    .child(
+                        Text::new(self.opponent.displayed_name()).font_size(FontSize::PanelTitle),
+                    )
+                    .child(
+                        Text::new(self.opponent.intro().unwrap_or_default())
+                            .font_size(FontSize::Body)
+                            .layout(Layout::new().margin(Edge::Vertical, 16.px())),
+                    )
+                    .child(Button::new("Begin").action(Panels::close(self.address()))),
+            )
+            .build()
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Panel for selecting the card back and board skin displayed during games
+
+use core_ui::button::{Button, ButtonType};
+use core_ui::design::FontSize;
+use core_ui::panel_window::PanelWindow;
+use core_ui::prelude::*;
+use core_ui::scroll_view::ScrollView;
+use core_ui::text::Text;
+use data::cosmetics::{BoardSkin, CardBack};
+use data::player_data::PlayerData;
+use data::user_actions::UserAction;
+use panel_address::{Panel, PanelAddress};
+use protos::spelldawn::{FlexAlign, FlexJustify};
+
+pub struct PlayerCosmeticsPanel<'a> {
+    pub player: &'a PlayerData,
+}
+
+impl<'a> Panel for PlayerCosmeticsPanel<'a> {
+    fn address(&self) -> PanelAddress {
+        PanelAddress::PlayerCosmetics
+    }
+}
+
+impl<'a> Component for PlayerCosmeticsPanel<'a> {
+    fn build(self) -> Option<Node> {
+        let cosmetics = &self.player.player_cosmetics;
+        let card_backs = enum_iterator::all::<CardBack>()
+            .filter(|card_back| cosmetics.owns_card_back(*card_back))
+            .map(|card_back| {
+                let equipped = cosmetics.equipped.card_back == Some(card_back);
+                row(
+                    format!("CardBack{card_back:?}"),
+                    card_back.displayed_name(),
+                    equipped,
+                    UserAction::SetCardBack(if equipped { None } else { Some(card_back) }),
+                )
+            });
+        let board_skins = enum_iterator::all::<BoardSkin>()
+            .filter(|board_skin| cosmetics.owns_board_skin(*board_skin))
+            .map(|board_skin| {
+                let equipped = cosmetics.equipped.board_skin == Some(board_skin);
+                row(
+                    format!("BoardSkin{board_skin:?}"),
+                    board_skin.displayed_name(),
+                    equipped,
+                    UserAction::SetBoardSkin(if equipped { None } else { Some(board_skin) }),
+                )
+            });
+
+        PanelWindow::new(self.address(), 800.px(), 600.px())
+            .title("Cosmetics")
+            .show_close_button(true)
+            .content(
+                ScrollView::new("PlayerCosmeticsScroll")
+                    .style(Style::new().width(100.pct()).height(450.px()))
+                    .child(Text::new("Card Backs").font_size(FontSize::Headline))
+                    .children(card_backs)
+                    .child(Text::new("Board Skins").font_size(FontSize::Headline))
+                    .children(board_skins),
+            )
+            .build()
+    }
+}
+
+fn row(name: String, label: &'static str, equipped: bool, action: UserAction) -> impl Component {
+    Row::new(name)
+        .style(
+            Style::new()
+                .width(100.pct())
+                .margin(Edge::Vertical, 8.px())
+                .justify_content(FlexJustify::SpaceBetween)
+                .align_items(FlexAlign::Center),
+        )
+        .child(Text::new(label).font_size(FontSize::Body))
+        .child(
+            Button::new(if equipped { "Unequip" } else { "Equip" })
+                .button_type(if equipped { ButtonType::Secondary } else { ButtonType::Primary })
+                .action(action)
+                .layout(Layout::new().margin(Edge::Left, 16.px())),
+        )
+}
@@ -0,0 +1,89 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Lists all puzzles along with whether the player has solved them
+
+use core_ui::actions;
+use core_ui::button::Button;
+use core_ui::design::{FontColor, FontSize};
+use core_ui::panel_window::PanelWindow;
+use core_ui::prelude::*;
+use core_ui::scroll_view::ScrollView;
+use core_ui::text::Text;
+use data::player_data::PlayerData;
+use data::puzzles::PuzzleId;
+use data::user_actions::UserAction;
+use panel_address::{Panel, PanelAddress};
+use protos::spelldawn::{FlexAlign, FlexJustify};
+
+pub struct PuzzlesPanel<'a> {
+    pub player: &'a PlayerData,
+}
+
+impl<'a> Panel for PuzzlesPanel<'a> {
+    fn address(&self) -> PanelAddress {
+        PanelAddress::Puzzles
+    }
+}
+
+impl<'a> Component for PuzzlesPanel<'a> {
+    fn build(self) -> Option<Node> {
+        PanelWindow::new(self.address(), 800.px(), 600.px())
+            .title("Puzzles")
+            .show_close_button(true)
+            .content(
+                ScrollView::new("PuzzlesScroll")
+                    .style(Style::new().width(100.pct()).height(450.px()))
+                    .children(enum_iterator::all::<PuzzleId>().map(|puzzle| {
+                        puzzle_row(puzzle, self.player.completed_puzzles.contains(&puzzle))
+                    })),
+            )
+            .build()
+    }
+}
+
+fn puzzle_row(id: PuzzleId, solved: bool) -> impl Component {
+    let puzzle = puzzles::get(id);
+    Row::new(format!("Puzzle{id:?}"))
+        .style(
+            Style::new()
+                .width(100.pct())
+                .margin(Edge::Vertical, 8.px())
+                .justify_content(FlexJustify::SpaceBetween)
+                .align_items(FlexAlign::Center),
+        )
+        .child(
+            Column::new("Description")
+                .style(Style::new().align_items(FlexAlign::FlexStart))
+                .child(Text::new(id.displayed_name()).font_size(FontSize::Headline))
+                .child(Text::new(id.description()).font_size(FontSize::Body))
+                .child(
+                    Text::new(format!("{:?}: {}", puzzle.side, puzzle.goal.description()))
+                        .font_size(FontSize::Body),
+                )
+                .children(puzzle.constraints.iter().map(|constraint| {
+                    Text::new(constraint.description()).font_size(FontSize::Body)
+                })),
+        )
+        .child(
+            solved.then(|| {
+                Text::new("Solved").font_size(FontSize::Headline).color(FontColor::CoinCount)
+            }),
+        )
+        .child(
+            Button::new("Play")
+                .action(actions::close_and(PanelAddress::Puzzles, UserAction::StartPuzzle(id)))
+                .layout(Layout::new().margin(Edge::All, 8.px())),
+        )
+}
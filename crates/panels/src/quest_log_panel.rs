@@ -0,0 +1,86 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Lists the player's daily quests along with their progress
+
+use core_ui::design::{FontColor, FontSize};
+use core_ui::panel_window::PanelWindow;
+use core_ui::prelude::*;
+use core_ui::text::Text;
+use data::player_data::PlayerData;
+use data::quests::Quest;
+use panel_address::{Panel, PanelAddress};
+use protos::spelldawn::{FlexAlign, FlexJustify};
+
+pub struct QuestLogPanel<'a> {
+    pub player: &'a PlayerData,
+}
+
+impl<'a> Panel for QuestLogPanel<'a> {
+    fn address(&self) -> PanelAddress {
+        PanelAddress::QuestLog
+    }
+}
+
+impl<'a> Component for QuestLogPanel<'a> {
+    fn build(self) -> Option<Node> {
+        PanelWindow::new(self.address(), 800.px(), 600.px())
+            .title("Daily Quests")
+            .show_close_button(true)
+            .content(
+                Column::new("Quests")
+                    .style(Style::new().width(100.pct()))
+                    .children(self.player.quests.quests.iter().enumerate().map(quest_row))
+                    .child(
+                        Text::new(format!("Banked Coins: {}", self.player.coins.0))
+                            .font_size(FontSize::Body)
+                            .color(FontColor::CoinCount),
+                    ),
+            )
+            .build()
+    }
+}
+
+fn quest_row((index, quest): (usize, &Quest)) -> impl Component {
+    Row::new(format!("Quest{index}"))
+        .style(
+            Style::new()
+                .width(100.pct())
+                .margin(Edge::Vertical, 8.px())
+                .justify_content(FlexJustify::SpaceBetween)
+                .align_items(FlexAlign::Center),
+        )
+        .child(
+            Column::new("Description")
+                .style(Style::new().align_items(FlexAlign::FlexStart))
+                .child(Text::new(quest.description()).font_size(FontSize::Headline))
+                .child(
+                    Text::new(format!("Reward: {} Coins", quest.reward.0))
+                        .font_size(FontSize::Body),
+                ),
+        )
+        .child(
+            Text::new(if quest.is_complete() {
+                "Complete".to_string()
+            } else {
+                format!("{}/{}", quest.progress, quest.target)
+            })
+            .font_size(FontSize::Headline)
+            .color(if quest.is_complete() {
+                FontColor::CoinCount
+            } else {
+                FontColor::PrimaryText
+            }),
+        )
+}
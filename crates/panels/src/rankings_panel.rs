@@ -0,0 +1,108 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Displays players ordered by their ranked rating, one page at a time
+
+use core_ui::button::{Button, ButtonType};
+use core_ui::design::FontSize;
+use core_ui::panel_window::PanelWindow;
+use core_ui::panels;
+use core_ui::prelude::*;
+use core_ui::text::Text;
+use data::ratings::{PlayerRating, LEADERBOARD_PAGE_SIZE};
+use panel_address::{Panel, PanelAddress};
+use protos::spelldawn::{FlexAlign, FlexJustify};
+
+pub struct RankingsPanel<'a> {
+    /// Index of the first entry on this page
+    pub offset: u32,
+    /// Entries on this page paired with their rank, in ranked order
+    pub entries: &'a [(usize, PlayerRating)],
+    /// Total number of rated players
+    pub total: usize,
+}
+
+impl<'a> Panel for RankingsPanel<'a> {
+    fn address(&self) -> PanelAddress {
+        PanelAddress::Rankings(self.offset)
+    }
+}
+
+impl<'a> Component for RankingsPanel<'a> {
+    fn build(self) -> Option<Node> {
+        let page_size = LEADERBOARD_PAGE_SIZE as u32;
+        let has_next = (self.offset + page_size) < self.total as u32;
+        PanelWindow::new(self.address(), 800.px(), 600.px())
+            .title("Rankings")
+            .show_close_button(true)
+            .content(
+                Column::new("Rankings")
+                    .style(Style::new().width(100.pct()))
+                    .child(if self.entries.is_empty() {
+                        Some(Text::new("No ranked games played yet").font_size(FontSize::Body))
+                    } else {
+                        None
+                    })
+                    .children(self.entries.iter().map(ranking_row))
+                    .child(
+                        Row::new("RankingsPages")
+                            .style(
+                                Style::new()
+                                    .width(100.pct())
+                                    .justify_content(FlexJustify::SpaceBetween),
+                            )
+                            .child((self.offset > 0).then(|| {
+                                page_button(
+                                    "Previous",
+                                    self.offset.saturating_sub(page_size),
+                                    self.address(),
+                                )
+                            }))
+                            .child(has_next.then(|| {
+                                page_button("Next", self.offset + page_size, self.address())
+                            })),
+                    ),
+            )
+            .build()
+    }
+}
+
+fn ranking_row((rank, rating): &(usize, PlayerRating)) -> impl Component {
+    Row::new(format!("Rank{rank}"))
+        .style(
+            Style::new()
+                .width(100.pct())
+                .margin(Edge::Vertical, 8.px())
+                .justify_content(FlexJustify::SpaceBetween)
+                .align_items(FlexAlign::Center),
+        )
+        .child(
+            Text::new(format!("#{} {}", rank, rating.player_id.displayed_name()))
+                .font_size(FontSize::Headline),
+        )
+        .child(
+            Text::new(format!(
+                "Rating {}, {} Wins in {} Games",
+                rating.rating, rating.wins, rating.games_played
+            ))
+            .font_size(FontSize::Body),
+        )
+}
+
+fn page_button(label: &str, offset: u32, current: PanelAddress) -> Button {
+    Button::new(label)
+        .button_type(ButtonType::Secondary)
+        .action(vec![panels::close(current), panels::open(PanelAddress::Rankings(offset))])
+        .layout(Layout::new().margin(Edge::All, 16.px()))
+}
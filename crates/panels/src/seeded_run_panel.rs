@@ -0,0 +1,94 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Launches adventures using a shared random seed, so that several players can
+//! attempt the same run
+
+use core_ui::action_builder::ActionBuilder;
+use core_ui::button::Button;
+use core_ui::design::FontSize;
+use core_ui::panel_window::PanelWindow;
+use core_ui::prelude::*;
+use core_ui::text::Text;
+use core_ui::text_field::TextField;
+use core_ui::{actions, panels};
+use data::leaderboard::RunSeed;
+use data::primitives::Side;
+use data::user_actions::UserAction;
+use panel_address::{Panel, PanelAddress};
+use protos::spelldawn::FlexAlign;
+
+/// Identifier for the text field containing the seed for a custom run
+pub const SEED_INPUT: &str = "SeedInput";
+
+#[derive(Default)]
+pub struct SeededRunPanel {}
+
+impl SeededRunPanel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Panel for SeededRunPanel {
+    fn address(&self) -> PanelAddress {
+        PanelAddress::SeededRun
+    }
+}
+
+impl Component for SeededRunPanel {
+    fn build(self) -> Option<Node> {
+        PanelWindow::new(self.address(), 800.px(), 600.px())
+            .title("Seeded Run")
+            .show_close_button(true)
+            .content(
+                Column::new("SeededRun")
+                    .style(Style::new().width(100.pct()).align_items(FlexAlign::Center))
+                    .child(
+                        Text::new("Every player gets the same map for the daily run")
+                            .font_size(FontSize::Body),
+                    )
+                    .child(
+                        Button::new("Daily Run")
+                            .layout(Layout::new().margin(Edge::All, 8.px()))
+                            .action(actions::close_and(
+                                self.address(),
+                                UserAction::NewDailyAdventure(Side::Champion),
+                            )),
+                    )
+                    .child(
+                        Button::new("Daily Leaderboard")
+                            .layout(Layout::new().margin(Edge::All, 8.px()))
+                            .action(panels::open(PanelAddress::Leaderboard(RunSeed::Daily))),
+                    )
+                    .child(
+                        Row::new("SeedInput")
+                            .style(Style::new().margin(Edge::Top, 32.px()))
+                            .child(Text::new("Seed:").font_size(FontSize::Body))
+                            .child(TextField::new(SEED_INPUT).max_characters(20)),
+                    )
+                    .child(
+                        Button::new("Start Seeded Run")
+                            .layout(Layout::new().margin(Edge::All, 8.px()))
+                            .action(
+                                ActionBuilder::new()
+                                    .action(UserAction::NewSeededAdventure(Side::Champion))
+                                    .update(panels::close(self.address()))
+                                    .request_field(SEED_INPUT),
+                            ),
+                    ),
+            )
+            .build()
+    }
+}
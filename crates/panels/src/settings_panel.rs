@@ -14,32 +14,41 @@
 
 //! The settings panel allows configuration of game options
 
-use core_ui::button::Button;
+use core_ui::button::{Button, ButtonType};
+use core_ui::design::FontSize;
 use core_ui::panel_window::PanelWindow;
 use core_ui::panels;
 use core_ui::prelude::*;
 use core_ui::scroll_view::ScrollView;
-use core_ui::slider::Slider;
+use core_ui::text::Text;
+use data::player_data::{AnimationSpeed, AutomationPreferences, IconMode, PlayerData};
+use data::user_actions::UserAction;
 use panel_address::{Panel, PanelAddress};
 use protos::spelldawn::{FlexAlign, FlexJustify};
 
-#[derive(Debug, Default)]
-pub struct SettingsPanel {}
+#[derive(Debug)]
+pub struct SettingsPanel<'a> {
+    player: &'a PlayerData,
+}
 
-impl SettingsPanel {
-    pub fn new() -> Self {
-        Self::default()
+impl<'a> SettingsPanel<'a> {
+    pub fn new(player: &'a PlayerData) -> Self {
+        Self { player }
     }
 }
 
-impl Panel for SettingsPanel {
+impl<'a> Panel for SettingsPanel<'a> {
     fn address(&self) -> PanelAddress {
         PanelAddress::Settings
     }
 }
 
-impl Component for SettingsPanel {
+impl<'a> Component for SettingsPanel<'a> {
     fn build(self) -> Option<Node> {
+        let settings = &self.player.settings;
+        let muted = settings.mute_emotes;
+        let high_contrast = settings.icon_mode == IconMode::HighContrast;
+        let share_statistics = settings.share_card_statistics;
         PanelWindow::new(PanelAddress::Settings, 600.px(), 600.px())
             .title("Settings")
             .content(
@@ -51,13 +60,70 @@ impl Component for SettingsPanel {
                             .justify_content(FlexJustify::Center),
                     )
                     .child(
-                        ScrollView::new("TextScroll").style(Style::new().height(400.px())).child(
-                            Slider::new()
-                                .label("Music Volume:")
-                                .preference_key("MusicVolume")
-                                .low_value(0.0)
-                                .high_value(1.0),
-                        ),
+                        ScrollView::new("TextScroll")
+                            .style(Style::new().height(400.px()))
+                            .child(volume_row(
+                                "Music Volume",
+                                settings.music_volume,
+                                UserAction::SetMusicVolume,
+                            ))
+                            .child(volume_row(
+                                "Sound Effects Volume",
+                                settings.sound_effects_volume,
+                                UserAction::SetSoundEffectsVolume,
+                            ))
+                            .child(animation_speed_row(settings.animation_speed))
+                            .child(
+                                Button::new(if settings.mute_audio {
+                                    "Unmute Audio"
+                                } else {
+                                    "Mute Audio"
+                                })
+                                .button_type(ButtonType::Secondary)
+                                .action(UserAction::SetMuteAudio(!settings.mute_audio))
+                                .layout(Layout::new().margin(Edge::All, 16.px())),
+                            )
+                            .child(
+                                Button::new(if share_statistics {
+                                    "Share Card Statistics: On"
+                                } else {
+                                    "Share Card Statistics: Off"
+                                })
+                                .button_type(ButtonType::Secondary)
+                                .action(UserAction::SetShareCardStatistics(!share_statistics))
+                                .layout(Layout::new().margin(Edge::All, 16.px())),
+                            )
+                            .child(
+                                Button::new(if settings.disable_hotkeys {
+                                    "Keyboard Shortcuts: Off"
+                                } else {
+                                    "Keyboard Shortcuts: On"
+                                })
+                                .button_type(ButtonType::Secondary)
+                                .action(UserAction::SetDisableHotkeys(!settings.disable_hotkeys))
+                                .layout(Layout::new().margin(Edge::All, 16.px())),
+                            )
+                            .child(automation_section(self.player.automation)),
+                    )
+                    .child(
+                        Button::new(if muted { "Unmute Emotes" } else { "Mute Emotes" })
+                            .button_type(ButtonType::Secondary)
+                            .action(UserAction::SetMuteEmotes(!muted))
+                            .layout(Layout::new().margin(Edge::All, 16.px())),
+                    )
+                    .child(
+                        Button::new(if high_contrast {
+                            "High Contrast Icons: On"
+                        } else {
+                            "High Contrast Icons: Off"
+                        })
+                        .button_type(ButtonType::Secondary)
+                        .action(UserAction::SetIconMode(if high_contrast {
+                            IconMode::Standard
+                        } else {
+                            IconMode::HighContrast
+                        }))
+                        .layout(Layout::new().margin(Edge::All, 16.px())),
                     )
                     .child(
                         Button::new("Back")
@@ -68,3 +134,94 @@ impl Component for SettingsPanel {
             .build()
     }
 }
+
+/// Amount by which volume buttons change the volume, as a percentage
+const VOLUME_STEP: u32 = 10;
+
+fn volume_row(label: &str, volume: u32, action: fn(u32) -> UserAction) -> impl Component {
+    Row::new(format!("{label}Row"))
+        .style(
+            Style::new()
+                .margin(Edge::All, 16.px())
+                .justify_content(FlexJustify::SpaceBetween)
+                .align_items(FlexAlign::Center),
+        )
+        .child(Text::new(format!("{label}: {volume}%")).font_size(FontSize::Body))
+        .child(
+            Row::new("Buttons")
+                .child(
+                    Button::new("-")
+                        .button_type(ButtonType::Secondary)
+                        .action(action(volume.saturating_sub(VOLUME_STEP)))
+                        .layout(Layout::new().margin(Edge::Horizontal, 8.px())),
+                )
+                .child(
+                    Button::new("+")
+                        .button_type(ButtonType::Secondary)
+                        .action(action((volume + VOLUME_STEP).min(100)))
+                        .layout(Layout::new().margin(Edge::Horizontal, 8.px())),
+                ),
+        )
+}
+
+fn animation_speed_row(current: AnimationSpeed) -> impl Component {
+    let button = |label: &str, speed: AnimationSpeed| {
+        Button::new(label)
+            .button_type(if speed == current { ButtonType::Primary } else { ButtonType::Secondary })
+            .action(UserAction::SetAnimationSpeed(speed))
+            .layout(Layout::new().margin(Edge::Horizontal, 8.px()))
+    };
+
+    Row::new("AnimationSpeedRow")
+        .style(
+            Style::new()
+                .margin(Edge::All, 16.px())
+                .justify_content(FlexJustify::SpaceBetween)
+                .align_items(FlexAlign::Center),
+        )
+        .child(Text::new("Animations").font_size(FontSize::Body))
+        .child(
+            Row::new("Buttons")
+                .child(button("Off", AnimationSpeed::Off))
+                .child(button("Fast", AnimationSpeed::Fast))
+                .child(button("Normal", AnimationSpeed::Normal)),
+        )
+}
+
+fn automation_section(preferences: AutomationPreferences) -> impl Component {
+    let toggle = |label: &str, enabled: bool, updated: AutomationPreferences| {
+        Button::new(format!("{label}: {}", if enabled { "On" } else { "Off" }))
+            .button_type(ButtonType::Secondary)
+            .action(UserAction::SetAutomationPreferences(updated))
+            .layout(Layout::new().margin(Edge::All, 16.px()))
+    };
+
+    Column::new("RaidAutomation")
+        .style(Style::new().align_items(FlexAlign::Stretch))
+        .child(
+            Text::new("Raid Automation")
+                .font_size(FontSize::Body)
+                .layout(Layout::new().margin(Edge::All, 16.px())),
+        )
+        .child(toggle(
+            "Skip Encounters Without Weapons",
+            preferences.auto_continue_encounters,
+            AutomationPreferences {
+                auto_continue_encounters: !preferences.auto_continue_encounters,
+                ..preferences
+            },
+        ))
+        .child(toggle(
+            "Auto-End Raids",
+            preferences.auto_end_raid,
+            AutomationPreferences { auto_end_raid: !preferences.auto_end_raid, ..preferences },
+        ))
+        .child(toggle(
+            "Always Stop at Access",
+            preferences.always_stop_at_access,
+            AutomationPreferences {
+                always_stop_at_access: !preferences.always_stop_at_access,
+                ..preferences
+            },
+        ))
+}
@@ -0,0 +1,59 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Celebrates the campaign unlocks granted by completing an adventure
+
+use core_ui::design::{FontColor, FontSize};
+use core_ui::panel_window::PanelWindow;
+use core_ui::prelude::*;
+use core_ui::text::Text;
+use data::campaign::Unlock;
+use data::player_data::PlayerData;
+use panel_address::{Panel, PanelAddress};
+use protos::spelldawn::FlexAlign;
+
+pub struct UnlockCelebrationPanel<'a> {
+    pub player: &'a PlayerData,
+}
+
+impl<'a> Panel for UnlockCelebrationPanel<'a> {
+    fn address(&self) -> PanelAddress {
+        PanelAddress::CampaignUnlocks
+    }
+}
+
+impl<'a> Component for UnlockCelebrationPanel<'a> {
+    fn build(self) -> Option<Node> {
+        PanelWindow::new(self.address(), 800.px(), 600.px())
+            .title("New Unlocks")
+            .show_close_button(true)
+            .content(
+                Column::new("Unlocks")
+                    .style(Style::new().width(100.pct()).align_items(FlexAlign::Center))
+                    .child(
+                        Text::new(format!(
+                            "Adventures Completed: {}",
+                            self.player.campaign.adventures_completed
+                        ))
+                        .font_size(FontSize::Body),
+                    )
+                    .children(self.player.campaign.recent_unlocks.iter().map(unlock_row)),
+            )
+            .build()
+    }
+}
+
+fn unlock_row(unlock: &Unlock) -> impl Component {
+    Text::new(unlock.description()).font_size(FontSize::Headline).color(FontColor::CoinCount)
+}
@@ -21,14 +21,15 @@ use std::{panic, str};
 
 use anyhow::Result;
 use cards::initialize;
+use database::SledDatabase;
 use prost::Message;
 use protos::spelldawn::client_debug_command::DebugCommand;
 use protos::spelldawn::game_command::Command;
 use protos::spelldawn::{
-    ClientDebugCommand, CommandList, ConnectRequest, GameCommand, GameRequest, LogMessage,
-    LogMessageLevel,
+    AuthenticateRequest, ClientDebugCommand, CommandList, ConnectRequest, FetchLeaderboardRequest,
+    FetchRecordingsRequest, GameCommand, GameRequest, LogMessage, LogMessageLevel,
 };
-use server::{agent_response, requests};
+use server::{agent_response, auth, requests};
 
 /// Initialize the plugin. Must be called immediately at application start.
 ///
@@ -46,6 +47,7 @@ unsafe fn initialize_impl(path: *const u8, path_length: i32) -> Result<i32> {
     let slice = std::slice::from_raw_parts(path, path_length as usize);
     let db_path = str::from_utf8(slice)?;
     database::override_path(db_path.to_string());
+    auth::initialize(&mut SledDatabase { flush_on_write: true })?;
     println!("Initialized plugin with database path {}", db_path);
     Ok(0)
 }
@@ -87,6 +89,41 @@ unsafe fn connect_impl(
     Ok(command_list.encoded_len() as i32)
 }
 
+/// Issues a session token for a player.
+///
+/// `request` should be a buffer including the protobuf serialization of an
+/// `AuthenticateRequest` message of `request_length` bytes. `response` should
+/// be an empty buffer of `response_length` bytes, this buffer will be populated
+/// with a protobuf-serialized `AuthenticateResponse`.
+///
+/// Returns the number of bytes written to the `response` buffer, or -1 on
+/// error.
+#[no_mangle]
+pub unsafe extern "C" fn spelldawn_authenticate(
+    request: *const u8,
+    request_length: i32,
+    response: *mut u8,
+    response_length: i32,
+) -> i32 {
+    error_boundary(response, response_length, || {
+        authenticate_impl(request, request_length, response, response_length)
+    })
+}
+
+unsafe fn authenticate_impl(
+    request: *const u8,
+    request_length: i32,
+    response: *mut u8,
+    response_length: i32,
+) -> Result<i32> {
+    let request_data = std::slice::from_raw_parts(request, request_length as usize);
+    let authenticate_request = AuthenticateRequest::decode(request_data)?;
+    let authenticate_response = requests::authenticate(authenticate_request)?;
+    let mut out = std::slice::from_raw_parts_mut(response, response_length as usize);
+    authenticate_response.encode(&mut out)?;
+    Ok(authenticate_response.encoded_len() as i32)
+}
+
 /// Checks for new game responses which are available to be rendered on the
 /// client.
 ///
@@ -148,6 +185,98 @@ unsafe fn perform_impl(
     Ok(command_list.encoded_len() as i32)
 }
 
+/// Fetches static metadata for every card in the game.
+///
+/// `response` should be an empty buffer of `response_length` bytes, this buffer
+/// will be populated with a protobuf-serialized `CardCatalog`.
+///
+/// Returns the number of bytes written to the `response` buffer, or -1 on
+/// error.
+#[no_mangle]
+pub unsafe extern "C" fn spelldawn_fetch_card_catalog(
+    response: *mut u8,
+    response_length: i32,
+) -> i32 {
+    error_boundary(response, response_length, || fetch_card_catalog_impl(response, response_length))
+}
+
+unsafe fn fetch_card_catalog_impl(response: *mut u8, response_length: i32) -> Result<i32> {
+    let catalog = requests::fetch_card_catalog();
+    let mut out = std::slice::from_raw_parts_mut(response, response_length as usize);
+    catalog.encode(&mut out)?;
+    Ok(catalog.encoded_len() as i32)
+}
+
+/// Fetches one page of the ranked leaderboard.
+///
+/// `request` should be a buffer including the protobuf serialization of a
+/// `FetchLeaderboardRequest` message of `request_length` bytes. `response`
+/// should be an empty buffer of `response_length` bytes, this buffer will be
+/// populated with a protobuf-serialized `LeaderboardPage`.
+///
+/// Returns the number of bytes written to the `response` buffer, or -1 on
+/// error.
+#[no_mangle]
+pub unsafe extern "C" fn spelldawn_fetch_leaderboard(
+    request: *const u8,
+    request_length: i32,
+    response: *mut u8,
+    response_length: i32,
+) -> i32 {
+    error_boundary(response, response_length, || {
+        fetch_leaderboard_impl(request, request_length, response, response_length)
+    })
+}
+
+unsafe fn fetch_leaderboard_impl(
+    request: *const u8,
+    request_length: i32,
+    response: *mut u8,
+    response_length: i32,
+) -> Result<i32> {
+    let request_data = std::slice::from_raw_parts(request, request_length as usize);
+    let leaderboard_request = FetchLeaderboardRequest::decode(request_data)?;
+    let page = requests::fetch_leaderboard(&leaderboard_request)?;
+    let mut out = std::slice::from_raw_parts_mut(response, response_length as usize);
+    page.encode(&mut out)?;
+    Ok(page.encoded_len() as i32)
+}
+
+/// Fetches scenario recordings for client visual tests.
+///
+/// `request` should be a buffer including the protobuf serialization of a
+/// `FetchRecordingsRequest` message of `request_length` bytes. `response`
+/// should be an empty buffer of `response_length` bytes, this buffer will be
+/// populated with a protobuf-serialized `RecordingList`.
+///
+/// Returns the number of bytes written to the `response` buffer, or -1 on
+/// error.
+#[no_mangle]
+pub unsafe extern "C" fn spelldawn_fetch_recordings(
+    request: *const u8,
+    request_length: i32,
+    response: *mut u8,
+    response_length: i32,
+) -> i32 {
+    error_boundary(response, response_length, || {
+        fetch_recordings_impl(request, request_length, response, response_length)
+    })
+}
+
+unsafe fn fetch_recordings_impl(
+    request: *const u8,
+    request_length: i32,
+    response: *mut u8,
+    response_length: i32,
+) -> Result<i32> {
+    let request_data = std::slice::from_raw_parts(request, request_length as usize);
+    let recordings_request = FetchRecordingsRequest::decode(request_data)?;
+    let recordings = requests::fetch_recordings(&recordings_request)?;
+    let mut out = std::slice::from_raw_parts_mut(response, response_length as usize);
+    recordings.encode(&mut out)?;
+    Ok(recordings.encoded_len() as i32)
+}
+
 unsafe fn error_boundary(
     response: *mut u8,
     response_length: i32,
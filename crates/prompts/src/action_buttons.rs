@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use core_ui::icons;
+use data::card_definition::AbilityType;
+use data::delegates::CombinedWeapons;
 use data::game::{GameState, MulliganDecision};
 use data::game_actions::{AccessPhaseAction, CardPromptAction, EncounterAction, PromptAction};
 use data::primitives::Side;
@@ -25,7 +27,7 @@ pub fn for_prompt(game: &GameState, side: Side, action: PromptAction) -> Respons
         PromptAction::MulliganDecision(data) => mulligan_button(data),
         PromptAction::EncounterAction(data) => encounter_action_button(game, side, data),
         PromptAction::AccessPhaseAction(data) => access_button(data),
-        PromptAction::CardAction(data) => card_response_button(side, data),
+        PromptAction::CardAction(data) => card_response_button(game, side, data),
     }
     .action(action)
 }
@@ -54,7 +56,43 @@ fn encounter_action_button(
             ResponseButton::new(label)
         }
         EncounterAction::NoWeapon => ResponseButton::new("Continue").primary(false),
-        EncounterAction::CardAction(action) => card_response_button(side, action),
+        EncounterAction::CardAction(action) => card_response_button(game, side, action),
+        EncounterAction::Retreat => ResponseButton::new("Retreat").primary(false),
+        EncounterAction::ActivateAbility(ability_id, weapon_id) => {
+            let label = format!(
+                "{}: {}",
+                rules::card_definition(game, ability_id.card_id).name.displayed_name(),
+                rules::card_definition(game, weapon_id).name.displayed_name()
+            );
+            match queries::ability_mana_cost(game, ability_id) {
+                Some(cost) if cost > 0 => {
+                    ResponseButton::new(format!("{}\n{}{}", label, cost, icons::MANA))
+                        .two_lines(true)
+                        .primary(false)
+                }
+                _ => ResponseButton::new(label).primary(false),
+            }
+        }
+        EncounterAction::UseCombinedWeapons(first_id, second_id, target_id) => {
+            let label = format!(
+                "{} + {}",
+                rules::card_definition(game, first_id).name.displayed_name(),
+                rules::card_definition(game, second_id).name.displayed_name()
+            );
+            let weapons = CombinedWeapons::new(first_id, second_id, target_id);
+            if let Some((first, second)) = queries::combined_cost_to_defeat_target(game, weapons) {
+                if first + second > 0 {
+                    return ResponseButton::new(format!(
+                        "{}\n{}{}",
+                        label,
+                        first + second,
+                        icons::MANA
+                    ))
+                    .two_lines(true);
+                }
+            }
+            ResponseButton::new(label)
+        }
     }
 }
 
@@ -72,7 +110,11 @@ fn access_button(access: AccessPhaseAction) -> ResponseButton {
     }
 }
 
-fn card_response_button(user_side: Side, action: CardPromptAction) -> ResponseButton {
+fn card_response_button(
+    game: &GameState,
+    user_side: Side,
+    action: CardPromptAction,
+) -> ResponseButton {
     let label = match action {
         CardPromptAction::LoseMana(side, amount) => {
             format!("{} {}{}", lose_text(user_side, side), amount, icons::MANA)
@@ -87,6 +129,40 @@ fn card_response_button(user_side: Side, action: CardPromptAction) -> ResponseBu
         CardPromptAction::EndRaid => "End Raid".to_string(),
         CardPromptAction::TakeDamage(_, amount) => format!("Take {}", amount),
         CardPromptAction::TakeDamageEndRaid(_, amount) => format!("End Raid, Take {}", amount),
+        CardPromptAction::TutorCard(_, card_id) => {
+            return ResponseButton::new("Choose").anchor_to(card_id);
+        }
+        CardPromptAction::PayUpkeep(card_id, cost) => {
+            return ResponseButton::new(format!("Upkeep\n{}{}", cost, icons::MANA))
+                .two_lines(true)
+                .anchor_to(card_id);
+        }
+        CardPromptAction::DiscardProject(card_id) => {
+            return ResponseButton::new("Discard").primary(false).anchor_to(card_id);
+        }
+        CardPromptAction::UnveilProject(card_id) => {
+            return ResponseButton::new("Unveil").anchor_to(card_id);
+        }
+        CardPromptAction::EndUnveilWindow => {
+            return ResponseButton::new("Continue").primary(false);
+        }
+        CardPromptAction::ConfirmRetreat => "Retreat".to_string(),
+        CardPromptAction::CancelRetreat => {
+            return ResponseButton::new("Cancel").primary(false);
+        }
+        CardPromptAction::DiscardToHandSize(_, card_id) => {
+            return ResponseButton::new("Discard").anchor_to(card_id);
+        }
+        CardPromptAction::PassReaction => {
+            return ResponseButton::new("Pass").primary(false);
+        }
+        CardPromptAction::ChooseX(_, _, x) => format!("X = {}{}", x, icons::MANA),
+        CardPromptAction::ChooseMode(mode, _) => {
+            match rules::ability_definition(game, mode).ability_type {
+                AbilityType::Mode(label) => label.to_string(),
+                _ => "Choose".to_string(),
+            }
+        }
     };
 
     ResponseButton::new(label)
@@ -0,0 +1,100 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Displays the most recent entries in the game's AI decision log, showing
+//! which actions each agent considered and how it scored them.
+
+use std::cmp::Ordering;
+
+use core_ui::design::{FontColor, FontSize};
+use core_ui::panel_window::PanelWindow;
+use core_ui::prelude::*;
+use core_ui::text::Text;
+use data::agent_definition::AgentExplanation;
+use data::game::GameState;
+use panel_address::{Panel, PanelAddress};
+use protos::spelldawn::FlexAlign;
+
+/// Number of log entries to display, starting from the most recent.
+const MAX_ENTRIES: usize = 10;
+
+/// Number of candidate actions to display for each entry.
+const MAX_CANDIDATES: usize = 5;
+
+pub struct AgentLogPanel<'a> {
+    pub game: &'a GameState,
+}
+
+impl<'a> Panel for AgentLogPanel<'a> {
+    fn address(&self) -> PanelAddress {
+        PanelAddress::AgentLog
+    }
+}
+
+impl<'a> Component for AgentLogPanel<'a> {
+    fn build(self) -> Option<Node> {
+        let mut content = Column::new("AgentLog")
+            .style(Style::new().width(100.pct()).align_items(FlexAlign::Stretch));
+
+        if self.game.agent_log.is_empty() {
+            let message = if self.game.data.config.explain_agents {
+                "No AI actions recorded"
+            } else {
+                "AI explanations are disabled"
+            };
+            content = content.child(Text::new(message).font_size(FontSize::Headline));
+        } else {
+            content = content.children(
+                self.game
+                    .agent_log
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .take(MAX_ENTRIES)
+                    .map(|(i, explanation)| entry_row(i, explanation)),
+            );
+        }
+
+        PanelWindow::new(self.address(), 1024.px(), 600.px())
+            .title("AI Log")
+            .show_close_button(true)
+            .content(content)
+            .build()
+    }
+}
+
+/// Describes a single agent decision along with its highest-scoring
+/// candidates.
+fn entry_row(index: usize, explanation: &AgentExplanation) -> impl Component {
+    let mut candidates = explanation.candidates.iter().collect::<Vec<_>>();
+    candidates.sort_by(|(_, a), (_, b)| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+
+    Column::new(format!("Entry{}", index))
+        .style(Style::new().margin(Edge::Vertical, 8.px()).align_items(FlexAlign::FlexStart))
+        .child(
+            Text::new(format!(
+                "Turn {} {:?} ({}): {:?}",
+                explanation.turn, explanation.side, explanation.agent, explanation.chosen
+            ))
+            .font_size(FontSize::Headline),
+        )
+        .children(candidates.into_iter().take(MAX_CANDIDATES).map(|(action, score)| {
+            let color = if *action == explanation.chosen {
+                FontColor::PrimaryText
+            } else {
+                FontColor::Warning
+            };
+            Text::new(format!("{:?}: {:.2}", action, score)).font_size(FontSize::Body).color(color)
+        }))
+}
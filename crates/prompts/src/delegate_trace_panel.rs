@@ -0,0 +1,92 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Debug panel listing every event delegate which fired while handling the
+//! most recent game action, in order, to help diagnose trigger-ordering bugs.
+
+use core_ui::design::{FontColor, FontSize};
+use core_ui::panel_window::PanelWindow;
+use core_ui::prelude::*;
+use core_ui::text::Text;
+use data::delegate_trace::DelegateTraceEntry;
+use data::game::GameState;
+use panel_address::{Panel, PanelAddress};
+use protos::spelldawn::FlexAlign;
+
+/// Maximum number of delegate invocations to display.
+const MAX_ENTRIES: usize = 100;
+
+pub struct DelegateTracePanel<'a> {
+    pub game: &'a GameState,
+}
+
+impl<'a> Panel for DelegateTracePanel<'a> {
+    fn address(&self) -> PanelAddress {
+        PanelAddress::DelegateTrace
+    }
+}
+
+impl<'a> Component for DelegateTracePanel<'a> {
+    fn build(self) -> Option<Node> {
+        let mut content = Column::new("DelegateTrace")
+            .style(Style::new().width(100.pct()).align_items(FlexAlign::Stretch));
+
+        if self.game.delegate_trace.is_empty() {
+            let message = if self.game.data.config.trace_delegates {
+                "No effects fired during the last action"
+            } else {
+                "Effect tracing is disabled"
+            };
+            content = content.child(Text::new(message).font_size(FontSize::Headline));
+        } else {
+            content = content.children(
+                self.game
+                    .delegate_trace
+                    .iter()
+                    .enumerate()
+                    .take(MAX_ENTRIES)
+                    .map(|(i, entry)| entry_row(i, entry)),
+            );
+        }
+
+        PanelWindow::new(self.address(), 1024.px(), 600.px())
+            .title("Effect Log")
+            .show_close_button(true)
+            .content(content)
+            .build()
+    }
+}
+
+/// Describes a single delegate invocation. Delegates whose requirement was not
+/// met are shown in a muted color.
+fn entry_row(index: usize, entry: &DelegateTraceEntry) -> impl Component {
+    let color = if entry.requirement { FontColor::PrimaryText } else { FontColor::Warning };
+    Column::new(format!("Entry{}", index))
+        .style(Style::new().margin(Edge::Vertical, 8.px()).align_items(FlexAlign::FlexStart))
+        .child(
+            Text::new(format!(
+                "{}. {} on {:?}: {}",
+                index + 1,
+                entry.event,
+                entry.ability_id,
+                if entry.requirement { "fired" } else { "requirement not met" }
+            ))
+            .font_size(FontSize::Headline)
+            .color(color),
+        )
+        .child(Text::new(entry.data.clone()).font_size(FontSize::Body))
+        .child(entry.mutation.as_ref().map(|mutation| {
+            Text::new(mutation.clone()).font_size(FontSize::Body).color(FontColor::PrimaryText)
+        }))
+}
@@ -15,7 +15,10 @@
 //! Renders UI elements displayed during card game sessions
 
 pub mod action_buttons;
+pub mod agent_log_panel;
 pub mod card_info;
+pub mod delegate_trace_panel;
 pub mod prompt_container;
 pub mod prompts;
+pub mod raid_info_panel;
 pub mod response_button;
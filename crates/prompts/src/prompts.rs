@@ -14,21 +14,36 @@
 
 use anyhow::Result;
 use core_ui::design::FontSize;
+use core_ui::hotkey::Hotkey;
+use core_ui::panels;
 use core_ui::prelude::*;
 use core_ui::text::Text;
 use data::game::GameState;
-use data::game_actions::{GamePrompt, PromptContext};
+use data::game_actions::{
+    CardPromptAction, EncounterAction, GamePrompt, PromptAction, PromptContext, PromptPreview,
+};
 use data::primitives::Side;
+use panel_address::PanelAddress;
 use protos::spelldawn::InterfaceMainControls;
+use rules::flags;
 
 use crate::action_buttons;
 use crate::prompt_container::PromptContainer;
+use crate::response_button::ResponseButton;
 
 /// Builds UI elements to display a [GamePrompt] for the `side` player.
+///
+/// The `preview` function is invoked for each response to optionally describe
+/// the consequences of selecting it. If `show_hotkeys` is true, response
+/// buttons are given keyboard shortcuts: Space for responses which continue
+/// without making a choice, and number keys for the remaining responses in
+/// order.
 pub fn action_prompt(
     game: &GameState,
     side: Side,
     prompt: &GamePrompt,
+    show_hotkeys: bool,
+    preview: impl Fn(&GameState, Side, PromptAction) -> Option<PromptPreview>,
 ) -> Result<Option<InterfaceMainControls>> {
     let mut main_controls: Vec<Box<dyn ComponentObject>> = vec![];
     let mut card_anchor_nodes = vec![];
@@ -37,8 +52,25 @@ pub fn action_prompt(
         main_controls.push(Box::new(Text::new(label).font_size(FontSize::PromptContext)));
     }
 
+    if prompt.responses.iter().any(|r| matches!(r, PromptAction::EncounterAction(_))) {
+        for hint in lineage_hints(game) {
+            main_controls.push(Box::new(Text::new(hint).font_size(FontSize::PromptPreview)));
+        }
+    }
+
+    let mut next_number = 0;
     for response in &prompt.responses {
-        let button = action_buttons::for_prompt(game, side, *response);
+        let hotkey = if !show_hotkeys {
+            None
+        } else if is_continue(*response) {
+            Some(Hotkey::Space)
+        } else {
+            next_number += 1;
+            Hotkey::for_index(next_number - 1)
+        };
+        let button = action_buttons::for_prompt(game, side, *response)
+            .preview(preview(game, side, *response))
+            .hotkey(hotkey);
         if button.has_anchor() {
             card_anchor_nodes.push(button.render_to_card_anchor_node()?);
         } else {
@@ -46,14 +78,62 @@ pub fn action_prompt(
         }
     }
 
+    if prompt.responses.iter().any(|r| matches!(r, PromptAction::EncounterAction(_))) {
+        main_controls.push(Box::new(
+            ResponseButton::new("Raid Info")
+                .primary(false)
+                .action(panels::open(PanelAddress::RaidInfo)),
+        ));
+    }
+
     Ok(Some(InterfaceMainControls {
         node: PromptContainer::new().children(main_controls).build(),
         card_anchor_nodes,
+        node_patches: None,
     }))
 }
 
+/// Explains why each of the Champion's weapons which cannot target the minion
+/// currently being encountered is unable to do so.
+fn lineage_hints(game: &GameState) -> Vec<String> {
+    let Ok(defender_id) = game.raid_defender() else {
+        return vec![];
+    };
+    let lineage = rules::card_definition(game, defender_id).config.lineage;
+    game.weapons()
+        .filter(|weapon| !flags::can_encounter_target(game, weapon.id, defender_id))
+        .map(|weapon| {
+            let name = rules::card_definition(game, weapon.id).name.displayed_name();
+            match lineage {
+                Some(lineage) => format!("{} cannot damage {:?} minions", name, lineage),
+                None => format!("{} cannot damage this minion", name),
+            }
+        })
+        .collect()
+}
+
+/// Returns true if `action` continues without making a choice, e.g. when
+/// declining to use a weapon.
+fn is_continue(action: PromptAction) -> bool {
+    matches!(
+        action,
+        PromptAction::EncounterAction(EncounterAction::NoWeapon)
+            | PromptAction::CardAction(CardPromptAction::EndUnveilWindow)
+            | PromptAction::CardAction(CardPromptAction::PassReaction)
+    )
+}
+
 fn prompt_context(context: Option<PromptContext>) -> Option<String> {
     context.map(|context| match context {
         PromptContext::RaidAdvance => "Continue?".to_string(),
+        PromptContext::ChooseCard => "Choose a card".to_string(),
+        PromptContext::Upkeep => "Pay upkeep?".to_string(),
+        PromptContext::UnveilProjects => "Unveil projects?".to_string(),
+        PromptContext::Retreat => "Retreat from raid?".to_string(),
+        PromptContext::DiscardToHandSize(1) => "Discard a card".to_string(),
+        PromptContext::DiscardToHandSize(count) => format!("Discard {} cards", count),
+        PromptContext::Reaction => "Play a reaction?".to_string(),
+        PromptContext::ChooseMode => "Choose one".to_string(),
+        PromptContext::ChooseX => "Choose X".to_string(),
     })
 }
@@ -0,0 +1,139 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Displays the combat math for the current raid encounter, explaining whether
+//! each of the Champion's weapons can defeat the minion being encountered.
+
+use core_ui::design::{FontColor, FontSize};
+use core_ui::icons;
+use core_ui::panel_window::PanelWindow;
+use core_ui::prelude::*;
+use core_ui::text::Text;
+use data::card_state::CardState;
+use data::game::GameState;
+use data::primitives::{CardId, Side};
+use panel_address::{Panel, PanelAddress};
+use protos::spelldawn::{FlexAlign, FlexJustify};
+use rules::mana::ManaPurpose;
+use rules::{flags, mana, queries};
+
+pub struct RaidInfoPanel<'a> {
+    pub game: &'a GameState,
+}
+
+impl<'a> Panel for RaidInfoPanel<'a> {
+    fn address(&self) -> PanelAddress {
+        PanelAddress::RaidInfo
+    }
+}
+
+impl<'a> Component for RaidInfoPanel<'a> {
+    fn build(self) -> Option<Node> {
+        let defender_id = self.game.raid_defender().ok()?;
+        let defender = self.game.card(defender_id);
+        let mut content = Column::new("RaidInfo")
+            .style(Style::new().width(100.pct()).align_items(FlexAlign::Stretch));
+
+        if defender.is_revealed_to(Side::Champion) {
+            content = content
+                .child(
+                    Text::new(rules::card_definition(self.game, defender_id).name.displayed_name())
+                        .font_size(FontSize::Headline),
+                )
+                .child(
+                    Text::new(format!(
+                        "Health: {}, Shield: {}",
+                        queries::health(self.game, defender_id),
+                        queries::shield(self.game, defender_id)
+                    ))
+                    .font_size(FontSize::Body),
+                )
+                .children(
+                    self.game.weapons().map(|weapon| weapon_row(self.game, weapon, defender_id)),
+                );
+        } else {
+            content = content.child(Text::new("Unknown Minion").font_size(FontSize::Headline));
+        }
+
+        PanelWindow::new(self.address(), 800.px(), 600.px())
+            .title("Raid Info")
+            .show_close_button(true)
+            .content(content)
+            .build()
+    }
+}
+
+/// Describes the costs of using `weapon` to defeat the `target_id` minion.
+fn weapon_row(game: &GameState, weapon: &CardState, target_id: CardId) -> impl Component {
+    let mut description = vec![format!("Attack: {}", queries::attack(game, weapon.id))];
+    if let Some(boost) = queries::attack_boost(game, weapon.id) {
+        description.push(format!("+{} attack for {}{}", boost.bonus, boost.cost, icons::MANA));
+    }
+    let breach = queries::breach(game, weapon.id);
+    if breach > 0 {
+        description.push(format!("Breach: {}", breach));
+    }
+
+    let available = mana::get(game, Side::Champion, ManaPurpose::UseWeapon(weapon.id));
+    let (summary, color) = match (
+        queries::boosts_to_defeat_target(game, weapon.id, target_id),
+        queries::cost_to_defeat_target(game, weapon.id, target_id),
+    ) {
+        _ if !flags::can_encounter_target(game, weapon.id, target_id) => {
+            ("Wrong lineage".to_string(), FontColor::Warning)
+        }
+        (Some(boosts), Some(cost)) if cost <= available => (
+            format!(
+                "Boosts: {}, Cost: {}{}, Remaining: {}{}",
+                boosts,
+                cost,
+                icons::MANA,
+                available - cost,
+                icons::MANA
+            ),
+            FontColor::PrimaryText,
+        ),
+        (Some(boosts), Some(cost)) => (
+            format!(
+                "Boosts: {}, Cost: {}{}, Short: {}{}",
+                boosts,
+                cost,
+                icons::MANA,
+                cost - available,
+                icons::MANA
+            ),
+            FontColor::Warning,
+        ),
+        _ => ("Cannot defeat".to_string(), FontColor::Warning),
+    };
+
+    Row::new(format!("Weapon{}", weapon.id.index))
+        .style(
+            Style::new()
+                .width(100.pct())
+                .margin(Edge::Vertical, 8.px())
+                .justify_content(FlexJustify::SpaceBetween)
+                .align_items(FlexAlign::Center),
+        )
+        .child(
+            Column::new("Description")
+                .style(Style::new().align_items(FlexAlign::FlexStart))
+                .child(
+                    Text::new(rules::card_definition(game, weapon.id).name.displayed_name())
+                        .font_size(FontSize::Headline),
+                )
+                .child(Text::new(description.join(", ")).font_size(FontSize::Body)),
+        )
+        .child(Text::new(summary).font_size(FontSize::Body).color(color))
+}
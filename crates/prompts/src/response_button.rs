@@ -16,7 +16,12 @@ use adapters;
 use anyhow::Result;
 use core_ui::actions::{InterfaceAction, NoAction};
 use core_ui::button::{Button, ButtonType};
+use core_ui::design::FontSize;
+use core_ui::hotkey::Hotkey;
+use core_ui::icons;
 use core_ui::prelude::*;
+use core_ui::text::Text;
+use data::game_actions::PromptPreview;
 use data::primitives::CardId;
 use protos::spelldawn::{AnchorCorner, CardAnchor, CardAnchorNode, FlexAlign, FlexJustify};
 use with_error::WithError;
@@ -29,6 +34,8 @@ pub struct ResponseButton {
     action: Box<dyn InterfaceAction>,
     shift_down: bool,
     two_lines: bool,
+    preview: Option<PromptPreview>,
+    hotkey: Option<Hotkey>,
 }
 
 impl ResponseButton {
@@ -41,6 +48,8 @@ impl ResponseButton {
             action: Box::new(NoAction {}),
             shift_down: false,
             two_lines: false,
+            preview: None,
+            hotkey: None,
         }
     }
 
@@ -81,6 +90,18 @@ impl ResponseButton {
         self
     }
 
+    /// Describes the consequences of selecting this response, displayed below
+    /// the button.
+    pub fn preview(mut self, preview: Option<PromptPreview>) -> Self {
+        self.preview = preview;
+        self
+    }
+
+    pub fn hotkey(mut self, hotkey: Option<Hotkey>) -> Self {
+        self.hotkey = hotkey;
+        self
+    }
+
     pub fn render_to_card_anchor_node(self) -> Result<CardAnchorNode> {
         Ok(CardAnchorNode {
             card_id: Some(adapters::card_identifier(
@@ -111,15 +132,49 @@ impl ResponseButton {
 
 impl Component for ResponseButton {
     fn build(self) -> Option<Node> {
-        Button::new(self.label)
+        let button = Button::new(self.label)
             .button_type(if self.primary { ButtonType::Primary } else { ButtonType::Secondary })
             .action(self.action.as_client_action())
             .two_lines(self.two_lines)
-            .layout(
+            .hotkey(self.hotkey);
+
+        let Some(preview) = self.preview else {
+            return button
+                .layout(
+                    self.layout
+                        .margin(Edge::Horizontal, 16.px())
+                        .margin(Edge::Bottom, if self.shift_down { 200.px() } else { 0.px() }),
+                )
+                .build();
+        };
+
+        Column::new("ResponseButtonWithPreview")
+            .style(
                 self.layout
                     .margin(Edge::Horizontal, 16.px())
-                    .margin(Edge::Bottom, if self.shift_down { 200.px() } else { 0.px() }),
+                    .margin(Edge::Bottom, if self.shift_down { 200.px() } else { 0.px() })
+                    .to_style()
+                    .align_items(FlexAlign::Center),
             )
+            .child(button)
+            .child(Text::new(preview_text(preview)).font_size(FontSize::PromptPreview))
             .build()
     }
 }
+
+fn preview_text(preview: PromptPreview) -> String {
+    let mut result = vec![];
+    if preview.mana_lost > 0 {
+        result.push(format!("-{}{}", preview.mana_lost, icons::MANA));
+    }
+    if preview.actions_lost > 0 {
+        result.push(format!("-{}{}", preview.actions_lost, icons::ACTION));
+    }
+    if preview.cards_discarded > 0 {
+        result.push(format!("Discard {}", preview.cards_discarded));
+    }
+    if preview.raid_ends {
+        result.push("Raid ends".to_string());
+    }
+    result.join(", ")
+}
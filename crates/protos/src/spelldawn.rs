@@ -486,6 +486,52 @@ pub struct Node {
     pub hover_style: ::core::option::Option<FlexStyle>,
     #[prost(message, optional, tag = "7")]
     pub pressed_style: ::core::option::Option<FlexStyle>,
+    /// Keyboard shortcut which invokes this node's 'on_click' handler.
+    #[prost(message, optional, tag = "8")]
+    pub hotkey: ::core::option::Option<NodeHotkey>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NodeHotkey {
+    /// Name of the Unity KeyCode which triggers this hotkey, e.g. "Space" or
+    /// "Alpha1".
+    #[prost(string, tag = "1")]
+    pub key_code: ::prost::alloc::string::String,
+    /// Short description of the key, displayed as a hint, e.g. "1".
+    #[prost(string, tag = "2")]
+    pub label: ::prost::alloc::string::String,
+}
+/// Describes a single change to a previously-rendered Node tree.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NodePatch {
+    /// Child indices leading from the root node to the node to modify. An
+    /// empty path refers to the root node itself.
+    #[prost(uint32, repeated, tag = "1")]
+    pub path: ::prost::alloc::vec::Vec<u32>,
+    #[prost(oneof = "node_patch::PatchType", tags = "2, 3, 4")]
+    pub patch_type: ::core::option::Option<node_patch::PatchType>,
+}
+/// Nested message and enum types in `NodePatch`.
+pub mod node_patch {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum PatchType {
+        /// Replace the node at this path with a new node.
+        #[prost(message, tag = "2")]
+        Replace(super::Node),
+        /// Remove all children of the node at this path, starting with the
+        /// child at this index.
+        #[prost(uint32, tag = "3")]
+        TruncateChildren(u32),
+        /// Add a new last child to the node at this path.
+        #[prost(message, tag = "4")]
+        AppendChild(super::Node),
+    }
+}
+/// A sequence of patches to be applied in order to the most recent Node
+/// the server sent for some interface element.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NodePatchList {
+    #[prost(message, repeated, tag = "1")]
+    pub patches: ::prost::alloc::vec::Vec<NodePatch>,
 }
 // ============================================================================
 // Game Primitives
@@ -566,6 +612,9 @@ pub struct CardIcon {
     /// Scale multiplier for the background image.
     #[prost(message, optional, tag = "3")]
     pub background_scale: ::core::option::Option<f32>,
+    /// Color for the icon's text. Defaults to white.
+    #[prost(message, optional, tag = "4")]
+    pub text_color: ::core::option::Option<FlexColor>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CardIcons {
@@ -618,6 +667,10 @@ pub struct ArrowTargetRoom {
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CardTargeting {
+    /// Rooms which are currently legal targets for this card, which should be
+    /// highlighted while it is being played.
+    #[prost(enumeration = "RoomIdentifier", repeated, tag = "4")]
+    pub highlighted_rooms: ::prost::alloc::vec::Vec<i32>,
     #[prost(oneof = "card_targeting::Targeting", tags = "1, 2, 3")]
     pub targeting: ::core::option::Option<card_targeting::Targeting>,
 }
@@ -707,6 +760,9 @@ pub struct ObjectPositionRevealedCards {
     #[prost(enumeration = "RevealedCardsBrowserSize", tag = "1")]
     pub size: i32,
 }
+/// Shared slot for a global modifier card which affects both players.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObjectPositionGlobalModifier {}
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ObjectPosition {
     /// A key by which to sort this object -- objects with higher sorting keys
@@ -723,7 +779,7 @@ pub struct ObjectPosition {
     pub sorting_subkey: u32,
     #[prost(
         oneof = "object_position::Position",
-        tags = "3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 14, 15, 16, 17, 18"
+        tags = "3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 14, 15, 16, 17, 18, 19"
     )]
     pub position: ::core::option::Option<object_position::Position>,
 }
@@ -761,8 +817,20 @@ pub mod object_position {
         IntoCard(super::ObjectPositionIntoCard),
         #[prost(message, tag = "18")]
         Revealed(super::ObjectPositionRevealedCards),
+        #[prost(message, tag = "19")]
+        GlobalModifier(super::ObjectPositionGlobalModifier),
     }
 }
+/// Explains the meaning of a keyword which appears in rules text.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KeywordTooltip {
+    /// Displayed name of the keyword, e.g. "Breach".
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// Reminder text explaining the keyword.
+    #[prost(string, tag = "2")]
+    pub text: ::prost::alloc::string::String,
+}
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RevealedCardView {
     #[prost(message, optional, tag = "1")]
@@ -791,6 +859,59 @@ pub struct RevealedCardView {
     /// info zoom.
     #[prost(message, optional, tag = "9")]
     pub supplemental_info: ::core::option::Option<Node>,
+    /// Explanations of the keywords which appear in this card's rules text,
+    /// e.g. for display in a reminder popup on long-press.
+    #[prost(message, repeated, tag = "10")]
+    pub keyword_tooltips: ::prost::alloc::vec::Vec<KeywordTooltip>,
+}
+/// A numeric statistic of a card, e.g. its attack value.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CardInfoStat {
+    #[prost(string, tag = "1")]
+    pub label: ::prost::alloc::string::String,
+    /// Current value, including the effects of any modifiers.
+    #[prost(uint32, tag = "2")]
+    pub value: u32,
+    /// Value printed on the card.
+    #[prost(uint32, tag = "3")]
+    pub base_value: u32,
+}
+/// A counter or other piece of state tracked on a card, e.g. its level.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CardInfoCounter {
+    #[prost(string, tag = "1")]
+    pub label: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "2")]
+    pub value: u32,
+}
+/// Detailed information about a card, shown in an inspector view e.g. on
+/// long-press.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CardInfoView {
+    /// Rules text for each of this card's abilities, in order.
+    #[prost(string, repeated, tag = "1")]
+    pub ability_text: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Current values of this card's statistics.
+    #[prost(message, repeated, tag = "2")]
+    pub stats: ::prost::alloc::vec::Vec<CardInfoStat>,
+    /// Descriptions of changes to this card's statistics by other effects,
+    /// e.g. "+2 Health".
+    #[prost(string, repeated, tag = "3")]
+    pub modifiers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Counters with a nonzero value on this card.
+    #[prost(message, repeated, tag = "4")]
+    pub counters: ::prost::alloc::vec::Vec<CardInfoCounter>,
+}
+/// Progress of a scheme card toward being scored, used to render a progress
+/// bar on the card in the arena.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SchemeProgress {
+    /// Number of level counters currently on this card.
+    #[prost(uint32, tag = "1")]
+    pub current_level: u32,
+    /// Level at which this card can be scored.
+    #[prost(uint32, tag = "2")]
+    pub required_level: u32,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CardView {
@@ -835,6 +956,14 @@ pub struct CardView {
     /// destroyed.
     #[prost(message, optional, tag = "12")]
     pub destroy_position: ::core::option::Option<ObjectPosition>,
+    /// Detailed information to display in a card inspector. Only present on
+    /// revealed cards.
+    #[prost(message, optional, tag = "13")]
+    pub info_zoom: ::core::option::Option<CardInfoView>,
+    /// Progress toward this card's level requirement. Only present on revealed
+    /// scheme cards in a room.
+    #[prost(message, optional, tag = "14")]
+    pub scheme_progress: ::core::option::Option<SchemeProgress>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PlayerInfo {
@@ -849,6 +978,19 @@ pub struct PlayerInfo {
     /// Card back asset to use for this player's cards.
     #[prost(message, optional, tag = "5")]
     pub card_back: ::core::option::Option<SpriteAddress>,
+    /// Background image to display behind this player's side of the arena. If
+    /// not provided, the standard arena is displayed.
+    #[prost(message, optional, tag = "6")]
+    pub board_skin: ::core::option::Option<SpriteAddress>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ManaPoolView {
+    /// Mana available in this pool.
+    #[prost(uint32, tag = "1")]
+    pub mana: u32,
+    /// Icon describing the use restriction on this mana.
+    #[prost(string, tag = "2")]
+    pub icon: ::prost::alloc::string::String,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ManaView {
@@ -857,6 +999,9 @@ pub struct ManaView {
     /// Additional mana with custom use restrictions.
     #[prost(uint32, tag = "2")]
     pub bonus_mana: u32,
+    /// Breakdown of 'bonus_mana' into individual restricted pools.
+    #[prost(message, repeated, tag = "3")]
+    pub pools: ::prost::alloc::vec::Vec<ManaPoolView>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ScoreView {
@@ -867,6 +1012,14 @@ pub struct ScoreView {
 pub struct ActionTrackerView {
     #[prost(uint32, tag = "1")]
     pub available_action_count: u32,
+    /// Number of the available actions which were granted by card effects in
+    /// addition to the standard actions for this turn.
+    #[prost(uint32, tag = "2")]
+    pub bonus_action_count: u32,
+    /// Number of additional actions which will be granted at the start of this
+    /// player's next turn.
+    #[prost(uint32, tag = "3")]
+    pub next_turn_action_count: u32,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PlayerView {
@@ -1040,20 +1193,57 @@ pub struct ConnectRequest {
     /// User making this request.
     #[prost(message, optional, tag = "1")]
     pub player_id: ::core::option::Option<PlayerIdentifier>,
+    /// Session token previously returned from the 'Authenticate' call for
+    /// this user.
+    #[prost(string, tag = "2")]
+    pub session_token: ::prost::alloc::string::String,
+    /// Identifies the client instance making this request, to allow a user to
+    /// be connected from multiple devices simultaneously.
+    #[prost(string, tag = "3")]
+    pub client_id: ::prost::alloc::string::String,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GameRequest {
     #[prost(message, optional, tag = "1")]
     pub action: ::core::option::Option<ClientAction>,
-    /// Identifies the user making this request. At some point I'm going to
-    /// figure out how to set up authentication, but currently we operate on
-    /// the honor system :)
+    /// Identifies the user making this request.
     #[prost(message, optional, tag = "2")]
     pub player_id: ::core::option::Option<PlayerIdentifier>,
     /// Interface panels which were open at the time of the action, to be
     /// updated.
     #[prost(message, repeated, tag = "3")]
     pub open_panels: ::prost::alloc::vec::Vec<InterfacePanelAddress>,
+    /// Session token previously returned from the 'Authenticate' call for
+    /// this user.
+    #[prost(string, tag = "4")]
+    pub session_token: ::prost::alloc::string::String,
+    /// Identifies the client instance making this request. Responses are also
+    /// sent to any other connected clients for this user.
+    #[prost(string, tag = "5")]
+    pub client_id: ::prost::alloc::string::String,
+}
+/// Requests a new session token for the provided player.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuthenticateRequest {
+    #[prost(message, optional, tag = "1")]
+    pub player_id: ::core::option::Option<PlayerIdentifier>,
+    /// Secret previously returned from the first 'Authenticate' call for this
+    /// player. Required for every call after the first.
+    #[prost(string, tag = "2")]
+    pub player_secret: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuthenticateResponse {
+    /// Opaque token which must be included in subsequent requests from this
+    /// player. Expires after a fixed period, after which the client should
+    /// authenticate again.
+    #[prost(string, tag = "1")]
+    pub session_token: ::prost::alloc::string::String,
+    /// Secret which the client must store and include in future
+    /// 'Authenticate' calls. Only populated by the first call for a new
+    /// player.
+    #[prost(string, tag = "2")]
+    pub player_secret: ::prost::alloc::string::String,
 }
 // ============================================================================
 // Commands
@@ -1097,6 +1287,10 @@ pub struct InterfacePanel {
     /// RenderScreenOverlayCommand.
     #[prost(message, optional, tag = "3")]
     pub screen_overlay: ::core::option::Option<Node>,
+    /// If present, 'node' is omitted and these patches should instead be
+    /// applied to the most recent node received for this panel.
+    #[prost(message, optional, tag = "4")]
+    pub node_patches: ::core::option::Option<NodePatchList>,
 }
 /// Requests that a specific corner of a Node be anchored to a specific
 /// corner of a card.
@@ -1128,6 +1322,10 @@ pub struct InterfaceMainControls {
     /// Controls for specific cards
     #[prost(message, repeated, tag = "3")]
     pub card_anchor_nodes: ::prost::alloc::vec::Vec<CardAnchorNode>,
+    /// If present, 'node' is omitted and these patches should instead be
+    /// applied to the most recent main controls node received.
+    #[prost(message, optional, tag = "4")]
+    pub node_patches: ::core::option::Option<NodePatchList>,
 }
 /// Updates the contents of one or more user interface panels
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1373,6 +1571,29 @@ pub struct SetGameObjectsEnabledCommand {
     #[prost(bool, tag = "1")]
     pub game_objects_enabled: bool,
 }
+/// Briefly displays a notification on top of all other interface elements.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ShowToastCommand {
+    /// Content of the notification.
+    #[prost(message, optional, tag = "1")]
+    pub content: ::core::option::Option<Node>,
+    /// How long to display the notification for before hiding it.
+    #[prost(message, optional, tag = "2")]
+    pub duration: ::core::option::Option<TimeValue>,
+}
+/// Displays a short message sent by a player, such as an emote.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DisplayEmoteCommand {
+    /// Player who sent this message.
+    #[prost(enumeration = "PlayerName", tag = "1")]
+    pub player: i32,
+    /// Content of the message.
+    #[prost(message, optional, tag = "2")]
+    pub content: ::core::option::Option<Node>,
+    /// How long to display the message for before hiding it.
+    #[prost(message, optional, tag = "3")]
+    pub duration: ::core::option::Option<TimeValue>,
+}
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DisplayRewardsCommand {
     #[prost(message, repeated, tag = "1")]
@@ -1398,6 +1619,23 @@ pub struct SetBooleanPreference {
     #[prost(bool, tag = "2")]
     pub value: bool,
 }
+/// Sets a client-side float player preference
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetFloatPreference {
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(float, tag = "2")]
+    pub value: f32,
+}
+/// Overwrites client-side player preferences with values stored on the server,
+/// so that settings are shared across all of a player's devices.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdatePreferencesCommand {
+    #[prost(message, repeated, tag = "1")]
+    pub float_preferences: ::prost::alloc::vec::Vec<SetFloatPreference>,
+    #[prost(message, repeated, tag = "2")]
+    pub boolean_preferences: ::prost::alloc::vec::Vec<SetBooleanPreference>,
+}
 /// Logs a client message
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LogMessage {
@@ -1671,11 +1909,30 @@ pub struct ConditionalCommand {
     #[prost(message, optional, tag = "3")]
     pub if_false: ::core::option::Option<CommandList>,
 }
+/// An arrow drawn between two game objects, e.g. from a weapon to the minion
+/// it can be used against.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DisplayedArrow {
+    #[prost(message, optional, tag = "1")]
+    pub source: ::core::option::Option<GameObjectIdentifier>,
+    #[prost(message, optional, tag = "2")]
+    pub target: ::core::option::Option<GameObjectIdentifier>,
+    #[prost(enumeration = "TargetingArrow", tag = "3")]
+    pub color: i32,
+}
+/// Displays arrows between game objects, e.g. to show which weapons can be
+/// used during an encounter. Arrows remain visible until the next
+/// UpdateGameViewCommand is received.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DisplayArrowsCommand {
+    #[prost(message, repeated, tag = "1")]
+    pub arrows: ::prost::alloc::vec::Vec<DisplayedArrow>,
+}
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GameCommand {
     #[prost(
         oneof = "game_command::Command",
-        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 18, 19, 20, 21"
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 18, 19, 20, 21, 22, 23, 24, 25"
     )]
     pub command: ::core::option::Option<game_command::Command>,
 }
@@ -1723,6 +1980,14 @@ pub mod game_command {
         UpdateInterface(super::UpdateInterfaceCommand),
         #[prost(message, tag = "21")]
         Conditional(super::ConditionalCommand),
+        #[prost(message, tag = "22")]
+        ShowToast(super::ShowToastCommand),
+        #[prost(message, tag = "23")]
+        DisplayEmote(super::DisplayEmoteCommand),
+        #[prost(message, tag = "24")]
+        UpdatePreferences(super::UpdatePreferencesCommand),
+        #[prost(message, tag = "25")]
+        DisplayArrows(super::DisplayArrowsCommand),
     }
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1730,6 +1995,137 @@ pub struct CommandList {
     #[prost(message, repeated, tag = "1")]
     pub commands: ::prost::alloc::vec::Vec<GameCommand>,
 }
+/// Describes why a request failed. Sent as the status details of an error
+/// response.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ServerError {
+    #[prost(enumeration = "ErrorCode", tag = "1")]
+    pub code: i32,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+}
+/// Requests the static definitions of every card, independent of any game in
+/// progress.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FetchCardCatalogRequest {}
+/// Static metadata describing a single card definition, suitable for caching
+/// by clients and external tools.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CatalogCard {
+    /// Stable identifier for this card, matching the server's card name.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// Name to display to the user.
+    #[prost(string, tag = "2")]
+    pub displayed_name: ::prost::alloc::string::String,
+    /// Base mana cost to play this card, if any.
+    #[prost(uint32, optional, tag = "3")]
+    pub mana_cost: ::core::option::Option<u32>,
+    /// Base action point cost to play this card.
+    #[prost(uint32, tag = "4")]
+    pub action_cost: u32,
+    #[prost(string, tag = "5")]
+    pub card_type: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub school: ::prost::alloc::string::String,
+    #[prost(string, tag = "7")]
+    pub rarity: ::prost::alloc::string::String,
+    #[prost(enumeration = "PlayerSide", tag = "8")]
+    pub side: i32,
+    /// Rules text rendered outside of any game context.
+    #[prost(message, optional, tag = "9")]
+    pub rules_text: ::core::option::Option<RulesText>,
+    /// Icons rendered outside of any game context.
+    #[prost(message, optional, tag = "10")]
+    pub card_icons: ::core::option::Option<CardIcons>,
+    /// Card art.
+    #[prost(message, optional, tag = "11")]
+    pub image: ::core::option::Option<SpriteAddress>,
+    #[prost(message, repeated, tag = "12")]
+    pub keyword_tooltips: ::prost::alloc::vec::Vec<KeywordTooltip>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CardCatalog {
+    #[prost(message, repeated, tag = "1")]
+    pub cards: ::prost::alloc::vec::Vec<CatalogCard>,
+}
+/// Requests one page of the ranked leaderboard, ordered from highest to lowest
+/// rating.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FetchLeaderboardRequest {
+    /// Index of the first entry to return.
+    #[prost(uint32, tag = "1")]
+    pub offset: u32,
+    /// Maximum number of entries to return. The server's default page size is
+    /// used if this is zero.
+    #[prost(uint32, tag = "2")]
+    pub limit: u32,
+}
+/// A player's position on the ranked leaderboard.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RankedPlayer {
+    /// Position on the leaderboard, starting from 1.
+    #[prost(uint32, tag = "1")]
+    pub rank: u32,
+    /// Name to display for this player.
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(int32, tag = "3")]
+    pub rating: i32,
+    #[prost(uint32, tag = "4")]
+    pub games_played: u32,
+    #[prost(uint32, tag = "5")]
+    pub wins: u32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LeaderboardPage {
+    #[prost(message, repeated, tag = "1")]
+    pub players: ::prost::alloc::vec::Vec<RankedPlayer>,
+    /// Total number of rated players across all pages.
+    #[prost(uint32, tag = "2")]
+    pub total_count: u32,
+}
+/// The command stream sent to one player during a scripted scenario, recorded
+/// by a server integration test for playback in client visual tests.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ScenarioRecording {
+    /// Version of the recording format. Recordings written with a different
+    /// version than the current one must be regenerated.
+    #[prost(uint32, tag = "1")]
+    pub format_version: u32,
+    /// Unique name for this recording, e.g. "complete_raid_overlord".
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    /// Human-readable explanation of the recorded scenario.
+    #[prost(string, tag = "3")]
+    pub description: ::prost::alloc::string::String,
+    /// Hash of the card definitions in use when this recording was made. Can
+    /// be compared to the current hash to detect stale recordings.
+    #[prost(string, tag = "4")]
+    pub card_set_hash: ::prost::alloc::string::String,
+    /// Player whose commands were recorded.
+    #[prost(enumeration = "PlayerSide", tag = "5")]
+    pub side: i32,
+    /// Commands sent to this player, in order.
+    #[prost(message, optional, tag = "6")]
+    pub commands: ::core::option::Option<CommandList>,
+}
+/// Requests recorded scenarios by name.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FetchRecordingsRequest {
+    /// Names of recordings to fetch. If empty, every available recording is
+    /// returned.
+    #[prost(string, repeated, tag = "1")]
+    pub names: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RecordingList {
+    #[prost(message, repeated, tag = "1")]
+    pub recordings: ::prost::alloc::vec::Vec<ScenarioRecording>,
+    /// Hash of the server's current card definitions.
+    #[prost(string, tag = "2")]
+    pub card_set_hash: ::prost::alloc::string::String,
+}
 // ============================================================================
 // Masonry
 // ============================================================================
@@ -2010,6 +2406,7 @@ pub enum ClientItemLocation {
     Unspecified = 0,
     Left = 1,
     Right = 2,
+    Allies = 3,
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -2099,6 +2496,22 @@ pub enum MapTileType {
     /// Player cannot enter this tile but can click to walk adjacent to it
     Visitable = 3,
 }
+/// Category of a failed request
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ErrorCode {
+    Unspecified = 0,
+    /// The requested action is not permitted in the current game state
+    IllegalAction = 1,
+    /// The requested action can only be taken during the player's own turn
+    NotYourTurn = 2,
+    /// The player does not have enough mana for the requested action
+    InsufficientMana = 3,
+    /// The selected deck cannot be used to play a game
+    DeckInvalid = 4,
+    /// An unexpected server error
+    Internal = 5,
+}
 /// Generated server implementations.
 pub mod spelldawn_server {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
@@ -2121,6 +2534,26 @@ pub mod spelldawn_server {
             &self,
             request: tonic::Request<super::GameRequest>,
         ) -> Result<tonic::Response<super::CommandList>, tonic::Status>;
+        /// Issue a session token for a player.
+        async fn authenticate(
+            &self,
+            request: tonic::Request<super::AuthenticateRequest>,
+        ) -> Result<tonic::Response<super::AuthenticateResponse>, tonic::Status>;
+        /// Fetch static metadata for every card in the game.
+        async fn fetch_card_catalog(
+            &self,
+            request: tonic::Request<super::FetchCardCatalogRequest>,
+        ) -> Result<tonic::Response<super::CardCatalog>, tonic::Status>;
+        /// Fetch one page of the ranked leaderboard.
+        async fn fetch_leaderboard(
+            &self,
+            request: tonic::Request<super::FetchLeaderboardRequest>,
+        ) -> Result<tonic::Response<super::LeaderboardPage>, tonic::Status>;
+        /// Fetch scenario recordings for client visual tests.
+        async fn fetch_recordings(
+            &self,
+            request: tonic::Request<super::FetchRecordingsRequest>,
+        ) -> Result<tonic::Response<super::RecordingList>, tonic::Status>;
     }
     #[derive(Debug)]
     pub struct SpelldawnServer<T: Spelldawn> {
@@ -2247,6 +2680,140 @@ pub mod spelldawn_server {
                     };
                     Box::pin(fut)
                 }
+                "/spelldawn.Spelldawn/Authenticate" => {
+                    #[allow(non_camel_case_types)]
+                    struct AuthenticateSvc<T: Spelldawn>(pub Arc<T>);
+                    impl<T: Spelldawn> tonic::server::UnaryService<super::AuthenticateRequest> for AuthenticateSvc<T> {
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        type Response = super::AuthenticateResponse;
+
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AuthenticateRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).authenticate(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AuthenticateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/spelldawn.Spelldawn/FetchCardCatalog" => {
+                    #[allow(non_camel_case_types)]
+                    struct FetchCardCatalogSvc<T: Spelldawn>(pub Arc<T>);
+                    impl<T: Spelldawn> tonic::server::UnaryService<super::FetchCardCatalogRequest>
+                        for FetchCardCatalogSvc<T>
+                    {
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        type Response = super::CardCatalog;
+
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FetchCardCatalogRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).fetch_card_catalog(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = FetchCardCatalogSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/spelldawn.Spelldawn/FetchLeaderboard" => {
+                    #[allow(non_camel_case_types)]
+                    struct FetchLeaderboardSvc<T: Spelldawn>(pub Arc<T>);
+                    impl<T: Spelldawn> tonic::server::UnaryService<super::FetchLeaderboardRequest>
+                        for FetchLeaderboardSvc<T>
+                    {
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        type Response = super::LeaderboardPage;
+
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FetchLeaderboardRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).fetch_leaderboard(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = FetchLeaderboardSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/spelldawn.Spelldawn/FetchRecordings" => {
+                    #[allow(non_camel_case_types)]
+                    struct FetchRecordingsSvc<T: Spelldawn>(pub Arc<T>);
+                    impl<T: Spelldawn> tonic::server::UnaryService<super::FetchRecordingsRequest>
+                        for FetchRecordingsSvc<T>
+                    {
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        type Response = super::RecordingList;
+
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FetchRecordingsRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).fetch_recordings(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = FetchRecordingsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec).apply_compression_config(
+                            accept_compression_encodings,
+                            send_compression_encodings,
+                        );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => Box::pin(async move {
                     Ok(http::Response::builder()
                         .status(200)
@@ -0,0 +1,210 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Definitions for puzzles, pre-built game situations with a specific goal.
+//! See [PuzzleId] for the list of available puzzles.
+
+use anyhow::Result;
+use cards::decklists;
+use data::card_name::CardName;
+use data::card_state::{CardPosition, CardPositionKind};
+use data::game::{GameConfiguration, GamePhase, GameState, TurnData, TurnStep};
+use data::game_actions::{CardTarget, GameAction};
+use data::player_name::{NamedPlayer, PlayerId};
+use data::primitives::{ActionCount, CardId, GameId, ManaValue, PointsValue, RoomId, Side};
+use data::puzzles::{PuzzleConstraint, PuzzleGoal, PuzzleId};
+use rules::{dispatch, mana, mutations, queries};
+use with_error::{fail, WithError};
+
+/// A card which starts a puzzle in play
+#[derive(Debug, Clone)]
+pub struct PuzzleCard {
+    pub name: CardName,
+    /// Room to play this card into, for cards which are played into rooms
+    pub room_id: Option<RoomId>,
+    pub card_level: u32,
+    pub face_up: bool,
+}
+
+impl PuzzleCard {
+    pub fn new(name: CardName) -> Self {
+        Self { name, room_id: None, card_level: 0, face_up: false }
+    }
+}
+
+/// Initial state for one player in a puzzle
+#[derive(Debug, Clone, Default)]
+pub struct PuzzlePlayer {
+    pub mana: ManaValue,
+    pub actions: ActionCount,
+    pub score: PointsValue,
+    pub hand: Vec<CardName>,
+    pub in_play: Vec<PuzzleCard>,
+}
+
+/// Describes the initial state, goal, and rules for a puzzle.
+#[derive(Debug, Clone)]
+pub struct PuzzleDefinition {
+    pub id: PuzzleId,
+    /// Side the player solving this puzzle plays as
+    pub side: Side,
+    pub goal: PuzzleGoal,
+    pub constraints: Vec<PuzzleConstraint>,
+    /// Initial state for the player solving this puzzle
+    pub user: PuzzlePlayer,
+    /// Initial state for the opponent, who takes no actions
+    pub opponent: PuzzlePlayer,
+}
+
+/// Looks up the definition for a puzzle
+pub fn get(id: PuzzleId) -> PuzzleDefinition {
+    match id {
+        PuzzleId::FinalScheme => PuzzleDefinition {
+            id,
+            side: Side::Overlord,
+            goal: PuzzleGoal::WinWithinTurns(1),
+            constraints: vec![],
+            user: PuzzlePlayer {
+                mana: 2,
+                actions: 2,
+                score: 5,
+                hand: vec![],
+                in_play: vec![PuzzleCard {
+                    room_id: Some(RoomId::RoomA),
+                    card_level: 2,
+                    ..PuzzleCard::new(CardName::GoldMine)
+                }],
+            },
+            opponent: PuzzlePlayer::default(),
+        },
+        PuzzleId::DesperateRaid => PuzzleDefinition {
+            id,
+            side: Side::Champion,
+            goal: PuzzleGoal::WinWithinTurns(1),
+            constraints: vec![PuzzleConstraint::NoDrawCards],
+            user: PuzzlePlayer { mana: 0, actions: 1, score: 5, ..PuzzlePlayer::default() },
+            opponent: PuzzlePlayer {
+                in_play: vec![PuzzleCard {
+                    room_id: Some(RoomId::RoomA),
+                    ..PuzzleCard::new(CardName::GoldMine)
+                }],
+                ..PuzzlePlayer::default()
+            },
+        },
+    }
+}
+
+/// Creates a new game set up as the indicated puzzle, with the `player_id`
+/// player solving the puzzle against an opponent who takes no actions. The
+/// game begins in the main phase of the player's first turn.
+pub fn new_game(game_id: GameId, player_id: PlayerId, id: PuzzleId) -> Result<GameState> {
+    let puzzle = get(id);
+    let opponent_id = PlayerId::Named(NamedPlayer::TestNoAction);
+    let mut user_deck = decklists::canonical_deck(player_id, puzzle.side);
+    let mut opponent_deck = decklists::canonical_deck(opponent_id, puzzle.side.opponent());
+    for (deck, player) in [(&mut user_deck, &puzzle.user), (&mut opponent_deck, &puzzle.opponent)] {
+        let names = player.hand.iter().copied().chain(player.in_play.iter().map(|c| c.name));
+        for name in names {
+            *deck.cards.entry(name).or_insert(0) += 1;
+        }
+    }
+
+    let (overlord_deck, champion_deck) = match puzzle.side {
+        Side::Overlord => (user_deck, opponent_deck),
+        Side::Champion => (opponent_deck, user_deck),
+    };
+    let mut game = GameState::new(
+        game_id,
+        overlord_deck,
+        champion_deck,
+        GameConfiguration { deterministic: true, puzzle: Some(id), ..GameConfiguration::default() },
+    );
+    dispatch::populate_delegate_cache(&mut game);
+
+    game.data.phase = GamePhase::Play;
+    game.data.turn = TurnData { side: puzzle.side, turn_number: 0 };
+    game.data.turn_step = TurnStep::Main;
+    setup_player(&mut game, puzzle.side, &puzzle.user)?;
+    setup_player(&mut game, puzzle.side.opponent(), &puzzle.opponent)?;
+    Ok(game)
+}
+
+/// Returns an error if the `side` player is not permitted to take `action` due
+/// to the constraints of the puzzle this game was created from, if any.
+pub fn check_action(game: &GameState, side: Side, action: GameAction) -> Result<()> {
+    let Some(id) = game.data.config.puzzle else { return Ok(()) };
+    let puzzle = get(id);
+    if side != puzzle.side {
+        return Ok(());
+    }
+
+    for constraint in puzzle.constraints {
+        match (constraint, action) {
+            (PuzzleConstraint::NoDrawCards, GameAction::DrawCard)
+            | (PuzzleConstraint::NoGainMana, GameAction::GainMana) => {
+                fail!("{}", constraint.description())
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Ends the game as a loss for the puzzle solver if this game was created from
+/// a puzzle and its goal can no longer be achieved.
+pub fn check_goal(game: &mut GameState) -> Result<()> {
+    let Some(id) = game.data.config.puzzle else { return Ok(()) };
+    let puzzle = get(id);
+    if !matches!(game.data.phase, GamePhase::GameOver { .. })
+        && puzzle.goal.is_expired(puzzle.side, game.data.turn)
+    {
+        mutations::game_over(game, puzzle.side.opponent())?;
+    }
+    Ok(())
+}
+
+fn setup_player(game: &mut GameState, side: Side, player: &PuzzlePlayer) -> Result<()> {
+    mana::set(game, side, player.mana);
+    game.player_mut(side).actions = player.actions;
+    game.player_mut(side).score = player.score;
+
+    for name in &player.hand {
+        let card_id = find_in_deck(game, side, *name)?;
+        game.move_card_internal(card_id, CardPosition::Hand(side));
+        game.card_mut(card_id).set_revealed_to(side, true);
+    }
+
+    for card in &player.in_play {
+        let card_id = find_in_deck(game, side, card.name)?;
+        let target = card.room_id.map_or(CardTarget::None, CardTarget::Room);
+        let position = queries::played_position(game, side, card_id, target)?;
+        game.move_card_internal(card_id, position);
+        let state = game.card_mut(card_id);
+        state.data.card_level = card.card_level;
+        state.set_revealed_to(side, true);
+        if card.face_up {
+            state.turn_face_up();
+        }
+    }
+    Ok(())
+}
+
+/// Finds a card with the given name in the `side` player's deck
+fn find_in_deck(game: &GameState, side: Side, name: CardName) -> Result<CardId> {
+    game.cards(side)
+        .iter()
+        .find(|c| c.name == name && c.position().kind() == CardPositionKind::DeckUnknown)
+        .map(|c| c.id)
+        .with_error(|| format!("Card not found in deck: {:?}", name))
+}
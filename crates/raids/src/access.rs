@@ -17,16 +17,18 @@ use std::iter;
 use anyhow::Result;
 use data::card_state::CardPosition;
 use data::delegates::{
-    CardAccessEvent, ChampionScoreCardEvent, RaidAccessStartEvent, RaidOutcome, ScoreCard,
-    ScoreCardEvent,
+    CardAccessEvent, ChampionScoreCardEvent, RaidAccessStartEvent, RaidOutcome, RaidStep,
+    ReplaceAccessEvent, ScoreCard, ScoreCardEvent,
 };
 use data::game::{GameState, InternalRaidPhase};
 use data::game_actions::{AccessPhaseAction, PromptAction};
+use data::history::HistoryEvent;
+use data::player_data::AutomationPreferences;
 use data::primitives::{CardId, CardType, RoomId, Side};
 use data::random;
 use data::updates::GameUpdate;
 use rules::mana::ManaPurpose;
-use rules::{dispatch, mana, mutations, queries};
+use rules::{dispatch, flags, mana, mutations, queries};
 use with_error::{fail, WithError};
 
 use crate::traits::{RaidDisplayState, RaidPhaseImpl};
@@ -50,16 +52,35 @@ impl RaidPhaseImpl for AccessPhase {
     }
 
     fn enter(self, game: &mut GameState) -> Result<Option<InternalRaidPhase>> {
+        if !mutations::before_raid_step(game, RaidStep::AccessStart)? {
+            return Ok(None);
+        }
+
         dispatch::invoke_event(game, RaidAccessStartEvent(game.raid()?.raid_id))?;
-        if game.data.raid.is_none() {
+        if !mutations::after_raid_step(game, RaidStep::AccessStart)? {
+            return Ok(None);
+        }
+
+        let raid_id = game.raid()?.raid_id;
+        if flags::should_replace_access(game, raid_id) {
+            dispatch::invoke_event(game, ReplaceAccessEvent(raid_id))?;
+            if game.data.raid.is_some() {
+                mutations::end_raid(game, RaidOutcome::Success)?;
+            }
             return Ok(None);
         }
 
         let accessed = accessed_cards(game)?;
         game.raid_mut()?.accessed = accessed.clone();
 
-        for card_id in &accessed {
-            dispatch::invoke_event(game, CardAccessEvent(*card_id))?;
+        for card_id in accessed {
+            if !mutations::before_raid_step(game, RaidStep::AccessCard(card_id))? {
+                return Ok(None);
+            }
+            dispatch::invoke_event(game, CardAccessEvent(card_id))?;
+            if !mutations::after_raid_step(game, RaidStep::AccessCard(card_id))? {
+                return Ok(None);
+            }
         }
 
         Ok(None)
@@ -75,6 +96,18 @@ impl RaidPhaseImpl for AccessPhase {
             .collect())
     }
 
+    fn automatic_action(
+        self,
+        game: &GameState,
+        preferences: AutomationPreferences,
+    ) -> Result<Option<AccessPhaseAction>> {
+        let stop = preferences.always_stop_at_access && !game.raid()?.accessed.is_empty();
+        Ok((preferences.auto_end_raid
+            && !stop
+            && self.actions(game)? == vec![AccessPhaseAction::EndRaid])
+        .then_some(AccessPhaseAction::EndRaid))
+    }
+
     fn handle_action(
         self,
         game: &mut GameState,
@@ -120,9 +153,15 @@ fn accessed_cards(game: &mut GameState) -> Result<Vec<CardId>> {
             )
         }
         RoomId::Crypts => {
-            game.card_list_for_position(Side::Overlord, CardPosition::DiscardPile(Side::Overlord))
+            let count = queries::crypts_access_count(game)? as usize;
+            let crypts = game
+                .card_list_for_position(Side::Overlord, CardPosition::DiscardPile(Side::Overlord));
+            crypts[crypts.len().saturating_sub(count)..].to_vec()
+        }
+        _ => {
+            let count = queries::room_access_count(game)? as usize;
+            game.occupants(target).map(|c| c.id).take(count).collect()
         }
-        _ => game.occupants(target).map(|c| c.id).collect(),
     };
 
     for card_id in &accessed {
@@ -172,6 +211,7 @@ fn handle_score_card(game: &mut GameState, card_id: CardId) -> Result<()> {
     mutations::move_card(game, card_id, CardPosition::Scoring)?;
     game.raid_mut()?.accessed.retain(|c| *c != card_id);
 
+    game.add_history_event(HistoryEvent::ScoreCard(Side::Champion, card_id));
     game.record_update(|| GameUpdate::ScoreCard(Side::Champion, card_id));
 
     dispatch::invoke_event(game, ChampionScoreCardEvent(card_id))?;
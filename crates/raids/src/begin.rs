@@ -13,11 +13,11 @@
 // limitations under the License.
 
 use anyhow::Result;
-use data::delegates::{RaidStart, RaidStartEvent};
+use data::delegates::{RaidStart, RaidStartEvent, RaidStep};
 use data::game::{GameState, InternalRaidPhase};
 use data::game_actions::PromptAction;
 use data::primitives::Side;
-use rules::dispatch;
+use rules::{dispatch, mutations};
 use with_error::fail;
 
 use crate::defenders;
@@ -40,6 +40,10 @@ impl RaidPhaseImpl for BeginPhase {
     }
 
     fn enter(self, game: &mut GameState) -> Result<Option<InternalRaidPhase>> {
+        if !mutations::before_raid_step(game, RaidStep::Begin)? {
+            return Ok(None);
+        }
+
         dispatch::invoke_event(
             game,
             RaidStartEvent(RaidStart {
@@ -48,7 +52,7 @@ impl RaidPhaseImpl for BeginPhase {
             }),
         )?;
 
-        if game.data.raid.is_none() {
+        if !mutations::after_raid_step(game, RaidStep::Begin)? {
             return Ok(None);
         }
 
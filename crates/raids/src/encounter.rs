@@ -12,18 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::iter;
+
 use anyhow::Result;
+use data::card_definition::AbilityType;
 use data::delegates::{
-    EncounterMinionEvent, MinionCombatAbilityEvent, MinionCombatActionsQuery, MinionDefeatedEvent,
-    UsedWeapon, UsedWeaponEvent,
+    CombinedWeapons, EncounterMinionEvent, MinionCombatAbilityEvent, MinionCombatActionsQuery,
+    MinionDefeatedEvent, RaidStep, UsedWeapon, UsedWeaponEvent,
 };
 use data::game::{GameState, InternalRaidPhase};
-use data::game_actions::{EncounterAction, PromptAction};
-use data::primitives::{CardId, GameObjectId, Side};
+use data::game_actions::{EncounterAction, GamePrompt, PromptAction};
+use data::player_data::AutomationPreferences;
+use data::primitives::{CardId, GameObjectId, ManaValue, Side};
 use data::updates::{GameUpdate, TargetedInteraction};
 use rules::mana::ManaPurpose;
 use rules::{card_prompt, dispatch, flags, mana, mutations, queries};
-use with_error::{fail, WithError};
+use with_error::{fail, verify, WithError};
 
 use crate::defenders;
 use crate::mutations::SummonMinion;
@@ -49,14 +53,25 @@ impl RaidPhaseImpl for EncounterPhase {
     }
 
     fn enter(self, game: &mut GameState) -> Result<Option<InternalRaidPhase>> {
-        if defenders::can_summon_defender(game, game.raid_defender()?)? {
-            mutations::summon_minion(game, game.raid_defender()?, SummonMinion::PayCosts)?;
+        let defender_id = game.raid_defender()?;
+        if !mutations::before_raid_step(game, RaidStep::ApproachDefender(defender_id))? {
+            return Ok(None);
+        }
+
+        if defenders::can_summon_defender(game, defender_id)? {
+            mutations::summon_minion(game, defender_id, SummonMinion::PayCosts)?;
             if game.data.raid.is_none() {
                 return Ok(None);
             }
         }
 
-        dispatch::invoke_event(game, EncounterMinionEvent(game.raid_defender()?))?;
+        if !mutations::after_raid_step(game, RaidStep::ApproachDefender(defender_id))?
+            || !mutations::before_raid_step(game, RaidStep::EncounterDefender(defender_id))?
+        {
+            return Ok(None);
+        }
+
+        dispatch::invoke_event(game, EncounterMinionEvent(defender_id))?;
         Ok(None)
     }
 
@@ -66,38 +81,64 @@ impl RaidPhaseImpl for EncounterPhase {
             .weapons()
             .filter(|weapon| flags::can_defeat_target(game, weapon.id, defender_id))
             .map(|weapon| EncounterAction::UseWeaponAbility(weapon.id, defender_id))
+            .chain(combined_weapon_actions(game, defender_id))
+            .chain(encounter_ability_actions(game))
             .chain(minion_combat_actions(game, defender_id))
+            .chain(iter::once(EncounterAction::Retreat))
             .collect())
     }
 
+    fn automatic_action(
+        self,
+        game: &GameState,
+        preferences: AutomationPreferences,
+    ) -> Result<Option<EncounterAction>> {
+        if !preferences.auto_continue_encounters {
+            return Ok(None);
+        }
+
+        let actions = self.actions(game)?;
+        Ok((actions.contains(&EncounterAction::NoWeapon)
+            && actions
+                .iter()
+                .all(|a| matches!(a, EncounterAction::NoWeapon | EncounterAction::Retreat)))
+        .then_some(EncounterAction::NoWeapon))
+    }
+
     fn handle_action(
         self,
         game: &mut GameState,
         action: EncounterAction,
     ) -> Result<Option<InternalRaidPhase>> {
+        let encountered = game.raid_defender()?;
         match action {
+            EncounterAction::Retreat => {
+                game.player_mut(Side::Champion).prompt = Some(GamePrompt::confirm_retreat());
+                return Ok(None);
+            }
+            EncounterAction::ActivateAbility(ability_id, weapon_id) => {
+                mutations::activate_encounter_ability(game, ability_id, weapon_id)?;
+                return Ok(None);
+            }
             EncounterAction::UseWeaponAbility(source_id, target_id) => {
                 let cost = queries::cost_to_defeat_target(game, source_id, target_id).with_error(
                     || format!("{:?} cannot defeat target: {:?}", source_id, target_id),
                 )?;
-                mana::spend(game, Side::Champion, ManaPurpose::UseWeapon(source_id), cost)?;
-
-                game.record_update(|| {
-                    GameUpdate::TargetedInteraction(TargetedInteraction {
-                        source: GameObjectId::CardId(source_id),
-                        target: GameObjectId::CardId(target_id),
-                    })
-                });
-
-                dispatch::invoke_event(
-                    game,
-                    UsedWeaponEvent(UsedWeapon {
-                        raid_id: game.raid()?.raid_id,
-                        weapon_id: source_id,
-                        target_id,
-                        mana_spent: cost,
-                    }),
-                )?;
+                use_weapon(game, source_id, target_id, cost)?;
+                dispatch::invoke_event(game, MinionDefeatedEvent(target_id))?;
+            }
+            EncounterAction::UseCombinedWeapons(first_id, second_id, target_id) => {
+                let weapons = CombinedWeapons::new(first_id, second_id, target_id);
+                verify!(
+                    flags::can_defeat_target_combined(game, weapons),
+                    "Cannot combine weapons {:?}",
+                    weapons
+                );
+                let (first_cost, second_cost) =
+                    queries::combined_cost_to_defeat_target(game, weapons)
+                        .with_error(|| format!("Weapons cannot defeat target: {:?}", weapons))?;
+                use_weapon(game, first_id, target_id, first_cost)?;
+                use_weapon(game, second_id, target_id, second_cost)?;
                 dispatch::invoke_event(game, MinionDefeatedEvent(target_id))?;
             }
             EncounterAction::NoWeapon | EncounterAction::CardAction(_) => {
@@ -117,6 +158,9 @@ impl RaidPhaseImpl for EncounterPhase {
             card_prompt::handle(game, Side::Champion, card_action)?;
         }
 
+        mutations::clear_encounter_weapons(game);
+        mutations::after_raid_step(game, RaidStep::EncounterDefender(encountered))?;
+
         Ok(if game.data.raid.is_none() {
             // Abilities may have ended the raid
             None
@@ -140,6 +184,87 @@ impl RaidPhaseImpl for EncounterPhase {
     }
 }
 
+/// Spends `cost` mana to use the `weapon_id` weapon against `target_id`, fires
+/// the [UsedWeaponEvent], and spends durability for weapons with limited uses.
+fn use_weapon(
+    game: &mut GameState,
+    weapon_id: CardId,
+    target_id: CardId,
+    cost: ManaValue,
+) -> Result<()> {
+    mana::spend(game, Side::Champion, ManaPurpose::UseWeapon(weapon_id), cost)?;
+
+    game.record_update(|| {
+        GameUpdate::TargetedInteraction(TargetedInteraction {
+            source: GameObjectId::CardId(weapon_id),
+            target: GameObjectId::CardId(target_id),
+        })
+    });
+
+    let used = UsedWeapon { raid_id: game.raid()?.raid_id, weapon_id, target_id, mana_spent: cost };
+    dispatch::invoke_event(game, UsedWeaponEvent(used))?;
+    mutations::spend_durability(game, used)
+}
+
+/// Actions to use two weapons together against `minion_id`.
+///
+/// A pair is only offered if combining the weapons is cheaper than using
+/// either weapon on its own.
+fn combined_weapon_actions(game: &GameState, minion_id: CardId) -> Vec<EncounterAction> {
+    let single_cost = |weapon_id| {
+        flags::can_defeat_target(game, weapon_id, minion_id)
+            .then(|| queries::cost_to_defeat_target(game, weapon_id, minion_id))
+            .flatten()
+    };
+    let mut weapons = game.weapons().map(|weapon| weapon.id).collect::<Vec<_>>();
+    weapons.sort();
+    weapons
+        .iter()
+        .enumerate()
+        .flat_map(|(i, first)| weapons[i + 1..].iter().map(move |second| (*first, *second)))
+        .map(|(first, second)| CombinedWeapons::new(first, second, minion_id))
+        .filter(|weapons| flags::can_defeat_target_combined(game, *weapons))
+        .filter(|weapons| {
+            let Some((first, second)) = queries::combined_cost_to_defeat_target(game, *weapons)
+            else {
+                return false;
+            };
+            [weapons.first, weapons.second]
+                .into_iter()
+                .filter_map(single_cost)
+                .all(|single| first + second < single)
+        })
+        .map(|weapons| {
+            EncounterAction::UseCombinedWeapons(weapons.first, weapons.second, minion_id)
+        })
+        .collect()
+}
+
+/// Actions to activate each of the Champion's encounter abilities targeting
+/// each of their weapons.
+fn encounter_ability_actions(game: &GameState) -> Vec<EncounterAction> {
+    game.artifacts()
+        .flat_map(|artifact| {
+            rules::card_definition(game, artifact.id)
+                .ability_ids(artifact.id)
+                .filter(|ability_id| {
+                    matches!(
+                        rules::ability_definition(game, *ability_id).ability_type,
+                        AbilityType::EncounterActivated(_)
+                    )
+                })
+                .collect::<Vec<_>>()
+        })
+        .flat_map(|ability_id| {
+            game.weapons()
+                .filter(move |weapon| {
+                    flags::can_activate_encounter_ability(game, ability_id, weapon.id)
+                })
+                .map(move |weapon| EncounterAction::ActivateAbility(ability_id, weapon.id))
+        })
+        .collect()
+}
+
 /// Actions to present when a minion is encountered in combat in addition to
 /// weapon abilities.
 fn minion_combat_actions(game: &GameState, minion_id: CardId) -> Vec<EncounterAction> {
@@ -22,12 +22,14 @@ mod defenders;
 mod encounter;
 
 use anyhow::Result;
-use data::game::{GameState, InternalRaidPhase, RaidData, RaidJumpRequest, RoomState};
+use data::game::{GamePhase, GameState, InternalRaidPhase, RaidData, RaidJumpRequest, RoomState};
 use data::game_actions::{GamePrompt, PromptAction};
+use data::history::HistoryEvent;
 use data::primitives::{RaidId, RoomId, Side};
 use data::updates::{GameUpdate, InitiatedBy};
-use rules::{flags, mutations, queries};
-use with_error::{verify, WithError};
+use rules::mana::ManaPurpose;
+use rules::{flags, mana, mutations, queries};
+use with_error::{verify_code, ErrorCode, WithError};
 
 use crate::access::AccessPhase;
 use crate::begin::BeginPhase;
@@ -50,19 +52,33 @@ impl RaidDataExt for RaidData {
     }
 }
 
-/// Handle a client request to initiate a new raid. Deducts action points and
-/// then invokes [initiate].
+/// Handle a client request to initiate a new raid. Pays the
+/// [queries::initiate_raid_cost] and then invokes [initiate].
 pub fn handle_initiate_action(
     game: &mut GameState,
     user_side: Side,
     target_room: RoomId,
 ) -> Result<()> {
-    verify!(
+    verify_code!(
+        !flags::is_opponents_turn(game, user_side),
+        ErrorCode::NotYourTurn,
+        "It is not the {:?} player's turn",
+        user_side
+    );
+    verify_code!(
         flags::can_take_initiate_raid_action(game, user_side, target_room),
+        ErrorCode::IllegalAction,
         "Cannot initiate raid for {:?}",
         user_side
     );
-    mutations::spend_action_points(game, user_side, 1)?;
+    let cost = queries::initiate_raid_cost(game, target_room);
+    mutations::spend_action_points(game, user_side, cost.actions)?;
+    if let Some(mana) = cost.mana {
+        mana::spend(game, user_side, ManaPurpose::InitiateRaid(target_room), mana)?;
+    }
+    if let Some(custom_cost) = &cost.custom_cost {
+        (custom_cost.pay)(game, target_room)?;
+    }
     initiate(game, target_room, InitiatedBy::GameAction, |_, _| {})
 }
 
@@ -92,19 +108,37 @@ pub fn initiate(
     game.room_state.entry(target_room).or_insert_with(RoomState::default).last_raided =
         Some(game.data.turn);
     on_begin(game, raid_id);
+    game.add_history_event(HistoryEvent::RaidBegin(target_room));
     game.record_update(|| GameUpdate::InitiateRaid(target_room, initiated_by));
     enter_phase(game, Some(phase))?;
 
     Ok(())
 }
 
+/// Initiates the next raid added via [mutations::queue_raid] if no raid is
+/// currently active.
+pub fn start_queued_raid(game: &mut GameState) -> Result<()> {
+    while game.data.raid.is_none()
+        && matches!(game.data.phase, GamePhase::Play)
+        && !game.data.queued_raids.is_empty()
+    {
+        let target = game.data.queued_raids.remove(0);
+        initiate(game, target, InitiatedBy::Card, |_, _| {})?;
+    }
+    Ok(())
+}
+
 /// Handles a [PromptAction] supplied by a user during a raid. Returns an error
 /// if no raid is currently active or if this action was not expected from this
 /// player.
 pub fn handle_action(game: &mut GameState, user_side: Side, action: PromptAction) -> Result<()> {
     let phase = game.raid()?.phase();
-    verify!(phase.active_side() == user_side, "Unexpected side");
-    verify!(phase.prompts(game)?.iter().any(|c| c == &action), "Unexpected action");
+    verify_code!(phase.active_side() == user_side, ErrorCode::IllegalAction, "Unexpected side");
+    verify_code!(
+        phase.prompts(game)?.iter().any(|c| c == &action),
+        ErrorCode::IllegalAction,
+        "Unexpected action"
+    );
     let mut new_state = phase.handle_prompt(game, action)?;
     new_state = apply_jump(game)?.or(new_state);
 
@@ -141,18 +175,44 @@ pub fn current_prompt(game: &GameState, user_side: Side) -> Result<Option<GamePr
 }
 
 /// Sets the gam eto a new raid phase and invokes callbacks as needed.
+///
+/// Once no further transition is requested, resolves any prompts which the
+/// active player has chosen to skip via their automation preferences.
 fn enter_phase(game: &mut GameState, mut phase: Option<InternalRaidPhase>) -> Result<()> {
     loop {
         if let Some(s) = phase {
             game.raid_mut()?.internal_phase = s;
             phase = game.raid()?.phase().enter(game)?;
             phase = apply_jump(game)?.or(phase);
+        } else if let Some(action) = automatic_action(game)? {
+            phase = game.raid()?.phase().handle_prompt(game, action)?;
+            phase = apply_jump(game)?.or(phase);
+            if game.data.raid.is_none() {
+                return Ok(());
+            }
         } else {
             return Ok(());
         }
     }
 }
 
+/// Returns an action to take on behalf of the active player in the current
+/// raid phase, if their automation preferences allow skipping its prompt.
+///
+/// Never returns an action while the player is facing a card prompt, such as a
+/// retreat confirmation.
+fn automatic_action(game: &GameState) -> Result<Option<PromptAction>> {
+    let Some(raid) = &game.data.raid else {
+        return Ok(None);
+    };
+    let phase = raid.phase();
+    let player = game.player(phase.active_side());
+    if player.prompt.is_some() {
+        return Ok(None);
+    }
+    phase.automatic_prompt(game, player.automation)
+}
+
 /// Implements a [RaidJumpRequest], if one has been specified for the current
 /// raid.
 fn apply_jump(game: &mut GameState) -> Result<Option<InternalRaidPhase>> {
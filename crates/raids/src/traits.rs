@@ -15,6 +15,7 @@
 use anyhow::Result;
 use data::game::{GameState, InternalRaidPhase};
 use data::game_actions::{PromptAction, PromptContext};
+use data::player_data::AutomationPreferences;
 use data::primitives::{CardId, Side};
 use data::utils;
 use fallible_iterator::FallibleIterator;
@@ -66,6 +67,15 @@ pub trait RaidPhase {
     /// Provides a list of possible user actions for the `active_side` player in
     /// the current phase.
     fn prompts(&self, game: &GameState) -> Result<Vec<PromptAction>>;
+
+    /// Returns one of the actions from `prompts` to take automatically on
+    /// behalf of the `active_side` player based on their `preferences`, or
+    /// None if they should be prompted as normal.
+    fn automatic_prompt(
+        &self,
+        game: &GameState,
+        preferences: AutomationPreferences,
+    ) -> Result<Option<PromptAction>>;
 }
 
 /// Strongly-typed implementation trait for [RaidPhase] which specified the type
@@ -102,6 +112,15 @@ pub trait RaidPhaseImpl: RaidPhase + Sized + Copy {
         None
     }
 
+    /// Strongly-typed equivalent of `automatic_prompt`.
+    fn automatic_action(
+        self,
+        _: &GameState,
+        _: AutomationPreferences,
+    ) -> Result<Option<Self::Action>> {
+        Ok(None)
+    }
+
     fn handle_prompt(
         self,
         game: &mut GameState,
@@ -143,4 +162,12 @@ impl<T: RaidPhaseImpl> RaidPhase for T {
     fn prompts(&self, game: &GameState) -> Result<Vec<PromptAction>> {
         RaidPhaseImpl::prompts(*self, game)
     }
+
+    fn automatic_prompt(
+        &self,
+        game: &GameState,
+        preferences: AutomationPreferences,
+    ) -> Result<Option<PromptAction>> {
+        RaidPhaseImpl::automatic_action(*self, game, preferences)?.map(Self::wrap).transpose()
+    }
 }
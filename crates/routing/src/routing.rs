@@ -15,42 +15,95 @@
 //! Panel rendering. A 'panel' is a discrete rectangular piece of UI which can
 //! be opened or closed by the user, such as a game menu or window.
 
+use std::collections::HashMap;
+
 use adventure_display::adventure_panels;
+use adventure_display::journal_panel::AdventureJournalPanel;
 use adventure_display::shop_panel::ShopPanel;
 use anyhow::Result;
+use dashmap::DashMap;
 use data::adventure::AdventureState;
 use data::player_data::PlayerData;
+use data::player_name::PlayerId;
+use data::primitives::DeckId;
+use deck_editor::cosmetics_panel::CosmeticsPanel;
 use deck_editor::deck_editor_panel::DeckEditorPanel;
 use deck_editor::deck_editor_prompt::DeckEditorPromptPanel;
+use old_deck_editor::crafting_panel::CraftingPanel;
 use old_deck_editor::deck_editor_panel::OldDeckEditorPanel;
 use old_deck_editor::pick_deck_name::PickDeckName;
 use old_deck_editor::pick_deck_school::PickDeckSchool;
 use old_deck_editor::pick_deck_side::PickDeckSide;
-use panel_address::{CreateDeckState, Panel, PanelAddress};
+use once_cell::sync::Lazy;
+use panel_address::{CreateDeckState, Panel, PanelAddress, PanelDependency};
 use panels::about_panel::AboutPanel;
+use panels::achievements_panel::AchievementsPanel;
 use panels::adventure_menu::AdventureMenu;
+use panels::confirmation_panel::ConfirmationPanel;
 use panels::debug_panel::DebugPanel;
 use panels::disclaimer_panel::DisclaimerPanel;
+use panels::draft_lobby_panel::DraftLobbyPanel;
+use panels::draft_pick_panel::DraftPickPanel;
+use panels::emote_menu::EmoteMenu;
 use panels::game_menu_panel::GameMenuPanel;
 use panels::game_over_panel::GameOverPanel;
 use panels::loading_panel::LoadingPanel;
 use panels::main_menu_panel::MainMenuPanel;
+use panels::opponent_intro_panel::OpponentIntroPanel;
+use panels::player_cosmetics_panel::PlayerCosmeticsPanel;
+use panels::puzzles_panel::PuzzlesPanel;
+use panels::quest_log_panel::QuestLogPanel;
+use panels::seeded_run_panel::SeededRunPanel;
 use panels::set_player_name_panel::SetPlayerNamePanel;
 use panels::settings_panel::SettingsPanel;
+use panels::unlock_panel::UnlockCelebrationPanel;
 use protos::spelldawn::game_command::Command;
 use protos::spelldawn::{InterfacePanel, InterfacePanelAddress, UpdatePanelsCommand};
 use serde_json::de;
 use with_error::WithError;
 
+/// Maximum number of entries in [STATIC_PANELS]. Some addresses carry
+/// parameters, so the cache is cleared once it reaches this size rather than
+/// growing without bound.
+const MAX_STATIC_PANELS: usize = 256;
+
+/// Rendered panels which depend only on their [PanelAddress].
+static STATIC_PANELS: Lazy<DashMap<PanelAddress, Option<InterfacePanel>>> = Lazy::new(DashMap::new);
+
+/// Rendered panels which depend on a player's [PlayerData]. Entries are
+/// removed via [invalidate] when the player's data changes or when they
+/// disconnect.
+static PLAYER_PANELS: Lazy<DashMap<PlayerId, PlayerPanels>> = Lazy::new(DashMap::new);
+
+/// Panels rendered for a single player from a given [PlayerData::revision].
+struct PlayerPanels {
+    revision: u64,
+    panels: HashMap<PanelAddress, Option<InterfacePanel>>,
+}
+
 pub fn main_menu_panels() -> Vec<PanelAddress> {
     vec![
         PanelAddress::MainMenu,
+        PanelAddress::Puzzles,
         PanelAddress::Settings,
+        PanelAddress::Achievements,
+        PanelAddress::QuestLog,
+        PanelAddress::PlayerCosmetics,
+        PanelAddress::SeededRun,
         PanelAddress::About,
         PanelAddress::Disclaimer,
     ]
 }
 
+pub fn game_panels() -> Vec<PanelAddress> {
+    vec![
+        PanelAddress::GameMenu,
+        PanelAddress::EmoteMenu,
+        PanelAddress::Settings,
+        PanelAddress::Achievements,
+    ]
+}
+
 pub fn adventure_panels(adventure: &AdventureState) -> Vec<PanelAddress> {
     adventure
         .tiles
@@ -63,7 +116,9 @@ pub fn adventure_panels(adventure: &AdventureState) -> Vec<PanelAddress> {
         }))
         .chain(vec![
             PanelAddress::AdventureMenu,
+            PanelAddress::AdventureJournal,
             PanelAddress::Settings,
+            PanelAddress::Achievements,
             PanelAddress::DeckEditorPrompt,
             PanelAddress::DeckEditorLoading,
         ])
@@ -85,12 +140,59 @@ pub fn render_panel(
     player: &PlayerData,
     client_address: InterfacePanelAddress,
 ) -> Result<UpdatePanelsCommand> {
-    let server_address =
-        de::from_slice(&client_address.serialized).with_error(|| "deserialization failed")?;
-    let panel = render_server_panel(player, server_address)?;
+    let panel = cached_panel(player, server_address(&client_address)?)?;
     Ok(UpdatePanelsCommand { panels: panel.map_or_else(Vec::new, |p| vec![p]) })
 }
 
+/// Discards all cached panels for the indicated player. Should be invoked
+/// whenever this player's [PlayerData] changes or they disconnect.
+pub fn invalidate(player_id: PlayerId) {
+    PLAYER_PANELS.remove(&player_id);
+}
+
+/// Converts a client panel address into a [PanelAddress]
+pub fn server_address(client_address: &InterfacePanelAddress) -> Result<PanelAddress> {
+    de::from_slice(&client_address.serialized).with_error(|| "deserialization failed")
+}
+
+/// Renders the panel at `address`, reusing a previous rendering if its
+/// [PanelDependency] has not changed.
+fn cached_panel(player: &PlayerData, address: PanelAddress) -> Result<Option<InterfacePanel>> {
+    match address.dependency() {
+        PanelDependency::None => {
+            if let Some(panel) = STATIC_PANELS.get(&address) {
+                return Ok(panel.clone());
+            }
+            let panel = render_server_panel(player, address)?;
+            if STATIC_PANELS.len() >= MAX_STATIC_PANELS {
+                STATIC_PANELS.clear();
+            }
+            STATIC_PANELS.insert(address, panel.clone());
+            Ok(panel)
+        }
+        PanelDependency::Player => {
+            if let Some(cached) = PLAYER_PANELS.get(&player.id) {
+                if cached.revision == player.revision {
+                    if let Some(panel) = cached.panels.get(&address) {
+                        return Ok(panel.clone());
+                    }
+                }
+            }
+            let panel = render_server_panel(player, address)?;
+            let mut cached = PLAYER_PANELS.entry(player.id).or_insert_with(|| PlayerPanels {
+                revision: player.revision,
+                panels: HashMap::new(),
+            });
+            if cached.revision != player.revision {
+                *cached = PlayerPanels { revision: player.revision, panels: HashMap::new() };
+            }
+            cached.panels.insert(address, panel.clone());
+            Ok(panel)
+        }
+        PanelDependency::Game | PanelDependency::Server => render_server_panel(player, address),
+    }
+}
+
 fn render_server_panel(
     player: &PlayerData,
     server_address: PanelAddress,
@@ -98,11 +200,24 @@ fn render_server_panel(
     Ok(match server_address {
         PanelAddress::MainMenu => MainMenuPanel::new().build_panel(),
         PanelAddress::About => AboutPanel::new().build_panel(),
-        PanelAddress::Settings => SettingsPanel::new().build_panel(),
+        PanelAddress::Settings => SettingsPanel::new(player).build_panel(),
         PanelAddress::Disclaimer => DisclaimerPanel::new().build_panel(),
         PanelAddress::DebugPanel => DebugPanel::new().build_panel(),
         PanelAddress::GameMenu => GameMenuPanel::new().build_panel(),
+        PanelAddress::EmoteMenu => EmoteMenu::new().build_panel(),
+        // Requires game or server state, rendered via the server's FetchPanel
+        // handler
+        PanelAddress::RaidInfo
+        | PanelAddress::AgentLog
+        | PanelAddress::DelegateTrace
+        | PanelAddress::Leaderboard(_)
+        | PanelAddress::CustomGame(_)
+        | PanelAddress::Rankings(_) => None,
         PanelAddress::AdventureMenu => AdventureMenu::new().build_panel(),
+        PanelAddress::SeededRun => SeededRunPanel::new().build_panel(),
+        PanelAddress::Achievements => AchievementsPanel { player }.build_panel(),
+        PanelAddress::QuestLog => QuestLogPanel { player }.build_panel(),
+        PanelAddress::PlayerCosmetics => PlayerCosmeticsPanel { player }.build_panel(),
         PanelAddress::SetPlayerName(side) => SetPlayerNamePanel::new(side).build_panel(),
         PanelAddress::DeckEditorLoading => LoadingPanel::new(
             server_address,
@@ -113,6 +228,9 @@ fn render_server_panel(
         PanelAddress::DeckEditor(data) => {
             DeckEditorPanel { player, data, deck: player.find_deck(data.deck_id)? }.build_panel()
         }
+        PanelAddress::DeckCosmetics => {
+            CosmeticsPanel { player, deck: player.find_deck(DeckId::Adventure)? }.build_panel()
+        }
         PanelAddress::OldDeckEditor(data) => {
             let open_deck = if let Some(id) = data.deck { Some(player.deck(id)?) } else { None };
             OldDeckEditorPanel { player, open_deck, data }.build_panel()
@@ -125,6 +243,7 @@ fn render_server_panel(
             }
         },
         PanelAddress::GameOver(data) => GameOverPanel { data, player }.build_panel(),
+        PanelAddress::OpponentIntro(opponent) => OpponentIntroPanel { opponent }.build_panel(),
         PanelAddress::TileLoading(position) => {
             adventure_panels::render_tile_loading_panel(position, player)?
         }
@@ -132,22 +251,35 @@ fn render_server_panel(
             adventure_panels::render_tile_prompt_panel(position, player)?
         }
         PanelAddress::DraftCard => render_adventure_choice(player)?,
-        PanelAddress::AdventureOver => render_adventure_choice(player)?,
+        PanelAddress::AdventureOver | PanelAddress::AdventureEvent => {
+            render_adventure_choice(player)?
+        }
+        PanelAddress::CampaignUnlocks => UnlockCelebrationPanel { player }.build_panel(),
+        PanelAddress::AdventureJournal => {
+            AdventureJournalPanel { adventure: player.adventure()? }.build_panel()
+        }
         PanelAddress::Shop(position) => ShopPanel::new(player, position)?.build_panel(),
+        PanelAddress::DraftLobby => DraftLobbyPanel::new().build_panel(),
+        PanelAddress::Confirm(confirmation) => ConfirmationPanel::new(confirmation).build_panel(),
+        PanelAddress::DraftPick => render_draft_pick(player)?,
+        PanelAddress::Puzzles => PuzzlesPanel { player }.build_panel(),
+        PanelAddress::Crafting(offset) => CraftingPanel { player, offset }.build_panel(),
     })
 }
 
+fn render_draft_pick(player: &PlayerData) -> Result<Option<InterfacePanel>> {
+    // The client may request this panel after the draft has ended
+    let Some(draft) = player.draft() else { return Ok(None) };
+    Ok(DraftPickPanel { draft, side: draft.side(player.id)? }.build_panel())
+}
+
 fn render_adventure_choice(player: &PlayerData) -> Result<Option<InterfacePanel>> {
     // It's normal for the client to request screens which aren't always valid,
     // e.g. refreshing the cached choice screen after it's been removed.
 
-    let Some(adventure) = &player.adventure else {
-        return Ok(None)
-    };
+    let Some(adventure) = &player.adventure else { return Ok(None) };
 
-    let Some(choice_screen) = &adventure.choice_screen else {
-        return Ok(None)
-    };
+    let Some(choice_screen) = &adventure.choice_screen else { return Ok(None) };
 
     let rendered = adventure_display::render_adventure_choice_screen(adventure, choice_screen)?;
 
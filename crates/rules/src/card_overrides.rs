@@ -0,0 +1,181 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Allows overriding card costs and stats from a JSON data file, so designers
+//! can tweak numbers without recompiling the workspace.
+//!
+//! When the `hot_reload` feature is enabled, the file named by the
+//! `SPELLDAWN_CARD_OVERRIDES` environment variable (default
+//! `card_overrides.json`) is applied to all card definitions and re-read
+//! whenever it changes via `reload_if_changed()`. The file maps card names to
+//! overrides, e.g. `{"TimeGolem": {"mana_cost": 3, "health": 4}}`. Abilities
+//! cannot be overridden.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use data::card_definition::{AttackBoost, CardDefinition, SchemePoints};
+use data::card_name::CardName;
+use data::primitives::{
+    ActionCount, AttackValue, BreachValue, DurabilityValue, HealthValue, LevelValue, ManaValue,
+    PointsValue, ShieldValue,
+};
+use serde::Deserialize;
+
+/// Replacement values for the cost & stats of a single card. Fields which are
+/// not present keep the value from the card's [CardDefinition].
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CardOverride {
+    pub mana_cost: Option<ManaValue>,
+    pub action_cost: Option<ActionCount>,
+    pub health: Option<HealthValue>,
+    pub shield: Option<ShieldValue>,
+    pub breach: Option<BreachValue>,
+    pub base_attack: Option<AttackValue>,
+    pub attack_boost_cost: Option<ManaValue>,
+    pub attack_boost_bonus: Option<AttackValue>,
+    pub level_requirement: Option<LevelValue>,
+    pub points: Option<PointsValue>,
+    pub durability: Option<DurabilityValue>,
+}
+
+pub type CardOverrides = HashMap<CardName, CardOverride>;
+
+/// Parses the JSON contents of a card overrides file.
+pub fn parse(json: &str) -> Result<CardOverrides> {
+    Ok(serde_json::from_str(json)?)
+}
+
+/// Applies a [CardOverride] to a [CardDefinition].
+pub fn apply(definition: &mut CardDefinition, card_override: &CardOverride) {
+    let stats = &mut definition.config.stats;
+    if card_override.mana_cost.is_some() {
+        definition.cost.mana = card_override.mana_cost;
+    }
+    if let Some(actions) = card_override.action_cost {
+        definition.cost.actions = actions;
+    }
+    if card_override.health.is_some() {
+        stats.health = card_override.health;
+    }
+    if card_override.shield.is_some() {
+        stats.shield = card_override.shield;
+    }
+    if card_override.breach.is_some() {
+        stats.breach = card_override.breach;
+    }
+    if card_override.base_attack.is_some() {
+        stats.base_attack = card_override.base_attack;
+    }
+    if card_override.attack_boost_cost.is_some() || card_override.attack_boost_bonus.is_some() {
+        let boost = stats.attack_boost.unwrap_or_default();
+        stats.attack_boost = Some(AttackBoost {
+            cost: card_override.attack_boost_cost.unwrap_or(boost.cost),
+            bonus: card_override.attack_boost_bonus.unwrap_or(boost.bonus),
+        });
+    }
+    if card_override.durability.is_some() {
+        stats.durability = card_override.durability;
+    }
+    if card_override.level_requirement.is_some() || card_override.points.is_some() {
+        let points =
+            stats.scheme_points.unwrap_or(SchemePoints { level_requirement: 0, points: 0 });
+        stats.scheme_points = Some(SchemePoints {
+            level_requirement: card_override.level_requirement.unwrap_or(points.level_requirement),
+            points: card_override.points.unwrap_or(points.points),
+        });
+    }
+}
+
+#[cfg(feature = "hot_reload")]
+pub use hot_reload::*;
+
+#[cfg(feature = "hot_reload")]
+mod hot_reload {
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+    use std::sync::RwLock;
+    use std::time::SystemTime;
+    use std::{env, fs};
+
+    use anyhow::Result;
+    use data::card_definition::CardDefinition;
+    use data::card_name::CardName;
+    use once_cell::sync::Lazy;
+    use tracing::info;
+
+    use crate::DEFINITIONS;
+
+    struct ReloadState {
+        cards: HashMap<CardName, &'static CardDefinition>,
+        modified: Option<SystemTime>,
+    }
+
+    static STATE: Lazy<RwLock<ReloadState>> = Lazy::new(|| {
+        let modified = modified_time();
+        let cards = build_cards().expect("Error loading card overrides");
+        RwLock::new(ReloadState { cards, modified })
+    });
+
+    /// Returns all current card definitions, including overrides.
+    pub fn all_cards() -> Vec<&'static CardDefinition> {
+        STATE.read().expect("lock").cards.values().copied().collect()
+    }
+
+    /// Looks up the current definition for a [CardName], including overrides.
+    pub fn get(name: CardName) -> Option<&'static CardDefinition> {
+        STATE.read().expect("lock").cards.get(&name).copied()
+    }
+
+    /// Rebuilds all card definitions if the overrides file has been modified
+    /// since it was last read.
+    ///
+    /// Previous definitions are intentionally leaked, since references to them
+    /// may still be held elsewhere.
+    pub fn reload_if_changed() -> Result<()> {
+        let modified = modified_time();
+        if STATE.read().expect("lock").modified == modified {
+            return Ok(());
+        }
+
+        info!(?modified, "Reloading card overrides");
+        let cards = build_cards()?;
+        *STATE.write().expect("lock") = ReloadState { cards, modified };
+        Ok(())
+    }
+
+    fn overrides_path() -> PathBuf {
+        env::var("SPELLDAWN_CARD_OVERRIDES").unwrap_or_else(|_| "card_overrides.json".into()).into()
+    }
+
+    fn modified_time() -> Option<SystemTime> {
+        fs::metadata(overrides_path()).and_then(|m| m.modified()).ok()
+    }
+
+    fn build_cards() -> Result<HashMap<CardName, &'static CardDefinition>> {
+        let path = overrides_path();
+        let overrides =
+            if path.exists() { super::parse(&fs::read_to_string(path)?)? } else { HashMap::new() };
+        let mut map = HashMap::new();
+        for card_fn in DEFINITIONS.iter() {
+            let mut card = card_fn();
+            if let Some(card_override) = overrides.get(&card.name) {
+                super::apply(&mut card, card_override);
+            }
+            map.insert(card.name, &*Box::leak(Box::new(card)));
+        }
+        Ok(map)
+    }
+}
@@ -15,15 +15,17 @@
 //! Handler for interactive card prompts
 
 use anyhow::Result;
-use data::delegates::RaidOutcome;
+use data::card_state::CardPosition;
+use data::delegates::{CardPlayed, RaidOutcome};
 use data::game::GameState;
 use data::game_actions::CardPromptAction;
 use data::primitives::Side;
+use with_error::verify;
 
 use crate::mana::ManaPurpose;
 use crate::{mana, mutations};
 
-pub fn handle(game: &mut GameState, _side: Side, action: CardPromptAction) -> Result<()> {
+pub fn handle(game: &mut GameState, user_side: Side, action: CardPromptAction) -> Result<()> {
     match action {
         CardPromptAction::LoseMana(side, amount) => {
             mana::spend(game, side, ManaPurpose::PayForTriggeredAbility, amount)?;
@@ -41,6 +43,60 @@ pub fn handle(game: &mut GameState, _side: Side, action: CardPromptAction) -> Re
             mutations::deal_damage(game, ability_id, amount)?;
             mutations::end_raid(game, RaidOutcome::Failure)?;
         }
+        CardPromptAction::TutorCard(side, card_id) => {
+            mutations::tutor_card(game, side, card_id)?;
+        }
+        CardPromptAction::PayUpkeep(card_id, cost) => {
+            mutations::pay_upkeep(game, card_id, cost)?;
+        }
+        CardPromptAction::DiscardProject(card_id) => {
+            mutations::discard_project(game, card_id)?;
+        }
+        CardPromptAction::UnveilProject(card_id) => {
+            verify!(
+                mutations::try_unveil_project(game, card_id)?,
+                "Cannot unveil project {:?}",
+                card_id
+            );
+            mutations::set_unveil_prompt(game);
+        }
+        CardPromptAction::EndUnveilWindow | CardPromptAction::CancelRetreat => {}
+        CardPromptAction::ConfirmRetreat => {
+            mutations::retreat(game)?;
+        }
+        CardPromptAction::DiscardToHandSize(side, card_id) => {
+            mutations::discard_to_hand_size(game, side, card_id)?;
+        }
+        CardPromptAction::PassReaction => {
+            mutations::close_reaction_window(game);
+            mutations::check_end_turn(game)?;
+        }
+        CardPromptAction::ChooseMode(mode, target) => {
+            let card_id = mode.card_id;
+            verify!(
+                game.card(card_id).position() == CardPosition::Played(user_side, target),
+                "Card {:?} is not being played",
+                card_id
+            );
+            mutations::resolve_played_card(
+                game,
+                user_side,
+                CardPlayed { card_id, target, mode: Some(mode), x: None },
+            )?;
+        }
+        CardPromptAction::ChooseX(card_id, target, x) => {
+            verify!(
+                game.card(card_id).position() == CardPosition::Played(user_side, target),
+                "Card {:?} is not being played",
+                card_id
+            );
+            mana::spend(game, user_side, ManaPurpose::PayForCard(card_id), x)?;
+            mutations::resolve_played_card(
+                game,
+                user_side,
+                CardPlayed { card_id, target, mode: None, x: Some(x) },
+            )?;
+        }
     }
     Ok(())
 }
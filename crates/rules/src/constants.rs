@@ -12,6 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-pub static STARTING_HAND_SIZE: u32 = 5;
 pub static STARTING_MAXIMUM_HAND_SIZE: u32 = 7;
 pub static MAXIMUM_MINIONS_IN_ROOM: usize = 4;
+pub static DISCONNECT_GRACE_PERIOD_SECONDS: u64 = 120;
@@ -19,31 +19,94 @@ use std::collections::HashMap;
 use std::fmt::Debug;
 
 use anyhow::Result;
-use data::delegates::{DelegateCache, DelegateContext, EventData, QueryData, Scope};
-use data::game::GameState;
-use data::primitives::AbilityId;
+use data::card_state::CardPosition;
+use data::delegate_trace::DelegateTraceEntry;
+use data::delegates::{DelegateCache, DelegateContext, DelegateKind, EventData, QueryData, Scope};
+use data::game::{DefinitionIndex, GameState};
+use data::primitives::{AbilityId, ActionCount, CardId, ManaValue, PointsValue, Side};
 use tracing::instrument;
 
-/// Adds a [DelegateCache] for this game in order to improve lookup performance.
+use crate::modifiers;
+
+/// Adds a [DelegateCache] and [DefinitionIndex] for this game in order to
+/// improve lookup performance. Delegates for the game's
+/// [data::game::GameModifier]s are registered as well.
+///
+/// Must be invoked again if the set of card names in the game changes.
 pub fn populate_delegate_cache(game: &mut GameState) {
+    game.definitions = DefinitionIndex {
+        overlord: game.cards(Side::Overlord).iter().map(|card| crate::get(card.name)).collect(),
+        champion: game.cards(Side::Champion).iter().map(|card| crate::get(card.name)).collect(),
+    };
+
+    // Tokens are registered after all other cards, matching the order in which
+    // `register_card` adds them during a game.
+    let (tokens, cards): (Vec<_>, Vec<_>) =
+        game.all_card_ids().partition(|card_id| game.card(*card_id).data.is_token);
     let mut result = HashMap::new();
-    for card_id in game.all_card_ids() {
-        let definition = crate::get(game.card(card_id).name);
-        for (index, ability) in definition.abilities.iter().enumerate() {
-            let ability_id = AbilityId::new(card_id, index);
-            let scope = Scope::new(ability_id);
-            for delegate in &ability.delegates {
-                result
-                    .entry(delegate.kind())
-                    .or_insert_with(Vec::new)
-                    .push(DelegateContext { delegate: delegate.clone(), scope });
-            }
+    for card_id in cards.into_iter().chain(tokens) {
+        if !game.card(card_id).position().is_banished() {
+            add_card_delegates(game, &mut result, card_id);
+        }
+    }
+
+    // Modifiers are not owned by any card, so they are scoped to the Overlord
+    // identity.
+    if let Some(identity) = game.identities(Side::Overlord).next().map(|card| card.id) {
+        let scope = Scope::new(AbilityId::new(identity, 0));
+        for delegate in modifiers::delegates(game) {
+            result.entry(delegate.kind()).or_default().push(DelegateContext { delegate, scope });
         }
     }
 
     game.delegate_cache = DelegateCache { lookup: result };
 }
 
+/// Registers the definition and delegates of a card which was added to the
+/// game after [populate_delegate_cache] was invoked, e.g. a token copy of
+/// another card.
+///
+/// Delegates for the new card are invoked after all existing delegates.
+pub fn register_card(game: &mut GameState, card_id: CardId) {
+    let definition = crate::get(game.card(card_id).name);
+    let definitions = match card_id.side {
+        Side::Overlord => &mut game.definitions.overlord,
+        Side::Champion => &mut game.definitions.champion,
+    };
+    // Definitions are looked up by name if the index has not been populated
+    if definitions.len() == card_id.index {
+        definitions.push(definition);
+    }
+
+    let mut lookup = std::mem::take(&mut game.delegate_cache.lookup);
+    add_card_delegates(game, &mut lookup, card_id);
+    game.delegate_cache.lookup = lookup;
+}
+
+fn add_card_delegates(
+    game: &GameState,
+    lookup: &mut HashMap<DelegateKind, Vec<DelegateContext>>,
+    card_id: CardId,
+) {
+    let definition = crate::card_definition(game, card_id);
+    for (index, ability) in definition.abilities.iter().enumerate() {
+        let ability_id = AbilityId::new(card_id, index);
+        let scope = Scope::new(ability_id);
+        for delegate in &ability.delegates {
+            lookup
+                .entry(delegate.kind())
+                .or_default()
+                .push(DelegateContext { delegate: delegate.clone(), scope });
+        }
+    }
+}
+
+/// Returns false if the card owning `scope` has been banished, in which case
+/// its delegates should no longer run.
+fn is_active(game: &GameState, scope: Scope) -> bool {
+    !game.card(scope.card_id()).position().is_banished()
+}
+
 /// Called when a game event occurs, invokes each registered
 /// [data::delegates::Delegate] for this event to mutate the [GameState]
 /// appropriately.
@@ -55,8 +118,31 @@ pub fn invoke_event<D: Debug, E: EventData<D>>(game: &mut GameState, event: E) -
         let scope = delegate_context.scope;
         let functions = E::extract(&delegate_context.delegate).expect("Delegate not in cache!");
         let data = event.data();
-        if (functions.requirement)(game, scope, data) {
+        let requirement = is_active(game, scope) && (functions.requirement)(game, scope, data);
+        if !game.data.config.trace_delegates {
+            if requirement {
+                (functions.mutation)(game, scope, data)?;
+            }
+            continue;
+        }
+
+        // The entry is recorded before running the mutation so that it precedes
+        // any entries for events the mutation triggers.
+        let index = game.delegate_trace.len();
+        game.delegate_trace.push(DelegateTraceEntry {
+            event: format!("{:?}", event.kind()),
+            ability_id: scope.ability_id(),
+            data: format!("{:?}", data),
+            requirement,
+            mutation: None,
+        });
+        if requirement {
+            let before = TraceSnapshot::new(game);
             (functions.mutation)(game, scope, data)?;
+            let changes = before.describe_changes(game);
+            if let Some(entry) = game.delegate_trace.get_mut(index) {
+                entry.mutation = Some(changes);
+            }
         }
     }
 
@@ -79,9 +165,73 @@ pub fn perform_query<D: Debug, R: Debug, E: QueryData<D, R>>(
         let scope = delegate_context.scope;
         let functions = E::extract(&delegate_context.delegate).expect("Delegate not in cache!");
         let data = query.data();
-        if (functions.requirement)(game, scope, data) {
+        if is_active(game, scope) && (functions.requirement)(game, scope, data) {
             result = (functions.transformation)(game, scope, data, result);
         }
     }
     result
 }
+
+/// Values compared before and after a traced delegate mutation in order to
+/// summarize its effects, see [data::game::GameConfiguration::trace_delegates].
+struct TraceSnapshot {
+    mana: [ManaValue; 2],
+    actions: [ActionCount; 2],
+    score: [PointsValue; 2],
+    positions: Vec<(CardId, CardPosition)>,
+    history: usize,
+}
+
+impl TraceSnapshot {
+    fn new(game: &GameState) -> Self {
+        let sides = [Side::Overlord, Side::Champion];
+        Self {
+            mana: sides.map(|side| game.player(side).mana_state.base_mana),
+            actions: sides.map(|side| game.player(side).actions),
+            score: sides.map(|side| game.player(side).score),
+            positions: game.all_cards().map(|card| (card.id, card.position())).collect(),
+            history: game.history.len(),
+        }
+    }
+
+    /// Describes the differences between this snapshot and the current state
+    /// of `game`.
+    fn describe_changes(&self, game: &GameState) -> String {
+        let after = Self::new(game);
+        let mut changes = vec![];
+        for (index, side) in [Side::Overlord, Side::Champion].into_iter().enumerate() {
+            for (label, before, after) in [
+                ("mana", self.mana[index], after.mana[index]),
+                ("actions", self.actions[index], after.actions[index]),
+                ("score", self.score[index], after.score[index]),
+            ] {
+                if before != after {
+                    changes.push(format!("{:?} {} {} -> {}", side, label, before, after));
+                }
+            }
+        }
+
+        let before_positions = self.positions.iter().copied().collect::<HashMap<_, _>>();
+        for (card_id, position) in &after.positions {
+            match before_positions.get(card_id) {
+                Some(before) if before != position => {
+                    changes.push(format!("{:?} moved {:?} -> {:?}", card_id, before, position));
+                }
+                None => changes.push(format!("{:?} created in {:?}", card_id, position)),
+                _ => {}
+            }
+        }
+
+        changes.extend(
+            game.history[self.history.min(game.history.len())..]
+                .iter()
+                .map(|entry| format!("{:?}", entry.event)),
+        );
+
+        if changes.is_empty() {
+            "No changes".to_string()
+        } else {
+            changes.join(", ")
+        }
+    }
+}
@@ -18,13 +18,18 @@
 use data::card_definition::{AbilityType, TargetRequirement};
 use data::card_state::CardPosition;
 use data::delegates::{
-    CanActivateAbilityQuery, CanActivateWhileFaceDownQuery, CanDefeatTargetQuery,
-    CanEncounterTargetQuery, CanInitiateRaidQuery, CanLevelUpCardQuery, CanLevelUpRoomQuery,
-    CanPlayCardQuery, CanTakeDrawCardActionQuery, CanTakeGainManaActionQuery, CardEncounter, Flag,
+    CanAbsorbDamageQuery, CanActivateAbilityQuery, CanActivateWhileFaceDownQuery,
+    CanCombineWeaponsQuery, CanDefeatTargetQuery, CanEncounterTargetQuery, CanInitiateRaidQuery,
+    CanLevelUpCardQuery, CanLevelUpRoomQuery, CanPlayCardQuery, CanTakeDrawCardActionQuery,
+    CanTakeGainManaActionQuery, CanUnveilQuery, CardEncounter, CombinedWeapons,
+    ConsumesDurabilityQuery, Flag, ShouldReplaceAccessQuery, ShouldReplaceDestructionQuery,
+    UsedWeapon,
 };
 use data::game::{GamePhase, GameState};
 use data::game_actions::CardTarget;
-use data::primitives::{AbilityId, CardId, CardType, Lineage, RoomId, Side};
+use data::primitives::{
+    AbilityId, CardId, CardSubtype, CardType, ItemLocation, Lineage, RaidId, RoomId, Side,
+};
 
 use crate::mana::ManaPurpose;
 use crate::{dispatch, mana, queries};
@@ -55,11 +60,17 @@ pub fn can_take_play_card_action(
     card_id: CardId,
     target: CardTarget,
 ) -> bool {
-    let mut can_play = in_main_phase(game, side)
+    let timing = if in_reaction_window(game, side) {
+        // Reactions do not cost action points
+        is_reaction(game, card_id)
+    } else {
+        in_main_phase(game, side)
+            && queries::action_cost(game, card_id) <= game.player(side).actions
+    };
+    let mut can_play = timing
         && side == card_id.side
         && game.card(card_id).position() == CardPosition::Hand(side)
-        && is_valid_target(game, card_id, target)
-        && queries::action_cost(game, card_id) <= game.player(side).actions;
+        && is_valid_target(game, card_id, target);
 
     if enters_play_face_up(game, card_id) {
         can_play &= can_pay_card_cost(game, card_id);
@@ -96,10 +107,11 @@ pub fn can_take_activate_ability_action(
     }
 
     let mut can_activate = in_main_phase(game, side)
-        && side == ability_id.card_id.side
+        && side == card.controller()
         && cost.actions <= game.player(side).actions
         && card.position().in_play()
-        && (card.is_face_up() || can_activate_while_face_down(game, ability_id));
+        && (card.is_face_up() || can_activate_while_face_down(game, ability_id))
+        && !queries::activation_limit_reached(game, ability_id, &cost.limit);
 
     if let Some(custom_cost) = &cost.custom_cost {
         can_activate &= (custom_cost.can_pay)(game, ability_id);
@@ -113,6 +125,42 @@ pub fn can_take_activate_ability_action(
         .into()
 }
 
+/// Whether the Champion can currently activate the `ability_id` encounter
+/// ability targeting the `weapon_id` weapon. Encounter abilities can only be
+/// activated during a raid encounter, once per encounter.
+pub fn can_activate_encounter_ability(
+    game: &GameState,
+    ability_id: AbilityId,
+    weapon_id: CardId,
+) -> bool {
+    let AbilityType::EncounterActivated(cost) =
+        &crate::ability_definition(game, ability_id).ability_type
+    else {
+        return false;
+    };
+
+    let card = game.card(ability_id.card_id);
+    let mut can_activate = ability_id.card_id.side == Side::Champion
+        && game.raid().is_ok_and(|raid| raid.encounter.is_some())
+        && card.is_face_up()
+        && card.position().in_play()
+        && game.weapons().any(|weapon| weapon.id == weapon_id)
+        && game.ability_state(ability_id).is_none_or(|state| state.encounter_weapon.is_none())
+        && !queries::activation_limit_reached(game, ability_id, &cost.limit);
+
+    if let Some(custom_cost) = &cost.custom_cost {
+        can_activate &= (custom_cost.can_pay)(game, ability_id);
+    }
+
+    if let Some(cost) = queries::ability_mana_cost(game, ability_id) {
+        can_activate &=
+            cost <= mana::get(game, Side::Champion, ManaPurpose::ActivateAbility(ability_id));
+    }
+
+    dispatch::perform_query(game, CanActivateAbilityQuery(ability_id), Flag::new(can_activate))
+        .into()
+}
+
 /// Returns true if the `ability_id` ability could be activated with a valid
 /// target.
 pub fn activated_ability_has_valid_targets(
@@ -138,10 +186,10 @@ fn is_valid_target(game: &GameState, card_id: CardId, target: CardTarget) -> boo
         !room_id.is_inner_room()
             && !game
                 .occupants(room_id)
-                .any(|card| card_types.contains(&crate::get(card.name).card_type))
+                .any(|card| card_types.contains(&crate::card_definition(game, card.id).card_type))
     }
 
-    let definition = crate::get(game.card(card_id).name);
+    let definition = crate::card_definition(game, card_id);
     if let Some(targeting) = &definition.config.custom_targeting {
         return matching_targeting(game, targeting, card_id, target);
     }
@@ -150,7 +198,9 @@ fn is_valid_target(game: &GameState, card_id: CardId, target: CardTarget) -> boo
         CardType::ChampionSpell
         | CardType::Weapon
         | CardType::Artifact
-        | CardType::OverlordSpell => target == CardTarget::None,
+        | CardType::Ally
+        | CardType::OverlordSpell
+        | CardType::GlobalModifier => target == CardTarget::None,
         CardType::Minion => matches!(target, CardTarget::Room(_)),
         CardType::Project | CardType::Scheme => {
             matches!(target, CardTarget::Room(room_id)
@@ -181,7 +231,7 @@ fn matching_targeting<T>(
 /// and is expected to pay its costs immediately.
 pub fn enters_play_face_up(game: &GameState, card_id: CardId) -> bool {
     !matches!(
-        crate::get(game.card(card_id).name).card_type,
+        crate::card_definition(game, card_id).card_type,
         CardType::Minion | CardType::Scheme | CardType::Project
     )
 }
@@ -189,7 +239,7 @@ pub fn enters_play_face_up(game: &GameState, card_id: CardId) -> bool {
 /// Returns true if the indicated card should enter play in a room
 pub fn enters_play_in_room(game: &GameState, card_id: CardId) -> bool {
     matches!(
-        crate::get(game.card(card_id).name).card_type,
+        crate::card_definition(game, card_id).card_type,
         CardType::Minion | CardType::Scheme | CardType::Project
     )
 }
@@ -215,10 +265,26 @@ pub fn can_take_initiate_raid_action(game: &GameState, side: Side, target: RoomI
     let can_initiate = non_empty
         && side == Side::Champion
         && game.data.raid.is_none()
-        && in_main_phase(game, side);
+        && in_main_phase(game, side)
+        && can_pay_initiate_raid_cost(game, side, target);
     dispatch::perform_query(game, CanInitiateRaidQuery(side), Flag::new(can_initiate)).into()
 }
 
+/// Returns whether the indicated player could pay the
+/// [queries::initiate_raid_cost] to raid the target [RoomId].
+pub fn can_pay_initiate_raid_cost(game: &GameState, side: Side, target: RoomId) -> bool {
+    let cost = queries::initiate_raid_cost(game, target);
+    let mut can_pay = cost.actions <= game.player(side).actions;
+    if let Some(mana) = cost.mana {
+        can_pay &= mana <= mana::get(game, side, ManaPurpose::InitiateRaid(target));
+    }
+    if let Some(custom_cost) = &cost.custom_cost {
+        can_pay &= (custom_cost.can_pay)(game, target);
+    }
+
+    can_pay
+}
+
 /// Returns whether the indicated player can currently take the basic game
 /// action to level up a room
 pub fn can_take_level_up_room_action(game: &GameState, side: Side, room_id: RoomId) -> bool {
@@ -240,6 +306,48 @@ pub fn can_level_up_card(game: &GameState, card_id: CardId) -> bool {
     dispatch::perform_query(game, CanLevelUpCardQuery(card_id), Flag::new(can_level_up)).into()
 }
 
+/// Whether using a weapon as described by `used` should spend one point of its
+/// durability. Always false for weapons without limited uses.
+pub fn consumes_durability(game: &GameState, used: UsedWeapon) -> bool {
+    let consumes = queries::durability(game, used.weapon_id).is_some();
+    consumes
+        && dispatch::perform_query(game, ConsumesDurabilityQuery(used), Flag::new(consumes)).into()
+}
+
+/// Whether the `card_id` scheme has reached its level requirement and can be
+/// scored by the Overlord. Only schemes in a room can be scored, never cards in
+/// hand.
+pub fn can_overlord_score_card(game: &GameState, card_id: CardId) -> bool {
+    let card = game.card(card_id);
+    card_id.side == Side::Overlord
+        && card.position().in_room()
+        && crate::card_definition(game, card_id)
+            .config
+            .stats
+            .scheme_points
+            .is_some_and(|points| card.data.card_level >= points.level_requirement)
+}
+
+/// Whether the `card_id` project is face-down in play under the Overlord's
+/// control and they can currently pay its cost to unveil it.
+pub fn can_unveil_project(game: &GameState, card_id: CardId) -> bool {
+    let card = game.card(card_id);
+    let mut can_unveil = crate::card_definition(game, card_id).card_type == CardType::Project
+        && card.controller() == Side::Overlord
+        && card.is_face_down()
+        && card.position().in_play();
+
+    if let Some(cost) = queries::mana_cost(game, card_id) {
+        can_unveil &= cost <= mana::get(game, card_id.side, ManaPurpose::PayForCard(card_id));
+    }
+
+    if let Some(custom_cost) = &crate::card_definition(game, card_id).cost.custom_cost {
+        can_unveil &= (custom_cost.can_pay)(game, card_id);
+    }
+
+    dispatch::perform_query(game, CanUnveilQuery(card_id), Flag::new(can_unveil)).into()
+}
+
 /// Whether the indicated card entered play this turn
 pub fn entered_play_this_turn(game: &GameState, card_id: CardId) -> bool {
     game.card(card_id).data.last_entered_play == Some(game.data.turn)
@@ -289,6 +397,30 @@ pub fn can_defeat_target(game: &GameState, source: CardId, target: CardId) -> bo
     .into()
 }
 
+/// Can the Champion use the two weapons in [CombinedWeapons] together to defeat
+/// its target, splitting the target's health between them?
+///
+/// Requires both weapons to be able to [can_encounter_target] the target and a
+/// card permitting this via [CanCombineWeaponsQuery]. See
+/// [queries::combined_cost_to_defeat_target] for the cost.
+pub fn can_defeat_target_combined(game: &GameState, weapons: CombinedWeapons) -> bool {
+    weapons.first != weapons.second
+        && can_encounter_target(game, weapons.first, weapons.target)
+        && can_encounter_target(game, weapons.second, weapons.target)
+        && matches!(
+            queries::combined_cost_to_defeat_target(game, weapons),
+            Some((first, second))
+            if first + second <= mana::get(game, Side::Champion, ManaPurpose::UseWeapon(weapons.first))
+        )
+        && dispatch::perform_query(game, CanCombineWeaponsQuery(weapons), Flag::new(false)).into()
+}
+
+/// Returns true if the game is in progress and it is currently the turn of the
+/// `side` player's opponent.
+pub fn is_opponents_turn(game: &GameState, side: Side) -> bool {
+    matches!(&game.data.phase, GamePhase::Play) && game.data.turn.side != side
+}
+
 /// Returns true if the provided `side` player is currently in their Main phase
 /// with no pending prompt responses, and thus can take a primary game action.
 pub fn in_main_phase(game: &GameState, side: Side) -> bool {
@@ -299,3 +431,52 @@ pub fn in_main_phase(game: &GameState, side: Side) -> bool {
         && game.overlord.prompt.is_none()
         && game.champion.prompt.is_none()
 }
+
+/// Returns true if the `side` player can currently respond to an Overlord
+/// action by playing a reaction card.
+pub fn in_reaction_window(game: &GameState, side: Side) -> bool {
+    side == Side::Champion
+        && matches!(&game.data.phase, GamePhase::Play)
+        && game.data.reaction_window.is_some()
+}
+
+/// Returns true if the `card_id` card has the [CardSubtype::Reaction] subtype.
+pub fn is_reaction(game: &GameState, card_id: CardId) -> bool {
+    crate::card_definition(game, card_id).config.subtypes.contains(&CardSubtype::Reaction)
+}
+
+/// Returns true if the `card_id` reaction card can be played with some target
+/// in the currently-open reaction window.
+pub fn can_play_reaction(game: &GameState, card_id: CardId) -> bool {
+    std::iter::once(CardTarget::None)
+        .chain(enum_iterator::all::<RoomId>().map(CardTarget::Room))
+        .any(|target| can_take_play_card_action(game, Side::Champion, card_id, target))
+}
+
+/// Returns whether the Champion's access during the current raid should be
+/// replaced by the `ReplaceAccess` event instead of accessing any cards.
+pub fn should_replace_access(game: &GameState, raid_id: RaidId) -> bool {
+    dispatch::perform_query(game, ShouldReplaceAccessQuery(raid_id), Flag::new(false)).into()
+}
+
+/// Returns whether the destruction of `card_id` should be replaced by the
+/// `ReplaceDestruction` event instead of moving it to the discard pile.
+pub fn should_replace_destruction(game: &GameState, card_id: CardId) -> bool {
+    dispatch::perform_query(game, ShouldReplaceDestructionQuery(card_id), Flag::new(false)).into()
+}
+
+/// Returns whether `card_id` can currently absorb damage which would otherwise
+/// be dealt to the Champion.
+pub fn can_absorb_damage(game: &GameState, card_id: CardId) -> bool {
+    let card = game.card(card_id);
+    dispatch::perform_query(
+        game,
+        CanAbsorbDamageQuery(card_id),
+        Flag::new(
+            card.position() == CardPosition::ArenaItem(ItemLocation::Allies)
+                && card.is_face_up()
+                && queries::remaining_health(game, card_id) > 0,
+        ),
+    )
+    .into()
+}
@@ -0,0 +1,73 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers for card abilities which modify the cost of other cards, e.g. "your
+//! weapons cost 1 less".
+//!
+//! Cost modifications are expressed via [Delegate::ManaCostAdjustment] as a
+//! signed change to a card's printed mana cost. Adjustments from every source
+//! are summed before being applied, so they stack additively and the order in
+//! which they are applied never changes the result. The adjusted cost is then
+//! floored at zero once, meaning a reduction can only offset an increase from
+//! another effect, never a "negative" printed cost.
+
+use data::delegates::{Delegate, QueryDelegate, RequirementFn, Scope};
+use data::game::GameState;
+use data::primitives::{CardId, CardType, ManaValue};
+
+/// Delegate which reduces the mana cost of cards matching `requirement` by
+/// `N`.
+pub fn reduce_cost<const N: ManaValue>(requirement: RequirementFn<CardId>) -> Delegate {
+    Delegate::ManaCostAdjustment(QueryDelegate {
+        requirement,
+        transformation: |_, _, _, current| current - N as i32,
+    })
+}
+
+/// Delegate which increases the mana cost of cards matching `requirement` by
+/// `N`.
+pub fn increase_cost<const N: ManaValue>(requirement: RequirementFn<CardId>) -> Delegate {
+    Delegate::ManaCostAdjustment(QueryDelegate {
+        requirement,
+        transformation: |_, _, _, current| current + N as i32,
+    })
+}
+
+/// Applies the net `adjustment` to a printed mana `cost`, flooring the result
+/// at zero.
+pub fn adjust_cost(cost: ManaValue, adjustment: i32) -> ManaValue {
+    (cost as i32 + adjustment).max(0) as ManaValue
+}
+
+/// RequirementFn for cost-modification auras which apply while this card is
+/// face up in play to cards of the player who controls this card.
+pub fn own_cards_while_in_play(game: &GameState, scope: Scope, card_id: &CardId) -> bool {
+    in_play(game, scope) && card_id.side == scope.controller(game)
+}
+
+/// RequirementFn for cost-modification auras which apply while this card is
+/// face up in play to cards of the opposing player.
+pub fn opponent_cards_while_in_play(game: &GameState, scope: Scope, card_id: &CardId) -> bool {
+    in_play(game, scope) && card_id.side != scope.controller(game)
+}
+
+/// Returns true if `card_id` has the provided [CardType].
+pub fn is_type(game: &GameState, card_id: CardId, card_type: CardType) -> bool {
+    crate::card_definition(game, card_id).card_type == card_type
+}
+
+fn in_play(game: &GameState, scope: Scope) -> bool {
+    let card = game.card(scope.card_id());
+    card.is_face_up() && card.position().in_play()
+}
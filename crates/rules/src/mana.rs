@@ -18,9 +18,10 @@
 use std::cmp;
 
 use anyhow::Result;
-use data::game::{GameState, SpecificRaidMana};
-use data::primitives::{AbilityId, CardId, ManaValue, RaidId, RoomId, Side};
-use with_error::{verify, WithError};
+use data::game::{GameState, ManaPool};
+use data::history::HistoryEvent;
+use data::primitives::{AbilityId, CardId, CardType, ManaValue, RaidId, RoomId, Side};
+use with_error::{verify_code, ErrorCode};
 
 /// Identifies possible reasons why a player's mana value would need to be
 /// queried or spent.
@@ -34,6 +35,8 @@ pub enum ManaPurpose {
     ActivateAbility(AbilityId),
     LevelUpRoom(RoomId),
     PayForTriggeredAbility,
+    PayUpkeep(CardId),
+    InitiateRaid(RoomId),
     AllSources,
 }
 
@@ -43,26 +46,41 @@ pub enum ManaPurpose {
 /// Certain card effects may grant mana conditionally for a given purpose.
 pub fn get(game: &GameState, side: Side, purpose: ManaPurpose) -> ManaValue {
     let base_mana = game.player(side).mana_state.base_mana;
-    let mut result = game.player(side).mana_state.base_mana;
-    match (&game.data.raid, &game.player(side).mana_state.specific_raid_mana) {
-        (Some(raid_data), Some(raid_mana)) if raid_data.raid_id == raid_mana.raid_id => {
-            result += raid_mana.mana;
-        }
-        _ => {}
-    }
-
     match purpose {
         ManaPurpose::BaseMana => base_mana,
-        ManaPurpose::BonusForDisplay => result - base_mana,
-        _ => result,
+        ManaPurpose::BonusForDisplay => displayed_pools(game, side).map(|(_, mana)| mana).sum(),
+        _ => {
+            base_mana
+                + game
+                    .player(side)
+                    .mana_state
+                    .pools
+                    .iter()
+                    .filter(|(pool, _)| can_use_pool(game, **pool, purpose))
+                    .map(|(_, mana)| *mana)
+                    .sum::<ManaValue>()
+        }
     }
 }
 
+/// Returns the restricted-purpose [ManaPool]s for the `side` player which
+/// should currently be shown in the user interface, along with their mana
+/// values. Excludes empty pools and pools for raids which are not ongoing.
+pub fn displayed_pools(
+    game: &GameState,
+    side: Side,
+) -> impl Iterator<Item = (ManaPool, ManaValue)> + '_ {
+    game.player(side).mana_state.pools.iter().filter_map(move |(pool, mana)| {
+        let expired = matches!(pool, ManaPool::Raid(raid_id) if !is_current_raid(game, *raid_id));
+        (*mana > 0 && !expired).then_some((*pool, *mana))
+    })
+}
+
 /// Spends mana for the `side` player for the given [ManaPurpose].
 ///
-/// An effort is made to spend "more specific" mana first, i.e. mana which can
-/// only be used for a certain type of action is preferred, then raid-specific
-/// mana, then general mana.
+/// Mana from restricted [ManaPool]s which can be used for this purpose is
+/// spent first, in order from most to least restrictive, followed by general
+/// mana.
 ///
 /// Returns an error if insufficient mana is available.
 pub fn spend(
@@ -71,35 +89,49 @@ pub fn spend(
     purpose: ManaPurpose,
     amount: ManaValue,
 ) -> Result<()> {
-    verify!(get(game, side, purpose) >= amount);
-    let mut to_spend = amount;
-
-    match (&game.data.raid, &game.player(side).mana_state.specific_raid_mana) {
-        (Some(raid_data), Some(raid_mana)) if raid_data.raid_id == raid_mana.raid_id => {
-            to_spend = try_spend(
-                &mut game
-                    .player_mut(side)
-                    .mana_state
-                    .specific_raid_mana
-                    .as_mut()
-                    .with_error(|| "Expected raid-specific mana")?
-                    .mana,
-                to_spend,
-            );
-        }
-        _ => {}
+    deduct(game, side, purpose, amount)?;
+    if amount > 0 {
+        game.add_history_event(HistoryEvent::SpendMana(side, amount));
     }
-
-    game.player_mut(side).mana_state.base_mana -= to_spend;
     Ok(())
 }
 
 /// Causes a player to lose up to a given amount of mana.
 pub fn lose_upto(game: &mut GameState, side: Side, purpose: ManaPurpose, amount: ManaValue) {
-    spend(game, side, purpose, cmp::min(get(game, side, purpose), amount))
+    deduct(game, side, purpose, cmp::min(get(game, side, purpose), amount))
         .expect("Error spending mana");
 }
 
+/// Removes mana for the `side` player, following the same rules as [spend]
+/// but without recording it as having been spent.
+fn deduct(game: &mut GameState, side: Side, purpose: ManaPurpose, amount: ManaValue) -> Result<()> {
+    verify_code!(
+        get(game, side, purpose) >= amount,
+        ErrorCode::InsufficientMana,
+        "Cannot spend {} mana for {:?}",
+        amount,
+        side
+    );
+    let mut to_spend = amount;
+
+    let usable = game
+        .player(side)
+        .mana_state
+        .pools
+        .keys()
+        .copied()
+        .filter(|pool| can_use_pool(game, *pool, purpose))
+        .collect::<Vec<_>>();
+    for pool in usable {
+        if let Some(mana) = game.player_mut(side).mana_state.pools.get_mut(&pool) {
+            to_spend = try_spend(mana, to_spend);
+        }
+    }
+
+    game.player_mut(side).mana_state.base_mana -= to_spend;
+    Ok(())
+}
+
 /// Adds the specified amount of base mana (no restrictions on use) for the
 /// `side` player.
 pub fn gain(game: &mut GameState, side: Side, amount: ManaValue) {
@@ -111,23 +143,42 @@ pub fn set(game: &mut GameState, side: Side, amount: ManaValue) {
     game.player_mut(side).mana_state.base_mana = amount;
 }
 
-/// Adds mana for the `side` player which can only be used during the specified
-/// `raid_id` raid.
-pub fn add_raid_specific_mana(
-    game: &mut GameState,
-    side: Side,
-    raid_id: RaidId,
-    amount: ManaValue,
-) {
-    match &mut game.player_mut(side).mana_state.specific_raid_mana {
-        Some(raid_mana) if raid_mana.raid_id == raid_id => raid_mana.mana += amount,
-        _ => {
-            game.player_mut(side).mana_state.specific_raid_mana =
-                Some(SpecificRaidMana { raid_id, mana: amount });
+/// Adds mana for the `side` player which can only be used for the purposes
+/// allowed by the provided [ManaPool].
+///
+/// Mana in [ManaPool::Raid] pools for other raids is discarded.
+pub fn add_to_pool(game: &mut GameState, side: Side, pool: ManaPool, amount: ManaValue) {
+    let pools = &mut game.player_mut(side).mana_state.pools;
+    if let ManaPool::Raid(raid_id) = pool {
+        pools.retain(|p, _| !matches!(p, ManaPool::Raid(other) if *other != raid_id));
+    }
+    *pools.entry(pool).or_default() += amount;
+}
+
+/// Returns true if mana from the provided [ManaPool] can currently be used for
+/// the given [ManaPurpose].
+fn can_use_pool(game: &GameState, pool: ManaPool, purpose: ManaPurpose) -> bool {
+    match (pool, purpose) {
+        (_, ManaPurpose::BaseMana | ManaPurpose::BonusForDisplay) => false,
+        (ManaPool::Raid(raid_id), _) => is_current_raid(game, raid_id),
+        (_, ManaPurpose::AllSources) => true,
+        (ManaPool::AnyRaid, _) => game.data.raid.is_some(),
+        (ManaPool::Artifacts, ManaPurpose::PayForCard(card_id)) => is_artifact(game, card_id),
+        (ManaPool::Artifacts, ManaPurpose::ActivateAbility(ability_id)) => {
+            is_artifact(game, ability_id.card_id)
         }
+        (ManaPool::Artifacts, _) => false,
     }
 }
 
+fn is_current_raid(game: &GameState, raid_id: RaidId) -> bool {
+    matches!(&game.data.raid, Some(raid) if raid.raid_id == raid_id)
+}
+
+fn is_artifact(game: &GameState, card_id: CardId) -> bool {
+    crate::get(game.card(card_id).name).card_type == CardType::Artifact
+}
+
 fn try_spend(source: &mut ManaValue, amount: ManaValue) -> ManaValue {
     if *source >= amount {
         *source -= amount;
@@ -0,0 +1,94 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implements [GameModifier]s, rule changes which apply to a single game.
+//!
+//! Modifiers are listed in [data::game::GameConfiguration::modifiers] and
+//! their delegates are registered by [crate::dispatch::populate_delegate_cache]
+//! alongside card abilities. Each delegate applies the combined effect of all
+//! modifiers of its kind, so delegates are registered once per kind.
+
+use std::mem;
+
+use anyhow::Result;
+use data::delegates::{Delegate, EventDelegate, QueryDelegate, Scope};
+use data::game::{GameModifier, GameState};
+use data::primitives::{CardId, CardType, HealthValue, ManaValue, Side, TurnNumber};
+
+use crate::mana;
+
+/// Returns the delegates which implement the modifiers for this game, one set
+/// per distinct kind of modifier.
+pub fn delegates(game: &GameState) -> Vec<Delegate> {
+    let mut kinds = vec![];
+    for modifier in &game.data.config.modifiers {
+        if !kinds.iter().any(|kind| mem::discriminant(kind) == mem::discriminant(modifier)) {
+            kinds.push(*modifier);
+        }
+    }
+    kinds.iter().flat_map(modifier_delegates).collect()
+}
+
+fn modifier_delegates(modifier: &GameModifier) -> Vec<Delegate> {
+    match modifier {
+        GameModifier::BonusStartingMana(..) => vec![
+            Delegate::Dusk(EventDelegate::new(is_first_turn, gain_starting_mana)),
+            Delegate::Dawn(EventDelegate::new(is_first_turn, gain_starting_mana)),
+        ],
+        GameModifier::MinionHealth(_) => {
+            vec![Delegate::HealthValue(QueryDelegate::new(is_minion, add_minion_health))]
+        }
+    }
+}
+
+fn is_first_turn(_: &GameState, _: Scope, turn: &TurnNumber) -> bool {
+    *turn == 1
+}
+
+fn gain_starting_mana(game: &mut GameState, _: Scope, _: &TurnNumber) -> Result<()> {
+    let side = game.data.turn.side;
+    let bonus = starting_mana_bonus(game, side);
+    mana::gain(game, side, bonus);
+    Ok(())
+}
+
+fn starting_mana_bonus(game: &GameState, side: Side) -> ManaValue {
+    game.data
+        .config
+        .modifiers
+        .iter()
+        .map(|modifier| match modifier {
+            GameModifier::BonusStartingMana(s, amount) if *s == side => *amount,
+            _ => 0,
+        })
+        .sum()
+}
+
+fn is_minion(game: &GameState, _: Scope, card_id: &CardId) -> bool {
+    crate::card_definition(game, *card_id).card_type == CardType::Minion
+}
+
+fn add_minion_health(game: &GameState, _: Scope, _: &CardId, health: HealthValue) -> HealthValue {
+    health
+        + game
+            .data
+            .config
+            .modifiers
+            .iter()
+            .map(|modifier| match modifier {
+                GameModifier::MinionHealth(amount) => *amount,
+                _ => 0,
+            })
+            .sum::<HealthValue>()
+}
@@ -22,24 +22,33 @@
 use std::cmp;
 
 use anyhow::Result;
+use data::card_definition::{AbilityType, ActivationLimit};
 #[allow(unused)] // Used in rustdocs
 use data::card_state::{CardData, CardPosition, CardPositionKind};
 use data::delegates::{
-    CardMoved, DawnEvent, DealtDamage, DealtDamageEvent, DrawCardEvent, DuskEvent, EnterPlayEvent,
-    MoveCardEvent, OverlordScoreCardEvent, RaidEndEvent, RaidEnded, RaidFailureEvent, RaidOutcome,
-    RaidSuccessEvent, Scope, ScoreCard, ScoreCardEvent, StoredManaTakenEvent, SummonMinionEvent,
-    UnveilProjectEvent,
+    ActivateEncounterAbilityEvent, AfterRaidStepEvent, BeforeRaidStepEvent, CardDestroyed,
+    CardMoved, CardPlayed, CastCardEvent, ChampionRetreatEvent, ControlChangedEvent,
+    DamageAbsorbed, DamageAbsorbedEvent, DawnEvent, DealtDamage, DealtDamageEvent,
+    DestroyCardEvent, DrawCardEvent, DrawPhaseEvent, DuskEvent, EncounterAbilityActivated,
+    EnterPlayEvent, MainPhaseBeginEvent, MoveCardEvent, OverlordScoreCardEvent, RaidEndEvent,
+    RaidEnded, RaidFailureEvent, RaidOutcome, RaidRetreat, RaidStep, RaidStepData,
+    RaidSuccessEvent, ReplaceDestructionEvent, Scope, ScoreCard, ScoreCardEvent,
+    StoredManaTakenEvent, SummonMinionEvent, TurnBeginEvent, TurnEndEvent, UnveilProjectEvent,
+    UsedWeapon,
+};
+use data::game::{
+    BonusActions, GamePhase, GameState, ReactionTrigger, ReactionWindow, TurnData, TurnStep,
 };
-use data::game::{GamePhase, GameState, TurnData};
 use data::game_actions::{CardPromptAction, GamePrompt};
+use data::history::HistoryEvent;
 use data::primitives::{
-    ActionCount, BoostData, CardId, HasAbilityId, ManaValue, PointsValue, RoomId, RoomLocation,
-    Side, TurnNumber,
+    AbilityId, ActionCount, BoostData, CardId, HasAbilityId, ManaValue, PointsValue, RoomId,
+    RoomLocation, Side, TurnNumber,
 };
 use data::random;
-use data::updates::GameUpdate;
+use data::updates::{GameUpdate, UpdatePriority};
 use tracing::{info, instrument};
-use with_error::verify;
+use with_error::{fail, verify, WithError};
 
 use crate::mana::ManaPurpose;
 use crate::{constants, dispatch, flags, mana, queries};
@@ -52,10 +61,22 @@ use crate::{constants, dispatch, flags, mana, queries};
 /// This function does *not* handle changing the 'revealed' or 'face down' state
 /// of the card, the caller is responsible for updating that when the card moves
 /// to a new game zone.
+///
+/// Token cards which are moved to a position outside of play are instead moved
+/// to [CardPosition::Banished], and can no longer be moved.
 #[instrument(skip(game))]
-pub fn move_card(game: &mut GameState, card_id: CardId, new_position: CardPosition) -> Result<()> {
+pub fn move_card(
+    game: &mut GameState,
+    card_id: CardId,
+    mut new_position: CardPosition,
+) -> Result<()> {
     info!(?card_id, ?new_position, "move_card");
     let old_position = game.card(card_id).position();
+    verify!(!old_position.is_banished(), "Card {:?} has been banished", card_id);
+    if game.card(card_id).data.is_token && !new_position.in_play() {
+        // Tokens cease to exist when they leave play
+        new_position = CardPosition::Banished;
+    }
     game.move_card_internal(card_id, new_position);
 
     dispatch::invoke_event(game, MoveCardEvent(CardMoved { old_position, new_position }))?;
@@ -77,6 +98,59 @@ pub fn move_card(game: &mut GameState, card_id: CardId, new_position: CardPositi
         check_minion_limit(game, room_id)?;
     }
 
+    if new_position == CardPosition::GlobalModifier {
+        replace_global_modifier(game, card_id)?;
+    }
+
+    Ok(())
+}
+
+/// Gives control of the `card_id` card to the `side` player until it leaves
+/// play. The new controller can see the card and activate its abilities, but
+/// the card still moves to its owner's discard pile or score pile.
+pub fn change_control(game: &mut GameState, card_id: CardId, side: Side) -> Result<()> {
+    verify!(game.card(card_id).position().in_play(), "Card {:?} is not in play", card_id);
+    let card = game.card_mut(card_id);
+    card.data.controller = (side != card_id.side).then_some(side);
+    card.set_revealed_to(side, true);
+    dispatch::invoke_event(game, ControlChangedEvent(card_id))
+}
+
+/// Creates a token copy of the `source_id` card for the `side` player and
+/// moves it to `position` via [move_card]. The copy uses the same card
+/// definition with a new [CardId], starts face-up, and has its delegates
+/// registered immediately. Its delegates do not respond to an event which is
+/// currently being handled.
+///
+/// The copy is banished when it leaves play, see [move_card].
+pub fn create_copy(
+    game: &mut GameState,
+    source_id: CardId,
+    side: Side,
+    position: CardPosition,
+) -> Result<CardId> {
+    let card_id = game.add_card_internal(side, game.card(source_id).name);
+    let card = game.card_mut(card_id);
+    card.data.is_token = true;
+    card.turn_face_up();
+    dispatch::register_card(game, card_id);
+    move_card(game, card_id, position)?;
+    Ok(card_id)
+}
+
+/// Moves any card other than `card_id` in the shared global modifier slot to
+/// its owner's discard pile. Only one global modifier can be in play at a time,
+/// so a newly-played modifier replaces the existing one regardless of which
+/// player owns it.
+fn replace_global_modifier(game: &mut GameState, card_id: CardId) -> Result<()> {
+    let replaced = game
+        .all_cards()
+        .filter(|c| c.position() == CardPosition::GlobalModifier && c.id != card_id)
+        .map(|c| c.id)
+        .collect::<Vec<_>>();
+    for replaced_id in replaced {
+        move_card(game, replaced_id, CardPosition::DiscardPile(replaced_id.side))?;
+    }
     Ok(())
 }
 
@@ -94,6 +168,55 @@ pub fn sacrifice_card(game: &mut GameState, card_id: CardId) -> Result<()> {
     move_card(game, card_id, CardPosition::DiscardPile(card_id.side))
 }
 
+/// Destroys all cards in `card_ids` simultaneously, moving them from play to
+/// their owners' discard pile. Cards which are not in play are ignored.
+///
+/// Replacement effects are checked for every card before any card moves. A
+/// card whose destruction is replaced via `ShouldReplaceDestruction` stays in
+/// play and fires the `ReplaceDestruction` event instead. The remaining cards
+/// are recorded as a single [GameUpdate::DestroyCards] and all leave play
+/// before any `DestroyCard` event fires, so each trigger observes the complete
+/// result. Events are fired once per card in the order of `card_ids`.
+pub fn destroy_cards(game: &mut GameState, card_ids: Vec<CardId>) -> Result<()> {
+    let mut destroyed = vec![];
+    let mut replaced = vec![];
+    for card_id in card_ids {
+        if !game.card(card_id).position().in_play()
+            || destroyed.contains(&card_id)
+            || replaced.contains(&card_id)
+        {
+            continue;
+        }
+
+        if flags::should_replace_destruction(game, card_id) {
+            replaced.push(card_id);
+        } else {
+            destroyed.push(card_id);
+        }
+    }
+
+    if !destroyed.is_empty() {
+        game.record_update(|| GameUpdate::DestroyCards(destroyed.clone()));
+    }
+
+    for card_id in &destroyed {
+        move_card(game, *card_id, CardPosition::DiscardPile(card_id.side))?;
+    }
+
+    for card_id in replaced {
+        dispatch::invoke_event(game, ReplaceDestructionEvent(card_id))?;
+    }
+
+    for card_id in &destroyed {
+        dispatch::invoke_event(
+            game,
+            DestroyCardEvent(CardDestroyed { card_id: *card_id, destroyed: destroyed.clone() }),
+        )?;
+    }
+
+    Ok(())
+}
+
 // Shuffles the provided `cards` into the `side` player's deck, clearing their
 // revealed state for both players.
 pub fn shuffle_into_deck(game: &mut GameState, side: Side, cards: &[CardId]) -> Result<()> {
@@ -104,16 +227,21 @@ pub fn shuffle_into_deck(game: &mut GameState, side: Side, cards: &[CardId]) ->
         game.card_mut(*card_id).set_revealed_to(Side::Champion, false);
     }
     shuffle_deck(game, side)?;
-    game.record_update(|| GameUpdate::ShuffleIntoDeck);
+    game.record_snapshot_point();
     Ok(())
 }
 
 /// Shuffles the `side` player's deck, moving all cards into the `DeckUnknown`
-/// card position.
+/// card position and clearing their revealed state for both players.
 pub fn shuffle_deck(game: &mut GameState, side: Side) -> Result<()> {
     let cards =
         game.cards_in_position(side, CardPosition::DeckTop(side)).map(|c| c.id).collect::<Vec<_>>();
-    move_cards(game, &cards, CardPosition::DeckUnknown(side))
+    move_cards(game, &cards, CardPosition::DeckUnknown(side))?;
+    for card_id in &cards {
+        game.card_mut(*card_id).set_revealed_to(Side::Overlord, false);
+        game.card_mut(*card_id).set_revealed_to(Side::Champion, false);
+    }
+    Ok(())
 }
 
 /// Helper function to draw `count` cards from the top of a player's deck and
@@ -138,6 +266,7 @@ pub fn draw_cards(game: &mut GameState, side: Side, count: u32) -> Result<Vec<Ca
 
     for card_id in &card_ids {
         move_card(game, *card_id, CardPosition::Hand(side))?;
+        game.add_history_event(HistoryEvent::DrawCard(side, *card_id));
     }
 
     Ok(card_ids)
@@ -167,10 +296,42 @@ pub fn spend_action_points(game: &mut GameState, side: Side, amount: ActionCount
     Ok(())
 }
 
+/// Grants additional action points to the `side` player. If it is currently
+/// their turn, these can be used immediately. Otherwise, or if their turn is
+/// already ending, they are added at the start of their next turn.
+pub fn gain_action_points(game: &mut GameState, side: Side, amount: ActionCount) {
+    if matches!(game.data.phase, GamePhase::Play)
+        && game.data.turn.side == side
+        && game.data.turn_step < TurnStep::End
+    {
+        let player = game.player_mut(side);
+        player.actions += amount;
+        player.bonus_actions.this_turn += amount;
+    } else {
+        gain_action_points_next_turn(game, side, amount);
+    }
+}
+
+/// Grants additional action points to the `side` player at the start of their
+/// next turn.
+pub fn gain_action_points_next_turn(game: &mut GameState, side: Side, amount: ActionCount) {
+    game.player_mut(side).bonus_actions.next_turn += amount;
+}
+
+/// Ends the current turn of the `side` player, adding their remaining action
+/// points to their next turn.
+pub fn carry_over_action_points(game: &mut GameState, side: Side) -> Result<()> {
+    verify!(game.data.turn.side == side, "Cannot carry over actions outside of {:?} turn", side);
+    let remaining = game.player(side).actions;
+    spend_action_points(game, side, remaining)?;
+    gain_action_points_next_turn(game, side, remaining);
+    check_end_turn(game)
+}
+
 /// Adds points to a player's score and checks for the Game Over condition.
 pub fn score_points(game: &mut GameState, side: Side, amount: PointsValue) -> Result<()> {
     game.player_mut(side).score += amount;
-    if game.player(side).score >= 7 {
+    if game.player(side).score >= game.data.config.rules.points_to_win {
         game_over(game, side)?;
     }
     Ok(())
@@ -179,7 +340,46 @@ pub fn score_points(game: &mut GameState, side: Side, amount: PointsValue) -> Re
 /// Mark the game as won by the `winner` player.
 pub fn game_over(game: &mut GameState, winner: Side) -> Result<()> {
     game.data.phase = GamePhase::GameOver { winner };
-    game.record_update(|| GameUpdate::GameOver(winner));
+    game.add_history_event(HistoryEvent::GameOver(winner));
+    game.record_update_with_priority(UpdatePriority::Deferred, || GameUpdate::GameOver(winner));
+    Ok(())
+}
+
+/// Records that the `side` player has lost their connection to this game as
+/// of `timestamp` (in seconds since the Unix epoch), if they are not already
+/// marked as disconnected.
+pub fn player_disconnected(game: &mut GameState, side: Side, timestamp: u64) {
+    if game.player(side).disconnected.is_none()
+        && !matches!(game.data.phase, GamePhase::GameOver { .. })
+    {
+        game.player_mut(side).disconnected = Some(timestamp);
+        game.record_update(|| GameUpdate::PlayerDisconnected(side));
+    }
+}
+
+/// Clears any disconnection recorded for the `side` player.
+pub fn player_reconnected(game: &mut GameState, side: Side) {
+    game.player_mut(side).disconnected = None;
+}
+
+/// Ends the game in favor of the opponent if a disconnected player has not
+/// reconnected within the configured grace period as of `timestamp`.
+///
+/// Games in which the opponent is also disconnected are not ended, since
+/// neither player is present to win. See [queries::is_abandoned].
+pub fn check_disconnect_forfeit(game: &mut GameState, timestamp: u64) -> Result<()> {
+    if matches!(game.data.phase, GamePhase::GameOver { .. }) {
+        return Ok(());
+    }
+
+    for side in enum_iterator::all::<Side>() {
+        if queries::has_forfeited(game, side, timestamp)
+            && game.player(side.opponent()).disconnected.is_none()
+        {
+            game.player_mut(side).disconnected = None;
+            return game_over(game, side.opponent());
+        }
+    }
     Ok(())
 }
 
@@ -206,7 +406,7 @@ pub fn take_stored_mana(
     let available = game.card(card_id).data.stored_mana;
     let taken = cmp::min(available, maximum);
     game.card_mut(card_id).data.stored_mana -= taken;
-    mana::gain(game, card_id.side, taken);
+    mana::gain(game, game.card(card_id).controller(), taken);
     dispatch::invoke_event(game, StoredManaTakenEvent(card_id))?;
 
     if on_zero_stored == OnZeroStored::Sacrifice && game.card(card_id).data.stored_mana == 0 {
@@ -248,27 +448,133 @@ pub fn set_prompt(
     Ok(())
 }
 
+/// Prompts the `side` player to search their deck for one of the `candidates`
+/// to put into their hand. Candidate cards are revealed to the player while the
+/// prompt is active. If no candidates are provided, the deck is shuffled
+/// immediately.
+///
+/// Returns an error if a prompt is already set for this player or if any
+/// candidate is not in their deck.
+pub fn set_tutor_prompt(game: &mut GameState, side: Side, candidates: &[CardId]) -> Result<()> {
+    verify!(game.player(side).prompt.is_none(), "Prompt already present");
+    if candidates.is_empty() {
+        return shuffle_deck(game, side);
+    }
+
+    for card_id in candidates {
+        verify!(
+            card_id.side == side && game.card(*card_id).position().in_deck(),
+            "Card {:?} is not in deck",
+            card_id
+        );
+        move_card(game, *card_id, CardPosition::DeckTop(side))?;
+        game.card_mut(*card_id).set_revealed_to(side, true);
+    }
+
+    game.player_mut(side).prompt = Some(GamePrompt::tutor(side, candidates));
+    Ok(())
+}
+
+/// Moves the `card_id` card from the `side` player's deck into their hand, then
+/// shuffles their deck.
+pub fn tutor_card(game: &mut GameState, side: Side, card_id: CardId) -> Result<()> {
+    verify!(
+        card_id.side == side && game.card(card_id).position().in_deck(),
+        "Card {:?} is not in deck",
+        card_id
+    );
+    game.card_mut(card_id).set_revealed_to(side, true);
+    game.record_update(|| GameUpdate::DrawCards(side, vec![card_id]));
+    move_card(game, card_id, CardPosition::Hand(side))?;
+    shuffle_deck(game, side)?;
+    game.record_snapshot_point();
+    Ok(())
+}
+
+/// Invokes the [BeforeRaidStepEvent] for the current raid. Returns false if
+/// the raid was ended by a delegate, in which case the step should not occur.
+///
+/// Returns an error if no raid is currently active.
+pub fn before_raid_step(game: &mut GameState, step: RaidStep) -> Result<bool> {
+    let (raid_id, target) = (game.raid()?.raid_id, game.raid()?.target);
+    dispatch::invoke_event(game, BeforeRaidStepEvent(RaidStepData { raid_id, target, step }))?;
+    Ok(game.data.raid.as_ref().is_some_and(|raid| raid.raid_id == raid_id))
+}
+
+/// Invokes the [AfterRaidStepEvent] for the current raid. Does nothing if no
+/// raid is active. Returns false if there is no active raid after invoking
+/// the event.
+pub fn after_raid_step(game: &mut GameState, step: RaidStep) -> Result<bool> {
+    let (raid_id, target) = match &game.data.raid {
+        Some(raid) => (raid.raid_id, raid.target),
+        None => return Ok(false),
+    };
+    dispatch::invoke_event(game, AfterRaidStepEvent(RaidStepData { raid_id, target, step }))?;
+    Ok(game.data.raid.as_ref().is_some_and(|raid| raid.raid_id == raid_id))
+}
+
 /// Ends the current raid. Returns an error if no raid is currently active.
 #[instrument(skip(game))]
 pub fn end_raid(game: &mut GameState, outcome: RaidOutcome) -> Result<()> {
     info!("end_raid");
     let raid_id = game.raid()?.raid_id;
+    if !before_raid_step(game, RaidStep::End(outcome))? {
+        return Ok(());
+    }
     match outcome {
-        RaidOutcome::Success => dispatch::invoke_event(game, RaidSuccessEvent(raid_id))?,
+        RaidOutcome::Success => {
+            game.add_history_event(HistoryEvent::RaidSuccess(game.raid()?.target));
+            dispatch::invoke_event(game, RaidSuccessEvent(raid_id))?
+        }
         RaidOutcome::Failure => dispatch::invoke_event(game, RaidFailureEvent(raid_id))?,
     }
     dispatch::invoke_event(game, RaidEndEvent(RaidEnded { raid_id, outcome }))?;
+    after_raid_step(game, RaidStep::End(outcome))?;
+    clear_encounter_weapons(game);
     game.data.raid = None;
     check_end_turn(game)?;
     Ok(())
 }
 
+/// Queues a raid on `room_id` to be initiated automatically once the current
+/// raid ends, e.g. for effects which raid several rooms in sequence. Queued
+/// raids begin in the order they were added.
+///
+/// If no raid is active, the queued raid begins once the current game action
+/// completes. The current turn will not end while a raid is queued.
+pub fn queue_raid(game: &mut GameState, room_id: RoomId) {
+    game.data.queued_raids.push(room_id);
+}
+
+/// The Champion retreats from the current encounter. Invokes the
+/// [ChampionRetreatEvent] and then ends the raid in failure.
+///
+/// Returns an error if no encounter is currently active.
+#[instrument(skip(game))]
+pub fn retreat(game: &mut GameState) -> Result<()> {
+    info!("retreat");
+    let raid = game.raid()?;
+    let retreat = RaidRetreat {
+        raid_id: raid.raid_id,
+        target: raid.target,
+        defender_id: game.raid_defender()?,
+        encounter: game.raid_encounter()?,
+    };
+    game.add_history_event(HistoryEvent::RaidRetreat(retreat.target));
+    dispatch::invoke_event(game, ChampionRetreatEvent(retreat))?;
+    if game.data.raid.as_ref().is_some_and(|raid| raid.raid_id == retreat.raid_id) {
+        end_raid(game, RaidOutcome::Failure)?;
+    }
+    Ok(())
+}
+
 /// Deals initial hands to both players and prompts for mulligan decisions.
 #[instrument(skip(game))]
 pub fn deal_opening_hands(game: &mut GameState) -> Result<()> {
     info!("deal_opening_hands");
-    draw_cards(game, Side::Overlord, constants::STARTING_HAND_SIZE)?;
-    draw_cards(game, Side::Champion, constants::STARTING_HAND_SIZE)?;
+    let hand_size = game.data.config.rules.starting_hand_size;
+    draw_cards(game, Side::Overlord, hand_size)?;
+    draw_cards(game, Side::Champion, hand_size)?;
     Ok(())
 }
 
@@ -282,8 +588,9 @@ pub fn check_start_game(game: &mut GameState) -> Result<()> {
         GamePhase::ResolveMulligans(mulligans)
             if mulligans.overlord.is_some() && mulligans.champion.is_some() =>
         {
-            mana::set(game, Side::Overlord, 5);
-            mana::set(game, Side::Champion, 5);
+            let starting_mana = game.data.config.rules.starting_mana;
+            mana::set(game, Side::Overlord, starting_mana);
+            mana::set(game, Side::Champion, starting_mana);
             start_turn(game, Side::Overlord, 1)?;
         }
         _ => {}
@@ -332,6 +639,9 @@ fn check_minion_limit(game: &mut GameState, room_id: RoomId) -> Result<()> {
 
 /// Invoked after taking a game action to check if the turn should be switched
 /// for the provided player.
+///
+/// If the player is over their maximum hand size, prompts them to discard a
+/// card instead. This function is invoked again after each discard.
 pub fn check_end_turn(game: &mut GameState) -> Result<()> {
     if !matches!(game.data.phase, GamePhase::Play) {
         return Ok(());
@@ -340,14 +650,22 @@ pub fn check_end_turn(game: &mut GameState) -> Result<()> {
     let turn = game.data.turn;
     let side = turn.side;
 
-    if game.player(side).actions == 0 && game.data.raid.is_none() {
+    if game.player(side).actions == 0
+        && game.data.raid.is_none()
+        && game.data.queued_raids.is_empty()
+        && game.data.reaction_window.is_none()
+    {
+        if game.data.turn_step < TurnStep::End {
+            advance_turn_step(game, TurnStep::End)?;
+            dispatch::invoke_event(game, TurnEndEvent(turn))?;
+        }
+
         let max_hand_size = queries::maximum_hand_size(game, side) as usize;
         let hand = game.card_list_for_position(side, CardPosition::Hand(side));
         if hand.len() > max_hand_size {
-            let count = hand.len() - max_hand_size;
-            for card_id in hand.iter().take(count) {
-                move_card(game, *card_id, CardPosition::DiscardPile(side))?;
-            }
+            game.player_mut(side).prompt =
+                Some(GamePrompt::discard_to_hand_size(side, &hand, hand.len() - max_hand_size));
+            return Ok(());
         }
 
         let turn_number = match side {
@@ -361,6 +679,19 @@ pub fn check_end_turn(game: &mut GameState) -> Result<()> {
     Ok(())
 }
 
+/// Discards `card_id` from the `side` player's hand in response to a
+/// [GamePrompt::discard_to_hand_size] prompt, then checks whether their turn
+/// can now end.
+pub fn discard_to_hand_size(game: &mut GameState, side: Side, card_id: CardId) -> Result<()> {
+    verify!(
+        game.card(card_id).position() == CardPosition::Hand(side),
+        "Card {:?} is not in hand",
+        card_id
+    );
+    move_card(game, card_id, CardPosition::DiscardPile(side))?;
+    check_end_turn(game)
+}
+
 /// Increases the level of all `can_level_up` Overlord cards in a room by 1. If
 /// a Scheme card's level reaches its `level_requirement`, that card is
 /// immediately scored and moved to the Overlord score zone.
@@ -392,69 +723,105 @@ pub fn level_up_room(game: &mut GameState, room_id: RoomId) -> Result<()> {
 pub fn add_level_counters(game: &mut GameState, card_id: CardId, amount: u32) -> Result<()> {
     verify!(flags::can_level_up_card(game, card_id));
     game.card_mut(card_id).data.card_level += amount;
-    let card = game.card(card_id);
-    if let Some(scheme_points) = crate::get(card.name).config.stats.scheme_points {
-        if card.data.card_level >= scheme_points.level_requirement {
-            game.card_mut(card_id).turn_face_up();
-            move_card(game, card_id, CardPosition::Scoring)?;
-            game.record_update(|| GameUpdate::ScoreCard(Side::Overlord, card_id));
-            dispatch::invoke_event(game, OverlordScoreCardEvent(card_id))?;
-            dispatch::invoke_event(
-                game,
-                ScoreCardEvent(ScoreCard { player: Side::Overlord, card_id }),
-            )?;
-            score_points(game, Side::Overlord, scheme_points.points)?;
-            move_card(game, card_id, CardPosition::Scored(Side::Overlord))?;
+    if flags::can_overlord_score_card(game, card_id) {
+        overlord_score_card(game, card_id)?;
+    }
+
+    Ok(())
+}
+
+/// Spends one point of durability from the weapon described by `used`, if
+/// required by [flags::consumes_durability]. The weapon is moved to its owner's
+/// discard pile once it has no durability remaining.
+pub fn spend_durability(game: &mut GameState, used: UsedWeapon) -> Result<()> {
+    if !flags::consumes_durability(game, used) {
+        return Ok(());
+    }
+
+    let weapon_id = used.weapon_id;
+    game.card_mut(weapon_id).data.durability_spent += 1;
+    if queries::remaining_durability(game, weapon_id) == Some(0) {
+        move_card(game, weapon_id, CardPosition::DiscardPile(weapon_id.side))?;
+    }
+    Ok(())
+}
+
+/// Scores every Overlord scheme in play which has reached its level
+/// requirement without being scored, e.g. because its requirement was
+/// lowered after level counters were placed on it. Invoked at the start of
+/// each Overlord turn.
+pub fn check_overlord_scoring(game: &mut GameState) -> Result<()> {
+    let schemes = game
+        .cards(Side::Overlord)
+        .iter()
+        .filter(|c| flags::can_overlord_score_card(game, c.id))
+        .map(|c| c.id)
+        .collect::<Vec<_>>();
+
+    for card_id in schemes {
+        if matches!(game.data.phase, GamePhase::GameOver { .. }) {
+            break;
         }
+        overlord_score_card(game, card_id)?;
     }
 
     Ok(())
 }
 
+/// Turns the `card_id` scheme face up, fires scoring events, and moves it to
+/// the Overlord's score zone, awarding its points.
+///
+/// Returns an error if the card cannot currently be scored, see
+/// [flags::can_overlord_score_card].
+pub fn overlord_score_card(game: &mut GameState, card_id: CardId) -> Result<()> {
+    verify!(flags::can_overlord_score_card(game, card_id), "Cannot score {:?}", card_id);
+    let scheme_points = crate::card_definition(game, card_id)
+        .config
+        .stats
+        .scheme_points
+        .with_error(|| format!("Expected SchemePoints for {:?}", card_id))?;
+    game.card_mut(card_id).turn_face_up();
+    move_card(game, card_id, CardPosition::Scoring)?;
+    game.add_history_event(HistoryEvent::ScoreCard(Side::Overlord, card_id));
+    game.record_update(|| GameUpdate::ScoreCard(Side::Overlord, card_id));
+    dispatch::invoke_event(game, OverlordScoreCardEvent(card_id))?;
+    dispatch::invoke_event(game, ScoreCardEvent(ScoreCard { player: Side::Overlord, card_id }))?;
+    score_points(game, Side::Overlord, scheme_points.points)?;
+    move_card(game, card_id, CardPosition::Scored(Side::Overlord))?;
+    Ok(())
+}
+
 /// Attempt to pay a project's cost and turn it face up. Has no effect if the
-/// card is not in play, already face up, or if the cost cannot be paid.
+/// card is not in play, already face up, or if the cost cannot be paid. See
+/// [flags::can_unveil_project].
 ///
 /// Returns true if the card was unveiled.
 pub fn try_unveil_project(game: &mut GameState, card_id: CardId) -> Result<bool> {
-    let result = if game.card(card_id).is_face_down() && game.card(card_id).position().in_play() {
-        if let Some(custom_cost) = &crate::card_definition(game, card_id).cost.custom_cost {
-            if (custom_cost.can_pay)(game, card_id) {
-                (custom_cost.pay)(game, card_id)?;
-            } else {
-                return Ok(false);
-            }
-        }
+    if !flags::can_unveil_project(game, card_id) {
+        return Ok(false);
+    }
 
-        match queries::mana_cost(game, card_id) {
-            None => {
-                game.card_mut(card_id).turn_face_up();
-                true
-            }
-            Some(cost)
-                if cost <= mana::get(game, card_id.side, ManaPurpose::PayForCard(card_id)) =>
-            {
-                mana::spend(game, card_id.side, ManaPurpose::PayForCard(card_id), cost)?;
-                game.card_mut(card_id).turn_face_up();
-                true
-            }
-            _ => false,
-        }
-    } else {
-        false
-    };
+    if let Some(custom_cost) = &crate::card_definition(game, card_id).cost.custom_cost {
+        (custom_cost.pay)(game, card_id)?;
+    }
 
-    if result {
-        game.record_update(|| GameUpdate::UnveilProject(card_id));
-        dispatch::invoke_event(game, UnveilProjectEvent(card_id))?;
+    if let Some(cost) = queries::mana_cost(game, card_id) {
+        mana::spend(game, card_id.side, ManaPurpose::PayForCard(card_id), cost)?;
     }
 
-    Ok(result)
+    game.card_mut(card_id).turn_face_up();
+    // Upkeep is first due on the turn after a project is unveiled
+    game.card_mut(card_id).data.last_upkeep = Some(game.data.turn);
+    game.record_update(|| GameUpdate::UnveilProject(card_id));
+    dispatch::invoke_event(game, UnveilProjectEvent(card_id))?;
+    Ok(true)
 }
 
 /// Equivalent function to [try_unveil_project] which ignores costs.
 pub fn unveil_project_for_free(game: &mut GameState, card_id: CardId) -> Result<bool> {
     let result = if game.card(card_id).is_face_down() && game.card(card_id).position().in_play() {
         game.card_mut(card_id).turn_face_up();
+        game.card_mut(card_id).data.last_upkeep = Some(game.data.turn);
         true
     } else {
         false
@@ -468,24 +835,196 @@ pub fn unveil_project_for_free(game: &mut GameState, card_id: CardId) -> Result<
     Ok(result)
 }
 
-/// Starts the turn for the `next_side` player.
+/// Opens the Overlord's unveil window at the start of their turn, or
+/// continues it after a prompt response.
+///
+/// First prompts the Overlord to pay the upkeep cost of each face-up project
+/// which requires one, discarding any project whose upkeep cannot be paid.
+/// Then prompts them to unveil projects via [set_unveil_prompt]. Has no effect
+/// if no project requires a decision.
+pub fn check_unveil_window(game: &mut GameState) -> Result<()> {
+    let turn = game.data.turn;
+    let upkeep = game
+        .cards(Side::Overlord)
+        .iter()
+        .filter(|c| {
+            c.controller() == Side::Overlord
+                && c.is_face_up()
+                && c.position().in_play()
+                && c.data.last_upkeep != Some(turn)
+        })
+        .find_map(|c| queries::upkeep_cost(game, c.id).map(|cost| (c.id, cost)));
+
+    if let Some((card_id, cost)) = upkeep {
+        if cost <= mana::get(game, Side::Overlord, ManaPurpose::PayUpkeep(card_id)) {
+            game.overlord.prompt = Some(GamePrompt::upkeep(card_id, cost));
+        } else {
+            discard_project(game, card_id)?;
+        }
+        return Ok(());
+    }
+
+    set_unveil_prompt(game);
+    Ok(())
+}
+
+/// Prompts the Overlord to unveil any face-down projects whose cost they can
+/// currently pay. Has no effect if there are no such projects.
+pub fn set_unveil_prompt(game: &mut GameState) {
+    let projects = game
+        .cards(Side::Overlord)
+        .iter()
+        .filter(|c| flags::can_unveil_project(game, c.id))
+        .map(|c| c.id)
+        .collect::<Vec<_>>();
+    if !projects.is_empty() {
+        game.overlord.prompt = Some(GamePrompt::unveil_window(&projects));
+    }
+}
+
+/// Pays `cost` mana to keep the `card_id` project in play for this turn, then
+/// continues the unveil window.
+pub fn pay_upkeep(game: &mut GameState, card_id: CardId, cost: ManaValue) -> Result<()> {
+    mana::spend(game, card_id.side, ManaPurpose::PayUpkeep(card_id), cost)?;
+    game.card_mut(card_id).data.last_upkeep = Some(game.data.turn);
+    game.record_update(|| GameUpdate::PayUpkeep(card_id));
+    check_unveil_window(game)
+}
+
+/// Moves the `card_id` project to its owner's discard pile instead of paying
+/// its upkeep cost, then continues the unveil window.
+pub fn discard_project(game: &mut GameState, card_id: CardId) -> Result<()> {
+    move_card(game, card_id, CardPosition::DiscardPile(card_id.side))?;
+    check_unveil_window(game)
+}
+
+/// Starts the turn for the `next_side` player, advancing through each
+/// [TurnStep] up to [TurnStep::Main].
 fn start_turn(game: &mut GameState, next_side: Side, turn_number: TurnNumber) -> Result<()> {
+    let turn = TurnData { side: next_side, turn_number };
     game.data.phase = GamePhase::Play;
-    game.data.turn = TurnData { side: next_side, turn_number };
+    game.data.turn = turn;
+    game.data.turn_step = TurnStep::Begin;
 
     info!(?next_side, "start_player_turn");
     game.record_update(|| GameUpdate::StartTurn(next_side));
 
+    dispatch::invoke_event(game, TurnBeginEvent(turn))?;
     if next_side == Side::Overlord {
         dispatch::invoke_event(game, DuskEvent(turn_number))?;
+        check_overlord_scoring(game)?;
+        if matches!(game.data.phase, GamePhase::GameOver { .. }) {
+            return Ok(());
+        }
     } else {
         dispatch::invoke_event(game, DawnEvent(turn_number))?;
     }
-    game.player_mut(next_side).actions = queries::start_of_turn_action_count(game, next_side);
+    refresh_ability_limits(game, next_side);
+    let bonus = game.player(next_side).bonus_actions.next_turn;
+    game.player_mut(next_side).actions =
+        queries::start_of_turn_action_count(game, next_side) + bonus;
+    game.player_mut(next_side).bonus_actions = BonusActions { this_turn: bonus, next_turn: 0 };
+    game.player_mut(next_side.opponent()).bonus_actions.this_turn = 0;
+
+    advance_turn_step(game, TurnStep::Draw)?;
+    dispatch::invoke_event(game, DrawPhaseEvent(turn))?;
     draw_cards(game, next_side, 1)?;
+
+    advance_turn_step(game, TurnStep::Main)?;
+    dispatch::invoke_event(game, MainPhaseBeginEvent(turn))?;
+    if next_side == Side::Overlord {
+        check_unveil_window(game)?;
+    }
+    Ok(())
+}
+
+/// Moves the current turn forward to the provided [TurnStep]. Returns an error
+/// if this step has already been reached during the current turn.
+fn advance_turn_step(game: &mut GameState, step: TurnStep) -> Result<()> {
+    verify!(
+        step > game.data.turn_step,
+        "Cannot move from {:?} to {:?} in turn {:?}",
+        game.data.turn_step,
+        step,
+        game.data.turn
+    );
+    game.data.turn_step = step;
     Ok(())
 }
 
+/// Updates the ability state for the `ability_id` ability to record an
+/// activation subject to the provided [ActivationLimit].
+///
+/// Spends one charge, marks the ability as used this turn, and starts its
+/// cooldown.
+pub fn record_ability_activation(
+    game: &mut GameState,
+    ability_id: AbilityId,
+    limit: &ActivationLimit,
+) {
+    let state = game.ability_state_mut(ability_id);
+    state.charges_spent += 1;
+    state.used_this_turn = true;
+    state.cooldown = limit.cooldown;
+}
+
+/// Pays the cost of the `ability_id` encounter ability and activates it
+/// targeting the `weapon_id` weapon for the remainder of the current encounter.
+///
+/// Returns an error if the ability cannot currently be activated, see
+/// [flags::can_activate_encounter_ability].
+pub fn activate_encounter_ability(
+    game: &mut GameState,
+    ability_id: AbilityId,
+    weapon_id: CardId,
+) -> Result<()> {
+    verify!(
+        flags::can_activate_encounter_ability(game, ability_id, weapon_id),
+        "Cannot activate {:?} targeting {:?}",
+        ability_id,
+        weapon_id
+    );
+    let AbilityType::EncounterActivated(cost) =
+        &crate::ability_definition(game, ability_id).ability_type
+    else {
+        fail!("Ability is not an encounter ability");
+    };
+
+    if let Some(mana) = queries::ability_mana_cost(game, ability_id) {
+        mana::spend(game, Side::Champion, ManaPurpose::ActivateAbility(ability_id), mana)?;
+    }
+    if let Some(custom_cost) = &cost.custom_cost {
+        (custom_cost.pay)(game, ability_id)?;
+    }
+    record_ability_activation(game, ability_id, &cost.limit);
+    game.ability_state_mut(ability_id).encounter_weapon = Some(weapon_id);
+    game.record_update(|| GameUpdate::AbilityActivated(Side::Champion, ability_id));
+    dispatch::invoke_event(
+        game,
+        ActivateEncounterAbilityEvent(EncounterAbilityActivated { ability_id, weapon_id }),
+    )
+}
+
+/// Clears the weapons targeted by encounter abilities once the current
+/// encounter ends.
+pub fn clear_encounter_weapons(game: &mut GameState) {
+    for state in game.ability_state.values_mut() {
+        state.encounter_weapon = None;
+    }
+}
+
+/// Clears once-per-turn usage flags for all abilities and advances the
+/// cooldowns of abilities owned by the `side` player at the start of their
+/// turn.
+fn refresh_ability_limits(game: &mut GameState, side: Side) {
+    for (ability_id, state) in game.ability_state.iter_mut() {
+        state.used_this_turn = false;
+        if ability_id.card_id.side == side {
+            state.cooldown = state.cooldown.saturating_sub(1);
+        }
+    }
+}
+
 /// Clears card state which is specific to a card being in play.
 ///
 /// Automatically invoked by [move_card] when a card moves to a non-play zone.
@@ -493,6 +1032,14 @@ fn clear_counters(game: &mut GameState, card_id: CardId) {
     game.card_mut(card_id).data.card_level = 0;
     game.card_mut(card_id).data.stored_mana = 0;
     game.card_mut(card_id).data.boost_count = 0;
+    game.card_mut(card_id).data.durability_spent = 0;
+    game.card_mut(card_id).data.damage_absorbed = 0;
+    game.card_mut(card_id).data.controller = None;
+    for (_, state) in game.ability_state.iter_mut().filter(|(id, _)| id.card_id == card_id) {
+        state.charges_spent = 0;
+        state.used_this_turn = false;
+        state.cooldown = 0;
+    }
 }
 
 /// Options when invoking [summon_minion]
@@ -521,14 +1068,111 @@ pub fn summon_minion(game: &mut GameState, card_id: CardId, costs: SummonMinion)
     dispatch::invoke_event(game, SummonMinionEvent(card_id))?;
     game.card_mut(card_id).turn_face_up();
     game.record_update(|| GameUpdate::SummonMinion(card_id));
+    check_reaction_window(game, ReactionTrigger::SummonMinion(card_id))
+}
+
+/// Opens a reaction window in response to the `trigger` Overlord action,
+/// prompting the Champion to play a reaction card or pass.
+///
+/// Has no effect outside of the Overlord's turn, during a raid, while the
+/// Champion already has a prompt, or if the Champion has no reaction card
+/// which they can currently play.
+pub fn check_reaction_window(game: &mut GameState, trigger: ReactionTrigger) -> Result<()> {
+    if !matches!(game.data.phase, GamePhase::Play)
+        || game.data.turn.side != Side::Overlord
+        || game.data.raid.is_some()
+        || game.data.reaction_window.is_some()
+        || game.champion.prompt.is_some()
+    {
+        return Ok(());
+    }
+
+    game.data.reaction_window = Some(ReactionWindow { trigger });
+    let can_react = game
+        .hand(Side::Champion)
+        .any(|card| flags::is_reaction(game, card.id) && flags::can_play_reaction(game, card.id));
+    if can_react {
+        game.champion.prompt = Some(GamePrompt::reaction_window());
+    } else {
+        game.data.reaction_window = None;
+    }
     Ok(())
 }
 
-/// Deals damage. Discards random card from the hand of the Champion player. If
-/// no cards remain, this player loses the game.
+/// Closes the current reaction window, if any, removing the Champion's prompt
+/// to pass. Callers should invoke [check_end_turn] once the response has
+/// resolved.
+pub fn close_reaction_window(game: &mut GameState) {
+    if game.data.reaction_window.take().is_some() {
+        game.champion.prompt = None;
+    }
+}
+
+/// Resolves a card which has been paid for and moved to the
+/// [CardPosition::Played] zone by the `side` player: fires the `CastCard` event
+/// for the card, moves it to its played position, and then checks whether the
+/// current turn should end.
+pub fn resolve_played_card(game: &mut GameState, side: Side, played: CardPlayed) -> Result<()> {
+    dispatch::invoke_event(game, CastCardEvent(played))?;
+    move_card(
+        game,
+        played.card_id,
+        queries::played_position(game, side, played.card_id, played.target)?,
+    )?;
+    check_end_turn(game)
+}
+
+/// Deals damage. Each point of damage is first absorbed by an ally which can
+/// absorb damage (see [flags::can_absorb_damage]), starting with the ally which
+/// entered play earliest, and allies with no health remaining are then
+/// destroyed. Each remaining point discards a random card from the hand of the
+/// Champion player. If no cards remain, this player loses the game.
+///
+/// Damage which is entirely absorbed by allies is not recorded in the game
+/// history and does not fire a [DealtDamageEvent].
 pub fn deal_damage(game: &mut GameState, source: impl HasAbilityId, amount: u32) -> Result<()> {
+    let source = source.ability_id();
+    let mut absorbed: Vec<(CardId, u32)> = vec![];
+    let mut remaining = amount;
+    while remaining > 0 {
+        let Some(ally_id) = game
+            .allies()
+            .filter(|c| flags::can_absorb_damage(game, c.id))
+            .min_by_key(|c| c.sorting_key)
+            .map(|c| c.id)
+        else {
+            break;
+        };
+
+        game.card_mut(ally_id).data.damage_absorbed += 1;
+        match absorbed.iter_mut().find(|(card_id, _)| *card_id == ally_id) {
+            Some((_, count)) => *count += 1,
+            None => absorbed.push((ally_id, 1)),
+        }
+        remaining -= 1;
+    }
+
+    for (card_id, count) in &absorbed {
+        dispatch::invoke_event(
+            game,
+            DamageAbsorbedEvent(DamageAbsorbed { card_id: *card_id, source, amount: *count }),
+        )?;
+    }
+
+    let exhausted = absorbed
+        .iter()
+        .map(|(card_id, _)| *card_id)
+        .filter(|card_id| queries::remaining_health(game, *card_id) == 0)
+        .collect();
+    destroy_cards(game, exhausted)?;
+
+    if remaining == 0 {
+        return Ok(());
+    }
+
+    game.add_history_event(HistoryEvent::DealDamage(remaining));
     let mut discarded = vec![];
-    for _ in 0..amount {
+    for _ in 0..remaining {
         if let Some(card_id) =
             random::card_in_position(game, Side::Champion, CardPosition::Hand(Side::Champion))
         {
@@ -541,7 +1185,7 @@ pub fn deal_damage(game: &mut GameState, source: impl HasAbilityId, amount: u32)
 
     dispatch::invoke_event(
         game,
-        DealtDamageEvent(DealtDamage { source: source.ability_id(), amount, discarded }),
+        DealtDamageEvent(DealtDamage { source, amount: remaining, discarded }),
     )?;
 
     Ok(())
@@ -15,25 +15,29 @@
 //! Core functions for querying the current state of a game
 
 use anyhow::Result;
-use data::card_definition::{AbilityType, AttackBoost, CardStats, TargetRequirement};
+use data::card_definition::{
+    AbilityType, ActivationLimit, AttackBoost, CardStats, Cost, TargetRequirement,
+};
 use data::card_state::{CardPosition, CardState};
 use data::delegates::{
     AbilityManaCostQuery, ActionCostQuery, AttackBoostQuery, AttackValueQuery, BoostCountQuery,
-    BreachValueQuery, HealthValueQuery, ManaCostQuery, MaximumHandSizeQuery,
-    SanctumAccessCountQuery, ShieldValueQuery, StartOfTurnActionsQuery, VaultAccessCountQuery,
+    BreachValueQuery, CombinedWeapons, CryptsAccessCountQuery, DurabilityQuery, HealthValueQuery,
+    InitiateRaidCostQuery, ManaCostAdjustmentQuery, ManaCostQuery, MaximumHandSizeQuery,
+    RoomAccessCountQuery, SanctumAccessCountQuery, ShieldValueQuery, StartOfTurnActionsQuery,
+    UpkeepCostQuery, VaultAccessCountQuery,
 };
 use data::game::GameState;
 use data::game_actions::{CardTarget, CardTargetKind};
 use data::primitives::{
-    AbilityId, ActionCount, AttackValue, BoostCount, BreachValue, CardId, CardType, HealthValue,
-    ItemLocation, ManaValue, RoomId, RoomLocation, ShieldValue, Side,
+    AbilityId, ActionCount, AttackValue, BoostCount, BreachValue, CardId, CardType,
+    DurabilityValue, HealthValue, ItemLocation, ManaValue, RoomId, RoomLocation, ShieldValue, Side,
 };
 
-use crate::{constants, dispatch};
+use crate::{constants, dispatch, helpers};
 
 /// Obtain the [CardStats] for a given card
 pub fn stats(game: &GameState, card_id: CardId) -> &CardStats {
-    &crate::get(game.card(card_id).name).config.stats
+    &crate::card_definition(game, card_id).config.stats
 }
 
 /// Returns the mana cost for a given card.
@@ -42,18 +46,25 @@ pub fn stats(game: &GameState, card_id: CardId) -> &CardStats {
 /// - For projects, this is the unveil cost.
 /// - For spells, artifacts, and weapons this is the casting cost.
 /// - Schemes do not have a mana cost
+///
+/// Cost adjustments are applied before other cost changes, see
+/// [crate::helpers].
 pub fn mana_cost(game: &GameState, card_id: CardId) -> Option<ManaValue> {
+    let adjustment = dispatch::perform_query(game, ManaCostAdjustmentQuery(card_id), 0);
     dispatch::perform_query(
         game,
         ManaCostQuery(card_id),
-        crate::get(game.card(card_id).name).cost.mana,
+        crate::card_definition(game, card_id)
+            .cost
+            .mana
+            .map(|cost| helpers::adjust_cost(cost, adjustment)),
     )
 }
 
 /// Returns the mana cost for a given ability, if any
 pub fn ability_mana_cost(game: &GameState, ability_id: AbilityId) -> Option<ManaValue> {
-    let cost = if let AbilityType::Activated(cost, _) =
-        &crate::get(game.card(ability_id.card_id).name).ability(ability_id.index).ability_type
+    let cost = if let AbilityType::Activated(cost, _) | AbilityType::EncounterActivated(cost) =
+        &crate::ability_definition(game, ability_id).ability_type
     {
         cost.mana
     } else {
@@ -63,12 +74,47 @@ pub fn ability_mana_cost(game: &GameState, ability_id: AbilityId) -> Option<Mana
     dispatch::perform_query(game, AbilityManaCostQuery(ability_id), cost)
 }
 
+/// Returns the number of remaining activations for the `ability_id` ability,
+/// or None if it is not an activated ability with limited charges.
+pub fn remaining_charges(game: &GameState, ability_id: AbilityId) -> Option<u32> {
+    if let AbilityType::Activated(cost, _) | AbilityType::EncounterActivated(cost) =
+        &crate::ability_definition(game, ability_id).ability_type
+    {
+        let spent = game.ability_state(ability_id).map_or(0, |state| state.charges_spent);
+        cost.limit.charges.map(|charges| charges.saturating_sub(spent))
+    } else {
+        None
+    }
+}
+
+/// Returns true if the `ability_id` ability cannot currently be activated due
+/// to its [ActivationLimit], i.e. because it has no remaining charges, has
+/// already been used this turn, or is on cooldown.
+pub fn activation_limit_reached(
+    game: &GameState,
+    ability_id: AbilityId,
+    limit: &ActivationLimit,
+) -> bool {
+    if remaining_charges(game, ability_id) == Some(0) {
+        return true;
+    }
+
+    game.ability_state(ability_id)
+        .is_some_and(|state| (limit.once_per_turn && state.used_this_turn) || state.cooldown > 0)
+}
+
+/// Returns the mana the `card_id` project must pay at the start of each
+/// Overlord turn to remain in play, if any.
+pub fn upkeep_cost(game: &GameState, card_id: CardId) -> Option<ManaValue> {
+    dispatch::perform_query(game, UpkeepCostQuery(card_id), None)
+}
+
 /// Returns the action point cost for a given card
 pub fn action_cost(game: &GameState, card_id: CardId) -> ActionCount {
     dispatch::perform_query(
         game,
         ActionCostQuery(card_id),
-        crate::get(game.card(card_id).name).cost.actions,
+        crate::card_definition(game, card_id).cost.actions,
     )
 }
 
@@ -90,6 +136,12 @@ pub fn health(game: &GameState, card_id: CardId) -> HealthValue {
     )
 }
 
+/// Returns the health of a card minus any damage it has absorbed since entering
+/// play.
+pub fn remaining_health(game: &GameState, card_id: CardId) -> HealthValue {
+    health(game, card_id).saturating_sub(game.card(card_id).data.damage_absorbed)
+}
+
 /// Returns the shield value for a given card, or 0 by default.
 pub fn shield(game: &GameState, card_id: CardId) -> ShieldValue {
     dispatch::perform_query(
@@ -117,11 +169,62 @@ pub fn attack_boost(game: &GameState, card_id: CardId) -> Option<AttackBoost> {
         .map(|boost| dispatch::perform_query(game, AttackBoostQuery(card_id), boost))
 }
 
+/// Returns the current durability of a weapon, if it has limited uses.
+pub fn durability(game: &GameState, card_id: CardId) -> Option<DurabilityValue> {
+    stats(game, card_id)
+        .durability
+        .map(|durability| dispatch::perform_query(game, DurabilityQuery(card_id), durability))
+}
+
+/// Returns the number of remaining uses of a weapon, or None if it does not
+/// have limited uses.
+pub fn remaining_durability(game: &GameState, card_id: CardId) -> Option<DurabilityValue> {
+    durability(game, card_id)
+        .map(|durability| durability.saturating_sub(game.card(card_id).data.durability_spent))
+}
+
 /// Returns the [BoostCount] for a given card.
 pub fn boost_count(game: &GameState, card_id: CardId) -> BoostCount {
     dispatch::perform_query(game, BoostCountQuery(card_id), game.card(card_id).data.boost_count)
 }
 
+/// Returns the number of times the owner of `card_id` would need to activate
+/// its [AttackBoost] to raise its [AttackValue] to the health of `target_id`.
+///
+/// - Returns 0 if this card can already defeat the target.
+/// - Returns None if it is impossible for this card to defeat the target.
+pub fn boosts_to_defeat_target(
+    game: &GameState,
+    card_id: CardId,
+    target_id: CardId,
+) -> Option<BoostCount> {
+    let target = health(game, target_id);
+    let current = attack(game, card_id);
+
+    if current >= target {
+        Some(0)
+    } else if let Some(boost) = attack_boost(game, card_id) {
+        boosts_needed(target - current, boost.bonus)
+    } else {
+        None
+    }
+}
+
+/// Returns the number of times an [AttackBoost] providing `bonus` attack must
+/// be applied to increase attack by `increase`, or None if `bonus` is 0.
+fn boosts_needed(increase: AttackValue, bonus: AttackValue) -> Option<BoostCount> {
+    if bonus == 0 {
+        None
+    } else {
+        // If the boost does not evenly divide into the target, we need to apply it an
+        // additional time.
+        let add = if (increase % bonus) == 0 { 0 } else { 1 };
+
+        #[allow(clippy::integer_division)] // Deliberate integer truncation
+        Some(add + (increase / bonus))
+    }
+}
+
 /// Returns the amount of mana the owner of `card_id` would need to spend to
 /// raise its [AttackValue] to the provided `target` by activating boosts or
 /// by using other innate abilities, plus the amount of mana required to pay
@@ -134,28 +237,57 @@ pub fn cost_to_defeat_target(
     card_id: CardId,
     target_id: CardId,
 ) -> Option<ManaValue> {
-    let target = health(game, target_id);
-    let current = attack(game, card_id);
+    boosts_to_defeat_target(game, card_id, target_id).map(|count| {
+        count * attack_boost(game, card_id).map_or(0, |boost| boost.cost)
+            + shield_cost(game, card_id, target_id)
+    })
+}
 
-    let result = if current >= target {
-        Some(0)
-    } else if let Some(boost) = attack_boost(game, card_id) {
-        if boost.bonus == 0 {
-            None
-        } else {
-            let increase = target - current;
-            // If the boost does not evenly divide into the target, we need to apply it an
-            // additional time.
-            let add = if (increase % boost.bonus) == 0 { 0 } else { 1 };
-
-            #[allow(clippy::integer_division)] // Deliberate integer truncation
-            Some((add + (increase / boost.bonus)) * boost.cost)
-        }
+/// Equivalent to [cost_to_defeat_target] for two weapons used together. The
+/// attack of both weapons counts towards the health of the target, and the
+/// cheapest combination of boosts is used to make up the difference. The
+/// shield cost is paid by whichever weapon has the lower shield cost.
+///
+/// Returns the mana to spend on behalf of the first and second weapon
+/// respectively, or None if the weapons cannot defeat the target together.
+pub fn combined_cost_to_defeat_target(
+    game: &GameState,
+    weapons: CombinedWeapons,
+) -> Option<(ManaValue, ManaValue)> {
+    let combined = attack(game, weapons.first) + attack(game, weapons.second);
+    let increase = health(game, weapons.target).saturating_sub(combined);
+    let first_boost = attack_boost(game, weapons.first).filter(|boost| boost.bonus > 0);
+    let second_boost = attack_boost(game, weapons.second).filter(|boost| boost.bonus > 0);
+    let max_first = first_boost.and_then(|boost| boosts_needed(increase, boost.bonus)).unwrap_or(0);
+
+    let (mut first, mut second) = (0..=max_first)
+        .filter_map(|count| {
+            let first_bonus = first_boost.map_or(0, |boost| count * boost.bonus);
+            let remaining = increase.saturating_sub(first_bonus);
+            let second_count =
+                if remaining == 0 { 0 } else { boosts_needed(remaining, second_boost?.bonus)? };
+            Some((
+                first_boost.map_or(0, |boost| count * boost.cost),
+                second_boost.map_or(0, |boost| second_count * boost.cost),
+            ))
+        })
+        .min_by_key(|(first, second)| first + second)?;
+
+    let first_shield = shield_cost(game, weapons.first, weapons.target);
+    let second_shield = shield_cost(game, weapons.second, weapons.target);
+    if first_shield <= second_shield {
+        first += first_shield;
     } else {
-        None
-    };
+        second += second_shield;
+    }
 
-    result.map(|r| r + (shield(game, target_id).saturating_sub(breach(game, card_id))))
+    Some((first, second))
+}
+
+/// Returns the amount of mana the owner of `card_id` must pay to overcome the
+/// [ShieldValue] of `target_id`, after accounting for breach.
+pub fn shield_cost(game: &GameState, card_id: CardId, target_id: CardId) -> ManaValue {
+    shield(game, target_id).saturating_sub(breach(game, card_id))
 }
 
 /// Look up the number of action points a player receives at the start of their
@@ -164,6 +296,12 @@ pub fn start_of_turn_action_count(game: &GameState, side: Side) -> ActionCount {
     dispatch::perform_query(game, StartOfTurnActionsQuery(side), 3)
 }
 
+/// Look up the cost the Champion must pay to take the basic game action to
+/// initiate a raid on the `target` room
+pub fn initiate_raid_cost(game: &GameState, target: RoomId) -> Cost<RoomId> {
+    dispatch::perform_query(game, InitiateRaidCostQuery(target), Cost::default())
+}
+
 /// Look up the number of cards the Champion player can access from the Vault
 /// during the current raid
 pub fn vault_access_count(game: &GameState) -> Result<u32> {
@@ -178,6 +316,22 @@ pub fn sanctum_access_count(game: &GameState) -> Result<u32> {
     Ok(dispatch::perform_query(game, SanctumAccessCountQuery(raid_id), 1))
 }
 
+/// Look up the number of cards the Champion player can access from the Crypts
+/// during the current raid
+pub fn crypts_access_count(game: &GameState) -> Result<u32> {
+    let raid_id = game.raid()?.raid_id;
+    let count = game.discard_pile(Side::Overlord).count() as u32;
+    Ok(dispatch::perform_query(game, CryptsAccessCountQuery(raid_id), count))
+}
+
+/// Look up the number of cards the Champion player can access from the outer
+/// room being raided during the current raid
+pub fn room_access_count(game: &GameState) -> Result<u32> {
+    let raid = game.raid()?;
+    let count = game.occupants(raid.target).count() as u32;
+    Ok(dispatch::perform_query(game, RoomAccessCountQuery(raid.raid_id), count))
+}
+
 /// Looks up what type of target a given card requires
 pub fn card_target_kind(game: &GameState, card_id: CardId) -> CardTargetKind {
     let definition = crate::card_definition(game, card_id);
@@ -211,6 +365,24 @@ pub fn maximum_hand_size(game: &GameState, side: Side) -> u32 {
     dispatch::perform_query(game, MaximumHandSizeQuery(side), constants::STARTING_MAXIMUM_HAND_SIZE)
 }
 
+/// Returns true if the `side` player has been disconnected from this game for
+/// longer than its grace period as of `timestamp`.
+pub fn has_forfeited(game: &GameState, side: Side, timestamp: u64) -> bool {
+    match (game.player(side).disconnected, game.data.config.disconnect_grace_period) {
+        (Some(disconnected), Some(grace_period)) => timestamp >= disconnected + grace_period,
+        _ => false,
+    }
+}
+
+/// Returns true if both players have left this game, i.e. one player has
+/// exceeded the disconnection grace period as of `timestamp` while their
+/// opponent is also disconnected. Abandoned games end without a result.
+pub fn is_abandoned(game: &GameState, timestamp: u64) -> bool {
+    enum_iterator::all::<Side>().any(|side| {
+        has_forfeited(game, side, timestamp) && game.player(side.opponent()).disconnected.is_some()
+    })
+}
+
 /// Locates a minion in play, returning its current room and index position
 /// within that room, if any.
 pub fn minion_position(game: &GameState, minion_id: CardId) -> Option<(RoomId, usize)> {
@@ -235,10 +407,12 @@ pub fn played_position(
         CardType::ChampionSpell | CardType::OverlordSpell => CardPosition::DiscardPile(side),
         CardType::Weapon => CardPosition::ArenaItem(ItemLocation::Weapons),
         CardType::Artifact => CardPosition::ArenaItem(ItemLocation::Artifacts),
+        CardType::Ally => CardPosition::ArenaItem(ItemLocation::Allies),
         CardType::Minion => CardPosition::Room(target.room_id()?, RoomLocation::Defender),
         CardType::Project | CardType::Scheme => {
             CardPosition::Room(target.room_id()?, RoomLocation::Occupant)
         }
         CardType::Identity => CardPosition::Identity(side),
+        CardType::GlobalModifier => CardPosition::GlobalModifier,
     })
 }
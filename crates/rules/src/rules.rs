@@ -14,6 +14,7 @@
 
 //! All primary game rules, responses to user actions, and associated helpers
 
+#[cfg(not(feature = "hot_reload"))]
 use std::collections::HashMap;
 
 use dashmap::DashSet;
@@ -23,17 +24,22 @@ use data::game::GameState;
 use data::primitives::{AbilityId, CardId};
 use once_cell::sync::Lazy;
 
+pub mod card_overrides;
 pub mod card_prompt;
 pub mod constants;
 pub mod dispatch;
 pub mod flags;
+pub mod helpers;
 pub mod mana;
+pub mod modifiers;
 pub mod mutations;
 pub mod queries;
+pub mod synthetic_cards;
 
 pub static DEFINITIONS: Lazy<DashSet<fn() -> CardDefinition>> = Lazy::new(DashSet::new);
 
 /// Contains [CardDefinition]s for all known cards, keyed by [CardName]
+#[cfg(not(feature = "hot_reload"))]
 static CARDS: Lazy<HashMap<CardName, CardDefinition>> = Lazy::new(|| {
     let mut map = HashMap::new();
     for card_fn in DEFINITIONS.iter() {
@@ -43,20 +49,49 @@ static CARDS: Lazy<HashMap<CardName, CardDefinition>> = Lazy::new(|| {
     map
 });
 
-/// Returns an iterator over all known card definitions in an undefined order
+/// Returns an iterator over all known card definitions in an undefined order,
+/// including any [synthetic_cards] registered on the current thread.
+#[cfg(not(feature = "hot_reload"))]
 pub fn all_cards() -> impl Iterator<Item = &'static CardDefinition> {
     assert!(CARDS.len() > 0, "Must call initialize() first!");
-    CARDS.values()
+    CARDS.values().chain(synthetic_cards::all())
+}
+
+/// Returns an iterator over all known card definitions in an undefined order,
+/// with [card_overrides] applied, including any [synthetic_cards] registered on
+/// the current thread.
+#[cfg(feature = "hot_reload")]
+pub fn all_cards() -> impl Iterator<Item = &'static CardDefinition> {
+    let cards = card_overrides::all_cards();
+    assert!(!cards.is_empty(), "Must call initialize() first!");
+    cards.into_iter().chain(synthetic_cards::all())
 }
 
 /// Looks up the definition for a [CardName]. Panics if no such card is defined.
 /// If this panics, you are probably not calling initialize::run();
 pub fn get(name: CardName) -> &'static CardDefinition {
-    CARDS.get(&name).unwrap_or_else(|| panic!("Must call initialize() first!"))
+    if name.is_synthetic() {
+        return synthetic_cards::get(name)
+            .unwrap_or_else(|| panic!("Synthetic card {:?} not registered", name));
+    }
+    lookup(name).unwrap_or_else(|| panic!("Must call initialize() first!"))
+}
+
+#[cfg(not(feature = "hot_reload"))]
+fn lookup(name: CardName) -> Option<&'static CardDefinition> {
+    CARDS.get(&name)
+}
+
+/// Looks up a definition with [card_overrides] applied.
+#[cfg(feature = "hot_reload")]
+fn lookup(name: CardName) -> Option<&'static CardDefinition> {
+    card_overrides::get(name)
 }
 
+/// Looks up the definition for a card in a game, using the game's
+/// [data::game::DefinitionIndex] if it has been populated.
 pub fn card_definition(game: &GameState, card_id: CardId) -> &'static CardDefinition {
-    get(game.card(card_id).name)
+    game.definition(card_id).unwrap_or_else(|| get(game.card(card_id).name))
 }
 
 pub fn ability_definition(game: &GameState, ability_id: AbilityId) -> &'static Ability {
@@ -0,0 +1,78 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Registry for card definitions created at runtime by tests, which lets a
+//! test define a bespoke card inline instead of adding a permanent [CardName].
+//!
+//! Definitions are stored under a small pool of reserved names (see
+//! [CardName::is_synthetic]) which are assigned in order, so a single test can
+//! register at most eight cards. The registry is thread-local so that tests
+//! running in parallel cannot observe each other's cards.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use data::card_definition::CardDefinition;
+use data::card_name::CardName;
+
+const NAMES: [CardName; 8] = [
+    CardName::TestSynthetic1,
+    CardName::TestSynthetic2,
+    CardName::TestSynthetic3,
+    CardName::TestSynthetic4,
+    CardName::TestSynthetic5,
+    CardName::TestSynthetic6,
+    CardName::TestSynthetic7,
+    CardName::TestSynthetic8,
+];
+
+#[derive(Default)]
+struct Registry {
+    cards: HashMap<CardName, &'static CardDefinition>,
+    next: usize,
+}
+
+thread_local! {
+    static REGISTRY: RefCell<Registry> = RefCell::new(Registry::default());
+}
+
+/// Registers a [CardDefinition] for the current thread, replacing its name
+/// with the next available synthetic [CardName]. Returns the assigned name.
+///
+/// Definitions are leaked, since they must outlive the test which created
+/// them.
+///
+/// Panics if every synthetic name on this thread is already in use.
+pub fn register(mut definition: CardDefinition) -> CardName {
+    REGISTRY.with(|registry| {
+        let mut registry = registry.borrow_mut();
+        let name = *NAMES.get(registry.next).unwrap_or_else(|| {
+            panic!("Synthetic card names exhausted, at most {} may be registered", NAMES.len())
+        });
+        registry.next += 1;
+        definition.name = name;
+        registry.cards.insert(name, Box::leak(Box::new(definition)));
+        name
+    })
+}
+
+/// Looks up a definition previously passed to [register] on this thread.
+pub fn get(name: CardName) -> Option<&'static CardDefinition> {
+    REGISTRY.with(|registry| registry.borrow().cards.get(&name).copied())
+}
+
+/// Returns all definitions currently registered on this thread.
+pub fn all() -> Vec<&'static CardDefinition> {
+    REGISTRY.with(|registry| registry.borrow().cards.values().copied().collect())
+}
@@ -12,68 +12,100 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use assets;
-use assets::CardIconType;
-use data::card_definition::CardDefinition;
-use data::primitives::ManaValue;
+use assets::{self, CardIconType};
+use data::card_definition::{AbilityType, CardDefinition};
+use data::player_data::IconMode;
+use data::primitives::{AbilityId, ManaValue};
 use data::text::RulesTextContext;
 use protos::spelldawn::{CardIcon, CardIcons};
 use rules::queries;
 
-pub fn build(context: &RulesTextContext, definition: &CardDefinition, revealed: bool) -> CardIcons {
+pub fn build(
+    context: &RulesTextContext,
+    definition: &CardDefinition,
+    revealed: bool,
+    mode: IconMode,
+) -> CardIcons {
     let mut icons = CardIcons::default();
 
-    match context.card_data() {
-        Some(data) if data.card_level > 0 => {
-            icons.arena_icon = Some(CardIcon {
-                background: Some(assets::card_icon(CardIconType::LevelCounter)),
-                text: Some(data.card_level.to_string()),
-                background_scale: assets::background_scale(CardIconType::LevelCounter),
-            })
-        }
-        _ => {}
+    let card_level = context.card_level();
+    if card_level > 0 {
+        icons.arena_icon = Some(CardIcon {
+            background: Some(assets::card_icon(CardIconType::LevelCounter, mode)),
+            text: Some(card_level.to_string()),
+            background_scale: assets::background_scale(CardIconType::LevelCounter),
+            text_color: assets::card_icon_text_color(mode),
+        })
     }
 
-    match context.card_data() {
-        Some(data) if data.stored_mana > 0 => {
-            icons.arena_icon = Some(CardIcon {
-                background: Some(assets::card_icon(CardIconType::Mana)),
-                text: Some(data.stored_mana.to_string()),
-                background_scale: assets::background_scale(CardIconType::Mana),
-            })
-        }
-        _ => {}
+    let stored_mana = context.stored_mana();
+    if stored_mana > 0 {
+        icons.arena_icon = Some(CardIcon {
+            background: Some(assets::card_icon(CardIconType::Mana, mode)),
+            text: Some(stored_mana.to_string()),
+            background_scale: assets::background_scale(CardIconType::Mana),
+            text_color: assets::card_icon_text_color(mode),
+        })
+    }
+
+    if let Some(durability) = remaining_durability(context, definition) {
+        icons.arena_icon = Some(CardIcon {
+            background: Some(assets::card_icon(CardIconType::Durability, mode)),
+            text: Some(durability.to_string()),
+            background_scale: assets::background_scale(CardIconType::Durability),
+            text_color: assets::card_icon_text_color(mode),
+        })
     }
 
     if revealed {
+        if let Some(charges) = remaining_charges(context, definition) {
+            icons.top_right_icon = Some(CardIcon {
+                background: Some(assets::card_icon(CardIconType::Charges, mode)),
+                text: Some(charges.to_string()),
+                background_scale: assets::background_scale(CardIconType::Charges),
+                text_color: assets::card_icon_text_color(mode),
+            })
+        }
+
         icons.top_left_icon =
             if let Some(mana_cost) = context.query_or(definition.cost.mana, queries::mana_cost) {
-                Some(mana_card_icon(mana_cost))
+                Some(if definition.cost.variable_mana {
+                    variable_mana_card_icon(mana_cost, mode)
+                } else {
+                    mana_card_icon(mana_cost, mode)
+                })
             } else {
                 definition.config.stats.scheme_points.map(|points| CardIcon {
-                    background: Some(assets::card_icon(CardIconType::LevelRequirement)),
+                    background: Some(assets::card_icon(CardIconType::LevelRequirement, mode)),
                     text: Some(points.level_requirement.to_string()),
                     background_scale: assets::background_scale(CardIconType::LevelRequirement),
+                    text_color: assets::card_icon_text_color(mode),
                 })
             };
 
-        icons.bottom_right_icon = if let Some(attack) = definition.config.stats.base_attack {
+        icons.bottom_right_icon = if let Some(base_attack) = definition.config.stats.base_attack {
+            let attack = base_attack
+                + context.boost_count()
+                    * definition.config.stats.attack_boost.map_or(0, |boost| boost.bonus);
             Some(CardIcon {
-                background: Some(assets::card_icon(CardIconType::Attack)),
+                background: Some(assets::card_icon(CardIconType::Attack, mode)),
                 text: Some(context.query_or(attack, queries::attack).to_string()),
                 background_scale: assets::background_scale(CardIconType::Attack),
+                text_color: assets::card_icon_text_color(mode),
             })
         } else if let Some(health) = definition.config.stats.health {
             Some(CardIcon {
-                background: Some(assets::card_icon(CardIconType::Health)),
-                text: Some(context.query_or(health, queries::health).to_string()),
+                background: Some(assets::card_icon(CardIconType::Health, mode)),
+                text: Some(context.query_or(health, queries::remaining_health).to_string()),
                 background_scale: assets::background_scale(CardIconType::Health),
+                text_color: assets::card_icon_text_color(mode),
             })
         } else {
             definition.config.stats.scheme_points.map(|points| CardIcon {
-                background: Some(assets::card_icon(CardIconType::Points)),
+                background: Some(assets::card_icon(CardIconType::Points, mode)),
                 text: Some(points.points.to_string()),
                 background_scale: assets::background_scale(CardIconType::Points),
+                text_color: assets::card_icon_text_color(mode),
             })
         };
 
@@ -81,9 +113,10 @@ pub fn build(context: &RulesTextContext, definition: &CardDefinition, revealed:
             context.query_or(definition.config.stats.shield.unwrap_or_default(), queries::shield);
         icons.bottom_left_icon = if shield > 0 {
             Some(CardIcon {
-                background: Some(assets::card_icon(CardIconType::Shield)),
+                background: Some(assets::card_icon(CardIconType::Shield, mode)),
                 text: Some(shield.to_string()),
                 background_scale: assets::background_scale(CardIconType::Shield),
+                text_color: assets::card_icon_text_color(mode),
             })
         } else {
             None
@@ -93,10 +126,51 @@ pub fn build(context: &RulesTextContext, definition: &CardDefinition, revealed:
     icons
 }
 
-pub fn mana_card_icon(value: ManaValue) -> CardIcon {
+/// Returns the lowest number of remaining charges among the activated
+/// abilities of this card which have limited charges, if any.
+fn remaining_charges(context: &RulesTextContext, definition: &CardDefinition) -> Option<u32> {
+    definition
+        .abilities
+        .iter()
+        .enumerate()
+        .filter_map(|(index, ability)| match &ability.ability_type {
+            AbilityType::Activated(cost, _) => cost.limit.charges.map(|charges| {
+                context.query_or(charges, |game, card_id| {
+                    queries::remaining_charges(game, AbilityId::new(card_id, index))
+                        .unwrap_or(charges)
+                })
+            }),
+            _ => None,
+        })
+        .min()
+}
+
+/// Returns the remaining uses of this card if it is a weapon with durability
+/// which is currently in play.
+fn remaining_durability(context: &RulesTextContext, definition: &CardDefinition) -> Option<u32> {
+    definition.config.stats.durability?;
+    match context {
+        RulesTextContext::Game(game, card) if card.position().in_play() => {
+            queries::remaining_durability(game, card.id)
+        }
+        _ => None,
+    }
+}
+
+pub fn mana_card_icon(value: ManaValue, mode: IconMode) -> CardIcon {
     CardIcon {
-        background: Some(assets::card_icon(CardIconType::Mana)),
+        background: Some(assets::card_icon(CardIconType::Mana, mode)),
         text: Some(value.to_string()),
         background_scale: assets::background_scale(CardIconType::Mana),
+        text_color: assets::card_icon_text_color(mode),
+    }
+}
+
+/// Icon for a variable mana cost of `value` plus X, see
+/// [data::card_definition::Cost::variable_mana].
+pub fn variable_mana_card_icon(value: ManaValue, mode: IconMode) -> CardIcon {
+    CardIcon {
+        text: Some(if value == 0 { "X".to_string() } else { format!("{}+X", value) }),
+        ..mana_card_icon(value, mode)
     }
 }
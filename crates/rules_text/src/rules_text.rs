@@ -28,25 +28,36 @@ use data::text::{
     TextToken,
 };
 use prompts::card_info::SupplementalCardInfo;
-use protos::spelldawn::{Node, RulesText};
+use protos::spelldawn::{KeywordTooltip, Node, RulesText};
 
 /// Primary function which turns the current state of a card into its client
 /// [RulesText] representation
 pub fn build(context: &RulesTextContext, definition: &CardDefinition) -> RulesText {
     let mut lines = vec![];
+    let mut has_modes = false;
     for (_index, ability) in definition.abilities.iter().enumerate() {
         let mut line = String::new();
-        if let AbilityType::Activated(cost, _) = &ability.ability_type {
+        if let AbilityType::Activated(cost, _) | AbilityType::EncounterActivated(cost) =
+            &ability.ability_type
+        {
             line.push_str(&ability_cost_string(cost));
         }
 
+        if let AbilityType::Mode(_) = &ability.ability_type {
+            if !has_modes {
+                lines.push("Choose one:".to_string());
+                has_modes = true;
+            }
+            let _err = write!(line, "{} ", icons::BULLET);
+        }
+
         line.push_str(&ability_text(context, ability));
 
         lines.push(line);
     }
 
     if let Some(breach) = definition.config.stats.breach {
-        lines.push(process_text_tokens(&[TextToken::Keyword(Keyword::Breach(breach))]));
+        lines.push(process_text_tokens(&[TextToken::Keyword(Keyword::Breach(breach))], false));
     }
 
     RulesText { text: lines.join("\n") }
@@ -56,10 +67,9 @@ pub fn build(context: &RulesTextContext, definition: &CardDefinition) -> RulesTe
 /// any).
 pub fn ability_text(context: &RulesTextContext, ability: &Ability) -> String {
     match &ability.text {
-        AbilityText::Text(text) => process_text_tokens(text),
+        AbilityText::Text(text) => process_text_tokens(text, false),
         AbilityText::TextFn(function) => {
-            let tokens = function(context);
-            process_text_tokens(&tokens)
+            process_text_tokens(&function(context), context.preview_state().is_some())
         }
     }
 }
@@ -75,6 +85,24 @@ pub fn build_supplemental_info(
 ) -> Option<Node> {
     let definition = rules::get(context.card_name());
     let mut result = vec![card_type_line(definition)];
+    result.extend(
+        build_keyword_tooltips(context, ability_index)
+            .into_iter()
+            .map(|tooltip| format!("<b>{}:</b> {}", tooltip.name, tooltip.text)),
+    );
+    SupplementalCardInfo::new(result).build()
+}
+
+/// Builds a [KeywordTooltip] explaining each keyword which appears in the
+/// rules text of a card, in a consistent order.
+///
+/// If an `ability_index` is provided, only keywords for that index are
+/// returned. Otherwise, keywords for all abilities are returned.
+pub fn build_keyword_tooltips(
+    context: &RulesTextContext,
+    ability_index: Option<AbilityIndex>,
+) -> Vec<KeywordTooltip> {
+    let definition = rules::get(context.card_name());
     let mut keywords = vec![];
     for (index, ability) in definition.abilities.iter().enumerate() {
         if matches!(ability_index, Some(i) if i.value() != index) {
@@ -94,8 +122,9 @@ pub fn build_supplemental_info(
         keywords.push(KeywordKind::Breach);
     }
 
-    process_keywords(&mut keywords, &mut result);
-    SupplementalCardInfo::new(result).build()
+    keywords.sort();
+    keywords.dedup();
+    keywords.into_iter().filter_map(keyword_tooltip).collect()
 }
 
 fn ability_cost_string(cost: &Cost<AbilityId>) -> String {
@@ -103,7 +132,8 @@ fn ability_cost_string(cost: &Cost<AbilityId>) -> String {
 
     if let Some(mana) = cost.mana {
         if mana > 0 {
-            let _err = write!(actions, ",{}{}", mana, icons::MANA);
+            let separator = if actions.is_empty() { "" } else { "," };
+            let _err = write!(actions, "{}{}{}", separator, mana, icons::MANA);
         }
     }
 
@@ -111,8 +141,12 @@ fn ability_cost_string(cost: &Cost<AbilityId>) -> String {
     actions
 }
 
-/// Primary function for converting a sequence of [TextToken]s into a string
-fn process_text_tokens(tokens: &[TextToken]) -> String {
+/// Primary function for converting a sequence of [TextToken]s into a string.
+///
+/// If `highlight_computed` is true, [TextToken::Computed] values are rendered
+/// in a distinct color to indicate that they are derived from a
+/// [data::text::PreviewState].
+fn process_text_tokens(tokens: &[TextToken], highlight_computed: bool) -> String {
     let mut result = vec![];
     for token in tokens {
         result.push(match token {
@@ -181,7 +215,21 @@ fn process_text_tokens(tokens: &[TextToken]) -> String {
                 Keyword::Construct => "<b>Construct</b>".to_string(),
             },
             TextToken::Reminder(text) => format!("<i>{}</i>", text),
-            TextToken::Cost(cost) => format!("{}: ", process_text_tokens(cost)),
+            TextToken::Cost(cost) => {
+                format!("{}: ", process_text_tokens(cost, highlight_computed))
+            }
+            TextToken::Computed(token) => {
+                let text = process_text_tokens(&[token.as_ref().clone()], highlight_computed);
+                if highlight_computed {
+                    format!(
+                        "<color={}>{}</color>",
+                        design::as_hex(FontColor::PreviewValue.into()),
+                        text
+                    )
+                } else {
+                    text
+                }
+            }
         })
     }
 
@@ -196,11 +244,13 @@ fn card_type_line(definition: &CardDefinition) -> String {
         CardType::ChampionSpell => "Spell",
         CardType::Weapon => "Weapon",
         CardType::Artifact => "Artifact",
+        CardType::Ally => "Ally",
         CardType::OverlordSpell => "Spell",
         CardType::Minion => "Minion",
         CardType::Project => "Project",
         CardType::Scheme => "Scheme",
         CardType::Identity => "Identity",
+        CardType::GlobalModifier => "Global Modifier",
     });
 
     if let Some(lineage) = definition.config.lineage {
@@ -220,6 +270,7 @@ fn card_type_line(definition: &CardDefinition) -> String {
         result.push_str(" • ");
         result.push_str(match subtype {
             CardSubtype::Silvered => "Silvered",
+            CardSubtype::Reaction => "Reaction",
         });
     }
 
@@ -236,77 +287,44 @@ fn find_keywords(tokens: &[TextToken], keywords: &mut Vec<KeywordKind>) {
     }));
 }
 
-fn process_keywords(keywords: &mut Vec<KeywordKind>, output: &mut Vec<String>) {
-    keywords.sort();
-    keywords.dedup();
-
-    for keyword in keywords {
-        match keyword {
-            KeywordKind::Play => {
-                output.push("<b>Play:</b> Triggers when this card enters the arena.".to_string());
-            }
-            KeywordKind::Dawn => {
-                output
-                    .push("<b>Dawn:</b> Triggers at the start of the Champion's turn.".to_string());
-            }
-            KeywordKind::Dusk => {
-                output
-                    .push("<b>Dusk:</b> Triggers at the start of the Overlord's turn.".to_string());
-            }
-            KeywordKind::Score => {
-                output
-                    .push("<b>Score:</b> Triggers when the Overlord scores this card.".to_string());
-            }
-            KeywordKind::Combat => {
-                output.push(
-                    "<b>Combat:</b> Triggers if this minion is not defeated during a raid."
-                        .to_string(),
-                );
-            }
-            KeywordKind::Encounter => {
-                output.push(
-                    "<b>Encounter:</b> Triggers when this minion is approached during a raid."
-                        .to_string(),
-                );
-            }
-            KeywordKind::Unveil => {
-                output.push("<b>Unveil:</b> Pay cost and turn face up (if able)".to_string());
-            }
-            KeywordKind::SuccessfulRaid => {
-                output.push(
-                    "<b>Successful Raid:</b> Triggers after the access phase of a raid."
-                        .to_string(),
-                );
-            }
-            KeywordKind::Store => {
-                output.push(format!(
-                    "<b>Store:</b> Place {} on this card to take later.",
-                    icons::MANA
-                ));
-            }
-            KeywordKind::DealDamage => {
-                output.push(
-                    "<b>Damage:</b> Causes the Champion to discard cards at random.".to_string(),
-                );
-            }
-            KeywordKind::InnerRoom => {
-                output.push("<b>Inner Room:</b> The Sanctum, Vault or Crypts.".to_string())
-            }
-            KeywordKind::Breach => output.push(
-                "<b>Breach:</b> Allows this weapon to bypass some amount of Shield.".to_string(),
-            ),
-            KeywordKind::LevelUp => output.push(
-                "<b>Level Up:</b> This card gets level counters when its room is leveled up."
-                    .to_string(),
-            ),
-            KeywordKind::Trap => output.push(
-                "<b>Trap:</b> Triggers when this card is accessed during a raid.".to_string(),
-            ),
-            KeywordKind::Construct => output.push(
-                "<b>Construct:</b> Goes to discard pile when defeated. Damage with any weapon."
-                    .to_string(),
-            ),
-            _ => {}
-        };
-    }
+/// Returns the name and reminder text for a keyword, or None if this keyword
+/// does not require explanation.
+fn keyword_tooltip(keyword: KeywordKind) -> Option<KeywordTooltip> {
+    let (name, text) = match keyword {
+        KeywordKind::Play => ("Play", "Triggers when this card enters the arena.".to_string()),
+        KeywordKind::Dawn => ("Dawn", "Triggers at the start of the Champion's turn.".to_string()),
+        KeywordKind::Dusk => ("Dusk", "Triggers at the start of the Overlord's turn.".to_string()),
+        KeywordKind::Score => ("Score", "Triggers when the Overlord scores this card.".to_string()),
+        KeywordKind::Combat => {
+            ("Combat", "Triggers if this minion is not defeated during a raid.".to_string())
+        }
+        KeywordKind::Encounter => {
+            ("Encounter", "Triggers when this minion is approached during a raid.".to_string())
+        }
+        KeywordKind::Unveil => ("Unveil", "Pay cost and turn face up (if able)".to_string()),
+        KeywordKind::SuccessfulRaid => {
+            ("Successful Raid", "Triggers after the access phase of a raid.".to_string())
+        }
+        KeywordKind::Store => {
+            ("Store", format!("Place {} on this card to take later.", icons::MANA))
+        }
+        KeywordKind::DealDamage => {
+            ("Damage", "Causes the Champion to discard cards at random.".to_string())
+        }
+        KeywordKind::InnerRoom => ("Inner Room", "The Sanctum, Vault or Crypts.".to_string()),
+        KeywordKind::Breach => {
+            ("Breach", "Allows this weapon to bypass some amount of Shield.".to_string())
+        }
+        KeywordKind::LevelUp => {
+            ("Level Up", "This card gets level counters when its room is leveled up.".to_string())
+        }
+        KeywordKind::Trap => {
+            ("Trap", "Triggers when this card is accessed during a raid.".to_string())
+        }
+        KeywordKind::Construct => {
+            ("Construct", "Goes to discard pile when defeated. Damage with any weapon.".to_string())
+        }
+        KeywordKind::Take => return None,
+    };
+    Some(KeywordTooltip { name: name.to_string(), text })
 }
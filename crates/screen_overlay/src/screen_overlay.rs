@@ -22,6 +22,7 @@ use core_ui::panels::Panels;
 use core_ui::prelude::*;
 use core_ui::style::Corner;
 use core_ui::text::Text;
+use data::match_state::MatchState;
 use data::player_data::PlayerData;
 use data::primitives::DeckId;
 use data::tutorial::TutorialMessageKey;
@@ -30,13 +31,28 @@ use protos::spelldawn::{FlexAlign, FlexJustify, FlexPosition};
 
 pub struct ScreenOverlay<'a> {
     player: &'a PlayerData,
+    match_state: Option<&'a MatchState>,
     show_close_button: Option<PanelAddress>,
     show_deck_button: bool,
+    show_cosmetics_button: bool,
 }
 
 impl<'a> ScreenOverlay<'a> {
     pub fn new(player: &'a PlayerData) -> Self {
-        Self { player, show_close_button: None, show_deck_button: true }
+        Self {
+            player,
+            match_state: None,
+            show_close_button: None,
+            show_deck_button: true,
+            show_cosmetics_button: false,
+        }
+    }
+
+    /// Displays the score of the best-of-three match the player is currently
+    /// participating in, if any.
+    pub fn match_state(mut self, match_state: Option<&'a MatchState>) -> Self {
+        self.match_state = match_state;
+        self
     }
 
     pub fn show_close_button(mut self, show_close_button: PanelAddress) -> Self {
@@ -48,6 +64,11 @@ impl<'a> ScreenOverlay<'a> {
         self.show_deck_button = show_deck_button;
         self
     }
+
+    pub fn show_cosmetics_button(mut self, show_cosmetics_button: bool) -> Self {
+        self.show_cosmetics_button = show_cosmetics_button;
+        self
+    }
 }
 
 impl<'a> Component for ScreenOverlay<'a> {
@@ -79,29 +100,36 @@ impl<'a> Component for ScreenOverlay<'a> {
                             .layout(Layout::new().margin(Edge::All, 12.px())),
                     )
                     .child(self.player.adventure.as_ref().map(|adventure| {
-                        Row::new("CoinCount")
-                            .style(
-                                Style::new()
-                                    .margin(Edge::Horizontal, 12.px())
-                                    .padding(Edge::Horizontal, 8.px())
-                                    .height(80.px())
-                                    .background_color(BackgroundColor::CoinCountOverlay)
-                                    .border_radius(Corner::All, 12.px())
-                                    .border_color(Edge::All, COIN_COUNT_BORDER)
-                                    .border_width(Edge::All, 1.px()),
-                            )
-                            .child(
-                                Text::new(format!(
-                                    "{} <color=yellow>{}</color>",
-                                    adventure.coins,
-                                    icons::COINS
-                                ))
-                                .font_size(FontSize::CoinCount),
-                            )
+                        resource_count(
+                            "CoinCount",
+                            format!("{} <color=yellow>{}</color>", adventure.coins, icons::COINS),
+                        )
+                    }))
+                    .child(self.player.adventure.as_ref().map(|adventure| {
+                        resource_count(
+                            "StaminaCount",
+                            format!(
+                                "{} <color=green>{}</color>",
+                                adventure.stamina,
+                                icons::STAMINA
+                            ),
+                        )
+                    }))
+                    .child(self.match_state.and_then(|state| {
+                        let wins = state.player(self.player.id).ok()?.wins;
+                        let losses = state.opponent(self.player.id).ok()?.wins;
+                        Some(resource_count("MatchScore", format!("Match {wins} - {losses}")))
                     })),
             )
             .child(
                 Row::new("Right")
+                    .child(self.show_cosmetics_button.then(|| {
+                        IconButton::new(icons::MAGIC)
+                            .name(&element_names::COSMETICS_BUTTON)
+                            .button_type(IconButtonType::NavBrown)
+                            .action(Panels::open(PanelAddress::DeckCosmetics))
+                            .layout(Layout::new().margin(Edge::All, 12.px()))
+                    }))
                     .child(self.show_deck_button.then(|| {
                         IconButton::new(icons::DECK)
                             .name(&element_names::DECK_BUTTON)
@@ -135,3 +163,20 @@ impl<'a> Component for ScreenOverlay<'a> {
             .build()
     }
 }
+
+/// Displays the quantity of an adventure resource such as coins, or the current
+/// match score
+fn resource_count(name: &str, text: String) -> impl Component {
+    Row::new(name)
+        .style(
+            Style::new()
+                .margin(Edge::Horizontal, 12.px())
+                .padding(Edge::Horizontal, 8.px())
+                .height(80.px())
+                .background_color(BackgroundColor::CoinCountOverlay)
+                .border_radius(Corner::All, 12.px())
+                .border_color(Edge::All, COIN_COUNT_BORDER)
+                .border_width(Edge::All, 1.px()),
+        )
+        .child(Text::new(text).font_size(FontSize::CoinCount))
+}
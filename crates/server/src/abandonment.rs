@@ -0,0 +1,125 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Detects players who have disconnected from an ongoing game and forfeits
+//! the game on their behalf if they do not return within the game's grace
+//! period. Games which both players have left end without a result.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::Result;
+use data::game::{GamePhase, GameState};
+use data::notification::Notification;
+use data::player_data::{self, PlayerState};
+use data::player_name::PlayerId;
+use data::primitives::Side;
+use database::{Database, SledDatabase};
+use display::interface;
+use protos::spelldawn::CommandList;
+use rules::{mutations, queries};
+use tracing::{error, info};
+
+use crate::{connections, notifications, requests};
+
+/// How often to check for disconnected players
+const CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Periodically checks for disconnected players, running forever.
+pub async fn monitor() {
+    loop {
+        tokio::time::sleep(CHECK_INTERVAL).await;
+        let mut db = SledDatabase { flush_on_write: false };
+        for player_id in connections::disconnected_players() {
+            match handle_disconnect(&mut db, player_id, timestamp()) {
+                Ok(response) => requests::send_player_response(response).await,
+                Err(error) => error!(?player_id, ?error, "Disconnect Error!"),
+            }
+        }
+    }
+}
+
+/// Records that `player_id` is disconnected from their current game as of
+/// `timestamp` (in seconds since the Unix epoch), forfeiting the game if they
+/// have exceeded its grace period. Panels cached for the player are discarded.
+///
+/// Returns a response to send to the opponent, if any. Players who are not in
+/// an ongoing game stop being tracked as disconnected. If the opponent is also
+/// disconnected once the grace period expires, the game is abandoned and both
+/// players are removed from it without recording a result.
+pub fn handle_disconnect(
+    database: &mut impl Database,
+    player_id: PlayerId,
+    timestamp: u64,
+) -> Result<Option<(PlayerId, CommandList)>> {
+    routing::invalidate(player_id);
+    let game_id = player_data::current_game_id(database.player(player_id)?);
+    let game = match game_id {
+        Some(game_id) if database.has_game(game_id)? => Some(database.game(game_id)?),
+        _ => None,
+    };
+    let Some(game) = game.filter(|g| !matches!(g.data.phase, GamePhase::GameOver { .. })) else {
+        connections::remove(player_id);
+        return Ok(None);
+    };
+
+    if !needs_update(&game, requests::user_side(player_id, &game)?, timestamp) {
+        return Ok(None);
+    }
+
+    if queries::is_abandoned(&game, timestamp) {
+        abandon(database, &game)?;
+        connections::remove(player_id);
+        return Ok(None);
+    }
+
+    let response = requests::handle_custom_action(database, player_id, game_id, |game, side| {
+        mutations::player_disconnected(game, side, timestamp);
+        mutations::check_disconnect_forfeit(game, timestamp)
+    })?;
+    let mut opponent_response = response.opponent_response;
+    if let Some((opponent_id, commands)) = &mut opponent_response {
+        interface::diff_commands(*opponent_id, commands);
+    }
+    Ok(opponent_response)
+}
+
+/// Returns true if the game needs to be written for a disconnection check of
+/// the `side` player at `timestamp`, i.e. if no disconnection has been recorded
+/// for them yet or their disconnection has exceeded the grace period.
+fn needs_update(game: &GameState, side: Side, timestamp: u64) -> bool {
+    game.player(side).disconnected.is_none() || queries::has_forfeited(game, side, timestamp)
+}
+
+/// Removes both players from an abandoned game, queueing a notification to
+/// explain what happened the next time they connect.
+fn abandon(database: &mut impl Database, game: &GameState) -> Result<()> {
+    info!(game_id = ?game.id, "abandon_game");
+    for side in enum_iterator::all::<Side>() {
+        let mut player = match database.player(game.player(side).id)? {
+            Some(player) if matches!(player.state, Some(PlayerState::Playing(id)) if id == game.id) => {
+                player
+            }
+            _ => continue,
+        };
+        player.state = None;
+        notifications::send(&mut player, Notification::GameAbandoned, false);
+        requests::write_player(database, &mut player)?;
+    }
+    Ok(())
+}
+
+/// Current time in seconds since the Unix epoch
+pub fn timestamp() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs())
+}
@@ -14,6 +14,7 @@
 
 //! Functions  for providing AI responses to the user
 
+use std::panic::{self, AssertUnwindSafe};
 use std::sync::atomic::{AtomicBool, Ordering};
 
 use ai_core::agent::{Agent, AgentConfig};
@@ -26,11 +27,13 @@ use data::player_data;
 use data::player_name::{NamedPlayer, PlayerId};
 use data::primitives::{GameId, Side};
 use database::Database;
+use display::interface;
 use once_cell::sync::Lazy;
 use protos::spelldawn::{CommandList, GameRequest};
+use tracing::{error, info_span, Instrument};
 use with_error::fail;
 
-use crate::requests;
+use crate::{quarantine, requests};
 
 // This feels safe-ish?
 static AGENT_RUNNING: AtomicBool = AtomicBool::new(false);
@@ -59,21 +62,48 @@ pub fn handle_request(
         _ => return Ok(()),
     };
     let game = database.game(game_id)?;
+    if game.data.quarantined.is_some() {
+        return Ok(());
+    }
 
-    if active_agent(&game).is_some() && !AGENT_RUNNING.swap(true, Ordering::Relaxed) {
-        tokio::spawn(async move {
-            run_agent_loop(database, game_id, respond_to, handle_request)
-                .await
-                .expect("Error running agent");
-            AGENT_RUNNING.store(false, Ordering::Relaxed);
-        });
+    // Checking whether an agent can act runs card code, so it can panic just
+    // like the agent's own actions.
+    let can_act = match panic::catch_unwind(AssertUnwindSafe(|| active_agent(&game).is_some())) {
+        Ok(can_act) => can_act,
+        Err(payload) => {
+            let message = quarantine::panic_message(payload.as_ref());
+            error!(?game_id, ?message, "Panic checking for agent!");
+            let response = quarantine::quarantine(&mut database, game_id, respond_to, message)?;
+            tokio::spawn(async move {
+                if let Err(error) =
+                    send_response(&handle_request, respond_to, response.command_list).await
+                {
+                    error!(?error, "Agent Error!");
+                }
+            });
+            return Ok(());
+        }
+    };
+
+    if can_act && !AGENT_RUNNING.swap(true, Ordering::Relaxed) {
+        let span = info_span!("agent_loop", ?game_id, player_id = ?respond_to);
+        tokio::spawn(
+            async move {
+                let result = run_agent_loop(database, game_id, respond_to, handle_request).await;
+                AGENT_RUNNING.store(false, Ordering::Relaxed);
+                if let Err(error) = result {
+                    error!(?error, "Agent Error!");
+                }
+            }
+            .instrument(span),
+        );
     }
     Ok(())
 }
 
 /// Returns a ([Side], [AgentData]) tuple for an agent that can currently act in
 /// this game, if one exists.
-fn active_agent(game: &GameState) -> Option<(Side, Box<dyn Agent<SpelldawnState>>)> {
+pub(crate) fn active_agent(game: &GameState) -> Option<(Side, Box<dyn Agent<SpelldawnState>>)> {
     for side in enum_iterator::all::<Side>() {
         if let PlayerId::Named(name) = game.player(side).id {
             if name != NamedPlayer::TestNoAction && actions::can_take_action(game, side) {
@@ -91,34 +121,70 @@ async fn run_agent_loop(
     handle_request: HandleRequest,
 ) -> Result<()> {
     loop {
-        let game = SpelldawnState(database.game(game_id)?);
-        let commands = if let Some((side, agent)) = active_agent(&game) {
-            let action = agent.pick_action(AgentConfig::with_deadline(3), &game)?;
-            let response = requests::handle_game_action(
-                &mut database,
-                game.player(side).id,
-                Some(game_id),
-                action,
-            )?;
-
-            match response.opponent_response {
-                Some((oid, response)) if oid == respond_to => response,
-                _ if game.player(side).id == respond_to => response.command_list,
-                _ => {
-                    fail!("Unknown PlayerId {:?}", respond_to);
-                }
+        // Agents simulate card code while searching, so they can panic just like
+        // a player's action.
+        let step = panic::catch_unwind(AssertUnwindSafe(|| {
+            perform_agent_action(&mut database, game_id, respond_to)
+        }));
+        let commands = match step {
+            Ok(Ok(Some(commands))) => commands,
+            Ok(Ok(None)) => return Ok(()),
+            Ok(Err(error)) => return Err(error),
+            Err(payload) => {
+                let message = quarantine::panic_message(payload.as_ref());
+                error!(?game_id, ?message, "Panic running agent!");
+                let response = quarantine::quarantine(&mut database, game_id, respond_to, message)?;
+                send_response(&handle_request, respond_to, response.command_list).await?;
+                return Ok(());
             }
-        } else {
-            return Ok(());
         };
 
-        match handle_request {
-            HandleRequest::SendToPlayer => {
-                requests::send_player_response(Some((respond_to, commands))).await;
-            }
-            HandleRequest::PushQueue => {
-                RESPONSES.push(commands)?;
-            }
+        send_response(&handle_request, respond_to, commands).await?;
+    }
+}
+
+/// Performs a single action for the agent which can currently act in this
+/// game, if any. Returns the commands to send to the `respond_to` player.
+fn perform_agent_action(
+    database: &mut impl Database,
+    game_id: GameId,
+    respond_to: PlayerId,
+) -> Result<Option<CommandList>> {
+    let mut game = SpelldawnState(database.game(game_id)?);
+    let Some((side, agent)) = active_agent(&game) else {
+        return Ok(None);
+    };
+
+    let action =
+        agents::pick_action(agent.as_ref(), AgentConfig::with_deadline(3), &mut game, side)?;
+    if game.data.config.explain_agents {
+        database.write_game(&game)?;
+    }
+    let response =
+        requests::handle_game_action(database, game.player(side).id, Some(game_id), action)?;
+
+    Ok(Some(match response.opponent_response {
+        Some((oid, response)) if oid == respond_to => response,
+        _ if game.player(side).id == respond_to => response.command_list,
+        _ => {
+            fail!("Unknown PlayerId {:?}", respond_to);
+        }
+    }))
+}
+
+async fn send_response(
+    handle_request: &HandleRequest,
+    respond_to: PlayerId,
+    mut commands: CommandList,
+) -> Result<()> {
+    interface::diff_commands(respond_to, &mut commands);
+    match handle_request {
+        HandleRequest::SendToPlayer => {
+            requests::send_player_response(Some((respond_to, commands))).await;
+        }
+        HandleRequest::PushQueue => {
+            RESPONSES.push(commands)?;
         }
     }
+    Ok(())
 }
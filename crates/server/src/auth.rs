@@ -0,0 +1,204 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Issues and validates the session tokens which authenticate players.
+//!
+//! The first time a player authenticates, a random secret is returned to the
+//! client and a hash of it is stored via the [Database] trait. Every later
+//! authentication for that player must present the same secret.
+//!
+//! A session token is an expiry time and random nonce followed by an
+//! HMAC-SHA256 signature over both and the [PlayerId] it was issued to.
+//! Validating a token is a signature check for the claimed player rather than
+//! a lookup. The signing key is created once by [initialize] when the server
+//! starts.
+//!
+//! Server identifiers name the AI agents, which act in-process and never send
+//! requests over the network, so clients may not authenticate with them.
+
+use std::fmt::Write;
+
+use anyhow::Result;
+use data::player_name::PlayerId;
+use database::Database;
+use hmac::{Hmac, Mac};
+use protos::spelldawn::player_identifier::PlayerIdentifierType;
+use protos::spelldawn::{AuthenticateRequest, AuthenticateResponse, PlayerIdentifier};
+use rand::rngs::OsRng;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+use with_error::{fail, verify, WithError};
+
+use crate::{abandonment, requests};
+
+/// Number of random bytes in a session token nonce.
+const NONCE_BYTES: usize = 16;
+
+/// Number of random bytes in a newly-generated signing key or player secret.
+const KEY_BYTES: usize = 32;
+
+/// Time in seconds after which a session token expires.
+pub const TOKEN_LIFETIME: u64 = 7 * 24 * 60 * 60;
+
+/// Creates the key used to sign session tokens if it does not exist yet. Must
+/// be called before handling any requests.
+pub fn initialize(database: &mut impl Database) -> Result<()> {
+    if database.signing_key()?.is_none() {
+        database.create_signing_key(&random_bytes())?;
+    }
+    Ok(())
+}
+
+/// Issues a new session token for the player identified in `request`.
+pub fn authenticate(
+    database: &mut impl Database,
+    request: &AuthenticateRequest,
+) -> Result<AuthenticateResponse> {
+    authenticate_at(database, request, abandonment::timestamp())
+}
+
+/// Equivalent to [authenticate] for a session beginning at `timestamp`, in
+/// seconds since the Unix epoch.
+pub fn authenticate_at(
+    database: &mut impl Database,
+    request: &AuthenticateRequest,
+    timestamp: u64,
+) -> Result<AuthenticateResponse> {
+    let player_id = client_player_id(database, &request.player_id)?;
+    let player_secret = match database.player_secret(player_id)? {
+        Some(hash) => {
+            let provided = Sha256::digest(request.player_secret.as_bytes());
+            verify!(
+                bool::from(provided.as_slice().ct_eq(&hash)),
+                "Invalid player secret for {:?}",
+                player_id
+            );
+            String::new()
+        }
+        None => {
+            let secret = to_hex(&random_bytes());
+            if !database.create_player_secret(player_id, &Sha256::digest(secret.as_bytes()))? {
+                fail!("Player secret for {:?} was created concurrently", player_id);
+            }
+            secret
+        }
+    };
+
+    let expiry = timestamp + TOKEN_LIFETIME;
+    let mut nonce = [0u8; NONCE_BYTES];
+    OsRng.fill_bytes(&mut nonce);
+    let key = signing_key(database)?;
+    let signature = signer(&key, player_id, expiry, &nonce)?.finalize().into_bytes();
+    Ok(AuthenticateResponse {
+        session_token: format!("{}.{}.{}", expiry, to_hex(&nonce), to_hex(&signature)),
+        player_secret,
+    })
+}
+
+/// Resolves the [PlayerId] making a request, returning an error if
+/// `session_token` was not issued to this player or has expired.
+pub fn player_id(
+    database: &mut impl Database,
+    identifier: &Option<PlayerIdentifier>,
+    session_token: &str,
+) -> Result<PlayerId> {
+    player_id_at(database, identifier, session_token, abandonment::timestamp())
+}
+
+/// Equivalent to [player_id] for a request made at `timestamp`, in seconds
+/// since the Unix epoch.
+pub fn player_id_at(
+    database: &mut impl Database,
+    identifier: &Option<PlayerIdentifier>,
+    session_token: &str,
+    timestamp: u64,
+) -> Result<PlayerId> {
+    let player_id = client_player_id(database, identifier)?;
+    verify!(!session_token.is_empty(), "Session token is required for {:?}", player_id);
+    let mut parts = session_token.split('.');
+    let (expiry, nonce, signature) = match (parts.next(), parts.next(), parts.next(), parts.next())
+    {
+        (Some(expiry), Some(nonce), Some(signature), None) => {
+            (expiry.parse::<u64>().ok(), from_hex(nonce), from_hex(signature))
+        }
+        _ => (None, None, None),
+    };
+    let (Some(expiry), Some(nonce), Some(signature)) = (expiry, nonce, signature) else {
+        fail!("Malformed session token for {:?}", player_id);
+    };
+
+    let key = signing_key(database)?;
+    verify!(
+        signer(&key, player_id, expiry, &nonce)?.verify_slice(&signature).is_ok(),
+        "Invalid session token for {:?}",
+        player_id
+    );
+    verify!(timestamp < expiry, "Session token for {:?} has expired", player_id);
+    Ok(player_id)
+}
+
+/// Resolves the [PlayerId] for an identifier supplied by a client, rejecting
+/// server identifiers.
+fn client_player_id(
+    database: &mut impl Database,
+    identifier: &Option<PlayerIdentifier>,
+) -> Result<PlayerId> {
+    verify!(
+        !matches!(
+            identifier.as_ref().and_then(|i| i.player_identifier_type.as_ref()),
+            Some(PlayerIdentifierType::ServerIdentifier(_))
+        ),
+        "Server identifiers cannot be used by clients"
+    );
+    requests::player_id(database, identifier)
+}
+
+fn signing_key(database: &impl Database) -> Result<Vec<u8>> {
+    database.signing_key()?.with_error(|| "Session signing key has not been initialized")
+}
+
+/// Returns an HMAC which has consumed the signed content of a token.
+fn signer(key: &[u8], player_id: PlayerId, expiry: u64, nonce: &[u8]) -> Result<Hmac<Sha256>> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).with_error(|| "Invalid signing key")?;
+    mac.update(&serde_json::to_vec(&player_id).with_error(|| "Error serializing player ID")?);
+    mac.update(&expiry.to_be_bytes());
+    mac.update(nonce);
+    Ok(mac)
+}
+
+fn random_bytes() -> [u8; KEY_BYTES] {
+    let mut bytes = [0u8; KEY_BYTES];
+    OsRng.fill_bytes(&mut bytes);
+    bytes
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    let mut result = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        let _err = write!(result, "{:02x}", byte);
+    }
+    result
+}
+
+fn from_hex(value: &str) -> Option<Vec<u8>> {
+    if !value.len().is_multiple_of(2) {
+        return None;
+    }
+
+    (0..value.len())
+        .step_by(2)
+        .map(|i| value.get(i..i + 2).and_then(|byte| u8::from_str_radix(byte, 16).ok()))
+        .collect()
+}
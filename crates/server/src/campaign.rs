@@ -0,0 +1,52 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Applies campaign unlocks when a player completes an adventure
+
+use anyhow::Result;
+use core_ui::panels;
+use data::campaign::{self, Unlock};
+use data::player_data::PlayerData;
+use panel_address::PanelAddress;
+use protos::spelldawn::game_command::Command;
+
+/// Records the completion of the player's current adventure and grants any
+/// campaign unlocks it earned.
+pub fn complete_adventure(player: &mut PlayerData) {
+    player.campaign.adventures_completed += 1;
+    player.campaign.recent_unlocks = campaign::unlocks_for(player.campaign.adventures_completed);
+    for unlock in player.campaign.recent_unlocks.clone() {
+        match unlock {
+            Unlock::Card(card, quantity) => {
+                *player.collection.entry(card).or_insert(0) += quantity;
+            }
+            Unlock::Identity(side, school) => {
+                player.campaign.identities.push((side, school));
+            }
+        }
+    }
+}
+
+/// Commands to display the unlock celebration panel, if the player's most
+/// recently completed adventure granted any unlocks.
+pub fn render_unlocks(player: &PlayerData) -> Result<Vec<Command>> {
+    if player.campaign.recent_unlocks.is_empty() {
+        return Ok(vec![]);
+    }
+
+    Ok(vec![
+        Command::UpdatePanels(routing::render_panel(player, PanelAddress::CampaignUnlocks.into())?),
+        panels::open(PanelAddress::CampaignUnlocks),
+    ])
+}
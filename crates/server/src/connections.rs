@@ -0,0 +1,92 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tracks the active streaming connections for each player, allowing a player
+//! to be connected from multiple devices at the same time.
+
+use dashmap::DashMap;
+use data::player_name::PlayerId;
+use once_cell::sync::Lazy;
+use protos::spelldawn::CommandList;
+use tokio::sync::mpsc::Sender;
+use tonic::Status;
+use tracing::info;
+
+pub type ResponseSender = Sender<Result<CommandList, Status>>;
+
+struct Connection {
+    client_id: String,
+    sender: ResponseSender,
+}
+
+/// Stores active connections for each user.
+///
+/// TODO: Clean this up on disconnect. This is quite easy to do with 'real' gRPC
+/// but I haven't figured out how to do it with gRPC-web (which is just
+/// fake-streaming over HTTP1). Unity doesn't support HTTP2 natively, but it's
+/// possible to do it via a third party networking stack. For now, closed
+/// connections are pruned whenever a player's connections are updated.
+static CONNECTIONS: Lazy<DashMap<PlayerId, Vec<Connection>>> = Lazy::new(DashMap::new);
+
+/// Registers a new connection for a player, replacing any previous connection
+/// from the same `client_id`.
+pub fn register(player_id: PlayerId, client_id: &str, sender: ResponseSender) {
+    let mut connections = CONNECTIONS.entry(player_id).or_default();
+    connections.retain(|c| c.client_id != client_id && !c.sender.is_closed());
+    connections.push(Connection { client_id: client_id.to_string(), sender });
+}
+
+/// Returns the number of open connections for a player.
+pub fn count(player_id: PlayerId) -> usize {
+    CONNECTIONS.get(&player_id).map_or(0, |c| c.iter().filter(|c| !c.sender.is_closed()).count())
+}
+
+/// Sends `commands` to every active connection for `player_id`, other than the
+/// connection for `except_client_id` if one is provided.
+pub async fn send(player_id: PlayerId, commands: CommandList, except_client_id: Option<&str>) {
+    let senders = CONNECTIONS.get(&player_id).map_or_else(Vec::new, |connections| {
+        connections
+            .iter()
+            .filter(|c| Some(c.client_id.as_str()) != except_client_id)
+            .map(|c| c.sender.clone())
+            .collect::<Vec<_>>()
+    });
+
+    for sender in senders {
+        if sender.send(Ok(commands.clone())).await.is_err() {
+            // This returns SendError if the client is disconnected, which isn't a
+            // huge problem. Hopefully they will reconnect again in the future.
+            info!(?player_id, "client_is_disconnected");
+        }
+    }
+
+    if let Some(mut connections) = CONNECTIONS.get_mut(&player_id) {
+        connections.retain(|c| !c.sender.is_closed());
+    }
+}
+
+/// Returns players who have previously connected but whose connections have
+/// all since closed.
+pub fn disconnected_players() -> Vec<PlayerId> {
+    CONNECTIONS
+        .iter()
+        .filter(|entry| entry.value().iter().all(|c| c.sender.is_closed()))
+        .map(|entry| *entry.key())
+        .collect()
+}
+
+/// Stops tracking all connections for a player.
+pub fn remove(player_id: PlayerId) {
+    CONNECTIONS.remove(&player_id);
+}
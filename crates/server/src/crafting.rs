@@ -0,0 +1,56 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Request handling for crafting and disenchanting cards
+
+use anyhow::Result;
+use data::card_name::CardName;
+use data::crafting::{self, MAX_CRAFTED_COPIES};
+use data::player_data::PlayerData;
+use with_error::{verify, WithError};
+
+/// Spends dust to add one copy of `card` to the player's collection.
+pub fn craft(player: &mut PlayerData, card: CardName) -> Result<()> {
+    verify!(!card.is_test_card(), "Cannot craft test card {:?}", card);
+    let cost = crafting::craft_cost(rules::get(card).rarity)
+        .with_error(|| format!("Card cannot be crafted {card:?}"))?;
+    verify!(
+        player.owned_copies(card) < MAX_CRAFTED_COPIES,
+        "Already own {} copies of {:?}",
+        MAX_CRAFTED_COPIES,
+        card
+    );
+    verify!(player.dust >= cost, "Insufficient dust to craft {:?}", card);
+    player.dust -= cost;
+    *player.collection.entry(card).or_insert(0) += 1;
+    Ok(())
+}
+
+/// Removes one copy of `card` from the player's collection in exchange for
+/// dust. Copies which are included in one of the player's decks cannot be
+/// disenchanted.
+pub fn disenchant(player: &mut PlayerData, card: CardName) -> Result<()> {
+    let value = crafting::disenchant_value(rules::get(card).rarity)
+        .with_error(|| format!("Card cannot be disenchanted {card:?}"))?;
+    let owned = player.owned_copies(card);
+    verify!(owned > 0, "Card not owned {:?}", card);
+    verify!(owned > player.copies_in_decks(card), "All copies of {:?} are in use by decks", card);
+    if owned == 1 {
+        player.collection.remove(&card);
+    } else {
+        player.collection.insert(card, owned - 1);
+    }
+    player.dust += value;
+    Ok(())
+}
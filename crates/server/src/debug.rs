@@ -14,23 +14,26 @@
 
 use anyhow::Result;
 use core_ui::actions::InterfaceAction;
+use core_ui::panels;
 use data::card_name::CardName;
 use data::game::GameState;
 use data::player_name::{NamedPlayer, PlayerId};
 use data::primitives::{DeckIndex, GameId, Side};
-use data::user_actions::{DebugAction, NewGameAction, NewGameDebugOptions, UserAction};
+use data::user_actions::{DebugAction, GamePreset, NewGameAction, NewGameDebugOptions, UserAction};
 use database::Database;
+use panel_address::{Confirmation, PanelAddress};
 use protos::spelldawn::client_debug_command::DebugCommand;
 use protos::spelldawn::game_command::Command;
 use protos::spelldawn::toggle_panel_command::ToggleCommand;
 use protos::spelldawn::{
-    ClientAction, ClientDebugCommand, LoadSceneCommand, SceneLoadMode, TogglePanelCommand,
+    ClientAction, ClientDebugCommand, GameCommand, LoadSceneCommand, SceneLoadMode,
+    TogglePanelCommand,
 };
 use rules::mana;
 use with_error::WithError;
 
-use crate::requests;
 use crate::requests::GameResponse;
+use crate::{exhibitions, requests};
 
 pub fn handle_debug_action(
     database: &mut impl Database,
@@ -59,6 +62,7 @@ pub fn handle_debug_action(
                                     deterministic: false,
                                     override_game_id: Some(GameId::new(0)),
                                 }),
+                                preset: GamePreset::default(),
                             })
                             .as_client_action(),
                         ),
@@ -104,14 +108,19 @@ pub fn handle_debug_action(
                 Ok(())
             })
         }
-        DebugAction::SaveState(index) => {
+        DebugAction::SaveState(index) if database.has_game(save_slot(index))? => {
+            Ok(GameResponse::from_commands(vec![panels::open(PanelAddress::Confirm(
+                Confirmation::OverwriteSaveState(index),
+            ))]))
+        }
+        DebugAction::SaveState(index) | DebugAction::OverwriteSaveState(index) => {
             let mut game = load_game(database, game_id)?;
-            game.id = GameId::new(u64::MAX - index);
+            game.id = save_slot(index);
             database.write_game(&game)?;
             Ok(GameResponse::from_commands(vec![]))
         }
         DebugAction::LoadState(index) => {
-            let mut game = database.game(GameId::new(u64::MAX - index))?;
+            let mut game = database.game(save_slot(index))?;
             game.id = game_id.with_error(|| "Expected GameId")?;
             database.write_game(&game)?;
             Ok(GameResponse::from_commands(vec![Command::LoadScene(LoadSceneCommand {
@@ -136,6 +145,28 @@ pub fn handle_debug_action(
                 Ok(vec![])
             })
         }
+        DebugAction::ToggleAgentExplanations => {
+            requests::handle_custom_action(database, player_id, game_id, |game, _| {
+                game.data.config.explain_agents = !game.data.config.explain_agents;
+                Ok(())
+            })
+        }
+        DebugAction::ToggleDelegateTrace => {
+            requests::handle_custom_action(database, player_id, game_id, |game, _| {
+                game.data.config.trace_delegates = !game.data.config.trace_delegates;
+                Ok(())
+            })
+        }
+        DebugAction::StartExhibition(overlord, champion) => {
+            let mut response = exhibitions::start(database, player_id, overlord, champion)?;
+            response.command_list.commands.insert(0, GameCommand { command: Some(close_all) });
+            Ok(response)
+        }
+        DebugAction::Panic => {
+            requests::handle_custom_action(database, player_id, game_id, |_, _| {
+                panic!("Debug panic")
+            })
+        }
     }
 }
 
@@ -147,6 +178,11 @@ fn load_scene() -> Result<GameResponse> {
     })]))
 }
 
+/// Identifies the game used to store a debug save state.
+fn save_slot(index: u64) -> GameId {
+    GameId::new(u64::MAX - index)
+}
+
 fn load_game(database: &mut impl Database, game_id: Option<GameId>) -> Result<GameState> {
     database.game(game_id.with_error(|| "GameId is required")?)
 }
@@ -0,0 +1,213 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Request handling for the standalone 'draft' game mode
+
+use std::collections::HashMap;
+
+use adventure_generator::card_generator;
+use anyhow::Result;
+use cards::decklists;
+use core_ui::panels;
+use data::cosmetics::DeckCosmetics;
+use data::deck::Deck;
+use data::draft::{DraftAction, DraftState, NewDraftAction, COPIES_PER_PICK};
+use data::player_data::PlayerState;
+use data::player_name::PlayerId;
+use data::primitives::{DeckIndex, Side};
+use data::user_actions::GamePreset;
+use database::Database;
+use display::render;
+use panel_address::PanelAddress;
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::toggle_panel_command::ToggleCommand;
+use protos::spelldawn::TogglePanelCommand;
+use tracing::info;
+use with_error::WithError;
+
+use crate::requests;
+use crate::requests::GameResponse;
+
+/// Requests a new draft against the opponent specified in `action`.
+///
+/// If the opponent has already requested a draft against this player (or is a
+/// named AI player), the draft begins immediately. Otherwise the player waits
+/// in the draft lobby until their opponent joins.
+pub fn handle_new_draft(
+    database: &mut impl Database,
+    player_id: PlayerId,
+    action: NewDraftAction,
+) -> Result<GameResponse> {
+    let opponent_id = action.opponent;
+    let opponent_ready = match opponent_id {
+        PlayerId::Database(_) => matches!(
+            requests::find_player(database, opponent_id)?.state,
+            Some(PlayerState::RequestedDraft(request))
+                if request.opponent == player_id && request.side == action.side.opponent()
+        ),
+        PlayerId::Named(_) => true,
+    };
+
+    if !opponent_ready {
+        let mut player = requests::find_player(database, player_id)?;
+        player.state = Some(PlayerState::RequestedDraft(action));
+        requests::write_player(database, &mut player)?;
+        return Ok(GameResponse::from_commands(vec![
+            Command::UpdatePanels(routing::render_panel(&player, PanelAddress::DraftLobby.into())?),
+            panels::open(PanelAddress::DraftLobby),
+        ]));
+    }
+
+    let id = database.generate_game_id()?;
+    info!(?id, "create_new_draft");
+    let (overlord, champion) = match action.side {
+        Side::Overlord => (player_id, opponent_id),
+        Side::Champion => (opponent_id, player_id),
+    };
+    let mut draft = card_generator::new_draft(id, overlord, champion);
+    make_agent_picks(&mut draft)?;
+    write_draft(database, &draft)?;
+    draft_response(database, &draft, player_id)
+}
+
+/// Applies a [DraftAction] to the player's ongoing draft. Once both players
+/// have made all of their picks, starts a new game using the drafted decks.
+pub fn handle_draft_action(
+    database: &mut impl Database,
+    player_id: PlayerId,
+    action: DraftAction,
+) -> Result<GameResponse> {
+    let player = requests::find_player(database, player_id)?;
+    let mut draft = player.draft().with_error(|| "Expected active draft")?.clone();
+    let side = draft.side(player_id)?;
+    match action {
+        DraftAction::PickCard(index) => {
+            draft.pick(side, index)?;
+            card_generator::refresh_pack(&mut draft, side);
+        }
+    }
+    make_agent_picks(&mut draft)?;
+
+    if draft.is_complete() {
+        let game = requests::start_game(
+            database,
+            draft.id,
+            drafted_deck(&draft, Side::Overlord),
+            drafted_deck(&draft, Side::Champion),
+            GamePreset::default(),
+            None,
+        )?;
+        let opponent_id = draft.player(side.opponent()).id;
+        let opponent_response = match opponent_id {
+            PlayerId::Database(_) => Some((
+                opponent_id,
+                requests::command_list(render::connect(
+                    &game,
+                    side.opponent(),
+                    &requests::player_settings(database, opponent_id)?,
+                )?),
+            )),
+            PlayerId::Named(_) => None,
+        };
+        return Ok(GameResponse {
+            command_list: requests::command_list(render::connect(
+                &game,
+                side,
+                &requests::player_settings(database, player_id)?,
+            )?),
+            opponent_response,
+        });
+    }
+
+    write_draft(database, &draft)?;
+    draft_response(database, &draft, player_id)
+}
+
+/// Makes picks on behalf of named AI players whenever it is their turn. AI
+/// players always pick the first card in their pack.
+fn make_agent_picks(draft: &mut DraftState) -> Result<()> {
+    while !draft.is_complete() {
+        let side = draft.active_side;
+        if !matches!(draft.player(side).id, PlayerId::Named(_)) {
+            break;
+        }
+        draft.pick(side, 0)?;
+        card_generator::refresh_pack(draft, side);
+    }
+    Ok(())
+}
+
+/// Stores a copy of the [DraftState] for each human participant
+fn write_draft(database: &mut impl Database, draft: &DraftState) -> Result<()> {
+    for player_id in [draft.overlord.id, draft.champion.id] {
+        if let PlayerId::Database(_) = player_id {
+            let mut player = requests::find_player(database, player_id)?;
+            player.state = Some(PlayerState::Drafting(Box::new(draft.clone())));
+            requests::write_player(database, &mut player)?;
+        }
+    }
+    Ok(())
+}
+
+/// Renders the current draft pick screen for the acting player and their
+/// opponent.
+fn draft_response(
+    database: &impl Database,
+    draft: &DraftState,
+    player_id: PlayerId,
+) -> Result<GameResponse> {
+    let side = draft.side(player_id)?;
+    let opponent_id = draft.player(side.opponent()).id;
+    let opponent_response = match opponent_id {
+        PlayerId::Database(_) => {
+            Some((opponent_id, requests::command_list(show_draft_pick(database, opponent_id)?)))
+        }
+        PlayerId::Named(_) => None,
+    };
+    Ok(GameResponse {
+        command_list: requests::command_list(show_draft_pick(database, player_id)?),
+        opponent_response,
+    })
+}
+
+/// Commands to render the draft pick panel for a player and display it as
+/// their only open panel.
+fn show_draft_pick(database: &impl Database, player_id: PlayerId) -> Result<Vec<Command>> {
+    let player = requests::find_player(database, player_id)?;
+    Ok(vec![
+        Command::UpdatePanels(routing::render_panel(&player, PanelAddress::DraftPick.into())?),
+        Command::TogglePanel(TogglePanelCommand {
+            toggle_command: Some(ToggleCommand::SetPanel(PanelAddress::DraftPick.into())),
+        }),
+    ])
+}
+
+/// Builds the deck the `side` player will use in the game following a draft,
+/// containing [COPIES_PER_PICK] copies of each card they picked.
+fn drafted_deck(draft: &DraftState, side: Side) -> Deck {
+    let player = draft.player(side);
+    let mut cards = HashMap::new();
+    for card in &player.picks {
+        *cards.entry(*card).or_insert(0) += COPIES_PER_PICK;
+    }
+    Deck {
+        index: DeckIndex::new(0),
+        name: "Draft".to_string(),
+        owner_id: player.id,
+        side,
+        identity: decklists::canonical_deck(player.id, side).identity,
+        cards,
+        cosmetics: DeckCosmetics::default(),
+    }
+}
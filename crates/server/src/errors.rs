@@ -0,0 +1,52 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Converts errors into structured responses so clients can show a meaningful
+//! message for each kind of failure
+
+use prost::Message;
+use protos::spelldawn::{ErrorCode as ProtoErrorCode, ServerError};
+use tonic::codegen::Bytes;
+use tonic::{Code, Status};
+use with_error::ErrorCode;
+
+/// Describes `error` for the client, using [ErrorCode::Internal] if no more
+/// specific code was assigned.
+pub fn server_error(error: &anyhow::Error) -> ServerError {
+    let code = match with_error::error_code(error) {
+        ErrorCode::IllegalAction => ProtoErrorCode::IllegalAction,
+        ErrorCode::NotYourTurn => ProtoErrorCode::NotYourTurn,
+        ErrorCode::InsufficientMana => ProtoErrorCode::InsufficientMana,
+        ErrorCode::DeckInvalid => ProtoErrorCode::DeckInvalid,
+        ErrorCode::Internal => ProtoErrorCode::Internal,
+    };
+    ServerError { code: code.into(), message: format!("{:#}", error) }
+}
+
+/// Returns a gRPC [Status] for a failed request, with a [ServerError] as its
+/// details. Errors caused by the player's request use
+/// [Code::FailedPrecondition], while all other errors use [Code::Internal].
+pub fn status(error: &anyhow::Error) -> Status {
+    let server_error = server_error(error);
+    let code = if server_error.code() == ProtoErrorCode::Internal {
+        Code::Internal
+    } else {
+        Code::FailedPrecondition
+    };
+    Status::with_details(
+        code,
+        server_error.message.clone(),
+        Bytes::from(server_error.encode_to_vec()),
+    )
+}
@@ -0,0 +1,191 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Exhibition games are played in real time between two AI agents and streamed
+//! to any connected spectators, e.g. for demos or for visually debugging agent
+//! behavior.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Duration;
+
+use ai_core::agent::AgentConfig;
+use ai_game_integration::agents;
+use ai_game_integration::state_node::SpelldawnState;
+use anyhow::Result;
+use cards::decklists;
+use dashmap::{DashMap, DashSet};
+use data::player_name::{NamedPlayer, PlayerId};
+use data::primitives::{GameId, Side};
+use data::user_actions::GamePreset;
+use database::Database;
+use display::render;
+use once_cell::sync::Lazy;
+use protos::spelldawn::CommandList;
+use tracing::{error, info, info_span, Instrument};
+
+use crate::requests::GameResponse;
+use crate::{agent_response, connections, quarantine, requests};
+
+/// Artificial delay before each agent action, so that spectators can follow
+/// along with the game.
+const ACTION_DELAY: Duration = Duration::from_millis(1500);
+
+/// Time limit for each agent decision, in seconds.
+const AGENT_DEADLINE: u64 = 3;
+
+/// Spectators always view exhibition games from this player's perspective,
+/// seeing only information which is public to both players.
+const VIEWPOINT: Side = Side::Overlord;
+
+/// Players currently watching each exhibition game
+static SPECTATORS: Lazy<DashMap<GameId, Vec<PlayerId>>> = Lazy::new(DashMap::new);
+
+/// Exhibition games which currently have an agent loop running
+static RUNNING: Lazy<DashSet<GameId>> = Lazy::new(DashSet::new);
+
+/// Creates a new exhibition game between the `overlord` and `champion` agents
+/// and adds `spectator` as its only spectator. Returns commands to display the
+/// game to the spectator.
+///
+/// The game does not advance until [spawn_pending] is invoked.
+pub fn start(
+    database: &mut impl Database,
+    spectator: PlayerId,
+    overlord: NamedPlayer,
+    champion: NamedPlayer,
+) -> Result<GameResponse> {
+    let game_id = database.generate_game_id()?;
+    let game = requests::start_game(
+        database,
+        game_id,
+        decklists::deck_for_player(overlord, Side::Overlord),
+        decklists::deck_for_player(champion, Side::Champion),
+        GamePreset::default(),
+        None,
+    )?;
+    remove_spectator(spectator);
+    SPECTATORS.entry(game_id).or_default().push(spectator);
+    info!(?game_id, ?overlord, ?champion, "start_exhibition");
+
+    let settings = requests::player_settings(database, spectator)?;
+    Ok(GameResponse::from_commands(render::spectate(&game, VIEWPOINT, &settings)?))
+}
+
+/// Returns the exhibition game `player_id` is currently watching, if any.
+pub fn spectated_game(player_id: PlayerId) -> Option<GameId> {
+    SPECTATORS.iter().find(|entry| entry.value().contains(&player_id)).map(|entry| *entry.key())
+}
+
+/// Stops sending exhibition updates to `player_id`.
+pub fn remove_spectator(player_id: PlayerId) {
+    for mut entry in SPECTATORS.iter_mut() {
+        entry.value_mut().retain(|id| *id != player_id);
+    }
+}
+
+/// Returns commands to display the exhibition game `player_id` is watching,
+/// if any, e.g. when they reconnect.
+pub fn reconnect(database: &impl Database, player_id: PlayerId) -> Result<Option<CommandList>> {
+    let Some(game_id) = spectated_game(player_id) else {
+        return Ok(None);
+    };
+    let game = database.game(game_id)?;
+    let settings = requests::player_settings(database, player_id)?;
+    Ok(Some(requests::command_list(render::spectate(&game, VIEWPOINT, &settings)?)))
+}
+
+/// Starts running the agent loop for each exhibition game which has spectators
+/// but is not already running. Each loop uses a database created via
+/// `new_database`.
+pub fn spawn_pending<D: Database + 'static>(new_database: impl Fn() -> D) {
+    let pending = SPECTATORS.iter().map(|entry| *entry.key()).collect::<Vec<_>>();
+    for game_id in pending {
+        if !RUNNING.insert(game_id) {
+            continue;
+        }
+
+        let database = new_database();
+        tokio::spawn(
+            async move {
+                if let Err(error) = run(database, game_id).await {
+                    error!(?error, "Exhibition Error!");
+                }
+                SPECTATORS.remove(&game_id);
+                RUNNING.remove(&game_id);
+            }
+            .instrument(info_span!("exhibition_loop", ?game_id)),
+        );
+    }
+}
+
+async fn run(mut database: impl Database, game_id: GameId) -> Result<()> {
+    loop {
+        tokio::time::sleep(ACTION_DELAY).await;
+
+        // Agents simulate card code while searching, so they can panic just like
+        // a player's action.
+        let responses = match panic::catch_unwind(AssertUnwindSafe(|| step(&mut database, game_id)))
+        {
+            Ok(result) => result?,
+            Err(payload) => {
+                let message = quarantine::panic_message(payload.as_ref());
+                error!(?game_id, ?message, "Panic running exhibition!");
+                return Ok(());
+            }
+        };
+
+        let Some(responses) = responses else {
+            return Ok(());
+        };
+        if responses.is_empty() {
+            // All spectators have left
+            return Ok(());
+        }
+
+        for (player_id, commands) in responses {
+            connections::send(player_id, commands, None).await;
+        }
+    }
+}
+
+/// Performs a single agent action in the `game_id` exhibition game and returns
+/// updated commands for each of its spectators. Returns None if no agent can
+/// currently act, e.g. because the game has ended.
+pub fn step(
+    database: &mut impl Database,
+    game_id: GameId,
+) -> Result<Option<Vec<(PlayerId, CommandList)>>> {
+    let mut game = SpelldawnState(database.game(game_id)?);
+    let Some((side, agent)) = agent_response::active_agent(&game) else {
+        return Ok(None);
+    };
+
+    let action = agents::pick_action(
+        agent.as_ref(),
+        AgentConfig::with_deadline(AGENT_DEADLINE),
+        &mut game,
+        side,
+    )?;
+    requests::handle_game_action(database, game.player(side).id, Some(game_id), action)?;
+
+    let game = database.game(game_id)?;
+    let spectators = SPECTATORS.get(&game_id).map(|s| s.value().clone()).unwrap_or_default();
+    let mut result = vec![];
+    for player_id in spectators {
+        let settings = requests::player_settings(database, player_id)?;
+        let commands = render::spectator_update(&game, VIEWPOINT, &settings)?;
+        result.push((player_id, requests::command_list(commands)));
+    }
+    Ok(Some(result))
+}
@@ -14,6 +14,23 @@
 
 //! Crate for operating the GRPC sever and handling top-level client requests.
 
+pub mod abandonment;
 pub mod agent_response;
+pub mod auth;
+pub mod campaign;
+pub mod connections;
+pub mod crafting;
 pub mod debug;
+pub mod draft;
+pub mod errors;
+pub mod exhibitions;
+pub mod matches;
+pub mod notifications;
+pub mod onboarding;
+pub mod quarantine;
+pub mod quests;
+pub mod ratings;
+pub mod recordings;
 pub mod requests;
+pub mod seeded_runs;
+pub mod telemetry;
@@ -0,0 +1,150 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Request handling for best-of-three matches
+
+use anyhow::Result;
+use cards::decklists;
+use data::deck::Deck;
+use data::game::{GamePhase, GameState};
+use data::match_state::{MatchPlayer, MatchState};
+use data::player_data::PlayerState;
+use data::player_name::PlayerId;
+use data::primitives::{GameId, MatchId, Side};
+use data::user_actions::GamePreset;
+use database::Database;
+use display::render;
+use tracing::info;
+use with_error::WithError;
+
+use crate::requests;
+use crate::requests::GameResponse;
+
+/// Creates a new match whose first game is `game_id`, played between the
+/// owners of `overlord_deck` and `champion_deck`.
+///
+/// Each player uses their first saved deck for the opposite side in games
+/// after sides have been swapped.
+pub fn new_match(
+    database: &mut impl Database,
+    game_id: GameId,
+    overlord_deck: &Deck,
+    champion_deck: &Deck,
+    preset: GamePreset,
+) -> Result<MatchId> {
+    let id = MatchId::new(game_id.value);
+    info!(?id, "create_new_match");
+    let overlord = MatchPlayer {
+        id: overlord_deck.owner_id,
+        overlord_deck: overlord_deck.clone(),
+        champion_deck: deck_for_side(database, overlord_deck.owner_id, Side::Champion)?,
+        wins: 0,
+    };
+    let champion = MatchPlayer {
+        id: champion_deck.owner_id,
+        overlord_deck: deck_for_side(database, champion_deck.owner_id, Side::Overlord)?,
+        champion_deck: champion_deck.clone(),
+        wins: 0,
+    };
+    let player_ids = [overlord.id, champion.id];
+    database.write_match(&MatchState {
+        id,
+        players: [overlord, champion],
+        preset,
+        games: vec![game_id],
+    })?;
+
+    for player_id in player_ids {
+        if let PlayerId::Database(_) = player_id {
+            let mut player = requests::find_player(database, player_id)?;
+            player.current_match = Some(id);
+            requests::write_player(database, &mut player)?;
+        }
+    }
+
+    Ok(id)
+}
+
+/// Records the winner of a completed game in its match, if the game is part of
+/// one. Once the match has a winner, both players leave the match.
+pub fn record_game(database: &mut impl Database, game: &GameState, winner: Side) -> Result<()> {
+    let Some(match_id) = game.data.config.match_id else { return Ok(()) };
+    let mut state = database.match_state(match_id)?;
+    state.player_mut(game.player(winner).id)?.wins += 1;
+    database.write_match(&state)?;
+
+    if state.is_complete() {
+        for player in &state.players {
+            if let PlayerId::Database(_) = player.id {
+                let mut data = requests::find_player(database, player.id)?;
+                data.current_match = None;
+                requests::write_player(database, &mut data)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Moves a player who is leaving a completed game on to the next game in their
+/// match, creating it with sides swapped if their opponent has not already
+/// done so.
+///
+/// Returns None if the player is not participating in an ongoing match.
+pub fn continue_match(
+    database: &mut impl Database,
+    player_id: PlayerId,
+) -> Result<Option<GameResponse>> {
+    let player = requests::find_player(database, player_id)?;
+    let (Some(match_id), Some(PlayerState::Playing(game_id))) =
+        (player.current_match, &player.state)
+    else {
+        return Ok(None);
+    };
+
+    let mut game = database.game(*game_id)?;
+    if matches!(game.data.phase, GamePhase::GameOver { .. }) {
+        let mut state = database.match_state(match_id)?;
+        let (overlord, champion) = state.next_sides();
+        let (overlord_deck, champion_deck) =
+            (overlord.overlord_deck.clone(), champion.champion_deck.clone());
+        let id = database.generate_game_id()?;
+        game = requests::start_game(
+            database,
+            id,
+            overlord_deck,
+            champion_deck,
+            state.preset,
+            Some(match_id),
+        )?;
+        state.games.push(id);
+        database.write_match(&state)?;
+    }
+
+    let side = requests::user_side(player_id, &game)?;
+    Ok(Some(GameResponse::from_commands(render::connect(&game, side, &player.settings)?)))
+}
+
+/// Returns the deck `player_id` uses when playing as `side` in a match
+fn deck_for_side(database: &impl Database, player_id: PlayerId, side: Side) -> Result<Deck> {
+    Ok(match player_id {
+        PlayerId::Database(_) => requests::find_player(database, player_id)?
+            .decks
+            .iter()
+            .find(|deck| deck.side == side)
+            .with_error(|| format!("No {side:?} deck found for {player_id:?}"))?
+            .clone(),
+        PlayerId::Named(name) => decklists::deck_for_player(name, side),
+    })
+}
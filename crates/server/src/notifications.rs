@@ -0,0 +1,49 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Delivers toast notifications to players, queueing them for players who are
+//! not currently connected.
+
+use data::game::GameState;
+use data::notification::Notification;
+use data::player_data::PlayerData;
+use data::primitives::Side;
+use protos::spelldawn::game_command::Command;
+
+/// Returns false if the `side` player in `game` is known to have lost their
+/// connection to the game.
+pub fn is_online(game: &GameState, side: Side) -> bool {
+    game.player(side).disconnected.is_none()
+}
+
+/// Notifies `player` of `notification`.
+///
+/// If `online` is true, returns a command to display the notification
+/// immediately. Otherwise it is added to the player's queue of pending
+/// notifications and displayed the next time they connect. The caller is
+/// responsible for writing the updated [PlayerData].
+pub fn send(player: &mut PlayerData, notification: Notification, online: bool) -> Option<Command> {
+    if online {
+        Some(display::notifications::command(notification))
+    } else {
+        player.notifications.push(notification);
+        None
+    }
+}
+
+/// Removes all of `player`'s pending notifications, returning commands to
+/// display them.
+pub fn deliver(player: &mut PlayerData) -> Vec<Command> {
+    player.notifications.drain(..).map(display::notifications::command).collect()
+}
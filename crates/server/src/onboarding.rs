@@ -0,0 +1,81 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! First-time setup for new players: grants the starter collection and decks,
+//! records onboarding progress in [TutorialData], and places the player into
+//! the tutorial adventure.
+//!
+//! [TutorialData]: data::tutorial::TutorialData
+
+use anyhow::Result;
+use cards::decklists;
+use data::adventure::AdventureConfiguration;
+use data::deck::Deck;
+use data::player_data::PlayerData;
+use data::player_name::PlayerId;
+use data::primitives::Side;
+use data::tutorial::TutorialFlag;
+use database::Database;
+
+use crate::requests;
+
+/// Seed for the tutorial adventure's random number generator, so that every
+/// new player is shown the same map.
+pub const TUTORIAL_ADVENTURE_SEED: u64 = 1;
+
+/// Side new players represent in the tutorial adventure
+pub const TUTORIAL_SIDE: Side = Side::Champion;
+
+/// Creates and stores [PlayerData] for a player who is connecting for the first
+/// time.
+pub fn create_new_player(database: &mut impl Database, player_id: PlayerId) -> Result<PlayerData> {
+    let mut player = PlayerData::new(player_id);
+    onboard(&mut player);
+    requests::write_player(database, &mut player)?;
+    Ok(player)
+}
+
+/// Performs each onboarding step which the player has not already completed.
+pub fn onboard(player: &mut PlayerData) {
+    if !player.tutorial.has_flag(TutorialFlag::ReceivedStarterCollection) {
+        for deck in starter_decks(player.id) {
+            for (card, count) in &deck.cards {
+                let owned = player.collection.entry(*card).or_insert(0);
+                *owned = (*owned).max(*count);
+            }
+            player.decks.push(deck);
+        }
+        player.tutorial.flags.insert(TutorialFlag::ReceivedStarterCollection);
+    }
+
+    if !player.tutorial.has_flag(TutorialFlag::StartedTutorialAdventure)
+        && player.adventure.is_none()
+    {
+        player.adventure =
+            Some(adventure_generator::new_adventure(AdventureConfiguration::deterministic(
+                player.id,
+                TUTORIAL_SIDE,
+                TUTORIAL_ADVENTURE_SEED,
+            )));
+        player.tutorial.flags.insert(TutorialFlag::StartedTutorialAdventure);
+    }
+}
+
+/// Pre-built decks given to every new player, one for each side
+pub fn starter_decks(player_id: PlayerId) -> [Deck; 2] {
+    [
+        decklists::canonical_deck(player_id, Side::Overlord),
+        decklists::canonical_deck(player_id, Side::Champion),
+    ]
+}
@@ -0,0 +1,138 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Isolates panics which occur while handling requests, e.g. from `expect`
+//! calls in card definitions. The affected game is quarantined so that it
+//! cannot be modified further, and its players are returned to the main menu
+//! instead of having their connection dropped.
+
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+
+use anyhow::Result;
+use data::notification::Notification;
+use data::player_data::{self, PlayerState};
+use data::player_name::PlayerId;
+use data::primitives::{GameId, Side};
+use database::Database;
+use display::interface;
+use panel_address::PanelAddress;
+use protos::spelldawn::GameRequest;
+use tracing::error;
+use with_error::fail;
+
+use crate::requests::{self, GameResponse};
+use crate::{auth, notifications};
+
+/// Equivalent to [requests::handle_request] which quarantines the requesting
+/// player's current game if handling the request panics.
+///
+/// Interface nodes in the response are replaced with patches where possible
+/// via [interface::diff_commands].
+pub fn handle_request(database: &mut impl Database, request: &GameRequest) -> Result<GameResponse> {
+    let player_id = auth::player_id(database, &request.player_id, &request.session_token)?;
+    let mut response = catch_panics(database, player_id, |database| {
+        requests::handle_request(database, player_id, request)
+    })?;
+    interface::diff_commands(player_id, &mut response.command_list);
+    if let Some((opponent_id, commands)) = &mut response.opponent_response {
+        interface::diff_commands(*opponent_id, commands);
+    }
+    Ok(response)
+}
+
+/// Equivalent to [requests::handle_connect] which quarantines the player's
+/// current game if rendering it panics. The returned
+/// [GameResponse::opponent_response] is only populated in that case.
+pub fn handle_connect(database: &mut impl Database, player_id: PlayerId) -> Result<GameResponse> {
+    catch_panics(database, player_id, |database| {
+        Ok(GameResponse {
+            command_list: requests::handle_connect(database, player_id)?,
+            opponent_response: None,
+        })
+    })
+}
+
+/// Invokes `function` on behalf of `player_id`. If it panics, the player's
+/// current game is quarantined and a response returning its players to the
+/// main menu is produced instead.
+pub fn catch_panics<D: Database>(
+    database: &mut D,
+    player_id: PlayerId,
+    function: impl FnOnce(&mut D) -> Result<GameResponse>,
+) -> Result<GameResponse> {
+    match panic::catch_unwind(AssertUnwindSafe(|| function(database))) {
+        Ok(response) => response,
+        Err(payload) => {
+            let message = panic_message(payload.as_ref());
+            error!(?player_id, ?message, "Panic handling request!");
+            match player_data::current_game_id(database.player(player_id)?) {
+                Some(game_id) => quarantine(database, game_id, player_id, message),
+                None => fail!("Internal error: {}", message),
+            }
+        }
+    }
+}
+
+/// Extracts the message from a panic payload
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Unknown panic".to_string()
+    }
+}
+
+/// Records `message` on the game with ID `game_id` and removes both players
+/// from it, notifying them that the game has ended. Returns commands for
+/// `player_id` to load the main menu, along with equivalent commands for their
+/// opponent if they are currently connected.
+pub fn quarantine(
+    database: &mut impl Database,
+    game_id: GameId,
+    player_id: PlayerId,
+    message: String,
+) -> Result<GameResponse> {
+    let mut game = database.game(game_id)?;
+    game.data.quarantined = Some(message);
+    database.write_game(&game)?;
+
+    let mut response = GameResponse::default();
+    for side in enum_iterator::all::<Side>() {
+        let id = game.player(side).id;
+        let mut player = match database.player(id)? {
+            Some(player) if matches!(player.state, Some(PlayerState::Playing(g)) if g == game_id) => {
+                player
+            }
+            _ => continue,
+        };
+
+        player.state = None;
+        let online = id == player_id || notifications::is_online(&game, side);
+        let toast = notifications::send(&mut player, Notification::GameQuarantined, online);
+        requests::write_player(database, &mut player)?;
+
+        let mut commands = requests::main_scene(&player, PanelAddress::MainMenu)?;
+        commands.extend(toast);
+        if id == player_id {
+            response.command_list = requests::command_list(commands);
+        } else if online {
+            response.opponent_response = Some((id, requests::command_list(commands)));
+        }
+    }
+
+    Ok(response)
+}
@@ -0,0 +1,119 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Daily quest generation and progress tracking
+
+use anyhow::Result;
+use data::adventure::JournalEntry;
+use data::game::{GamePhase, GameState};
+use data::history::HistoryEvent;
+use data::notification::Notification;
+use data::player_data::PlayerData;
+use data::player_name::PlayerId;
+use data::primitives::Side;
+use data::quests;
+use data::quests::{QuestKind, QuestLog};
+use database::Database;
+use protos::spelldawn::game_command::Command;
+
+use crate::{abandonment, notifications, requests};
+
+/// Returns the current quest day
+pub fn today() -> u64 {
+    quests::day(abandonment::timestamp())
+}
+
+/// Replaces the player's quests with a fresh set if they were generated on a
+/// day other than `day`. Returns true if the quest log was changed.
+pub fn refresh(player: &mut PlayerData, day: u64) -> bool {
+    if player.quests.day == day {
+        return false;
+    }
+
+    player.quests = QuestLog { day, quests: quests::generate(day) };
+    true
+}
+
+/// Applies progress from a completed game to the `side` player's daily quests,
+/// awarding coins for any quests which are newly completed. Returns commands
+/// to notify the player if they are `online`, otherwise queues notifications to
+/// display when they next connect.
+pub fn record_game(
+    database: &mut impl Database,
+    game: &GameState,
+    side: Side,
+    online: bool,
+) -> Result<Vec<Command>> {
+    let player_id = game.player(side).id;
+    if !matches!(player_id, PlayerId::Database(_)) || game.data.config.puzzle.is_some() {
+        return Ok(vec![]);
+    }
+
+    let mut player = requests::find_player(database, player_id)?;
+    refresh(&mut player, today());
+    let mut commands = vec![];
+    let mut completed = vec![];
+    for quest in &mut player.quests.quests {
+        if quest.is_complete() {
+            continue;
+        }
+
+        quest.progress = (quest.progress + progress(quest.kind, game, side)).min(quest.target);
+        if quest.is_complete() {
+            completed.push(*quest);
+        }
+    }
+
+    for quest in completed {
+        commands.extend(notifications::send(
+            &mut player,
+            Notification::QuestCompleted(quest),
+            online,
+        ));
+        if let Some(adventure) = &mut player.adventure {
+            adventure.coins += quest.reward;
+            adventure.journal.push(JournalEntry::CoinsEarned(quest.reward));
+        } else {
+            player.coins += quest.reward;
+        }
+        if let Some(record) = player.match_history.iter_mut().find(|r| r.game_id == game.id) {
+            record.summary.coins_earned += quest.reward;
+        }
+    }
+
+    requests::write_player(database, &mut player)?;
+    Ok(commands)
+}
+
+/// Returns the amount of progress the `side` player made towards a quest of
+/// type `kind` in the provided game.
+fn progress(kind: QuestKind, game: &GameState, side: Side) -> u32 {
+    let count = |predicate: &dyn Fn(HistoryEvent) -> bool| {
+        game.history.iter().filter(|entry| predicate(entry.event)).count() as u32
+    };
+
+    match kind {
+        QuestKind::WinGames => {
+            u32::from(matches!(game.data.phase, GamePhase::GameOver { winner } if winner == side))
+        }
+        QuestKind::WinRaids if side == Side::Champion => {
+            count(&|event| matches!(event, HistoryEvent::RaidSuccess(_)))
+        }
+        QuestKind::WinRaids => 0,
+        QuestKind::PlayCards(school) => count(&|event| {
+            matches!(event, HistoryEvent::PlayCard(s, card_id)
+                if s == side && rules::card_definition(game, card_id).school == school)
+        }),
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Request handling for ranked games and the ranked leaderboard
+
+use anyhow::Result;
+use data::game::GameState;
+use data::player_name::PlayerId;
+use data::primitives::Side;
+use data::ratings::{self, PlayerRating, LEADERBOARD_PAGE_SIZE};
+use database::Database;
+use protos::spelldawn::{FetchLeaderboardRequest, LeaderboardPage, RankedPlayer};
+use tracing::info;
+
+/// Updates both players' ratings after a ranked game is won by `winner`. Does
+/// nothing if the game was not ranked.
+pub fn record_game(database: &mut impl Database, game: &GameState, winner: Side) -> Result<()> {
+    if !game.data.config.ranked {
+        return Ok(());
+    }
+
+    let mut winner_rating = rating(database, game.player(winner).id)?;
+    let mut loser_rating = rating(database, game.player(winner.opponent()).id)?;
+    ratings::record_result(&mut winner_rating, &mut loser_rating);
+    info!(?winner_rating, ?loser_rating, "record_ranked_game");
+    database.write_rating(&winner_rating)?;
+    database.write_rating(&loser_rating)
+}
+
+/// Returns the current rating for `player_id`, or their starting rating if
+/// they have not yet played a ranked game.
+pub fn rating(database: &impl Database, player_id: PlayerId) -> Result<PlayerRating> {
+    Ok(database.rating(player_id)?.unwrap_or_else(|| PlayerRating::new(player_id)))
+}
+
+/// Returns up to `limit` ranked entries starting at `offset`, each paired with
+/// its position on the leaderboard (starting from 1), along with the total
+/// number of rated players.
+pub fn leaderboard(
+    database: &impl Database,
+    offset: usize,
+    limit: usize,
+) -> Result<(Vec<(usize, PlayerRating)>, usize)> {
+    let mut all = database.ratings()?;
+    ratings::rank(&mut all);
+    let total = all.len();
+    let page = all.into_iter().enumerate().skip(offset).take(limit).map(|(i, r)| (i + 1, r));
+    Ok((page.collect(), total))
+}
+
+/// Builds one page of the ranked leaderboard for a client request
+pub fn fetch_leaderboard(
+    database: &impl Database,
+    request: &FetchLeaderboardRequest,
+) -> Result<LeaderboardPage> {
+    let limit = if request.limit == 0 { LEADERBOARD_PAGE_SIZE } else { request.limit as usize };
+    let (entries, total) = leaderboard(database, request.offset as usize, limit)?;
+    Ok(LeaderboardPage {
+        players: entries
+            .into_iter()
+            .map(|(rank, rating)| RankedPlayer {
+                rank: rank as u32,
+                name: rating.player_id.displayed_name(),
+                rating: rating.rating,
+                games_played: rating.games_played,
+                wins: rating.wins,
+            })
+            .collect(),
+        total_count: total as u32,
+    })
+}
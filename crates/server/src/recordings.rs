@@ -0,0 +1,136 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Scenario recordings: the command streams sent to each player during
+//! integration tests, saved so that the client can play them back in its
+//! visual tests.
+
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+use anyhow::Result;
+use data::primitives::Side;
+use display::card_catalog;
+use enum_iterator::Sequence;
+use prost::Message;
+use protos::spelldawn::{CommandList, FetchRecordingsRequest, RecordingList, ScenarioRecording};
+use with_error::{verify, WithError};
+
+/// Version of the [ScenarioRecording] format. Must be incremented whenever a
+/// change to the command protocol means existing recordings can no longer be
+/// played back, and the recordings regenerated by running the tests.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Environment variable which overrides [DEFAULT_DIRECTORY].
+pub const DIRECTORY_VARIABLE: &str = "SPELLDAWN_RECORDINGS_PATH";
+
+/// Location of recording files relative to the repository root.
+pub const DEFAULT_DIRECTORY: &str = "Assets/Resources/TestRecordings";
+
+/// Registry of recorded scenarios. Each scenario is recorded once from the
+/// perspective of each player.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Sequence)]
+pub enum RecordingName {
+    CompleteRaid,
+}
+
+impl RecordingName {
+    /// Identifier used in file names for this scenario.
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::CompleteRaid => "complete_raid",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::CompleteRaid => {
+                "The Champion raids a room with a weapon, defeats its minion, scores the \
+                 scheme and ends the raid as the last action of their turn"
+            }
+        }
+    }
+
+    /// Name of the recording of this scenario made from the perspective of
+    /// `side`.
+    pub fn recording_name(self, side: Side) -> String {
+        format!("{}_{}", self.key(), format!("{:?}", side).to_lowercase())
+    }
+}
+
+/// Names of every registered recording.
+pub fn all_names() -> Vec<String> {
+    enum_iterator::all::<RecordingName>()
+        .flat_map(|scenario| enum_iterator::all::<Side>().map(move |s| scenario.recording_name(s)))
+        .collect()
+}
+
+/// Directory to read recordings from, configured via [DIRECTORY_VARIABLE].
+pub fn directory() -> PathBuf {
+    PathBuf::from(env::var(DIRECTORY_VARIABLE).unwrap_or_else(|_| DEFAULT_DIRECTORY.to_string()))
+}
+
+/// Path to the file for the recording named `name` in `directory`.
+pub fn path(directory: &Path, name: &str) -> PathBuf {
+    directory.join(format!("test_{}.bytes", name))
+}
+
+/// Creates a recording of the `commands` sent to the `side` player during
+/// `scenario`.
+pub fn new(scenario: RecordingName, side: Side, commands: CommandList) -> ScenarioRecording {
+    ScenarioRecording {
+        format_version: FORMAT_VERSION,
+        name: scenario.recording_name(side),
+        description: scenario.description().to_string(),
+        card_set_hash: card_catalog::card_set_hash(),
+        side: adapters::player_side(side),
+        commands: Some(commands),
+    }
+}
+
+/// Writes `recording` to its file in `directory`.
+pub fn write(directory: &Path, recording: &ScenarioRecording) -> Result<()> {
+    let path = path(directory, &recording.name);
+    fs::write(&path, recording.encode_length_delimited_to_vec())
+        .with_error(|| format!("Error writing {:?}", path))
+}
+
+/// Decodes a recording, checking that it was written with the current
+/// [FORMAT_VERSION].
+pub fn decode(bytes: &[u8]) -> Result<ScenarioRecording> {
+    let recording = ScenarioRecording::decode_length_delimited(bytes)?;
+    verify!(
+        recording.format_version == FORMAT_VERSION,
+        "Recording {} has format version {}, expected {}",
+        recording.name,
+        recording.format_version,
+        FORMAT_VERSION
+    );
+    Ok(recording)
+}
+
+/// Reads the recordings named in `request` from `directory`, or every
+/// registered recording if no names are provided.
+pub fn fetch(directory: &Path, request: &FetchRecordingsRequest) -> Result<RecordingList> {
+    let registered = all_names();
+    let names = if request.names.is_empty() { &registered } else { &request.names };
+    let mut recordings = vec![];
+    for name in names {
+        verify!(registered.contains(name), "Unknown recording {}", name);
+        let path = path(directory, name);
+        let bytes = fs::read(&path).with_error(|| format!("Error reading {:?}", path))?;
+        recordings.push(decode(&bytes)?);
+    }
+    Ok(RecordingList { recordings, card_set_hash: card_catalog::card_set_hash() })
+}
@@ -14,6 +14,13 @@
 
 //! Top-level server request handling
 
+use std::mem;
+use std::time::{Duration, Instant};
+
+use ::panels::achievement_toast::AchievementToast;
+use ::panels::custom_game_panel::CustomGamePanel;
+use ::panels::leaderboard_panel::LeaderboardPanel;
+use ::panels::rankings_panel::RankingsPanel;
 use actions;
 use adapters::ServerCardId;
 use anyhow::Result;
@@ -21,52 +28,68 @@ use cards::decklists;
 use core_ui::panels;
 use core_ui::prelude::Component;
 use dashmap::DashMap;
-use data::adventure::{AdventureConfiguration, AdventureState};
+use data::adventure::{
+    AdventureChoiceScreen, AdventureConfiguration, AdventureState, JournalEntry,
+};
 use data::deck::Deck;
-use data::game::{GameConfiguration, GameState};
+use data::emotes::Emote;
+use data::game::{GameConfiguration, GamePhase, GameRules, GameState};
 use data::game_actions::GameAction;
-use data::player_data::{NewGameRequest, PlayerData, PlayerState};
+use data::history::MatchSummary;
+use data::leaderboard::RunSeed;
+use data::notification::Notification;
+use data::player_data::{
+    AutomationPreferences, MatchRecord, NewGameRequest, PlayerData, PlayerSettings, PlayerState,
+};
 use data::player_name::PlayerId;
-use data::primitives::{GameId, Side};
-use data::tutorial::TutorialData;
-use data::updates::{UpdateTracker, Updates};
-use data::user_actions::{NewGameAction, UserAction};
-use data::{game_actions, player_data};
+use data::primitives::{GameId, MatchId, Side};
+use data::puzzles::PuzzleId;
+use data::ratings::LEADERBOARD_PAGE_SIZE;
+use data::updates::{UpdateQueue, Updates};
+use data::user_actions::{GamePreset, NewGameAction, UserAction};
+use data::{achievements, game_actions, player_data};
 use database::{Database, SledDatabase};
 use deck_editor::deck_editor_actions;
-use display::render;
+use display::{card_catalog, emotes, interface, render};
 use once_cell::sync::Lazy;
-use panel_address::PanelAddress;
+use panel_address::{Panel, PanelAddress};
+use prompts::agent_log_panel::AgentLogPanel;
+use prompts::delegate_trace_panel::DelegateTracePanel;
+use prompts::raid_info_panel::RaidInfoPanel;
 use protos::spelldawn::client_action::Action;
 use protos::spelldawn::game_command::Command;
 use protos::spelldawn::spelldawn_server::Spelldawn;
 use protos::spelldawn::toggle_panel_command::ToggleCommand;
 use protos::spelldawn::{
-    card_target, CardTarget, ClientAction, CommandList, ConnectRequest, GameCommand, GameRequest,
-    InterfacePanelAddress, LoadSceneCommand, PlayerIdentifier, RenderScreenOverlayCommand,
-    SceneLoadMode, StandardAction, TogglePanelCommand,
+    card_target, AuthenticateRequest, AuthenticateResponse, CardCatalog, CardTarget, ClientAction,
+    CommandList, ConnectRequest, FetchCardCatalogRequest, FetchLeaderboardRequest,
+    FetchRecordingsRequest, GameCommand, GameRequest, InterfacePanelAddress, LeaderboardPage,
+    LoadSceneCommand, MusicState, PlayerIdentifier, PlayerName, RecordingList,
+    RenderScreenOverlayCommand, SceneLoadMode, SetBooleanPreference, SetFloatPreference,
+    SetMusicCommand, StandardAction, TogglePanelCommand, UpdatePanelsCommand,
+    UpdatePreferencesCommand,
 };
-use rules::{dispatch, mutations};
+use rules::{constants, dispatch, mutations};
 use screen_overlay::ScreenOverlay;
 use serde_json::de;
 use tokio::sync::mpsc;
-use tokio::sync::mpsc::Sender;
 use tokio_stream::wrappers::ReceiverStream;
 use tonic::{Request, Response, Status};
-use tracing::{error, info, warn, warn_span};
-use with_error::{fail, verify, WithError};
+use tracing::{error, info, instrument, warn, warn_span};
+use with_error::{fail, fail_code, verify, verify_code, ErrorCode, WithError};
 
 use crate::agent_response::HandleRequest;
-use crate::{agent_response, debug};
+use crate::{
+    agent_response, auth, campaign, connections, crafting, debug, draft, errors, exhibitions,
+    matches, notifications, onboarding, quarantine, quests, ratings, recordings, seeded_runs,
+    telemetry,
+};
 
-/// Stores active channels for each user.
-///
-/// TODO: Clean this up on disconnect. This is quite easy to do with 'real' gRPC
-/// but I haven't figured out how to do it with gRPC-web (which is just
-/// fake-streaming over HTTP1). Unity doesn't support HTTP2 natively, but it's
-/// possible to do it via a third party networking stack.
-static CHANNELS: Lazy<DashMap<PlayerId, Sender<Result<CommandList, Status>>>> =
-    Lazy::new(DashMap::new);
+/// Minimum time between emotes sent by a single player
+const EMOTE_COOLDOWN: Duration = Duration::from_secs(3);
+
+/// Time at which each player most recently sent an emote
+static LAST_EMOTE: Lazy<DashMap<PlayerId, Instant>> = Lazy::new(DashMap::new);
 
 pub type ResponseInterceptor = fn(&CommandList);
 
@@ -85,19 +108,21 @@ impl Spelldawn for GameService {
     ) -> Result<Response<Self::ConnectStream>, Status> {
         let mut db = SledDatabase { flush_on_write: false };
         let message = request.get_ref();
-        let player_id = match player_id(&mut db, &message.player_id) {
+        let player_id = match auth::player_id(&mut db, &message.player_id, &message.session_token) {
             Ok(player_id) => player_id,
-            _ => return Err(Status::unauthenticated("PlayerId is required")),
+            Err(error) => return Err(Status::unauthenticated(format!("{:#}", error))),
         };
         warn!(?player_id, "received_connection");
 
         let (tx, rx) = mpsc::channel(4);
 
-        let result = handle_connect(&mut db, player_id);
+        let result = quarantine::handle_connect(&mut db, player_id);
         match result {
-            Ok(commands) => {
+            Ok(response) => {
+                let commands = response.command_list;
                 let names = commands.commands.iter().map(command_name).collect::<Vec<_>>();
                 info!(?player_id, ?names, "sending_connection_response");
+                send_player_response(response.opponent_response).await;
 
                 if let Err(error) = tx.send(Ok(commands)).await {
                     error!(?player_id, ?error, "Send Error!");
@@ -106,11 +131,11 @@ impl Spelldawn for GameService {
             }
             Err(error) => {
                 error!(?player_id, ?error, "Connection Error!");
-                return Err(Status::internal(format!("Connection Error: {:#}", error)));
+                return Err(errors::status(&error));
             }
         }
 
-        CHANNELS.insert(player_id, tx);
+        connections::register(player_id, &message.client_id, tx);
         Ok(Response::new(ReceiverStream::new(rx)))
     }
 
@@ -119,19 +144,29 @@ impl Spelldawn for GameService {
         request: Request<GameRequest>,
     ) -> Result<Response<CommandList>, Status> {
         let mut db = SledDatabase { flush_on_write: false };
-        let response = handle_request(&mut db, request.get_ref());
+        let response = quarantine::handle_request(&mut db, request.get_ref());
         match response {
             Ok(response) => {
                 if let Some(interceptor) = self.response_interceptor {
                     interceptor(&response.command_list);
                 }
 
+                if let Ok(player_id) = player_id(&mut db, &request.get_ref().player_id) {
+                    // Keep this player's other devices in sync
+                    connections::send(
+                        player_id,
+                        response.command_list.clone(),
+                        Some(&request.get_ref().client_id),
+                    )
+                    .await;
+                }
                 send_player_response(response.opponent_response).await;
                 let result = agent_response::handle_request(
                     db,
                     request.get_ref(),
                     HandleRequest::SendToPlayer,
                 );
+                exhibitions::spawn_pending(|| SledDatabase { flush_on_write: false });
                 if let Err(error) = result {
                     return Err(Status::internal(format!("Agent Error: {:#}", error)));
                 }
@@ -139,7 +174,54 @@ impl Spelldawn for GameService {
             }
             Err(error) => {
                 error!(?error, "Server Error!");
-                Err(Status::internal(format!("Server Error: {:#}", error)))
+                Err(errors::status(&error))
+            }
+        }
+    }
+
+    async fn authenticate(
+        &self,
+        request: Request<AuthenticateRequest>,
+    ) -> Result<Response<AuthenticateResponse>, Status> {
+        let mut db = SledDatabase { flush_on_write: false };
+        match auth::authenticate(&mut db, request.get_ref()) {
+            Ok(response) => Ok(Response::new(response)),
+            Err(error) => {
+                error!(?error, "Authentication Error!");
+                Err(Status::unauthenticated(format!("Authentication Error: {:#}", error)))
+            }
+        }
+    }
+
+    async fn fetch_card_catalog(
+        &self,
+        _: Request<FetchCardCatalogRequest>,
+    ) -> Result<Response<CardCatalog>, Status> {
+        Ok(Response::new(fetch_card_catalog()))
+    }
+
+    async fn fetch_leaderboard(
+        &self,
+        request: Request<FetchLeaderboardRequest>,
+    ) -> Result<Response<LeaderboardPage>, Status> {
+        match fetch_leaderboard(request.get_ref()) {
+            Ok(page) => Ok(Response::new(page)),
+            Err(error) => {
+                error!(?error, "Leaderboard Error!");
+                Err(Status::internal(format!("Leaderboard Error: {:#}", error)))
+            }
+        }
+    }
+
+    async fn fetch_recordings(
+        &self,
+        request: Request<FetchRecordingsRequest>,
+    ) -> Result<Response<RecordingList>, Status> {
+        match fetch_recordings(request.get_ref()) {
+            Ok(recordings) => Ok(Response::new(recordings)),
+            Err(error) => {
+                error!(?error, "Recording Error!");
+                Err(errors::status(&error))
             }
         }
     }
@@ -148,14 +230,35 @@ impl Spelldawn for GameService {
 /// Helper to perform the connect action from the unity plugin
 pub fn connect(message: ConnectRequest) -> Result<CommandList> {
     let mut db = SledDatabase { flush_on_write: true };
-    let player_id = player_id(&mut db, &message.player_id)?;
-    handle_connect(&mut db, player_id)
+    let player_id = auth::player_id(&mut db, &message.player_id, &message.session_token)?;
+    Ok(quarantine::handle_connect(&mut db, player_id)?.command_list)
+}
+
+/// Helper to issue a session token from the unity plugin
+pub fn authenticate(request: AuthenticateRequest) -> Result<AuthenticateResponse> {
+    let mut db = SledDatabase { flush_on_write: true };
+    auth::authenticate(&mut db, &request)
+}
+
+/// Returns static metadata for every card in the game
+pub fn fetch_card_catalog() -> CardCatalog {
+    card_catalog::build()
+}
+
+/// Returns one page of the ranked leaderboard
+pub fn fetch_leaderboard(request: &FetchLeaderboardRequest) -> Result<LeaderboardPage> {
+    ratings::fetch_leaderboard(&SledDatabase { flush_on_write: false }, request)
+}
+
+/// Returns the requested scenario recordings for client visual tests
+pub fn fetch_recordings(request: &FetchRecordingsRequest) -> Result<RecordingList> {
+    recordings::fetch(&recordings::directory(), request)
 }
 
 /// Helper to perform an action from the unity plugin
 pub fn perform_action(request: GameRequest) -> Result<CommandList> {
     let mut db = SledDatabase { flush_on_write: true };
-    let response = handle_request(&mut db, &request)?;
+    let response = quarantine::handle_request(&mut db, &request)?;
     agent_response::handle_request(db, &request, HandleRequest::PushQueue)?;
     Ok(response.command_list)
 }
@@ -186,10 +289,19 @@ impl GameResponse {
     }
 }
 
-/// Processes an incoming client request and returns a [GameResponse] describing
-/// required updates to send to connected users.
-pub fn handle_request(database: &mut impl Database, request: &GameRequest) -> Result<GameResponse> {
-    let player_id = player_id(database, &request.player_id)?;
+/// Processes an incoming client request from `player_id` and returns a
+/// [GameResponse] describing required updates to send to connected users.
+///
+/// The caller is responsible for authenticating the request, see
+/// [quarantine::handle_request].
+pub fn handle_request(
+    database: &mut impl Database,
+    player_id: PlayerId,
+    request: &GameRequest,
+) -> Result<GameResponse> {
+    #[cfg(feature = "hot_reload")]
+    rules::card_overrides::reload_if_changed()?;
+
     let game_id = player_data::current_game_id(database.player(player_id)?);
     let client_action = request
         .action
@@ -213,12 +325,12 @@ pub fn handle_request(database: &mut impl Database, request: &GameRequest) -> Re
             &request.open_panels,
             standard_action,
         ),
-        Action::FetchPanel(fetch_panel) => {
-            Ok(GameResponse::from_commands(vec![Command::UpdatePanels(routing::render_panel(
-                &find_player(database, player_id)?,
-                fetch_panel.panel_address.clone().with_error(|| "missing address")?,
-            )?)]))
-        }
+        Action::FetchPanel(fetch_panel) => handle_fetch_panel(
+            database,
+            player_id,
+            game_id,
+            fetch_panel.panel_address.clone().with_error(|| "missing address")?,
+        ),
         Action::DrawCard(_) => {
             handle_game_action(database, player_id, game_id, GameAction::DrawCard)
         }
@@ -258,25 +370,66 @@ pub fn handle_request(database: &mut impl Database, request: &GameRequest) -> Re
 }
 
 /// Sets up the game state for a game connection request.
+#[instrument(skip(database))]
 pub fn handle_connect(database: &mut impl Database, player_id: PlayerId) -> Result<CommandList> {
-    let (player, is_new_player) = match database.player(player_id)? {
+    let (mut player, is_new_player) = match database.player(player_id)? {
         Some(p) => (p, false),
-        None => (create_new_player(database, player_id)?, true),
+        None => (onboarding::create_new_player(database, player_id)?, true),
     };
+    if let Some(commands) = exhibitions::reconnect(database, player_id)? {
+        return Ok(commands);
+    }
+
+    let refreshed = quests::refresh(&mut player, quests::today());
+    let queued = notifications::deliver(&mut player);
+    if refreshed || !queued.is_empty() {
+        write_player(database, &mut player)?;
+    }
 
     let mut commands = vec![];
     match (&player.state, &player.adventure) {
         (Some(PlayerState::Playing(game_id)), _) => {
-            if database.has_game(*game_id)? {
-                let game = database.game(*game_id)?;
-                let side = user_side(player_id, &game)?;
-                commands.extend(render::connect(&game, side)?);
-            } else {
-                fail!("Game not found: {:?}", game_id)
+            let game_id = *game_id;
+            match load_game(database, game_id) {
+                Ok(mut game) => {
+                    let side = user_side(player_id, &game)?;
+                    if game.player(side).disconnected.is_some() {
+                        mutations::player_reconnected(&mut game, side);
+                        database.write_game(&game)?;
+                    }
+                    let positions = database.card_positions(player_id, game_id)?;
+                    let rendered = render::reconnect(&game, side, &player.settings, positions)?;
+                    record_card_positions(database, player_id, game_id, &rendered)?;
+                    commands.extend(rendered);
+                    routing::render_panels(&mut commands, &player, routing::game_panels())?;
+                }
+                Err(error) => {
+                    // Returning to the main menu avoids locking the player out
+                    // if their game can no longer be loaded, e.g. after an
+                    // incompatible change to the stored game format.
+                    error!(?player_id, ?game_id, ?error, "Unable to load game!");
+                    player.state = None;
+                    write_player(database, &mut player)?;
+                    commands.extend(main_scene(&player, PanelAddress::MainMenu)?);
+                }
             }
         }
-        (Some(PlayerState::RequestedGame(_)), _) => todo!("Not implemented"),
+        (Some(PlayerState::RequestedGame(request)), _) => {
+            let address = PanelAddress::CustomGame(request.preset);
+            commands.extend(main_scene(&player, address)?);
+            commands.push(Command::UpdatePanels(render_panel(database, player_id, None, address)?));
+        }
+        (Some(PlayerState::RequestedDraft(_)), _) => {
+            commands.extend(main_scene(&player, PanelAddress::DraftLobby)?);
+        }
+        (Some(PlayerState::Drafting(_)), _) => {
+            commands.extend(main_scene(&player, PanelAddress::DraftPick)?);
+        }
         (None, Some(adventure_state)) => {
+            if is_new_player {
+                // New players begin in the tutorial adventure
+                commands.push(world_scene());
+            }
             commands.extend(adventure_display::render(adventure_state)?);
             routing::render_panels(
                 &mut commands,
@@ -285,39 +438,82 @@ pub fn handle_connect(database: &mut impl Database, player_id: PlayerId) -> Resu
             )?;
         }
         (None, None) => {
-            commands.push(Command::LoadScene(LoadSceneCommand {
-                scene_name: "Main".to_string(),
-                mode: SceneLoadMode::Single.into(),
-                skip_if_current: true,
-            }));
-            routing::render_panels(&mut commands, &player, routing::main_menu_panels())?;
-            commands.push(Command::TogglePanel(TogglePanelCommand {
-                toggle_command: Some(ToggleCommand::SetPanel(PanelAddress::MainMenu.into())),
-            }));
-            if is_new_player {
-                commands.push(panels::open(PanelAddress::Disclaimer));
-            }
+            commands.extend(main_scene(&player, PanelAddress::MainMenu)?);
         }
     }
 
-    commands.push(update_navbar(&player));
-    Ok(command_list(commands))
+    if is_new_player {
+        commands.push(panels::open(PanelAddress::Disclaimer));
+    }
+
+    commands.push(update_navbar(database, &player)?);
+    commands.push(update_preferences(&player.settings));
+    if player.settings.mute_audio {
+        commands
+            .push(Command::SetMusic(SetMusicCommand { music_state: MusicState::Silent.into() }));
+    }
+    commands.extend(queued);
+
+    // A newly-connected client has no previous interface nodes to patch
+    interface::reset_sent_nodes(player_id);
+    let mut result = command_list(commands);
+    interface::diff_commands(player_id, &mut result);
+    Ok(result)
+}
+
+/// Loads the game with the provided ID, returning an error if it does not
+/// exist or cannot be decoded.
+fn load_game(database: &impl Database, game_id: GameId) -> Result<GameState> {
+    verify!(database.has_game(game_id)?, "Game not found: {:?}", game_id);
+    database.game(game_id)
+}
+
+/// Commands to load the main menu scene and display `open` as the only open
+/// panel.
+pub fn main_scene(player: &PlayerData, open: PanelAddress) -> Result<Vec<Command>> {
+    let mut commands = vec![Command::LoadScene(LoadSceneCommand {
+        scene_name: "Main".to_string(),
+        mode: SceneLoadMode::Single.into(),
+        skip_if_current: true,
+    })];
+    let mut addresses = routing::main_menu_panels();
+    if !addresses.contains(&open) {
+        addresses.push(open);
+    }
+    routing::render_panels(&mut commands, player, addresses)?;
+    commands.push(Command::TogglePanel(TogglePanelCommand {
+        toggle_command: Some(ToggleCommand::SetPanel(open.into())),
+    }));
+    Ok(commands)
 }
 
 fn handle_new_adventure(
     database: &mut impl Database,
     player_id: PlayerId,
-    side: Side,
+    mut config: AdventureConfiguration,
 ) -> Result<GameResponse> {
     let mut player = database.player(player_id)?.with_error(|| "Player not found")?;
-    player.adventure =
-        Some(adventure_generator::new_adventure(AdventureConfiguration::new(player_id, side)));
-    database.write_player(&player)?;
-    Ok(GameResponse::from_commands(vec![Command::LoadScene(LoadSceneCommand {
+    let identity = player.campaign.starting_identity(config.side);
+    if let Some(identity) = identity {
+        config.school = Some(rules::get(identity).school);
+    }
+    let mut adventure = adventure_generator::new_adventure(config);
+    adventure.coins += mem::take(&mut player.coins);
+    if let Some(identity) = identity {
+        adventure.deck.identity = identity;
+    }
+    player.adventure = Some(adventure);
+    write_player(database, &mut player)?;
+    Ok(GameResponse::from_commands(vec![world_scene()]))
+}
+
+/// Command to load the adventure world map scene
+fn world_scene() -> Command {
+    Command::LoadScene(LoadSceneCommand {
         scene_name: "World".to_string(),
         mode: SceneLoadMode::Single.into(),
         skip_if_current: true,
-    })]))
+    })
 }
 
 /// Creates a new default [GameState], deals opening hands, and writes its value
@@ -327,26 +523,41 @@ fn handle_new_game(
     player_id: PlayerId,
     action: NewGameAction,
 ) -> Result<GameResponse> {
+    verify!(
+        !action.preset.ranked || action.preset.rules == GameRules::default(),
+        "Ranked games must use the standard rules"
+    );
     let debug_options = action.debug_options.unwrap_or_default();
     let opponent_id = action.opponent;
     let deck_id = action.deck_index;
     let mut player = database.player(player_id)?.with_error(|| "Player not found")?;
     let user_deck = player.deck(deck_id)?.clone();
-    let opponent_deck =
-        if let Some(deck) = requested_deck(database, opponent_id, user_deck.side.opponent())? {
-            deck
-        } else {
-            player.state = Some(PlayerState::RequestedGame(NewGameRequest { deck_id }));
-            database.write_player(&player)?;
-            // TODO: Implement some kind of waiting UI here
-            return Ok(GameResponse::from_commands(vec![]));
-        };
+    verify_code!(
+        !user_deck.cards.is_empty(),
+        ErrorCode::DeckInvalid,
+        "Deck {:?} contains no cards",
+        user_deck.name
+    );
+    let opponent_deck = if let Some(deck) =
+        requested_deck(database, opponent_id, user_deck.side.opponent(), action.preset)?
+    {
+        deck
+    } else {
+        player.state =
+            Some(PlayerState::RequestedGame(NewGameRequest { deck_id, preset: action.preset }));
+        write_player(database, &mut player)?;
+        let address = PanelAddress::CustomGame(action.preset);
+        return Ok(GameResponse::from_commands(vec![
+            Command::UpdatePanels(render_panel(database, player_id, None, address)?),
+            panels::open(address),
+        ]));
+    };
 
     let (user_side, opponent_side) = (user_deck.side, opponent_deck.side);
     let (overlord_deck, champion_deck) = match (user_side, opponent_side) {
         (Side::Overlord, Side::Champion) => (user_deck, opponent_deck),
         (Side::Champion, Side::Overlord) => (opponent_deck, user_deck),
-        _ => fail!("Deck side mismatch!"),
+        _ => fail_code!(ErrorCode::DeckInvalid, "Deck side mismatch!"),
     };
 
     let game_id = if let Some(id) = debug_options.override_game_id {
@@ -354,44 +565,234 @@ fn handle_new_game(
     } else {
         database.generate_game_id()?
     };
-    info!(?game_id, "create_new_game");
+    let preset = GamePreset {
+        deterministic: action.preset.deterministic || debug_options.deterministic,
+        ..action.preset
+    };
+    let match_id = if preset.best_of_three {
+        Some(matches::new_match(database, game_id, &overlord_deck, &champion_deck, preset)?)
+    } else {
+        None
+    };
+    let game = start_game(database, game_id, overlord_deck, champion_deck, preset, match_id)?;
 
+    let mut commands = render::connect(&game, user_side, &player.settings)?;
+    if let PlayerId::Named(name) = opponent_id {
+        if name.intro().is_some() {
+            let address = PanelAddress::OpponentIntro(name);
+            commands.push(Command::UpdatePanels(routing::render_panel(&player, address.into())?));
+            commands.push(panels::open(address));
+        }
+    }
+
+    Ok(GameResponse {
+        command_list: command_list(commands),
+        opponent_response: Some((
+            opponent_id,
+            command_list(render::connect(
+                &game,
+                opponent_side,
+                &player_settings(database, opponent_id)?,
+            )?),
+        )),
+    })
+}
+
+/// Creates a new [GameState] from the provided decks using the rules in
+/// `preset`, deals opening hands, and writes its value to the database. Marks
+/// both players as playing in this game.
+///
+/// If `match_id` is provided, the game is played as part of that match.
+pub fn start_game(
+    database: &mut impl Database,
+    game_id: GameId,
+    overlord_deck: Deck,
+    champion_deck: Deck,
+    preset: GamePreset,
+    match_id: Option<MatchId>,
+) -> Result<GameState> {
+    info!(?game_id, "create_new_game");
+    let player_ids = [overlord_deck.owner_id, champion_deck.owner_id];
     let mut game = GameState::new(
         game_id,
         overlord_deck,
         champion_deck,
         GameConfiguration {
-            deterministic: debug_options.deterministic,
+            deterministic: preset.deterministic,
+            rules: preset.rules,
+            ranked: preset.ranked,
+            reveal_on_game_over: preset.reveal_on_game_over,
+            match_id,
+            disconnect_grace_period: Some(constants::DISCONNECT_GRACE_PERIOD_SECONDS),
             ..GameConfiguration::default()
         },
     );
 
+    apply_appearance(database, &mut game)?;
     dispatch::populate_delegate_cache(&mut game);
     mutations::deal_opening_hands(&mut game)?;
     database.write_game(&game)?;
 
-    player.state = Some(PlayerState::Playing(game_id));
-    database.write_player(&player)?;
+    for player_id in player_ids {
+        if let PlayerId::Database(_) = player_id {
+            let mut player = find_player(database, player_id)?;
+            player.state = Some(PlayerState::Playing(game_id));
+            write_player(database, &mut player)?;
+        }
+    }
 
-    if let PlayerId::Database(_) = opponent_id {
-        let mut opponent = database.player(opponent_id)?.with_error(|| "Opponent not found")?;
-        opponent.state = Some(PlayerState::Playing(game_id));
-        database.write_player(&opponent)?;
+    Ok(game)
+}
+
+/// Copies the card back, board skin and automation preferences selected by each
+/// human player into the [GameState].
+fn apply_appearance(database: &impl Database, game: &mut GameState) -> Result<()> {
+    for side in enum_iterator::all::<Side>() {
+        let player_id = game.player(side).id;
+        if let PlayerId::Database(_) = player_id {
+            let player = find_player(database, player_id)?;
+            game.player_mut(side).appearance = player.player_cosmetics.equipped;
+            game.player_mut(side).automation = player.automation;
+        }
     }
+    Ok(())
+}
 
-    Ok(GameResponse {
-        command_list: command_list(render::connect(&game, user_side)?),
-        opponent_response: Some((
-            opponent_id,
-            command_list(render::connect(&game, opponent_side)?),
-        )),
+/// Creates a new game set up as the indicated puzzle and makes it the player's
+/// current game.
+fn handle_start_puzzle(
+    database: &mut impl Database,
+    player_id: PlayerId,
+    puzzle: PuzzleId,
+) -> Result<GameResponse> {
+    let game_id = database.generate_game_id()?;
+    info!(?game_id, ?puzzle, "start_puzzle");
+    let mut game = puzzles::new_game(game_id, player_id, puzzle)?;
+    apply_appearance(database, &mut game)?;
+    database.write_game(&game)?;
+
+    let mut player = find_player(database, player_id)?;
+    player.state = Some(PlayerState::Playing(game_id));
+    write_player(database, &mut player)?;
+    Ok(GameResponse::from_commands(render::connect(
+        &game,
+        user_side(player_id, &game)?,
+        &player.settings,
+    )?))
+}
+
+/// Renders the panel at the requested address. Panels which display in-game
+/// state are rendered here, other panels are delegated to [routing].
+fn handle_fetch_panel(
+    database: &impl Database,
+    player_id: PlayerId,
+    game_id: Option<GameId>,
+    address: InterfacePanelAddress,
+) -> Result<GameResponse> {
+    let command = render_panel(database, player_id, game_id, routing::server_address(&address)?)?;
+    Ok(GameResponse::from_commands(vec![Command::UpdatePanels(command)]))
+}
+
+/// Renders the panel at `address`, including panels which depend on game or
+/// server state and thus cannot be rendered via [routing::render_panel].
+fn render_panel(
+    database: &impl Database,
+    player_id: PlayerId,
+    game_id: Option<GameId>,
+    address: PanelAddress,
+) -> Result<UpdatePanelsCommand> {
+    Ok(match address {
+        PanelAddress::RaidInfo => UpdatePanelsCommand {
+            panels: RaidInfoPanel { game: &find_game(database, game_id)? }
+                .build_panel()
+                .into_iter()
+                .collect(),
+        },
+        PanelAddress::AgentLog => UpdatePanelsCommand {
+            panels: AgentLogPanel { game: &find_game(database, game_id)? }
+                .build_panel()
+                .into_iter()
+                .collect(),
+        },
+        PanelAddress::DelegateTrace => UpdatePanelsCommand {
+            panels: DelegateTracePanel { game: &find_game(database, game_id)? }
+                .build_panel()
+                .into_iter()
+                .collect(),
+        },
+        PanelAddress::Leaderboard(run) => {
+            let seed = seeded_runs::seed(run);
+            UpdatePanelsCommand {
+                panels: LeaderboardPanel {
+                    run,
+                    seed,
+                    entries: &seeded_runs::leaderboard(database, seed)?,
+                }
+                .build_panel()
+                .into_iter()
+                .collect(),
+            }
+        }
+        PanelAddress::CustomGame(preset) => UpdatePanelsCommand {
+            panels: CustomGamePanel {
+                player: &find_player(database, player_id)?,
+                preset,
+                rating: ratings::rating(database, player_id)?,
+            }
+            .build_panel()
+            .into_iter()
+            .collect(),
+        },
+        PanelAddress::Rankings(offset) => {
+            let (entries, total) =
+                ratings::leaderboard(database, offset as usize, LEADERBOARD_PAGE_SIZE)?;
+            UpdatePanelsCommand {
+                panels: RankingsPanel { offset, entries: &entries, total }
+                    .build_panel()
+                    .into_iter()
+                    .collect(),
+            }
+        }
+        _ => routing::render_panel(&find_player(database, player_id)?, address.into())?,
     })
 }
 
+/// Resigns the player's current game. If their opponent is not connected,
+/// queues a notification to tell them about it when they next connect.
+fn handle_concede(
+    database: &mut impl Database,
+    player_id: PlayerId,
+    game_id: Option<GameId>,
+) -> Result<GameResponse> {
+    let game = find_game(database, game_id)?;
+    let opponent = user_side(player_id, &game)?.opponent();
+    let was_game_over = matches!(game.data.phase, GamePhase::GameOver { .. });
+    let response = handle_custom_action(database, player_id, game_id, |game, side| {
+        actions::handle_game_action(game, side, GameAction::Resign)
+    })?;
+
+    let opponent_id = game.player(opponent).id;
+    if !was_game_over
+        && matches!(opponent_id, PlayerId::Database(_))
+        && !notifications::is_online(&game, opponent)
+    {
+        let mut player = find_player(database, opponent_id)?;
+        notifications::send(&mut player, Notification::OpponentConceded, false);
+        write_player(database, &mut player)?;
+    }
+
+    Ok(response)
+}
+
 fn handle_leave_game(database: &mut impl Database, player_id: PlayerId) -> Result<GameResponse> {
+    exhibitions::remove_spectator(player_id);
+    if let Some(response) = matches::continue_match(database, player_id)? {
+        return Ok(response);
+    }
+
     let mut player = database.player(player_id)?.with_error(|| "Player not found")?;
     player.state = None;
-    database.write_player(&player)?;
+    write_player(database, &mut player)?;
     Ok(GameResponse::from_commands(vec![Command::LoadScene(LoadSceneCommand {
         scene_name: "Main".to_string(),
         mode: SceneLoadMode::Single.into(),
@@ -399,6 +800,38 @@ fn handle_leave_game(database: &mut impl Database, player_id: PlayerId) -> Resul
     })]))
 }
 
+/// Relays an emote to the opponent in the current game, unless the opponent
+/// has muted emotes. Emotes sent within [EMOTE_COOLDOWN] of a previous emote
+/// are ignored.
+fn handle_send_emote(
+    database: &mut impl Database,
+    player_id: PlayerId,
+    game_id: Option<GameId>,
+    emote: Emote,
+) -> Result<GameResponse> {
+    let game = find_game(database, game_id)?;
+    let user_side = user_side(player_id, &game)?;
+
+    let now = Instant::now();
+    if LAST_EMOTE.get(&player_id).is_some_and(|last| now.duration_since(*last) < EMOTE_COOLDOWN) {
+        return Ok(GameResponse::from_commands(vec![]));
+    }
+    LAST_EMOTE.insert(player_id, now);
+
+    let opponent_id = game.player(user_side.opponent()).id;
+    let opponent_response = match opponent_id {
+        PlayerId::Database(_) if !find_player(database, opponent_id)?.settings.mute_emotes => {
+            Some((opponent_id, command_list(vec![emotes::render(emote, PlayerName::Opponent)])))
+        }
+        _ => None,
+    };
+
+    Ok(GameResponse {
+        command_list: command_list(vec![emotes::render(emote, PlayerName::User)]),
+        opponent_response,
+    })
+}
+
 fn handle_leave_adventure(state: &mut PlayerData) -> Result<Vec<Command>> {
     state.adventure = None;
     Ok(vec![Command::LoadScene(LoadSceneCommand {
@@ -409,22 +842,26 @@ fn handle_leave_adventure(state: &mut PlayerData) -> Result<Vec<Command>> {
 }
 
 /// Looks up the deck the `player_id` player has requested to use for a new game
+///
+/// Returns None if this player has not yet requested a game using the same
+/// [GamePreset]. Named AI players always accept the preset.
 fn requested_deck(
     database: &impl Database,
     player_id: PlayerId,
     side: Side,
+    preset: GamePreset,
 ) -> Result<Option<Deck>> {
     Ok(match player_id {
         PlayerId::Database(_) => {
             let player = database.player(player_id)?.with_error(|| "Player not found")?;
-            if let Some(deck_id) = player.requested_deck_id() {
-                Some(player.deck(deck_id)?.clone())
-            } else {
-                None
+            match player.requested_game() {
+                Some(request) if request.preset == preset => {
+                    Some(player.deck(request.deck_id)?.clone())
+                }
+                _ => None,
             }
         }
-        // TODO: Each named player should have their own decklist
-        PlayerId::Named(_) => Some(decklists::canonical_deck(player_id, side)),
+        PlayerId::Named(name) => Some(decklists::deck_for_player(name, side)),
     })
 }
 
@@ -444,12 +881,14 @@ pub fn handle_game_action(
     action: GameAction,
 ) -> Result<GameResponse> {
     handle_custom_action(database, player_id, game_id, |game, user_side| {
+        puzzles::check_action(game, user_side, action)?;
         actions::handle_game_action(game, user_side, action)
     })
 }
 
 /// Custom version of `handle_action` which accepts a function allowing
 /// arbitrary mutation of the [GameState].
+#[instrument(skip(database, function))]
 pub fn handle_custom_action(
     database: &mut impl Database,
     player_id: PlayerId,
@@ -458,14 +897,45 @@ pub fn handle_custom_action(
 ) -> Result<GameResponse> {
     // TODO: Use transactions?
     let mut game = find_game(database, game_id)?;
+    if let Some(message) = &game.data.quarantined {
+        fail!("Game {:?} is quarantined: {}", game.id, message);
+    }
     let user_side = user_side(player_id, &game)?;
+    let was_game_over = matches!(game.data.phase, GamePhase::GameOver { .. });
     function(&mut game, user_side)?;
+    puzzles::check_goal(&mut game)?;
 
-    let user_result = render::render_updates(&game, user_side)?;
     let opponent_id = game.player(user_side.opponent()).id;
+    let mut user_result =
+        render::render_updates(&game, user_side, &player_settings(database, player_id)?)?;
+    let mut opponent_result = render::render_updates(
+        &game,
+        user_side.opponent(),
+        &player_settings(database, opponent_id)?,
+    )?;
+    record_card_positions(database, player_id, game.id, &user_result)?;
+    record_card_positions(database, opponent_id, game.id, &opponent_result)?;
+    if !was_game_over {
+        user_result.extend(award_achievements(database, &game, user_side)?);
+        opponent_result.extend(award_achievements(database, &game, user_side.opponent())?);
+        if let GamePhase::GameOver { winner } = game.data.phase {
+            record_match_result(database, &game, user_side, winner)?;
+            record_match_result(database, &game, user_side.opponent(), winner)?;
+            record_puzzle_result(database, &game, winner)?;
+            matches::record_game(database, &game, winner)?;
+            ratings::record_game(database, &game, winner)?;
+            telemetry::record_game(database, &game, winner)?;
+            user_result.extend(quests::record_game(database, &game, user_side, true)?);
+            opponent_result.extend(quests::record_game(
+                database,
+                &game,
+                user_side.opponent(),
+                notifications::is_online(&game, user_side.opponent()),
+            )?);
+        }
+    }
 
-    let channel_response =
-        Some((opponent_id, command_list(render::render_updates(&game, user_side.opponent())?)));
+    let channel_response = Some((opponent_id, command_list(opponent_result)));
     database.write_game(&game)?;
 
     Ok(GameResponse {
@@ -474,6 +944,85 @@ pub fn handle_custom_action(
     })
 }
 
+/// Grants any achievements the `side` player has newly earned in this game,
+/// along with their cosmetic rewards. Returns commands to notify the player.
+fn award_achievements(
+    database: &mut impl Database,
+    game: &GameState,
+    side: Side,
+) -> Result<Vec<Command>> {
+    let player_id = game.player(side).id;
+    if !matches!(player_id, PlayerId::Database(_)) {
+        return Ok(vec![]);
+    }
+
+    let earned = achievements::evaluate(game, side);
+    if earned.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut player = find_player(database, player_id)?;
+    let mut commands = vec![];
+    for achievement in earned {
+        if player.achievements.insert(achievement) {
+            if let Some(reward) = achievement.reward() {
+                player.cosmetics.insert(reward);
+            }
+            commands.push(AchievementToast::new(achievement).command());
+        }
+    }
+    write_player(database, &mut player)?;
+    Ok(commands)
+}
+
+/// Appends the outcome of a completed game to the `side` player's match
+/// history.
+fn record_match_result(
+    database: &mut impl Database,
+    game: &GameState,
+    side: Side,
+    winner: Side,
+) -> Result<()> {
+    let player_id = game.player(side).id;
+    if !matches!(player_id, PlayerId::Database(_)) {
+        return Ok(());
+    }
+
+    let mut player = find_player(database, player_id)?;
+    player.match_history.push(MatchRecord {
+        game_id: game.id,
+        side,
+        opponent: game.player(side.opponent()).id,
+        won: side == winner,
+        turn_number: game.data.turn.turn_number,
+        summary: MatchSummary::new(game, side),
+    });
+    if let Some(adventure) = &mut player.adventure {
+        if side == winner {
+            adventure.journal.push(JournalEntry::BattleWon(game.id));
+        }
+    }
+    write_player(database, &mut player)
+}
+
+/// Marks the puzzle a completed game was created from as solved if the player
+/// solving it won.
+fn record_puzzle_result(
+    database: &mut impl Database,
+    game: &GameState,
+    winner: Side,
+) -> Result<()> {
+    let Some(puzzle) = game.data.config.puzzle else { return Ok(()) };
+    let player_id = game.player(winner).id;
+    if !matches!(player_id, PlayerId::Database(_)) {
+        return Ok(());
+    }
+
+    let mut player = find_player(database, player_id)?;
+    player.completed_puzzles.insert(puzzle);
+    write_player(database, &mut player)
+}
+
 /// Allows mutation of a player's data outside of an active game ([PlayerData]).
 pub fn handle_player_action(
     database: &mut impl Database,
@@ -482,11 +1031,13 @@ pub fn handle_player_action(
 ) -> Result<GameResponse> {
     let mut player = find_player(database, player_id)?;
     let response = function(&mut player)?;
-    database.write_player(&player)?;
+    write_player(database, &mut player)?;
     Ok(GameResponse::from_commands(response))
 }
 
 /// Allows mutation of a player's data outside of an active game ([PlayerData]).
+///
+/// Grants campaign unlocks if the mutation ends the adventure.
 pub fn with_adventure(
     database: &mut impl Database,
     player_id: PlayerId,
@@ -494,23 +1045,29 @@ pub fn with_adventure(
 ) -> Result<GameResponse> {
     let mut player = find_player(database, player_id)?;
     let adventure_state = player.adventure.as_mut().with_error(|| "Expected active adventure")?;
+    let was_over = is_adventure_over(adventure_state);
     function(adventure_state)?;
-    let commands = adventure_display::render(adventure_state)?;
-    database.write_player(&player)?;
+    let completed = !was_over && is_adventure_over(adventure_state);
+    let mut commands = adventure_display::render(adventure_state)?;
+    if completed {
+        campaign::complete_adventure(&mut player);
+    }
+    write_player(database, &mut player)?;
+    if completed {
+        commands.extend(campaign::render_unlocks(&player)?);
+    }
     Ok(GameResponse::from_commands(commands))
 }
 
+fn is_adventure_over(state: &AdventureState) -> bool {
+    matches!(state.choice_screen, Some(AdventureChoiceScreen::AdventureOver))
+}
+
 /// Sends a game response to a given player, if they are connected to the
 /// server.
 pub async fn send_player_response(response: Option<(PlayerId, CommandList)>) {
     if let Some((player_id, commands)) = response {
-        if let Some(channel) = CHANNELS.get(&player_id) {
-            if channel.send(Ok(commands)).await.is_err() {
-                // This returns SendError if the client is disconnected, which isn't a
-                // huge problem. Hopefully they will reconnect again in the future.
-                info!(?player_id, "client_is_disconnected");
-            }
-        }
+        connections::send(player_id, commands, None).await;
     }
 }
 
@@ -527,16 +1084,37 @@ fn handle_standard_action(
     let action: UserAction = de::from_slice(&standard_action.payload)
         .with_error(|| "Failed to deserialize action payload")?;
     let mut result = match action {
-        UserAction::NewAdventure(side) => handle_new_adventure(database, player_id, side),
+        UserAction::NewAdventure(side) => {
+            handle_new_adventure(database, player_id, AdventureConfiguration::new(player_id, side))
+        }
+        UserAction::NewDailyAdventure(side) => handle_new_adventure(
+            database,
+            player_id,
+            AdventureConfiguration::seeded(player_id, side, seeded_runs::seed(RunSeed::Daily)),
+        ),
+        UserAction::NewSeededAdventure(side) => handle_new_adventure(
+            database,
+            player_id,
+            AdventureConfiguration::seeded(
+                player_id,
+                side,
+                seeded_runs::entered_seed(&standard_action.request_fields)?,
+            ),
+        ),
         UserAction::AdventureAction(action) => with_adventure(database, player_id, |state| {
             adventure_actions::handle_adventure_action(state, &action)
         }),
         UserAction::LeaveAdventure => {
+            seeded_runs::record_result(database, player_id)?;
             handle_player_action(database, player_id, handle_leave_adventure)
         }
+        UserAction::NewDraft(action) => draft::handle_new_draft(database, player_id, action),
+        UserAction::DraftAction(action) => draft::handle_draft_action(database, player_id, action),
         UserAction::NewGame(new_game_action) => {
             handle_new_game(database, player_id, new_game_action)
         }
+        UserAction::StartPuzzle(puzzle) => handle_start_puzzle(database, player_id, puzzle),
+        UserAction::Concede => handle_concede(database, player_id, game_id),
         UserAction::LeaveGame => handle_leave_game(database, player_id),
         UserAction::Debug(debug_action) => {
             debug::handle_debug_action(database, player_id, game_id, debug_action)
@@ -554,6 +1132,80 @@ fn handle_standard_action(
             )?;
             Ok(vec![])
         }),
+        UserAction::CraftCard(card) => handle_player_action(database, player_id, |player| {
+            crafting::craft(player, card)?;
+            Ok(vec![])
+        }),
+        UserAction::DisenchantCard(card) => handle_player_action(database, player_id, |player| {
+            crafting::disenchant(player, card)?;
+            Ok(vec![])
+        }),
+        UserAction::SendEmote(emote) => handle_send_emote(database, player_id, game_id, emote),
+        UserAction::SetMuteEmotes(muted) => handle_player_action(database, player_id, |player| {
+            player.settings.mute_emotes = muted;
+            Ok(vec![])
+        }),
+        UserAction::SetMusicVolume(volume) => {
+            handle_audio_settings(database, player_id, |settings| {
+                verify!(volume <= 100, "Invalid volume {}", volume);
+                settings.music_volume = volume;
+                Ok(())
+            })
+        }
+        UserAction::SetSoundEffectsVolume(volume) => {
+            handle_audio_settings(database, player_id, |settings| {
+                verify!(volume <= 100, "Invalid volume {}", volume);
+                settings.sound_effects_volume = volume;
+                Ok(())
+            })
+        }
+        UserAction::SetMuteAudio(muted) => handle_audio_settings(database, player_id, |settings| {
+            settings.mute_audio = muted;
+            Ok(())
+        }),
+        UserAction::SetIconMode(mode) => {
+            handle_display_setting(database, player_id, |settings| settings.icon_mode = mode)
+        }
+        UserAction::SetAnimationSpeed(speed) => {
+            handle_player_action(database, player_id, |player| {
+                player.settings.animation_speed = speed;
+                Ok(vec![])
+            })
+        }
+        UserAction::SetShareCardStatistics(share) => {
+            handle_player_action(database, player_id, |player| {
+                player.settings.share_card_statistics = share;
+                Ok(vec![])
+            })
+        }
+        UserAction::SetDisableHotkeys(disable) => {
+            handle_display_setting(database, player_id, |settings| {
+                settings.disable_hotkeys = disable
+            })
+        }
+        UserAction::SetAutomationPreferences(preferences) => {
+            handle_set_automation_preferences(database, player_id, preferences)
+        }
+        UserAction::SetCardBack(card_back) => handle_player_action(database, player_id, |player| {
+            if let Some(c) = card_back {
+                verify!(player.player_cosmetics.owns_card_back(c), "Card back not owned {:?}", c);
+            }
+            player.player_cosmetics.equipped.card_back = card_back;
+            Ok(vec![])
+        }),
+        UserAction::SetBoardSkin(board_skin) => {
+            handle_player_action(database, player_id, |player| {
+                if let Some(s) = board_skin {
+                    verify!(
+                        player.player_cosmetics.owns_board_skin(s),
+                        "Board skin not owned {:?}",
+                        s
+                    );
+                }
+                player.player_cosmetics.equipped.board_skin = board_skin;
+                Ok(vec![])
+            })
+        }
     }?;
 
     let player = find_player(database, player_id)?;
@@ -562,45 +1214,128 @@ fn handle_standard_action(
             command: Some(Command::UpdatePanels(routing::render_panel(&player, address.clone())?)),
         });
     }
-    result.command_list.commands.push(GameCommand { command: Some(update_navbar(&player)) });
+    result
+        .command_list
+        .commands
+        .push(GameCommand { command: Some(update_navbar(database, &player)?) });
 
     Ok(result)
 }
 
-fn update_navbar(player: &PlayerData) -> Command {
-    Command::RenderScreenOverlay(RenderScreenOverlayCommand {
-        node: ScreenOverlay::new(player).build(),
+fn update_navbar(database: &impl Database, player: &PlayerData) -> Result<Command> {
+    let match_state = player.current_match.map(|id| database.match_state(id)).transpose()?;
+    Ok(Command::RenderScreenOverlay(RenderScreenOverlayCommand {
+        node: ScreenOverlay::new(player).match_state(match_state.as_ref()).build(),
+    }))
+}
+
+/// Command to apply the player's stored audio settings on the client.
+fn update_preferences(settings: &PlayerSettings) -> Command {
+    let volume = |key: &str, percent: u32| SetFloatPreference {
+        key: key.to_string(),
+        value: percent as f32 / 100.0,
+    };
+    Command::UpdatePreferences(UpdatePreferencesCommand {
+        float_preferences: vec![
+            volume("MusicVolume", settings.music_volume),
+            volume("SoundEffectsVolume", settings.sound_effects_volume),
+        ],
+        boolean_preferences: vec![SetBooleanPreference {
+            key: "MuteAudio".to_string(),
+            value: settings.mute_audio,
+        }],
+    })
+}
+
+/// Updates the player's audio settings via `function`, returning a command to
+/// apply the new settings on the client.
+fn handle_audio_settings(
+    database: &mut impl Database,
+    player_id: PlayerId,
+    function: impl Fn(&mut PlayerSettings) -> Result<()>,
+) -> Result<GameResponse> {
+    handle_player_action(database, player_id, |player| {
+        function(&mut player.settings)?;
+        Ok(vec![update_preferences(&player.settings)])
     })
 }
 
+/// Updates a player setting which affects how games are displayed, such as the
+/// card icon mode. If the player is currently in a game, re-renders the game so
+/// that the change is visible.
+fn handle_display_setting(
+    database: &mut impl Database,
+    player_id: PlayerId,
+    update: impl FnOnce(&mut PlayerSettings),
+) -> Result<GameResponse> {
+    let mut player = find_player(database, player_id)?;
+    update(&mut player.settings);
+    write_player(database, &mut player)?;
+
+    Ok(GameResponse::from_commands(match player.state {
+        Some(PlayerState::Playing(game_id)) => {
+            let game = database.game(game_id)?;
+            render::connect(&game, user_side(player_id, &game)?, &player.settings)?
+        }
+        _ => vec![],
+    }))
+}
+
+/// Updates the player's raid automation preferences. If the player is currently
+/// in a game, the new preferences also apply to the remainder of that game.
+fn handle_set_automation_preferences(
+    database: &mut impl Database,
+    player_id: PlayerId,
+    preferences: AutomationPreferences,
+) -> Result<GameResponse> {
+    let mut player = find_player(database, player_id)?;
+    player.automation = preferences;
+    write_player(database, &mut player)?;
+
+    if let Some(PlayerState::Playing(game_id)) = player.state {
+        let mut game = database.game(game_id)?;
+        let side = user_side(player_id, &game)?;
+        game.player_mut(side).automation = preferences;
+        database.write_game(&game)?;
+    }
+
+    Ok(GameResponse::from_commands(vec![]))
+}
+
 /// Look up the state for a game which is expected to exist and assigns an
-/// [UpdateTracker] to it for the duration of this request.
+/// [UpdateQueue] to it for the duration of this request.
 pub fn find_game(database: &impl Database, game_id: Option<GameId>) -> Result<GameState> {
     let id = game_id.as_ref().with_error(|| "GameId not provided!")?;
     let mut game = database.game(*id)?;
-    game.updates = UpdateTracker::new(if game.data.config.simulation {
-        Updates::Ignore
-    } else {
-        Updates::Push
-    });
+    game.updates =
+        UpdateQueue::new(if game.data.config.simulation { Updates::Ignore } else { Updates::Push });
 
     Ok(game)
 }
 
-/// Writes the default initial state for a new player to the provided database
-fn create_new_player(database: &mut impl Database, player_id: PlayerId) -> Result<PlayerData> {
-    let canonical_overlord = decklists::canonical_deck(player_id, Side::Overlord);
-    let canonical_champion = decklists::canonical_deck(player_id, Side::Champion);
-    let result = PlayerData {
-        id: player_id,
-        state: None,
-        decks: vec![canonical_overlord.clone(), canonical_champion.clone()],
-        adventure: None,
-        collection: canonical_overlord.cards.into_iter().chain(canonical_champion.cards).collect(),
-        tutorial: TutorialData::default(),
-    };
-    database.write_player(&result)?;
-    Ok(result)
+/// Returns the [PlayerSettings] for the indicated player, or the default
+/// settings for players who are not human.
+pub fn player_settings(database: &impl Database, player_id: PlayerId) -> Result<PlayerSettings> {
+    Ok(match player_id {
+        PlayerId::Database(_) => find_player(database, player_id)?.settings,
+        PlayerId::Named(_) => PlayerSettings::default(),
+    })
+}
+
+/// Stores the client positions of cards in the game view sent to `player_id`
+/// via `commands`, if any, so that a later reconnection can animate from them.
+/// See [render::reconnect].
+fn record_card_positions(
+    database: &mut impl Database,
+    player_id: PlayerId,
+    game_id: GameId,
+    commands: &[Command],
+) -> Result<()> {
+    if let (PlayerId::Database(_), Some(positions)) = (player_id, render::card_positions(commands))
+    {
+        database.write_card_positions(player_id, game_id, &positions)?;
+    }
+    Ok(())
 }
 
 /// Look up the [PlayerData] for a player, or creates a new instance if none
@@ -609,6 +1344,14 @@ pub fn find_player(database: &impl Database, player_id: PlayerId) -> Result<Play
     Ok(database.player(player_id)?.unwrap_or_else(|| PlayerData::new(player_id)))
 }
 
+/// Stores a player's [PlayerData], incrementing its revision and discarding
+/// any panels which were rendered from the previous revision.
+pub fn write_player(database: &mut impl Database, player: &mut PlayerData) -> Result<()> {
+    player.revision += 1;
+    routing::invalidate(player.id);
+    database.write_player(player)
+}
+
 /// Turns an `&Option<PlayerIdentifier>` into a [PlayerId], or returns an error
 /// if the input is `None`.
 pub fn player_id(
@@ -653,6 +1396,10 @@ pub fn command_name(command: &GameCommand) -> &'static str {
         Command::RenderScreenOverlay(_) => "RenderScreenOverlay",
         Command::UpdateInterface(_) => "UpdateInterface",
         Command::Conditional(_) => "Conditional",
+        Command::ShowToast(_) => "ShowToast",
+        Command::DisplayEmote(_) => "DisplayEmote",
+        Command::UpdatePreferences(_) => "UpdatePreferences",
+        Command::DisplayArrows(_) => "DisplayArrows",
     })
 }
 
@@ -665,7 +1412,7 @@ fn card_target(target: &Option<CardTarget>) -> game_actions::CardTarget {
     })
 }
 
-fn command_list(commands: Vec<Command>) -> CommandList {
+pub fn command_list(commands: Vec<Command>) -> CommandList {
     CommandList {
         commands: commands.into_iter().map(|c| GameCommand { command: Some(c) }).collect(),
     }
@@ -0,0 +1,72 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Seeded adventure runs and their per-seed leaderboards
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use data::adventure::JournalEntry;
+use data::leaderboard::{self, LeaderboardEntry, RunSeed};
+use data::player_name::PlayerId;
+use database::Database;
+use panels::seeded_run_panel::SEED_INPUT;
+use with_error::{fail, WithError};
+
+use crate::{quests, requests};
+
+/// Returns the seed value for a [RunSeed]
+pub fn seed(run: RunSeed) -> u64 {
+    match run {
+        RunSeed::Daily => leaderboard::daily_seed(quests::today()),
+        RunSeed::Custom(seed) => seed,
+    }
+}
+
+/// Reads the seed the player entered into the seed text field
+pub fn entered_seed(request_fields: &HashMap<String, String>) -> Result<u64> {
+    let text = request_fields.get(SEED_INPUT).with_error(|| "Expected seed field")?;
+    let Ok(seed) = text.trim().parse() else {
+        fail!("Invalid seed {:?}", text);
+    };
+    Ok(seed)
+}
+
+/// Returns the recorded results for runs with the given seed, best first.
+pub fn leaderboard(database: &impl Database, seed: u64) -> Result<Vec<LeaderboardEntry>> {
+    let mut entries = database.leaderboard(seed)?;
+    leaderboard::rank(&mut entries);
+    Ok(entries)
+}
+
+/// Records the result of the player's current adventure on its seed's
+/// leaderboard. Does nothing if the adventure was not a seeded run.
+pub fn record_result(database: &mut impl Database, player_id: PlayerId) -> Result<()> {
+    let player = requests::find_player(database, player_id)?;
+    let Some(adventure) = &player.adventure else { return Ok(()) };
+    let Some(seed) = adventure.config.seed else { return Ok(()) };
+
+    let mut entries = database.leaderboard(seed)?;
+    entries.push(LeaderboardEntry {
+        player_id,
+        side: adventure.side,
+        coins: adventure.coins,
+        battles_won: adventure
+            .journal
+            .iter()
+            .filter(|entry| matches!(entry, JournalEntry::BattleWon(_)))
+            .count() as u32,
+    });
+    database.write_leaderboard(seed, &entries)
+}
@@ -0,0 +1,61 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Opt-in telemetry which aggregates per-card statistics from completed
+//! games for balance analysis
+
+use anyhow::Result;
+use data::card_stats::{self, CardStatistics};
+use data::game::GameState;
+use data::primitives::Side;
+use database::Database;
+use tracing::info;
+
+/// Adds each player's card usage from a game won by `winner` to the stored
+/// card statistics. Only players who have enabled
+/// [data::player_data::PlayerSettings::share_card_statistics] are recorded.
+pub fn record_game(database: &mut impl Database, game: &GameState, winner: Side) -> Result<()> {
+    for side in [Side::Overlord, Side::Champion] {
+        if !is_sharing(database, game, side)? {
+            continue;
+        }
+
+        for (card_name, usage) in card_stats::card_usage(game, side) {
+            let mut statistics = database
+                .card_statistics(card_name)?
+                .unwrap_or_else(|| CardStatistics::new(card_name));
+            statistics.record(&usage, side == winner);
+            database.write_card_statistics(&statistics)?;
+        }
+        info!(?side, game_id = ?game.id, "record_card_statistics");
+    }
+    Ok(())
+}
+
+/// Returns all recorded card statistics, most frequently played first.
+pub fn export(database: &impl Database) -> Result<Vec<CardStatistics>> {
+    let mut result = database.all_card_statistics()?;
+    result.sort_by(|a, b| {
+        b.times_played
+            .cmp(&a.times_played)
+            .then_with(|| a.card_name.to_string().cmp(&b.card_name.to_string()))
+    });
+    Ok(result)
+}
+
+fn is_sharing(database: &impl Database, game: &GameState, side: Side) -> Result<bool> {
+    Ok(database
+        .player(game.player(side).id)?
+        .is_some_and(|player| player.settings.share_card_statistics))
+}
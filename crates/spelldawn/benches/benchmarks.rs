@@ -25,12 +25,20 @@ use ai_tree_search::alpha_beta::AlphaBetaAlgorithm;
 use ai_tree_search::minimax::MinimaxAlgorithm;
 use cards::{decklists, initialize};
 use criterion::measurement::WallTime;
-use criterion::{criterion_group, criterion_main, BenchmarkGroup, Criterion};
-use data::primitives::Side;
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkGroup, Criterion};
+use data::delegates::DawnEvent;
+use data::game::{GamePhase, GameState};
+use data::game_actions::{CardTarget, GameAction};
+use data::primitives::{CardType, RoomId, Side};
+use rules::{dispatch, flags, queries};
 
 criterion_group!(
     benches,
     legal_actions,
+    legal_actions_mid_game,
+    invoke_event,
+    attack_and_health,
+    raid_resolution,
     minimax_nim,
     alpha_beta_nim,
     uct1_nim,
@@ -57,6 +65,72 @@ pub fn legal_actions(c: &mut Criterion) {
     group.finish();
 }
 
+pub fn legal_actions_mid_game(c: &mut Criterion) {
+    let mut group = c.benchmark_group("legal_actions_mid_game");
+    configure(&mut group);
+    let game = mid_game();
+    let side = acting_side(&game);
+    group.bench_function("legal_actions_mid_game", |b| {
+        b.iter(|| {
+            let _actions = legal_actions::evaluate(&game, side).unwrap().collect::<Vec<_>>();
+        })
+    });
+    group.finish();
+}
+
+pub fn invoke_event(c: &mut Criterion) {
+    let mut group = c.benchmark_group("invoke_event");
+    configure(&mut group);
+    let game = mid_game();
+    let turn_number = game.data.turn.turn_number;
+    group.bench_function("invoke_event", |b| {
+        b.iter_batched_ref(
+            || game.clone(),
+            |game| dispatch::invoke_event(game, DawnEvent(turn_number)).unwrap(),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+pub fn attack_and_health(c: &mut Criterion) {
+    let mut group = c.benchmark_group("attack_and_health");
+    configure(&mut group);
+    for (name, game) in [("mid_game", mid_game()), ("many_delegates", many_delegates())] {
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                for card_id in game.all_card_ids() {
+                    black_box(queries::attack(&game, card_id));
+                    black_box(queries::health(&game, card_id));
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+pub fn raid_resolution(c: &mut Criterion) {
+    let mut group = c.benchmark_group("raid_resolution");
+    configure(&mut group);
+    let mut game = mid_game();
+    play_until(&mut game, |game| flags::in_main_phase(game, Side::Champion));
+    let raid = legal_actions::evaluate(&game, Side::Champion)
+        .unwrap()
+        .find(|action| matches!(action, GameAction::InitiateRaid(_)))
+        .expect("No raid available");
+    group.bench_function("raid_resolution", |b| {
+        b.iter_batched_ref(
+            || game.clone(),
+            |game| {
+                actions::handle_game_action(game, Side::Champion, raid).unwrap();
+                play_until(game, |game| game.data.raid.is_none());
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
 pub fn minimax_nim(c: &mut Criterion) {
     let mut group = c.benchmark_group("minimax_nim");
     configure(&mut group);
@@ -144,3 +218,82 @@ pub fn alpha_beta_search(c: &mut Criterion) {
     });
     group.finish();
 }
+
+/// Number of actions to take from the start of the canonical game to produce
+/// a representative mid-game state.
+const MID_GAME_ACTIONS: usize = 40;
+
+/// Maximum number of actions [play_until] will take before giving up.
+const MAX_PLAY_FORWARD_ACTIONS: usize = 500;
+
+/// Plays the canonical game forward, always picking the action with the
+/// highest [legal_actions::prior], to produce a game with cards in play.
+fn mid_game() -> GameState {
+    let mut game = decklists::canonical_game().unwrap();
+    for _ in 0..MID_GAME_ACTIONS {
+        take_action(&mut game);
+    }
+    game
+}
+
+/// Moves every minion, weapon, artifact, and ally with delegates in the
+/// canonical game's decks into play face up, spreading minions across all
+/// rooms, to measure queries which must consult every delegate in play.
+fn many_delegates() -> GameState {
+    let mut game = decklists::canonical_game().unwrap();
+    let mut rooms = enum_iterator::all::<RoomId>().cycle();
+    for side in enum_iterator::all::<Side>() {
+        let card_ids = game
+            .cards(side)
+            .iter()
+            .filter(|card| {
+                let definition = rules::get(card.name);
+                matches!(
+                    definition.card_type,
+                    CardType::Minion | CardType::Weapon | CardType::Artifact | CardType::Ally
+                ) && definition.abilities.iter().any(|ability| !ability.delegates.is_empty())
+            })
+            .map(|card| card.id)
+            .collect::<Vec<_>>();
+        for card_id in card_ids {
+            let target = CardTarget::Room(rooms.next().unwrap());
+            let position = queries::played_position(&game, side, card_id, target).unwrap();
+            game.move_card_internal(card_id, position);
+            game.card_mut(card_id).turn_face_up();
+        }
+    }
+    game
+}
+
+/// Takes actions until `done` returns true, panicking if the game ends first
+/// or more than [MAX_PLAY_FORWARD_ACTIONS] actions are required.
+fn play_until(game: &mut GameState, done: impl Fn(&GameState) -> bool) {
+    for _ in 0..MAX_PLAY_FORWARD_ACTIONS {
+        if done(game) {
+            return;
+        }
+        assert!(
+            !matches!(game.data.phase, GamePhase::GameOver { .. }),
+            "Game ended while playing forward"
+        );
+        take_action(game);
+    }
+    panic!("Exceeded {MAX_PLAY_FORWARD_ACTIONS} actions while playing forward");
+}
+
+fn acting_side(game: &GameState) -> Side {
+    if actions::can_take_action(game, Side::Overlord) {
+        Side::Overlord
+    } else {
+        Side::Champion
+    }
+}
+
+fn take_action(game: &mut GameState) {
+    let side = acting_side(game);
+    let (action, _) = legal_actions::evaluate_with_priors(game, side)
+        .unwrap()
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .expect("No legal actions");
+    actions::handle_game_action(game, side, action).unwrap();
+}
@@ -14,21 +14,26 @@
 
 //! Spelldawn: An asymmetric trading card game
 
+use std::env;
+
 use cards::initialize;
+use database::SledDatabase;
 use protos::spelldawn::spelldawn_server::SpelldawnServer;
 use server::requests::GameService;
+use server::{abandonment, auth};
 use tonic::transport::Server;
-use tracing::warn;
-use tracing_subscriber::filter::LevelFilter;
+use tracing::{error, warn};
+use tracing_subscriber::filter::{LevelFilter, Targets};
 use tracing_subscriber::fmt;
+use tracing_subscriber::fmt::format::FmtSpan;
 use tracing_subscriber::prelude::*;
 use tracing_subscriber::util::SubscriberInitExt;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     initialize::run();
-    let fmt_layer = fmt::Layer::default().pretty().with_filter(LevelFilter::WARN);
-    tracing_subscriber::registry().with(fmt_layer).init();
+    initialize_tracing();
+    auth::initialize(&mut SledDatabase { flush_on_write: true })?;
 
     let address = "0.0.0.0:80".parse().expect("valid address");
     let server = SpelldawnServer::new(GameService {
@@ -40,8 +45,48 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     .accept_gzip();
     let service = tonic_web::config().enable(server);
 
+    if let Ok(gateway_address) = env::var("SPELLDAWN_HTTP_GATEWAY_ADDRESS") {
+        let gateway_address = gateway_address.parse()?;
+        warn!("HTTP gateway listening on {}.", gateway_address);
+        tokio::spawn(async move {
+            if let Err(error) = http_gateway::serve(gateway_address).await {
+                error!(?error, "HTTP gateway error");
+            }
+        });
+    }
+
+    tokio::spawn(abandonment::monitor());
+
     warn!("Server listening on {}.", address);
     Server::builder().accept_http1(true).add_service(service).serve(address).await?;
 
     Ok(())
 }
+
+/// Configures log output.
+///
+/// `SPELLDAWN_LOG` selects which events are logged using the
+/// [Targets] syntax, e.g. `warn,server=info,database=debug`, and defaults to
+/// `warn`. `SPELLDAWN_LOG_FORMAT` may be set to `compact` for single-line
+/// output or `spans` to additionally log the duration of each span when it
+/// closes. Events logged inside a request span include that span's
+/// `player_id` and `game_id`.
+fn initialize_tracing() {
+    let configured = env::var("SPELLDAWN_LOG").ok().map(|value| (value.parse::<Targets>(), value));
+    let targets = match &configured {
+        Some((Ok(targets), _)) => targets.clone(),
+        _ => Targets::new().with_default(LevelFilter::WARN),
+    };
+    let registry = tracing_subscriber::registry();
+    match env::var("SPELLDAWN_LOG_FORMAT").as_deref() {
+        Ok("compact") => registry.with(fmt::Layer::default().compact().with_filter(targets)).init(),
+        Ok("spans") => registry
+            .with(fmt::Layer::default().with_span_events(FmtSpan::CLOSE).with_filter(targets))
+            .init(),
+        _ => registry.with(fmt::Layer::default().pretty().with_filter(targets)).init(),
+    }
+
+    if let Some((Err(error), value)) = configured {
+        warn!(?value, %error, "Invalid SPELLDAWN_LOG value, defaulting to 'warn'");
+    }
+}
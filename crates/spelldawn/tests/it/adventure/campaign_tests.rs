@@ -0,0 +1,63 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::adventure_action::AdventureAction;
+use data::card_name::CardName;
+use data::player_data::PlayerData;
+use data::primitives::{School, Side};
+use data::user_actions::UserAction;
+use test_utils::client_interface::HasText;
+use test_utils::test_adventure::TestAdventure;
+
+#[test]
+fn test_first_completion_unlocks_card() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    let before = player(&adventure).collection.get(&CardName::Lodestone).copied().unwrap_or(0);
+    adventure.perform(UserAction::AdventureAction(AdventureAction::AbandonAdventure));
+    assert_eq!(1, player(&adventure).campaign.adventures_completed);
+    assert_eq!(before + 1, player(&adventure).collection[&CardName::Lodestone]);
+    assert!(adventure.interface.top_panel().has_text("New Unlocks"));
+    assert!(adventure.interface.top_panel().has_text("Lodestone"));
+}
+
+#[test]
+fn test_second_completion_unlocks_identity() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    complete_adventure(&mut adventure);
+    adventure.perform(UserAction::NewAdventure(Side::Champion));
+    complete_adventure(&mut adventure);
+    assert!(player(&adventure).campaign.identities.contains(&(Side::Champion, School::Law)));
+
+    adventure.perform(UserAction::NewAdventure(Side::Champion));
+    let state = player(&adventure).adventure.as_ref().expect("adventure");
+    assert_eq!(CardName::NoIdentityChampionLaw, state.deck.identity);
+    assert_eq!(Some(School::Law), state.config.school);
+}
+
+#[test]
+fn test_abandoning_completed_adventure_counts_once() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    adventure.perform(UserAction::AdventureAction(AdventureAction::AbandonAdventure));
+    adventure.perform(UserAction::AdventureAction(AdventureAction::AbandonAdventure));
+    assert_eq!(1, player(&adventure).campaign.adventures_completed);
+}
+
+fn complete_adventure(adventure: &mut TestAdventure) {
+    adventure.perform(UserAction::AdventureAction(AdventureAction::AbandonAdventure));
+    adventure.perform(UserAction::LeaveAdventure);
+}
+
+fn player(adventure: &TestAdventure) -> &PlayerData {
+    &adventure.database.players[&adventure.player_id]
+}
@@ -0,0 +1,69 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::cosmetics::{Cosmetic, DeckCosmetics, RaidEffect, VictoryEffect};
+use data::primitives::Side;
+use data::user_actions::{DeckEditorAction, UserAction};
+use test_utils::test_adventure::TestAdventure;
+
+#[test]
+fn test_equip_cosmetic() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    let cosmetic = Cosmetic::Victory(VictoryEffect::Fireworks);
+    grant(&mut adventure, cosmetic);
+    adventure.perform(UserAction::DeckEditorAction(DeckEditorAction::EquipCosmetic(cosmetic)));
+    assert_eq!(Some(VictoryEffect::Fireworks), deck_cosmetics(&adventure).victory_effect);
+    assert_eq!(None, deck_cosmetics(&adventure).raid_effect);
+}
+
+#[test]
+fn test_equip_replaces_existing() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    let lightning = Cosmetic::Raid(RaidEffect::Lightning);
+    let sword_storm = Cosmetic::Raid(RaidEffect::SwordStorm);
+    grant(&mut adventure, lightning);
+    grant(&mut adventure, sword_storm);
+    adventure.perform(UserAction::DeckEditorAction(DeckEditorAction::EquipCosmetic(lightning)));
+    adventure.perform(UserAction::DeckEditorAction(DeckEditorAction::EquipCosmetic(sword_storm)));
+    assert_eq!(Some(RaidEffect::SwordStorm), deck_cosmetics(&adventure).raid_effect);
+}
+
+#[test]
+fn test_unequip_cosmetic() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    let cosmetic = Cosmetic::Raid(RaidEffect::Lightning);
+    grant(&mut adventure, cosmetic);
+    adventure.perform(UserAction::DeckEditorAction(DeckEditorAction::EquipCosmetic(cosmetic)));
+    adventure.perform(UserAction::DeckEditorAction(DeckEditorAction::UnequipCosmetic(cosmetic)));
+    assert_eq!(DeckCosmetics::default(), deck_cosmetics(&adventure));
+}
+
+fn grant(adventure: &mut TestAdventure, cosmetic: Cosmetic) {
+    adventure
+        .database
+        .players
+        .get_mut(&adventure.player_id)
+        .expect("player")
+        .cosmetics
+        .insert(cosmetic);
+}
+
+fn deck_cosmetics(adventure: &TestAdventure) -> DeckCosmetics {
+    adventure.database.players[&adventure.player_id]
+        .adventure
+        .as_ref()
+        .expect("adventure")
+        .deck
+        .cosmetics
+}
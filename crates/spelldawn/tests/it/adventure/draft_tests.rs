@@ -0,0 +1,125 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use adventure_generator::card_generator::{self, DRAFT_PITY_THRESHOLD};
+use cards::initialize;
+use core_ui::icons;
+use data::adventure::{AdventureChoiceScreen, AdventureConfiguration, AdventureState, TileEntity};
+use data::card_name::CardName;
+use data::player_name::PlayerId;
+use data::primitives::{Rarity, Side};
+use data::set_name::SetName;
+use test_utils::client_interface::HasText;
+use test_utils::test_adventure::{TestAdventure, DRAFT_ICON};
+use test_utils::test_card_builder::TestCardBuilder;
+
+#[test]
+fn test_initiate_draft() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    adventure.visit_tile_with_icon(DRAFT_ICON);
+    adventure.click_on(format!("Draft: 25 {}", icons::COINS));
+    assert!(adventure.interface.top_panel().has_text("Pick"));
+    assert_eq!(3, draft_choices(&adventure).len());
+}
+
+#[test]
+fn test_draft_choices_match_side() {
+    let mut adventure = TestAdventure::new(Side::Overlord);
+    adventure.visit_tile_with_icon(DRAFT_ICON);
+    adventure.click_on(format!("Draft: 25 {}", icons::COINS));
+    assert!(draft_choices(&adventure).iter().all(|name| rules::get(*name).side == Side::Overlord));
+}
+
+#[test]
+fn test_draft_card() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    adventure.visit_tile_with_icon(DRAFT_ICON);
+    adventure.click_on(format!("Draft: 25 {}", icons::COINS));
+    let choices = draft_choices(&adventure);
+    adventure.click_on("Pick");
+
+    let state = adventure.state();
+    assert_eq!(1, state.collection.len());
+    assert!(state.collection.keys().all(|name| choices.contains(name)));
+    assert!(state.choice_screen.is_none());
+}
+
+#[test]
+fn test_draft_increments_pity_counter() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    adventure.visit_tile_with_icon(DRAFT_ICON);
+    adventure.click_on(format!("Draft: 25 {}", icons::COINS));
+    adventure.click_on("Pick");
+
+    // The current card set has no rare cards, so the pity counter increases
+    assert_eq!(1, adventure.state().draft_pity);
+}
+
+#[test]
+fn test_rare_cards_are_offered_by_weight() {
+    let rares = register_rare_cards();
+    let mut state = new_adventure_state();
+    let mut offered = 0;
+    let mut rare = 0;
+    for _ in 0..200 {
+        let choices = card_generator::draft_choices(&mut state).choices;
+        offered += choices.len();
+        rare += choices.iter().filter(|choice| rares.contains(&choice.card)).count();
+    }
+
+    // Rares are weighted at 22%, while exalted and epic choices fall back to
+    // common cards since none exist.
+    let fraction = rare as f64 / offered as f64;
+    assert!((0.12..0.32).contains(&fraction), "Unexpected rare fraction {}", fraction);
+}
+
+#[test]
+fn test_pity_threshold_guarantees_rare() {
+    let rares = register_rare_cards();
+    let mut state = new_adventure_state();
+    let offers_rare = |state: &mut AdventureState| {
+        card_generator::draft_choices(state).choices.iter().any(|c| rares.contains(&c.card))
+    };
+
+    assert!(!(0..20).all(|_| offers_rare(&mut state)));
+    state.draft_pity = DRAFT_PITY_THRESHOLD - 1;
+    assert!((0..20).all(|_| offers_rare(&mut state)));
+}
+
+fn register_rare_cards() -> Vec<CardName> {
+    initialize::run();
+    (0..3)
+        .map(|_| {
+            TestCardBuilder::champion_spell().rarity(Rarity::Rare).set(SetName::Core2024).build()
+        })
+        .collect()
+}
+
+fn new_adventure_state() -> AdventureState {
+    adventure_generator::new_adventure(AdventureConfiguration::deterministic(
+        PlayerId::Database(1),
+        Side::Champion,
+        314159,
+    ))
+}
+
+fn draft_choices(adventure: &TestAdventure) -> Vec<CardName> {
+    let Some(AdventureChoiceScreen::Draft(position)) = adventure.state().choice_screen else {
+        panic!("Expected draft screen");
+    };
+    let Ok(TileEntity::Draft { data, .. }) = adventure.state().tile_entity(position) else {
+        panic!("Expected draft tile");
+    };
+    data.choices.iter().map(|choice| choice.card).collect()
+}
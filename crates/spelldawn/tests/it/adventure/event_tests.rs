@@ -0,0 +1,104 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::adventure::{
+    AdventureChoiceScreen, AdventureState, Coins, EventOutcome, JournalEntry, TileEntity,
+    TilePosition,
+};
+use data::card_name::CardName;
+use data::primitives::Side;
+use test_utils::client_interface::HasText;
+use test_utils::test_adventure::{TestAdventure, EVENT_ICON};
+
+#[test]
+fn test_open_event_prompt() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    adventure.visit_tile_with_icon(EVENT_ICON);
+    assert!(adventure.interface.top_panel().has_text("Investigate"));
+}
+
+#[test]
+fn test_gain_coins_event() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    set_outcome(&mut adventure, EventOutcome::GainCoins(Coins(75)));
+    let coins = adventure.state().coins;
+    adventure.visit_tile_with_icon(EVENT_ICON);
+    adventure.click_on("Investigate");
+    assert_eq!(adventure.state().coins, coins + Coins(75));
+    assert!(adventure.interface.top_panel().has_text("75 coins"));
+    assert!(matches!(adventure.state().choice_screen, Some(AdventureChoiceScreen::Event(_))));
+}
+
+#[test]
+fn test_ambush_event() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    set_outcome(&mut adventure, EventOutcome::Ambush(Coins(50)));
+    let coins = adventure.state().coins;
+    adventure.visit_tile_with_icon(EVENT_ICON);
+    adventure.click_on("Investigate");
+    assert_eq!(adventure.state().coins, coins - Coins(50));
+}
+
+#[test]
+fn test_lose_card_event() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    set_outcome(&mut adventure, EventOutcome::LoseCard);
+    adventure.state_mut().collection.insert(CardName::TestChampionSpell, 1);
+    adventure.visit_tile_with_icon(EVENT_ICON);
+    adventure.click_on("Investigate");
+    assert!(adventure.state().collection.is_empty());
+    assert!(matches!(
+        adventure.state().journal.as_slice(),
+        [JournalEntry::Event { lost_card: Some(CardName::TestChampionSpell), .. }]
+    ));
+}
+
+#[test]
+fn test_lose_card_with_empty_collection() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    set_outcome(&mut adventure, EventOutcome::LoseCard);
+    adventure.visit_tile_with_icon(EVENT_ICON);
+    adventure.click_on("Investigate");
+    assert!(adventure.interface.top_panel().has_text("nothing of value"));
+}
+
+#[test]
+fn test_dismiss_event() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    adventure.visit_tile_with_icon(EVENT_ICON);
+    adventure.click_on("Investigate");
+    adventure.click_on("Continue");
+    let state = adventure.state();
+    assert!(state.choice_screen.is_none());
+    assert!(state.tile(event_position(state)).expect("tile").entity.is_none());
+}
+
+fn event_position(state: &AdventureState) -> TilePosition {
+    state
+        .journal
+        .iter()
+        .find_map(|entry| match entry {
+            JournalEntry::Event { position, .. } => Some(*position),
+            _ => None,
+        })
+        .expect("event position")
+}
+
+fn set_outcome(adventure: &mut TestAdventure, outcome: EventOutcome) {
+    for tile in adventure.state_mut().tiles.values_mut() {
+        if let Some(TileEntity::Event { data }) = &mut tile.entity {
+            data.outcome = outcome;
+        }
+    }
+}
@@ -13,8 +13,9 @@
 // limitations under the License.
 
 use core_ui::icons;
-use data::adventure::Coins;
+use data::adventure::{Coins, TilePosition};
 use data::primitives::Side;
+use protos::spelldawn::MapTileType;
 use test_utils::client_interface::HasText;
 use test_utils::test_adventure::{TestAdventure, EXPLORE_ICON};
 
@@ -45,3 +46,30 @@ fn test_invoke_explore() {
         .screen_overlay()
         .has_text(format!("{}", /* adventure_generator::STARTING_COINS - */ Coins(400))));
 }
+
+#[test]
+fn test_tiles_outside_starting_radius_are_hidden() {
+    let adventure = TestAdventure::new(Side::Champion);
+    let state = adventure.state();
+    assert!(!state.tile(TilePosition::new(0, -1)).expect("tile").hidden);
+    assert!(state.tile(TilePosition::new(4, -2)).expect("tile").hidden);
+}
+
+#[test]
+fn test_explore_reveals_radius() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    adventure.visit_tile_with_icon(EXPLORE_ICON);
+    adventure.click_on(format!("Explore: 100 {}", icons::COINS));
+    assert_eq!(adventure.map.tile(3, 3).tile_type(), MapTileType::Walkable);
+    assert_eq!(adventure.map.tile(-2, 6).tile_type(), MapTileType::Obstacle);
+}
+
+#[test]
+fn test_reveal_full_map() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    let count = adventure.map.tile_count();
+    adventure.state_mut().config.reveal_full_map = true;
+    adventure.connect();
+    assert!(adventure.map.tile_count() > count);
+    assert_eq!(adventure.map.tile(-2, 6).tile_type(), MapTileType::Walkable);
+}
@@ -0,0 +1,78 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::icons;
+use data::adventure::{Coins, JournalEntry};
+use data::primitives::Side;
+use panel_address::PanelAddress;
+use test_utils::client_interface::HasText;
+use test_utils::test_adventure::{TestAdventure, DRAFT_ICON, EXPLORE_ICON};
+
+#[test]
+fn test_new_adventure_has_empty_journal() {
+    let adventure = TestAdventure::new(Side::Champion);
+    assert!(journal(&adventure).is_empty());
+}
+
+#[test]
+fn test_explore_recorded_in_journal() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    adventure.visit_tile_with_icon(EXPLORE_ICON);
+    adventure.click_on(format!("Explore: 100 {}", icons::COINS));
+    assert!(matches!(
+        journal(&adventure).as_slice(),
+        [JournalEntry::Explored { cost: Coins(100), .. }]
+    ));
+}
+
+#[test]
+fn test_draft_recorded_in_journal() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    adventure.visit_tile_with_icon(DRAFT_ICON);
+    adventure.click_on(format!("Draft: 25 {}", icons::COINS));
+    adventure.click_on("Pick");
+    let player = &adventure.database.players[&adventure.player_id];
+    let collection = &player.adventure.as_ref().expect("adventure").collection;
+    let entries = journal(&adventure);
+    let [JournalEntry::DraftedCard { card, quantity }] = entries.as_slice() else {
+        panic!("Expected draft entry");
+    };
+    assert_eq!(collection.get(card), Some(quantity));
+}
+
+#[test]
+fn test_journal_panel_lists_entries_in_order() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    adventure.visit_tile_with_icon(EXPLORE_ICON);
+    adventure.click_on(format!("Explore: 100 {}", icons::COINS));
+    adventure.visit_tile_with_icon(DRAFT_ICON);
+    adventure.click_on(format!("Draft: 25 {}", icons::COINS));
+    adventure.click_on("Pick");
+
+    let player = &adventure.database.players[&adventure.player_id];
+    let panel = routing::render_panel(player, PanelAddress::AdventureJournal.into())
+        .expect("Render error")
+        .panels
+        .remove(0)
+        .node
+        .expect("Node");
+    assert!(panel.has_text("Journal"));
+    assert!(panel.has_text("1. Explored"));
+    assert!(panel.has_text("2. Drafted"));
+}
+
+fn journal(adventure: &TestAdventure) -> Vec<JournalEntry> {
+    let player = &adventure.database.players[&adventure.player_id];
+    player.adventure.as_ref().expect("adventure").journal.clone()
+}
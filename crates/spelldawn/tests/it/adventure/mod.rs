@@ -12,4 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod campaign_tests;
+mod cosmetics_tests;
+mod draft_tests;
+mod event_tests;
 mod explore_tests;
+mod journal_tests;
+mod seeded_run_tests;
+mod stamina_tests;
@@ -0,0 +1,117 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ::panels::seeded_run_panel::SEED_INPUT;
+use core_ui::action_builder::ActionBuilder;
+use core_ui::panels;
+use data::adventure::{Coins, TileEntity};
+use data::adventure_action::AdventureAction;
+use data::card_name::CardName;
+use data::leaderboard::RunSeed;
+use data::primitives::Side;
+use data::user_actions::UserAction;
+use panel_address::PanelAddress;
+use protos::spelldawn::client_action::Action;
+use protos::spelldawn::{ClientAction, FetchPanelAction};
+use test_utils::client_interface::HasText;
+use test_utils::test_adventure::TestAdventure;
+
+#[test]
+fn test_seeded_run_sets_seed() {
+    let adventure = seeded_adventure("42");
+    assert_eq!(adventure.state().config.seed, Some(42));
+}
+
+#[test]
+fn test_same_seed_generates_same_shop() {
+    let first = seeded_adventure("42");
+    let second = seeded_adventure("42");
+    assert_eq!(shop_cards(&first), shop_cards(&second));
+}
+
+#[test]
+fn test_daily_runs_share_seed() {
+    let mut first = TestAdventure::new(Side::Champion);
+    first.perform(UserAction::NewDailyAdventure(Side::Champion));
+    let mut second = TestAdventure::new(Side::Champion);
+    second.perform(UserAction::NewDailyAdventure(Side::Champion));
+    let seed = first.state().config.seed;
+    assert!(seed.is_some());
+    assert_eq!(seed, second.state().config.seed);
+}
+
+#[test]
+fn test_unseeded_adventure_has_no_seed() {
+    let adventure = TestAdventure::new(Side::Champion);
+    assert_eq!(adventure.state().config.seed, None);
+}
+
+#[test]
+fn test_leaving_seeded_run_records_result() {
+    let mut adventure = seeded_adventure("42");
+    adventure.perform(UserAction::AdventureAction(AdventureAction::AbandonAdventure));
+    adventure.perform(UserAction::LeaveAdventure);
+    let entries = &adventure.database.leaderboards[&42];
+    assert_eq!(1, entries.len());
+    assert_eq!(adventure.player_id, entries[0].player_id);
+    assert_eq!(Coins(500), entries[0].coins);
+    assert_eq!(0, entries[0].battles_won);
+}
+
+#[test]
+fn test_leaving_unseeded_run_records_nothing() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    adventure.perform(UserAction::AdventureAction(AdventureAction::AbandonAdventure));
+    adventure.perform(UserAction::LeaveAdventure);
+    assert!(adventure.database.leaderboards.is_empty());
+}
+
+#[test]
+fn test_leaderboard_panel() {
+    let mut adventure = seeded_adventure("42");
+    adventure.perform(UserAction::AdventureAction(AdventureAction::AbandonAdventure));
+    adventure.perform(UserAction::LeaveAdventure);
+    let address = PanelAddress::Leaderboard(RunSeed::Custom(42));
+    adventure.perform_client_action(ClientAction {
+        action: Some(Action::FetchPanel(FetchPanelAction { panel_address: Some(address.into()) })),
+    });
+    adventure.interface.update(panels::open(address));
+    assert!(adventure.interface.top_panel().has_text("Seed: 42"));
+    assert!(adventure.interface.top_panel().has_text("500 Coins"));
+}
+
+fn seeded_adventure(seed: &str) -> TestAdventure {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    let mut action = ActionBuilder::new()
+        .action(UserAction::NewSeededAdventure(Side::Champion))
+        .request_field(SEED_INPUT)
+        .build();
+    action.request_fields.insert(SEED_INPUT.to_string(), seed.to_string());
+    adventure.perform_client_action(ClientAction { action: Some(Action::StandardAction(action)) });
+    adventure
+}
+
+fn shop_cards(adventure: &TestAdventure) -> Vec<(CardName, u32, Coins)> {
+    adventure
+        .state()
+        .tiles
+        .values()
+        .find_map(|tile| match &tile.entity {
+            Some(TileEntity::Shop { data }) => {
+                Some(data.choices.iter().map(|c| (c.card, c.quantity, c.cost)).collect())
+            }
+            _ => None,
+        })
+        .expect("shop")
+}
@@ -0,0 +1,80 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::icons;
+use data::adventure::{AdventureChoiceScreen, JournalEntry, TilePosition, STARTING_STAMINA};
+use data::primitives::Side;
+use test_utils::client_interface::HasText;
+use test_utils::test_adventure::{TestAdventure, CAMP_ICON, EVENT_ICON, EXPLORE_ICON};
+
+#[test]
+fn test_explore_spends_stamina() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    explore(&mut adventure);
+    assert_eq!(adventure.state().stamina, STARTING_STAMINA - 2);
+}
+
+#[test]
+fn test_event_spends_stamina() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    adventure.visit_tile_with_icon(EVENT_ICON);
+    adventure.click_on("Investigate");
+    assert_eq!(adventure.state().stamina, STARTING_STAMINA - 1);
+}
+
+#[test]
+fn test_rest_at_camp() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    adventure.state_mut().stamina = 3;
+    adventure.visit_tile_with_icon(CAMP_ICON);
+    assert!(adventure.interface.top_panel().has_text("restores 4"));
+    adventure.click_on("Rest");
+    let state = adventure.state();
+    assert_eq!(state.stamina, 7);
+    assert!(matches!(state.journal.as_slice(), [JournalEntry::Rested { stamina: 4, .. }]));
+    assert!(state.tile(TilePosition::new(2, 2)).expect("tile").entity.is_none());
+}
+
+#[test]
+fn test_running_out_of_stamina_ends_adventure() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    adventure.state_mut().stamina = 2;
+    explore(&mut adventure);
+    let state = adventure.state();
+    assert!(matches!(state.choice_screen, Some(AdventureChoiceScreen::AdventureOver)));
+    assert_eq!(state.journal.last(), Some(&JournalEntry::Exhausted));
+}
+
+#[test]
+fn test_exhaustion_waits_for_event_to_be_dismissed() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    adventure.state_mut().stamina = 1;
+    adventure.visit_tile_with_icon(EVENT_ICON);
+    adventure.click_on("Investigate");
+    assert!(matches!(adventure.state().choice_screen, Some(AdventureChoiceScreen::Event(_))));
+    adventure.click_on("Continue");
+    assert!(matches!(adventure.state().choice_screen, Some(AdventureChoiceScreen::AdventureOver)));
+}
+
+#[test]
+fn test_stamina_displayed_in_overlay() {
+    let mut adventure = TestAdventure::new(Side::Champion);
+    explore(&mut adventure);
+    assert!(adventure.interface.screen_overlay().has_text(format!("{}", STARTING_STAMINA - 2)));
+}
+
+fn explore(adventure: &mut TestAdventure) {
+    adventure.visit_tile_with_icon(EXPLORE_ICON);
+    adventure.click_on(format!("Explore: 100 {}", icons::COINS));
+}
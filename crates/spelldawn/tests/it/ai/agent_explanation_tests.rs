@@ -0,0 +1,92 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ai_core::agent::{Agent, AgentConfig, AgentData};
+use ai_game_integration::agents;
+use ai_game_integration::evaluators::ScoreEvaluator;
+use ai_game_integration::state_node::SpelldawnState;
+use ai_tree_search::minimax::MinimaxAlgorithm;
+use data::game_actions::GameAction;
+use data::player_name::NamedPlayer;
+use data::primitives::Side;
+use test_utils::client::TestSession;
+use test_utils::*;
+
+fn state(g: &TestSession, explain_agents: bool) -> SpelldawnState {
+    let mut state = SpelldawnState(g.game().clone_without_updates());
+    state.data.config.explain_agents = explain_agents;
+    state
+}
+
+fn sorted(actions: impl Iterator<Item = GameAction>) -> Vec<String> {
+    let mut result = actions.map(|action| format!("{:?}", action)).collect::<Vec<_>>();
+    result.sort();
+    result
+}
+
+#[test]
+fn search_agent_scores_each_legal_action() {
+    let g = new_game(Side::Overlord, Args::default());
+    let agent =
+        AgentData::omniscient("MINIMAX", MinimaxAlgorithm { search_depth: 1 }, ScoreEvaluator {});
+    let explanation = agent
+        .explain_action(AgentConfig::with_deadline(1), &state(&g, false))
+        .expect("Agent error");
+    assert_eq!(
+        sorted(g.legal_actions_with_priors(Side::Overlord).into_iter().map(|(action, _)| action)),
+        sorted(explanation.candidates.iter().map(|(action, _)| *action))
+    );
+    assert!(explanation.candidates.iter().any(|(action, _)| *action == explanation.chosen));
+}
+
+#[test]
+fn scripted_agent_reports_priors() {
+    let g = new_game(Side::Champion, Args::default());
+    let agent = agents::get(NamedPlayer::AdventureBossRaider);
+    let explanation = agent
+        .explain_action(AgentConfig::with_deadline(1), &state(&g, false))
+        .expect("Agent error");
+    assert_eq!(g.legal_actions_with_priors(Side::Champion), explanation.candidates);
+}
+
+#[test]
+fn pick_action_records_explanation() {
+    let g = new_game(Side::Champion, Args::default());
+    let agent = agents::get(NamedPlayer::AdventureBossRaider);
+    let mut state = state(&g, true);
+    let action = agents::pick_action(
+        agent.as_ref(),
+        AgentConfig::with_deadline(1),
+        &mut state,
+        Side::Champion,
+    )
+    .expect("Agent error");
+
+    assert_eq!(1, state.agent_log.len());
+    let entry = &state.agent_log[0];
+    assert_eq!(Side::Champion, entry.side);
+    assert_eq!("SCRIPTED", entry.agent);
+    assert_eq!(action, entry.chosen);
+    assert!(!entry.candidates.is_empty());
+}
+
+#[test]
+fn pick_action_without_explanations() {
+    let g = new_game(Side::Champion, Args::default());
+    let agent = agents::get(NamedPlayer::AdventureBossRaider);
+    let mut state = state(&g, false);
+    agents::pick_action(agent.as_ref(), AgentConfig::with_deadline(1), &mut state, Side::Champion)
+        .expect("Agent error");
+    assert!(state.agent_log.is_empty());
+}
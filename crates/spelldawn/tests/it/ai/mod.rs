@@ -12,5 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod agent_explanation_tests;
 mod monte_carlo_tests;
+mod scripted_agent_tests;
 mod tree_search_tests;
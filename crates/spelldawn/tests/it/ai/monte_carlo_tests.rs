@@ -15,7 +15,9 @@
 use std::time::Instant;
 
 use ai_core::agent::{Agent, AgentConfig, AgentData};
-use ai_monte_carlo::monte_carlo::{MonteCarloAlgorithm, RandomPlayoutEvaluator};
+use ai_monte_carlo::monte_carlo::{
+    MonteCarloAlgorithm, PriorPlayoutEvaluator, RandomPlayoutEvaluator,
+};
 use ai_monte_carlo::uct1::Uct1;
 use ai_testing::nim;
 use ai_testing::nim::NimState;
@@ -41,6 +43,16 @@ pub fn uct1_432() {
     nim::assert_perfect_short(&NimState::new_with_piles(4, 3, 2), &NIM_UCT1_AGENT);
 }
 
+#[test]
+pub fn uct1_priors_223() {
+    let agent = AgentData::omniscient(
+        "UCT1_PRIORS",
+        MonteCarloAlgorithm { child_score_algorithm: Uct1 {} },
+        PriorPlayoutEvaluator {},
+    );
+    nim::assert_perfect_short(&NimState::new_with_piles(2, 2, 3), &agent);
+}
+
 #[test]
 pub fn uct1_deadline_exceeded() {
     let agent = AgentData::omniscient(
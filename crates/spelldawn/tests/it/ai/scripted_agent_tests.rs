@@ -0,0 +1,72 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ai_core::agent::{Agent, AgentConfig};
+use ai_game_integration::agents;
+use ai_game_integration::scripted_agent::ScriptedAgent;
+use ai_game_integration::state_node::SpelldawnState;
+use data::game_actions::GameAction;
+use data::player_name::NamedPlayer;
+use data::primitives::{RoomId, Side};
+use test_utils::client::TestSession;
+use test_utils::test_card_builder::TestCardBuilder;
+use test_utils::*;
+
+fn pick_action(g: &TestSession, agent: &dyn Agent<SpelldawnState>) -> GameAction {
+    let state = SpelldawnState(g.game().clone_without_updates());
+    agent.pick_action(AgentConfig::with_deadline(1), &state).expect("Agent error")
+}
+
+#[test]
+fn warden_plays_cheapest_minion() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.add_to_hand(TestCardBuilder::minion().mana_cost(4).build());
+    let cheap = g.add_to_hand(TestCardBuilder::minion().mana_cost(1).build());
+    g.add_to_hand(TestCardBuilder::minion().mana_cost(3).build());
+    let action = pick_action(&g, agents::get(NamedPlayer::AdventureBossWarden).as_ref());
+    assert!(
+        matches!(action, GameAction::PlayCard(card_id, _) if card_id == server_card_id(cheap)),
+        "Expected to play {:?}, got {:?}",
+        server_card_id(cheap),
+        action
+    );
+}
+
+#[test]
+fn raider_raids_sanctum_with_enough_mana() {
+    let g = new_game(Side::Champion, Args { mana: 8, ..Args::default() });
+    let action = pick_action(&g, agents::get(NamedPlayer::AdventureBossRaider).as_ref());
+    assert_eq!(GameAction::InitiateRaid(RoomId::Sanctum), action);
+}
+
+#[test]
+fn raider_draws_without_enough_mana() {
+    let g = new_game(Side::Champion, Args { mana: 7, ..Args::default() });
+    let action = pick_action(&g, agents::get(NamedPlayer::AdventureBossRaider).as_ref());
+    assert_eq!(GameAction::DrawCard, action);
+}
+
+#[test]
+fn falls_back_to_highest_prior() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.add_to_hand(TestCardBuilder::scheme().build());
+    let agent = ScriptedAgent { name: "EMPTY", rules: &[] };
+    let best = g
+        .legal_actions_with_priors(Side::Overlord)
+        .into_iter()
+        .max_by(|(_, a), (_, b)| a.partial_cmp(b).expect("NaN prior"))
+        .map(|(action, _)| action)
+        .expect("Legal action");
+    assert_eq!(best, pick_action(&g, &agent));
+}
@@ -0,0 +1,91 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_name::CardName;
+use data::card_state::CardPosition;
+use data::primitives::{AbilityId, CardId, RoomId, Side};
+use protos::spelldawn::PlayerName;
+use rules::mutations;
+use test_utils::client::TestSession;
+use test_utils::*;
+
+#[test]
+fn loyal_squire() {
+    let card_cost = 1;
+    let mut g = raid_setup(2);
+    g.play_from_hand(CardName::LoyalSquire);
+    g.initiate_raid(RoomId::Vault);
+    g.click_on(g.user_id(), "Continue");
+    g.click_on(g.user_id(), "Continue");
+    assert!(g.user.cards.allies().is_empty());
+    assert!(g.user.cards.discard_pile(PlayerName::User).contains(&"Loyal Squire".to_string()));
+    assert_eq!(STARTING_MANA - card_cost + 1, g.me().mana());
+}
+
+#[test]
+fn devoted_acolyte() {
+    let mut g = raid_setup(1);
+    g.play_from_hand(CardName::DevotedAcolyte);
+    let hand_size = g.user.cards.hand(PlayerName::User).len();
+    g.initiate_raid(RoomId::Vault);
+    g.click_on(g.user_id(), "Continue");
+    assert_eq!(hand_size + 1, g.user.cards.hand(PlayerName::User).len());
+    assert!(g.user.cards.allies().is_empty());
+}
+
+#[test]
+fn temple_guardian() {
+    let mut g = raid_setup(1);
+    let id = g.play_from_hand(CardName::TempleGuardian);
+    let hand_size = g.user.cards.hand(PlayerName::User).len();
+    g.initiate_raid(RoomId::Vault);
+    g.click_on(g.user_id(), "Continue");
+    assert_eq!(hand_size, g.user.cards.hand(PlayerName::User).len());
+    assert_eq!("3", g.user.get_card(id).bottom_right_icon());
+    click_on_end_raid(&mut g);
+
+    let mut game = g.game().clone();
+    assert!(game.data.raid.is_none());
+    let source = AbilityId::new(CardId::new(Side::Overlord, 0), 0);
+    mutations::deal_damage(&mut game, source, 1).expect("deal_damage");
+    assert_eq!(hand_size - 1, game.hand(Side::Champion).count());
+}
+
+#[test]
+fn temple_guardian_destroyed_when_exhausted() {
+    let mut g = raid_setup(1);
+    let id = g.play_from_hand(CardName::TempleGuardian);
+    let hand_size = g.user.cards.hand(PlayerName::User).len();
+    g.initiate_raid(RoomId::Vault);
+    g.click_on(g.user_id(), "Continue");
+
+    let mut game = g.game().clone();
+    let source = AbilityId::new(CardId::new(Side::Overlord, 0), 0);
+    mutations::deal_damage(&mut game, source, 4).expect("deal_damage");
+    assert_eq!(CardPosition::DiscardPile(Side::Champion), game.card(server_card_id(id)).position());
+    assert_eq!(hand_size - 1, game.hand(Side::Champion).count());
+}
+
+/// Creates a game in which the Overlord has placed `minions` copies of a 'deal
+/// 1 damage' minion in the Vault, followed by the start of the Champion's turn.
+fn raid_setup(minions: u32) -> TestSession {
+    let mut g = new_game(
+        Side::Champion,
+        Args { turn: Some(Side::Overlord), actions: minions, ..Args::default() },
+    );
+    for _ in 0..minions {
+        g.play_with_target_room(CardName::TestMinionDealDamage, RoomId::Vault);
+    }
+    g
+}
@@ -153,3 +153,20 @@ fn dark_grimoire() {
     g.perform(Action::DrawCard(DrawCardAction {}), g.user_id());
     assert_eq!(3, g.user.cards.hand(PlayerName::User).len());
 }
+
+#[test]
+fn forge_hammer() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.play_from_hand(CardName::ForgeHammer);
+    g.play_from_hand(CardName::TestWeapon3Attack12Boost3Cost);
+    assert_eq!(STARTING_MANA - 2 - 2, g.me().mana());
+}
+
+#[test]
+fn channeling_orb() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.play_from_hand(CardName::ChannelingOrb);
+    g.play_from_hand(CardName::Test1CostChampionSpell);
+    g.play_from_hand(CardName::TestWeapon3Attack12Boost3Cost);
+    assert_eq!(STARTING_MANA - 2 - 3, g.me().mana());
+}
@@ -0,0 +1,108 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use cards::{card_lint, champion_spells, test_cards};
+use data::card_definition::{AbilityType, AttackBoost, CardConfig, CardDefinition};
+use data::card_name::CardName;
+use data::text::{AbilityText, TextToken};
+
+#[test]
+fn all_cards_pass_lint() {
+    cards::initialize::run();
+    let errors = card_lint::lint_all_cards();
+    assert!(
+        errors.is_empty(),
+        "{}",
+        errors.iter().map(|e| e.to_string()).collect::<Vec<_>>().join("\n")
+    );
+}
+
+#[test]
+fn minion_without_lineage() {
+    let definition = CardDefinition {
+        config: CardConfig { lineage: None, ..test_cards::test_minion_end_raid().config },
+        ..test_cards::test_minion_end_raid()
+    };
+    assert_eq!(vec!["Minion must have a lineage"], messages(&definition));
+}
+
+#[test]
+fn scheme_without_points() {
+    let definition =
+        CardDefinition { config: CardConfig::default(), ..test_cards::test_scheme_31() };
+    assert_eq!(vec!["Scheme must have scheme_points"], messages(&definition));
+}
+
+#[test]
+fn modal_card_with_one_mode() {
+    let mut definition = champion_spells::crossroads();
+    definition.abilities.pop();
+    assert_eq!(vec!["Modal card must have at least two modes"], messages(&definition));
+}
+
+#[test]
+fn variable_mana_cost_on_modal_card() {
+    let mut definition = champion_spells::crossroads();
+    definition.cost.variable_mana = true;
+    assert_eq!(vec!["Modal card cannot have a variable mana cost"], messages(&definition));
+}
+
+#[test]
+fn attack_boost_without_encounter_ability() {
+    let mut definition = test_cards::test_weapon_2_attack_12_boost();
+    definition.config.stats.attack_boost = None;
+    assert_eq!(vec!["Encounter ability requires an attack_boost"], messages(&definition));
+
+    let mut definition = test_cards::test_weapon_2_attack();
+    definition.config.stats.attack_boost = Some(AttackBoost { cost: 1, bonus: 2 });
+    assert_eq!(vec!["attack_boost has no encounter ability to activate it"], messages(&definition));
+}
+
+#[test]
+fn activated_ability_cost_in_text() {
+    let mut definition = test_cards::test_activated_ability_charges();
+    definition.abilities[0].text =
+        AbilityText::Text(vec![TextToken::Cost(vec![TextToken::Mana(1)])]);
+    assert_eq!(
+        vec![
+            "Ability 0: Activated ability cost is rendered from its Cost, remove the cost from \
+             its text"
+        ],
+        messages(&definition)
+    );
+}
+
+#[test]
+fn ability_without_delegates() {
+    let mut definition = test_cards::test_minion_end_raid();
+    definition.abilities[0].delegates.clear();
+    assert_eq!(
+        vec!["Ability 0: Ability has no delegates, use AbilityType::TextOnly for text"],
+        messages(&definition)
+    );
+
+    definition.abilities[0].ability_type = AbilityType::TextOnly;
+    assert!(messages(&definition).is_empty());
+}
+
+#[test]
+fn test_card_without_rarity_allowed() {
+    let definition = test_cards::test_overlord_spell();
+    assert_eq!(CardName::TestOverlordSpell, definition.name);
+    assert!(messages(&definition).is_empty());
+}
+
+fn messages(definition: &CardDefinition) -> Vec<String> {
+    card_lint::lint(definition).into_iter().map(|error| error.message).collect()
+}
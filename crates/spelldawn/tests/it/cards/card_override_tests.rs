@@ -0,0 +1,58 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use cards::test_cards;
+use data::card_definition::{AttackBoost, SchemePoints};
+use data::card_name::CardName;
+use rules::card_overrides;
+
+#[test]
+fn parse_and_apply_override() {
+    let overrides = card_overrides::parse(
+        r#"{"TestMinionEndRaid": {"mana_cost": 7, "action_cost": 2, "health": 9}}"#,
+    )
+    .unwrap();
+    let mut definition = test_cards::test_minion_end_raid();
+    card_overrides::apply(&mut definition, &overrides[&CardName::TestMinionEndRaid]);
+    assert_eq!(Some(7), definition.cost.mana);
+    assert_eq!(2, definition.cost.actions);
+    assert_eq!(Some(9), definition.config.stats.health);
+    assert_eq!(None, definition.config.stats.shield);
+}
+
+#[test]
+fn override_attack_boost_bonus() {
+    let overrides =
+        card_overrides::parse(r#"{"TestWeapon2Attack12Boost": {"attack_boost_bonus": 5}}"#)
+            .unwrap();
+    let mut definition = test_cards::test_weapon_2_attack_12_boost();
+    card_overrides::apply(&mut definition, &overrides[&CardName::TestWeapon2Attack12Boost]);
+    assert_eq!(Some(AttackBoost { cost: 1, bonus: 5 }), definition.config.stats.attack_boost);
+}
+
+#[test]
+fn override_scheme_points() {
+    let overrides = card_overrides::parse(r#"{"TestScheme31": {"points": 4}}"#).unwrap();
+    let mut definition = test_cards::test_scheme_31();
+    card_overrides::apply(&mut definition, &overrides[&CardName::TestScheme31]);
+    assert_eq!(
+        Some(SchemePoints { level_requirement: 3, points: 4 }),
+        definition.config.stats.scheme_points
+    );
+}
+
+#[test]
+fn unknown_field_error() {
+    assert!(card_overrides::parse(r#"{"TestScheme31": {"cost": 4}}"#).is_err());
+}
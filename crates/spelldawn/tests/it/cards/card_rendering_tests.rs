@@ -0,0 +1,79 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Golden tests which render every card and compare the result against a
+//! stored snapshot, to catch regressions in rules text or card icons.
+
+use std::fmt::Write;
+
+use data::card_name::CardName;
+use data::player_data::IconMode;
+use data::text::RulesTextContext;
+use insta::assert_snapshot;
+use protos::spelldawn::CardIcons;
+use rules_text::card_icons;
+use test_utils::*;
+
+#[test]
+fn render_all_cards() {
+    cards::initialize::run();
+    for name in enum_iterator::all::<CardName>().filter(|name| !name.is_synthetic()) {
+        assert_snapshot!(format!("{:?}", name), render(name));
+    }
+}
+
+fn render(name: CardName) -> String {
+    let definition = rules::get(name);
+    let context = RulesTextContext::Default(definition);
+    let mut result = String::new();
+
+    writeln!(result, "== Default ==").unwrap();
+    writeln!(result, "text: {}", rules_text::build(&context, definition).text).unwrap();
+    for (icon, text) in
+        icon_texts(card_icons::build(&context, definition, true, IconMode::Standard))
+    {
+        writeln!(result, "{}: {}", icon, text).unwrap();
+    }
+    for tooltip in rules_text::build_keyword_tooltips(&context, None) {
+        writeln!(result, "keyword: {}", tooltip.name).unwrap();
+    }
+
+    let mut g = new_game(definition.side, Args::default());
+    let id = g.add_to_hand(name);
+    let card = g.user.cards.get(id);
+    writeln!(result, "== In Game ==").unwrap();
+    writeln!(result, "title: {}", card.title()).unwrap();
+    writeln!(result, "text: {}", card.rules_text()).unwrap();
+    for (icon, text) in card.icons() {
+        writeln!(result, "{}: {}", icon, text).unwrap();
+    }
+    for tooltip in card.keyword_tooltips() {
+        writeln!(result, "keyword: {}", tooltip.name).unwrap();
+    }
+
+    result
+}
+
+fn icon_texts(icons: CardIcons) -> Vec<(&'static str, String)> {
+    [
+        ("arena", icons.arena_icon),
+        ("top_left", icons.top_left_icon),
+        ("top_right", icons.top_right_icon),
+        ("bottom_left", icons.bottom_left_icon),
+        ("bottom_right", icons.bottom_right_icon),
+    ]
+    .into_iter()
+    .filter_map(|(name, icon)| icon.and_then(|icon| icon.text).map(|text| (name, text)))
+    .collect()
+}
@@ -14,9 +14,10 @@
 
 use cards::test_cards::{MINION_COST, TEST_LINEAGE};
 use data::card_name::CardName;
-use data::primitives::{RoomId, Side};
+use data::game_actions::{CardTarget, GameAction};
+use data::primitives::{AbilityId, RoomId, Side};
 use protos::spelldawn::object_position::Position;
-use protos::spelldawn::{ObjectPositionBrowser, PlayerName};
+use protos::spelldawn::{ClientRoomLocation, ObjectPositionBrowser, PlayerName};
 use test_utils::*;
 
 #[test]
@@ -91,6 +92,75 @@ fn preparation() {
     assert_eq!(4, g.user.cards.hand(PlayerName::User).len());
     assert_eq!(1, g.me().actions());
     g.play_from_hand(CardName::Preparation);
-    assert_eq!(7, g.user.cards.hand(PlayerName::User).len()); // One discarded to hand size
+    assert_eq!(8, g.user.cards.revealed_cards().len());
+    discard_to_hand_size(&mut g, Side::Champion);
+    assert_eq!(7, g.user.cards.hand(PlayerName::User).len());
     assert!(g.dusk());
 }
+
+#[test]
+fn sunburst() {
+    cards::initialize::run();
+    let mut g = new_game(
+        Side::Champion,
+        Args {
+            in_play: vec![
+                TestCard::new(CardName::TestInfernalMinion),
+                TestCard::new(CardName::TestAbyssalMinion),
+                TestCard::new(CardName::TestMortalMinion).room_id(RoomId::RoomB),
+            ],
+            ..Args::default()
+        },
+    );
+    g.play_with_target_room(CardName::Sunburst, ROOM_ID);
+    assert_eq!(2, g.user.cards.discard_pile(PlayerName::Opponent).len());
+    assert_eq!(1, g.user.cards.room_cards(RoomId::RoomB, ClientRoomLocation::Front).len());
+    assert!(g.user.cards.room_cards(ROOM_ID, ClientRoomLocation::Front).is_empty());
+}
+
+#[test]
+fn embezzle() {
+    cards::initialize::run();
+    let (embezzle_cost, coinery_cost, taken) = (3, 2, 3);
+    let mut g = new_game(
+        Side::Champion,
+        Args { in_play: vec![TestCard::new(CardName::Coinery)], ..Args::default() },
+    );
+    let coinery = g.game().all_cards().find(|c| c.name == CardName::Coinery).expect("coinery").id;
+    let ability_id = AbilityId::new(coinery, 1);
+    assert!(!g.user.get_card(adapters::card_identifier(coinery)).revealed_to_me());
+    assert!(!g
+        .legal_actions(Side::Champion)
+        .contains(&GameAction::ActivateAbility(ability_id, CardTarget::None)));
+
+    g.play_with_target_room(CardName::Embezzle, ROOM_ID);
+    assert_eq!(Side::Champion, g.game().card(coinery).controller());
+    assert!(g.user.get_card(adapters::card_identifier(coinery)).revealed_to_me());
+    assert!(g
+        .legal_actions(Side::Champion)
+        .contains(&GameAction::ActivateAbility(ability_id, CardTarget::None)));
+
+    g.activate_ability(adapters::card_identifier(coinery), 1);
+    assert_eq!(STARTING_MANA - embezzle_cost - coinery_cost + taken, g.me().mana());
+    assert_eq!(STARTING_MANA, g.opponent.this_player.mana());
+}
+
+#[test]
+fn crossroads_gain_mana() {
+    let card_cost = 1;
+    let mut g = new_game(Side::Champion, Args::default());
+    g.play_from_hand(CardName::Crossroads);
+    assert_eq!(STARTING_MANA - card_cost, g.me().mana());
+    g.click_on(g.user_id(), "Gain Mana");
+    assert_eq!(STARTING_MANA - card_cost + 5, g.me().mana());
+    assert!(g.user.cards.discard_pile(PlayerName::User).contains(&"Crossroads".to_string()));
+}
+
+#[test]
+fn crossroads_draw_cards() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.play_from_hand(CardName::Crossroads);
+    let hand_size = g.user.cards.hand(PlayerName::User).len();
+    g.click_on(g.user_id(), "Draw Cards");
+    assert_eq!(hand_size + 2, g.user.cards.hand(PlayerName::User).len());
+}
@@ -226,6 +226,15 @@ fn stormcaller_take_4() {
     assert_eq!(4, g.opponent.cards.discard_pile(PlayerName::User).len());
 }
 
+#[test]
+fn stormcaller_prompt_preview() {
+    let mut g = new_game(Side::Overlord, Args { opponent_hand_size: 5, ..Args::default() });
+    g.play_from_hand(CardName::Stormcaller);
+    set_up_minion_combat(&mut g);
+    assert!(g.opponent.interface.controls().has_text("Discard 2, Raid ends"));
+    assert!(g.opponent.interface.controls().has_text("Discard 4"));
+}
+
 #[test]
 fn stormcaller_take_2_game_over() {
     let mut g = new_game(Side::Overlord, Args::default());
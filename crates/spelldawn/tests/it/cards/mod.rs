@@ -12,7 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod ally_tests;
 mod artifact_tests;
+mod card_lint_tests;
+mod card_override_tests;
+mod card_rendering_tests;
 mod champion_spell_tests;
 mod minion_tests;
 mod overlord_spell_tests;
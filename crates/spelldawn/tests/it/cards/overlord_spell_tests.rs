@@ -14,6 +14,7 @@
 
 use data::card_name::CardName;
 use data::primitives::Side;
+use protos::spelldawn::PlayerName;
 use test_utils::*;
 
 #[test]
@@ -49,3 +50,44 @@ fn forced_march_same_turn_panic() {
     g.play_from_hand(CardName::TestScheme31);
     g.play_with_target_room(CardName::ForcedMarch, ROOM_ID);
 }
+
+#[test]
+fn corrosion() {
+    cards::initialize::run();
+    let mut g = new_game(
+        Side::Overlord,
+        Args {
+            in_play: vec![
+                TestCard::new(CardName::TestActivatedAbilityTakeMana),
+                TestCard::new(CardName::TestActivatedAbilityCharges),
+                TestCard::new(CardName::TestWeapon2Attack),
+            ],
+            ..Args::default()
+        },
+    );
+    g.play_from_hand(CardName::Corrosion);
+    assert_eq!(2, g.user.cards.discard_pile(PlayerName::Opponent).len());
+    assert_eq!(1, g.opponent.cards.left_items().len() + g.opponent.cards.right_items().len());
+}
+
+#[test]
+fn torment() {
+    let (mana, cost, x) = (5, 1, 2);
+    let mut g = new_game(
+        Side::Overlord,
+        Args {
+            mana,
+            opponent_hand: vec![
+                CardName::TestChampionSpell,
+                CardName::TestChampionSpell,
+                CardName::TestChampionSpell,
+            ],
+            ..Args::default()
+        },
+    );
+    g.play_from_hand(CardName::Torment);
+    g.click_on(g.user_id(), "X = 2");
+    assert_eq!(mana - cost - x, g.me().mana());
+    assert_eq!(1, g.user.cards.hand(PlayerName::Opponent).len());
+    assert_eq!(2, g.user.cards.discard_pile(PlayerName::Opponent).len());
+}
@@ -12,9 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use cards::test_cards::{MINION_COST, WEAPON_COST};
+use core_ui::actions::InterfaceAction;
 use data::card_name::CardName;
+use data::game_actions::{CardPromptAction, PromptAction};
 use data::primitives::Side;
-use protos::spelldawn::PlayerName;
+use protos::spelldawn::{CardIdentifier, PlayerName};
+use test_utils::client::TestSession;
 use test_utils::*;
 
 #[test]
@@ -91,3 +95,34 @@ fn spike_trap_victory() {
     g.initiate_raid(ROOM_ID);
     assert!(g.is_victory_for_player(Side::Overlord));
 }
+
+#[test]
+fn summoning_circle() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let id = g.play_from_hand(CardName::SummoningCircle);
+    let minion = g.add_to_hand(CardName::TestMinionEndRaid);
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    unveil(&mut g, id);
+    assert_eq!(STARTING_MANA - 2, g.me().mana());
+    assert_eq!((MINION_COST - 1).to_string(), g.user.get_card(minion).top_left_icon());
+}
+
+#[test]
+fn iron_tithe() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let id = g.play_from_hand(CardName::IronTithe);
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    unveil(&mut g, id);
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    let weapon = g.add_to_hand(CardName::TestWeapon3Attack12Boost3Cost);
+    assert_eq!((WEAPON_COST + 1).to_string(), g.opponent.get_card(weapon).top_left_icon());
+    g.play_from_hand(CardName::TestWeapon3Attack12Boost3Cost);
+    assert_eq!(STARTING_MANA - WEAPON_COST - 1, g.you().mana());
+}
+
+fn unveil(g: &mut TestSession, card_id: CardIdentifier) {
+    let action = CardPromptAction::UnveilProject(server_card_id(card_id));
+    g.perform(PromptAction::CardAction(action).as_client_action(), g.user_id());
+}
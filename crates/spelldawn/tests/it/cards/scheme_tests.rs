@@ -69,5 +69,6 @@ fn research_project() {
     g.perform(Action::DrawCard(DrawCardAction {}), g.user_id());
     g.perform(Action::DrawCard(DrawCardAction {}), g.user_id());
     g.perform(Action::DrawCard(DrawCardAction {}), g.user_id());
+    discard_to_hand_size(&mut g, Side::Overlord);
     assert_eq!(9, g.user.cards.hand(PlayerName::User).len());
 }
@@ -12,10 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use data::card_definition::{Ability, AbilityType};
 use data::card_name::CardName;
+use data::player_data::IconMode;
 use data::primitives::{Lineage, RoomId, Side};
+use data::text::{AbilityText, PreviewState, RulesTextContext, TextToken};
 use protos::spelldawn::PlayerName;
+use rules_text::card_icons;
 use test_utils::client_interface::HasText;
+use test_utils::test_card_builder::TestCardBuilder;
 use test_utils::*;
 
 #[test]
@@ -30,6 +35,42 @@ fn test_attack_weapon() {
     assert!(g.user.interface.controls().has_text("End Raid"));
 }
 
+#[test]
+fn test_attack_weapon_preview() {
+    cards::initialize::run();
+    let definition = rules::get(CardName::TestAttackWeapon);
+    let preview = PreviewState::default().boost_count(2);
+    let context = RulesTextContext::Preview(definition, &preview);
+    let icons = card_icons::build(&context, definition, true, IconMode::Standard);
+    assert_eq!(Some("7".to_string()), icons.bottom_right_icon.and_then(|icon| icon.text));
+    assert!(!rules_text::build(&context, definition).text.contains("<color="));
+}
+
+#[test]
+fn test_preview_highlights_computed_values() {
+    cards::initialize::run();
+    let name = TestCardBuilder::artifact()
+        .ability(Ability {
+            text: AbilityText::TextFn(|context| {
+                vec![
+                    TextToken::Literal("Stores".to_string()),
+                    TextToken::Computed(Box::new(TextToken::Mana(context.stored_mana()))),
+                ]
+            }),
+            ability_type: AbilityType::Standard,
+            delegates: vec![],
+        })
+        .build();
+    let definition = rules::get(name);
+    let preview = PreviewState::default().stored_mana(3);
+    let text = rules_text::build(&RulesTextContext::Preview(definition, &preview), definition).text;
+    assert!(text.contains("<color="));
+    assert!(text.contains(">3"));
+    assert!(!rules_text::build(&RulesTextContext::Default(definition), definition)
+        .text
+        .contains("<color="));
+}
+
 #[test]
 fn marauders_axe() {
     let card_cost = 5;
@@ -57,6 +98,21 @@ fn keen_halberd() {
     );
 }
 
+#[test]
+fn keen_halberd_keyword_tooltips() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let id = g.add_to_hand(CardName::KeenHalberd);
+    let names = g
+        .user
+        .cards
+        .get(id)
+        .keyword_tooltips()
+        .iter()
+        .map(|tooltip| tooltip.name.clone())
+        .collect::<Vec<_>>();
+    assert!(names.contains(&"Breach".to_string()));
+}
+
 #[test]
 fn ethereal_blade() {
     let (card_cost, activation_cost) = (1, 1);
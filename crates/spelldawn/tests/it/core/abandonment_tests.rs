@@ -0,0 +1,108 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::actions::InterfaceAction;
+use data::notification::Notification;
+use data::primitives::Side;
+use data::user_actions::UserAction;
+use test_utils::client_interface::HasText;
+use test_utils::*;
+
+#[test]
+fn concede_on_opponent_turn() {
+    let mut g = new_game(Side::Champion, Args { turn: Some(Side::Overlord), ..Args::default() });
+    g.perform(UserAction::Concede.as_client_action(), g.user_id());
+    assert!(!g.player_data(g.user_id()).match_history[0].won);
+    assert!(g.player_data(g.opponent_id()).match_history[0].won);
+}
+
+#[test]
+fn disconnect_notifies_opponent() {
+    let mut g =
+        new_game(Side::Overlord, Args { disconnect_grace_period: Some(60), ..Args::default() });
+    g.disconnect(g.opponent_id(), 1000).unwrap();
+    assert_eq!(1, g.user.interface.toasts().len());
+    assert!(g.user.interface.toasts()[0].has_text("Opponent Disconnected"));
+    assert!(g.opponent.interface.toasts().is_empty());
+
+    g.disconnect(g.opponent_id(), 1030).unwrap();
+    assert_eq!(1, g.user.interface.toasts().len());
+    assert!(g.player_data(g.user_id()).match_history.is_empty());
+}
+
+#[test]
+fn game_untouched_during_grace_period() {
+    let mut g =
+        new_game(Side::Overlord, Args { disconnect_grace_period: Some(60), ..Args::default() });
+    assert!(g.disconnect(g.opponent_id(), 1000).unwrap());
+    assert!(!g.disconnect(g.opponent_id(), 1010).unwrap());
+    assert!(!g.disconnect(g.opponent_id(), 1059).unwrap());
+    assert!(g.disconnect(g.opponent_id(), 1060).unwrap());
+    assert!(g.player_data(g.user_id()).match_history[0].won);
+}
+
+#[test]
+fn forfeit_after_grace_period() {
+    let mut g =
+        new_game(Side::Overlord, Args { disconnect_grace_period: Some(60), ..Args::default() });
+    g.disconnect(g.opponent_id(), 1000).unwrap();
+    g.disconnect(g.opponent_id(), 1060).unwrap();
+    assert!(g.player_data(g.user_id()).match_history[0].won);
+    assert!(!g.player_data(g.opponent_id()).match_history[0].won);
+}
+
+#[test]
+fn reconnect_resets_grace_period() {
+    let mut g =
+        new_game(Side::Overlord, Args { disconnect_grace_period: Some(60), ..Args::default() });
+    g.disconnect(g.opponent_id(), 1000).unwrap();
+    g.connect(g.opponent_id()).unwrap();
+    g.disconnect(g.opponent_id(), 1100).unwrap();
+    assert!(g.player_data(g.user_id()).match_history.is_empty());
+}
+
+#[test]
+fn no_forfeit_without_grace_period() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.disconnect(g.opponent_id(), 1000).unwrap();
+    g.disconnect(g.opponent_id(), 1_000_000).unwrap();
+    assert!(g.player_data(g.user_id()).match_history.is_empty());
+}
+
+#[test]
+fn disconnections_tracked_per_player() {
+    let mut g =
+        new_game(Side::Overlord, Args { disconnect_grace_period: Some(60), ..Args::default() });
+    g.disconnect(g.user_id(), 1000).unwrap();
+    g.disconnect(g.opponent_id(), 1010).unwrap();
+    g.connect(g.user_id()).unwrap();
+    g.disconnect(g.opponent_id(), 1070).unwrap();
+    assert!(g.player_data(g.user_id()).match_history[0].won);
+    assert!(!g.player_data(g.opponent_id()).match_history[0].won);
+}
+
+#[test]
+fn both_players_disconnected_abandons_game() {
+    let mut g =
+        new_game(Side::Overlord, Args { disconnect_grace_period: Some(60), ..Args::default() });
+    g.disconnect(g.opponent_id(), 1000).unwrap();
+    g.disconnect(g.user_id(), 1030).unwrap();
+    assert!(!g.disconnect(g.opponent_id(), 1060).unwrap());
+    for player_id in [g.user_id(), g.opponent_id()] {
+        let player = g.player_data(player_id);
+        assert!(player.state.is_none());
+        assert!(player.match_history.is_empty());
+        assert_eq!(vec![Notification::GameAbandoned], player.notifications);
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use cards::test_cards::ARTIFACT_COST;
+use data::card_name::CardName;
+use data::primitives::Side;
+use protos::spelldawn::client_action::Action;
+use protos::spelldawn::{CardIdentifier, PlayCardAction};
+use test_utils::client::TestSession;
+use test_utils::*;
+
+#[test]
+fn charges_limit_activations() {
+    let mut g = new_game(Side::Champion, Args { actions: 4, ..Args::default() });
+    let id = g.play_from_hand(CardName::TestActivatedAbilityCharges);
+    assert_eq!("2", g.user.get_card(id).top_right_icon());
+    g.activate_ability(id, 0);
+    assert_eq!("1", g.user.get_card(id).top_right_icon());
+    assert_eq!("1", g.opponent.get_card(id).top_right_icon());
+    g.activate_ability(id, 0);
+    assert_eq!("0", g.user.get_card(id).top_right_icon());
+    assert_eq!(STARTING_MANA - ARTIFACT_COST + 2, g.me().mana());
+    assert!(activation_fails(&mut g, id, 0));
+}
+
+#[test]
+fn once_per_turn_cleared_at_turn_boundary() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let id = g.play_from_hand(CardName::MagicalResonator);
+    g.activate_ability(id, 1);
+    assert!(activation_fails(&mut g, id, 1));
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    g.activate_ability(id, 1);
+    assert_eq!("3", g.user.get_card(id).arena_icon());
+}
+
+#[test]
+fn cooldown_blocks_activation_for_following_turn() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let id = g.play_from_hand(CardName::TestActivatedAbilityCooldown);
+    g.activate_ability(id, 0);
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    assert!(activation_fails(&mut g, id, 0));
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    g.activate_ability(id, 0);
+    assert_eq!(STARTING_MANA - ARTIFACT_COST + 2, g.me().mana());
+}
+
+fn activation_fails(g: &mut TestSession, id: CardIdentifier, index: u32) -> bool {
+    g.perform_action(
+        Action::PlayCard(PlayCardAction { card_id: Some(ability_id(id, index)), target: None }),
+        g.user_id(),
+    )
+    .is_err()
+}
@@ -0,0 +1,94 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use card_helpers::{face_up_in_play, replace_access, simple_ability, text};
+use data::card_definition::{Ability, AbilityType};
+use data::card_name::CardName;
+use data::delegates::{Delegate, QueryDelegate};
+use data::primitives::{RoomId, Side};
+use protos::spelldawn::PlayerName;
+use rules::mutations;
+use test_utils::client::TestSession;
+use test_utils::client_interface::HasText;
+use test_utils::test_card_builder::TestCardBuilder;
+use test_utils::*;
+
+fn champion_turn(opponent_discard: Option<CardName>) -> TestSession {
+    let mut g = new_game(
+        Side::Champion,
+        Args { turn: Some(Side::Overlord), actions: 1, opponent_discard, ..Args::default() },
+    );
+    g.play_from_hand(CardName::TestScheme31);
+    g
+}
+
+#[test]
+fn crypts_access_count() {
+    let mut g = champion_turn(Some(CardName::TestScheme31));
+    g.play_from_hand(
+        TestCardBuilder::artifact()
+            .ability(simple_ability(
+                text!["Access no cards in the Crypts."],
+                Delegate::CryptsAccessCount(QueryDelegate {
+                    requirement: face_up_in_play,
+                    transformation: |_, _, _, _| 0,
+                }),
+            ))
+            .build(),
+    );
+    g.initiate_raid(RoomId::Crypts);
+    assert!(g.user.data.raid_active());
+    assert!(!g.user.interface.controls().has_text("Score"));
+}
+
+#[test]
+fn room_access_count() {
+    let mut g = champion_turn(None);
+    g.play_from_hand(
+        TestCardBuilder::artifact()
+            .ability(simple_ability(
+                text!["Access no cards in outer rooms."],
+                Delegate::RoomAccessCount(QueryDelegate {
+                    requirement: face_up_in_play,
+                    transformation: |_, _, _, _| 0,
+                }),
+            ))
+            .build(),
+    );
+    g.initiate_raid(ROOM_ID);
+    assert!(g.user.data.raid_active());
+    assert!(!g.user.interface.controls().has_text("Score"));
+}
+
+#[test]
+fn replace_access_with_draw() {
+    let mut g = champion_turn(None);
+    g.play_from_hand(
+        TestCardBuilder::artifact()
+            .ability(Ability {
+                text: text!["Instead of accessing cards, draw 2 cards."],
+                ability_type: AbilityType::Standard,
+                delegates: replace_access(face_up_in_play, |g, _, _| {
+                    mutations::draw_cards(g, Side::Champion, 2)?;
+                    Ok(())
+                }),
+            })
+            .build(),
+    );
+    let hand_size = g.user.cards.hand(PlayerName::User).len();
+    g.initiate_raid(ROOM_ID);
+    assert!(!g.user.data.raid_active());
+    assert_eq!(hand_size + 2, g.user.cards.hand(PlayerName::User).len());
+    assert!(!g.user.interface.controls().has_text("Score"));
+}
@@ -0,0 +1,61 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::actions::InterfaceAction;
+use data::achievements::AchievementName;
+use data::card_name::CardName;
+use data::game_actions::GameAction;
+use data::primitives::Side;
+use data::user_actions::UserAction;
+use protos::spelldawn::client_action::Action;
+use protos::spelldawn::LevelUpRoomAction;
+use test_utils::client_interface::HasText;
+use test_utils::*;
+
+#[test]
+fn first_victory() {
+    let mut g =
+        new_game(Side::Overlord, Args { mana: 10, score: 6, actions: 5, ..Args::default() });
+    g.play_from_hand(CardName::TestScheme31);
+    let level_up = Action::LevelUpRoom(LevelUpRoomAction { room_id: CLIENT_ROOM_ID.into() });
+    g.perform(level_up.clone(), g.user_id());
+    g.perform(level_up.clone(), g.user_id());
+    g.perform(level_up, g.user_id());
+
+    let player = g.player_data(g.user_id());
+    assert!(player.achievements.contains(&AchievementName::FirstVictory));
+    assert!(player.cosmetics.contains(&AchievementName::FirstVictory.reward().unwrap()));
+    assert_eq!(1, g.user.interface.toasts().len());
+    assert!(g.user.interface.toasts()[0].has_text("First Victory"));
+    assert!(g.player_data(g.opponent_id()).achievements.is_empty());
+    assert!(g.opponent.interface.toasts().is_empty());
+}
+
+#[test]
+fn flawless_victory() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.perform(UserAction::GameAction(GameAction::Resign).as_client_action(), g.user_id());
+    let champion = g.player_data(g.opponent_id());
+    assert!(champion.achievements.contains(&AchievementName::FlawlessVictory));
+    assert!(g.opponent.interface.toasts().iter().any(|toast| toast.has_text("Flawless Victory")));
+}
+
+#[test]
+fn no_flawless_victory_for_overlord() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.perform(UserAction::GameAction(GameAction::Resign).as_client_action(), g.user_id());
+    let overlord = g.player_data(g.opponent_id());
+    assert!(overlord.achievements.contains(&AchievementName::FirstVictory));
+    assert!(!overlord.achievements.contains(&AchievementName::FlawlessVictory));
+}
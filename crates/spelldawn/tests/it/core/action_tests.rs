@@ -13,9 +13,10 @@
 // limitations under the License.
 
 use cards::test_cards::{ARTIFACT_COST, MANA_STORED, MANA_TAKEN, UNVEIL_COST};
+use core_ui::actions::InterfaceAction;
 use data::card_name::CardName;
 use data::game_actions;
-use data::game_actions::GameAction;
+use data::game_actions::{CardPromptAction, GameAction, PromptAction};
 use data::primitives::{RoomId, Side};
 use insta::assert_snapshot;
 use protos::spelldawn::client_action::Action;
@@ -24,6 +25,7 @@ use protos::spelldawn::{
     card_target, CardTarget, ClientRoomLocation, DrawCardAction, GainManaAction, GameMessageType,
     LevelUpRoomAction, ObjectPositionDiscardPile, PlayCardAction, PlayerName,
 };
+use test_utils::client_interface::HasText;
 use test_utils::summarize::Summary;
 use test_utils::*;
 
@@ -114,7 +116,70 @@ fn maximum_hand_size() {
     spend_actions_until_turn_over(&mut g, Side::Champion);
     assert_eq!(8, g.user.cards.hand(PlayerName::User).len());
     spend_actions_until_turn_over(&mut g, Side::Overlord);
+    assert!(g.user.interface.controls().has_text("Discard a card"));
+    assert_eq!(8, g.user.cards.revealed_cards().len());
+    assert_eq!(0, g.opponent.this_player.actions());
+    discard_to_hand_size(&mut g, Side::Overlord);
     assert_eq!(vec!["Test Minion End Raid"], g.user.cards.discard_pile(PlayerName::User));
+    assert_eq!(7, g.user.cards.hand(PlayerName::User).len());
+    assert!(g.dawn());
+}
+
+#[test]
+fn discard_to_hand_size_prompt() {
+    let mut g = new_game(Side::Champion, Args::default());
+    for _ in 0..8 {
+        g.add_to_hand(CardName::TestChampionSpell);
+    }
+    let first = g.add_to_hand(CardName::TestWeapon2Attack);
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    assert!(g.user.interface.controls().has_text("Discard 2 cards"));
+    assert!(g.user.interface.card_anchor_nodes().has_text("Discard"));
+    g.perform(
+        PromptAction::CardAction(CardPromptAction::DiscardToHandSize(
+            Side::Champion,
+            server_card_id(first),
+        ))
+        .as_client_action(),
+        g.user_id(),
+    );
+    assert!(g.user.interface.controls().has_text("Discard a card"));
+    assert_eq!(
+        vec![CardName::TestWeapon2Attack.displayed_name()],
+        g.user.cards.discard_pile(PlayerName::User)
+    );
+    discard_to_hand_size(&mut g, Side::Champion);
+    assert_eq!(7, g.user.cards.hand(PlayerName::User).len());
+    assert!(g.dusk());
+}
+
+#[test]
+fn cannot_discard_card_not_in_hand() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let weapon = g.play_from_hand(CardName::TestWeapon2Attack);
+    for _ in 0..8 {
+        g.add_to_hand(CardName::TestChampionSpell);
+    }
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    assert!(g.user.interface.controls().has_text("Discard a card"));
+    let action = PromptAction::CardAction(CardPromptAction::DiscardToHandSize(
+        Side::Champion,
+        server_card_id(weapon),
+    ));
+    assert!(g.perform_action(action.as_client_action(), g.user_id()).is_err());
+}
+
+#[test]
+fn raised_hand_size_avoids_discard() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.play_from_hand(CardName::TestGlobalModifierHandSize);
+    for _ in 0..9 {
+        g.add_to_hand(CardName::TestChampionSpell);
+    }
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    assert!(!g.user.interface.controls().has_text("Discard"));
+    assert_eq!(9, g.user.cards.hand(PlayerName::User).len());
+    assert!(g.dusk());
 }
 
 #[test]
@@ -239,6 +304,39 @@ fn level_up_room() {
     assert_eq!(g.opponent.other_player.mana(), 9);
 }
 
+#[test]
+fn level_up_seeded_scheme() {
+    let mut g = new_game(
+        Side::Overlord,
+        Args {
+            in_play: vec![TestCard::new(CardName::TestScheme31).card_level(2)],
+            ..Args::default()
+        },
+    );
+    assert_eq!(g.user.cards.room_cards(ROOM_ID, ClientRoomLocation::Back).len(), 1);
+    g.perform(
+        Action::LevelUpRoom(LevelUpRoomAction { room_id: CLIENT_ROOM_ID.into() }),
+        g.user_id(),
+    );
+    assert_eq!(g.user.this_player.score(), 1);
+}
+
+#[test]
+fn seeded_hands_and_items() {
+    let g = new_game(
+        Side::Champion,
+        Args {
+            hand: vec![CardName::TestWeapon3Attack12Boost3Cost],
+            opponent_hand: vec![CardName::TestMinionEndRaid, CardName::TestScheme31],
+            in_play: vec![TestCard::new(CardName::TestWeapon5Attack)],
+            ..Args::default()
+        },
+    );
+    assert_eq!(g.user.cards.hand(PlayerName::User).len(), 1);
+    assert_eq!(g.user.cards.hand(PlayerName::Opponent).len(), 2);
+    assert_eq!(g.user.cards.left_items().len(), 1);
+}
+
 #[test]
 fn minion_limit() {
     let mut g = new_game(Side::Overlord, Args { actions: 6, ..Args::default() });
@@ -484,3 +582,20 @@ fn champion_legal_actions() {
         ],
     );
 }
+
+#[test]
+fn legal_action_priors() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let scheme_id = server_card_id(g.add_to_hand(CardName::TestScheme31));
+    let priors = g.legal_actions_with_priors(Side::Overlord);
+    let prior = |action: GameAction| {
+        priors.iter().find(|(a, _)| *a == action).map(|(_, weight)| *weight).expect("action")
+    };
+
+    assert_eq!(g.legal_actions(Side::Overlord).len(), priors.len());
+    assert!(priors.iter().all(|(_, weight)| *weight > 0.0));
+    assert!(
+        prior(GameAction::PlayCard(scheme_id, game_actions::CardTarget::Room(RoomId::RoomA)))
+            > prior(GameAction::GainMana)
+    );
+}
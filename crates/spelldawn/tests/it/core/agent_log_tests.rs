@@ -0,0 +1,57 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::actions::InterfaceAction;
+use core_ui::panels;
+use data::primitives::Side;
+use data::user_actions::DebugAction;
+use panel_address::PanelAddress;
+use protos::spelldawn::client_action::Action;
+use protos::spelldawn::FetchPanelAction;
+use test_utils::client::TestSession;
+use test_utils::client_interface::HasText;
+use test_utils::*;
+
+#[test]
+fn toggle_agent_explanations() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    assert!(!g.game().data.config.explain_agents);
+    g.perform(DebugAction::ToggleAgentExplanations.as_client_action(), g.user_id());
+    assert!(g.game().data.config.explain_agents);
+    g.perform(DebugAction::ToggleAgentExplanations.as_client_action(), g.user_id());
+    assert!(!g.game().data.config.explain_agents);
+}
+
+#[test]
+fn agent_log_disabled() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    open_agent_log(&mut g);
+    assert!(g.user.interface.top_panel().has_text("AI explanations are disabled"));
+}
+
+#[test]
+fn agent_log_empty() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.perform(DebugAction::ToggleAgentExplanations.as_client_action(), g.user_id());
+    open_agent_log(&mut g);
+    assert!(g.user.interface.top_panel().has_text("No AI actions recorded"));
+}
+
+fn open_agent_log(g: &mut TestSession) {
+    g.perform(
+        Action::FetchPanel(FetchPanelAction { panel_address: Some(PanelAddress::AgentLog.into()) }),
+        g.user_id(),
+    );
+    g.user.interface.update(panels::open(PanelAddress::AgentLog));
+}
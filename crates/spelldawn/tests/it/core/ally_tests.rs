@@ -0,0 +1,97 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_name::CardName;
+use data::card_state::CardPosition;
+use data::game::GameState;
+use data::history::HistoryEvent;
+use data::primitives::{AbilityId, CardId, ItemLocation, Side};
+use rules::{mutations, queries};
+use test_utils::*;
+
+#[test]
+fn ally_enters_ally_zone() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let id = g.play_from_hand(CardName::LoyalSquire);
+    assert_eq!(vec!["Loyal Squire"], g.user.cards.allies());
+    assert_eq!(vec!["Loyal Squire"], g.opponent.cards.allies());
+    assert!(g.user.cards.right_items().is_empty());
+    assert_eq!(
+        CardPosition::ArenaItem(ItemLocation::Allies),
+        g.game().card(server_card_id(id)).position()
+    );
+}
+
+#[test]
+fn ally_absorbs_damage_before_hand() {
+    let g = new_game(
+        Side::Champion,
+        Args {
+            in_play: vec![TestCard::new(CardName::LoyalSquire)],
+            hand: vec![CardName::TestChampionSpell],
+            ..Args::default()
+        },
+    );
+    let mut game = g.game().clone();
+    let squire = ally_id(&game);
+    let hand_size = hand_size(&game);
+    mutations::deal_damage(&mut game, damage_source(), 1).expect("deal_damage");
+
+    assert_eq!(1, queries::remaining_health(&game, squire));
+    assert_eq!(CardPosition::ArenaItem(ItemLocation::Allies), game.card(squire).position());
+    assert_eq!(hand_size, self::hand_size(&game));
+    assert!(damage_history(&game).is_empty());
+}
+
+#[test]
+fn exhausted_ally_destroyed_and_excess_damage_discards() {
+    let g = new_game(
+        Side::Champion,
+        Args {
+            in_play: vec![TestCard::new(CardName::LoyalSquire)],
+            hand: vec![CardName::TestChampionSpell],
+            ..Args::default()
+        },
+    );
+    let mut game = g.game().clone();
+    let squire = ally_id(&game);
+    let hand_size = hand_size(&game);
+    mutations::deal_damage(&mut game, damage_source(), 3).expect("deal_damage");
+
+    assert_eq!(CardPosition::DiscardPile(Side::Champion), game.card(squire).position());
+    assert_eq!(0, game.card(squire).data.damage_absorbed);
+    assert_eq!(hand_size - 1, self::hand_size(&game));
+    assert_eq!(vec![1], damage_history(&game));
+}
+
+fn ally_id(game: &GameState) -> CardId {
+    game.allies().next().expect("ally").id
+}
+
+fn hand_size(game: &GameState) -> usize {
+    game.hand(Side::Champion).count()
+}
+
+fn damage_history(game: &GameState) -> Vec<u32> {
+    game.history_events()
+        .filter_map(|event| match event {
+            HistoryEvent::DealDamage(amount) => Some(amount),
+            _ => None,
+        })
+        .collect()
+}
+
+fn damage_source() -> AbilityId {
+    AbilityId::new(CardId::new(Side::Overlord, 0), 0)
+}
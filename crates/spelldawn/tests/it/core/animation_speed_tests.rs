@@ -0,0 +1,68 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::actions::InterfaceAction;
+use data::player_data::AnimationSpeed;
+use data::primitives::Side;
+use data::user_actions::UserAction;
+use protos::spelldawn::client_action::Action;
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::{DrawCardAction, MoveGameObjectsCommand};
+use server::requests::GameResponse;
+use test_utils::*;
+
+#[test]
+fn defaults_to_normal_speed() {
+    let mut g = new_game(Side::Overlord, Args { actions: 3, ..Args::default() });
+    assert_eq!(AnimationSpeed::Normal, g.player_data(g.user_id()).settings.animation_speed);
+    let response = g.perform_action(Action::DrawCard(DrawCardAction {}), g.user_id());
+    let command = move_command(&response.expect("Request failed")).expect("Move command");
+    assert!(!command.disable_animation);
+    assert_eq!(1000, command.delay.expect("delay").milliseconds);
+}
+
+#[test]
+fn fast_animations() {
+    let mut g = new_game(Side::Overlord, Args { actions: 3, ..Args::default() });
+    g.perform(UserAction::SetAnimationSpeed(AnimationSpeed::Fast).as_client_action(), g.user_id());
+    assert_eq!(AnimationSpeed::Fast, g.player_data(g.user_id()).settings.animation_speed);
+    let response = g.perform_action(Action::DrawCard(DrawCardAction {}), g.user_id());
+    let command = move_command(&response.expect("Request failed")).expect("Move command");
+    assert!(!command.disable_animation);
+    assert_eq!(500, command.delay.expect("delay").milliseconds);
+}
+
+#[test]
+fn animations_off() {
+    let mut g = new_game(Side::Overlord, Args { actions: 3, ..Args::default() });
+    g.perform(UserAction::SetAnimationSpeed(AnimationSpeed::Off).as_client_action(), g.user_id());
+    let response = g.perform_action(Action::DrawCard(DrawCardAction {}), g.user_id());
+    let command = move_command(&response.expect("Request failed")).expect("Move command");
+    assert!(command.disable_animation);
+    assert_eq!(0, command.delay.expect("delay").milliseconds);
+}
+
+#[test]
+fn animation_speed_only_affects_own_player() {
+    let mut g = new_game(Side::Overlord, Args { actions: 3, ..Args::default() });
+    g.perform(UserAction::SetAnimationSpeed(AnimationSpeed::Off).as_client_action(), g.user_id());
+    assert_eq!(AnimationSpeed::Normal, g.player_data(g.opponent_id()).settings.animation_speed);
+}
+
+fn move_command(response: &GameResponse) -> Option<MoveGameObjectsCommand> {
+    response.command_list.commands.iter().find_map(|c| match &c.command {
+        Some(Command::MoveGameObjects(command)) => Some(command.clone()),
+        _ => None,
+    })
+}
@@ -0,0 +1,139 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::player_name::{NamedPlayer, PlayerId};
+use protos::spelldawn::{AuthenticateRequest, GameRequest, PlayerIdentifier};
+use server::{auth, quarantine};
+use test_utils::fake_database::{self, FakeDatabase};
+
+const PLAYER: PlayerId = PlayerId::Database(1);
+const OTHER: PlayerId = PlayerId::Database(2);
+
+#[test]
+fn valid_session_token() {
+    let mut database = FakeDatabase::default();
+    let token = fake_database::authenticate(&mut database, PLAYER);
+    let identifier = Some(fake_database::to_player_identifier(PLAYER));
+    assert_eq!(PLAYER, auth::player_id(&mut database, &identifier, &token).unwrap());
+}
+
+#[test]
+fn tokens_are_unique() {
+    let mut database = FakeDatabase::default();
+    let first = fake_database::authenticate(&mut database, PLAYER);
+    let second = fake_database::authenticate(&mut database, PLAYER);
+    assert_ne!(first, second);
+    let identifier = Some(fake_database::to_player_identifier(PLAYER));
+    assert_eq!(PLAYER, auth::player_id(&mut database, &identifier, &first).unwrap());
+    assert_eq!(PLAYER, auth::player_id(&mut database, &identifier, &second).unwrap());
+}
+
+#[test]
+fn missing_session_token() {
+    let mut database = FakeDatabase::default();
+    let identifier = Some(fake_database::to_player_identifier(PLAYER));
+    assert!(auth::player_id(&mut database, &identifier, "").is_err());
+    assert!(auth::player_id(&mut database, &identifier, "unknown").is_err());
+}
+
+#[test]
+fn mismatched_player_claim() {
+    let mut database = FakeDatabase::default();
+    let token = fake_database::authenticate(&mut database, OTHER);
+    let identifier = Some(fake_database::to_player_identifier(PLAYER));
+    assert!(auth::player_id(&mut database, &identifier, &token).is_err());
+}
+
+#[test]
+fn tampered_signature() {
+    let mut database = FakeDatabase::default();
+    let token = fake_database::authenticate(&mut database, PLAYER);
+    let identifier = Some(fake_database::to_player_identifier(PLAYER));
+    let last = if token.ends_with('0') { '1' } else { '0' };
+    let tampered = format!("{}{}", &token[..token.len() - 1], last);
+    assert!(auth::player_id(&mut database, &identifier, &tampered).is_err());
+}
+
+#[test]
+fn player_secret_required_after_first_authentication() {
+    let mut database = FakeDatabase::default();
+    auth::initialize(&mut database).unwrap();
+    let identifier = Some(fake_database::to_player_identifier(PLAYER));
+    let first = auth::authenticate(&mut database, &request(identifier.clone(), "")).unwrap();
+    assert!(!first.player_secret.is_empty());
+
+    assert!(auth::authenticate(&mut database, &request(identifier.clone(), "")).is_err());
+    assert!(auth::authenticate(&mut database, &request(identifier.clone(), "wrong")).is_err());
+    let second =
+        auth::authenticate(&mut database, &request(identifier.clone(), &first.player_secret))
+            .unwrap();
+    assert!(second.player_secret.is_empty());
+    assert_eq!(PLAYER, auth::player_id(&mut database, &identifier, &second.session_token).unwrap());
+}
+
+#[test]
+fn session_token_expires() {
+    let mut database = FakeDatabase::default();
+    auth::initialize(&mut database).unwrap();
+    let identifier = Some(fake_database::to_player_identifier(PLAYER));
+    let token = auth::authenticate_at(&mut database, &request(identifier.clone(), ""), 1000)
+        .unwrap()
+        .session_token;
+    let expiry = 1000 + auth::TOKEN_LIFETIME;
+    assert!(auth::player_id_at(&mut database, &identifier, &token, expiry - 1).is_ok());
+    assert!(auth::player_id_at(&mut database, &identifier, &token, expiry).is_err());
+}
+
+#[test]
+fn initialize_keeps_existing_signing_key() {
+    let mut database = FakeDatabase::default();
+    let token = fake_database::authenticate(&mut database, PLAYER);
+    auth::initialize(&mut database).unwrap();
+    let identifier = Some(fake_database::to_player_identifier(PLAYER));
+    assert_eq!(PLAYER, auth::player_id(&mut database, &identifier, &token).unwrap());
+}
+
+#[test]
+fn signing_key_is_required() {
+    let mut database = FakeDatabase::default();
+    let token = fake_database::authenticate(&mut database, PLAYER);
+    let identifier = Some(fake_database::to_player_identifier(PLAYER));
+    database.signing_key = None;
+    assert!(auth::player_id(&mut database, &identifier, &token).is_err());
+}
+
+#[test]
+fn server_identifier_rejected() {
+    let mut database = FakeDatabase::default();
+    let identifier = Some(adapters::named_player_identifier(NamedPlayer::TestNoAction));
+    auth::initialize(&mut database).unwrap();
+    assert!(auth::authenticate(&mut database, &request(identifier.clone(), "")).is_err());
+
+    let error = quarantine::handle_request(
+        &mut database,
+        &GameRequest {
+            action: None,
+            player_id: identifier,
+            open_panels: vec![],
+            session_token: String::new(),
+            client_id: String::new(),
+        },
+    )
+    .unwrap_err();
+    assert!(error.to_string().contains("Server identifiers cannot be used by clients"));
+}
+
+fn request(player_id: Option<PlayerIdentifier>, player_secret: &str) -> AuthenticateRequest {
+    AuthenticateRequest { player_id, player_secret: player_secret.to_string() }
+}
@@ -0,0 +1,118 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::actions::InterfaceAction;
+use data::card_name::CardName;
+use data::player_data::AutomationPreferences;
+use data::primitives::{RoomId, Side};
+use data::user_actions::UserAction;
+use test_utils::client::TestSession;
+use test_utils::client_interface::HasText;
+use test_utils::*;
+
+fn set_automation(g: &mut TestSession, preferences: AutomationPreferences) {
+    g.perform(UserAction::SetAutomationPreferences(preferences).as_client_action(), g.user_id());
+}
+
+#[test]
+fn encounter_prompts_by_default() {
+    let mut g = new_game(Side::Champion, Args::default());
+    setup_raid_target(&mut g, CardName::TestMinionDealDamage);
+    g.initiate_raid(ROOM_ID);
+    assert!(g.user.interface.controls().has_text("Continue"));
+}
+
+#[test]
+fn auto_continue_encounters() {
+    let mut g = new_game(Side::Champion, Args::default());
+    set_automation(
+        &mut g,
+        AutomationPreferences {
+            auto_continue_encounters: true,
+            ..AutomationPreferences::default()
+        },
+    );
+    assert!(g.player_data(g.user_id()).automation.auto_continue_encounters);
+    setup_raid_target(&mut g, CardName::TestMinionDealDamage);
+    g.initiate_raid(ROOM_ID);
+    assert!(!g.user.interface.controls().has_text("Continue"));
+    assert!(g.user.interface.controls().has_text("Score"));
+}
+
+#[test]
+fn auto_continue_with_usable_weapon() {
+    let mut g = new_game(Side::Champion, Args::default());
+    set_automation(
+        &mut g,
+        AutomationPreferences {
+            auto_continue_encounters: true,
+            ..AutomationPreferences::default()
+        },
+    );
+    g.play_from_hand(CardName::TestWeapon3Attack12Boost3Cost);
+    setup_raid_target(&mut g, CardName::TestMinionDealDamage);
+    g.initiate_raid(ROOM_ID);
+    assert!(g.user.interface.controls().has_text("Continue"));
+}
+
+#[test]
+fn auto_end_raid_after_score() {
+    let mut g = new_game(Side::Champion, Args::default());
+    set_automation(
+        &mut g,
+        AutomationPreferences { auto_end_raid: true, ..AutomationPreferences::default() },
+    );
+    g.play_from_hand(CardName::TestWeapon3Attack12Boost3Cost);
+    setup_raid_target(&mut g, CardName::TestMinionEndRaid);
+    g.initiate_raid(ROOM_ID);
+    g.click_on(g.user_id(), "Test Weapon");
+    assert!(g.user.data.raid_active());
+    g.click_on(g.user_id(), "Score");
+    assert_eq!(g.user.this_player.score(), 1);
+    assert!(!g.user.data.raid_active());
+    assert!(!g.opponent.data.raid_active());
+}
+
+#[test]
+fn auto_end_raid_without_access_actions() {
+    let mut g = new_game(
+        Side::Champion,
+        Args { opponent_deck_top: Some(CardName::TestMinionEndRaid), ..Args::default() },
+    );
+    set_automation(
+        &mut g,
+        AutomationPreferences { auto_end_raid: true, ..AutomationPreferences::default() },
+    );
+    g.initiate_raid(RoomId::Vault);
+    assert!(!g.user.data.raid_active());
+}
+
+#[test]
+fn always_stop_at_access() {
+    let mut g = new_game(
+        Side::Champion,
+        Args { opponent_deck_top: Some(CardName::TestMinionEndRaid), ..Args::default() },
+    );
+    set_automation(
+        &mut g,
+        AutomationPreferences {
+            auto_end_raid: true,
+            always_stop_at_access: true,
+            ..AutomationPreferences::default()
+        },
+    );
+    g.initiate_raid(RoomId::Vault);
+    assert!(g.user.data.raid_active());
+    assert!(g.user.interface.controls().has_text("End Raid"));
+}
@@ -0,0 +1,121 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use card_helpers::{on_cast, simple_ability, text};
+use data::card_name::CardName;
+use data::primitives::Side;
+use rules::mutations;
+use test_utils::test_card_builder::TestCardBuilder;
+use test_utils::*;
+
+#[test]
+fn gain_actions_this_turn() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let spell = TestCardBuilder::champion_spell()
+        .ability(simple_ability(
+            text!["Gain 2 actions."],
+            on_cast(|g, s, _| {
+                mutations::gain_action_points(g, s.side(), 2);
+                Ok(())
+            }),
+        ))
+        .build();
+    g.play_from_hand(spell);
+    assert_eq!(4, g.me().actions());
+    assert_eq!(2, g.user.this_player.bonus_actions());
+    assert_eq!(2, g.opponent.other_player.bonus_actions());
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    assert!(g.dusk());
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    assert_eq!(3, g.me().actions());
+    assert_eq!(0, g.user.this_player.bonus_actions());
+}
+
+#[test]
+fn gain_actions_next_turn() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let spell = TestCardBuilder::champion_spell()
+        .ability(simple_ability(
+            text!["Next turn, gain an action."],
+            on_cast(|g, s, _| {
+                mutations::gain_action_points_next_turn(g, s.side(), 1);
+                Ok(())
+            }),
+        ))
+        .build();
+    g.play_from_hand(spell);
+    assert_eq!(2, g.me().actions());
+    assert_eq!(1, g.user.this_player.next_turn_actions());
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    assert_eq!(4, g.me().actions());
+    assert_eq!(1, g.user.this_player.bonus_actions());
+    assert_eq!(0, g.user.this_player.next_turn_actions());
+}
+
+#[test]
+fn opponent_turn_grant_applies_next_turn() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let spell = TestCardBuilder::overlord_spell()
+        .ability(simple_ability(
+            text!["The Champion gains an action."],
+            on_cast(|g, _, _| {
+                mutations::gain_action_points(g, Side::Champion, 1);
+                Ok(())
+            }),
+        ))
+        .build();
+    g.play_from_hand(spell);
+    assert_eq!(0, g.you().actions());
+    assert_eq!(1, g.opponent.this_player.next_turn_actions());
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    assert_eq!(4, g.you().actions());
+}
+
+#[test]
+fn carry_over_remaining_actions() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let spell = TestCardBuilder::champion_spell()
+        .ability(simple_ability(
+            text!["End your turn. Gain your remaining actions next turn."],
+            on_cast(|g, s, _| mutations::carry_over_action_points(g, s.side())),
+        ))
+        .build();
+    g.play_from_hand(spell);
+    assert_eq!(0, g.me().actions());
+    assert_eq!(2, g.user.this_player.next_turn_actions());
+    assert!(g.dusk());
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    assert_eq!(5, g.me().actions());
+    assert_eq!(2, g.user.this_player.bonus_actions());
+}
+
+#[test]
+fn bonus_actions_allow_playing_cards() {
+    let mut g = new_game(Side::Champion, Args { actions: 1, ..Args::default() });
+    let spell = TestCardBuilder::champion_spell()
+        .ability(simple_ability(
+            text!["Gain an action."],
+            on_cast(|g, s, _| {
+                mutations::gain_action_points(g, s.side(), 1);
+                Ok(())
+            }),
+        ))
+        .build();
+    g.play_from_hand(spell);
+    assert_eq!(1, g.me().actions());
+    g.play_from_hand(CardName::TestChampionSpell);
+    assert_eq!(0, g.me().actions());
+    assert!(g.dusk());
+}
@@ -0,0 +1,60 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+
+use actions::legal_actions;
+use cards::{decklists, initialize};
+use data::game_actions::GameAction;
+use data::player_data::PlayerSettings;
+use data::primitives::Side;
+use display::render;
+use insta::assert_snapshot;
+use server::requests;
+use test_utils::summarize::Summary;
+
+/// Replays a short scripted sequence of actions in the canonical game and
+/// compares the full command stream sent to both players against a golden
+/// file, as a regression test for the complete display pipeline.
+#[test]
+fn canonical_game_replay() {
+    initialize::run();
+    let game = decklists::canonical_game().expect("canonical_game");
+    let play_card = legal_actions::evaluate(&game, Side::Overlord)
+        .expect("legal_actions")
+        .find(|action| matches!(action, GameAction::PlayCard(..)))
+        .expect("PlayCard action");
+    let script = [
+        (Side::Overlord, play_card),
+        (Side::Overlord, GameAction::GainMana),
+        (Side::Overlord, GameAction::DrawCard),
+        (Side::Champion, GameAction::GainMana),
+        (Side::Champion, GameAction::DrawCard),
+    ];
+
+    let mut output = String::new();
+    let mut index = 0;
+    decklists::replay_canonical_game(&script, |game| {
+        let (side, action) = script[index];
+        index += 1;
+        for viewer in [Side::Overlord, Side::Champion] {
+            let commands = render::render_updates(game, viewer, &PlayerSettings::default())?;
+            let summary = Summary::summarize(&requests::command_list(commands));
+            let _err = write!(output, "\n=== {side:?} {action:?} (to {viewer:?}) ==={summary}\n");
+        }
+        Ok(())
+    })
+    .expect("replay_canonical_game");
+    assert_snapshot!(output);
+}
@@ -0,0 +1,29 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use protos::spelldawn::PlayerSide;
+use server::requests;
+
+#[test]
+fn fetch_card_catalog() {
+    cards::initialize::run();
+    let catalog = requests::fetch_card_catalog();
+    let golem = catalog.cards.iter().find(|c| c.name == "TimeGolem").expect("TimeGolem");
+    assert_eq!("Time Golem", golem.displayed_name);
+    assert_eq!("Minion", golem.card_type);
+    assert_eq!(PlayerSide::Overlord as i32, golem.side);
+    assert!(golem.rules_text.as_ref().unwrap().text.contains("Encounter"));
+    assert!(golem.image.is_some());
+    assert!(catalog.cards.iter().all(|c| !c.name.starts_with("Test")));
+}
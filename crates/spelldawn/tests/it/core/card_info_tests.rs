@@ -0,0 +1,77 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_name::CardName;
+use data::game::GameModifier;
+use data::primitives::Side;
+use protos::spelldawn::client_action::Action;
+use protos::spelldawn::{CardInfoCounter, CardInfoStat, LevelUpRoomAction};
+use test_utils::test_card_builder::TestCardBuilder;
+use test_utils::*;
+
+fn stat(label: &str, value: u32, base_value: u32) -> CardInfoStat {
+    CardInfoStat { label: label.to_string(), value, base_value }
+}
+
+#[test]
+fn minion_stats() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let id = g.play_from_hand(TestCardBuilder::minion().health(5).build());
+    let info = g.user.cards.get(id).info_zoom().expect("info_zoom");
+    assert!(info.stats.contains(&stat("Health", 5, 5)));
+    assert!(info.modifiers.is_empty());
+}
+
+#[test]
+fn modified_minion_stats() {
+    let mut g = new_game(
+        Side::Overlord,
+        Args { modifiers: vec![GameModifier::MinionHealth(2)], ..Args::default() },
+    );
+    let id = g.play_from_hand(TestCardBuilder::minion().health(5).build());
+    let info = g.user.cards.get(id).info_zoom().expect("info_zoom");
+    assert!(info.stats.contains(&stat("Health", 7, 5)));
+    assert_eq!(vec!["+2 Health".to_string()], info.modifiers);
+}
+
+#[test]
+fn scheme_level_counters() {
+    let mut g = new_game(Side::Overlord, Args { mana: 10, ..Args::default() });
+    let id = g.play_from_hand(CardName::TestScheme31);
+    g.perform(
+        Action::LevelUpRoom(LevelUpRoomAction { room_id: CLIENT_ROOM_ID.into() }),
+        g.user_id(),
+    );
+    let info = g.user.cards.get(id).info_zoom().expect("info_zoom");
+    assert!(info.stats.contains(&stat("Level Requirement", 3, 3)));
+    assert!(info.stats.contains(&stat("Points", 1, 1)));
+    assert_eq!(vec![CardInfoCounter { label: "Level".to_string(), value: 1 }], info.counters);
+}
+
+#[test]
+fn ability_text() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let id = g.play_from_hand(CardName::TestWeapon3Attack12Boost3Cost);
+    let info = g.user.cards.get(id).info_zoom().expect("info_zoom");
+    assert!(!info.ability_text.is_empty());
+    assert!(info.stats.contains(&stat("Attack", 3, 3)));
+}
+
+#[test]
+fn hidden_cards_have_no_info() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let id = g.play_from_hand(CardName::TestScheme31);
+    assert!(g.user.cards.get(id).info_zoom().is_some());
+    assert!(g.opponent.cards.get(id).info_zoom().is_none());
+}
@@ -0,0 +1,85 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_name::CardName;
+use data::primitives::Side;
+use protos::spelldawn::client_action::Action;
+use protos::spelldawn::{card_target, PlayerName, RoomIdentifier};
+use spelldawn_cli::client_state::ClientState;
+use spelldawn_cli::input::Input;
+use spelldawn_cli::{input, render};
+use test_utils::*;
+
+#[test]
+fn render_game() {
+    let mut g = new_game(Side::Overlord, Args { connect: false, ..Args::default() });
+    let mut state = ClientState::default();
+    state.apply(g.connect(g.user_id()).unwrap());
+    let output = render::render(&state);
+    assert!(output.contains("== You (Overlord) =="));
+    assert!(output.contains("== Opponent (Champion) =="));
+    assert!(output.contains("Actions: 3"));
+}
+
+#[test]
+fn draw_card() {
+    let mut g = new_game(
+        Side::Overlord,
+        Args { connect: false, deck_top: Some(CardName::TestMinionEndRaid), ..Args::default() },
+    );
+    let mut state = ClientState::default();
+    state.apply(g.connect(g.user_id()).unwrap());
+    let response = g.perform_action(parse_action(&state, "draw"), g.user_id()).unwrap();
+    state.apply(response.command_list);
+    assert_eq!(1, state.hand(PlayerName::User).len());
+    assert!(render::render(&state).contains(&CardName::TestMinionEndRaid.displayed_name()));
+}
+
+#[test]
+fn play_card_in_room() {
+    let mut g = new_game(Side::Overlord, Args { connect: false, ..Args::default() });
+    let card_id = g.add_to_hand(CardName::TestScheme31);
+    let mut state = ClientState::default();
+    state.apply(g.connect(g.user_id()).unwrap());
+    let Action::PlayCard(play) = parse_action(&state, "play 1 a") else {
+        panic!("Expected PlayCardAction");
+    };
+    assert_eq!(Some(card_id), play.card_id);
+    assert_eq!(
+        Some(card_target::CardTarget::RoomId(RoomIdentifier::RoomA.into())),
+        play.target.clone().and_then(|t| t.card_target)
+    );
+
+    let response = g.perform_action(Action::PlayCard(play), g.user_id()).unwrap();
+    state.apply(response.command_list);
+    assert!(render::render(&state)
+        .contains(&format!("Room A: {} (face down)", CardName::TestScheme31.displayed_name())));
+}
+
+#[test]
+fn invalid_input() {
+    let state = ClientState::default();
+    assert!(input::parse(&state, "play 1").is_err());
+    assert!(input::parse(&state, "raid attic").is_err());
+    assert!(input::parse(&state, "click 0").is_err());
+    assert!(input::parse(&state, "bogus").is_err());
+    assert!(matches!(input::parse(&state, "quit"), Ok(Input::Quit)));
+}
+
+fn parse_action(state: &ClientState, line: &str) -> Action {
+    match input::parse(state, line) {
+        Ok(Input::Action(action)) => action.action.expect("action"),
+        other => panic!("Expected action, got {:?}", other),
+    }
+}
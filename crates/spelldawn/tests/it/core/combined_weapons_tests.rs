@@ -0,0 +1,107 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use card_helpers::abilities;
+use cards::test_cards;
+use data::card_name::CardName;
+use data::game_actions::{EncounterAction, GameAction, PromptAction};
+use data::primitives::Side;
+use test_utils::client::TestSession;
+use test_utils::client_interface::HasText;
+use test_utils::test_card_builder::TestCardBuilder;
+use test_utils::*;
+
+/// Plays a minion with `health` and a scheme in [ROOM_ID], then plays the
+/// provided Champion weapons and raids the room.
+fn raid_with_weapons(health: u32, weapons: &[CardName]) -> TestSession {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let minion = TestCardBuilder::minion().health(health).combat_end_raid().build();
+    g.play_from_hand(minion);
+    g.play_from_hand(CardName::TestScheme31);
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    for weapon in weapons {
+        g.play_from_hand(*weapon);
+    }
+    g.initiate_raid(ROOM_ID);
+    g
+}
+
+/// Returns the label of the button to use two weapons together, checking that
+/// it names both `first` and `second`.
+fn combined_label(g: &TestSession, first: CardName, second: CardName) -> String {
+    let label = g
+        .opponent
+        .interface
+        .controls()
+        .get_text()
+        .into_iter()
+        .find(|text| text.contains(" + "))
+        .expect("Combined weapons button");
+    assert!(label.contains(&first.displayed_name()));
+    assert!(label.contains(&second.displayed_name()));
+    label
+}
+
+fn has_combined_action(g: &TestSession) -> bool {
+    g.legal_actions(Side::Champion).iter().any(|action| {
+        matches!(
+            action,
+            GameAction::PromptAction(PromptAction::EncounterAction(
+                EncounterAction::UseCombinedWeapons(..)
+            ))
+        )
+    })
+}
+
+#[test]
+fn combined_weapons_defeat_minion() {
+    let first = TestCardBuilder::weapon().base_attack(2).ability(abilities::dual_wield()).build();
+    let second = TestCardBuilder::weapon().base_attack(3).build();
+    let mut g = raid_with_weapons(5, &[first, second]);
+    let label = combined_label(&g, first, second);
+    g.click_on(g.opponent_id(), label);
+    assert_eq!(STARTING_MANA - 2 * test_cards::WEAPON_COST, g.opponent.this_player.mana());
+    assert!(g.opponent.interface.card_anchor_nodes().has_text("Score!"));
+}
+
+#[test]
+fn combined_weapons_split_boost_cost() {
+    let first = TestCardBuilder::weapon()
+        .base_attack(1)
+        .attack_boost(1, 1)
+        .ability(abilities::dual_wield())
+        .build();
+    let second = TestCardBuilder::weapon().base_attack(2).attack_boost(3, 1).build();
+    let mut g = raid_with_weapons(4, &[first, second]);
+    let label = combined_label(&g, first, second);
+    g.click_on(g.opponent_id(), label);
+    assert_eq!(STARTING_MANA - 2 * test_cards::WEAPON_COST - 1, g.opponent.this_player.mana());
+    assert!(g.opponent.interface.card_anchor_nodes().has_text("Score!"));
+}
+
+#[test]
+fn cannot_combine_without_dual_wield() {
+    let first = TestCardBuilder::weapon().base_attack(2).build();
+    let second = TestCardBuilder::weapon().base_attack(3).build();
+    let g = raid_with_weapons(5, &[first, second]);
+    assert!(!has_combined_action(&g));
+}
+
+#[test]
+fn combination_not_offered_when_single_weapon_is_cheaper() {
+    let first = TestCardBuilder::weapon().base_attack(5).ability(abilities::dual_wield()).build();
+    let second = TestCardBuilder::weapon().base_attack(1).build();
+    let g = raid_with_weapons(5, &[first, second]);
+    assert!(!has_combined_action(&g));
+}
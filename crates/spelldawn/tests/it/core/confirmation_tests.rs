@@ -0,0 +1,164 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::actions::InterfaceAction;
+use data::card_name::CardName;
+use data::cosmetics::DeckCosmetics;
+use data::deck::Deck;
+use data::player_data::PlayerData;
+use data::player_name::{NamedPlayer, PlayerId};
+use data::primitives::{DeckIndex, Side};
+use data::user_actions::{DebugAction, UserAction};
+use maplit::hashmap;
+use panel_address::{Confirmation, PanelAddress};
+use protos::spelldawn::client_action::Action;
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::toggle_panel_command::ToggleCommand;
+use protos::spelldawn::{InterfacePanelAddress, Node};
+use server::requests::GameResponse;
+use test_utils::client::TestSession;
+use test_utils::fake_database::FakeDatabase;
+use test_utils::*;
+
+#[test]
+fn resign_requires_confirmation() {
+    let g = new_game(Side::Overlord, Args::default());
+    let menu = render(&g, PanelAddress::GameMenu);
+    let Action::StandardAction(resign) = click(&menu, "Resign Button") else {
+        panic!("Expected StandardAction");
+    };
+    assert!(resign.payload.is_empty());
+    assert!(g.player_data(g.user_id()).match_history.is_empty());
+}
+
+#[test]
+fn confirm_concede() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let panel = render(&g, PanelAddress::Confirm(Confirmation::Concede));
+    g.perform(click(&panel, &String::from(element_names::CONFIRM_BUTTON)), g.user_id());
+    assert!(!g.player_data(g.user_id()).match_history[0].won);
+}
+
+#[test]
+fn cancel_sends_no_action() {
+    let g = new_game(Side::Overlord, Args::default());
+    let panel = render(&g, PanelAddress::Confirm(Confirmation::Concede));
+    let Action::StandardAction(cancel) = click(&panel, &String::from(element_names::CANCEL_BUTTON))
+    else {
+        panic!("Expected StandardAction");
+    };
+    assert!(cancel.payload.is_empty());
+}
+
+#[test]
+fn confirm_delete_deck() {
+    let mut g = session_with_decks(&["First", "Second"]);
+    let user_id = g.user_id();
+    let panel = render(&g, PanelAddress::Confirm(Confirmation::DeleteDeck(DeckIndex::new(0))));
+    g.perform(click(&panel, &String::from(element_names::CONFIRM_BUTTON)), user_id);
+    let decks = &g.player_data(user_id).decks;
+    assert_eq!(1, decks.len());
+    assert_eq!("Second", decks[0].name);
+    assert_eq!(DeckIndex::new(0), decks[0].index);
+}
+
+#[test]
+fn overwriting_save_state_requires_confirmation() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let first = g
+        .perform_action(DebugAction::SaveState(1).as_client_action(), g.user_id())
+        .expect("Request failed");
+    assert!(!opens_overwrite_confirmation(&first));
+
+    let second = g
+        .perform_action(DebugAction::SaveState(1).as_client_action(), g.user_id())
+        .expect("Request failed");
+    assert!(opens_overwrite_confirmation(&second));
+
+    let overwrite = g
+        .perform_action(
+            UserAction::Debug(DebugAction::OverwriteSaveState(1)).as_client_action(),
+            g.user_id(),
+        )
+        .expect("Request failed");
+    assert!(!opens_overwrite_confirmation(&overwrite));
+}
+
+fn opens_overwrite_confirmation(response: &GameResponse) -> bool {
+    let expected: InterfacePanelAddress =
+        PanelAddress::Confirm(Confirmation::OverwriteSaveState(1)).into();
+    response.command_list.commands.iter().any(|c| {
+        matches!(
+            &c.command,
+            Some(Command::TogglePanel(toggle))
+                if toggle.toggle_command == Some(ToggleCommand::OpenPanel(expected.clone()))
+        )
+    })
+}
+
+fn session_with_decks(names: &[&str]) -> TestSession {
+    let (game_id, user_id, _) = generate_ids();
+    let mut player = PlayerData::new(user_id);
+    player.decks = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| Deck {
+            index: DeckIndex::new(i),
+            name: name.to_string(),
+            owner_id: player.id,
+            side: Side::Champion,
+            identity: CardName::TestChampionIdentity,
+            cards: hashmap! {},
+            cosmetics: DeckCosmetics::default(),
+        })
+        .collect();
+    let database = FakeDatabase {
+        generated_game_id: Some(game_id),
+        game: None,
+        players: hashmap! { user_id => player },
+        signing_key: None,
+        player_secrets: hashmap! {},
+        leaderboards: hashmap! {},
+        matches: hashmap! {},
+        ratings: hashmap! {},
+        card_statistics: hashmap! {},
+        card_positions: hashmap! {},
+    };
+    TestSession::new(database, user_id, PlayerId::Named(NamedPlayer::TestNoAction))
+}
+
+fn render(g: &TestSession, address: PanelAddress) -> Node {
+    routing::render_panel(g.player_data(g.user_id()), address.into())
+        .expect("Render error")
+        .panels
+        .remove(0)
+        .node
+        .expect("Node")
+}
+
+fn find<'a>(node: &'a Node, name: &str) -> Option<&'a Node> {
+    if node.name == name {
+        Some(node)
+    } else {
+        node.children.iter().find_map(|child| find(child, name))
+    }
+}
+
+fn click(node: &Node, name: &str) -> Action {
+    find(node, name)
+        .and_then(|node| node.event_handlers.clone())
+        .and_then(|handlers| handlers.on_click)
+        .and_then(|action| action.action)
+        .expect("Button not found")
+}
@@ -0,0 +1,87 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::player_name::PlayerId;
+use data::primitives::Side;
+use protos::spelldawn::client_action::Action;
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::{CommandList, GainManaAction};
+use server::connections;
+use test_utils::*;
+use tokio::sync::mpsc;
+
+#[tokio::test]
+async fn fan_out_to_other_devices() {
+    let player_id = PlayerId::Database(43241);
+    let (phone, mut phone_rx) = mpsc::channel(4);
+    let (tablet, mut tablet_rx) = mpsc::channel(4);
+    connections::register(player_id, "phone", phone);
+    connections::register(player_id, "tablet", tablet);
+    assert_eq!(2, connections::count(player_id));
+
+    connections::send(player_id, CommandList::default(), Some("phone")).await;
+    assert!(tablet_rx.try_recv().is_ok());
+    assert!(phone_rx.try_recv().is_err());
+
+    connections::send(player_id, CommandList::default(), None).await;
+    assert!(tablet_rx.try_recv().is_ok());
+    assert!(phone_rx.try_recv().is_ok());
+}
+
+#[tokio::test]
+async fn reconnect_replaces_connection() {
+    let player_id = PlayerId::Database(43242);
+    let (first, mut first_rx) = mpsc::channel(4);
+    let (second, mut second_rx) = mpsc::channel(4);
+    connections::register(player_id, "phone", first);
+    connections::register(player_id, "phone", second);
+    assert_eq!(1, connections::count(player_id));
+
+    connections::send(player_id, CommandList::default(), None).await;
+    assert!(second_rx.try_recv().is_ok());
+    assert!(first_rx.try_recv().is_err());
+}
+
+#[test]
+fn connect_renders_game_panels() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let response = g.connect(g.user_id()).unwrap();
+    assert!(response.commands.iter().any(|c| matches!(c.command, Some(Command::UpdatePanels(_)))));
+}
+
+#[test]
+fn game_action_records_card_positions() {
+    let mut g = new_game(Side::Champion, Args { hand_size: 2, ..Args::default() });
+    g.perform(Action::GainMana(GainManaAction {}), g.user_id());
+    let positions = g.card_positions(g.user_id());
+    assert!(!positions.is_empty());
+    assert!(positions.keys().all(|id| g.user.cards.card_map.contains_key(id)));
+    assert!(!g.card_positions(g.opponent_id()).is_empty());
+}
+
+#[test]
+fn reconnect_animates_from_recorded_positions() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.perform(Action::GainMana(GainManaAction {}), g.user_id());
+    let response = g.connect(g.user_id()).unwrap();
+    let update = response
+        .commands
+        .iter()
+        .find_map(|c| match &c.command {
+            Some(Command::UpdateGameView(update)) => Some(update),
+            _ => None,
+        })
+        .expect("UpdateGameView");
+    assert!(update.animate);
+}
@@ -0,0 +1,107 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_name::CardName;
+use data::card_state::CardPosition;
+use data::delegates::DuskEvent;
+use data::game::GameState;
+use data::game_actions::CardTarget;
+use data::primitives::{AbilityId, CardId, Side};
+use rules::{dispatch, flags, mutations};
+use test_utils::*;
+
+#[test]
+fn owner_cannot_activate_stolen_project() {
+    cards::initialize::run();
+    let g = new_game(
+        Side::Overlord,
+        Args { in_play: vec![TestCard::new(CardName::Coinery)], ..Args::default() },
+    );
+    let mut game = g.game().clone();
+    let coinery = coinery_id(&game);
+    let ability_id = AbilityId::new(coinery, 1);
+    assert!(flags::can_take_activate_ability_action(
+        &game,
+        Side::Overlord,
+        ability_id,
+        CardTarget::None
+    ));
+
+    mutations::change_control(&mut game, coinery, Side::Champion).expect("change_control");
+    assert!(!flags::can_take_activate_ability_action(
+        &game,
+        Side::Overlord,
+        ability_id,
+        CardTarget::None
+    ));
+    assert!(!flags::can_unveil_project(&game, coinery));
+}
+
+#[test]
+fn stolen_card_moves_to_owner_discard_pile() {
+    cards::initialize::run();
+    let g = new_game(
+        Side::Champion,
+        Args { in_play: vec![TestCard::new(CardName::Coinery)], ..Args::default() },
+    );
+    let mut game = g.game().clone();
+    let coinery = coinery_id(&game);
+    mutations::change_control(&mut game, coinery, Side::Champion).expect("change_control");
+    mutations::destroy_cards(&mut game, vec![coinery]).expect("destroy_cards");
+
+    assert_eq!(CardPosition::DiscardPile(Side::Overlord), game.card(coinery).position());
+    assert_eq!(Side::Overlord, game.card(coinery).controller());
+}
+
+#[test]
+fn cannot_take_control_of_card_outside_play() {
+    cards::initialize::run();
+    let g = new_game(
+        Side::Champion,
+        Args { opponent_hand: vec![CardName::Coinery], ..Args::default() },
+    );
+    let mut game = g.game().clone();
+    let coinery = coinery_id(&game);
+    assert!(mutations::change_control(&mut game, coinery, Side::Champion).is_err());
+}
+
+#[test]
+fn stolen_gemcarver_benefits_controller() {
+    cards::initialize::run();
+    let g = new_game(
+        Side::Champion,
+        Args {
+            in_play: vec![TestCard::new(CardName::Gemcarver).face_up(true).stored_mana(3)],
+            ..Args::default()
+        },
+    );
+    let mut game = g.game().clone();
+    let gemcarver = game.all_cards().find(|c| c.name == CardName::Gemcarver).expect("gemcarver").id;
+    mutations::change_control(&mut game, gemcarver, Side::Champion).expect("change_control");
+    let champion_hand = game.hand(Side::Champion).count();
+    let overlord_hand = game.hand(Side::Overlord).count();
+    let champion_mana = game.champion.mana_state.base_mana;
+    let overlord_mana = game.overlord.mana_state.base_mana;
+
+    dispatch::invoke_event(&mut game, DuskEvent(2)).expect("dusk");
+
+    assert_eq!(champion_hand + 1, game.hand(Side::Champion).count());
+    assert_eq!(overlord_hand, game.hand(Side::Overlord).count());
+    assert_eq!(champion_mana + 3, game.champion.mana_state.base_mana);
+    assert_eq!(overlord_mana, game.overlord.mana_state.base_mana);
+}
+
+fn coinery_id(game: &GameState) -> CardId {
+    game.all_cards().find(|c| c.name == CardName::Coinery).expect("coinery").id
+}
@@ -0,0 +1,146 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use card_helpers::{face_up_in_play, on_cast, simple_ability, text};
+use data::card_name::CardName;
+use data::card_state::CardPosition;
+use data::delegates::{Delegate, QueryDelegate};
+use data::game::GameState;
+use data::primitives::{CardId, ItemLocation, Side};
+use rules::{constants, dispatch, mutations, queries};
+use test_utils::test_card_builder::TestCardBuilder;
+use test_utils::*;
+
+const ARTIFACTS: CardPosition = CardPosition::ArenaItem(ItemLocation::Artifacts);
+
+#[test]
+fn copy_enters_play_as_token() {
+    let artifact = hand_size_artifact();
+    let spell = TestCardBuilder::champion_spell()
+        .ability(simple_ability(
+            text!["Create a copy of an artifact"],
+            on_cast(|g, s, _| {
+                let source = g.artifacts().next().expect("artifact").id;
+                mutations::create_copy(g, source, s.side(), ARTIFACTS).map(|_| ())
+            }),
+        ))
+        .build();
+    let mut g = new_game(
+        Side::Champion,
+        Args { in_play: vec![TestCard::new(artifact)], ..Args::default() },
+    );
+    assert_eq!(1, g.user.cards.right_items().len());
+    g.play_from_hand(spell);
+    assert_eq!(2, g.user.cards.right_items().len());
+    assert_eq!(2, g.opponent.cards.right_items().len());
+
+    let copy = g.game().artifacts().find(|c| c.data.is_token).expect("token");
+    assert_eq!(artifact, copy.name);
+    assert!(copy.is_face_up());
+    assert_eq!(
+        constants::STARTING_MAXIMUM_HAND_SIZE + 2,
+        queries::maximum_hand_size(g.game(), Side::Champion)
+    );
+}
+
+#[test]
+fn copy_delegates_match_after_reload() {
+    let artifact = hand_size_artifact();
+    let g = new_game(
+        Side::Champion,
+        Args { in_play: vec![TestCard::new(artifact)], ..Args::default() },
+    );
+    let mut game = g.game().clone();
+    let copy = create_artifact_copy(&mut game);
+    assert_ne!(source_id(&game), copy);
+    assert_eq!(CardPosition::ArenaItem(ItemLocation::Artifacts), game.card(copy).position());
+    let hand_size = queries::maximum_hand_size(&game, Side::Champion);
+    assert_eq!(constants::STARTING_MAXIMUM_HAND_SIZE + 2, hand_size);
+
+    dispatch::populate_delegate_cache(&mut game);
+    assert_eq!(hand_size, queries::maximum_hand_size(&game, Side::Champion));
+}
+
+#[test]
+fn copy_banished_when_leaving_play() {
+    let artifact = hand_size_artifact();
+    let g = new_game(
+        Side::Champion,
+        Args { in_play: vec![TestCard::new(artifact)], ..Args::default() },
+    );
+    let mut game = g.game().clone();
+    let copy = create_artifact_copy(&mut game);
+    mutations::destroy_cards(&mut game, vec![copy]).expect("destroy_cards");
+
+    assert!(game.card(copy).position().is_banished());
+    assert_eq!(0, game.discard_pile(Side::Champion).count());
+    assert_eq!(
+        constants::STARTING_MAXIMUM_HAND_SIZE + 1,
+        queries::maximum_hand_size(&game, Side::Champion)
+    );
+    assert!(mutations::move_card(&mut game, copy, ARTIFACTS).is_err());
+
+    dispatch::populate_delegate_cache(&mut game);
+    assert_eq!(
+        constants::STARTING_MAXIMUM_HAND_SIZE + 1,
+        queries::maximum_hand_size(&game, Side::Champion)
+    );
+}
+
+#[test]
+fn destroying_source_keeps_copy_in_play() {
+    cards::initialize::run();
+    let g = new_game(
+        Side::Champion,
+        Args {
+            in_play: vec![TestCard::new(CardName::TestActivatedAbilityTakeMana)],
+            ..Args::default()
+        },
+    );
+    let mut game = g.game().clone();
+    let copy = create_artifact_copy(&mut game);
+    let source = source_id(&game);
+    mutations::destroy_cards(&mut game, vec![source]).expect("destroy_cards");
+
+    assert_eq!(CardPosition::DiscardPile(Side::Champion), game.card(source).position());
+    assert_eq!(ARTIFACTS, game.card(copy).position());
+}
+
+/// Artifact which increases its owner's maximum hand size by 1 while in play
+fn hand_size_artifact() -> CardName {
+    TestCardBuilder::artifact()
+        .ability(simple_ability(
+            text!["+1 maximum hand size"],
+            Delegate::MaximumHandSize(QueryDelegate {
+                requirement: face_up_in_play,
+                transformation: |_, s, side, current| {
+                    if *side == s.side() {
+                        current + 1
+                    } else {
+                        current
+                    }
+                },
+            }),
+        ))
+        .build()
+}
+
+fn source_id(game: &GameState) -> CardId {
+    game.artifacts().find(|c| !c.data.is_token).expect("source").id
+}
+
+fn create_artifact_copy(game: &mut GameState) -> CardId {
+    let source = source_id(game);
+    mutations::create_copy(game, source, Side::Champion, ARTIFACTS).expect("create_copy")
+}
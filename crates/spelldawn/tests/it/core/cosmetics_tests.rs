@@ -0,0 +1,130 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use cards::initialize;
+use core_ui::actions::InterfaceAction;
+use data::adventure::Coins;
+use data::campaign::CampaignProgress;
+use data::cosmetics::{BoardSkin, CardBack, PlayerCosmetics};
+use data::crafting::Dust;
+use data::player_data::{AutomationPreferences, PlayerData, PlayerSettings};
+use data::player_name::{NamedPlayer, PlayerId};
+use data::puzzles::PuzzleId;
+use data::quests::QuestLog;
+use data::tutorial::TutorialData;
+use data::user_actions::UserAction;
+use maplit::hashmap;
+use test_utils::client::TestSession;
+use test_utils::fake_database::FakeDatabase;
+use test_utils::*;
+
+#[test]
+fn select_card_back() {
+    let mut session = new_session(PlayerCosmetics::default());
+    let user_id = session.user_id();
+    session.perform(UserAction::SetCardBack(Some(CardBack::Elf)).as_client_action(), user_id);
+    assert_eq!(
+        Some(CardBack::Elf),
+        session.player_data(user_id).player_cosmetics.equipped.card_back
+    );
+
+    session.perform(UserAction::SetCardBack(None).as_client_action(), user_id);
+    assert_eq!(None, session.player_data(user_id).player_cosmetics.equipped.card_back);
+}
+
+#[test]
+fn cannot_select_locked_cosmetics() {
+    let mut session = new_session(PlayerCosmetics::default());
+    let user_id = session.user_id();
+    assert!(session
+        .perform_action(UserAction::SetCardBack(Some(CardBack::Gilded)).as_client_action(), user_id)
+        .is_err());
+    assert!(session
+        .perform_action(
+            UserAction::SetBoardSkin(Some(BoardSkin::SnowMountain)).as_client_action(),
+            user_id
+        )
+        .is_err());
+}
+
+#[test]
+fn default_card_back_matches_identity_school() {
+    let mut session = new_session(PlayerCosmetics::default());
+    let user_id = session.user_id();
+    session.perform(UserAction::StartPuzzle(PuzzleId::FinalScheme).as_client_action(), user_id);
+    assert!(session.user.this_player.card_back().contains("Back_Steampunk_Style_Color_1"));
+    assert_eq!(None, session.user.this_player.board_skin());
+}
+
+#[test]
+fn equipped_cosmetics_displayed_in_game() {
+    let mut session = new_session(PlayerCosmetics {
+        card_backs: HashSet::from([CardBack::Gilded]),
+        board_skins: HashSet::from([BoardSkin::SnowMountain]),
+        ..PlayerCosmetics::default()
+    });
+    let user_id = session.user_id();
+    session.perform(UserAction::SetCardBack(Some(CardBack::Gilded)).as_client_action(), user_id);
+    session.perform(
+        UserAction::SetBoardSkin(Some(BoardSkin::SnowMountain)).as_client_action(),
+        user_id,
+    );
+    session.perform(UserAction::StartPuzzle(PuzzleId::FinalScheme).as_client_action(), user_id);
+
+    assert!(session.user.this_player.card_back().contains("Back_Steampunk_Style_Color_4"));
+    assert!(session.user.this_player.board_skin().unwrap().contains("ScenerySnowMountain"));
+}
+
+fn new_session(player_cosmetics: PlayerCosmetics) -> TestSession {
+    initialize::run();
+    let (game_id, user_id, _) = generate_ids();
+    let database = FakeDatabase {
+        generated_game_id: Some(game_id),
+        game: None,
+        players: hashmap! {
+            user_id => PlayerData {
+                id: user_id,
+                state: None,
+                decks: vec![],
+                adventure: None,
+                collection: hashmap! {},
+                tutorial: TutorialData::default(),
+                cosmetics: HashSet::new(),
+                achievements: HashSet::new(),
+                settings: PlayerSettings::default(),
+                match_history: vec![],
+                completed_puzzles: HashSet::new(),
+                quests: QuestLog::default(),
+                coins: Coins::default(),
+                player_cosmetics,
+                revision: 0,
+                notifications: vec![],
+                campaign: CampaignProgress::default(),
+                current_match: None,
+                dust: Dust::default(),
+                automation: AutomationPreferences::default(),
+            }
+        },
+        signing_key: None,
+        player_secrets: hashmap! {},
+        leaderboards: hashmap! {},
+        matches: hashmap! {},
+        ratings: hashmap! {},
+        card_statistics: hashmap! {},
+        card_positions: hashmap! {},
+    };
+    TestSession::new(database, user_id, PlayerId::Named(NamedPlayer::TestNoAction))
+}
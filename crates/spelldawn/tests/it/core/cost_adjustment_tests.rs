@@ -0,0 +1,122 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use card_helpers::{simple_ability, text};
+use cards::test_cards::{ARTIFACT_COST, WEAPON_COST};
+use data::card_name::CardName;
+use data::primitives::{CardType, Side};
+use rules::helpers;
+use test_utils::test_card_builder::TestCardBuilder;
+use test_utils::*;
+
+/// Artifact which makes the Champion's weapons cost 1 less.
+fn weapon_discount() -> CardName {
+    TestCardBuilder::artifact()
+        .ability(simple_ability(
+            text!["Your weapons cost 1 less."],
+            helpers::reduce_cost::<1>(|g, s, card_id| {
+                helpers::own_cards_while_in_play(g, s, card_id)
+                    && helpers::is_type(g, *card_id, CardType::Weapon)
+            }),
+        ))
+        .build()
+}
+
+/// Artifact which makes the Champion's spells cost 2 less.
+fn spell_discount() -> CardName {
+    TestCardBuilder::artifact()
+        .ability(simple_ability(
+            text!["Your spells cost 2 less."],
+            helpers::reduce_cost::<2>(|g, s, card_id| {
+                helpers::own_cards_while_in_play(g, s, card_id)
+                    && helpers::is_type(g, *card_id, CardType::ChampionSpell)
+            }),
+        ))
+        .build()
+}
+
+/// Artifact which makes the Champion's spells cost 1 more.
+fn spell_tax() -> CardName {
+    TestCardBuilder::artifact()
+        .ability(simple_ability(
+            text!["Your spells cost 1 more."],
+            helpers::increase_cost::<1>(|g, s, card_id| {
+                helpers::own_cards_while_in_play(g, s, card_id)
+                    && helpers::is_type(g, *card_id, CardType::ChampionSpell)
+            }),
+        ))
+        .build()
+}
+
+#[test]
+fn reduction_applies_while_in_play() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let discount = weapon_discount();
+    let weapon = g.add_to_hand(CardName::TestWeapon3Attack12Boost3Cost);
+    g.add_to_hand(discount);
+    assert_eq!(WEAPON_COST.to_string(), g.user.get_card(weapon).top_left_icon());
+    g.play_from_hand(discount);
+    assert_eq!((WEAPON_COST - 1).to_string(), g.user.get_card(weapon).top_left_icon());
+}
+
+#[test]
+fn reductions_stack() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.play_from_hand(weapon_discount());
+    g.play_from_hand(weapon_discount());
+    g.play_from_hand(CardName::TestWeapon3Attack12Boost3Cost);
+    assert_eq!(STARTING_MANA - 2 * ARTIFACT_COST - (WEAPON_COST - 2), g.me().mana());
+}
+
+#[test]
+fn cost_cannot_be_reduced_below_zero() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.play_from_hand(spell_discount());
+    g.play_from_hand(CardName::Test1CostChampionSpell);
+    assert_eq!(STARTING_MANA - ARTIFACT_COST, g.me().mana());
+}
+
+#[test]
+fn reduction_does_not_apply_to_other_card_types() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.play_from_hand(weapon_discount());
+    g.play_from_hand(CardName::Test1CostChampionSpell);
+    assert_eq!(STARTING_MANA - ARTIFACT_COST - 1, g.me().mana());
+}
+
+#[test]
+fn adjustments_summed_before_floor_reduction_first() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.play_from_hand(spell_discount());
+    g.play_from_hand(spell_tax());
+    g.play_from_hand(CardName::Test1CostChampionSpell);
+    assert_eq!(STARTING_MANA - 2 * ARTIFACT_COST, g.me().mana());
+}
+
+#[test]
+fn adjustments_summed_before_floor_increase_first() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.play_from_hand(spell_tax());
+    g.play_from_hand(spell_discount());
+    g.play_from_hand(CardName::Test1CostChampionSpell);
+    assert_eq!(STARTING_MANA - 2 * ARTIFACT_COST, g.me().mana());
+}
+
+#[test]
+fn adjust_cost_floors_at_zero() {
+    assert_eq!(3, helpers::adjust_cost(3, 0));
+    assert_eq!(1, helpers::adjust_cost(3, -2));
+    assert_eq!(0, helpers::adjust_cost(1, -2));
+    assert_eq!(4, helpers::adjust_cost(3, 1));
+}
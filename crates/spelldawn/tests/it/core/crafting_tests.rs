@@ -0,0 +1,155 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, HashSet};
+
+use cards::{decklists, initialize};
+use core_ui::actions::InterfaceAction;
+use data::adventure::Coins;
+use data::campaign::CampaignProgress;
+use data::card_name::CardName;
+use data::cosmetics::PlayerCosmetics;
+use data::crafting::Dust;
+use data::player_data::{AutomationPreferences, PlayerData, PlayerSettings};
+use data::player_name::{NamedPlayer, PlayerId};
+use data::primitives::Side;
+use data::quests::QuestLog;
+use data::tutorial::TutorialData;
+use data::user_actions::UserAction;
+use maplit::hashmap;
+use panel_address::PanelAddress;
+use test_utils::client::TestSession;
+use test_utils::client_interface::HasText;
+use test_utils::fake_database::FakeDatabase;
+use test_utils::*;
+
+#[test]
+fn craft_card() {
+    let mut session = new_session(hashmap! {}, Dust(50));
+    perform(&mut session, UserAction::CraftCard(CardName::ArcaneRecovery));
+    let player = session.player_data(session.user_id());
+    assert_eq!(1, player.owned_copies(CardName::ArcaneRecovery));
+    assert_eq!(Dust(10), player.dust);
+}
+
+#[test]
+fn craft_requires_dust() {
+    let mut session = new_session(hashmap! {}, Dust(39));
+    assert!(fails(&mut session, UserAction::CraftCard(CardName::ArcaneRecovery)));
+}
+
+#[test]
+fn craft_limited_to_max_copies() {
+    let mut session = new_session(hashmap! { CardName::ArcaneRecovery => 3 }, Dust(1000));
+    assert!(fails(&mut session, UserAction::CraftCard(CardName::ArcaneRecovery)));
+}
+
+#[test]
+fn cannot_craft_test_cards() {
+    let mut session = new_session(hashmap! {}, Dust(1000));
+    assert!(fails(&mut session, UserAction::CraftCard(CardName::TestChampionSpell)));
+}
+
+#[test]
+fn disenchant_card() {
+    let mut session = new_session(hashmap! { CardName::GoldMine => 2 }, Dust(0));
+    perform(&mut session, UserAction::DisenchantCard(CardName::GoldMine));
+    let player = session.player_data(session.user_id());
+    assert_eq!(1, player.owned_copies(CardName::GoldMine));
+    assert_eq!(Dust(5), player.dust);
+}
+
+#[test]
+fn disenchant_last_copy_removes_card() {
+    let mut session = new_session(hashmap! { CardName::GoldMine => 1 }, Dust(0));
+    perform(&mut session, UserAction::DisenchantCard(CardName::GoldMine));
+    assert!(!session.player_data(session.user_id()).collection.contains_key(&CardName::GoldMine));
+}
+
+#[test]
+fn cannot_disenchant_unowned_card() {
+    let mut session = new_session(hashmap! {}, Dust(0));
+    assert!(fails(&mut session, UserAction::DisenchantCard(CardName::GoldMine)));
+}
+
+#[test]
+fn cannot_disenchant_copies_used_in_decks() {
+    // The canonical champion deck includes two copies of Meditation
+    let mut session = new_session(hashmap! { CardName::Meditation => 2 }, Dust(0));
+    assert!(fails(&mut session, UserAction::DisenchantCard(CardName::Meditation)));
+
+    let mut session = new_session(hashmap! { CardName::Meditation => 3 }, Dust(0));
+    perform(&mut session, UserAction::DisenchantCard(CardName::Meditation));
+    assert!(fails(&mut session, UserAction::DisenchantCard(CardName::Meditation)));
+}
+
+#[test]
+fn crafting_panel() {
+    let session = new_session(hashmap! { CardName::ArcaneRecovery => 2 }, Dust(45));
+    let panel = routing::render_panel(
+        session.player_data(session.user_id()),
+        PanelAddress::Crafting(0).into(),
+    )
+    .expect("Render error")
+    .panels
+    .remove(0)
+    .node
+    .expect("Node");
+    assert!(panel.has_text("Dust: 45"));
+    assert!(panel.has_text("Arcane Recovery (Common) x2"));
+    assert!(panel.has_text("Craft (40)"));
+}
+
+fn perform(session: &mut TestSession, action: UserAction) {
+    session.perform(action.as_client_action(), session.user_id());
+}
+
+fn fails(session: &mut TestSession, action: UserAction) -> bool {
+    session.perform_action(action.as_client_action(), session.user_id()).is_err()
+}
+
+/// Creates a session for a player who owns the canonical champion deck along
+/// with the provided collection and dust.
+fn new_session(collection: HashMap<CardName, u32>, dust: Dust) -> TestSession {
+    initialize::run();
+    let (_, user_id, _) = generate_ids();
+    let database = FakeDatabase {
+        players: hashmap! {
+            user_id => PlayerData {
+                id: user_id,
+                state: None,
+                decks: vec![decklists::canonical_deck(user_id, Side::Champion)],
+                adventure: None,
+                collection,
+                tutorial: TutorialData::default(),
+                cosmetics: HashSet::new(),
+                achievements: HashSet::new(),
+                settings: PlayerSettings::default(),
+                match_history: vec![],
+                completed_puzzles: HashSet::new(),
+                quests: QuestLog::default(),
+                coins: Coins::default(),
+                player_cosmetics: PlayerCosmetics::default(),
+                revision: 0,
+                notifications: vec![],
+                campaign: CampaignProgress::default(),
+                current_match: None,
+                dust,
+                automation: AutomationPreferences::default(),
+            }
+        },
+        ..FakeDatabase::default()
+    };
+    TestSession::new(database, user_id, PlayerId::Named(NamedPlayer::TestNoAction))
+}
@@ -12,20 +12,28 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+
 use cards::initialize;
 use core_ui::actions::InterfaceAction;
+use data::adventure::Coins;
+use data::campaign::CampaignProgress;
 use data::card_name::CardName;
+use data::cosmetics::{DeckCosmetics, PlayerCosmetics};
+use data::crafting::Dust;
 use data::deck::Deck;
-use data::game::MulliganDecision;
+use data::game::{GamePhase, GameRules, MulliganDecision};
 use data::game_actions::{GameAction, PromptAction};
-use data::player_data::PlayerData;
+use data::player_data::{AutomationPreferences, PlayerData, PlayerSettings, PlayerState};
 use data::player_name::PlayerId;
 use data::primitives::{DeckIndex, GameId, Side};
+use data::quests::QuestLog;
 use data::tutorial::TutorialData;
-use data::user_actions::{NewGameAction, NewGameDebugOptions, UserAction};
+use data::user_actions::{GamePreset, NewGameAction, NewGameDebugOptions, UserAction};
 use insta::assert_snapshot;
 use maplit::hashmap;
 use protos::spelldawn::PlayerName;
+use rules::mutations;
 use test_utils::client::TestSession;
 use test_utils::client_interface::HasText;
 use test_utils::fake_database::FakeDatabase;
@@ -47,6 +55,7 @@ fn create_new_game() {
                 deterministic: true,
                 ..NewGameDebugOptions::default()
             }),
+            preset: GamePreset::default(),
         })
         .as_client_action(),
         session.user_id(),
@@ -160,6 +169,59 @@ fn both_keep_opening_hands() {
     assert!(session.dusk());
 }
 
+#[test]
+fn custom_game_preset() {
+    let (game_id, overlord_id, champion_id) = generate_ids();
+    let mut session = make_overlord_test_session(game_id, overlord_id, champion_id);
+    let preset = GamePreset {
+        rules: GameRules { starting_mana: 8, starting_hand_size: 3, points_to_win: 4 },
+        deterministic: true,
+        reveal_on_game_over: true,
+        ..GamePreset::default()
+    };
+    initiate_game_with_presets(&mut session, preset, preset);
+
+    assert_eq!(3, session.user.cards.revealed_cards().len());
+    assert_eq!(3, session.user.cards.hand(PlayerName::Opponent).len());
+    assert_eq!(preset.rules, session.game().data.config.rules);
+    assert!(session.game().data.config.reveal_on_game_over);
+
+    session.click_on(overlord_id, "Mulligan");
+    assert_eq!(3, session.user.cards.hand(PlayerName::User).len());
+
+    session.click_on(champion_id, "Keep");
+    assert_eq!(8, session.user.this_player.mana());
+    assert_eq!(8, session.user.other_player.mana());
+}
+
+#[test]
+fn mismatched_presets_wait_in_lobby() {
+    let (game_id, overlord_id, champion_id) = generate_ids();
+    let mut session = make_overlord_test_session(game_id, overlord_id, champion_id);
+    let preset = GamePreset {
+        rules: GameRules { starting_mana: 8, ..GameRules::default() },
+        ..GamePreset::default()
+    };
+    initiate_game_with_presets(&mut session, GamePreset::default(), preset);
+
+    assert!(matches!(
+        &session.player_data(overlord_id).state,
+        Some(PlayerState::RequestedGame(request)) if request.preset == preset
+    ));
+    assert!(session.user.interface.top_panel().has_text("Waiting for opponent"));
+}
+
+#[test]
+fn reaching_custom_points_to_win_ends_game() {
+    let g = new_game(Side::Overlord, Args::default());
+    let mut game = g.game().clone();
+    game.data.config.rules.points_to_win = 3;
+    mutations::score_points(&mut game, Side::Overlord, 2).unwrap();
+    assert!(matches!(game.data.phase, GamePhase::Play));
+    mutations::score_points(&mut game, Side::Overlord, 1).unwrap();
+    assert!(matches!(game.data.phase, GamePhase::GameOver { winner: Side::Overlord }));
+}
+
 /// Creates a [TestSession] for the Overlord player. Both players have their
 /// decks populated, but neither has submitted a 'new game' request.
 fn make_overlord_test_session(
@@ -175,6 +237,7 @@ fn make_overlord_test_session(
         side: Side::Overlord,
         identity: CardName::TestOverlordIdentity,
         cards: hashmap! {CardName::TestOverlordSpell => 45},
+        cosmetics: DeckCosmetics::default(),
     };
     let champion_deck = Deck {
         index: DeckIndex::new(0),
@@ -183,6 +246,7 @@ fn make_overlord_test_session(
         side: Side::Champion,
         identity: CardName::TestChampionIdentity,
         cards: hashmap! {CardName::TestChampionSpell => 45},
+        cosmetics: DeckCosmetics::default(),
     };
 
     let database = FakeDatabase {
@@ -195,7 +259,21 @@ fn make_overlord_test_session(
                 decks: vec![overlord_deck.clone(), champion_deck.clone()],
                 adventure: None,
                 collection: hashmap! {},
-                tutorial: TutorialData::default()
+                tutorial: TutorialData::default(),
+                cosmetics: HashSet::new(),
+                achievements: HashSet::new(),
+                settings: PlayerSettings::default(),
+                match_history: vec![],
+                completed_puzzles: HashSet::new(),
+                quests: QuestLog::default(),
+                coins: Coins::default(),
+                player_cosmetics: PlayerCosmetics::default(),
+                revision: 0,
+                notifications: vec![],
+                campaign: CampaignProgress::default(),
+                current_match: None,
+                dust: Dust::default(),
+                automation: AutomationPreferences::default()
             },
             champion_id => PlayerData {
                 id: champion_id,
@@ -203,15 +281,46 @@ fn make_overlord_test_session(
                 decks: vec![overlord_deck, champion_deck],
                 adventure: None,
                 collection: hashmap! {},
-                tutorial: TutorialData::default()
+                tutorial: TutorialData::default(),
+                cosmetics: HashSet::new(),
+                achievements: HashSet::new(),
+                settings: PlayerSettings::default(),
+                match_history: vec![],
+                completed_puzzles: HashSet::new(),
+                quests: QuestLog::default(),
+                coins: Coins::default(),
+                player_cosmetics: PlayerCosmetics::default(),
+                revision: 0,
+                notifications: vec![],
+                campaign: CampaignProgress::default(),
+                current_match: None,
+                dust: Dust::default(),
+                automation: AutomationPreferences::default()
             }
         },
+        signing_key: None,
+        player_secrets: hashmap! {},
+        leaderboards: hashmap! {},
+        matches: hashmap! {},
+        ratings: hashmap! {},
+        card_statistics: hashmap! {},
+        card_positions: hashmap! {},
     };
 
     TestSession::new(database, overlord_id, champion_id)
 }
 
 fn initiate_game(session: &mut TestSession) {
+    initiate_game_with_presets(session, GamePreset::default(), GamePreset::default());
+}
+
+/// Submits 'new game' requests for the Champion and then the Overlord player,
+/// each accepting the provided [GamePreset], and connects both players.
+fn initiate_game_with_presets(
+    session: &mut TestSession,
+    champion_preset: GamePreset,
+    overlord_preset: GamePreset,
+) {
     session.perform(
         UserAction::NewGame(NewGameAction {
             deck_index: CHAMPION_DECK,
@@ -220,6 +329,7 @@ fn initiate_game(session: &mut TestSession) {
                 deterministic: true,
                 ..NewGameDebugOptions::default()
             }),
+            preset: champion_preset,
         })
         .as_client_action(),
         session.opponent_id(),
@@ -233,6 +343,7 @@ fn initiate_game(session: &mut TestSession) {
                     deterministic: true,
                     ..NewGameDebugOptions::default()
                 }),
+                preset: overlord_preset,
             })
             .as_client_action(),
             session.user_id(),
@@ -0,0 +1,183 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use cards::initialize;
+use data::card_name::CardName;
+use data::cosmetics::DeckCosmetics;
+use data::deck::Deck;
+use data::player_data::PlayerData;
+use data::player_name::{NamedPlayer, PlayerId};
+use data::primitives::{DeckIndex, Side};
+use maplit::hashmap;
+use panel_address::{OldDeckEditorData, PanelAddress};
+use protos::spelldawn::client_action::Action;
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::interface_update::Update;
+use protos::spelldawn::node_type::NodeType;
+use protos::spelldawn::{DraggableNode, FlexDisplayStyle, Node};
+use test_utils::client::TestSession;
+use test_utils::fake_database::FakeDatabase;
+use test_utils::*;
+
+const IN_DECK: CardName = CardName::TestChampionSpell;
+const NOT_IN_DECK: CardName = CardName::TestWeapon3Attack12Boost3Cost;
+
+#[test]
+fn drag_to_add_existing_card() {
+    let mut g = new_session();
+    let action = on_drop(&g, element_names::deck_card(IN_DECK));
+    g.perform(action, g.user_id());
+    assert_eq!(3, deck_count(&g, IN_DECK));
+}
+
+#[test]
+fn drag_to_add_new_card() {
+    let mut g = new_session();
+    let action = on_drop(&g, element_names::deck_card(NOT_IN_DECK));
+    g.perform(action, g.user_id());
+    assert_eq!(1, deck_count(&g, NOT_IN_DECK));
+}
+
+#[test]
+fn drag_to_remove_card() {
+    let mut g = new_session();
+    let action = on_drop(&g, element_names::card_list_card_name(IN_DECK));
+    g.perform(action, g.user_id());
+    assert_eq!(1, deck_count(&g, IN_DECK));
+}
+
+#[test]
+fn drop_targets() {
+    let g = new_session();
+    let panel = render(&g);
+    assert_eq!(
+        vec![String::from(element_names::CARD_LIST)],
+        draggable(&panel, element_names::deck_card(IN_DECK)).drop_target_identifiers
+    );
+    assert_eq!(
+        vec![String::from(element_names::COLLECTION_BROWSER)],
+        draggable(&panel, element_names::card_list_card_name(IN_DECK)).drop_target_identifiers
+    );
+}
+
+#[test]
+fn renders_adjacent_count_badges() {
+    let g = new_session();
+    let panel = render(&g);
+    let display = |count| {
+        find(&panel, &String::from(element_names::card_count(IN_DECK, count)))
+            .and_then(|node| node.style.as_ref())
+            .map(|style| style.display())
+    };
+    assert_eq!(Some(FlexDisplayStyle::None), display(1));
+    assert_eq!(Some(FlexDisplayStyle::Flex), display(2));
+    assert_eq!(Some(FlexDisplayStyle::None), display(3));
+}
+
+#[test]
+fn drag_to_add_updates_count_optimistically() {
+    let g = new_session();
+    let Action::StandardAction(action) = on_drop(&g, element_names::deck_card(IN_DECK)) else {
+        panic!("Expected StandardAction");
+    };
+    let styled = action
+        .update
+        .expect("Update")
+        .commands
+        .into_iter()
+        .filter_map(|command| match command.command {
+            Some(Command::UpdateInterface(update)) => Some(update.steps),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|step| match step.update.and_then(|u| u.update) {
+            Some(Update::ApplyStyle(style)) => {
+                Some((format!("{:?}", step.element), style.display()))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(2, styled.len());
+    assert!(styled[0].0.contains(&String::from(element_names::card_count(IN_DECK, 2))));
+    assert_eq!(FlexDisplayStyle::None, styled[0].1);
+    assert!(styled[1].0.contains(&String::from(element_names::card_count(IN_DECK, 3))));
+    assert_eq!(FlexDisplayStyle::Flex, styled[1].1);
+}
+
+fn new_session() -> TestSession {
+    initialize::run();
+    let (game_id, user_id, _) = generate_ids();
+    let mut player = PlayerData::new(user_id);
+    player.collection = hashmap! { IN_DECK => 3, NOT_IN_DECK => 1 };
+    player.decks = vec![Deck {
+        index: DeckIndex::new(0),
+        name: "Deck".to_string(),
+        owner_id: user_id,
+        side: Side::Champion,
+        identity: CardName::TestChampionIdentity,
+        cards: hashmap! { IN_DECK => 2 },
+        cosmetics: DeckCosmetics::default(),
+    }];
+    let database = FakeDatabase {
+        generated_game_id: Some(game_id),
+        game: None,
+        players: hashmap! { user_id => player },
+        signing_key: None,
+        player_secrets: hashmap! {},
+        leaderboards: hashmap! {},
+        matches: hashmap! {},
+        ratings: hashmap! {},
+        card_statistics: hashmap! {},
+        card_positions: hashmap! {},
+    };
+    TestSession::new(database, user_id, PlayerId::Named(NamedPlayer::TestNoAction))
+}
+
+fn render(g: &TestSession) -> Node {
+    let address = PanelAddress::OldDeckEditor(OldDeckEditorData {
+        deck: Some(DeckIndex::new(0)),
+        ..OldDeckEditorData::default()
+    });
+    routing::render_panel(g.player_data(g.user_id()), address.into())
+        .expect("Render error")
+        .panels
+        .remove(0)
+        .node
+        .expect("Node")
+}
+
+fn deck_count(g: &TestSession, card_name: CardName) -> u32 {
+    g.player_data(g.user_id()).decks[0].cards.get(&card_name).copied().unwrap_or_default()
+}
+
+fn find<'a>(node: &'a Node, name: &str) -> Option<&'a Node> {
+    if node.name == name {
+        Some(node)
+    } else {
+        node.children.iter().find_map(|child| find(child, name))
+    }
+}
+
+fn draggable(node: &Node, name: element_names::ElementName) -> DraggableNode {
+    let node = find(node, &String::from(name)).expect("Draggable not found");
+    match node.node_type.as_ref().and_then(|t| t.node_type.clone()) {
+        Some(NodeType::DraggableNode(draggable)) => *draggable,
+        _ => panic!("Expected DraggableNode"),
+    }
+}
+
+fn on_drop(g: &TestSession, name: element_names::ElementName) -> Action {
+    draggable(&render(g), name).on_drop.expect("on_drop").action.expect("Action")
+}
@@ -0,0 +1,46 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use cards::decklists;
+use data::game::{GameConfiguration, GameState};
+use data::primitives::GameId;
+use rules::dispatch;
+
+#[test]
+fn index_matches_card_names() {
+    cards::initialize::run();
+    let game = decklists::canonical_game().unwrap();
+    for card_id in game.all_card_ids() {
+        let definition = game.definition(card_id).expect("definition");
+        assert_eq!(game.card(card_id).name, definition.name);
+        assert!(std::ptr::eq(rules::get(definition.name), definition));
+    }
+}
+
+#[test]
+fn unpopulated_index_falls_back_to_name_lookup() {
+    cards::initialize::run();
+    let mut game = GameState::new(
+        GameId::new(0),
+        decklists::CANONICAL_OVERLORD.clone(),
+        decklists::CANONICAL_CHAMPION.clone(),
+        GameConfiguration::default(),
+    );
+    let card_id = game.all_card_ids().last().unwrap();
+    assert!(game.definition(card_id).is_none());
+    assert_eq!(game.card(card_id).name, rules::card_definition(&game, card_id).name);
+
+    dispatch::populate_delegate_cache(&mut game);
+    assert_eq!(game.card(card_id).name, game.definition(card_id).unwrap().name);
+}
@@ -0,0 +1,105 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use card_helpers::{always, at_dusk, simple_ability, text};
+use core_ui::actions::InterfaceAction;
+use core_ui::panels;
+use data::card_name::CardName;
+use data::delegates::{Delegate, DuskEvent, EventDelegate};
+use data::primitives::Side;
+use data::user_actions::DebugAction;
+use panel_address::PanelAddress;
+use protos::spelldawn::client_action::Action;
+use protos::spelldawn::{FetchPanelAction, GainManaAction};
+use rules::{dispatch, mutations};
+use test_utils::client::TestSession;
+use test_utils::client_interface::HasText;
+use test_utils::test_card_builder::TestCardBuilder;
+use test_utils::*;
+
+#[test]
+fn toggle_delegate_trace() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    assert!(!g.game().data.config.trace_delegates);
+    g.perform(DebugAction::ToggleDelegateTrace.as_client_action(), g.user_id());
+    assert!(g.game().data.config.trace_delegates);
+    g.perform(DebugAction::ToggleDelegateTrace.as_client_action(), g.user_id());
+    assert!(!g.game().data.config.trace_delegates);
+}
+
+#[test]
+fn records_fired_delegates() {
+    let mut g = new_game(Side::Champion, Args { mana: 5, ..Args::default() });
+    g.perform(DebugAction::ToggleDelegateTrace.as_client_action(), g.user_id());
+    let card_id = g.add_to_hand(CardName::ArcaneRecovery);
+    g.play_card(card_id, g.user_id(), None);
+    let entry = g
+        .game()
+        .delegate_trace
+        .iter()
+        .find(|entry| entry.event == "CastCard" && entry.requirement)
+        .expect("Expected CastCard entry");
+    assert!(entry.mutation.as_ref().expect("Expected mutation").contains("Champion mana"));
+
+    g.perform(Action::GainMana(GainManaAction {}), g.user_id());
+    assert!(g.game().delegate_trace.iter().all(|entry| entry.event != "CastCard"));
+}
+
+#[test]
+fn nested_events_follow_parent() {
+    let artifact = TestCardBuilder::artifact()
+        .ability(simple_ability(
+            text!["Dusk: Draw a card"],
+            at_dusk(|g, s, _| mutations::draw_cards(g, s.side(), 1).map(|_| ())),
+        ))
+        .ability(simple_ability(
+            text!["Whenever you draw a card, do nothing"],
+            Delegate::DrawCard(EventDelegate::new(always, |_, _, _| Ok(()))),
+        ))
+        .build();
+    let g = new_game(
+        Side::Champion,
+        Args { in_play: vec![TestCard::new(artifact)], ..Args::default() },
+    );
+    let mut game = g.game().clone();
+    game.data.config.trace_delegates = true;
+    dispatch::invoke_event(&mut game, DuskEvent(2)).expect("dusk");
+
+    let position = |event: &str| {
+        game.delegate_trace
+            .iter()
+            .position(|entry| entry.event == event && entry.requirement)
+            .unwrap_or_else(|| panic!("Expected {} entry", event))
+    };
+    assert!(position("Dusk") < position("DrawCard"));
+    let dusk = &game.delegate_trace[position("Dusk")];
+    assert!(dusk.mutation.as_ref().expect("Expected mutation").contains("moved"));
+}
+
+#[test]
+fn delegate_trace_disabled() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    open_delegate_trace(&mut g);
+    assert!(g.user.interface.top_panel().has_text("Effect tracing is disabled"));
+}
+
+fn open_delegate_trace(g: &mut TestSession) {
+    g.perform(
+        Action::FetchPanel(FetchPanelAction {
+            panel_address: Some(PanelAddress::DelegateTrace.into()),
+        }),
+        g.user_id(),
+    );
+    g.user.interface.update(panels::open(PanelAddress::DelegateTrace));
+}
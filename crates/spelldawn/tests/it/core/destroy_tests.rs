@@ -0,0 +1,156 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use card_helpers::{on_destroyed, replace_destruction, simple_ability, text, this_card};
+use data::card_definition::{Ability, AbilityType};
+use data::card_name::CardName;
+use data::card_state::CardPosition;
+use data::delegates::CardDestroyed;
+use data::game::GameState;
+use data::primitives::{CardId, Side};
+use data::updates::{GameUpdate, UpdateQueue, Updates};
+use protos::spelldawn::PlayerName;
+use rules::mana::ManaPurpose;
+use rules::{mana, mutations};
+use test_utils::client::TestSession;
+use test_utils::test_card_builder::TestCardBuilder;
+use test_utils::*;
+
+#[test]
+fn destroyed_cards_recorded_as_single_update() {
+    cards::initialize::run();
+    let g = new_game(
+        Side::Overlord,
+        Args {
+            in_play: vec![
+                TestCard::new(CardName::TestActivatedAbilityTakeMana),
+                TestCard::new(CardName::TestActivatedAbilityCharges),
+            ],
+            opponent_hand: vec![CardName::TestActivatedAbilityCooldown],
+            ..Args::default()
+        },
+    );
+    let mut game = g.game().clone();
+    game.updates = UpdateQueue::new(Updates::Push);
+    let in_play = artifact_ids(&game);
+    let in_hand = card_id(&game, CardName::TestActivatedAbilityCooldown);
+    mutations::destroy_cards(&mut game, vec![in_play[0], in_hand, in_play[1], in_play[0]])
+        .expect("destroy_cards");
+
+    let updates = game.updates.steps.iter().filter_map(|s| s.update.clone()).collect::<Vec<_>>();
+    assert_eq!(vec![GameUpdate::DestroyCards(in_play.clone())], updates);
+    for card_id in in_play {
+        assert_eq!(CardPosition::DiscardPile(Side::Champion), game.card(card_id).position());
+    }
+    assert!(game.card(in_hand).position().in_hand());
+}
+
+#[test]
+fn destroy_triggers_fire_in_order_after_all_cards_leave_play() {
+    let first = TestCardBuilder::artifact()
+        .ability(simple_ability(
+            text!["When destroyed, multiply mana by 10 and gain 1"],
+            on_destroyed(|g, s, destroyed| {
+                assert_all_discarded(g, destroyed);
+                record_trigger(g, s.side(), 1);
+                Ok(())
+            }),
+        ))
+        .build();
+    let second = TestCardBuilder::artifact()
+        .ability(simple_ability(
+            text!["When destroyed, multiply mana by 10 and gain 2"],
+            on_destroyed(|g, s, destroyed| {
+                assert_all_discarded(g, destroyed);
+                record_trigger(g, s.side(), 2);
+                Ok(())
+            }),
+        ))
+        .build();
+    let mut g = new_game(
+        Side::Overlord,
+        Args {
+            in_play: vec![TestCard::new(first), TestCard::new(second)],
+            opponent_mana: 0,
+            ..Args::default()
+        },
+    );
+    g.play_from_hand(CardName::Corrosion);
+    assert_eq!(12, g.opponent.this_player.mana());
+    assert_eq!(2, g.user.cards.discard_pile(PlayerName::Opponent).len());
+}
+
+#[test]
+fn replaced_destruction_keeps_card_in_play() {
+    let protected = TestCardBuilder::artifact()
+        .ability(Ability {
+            text: text!["If this would be destroyed, instead gain 3 mana"],
+            ability_type: AbilityType::Standard,
+            delegates: replace_destruction(this_card, |g, s, _| {
+                mana::gain(g, s.side(), 3);
+                Ok(())
+            })
+            .into_iter()
+            .chain([on_destroyed(|g, s, _| {
+                mana::gain(g, s.side(), 100);
+                Ok(())
+            })])
+            .collect(),
+        })
+        .build();
+    let mut g = new_game(
+        Side::Overlord,
+        Args {
+            in_play: vec![
+                TestCard::new(protected),
+                TestCard::new(CardName::TestActivatedAbilityTakeMana),
+            ],
+            opponent_mana: 0,
+            ..Args::default()
+        },
+    );
+    g.play_from_hand(CardName::Corrosion);
+    assert_eq!(3, g.opponent.this_player.mana());
+    assert_eq!(
+        vec![CardName::TestActivatedAbilityTakeMana.displayed_name()],
+        g.user.cards.discard_pile(PlayerName::Opponent)
+    );
+    assert_eq!(vec![protected], artifact_names(&g));
+}
+
+fn record_trigger(game: &mut GameState, side: Side, value: u32) {
+    let current = mana::get(game, side, ManaPurpose::BaseMana);
+    mana::set(game, side, current * 10 + value);
+}
+
+fn assert_all_discarded(game: &GameState, destroyed: &CardDestroyed) {
+    assert_eq!(2, destroyed.destroyed.len());
+    for card_id in &destroyed.destroyed {
+        assert!(game.card(*card_id).position().in_discard_pile());
+    }
+}
+
+fn artifact_ids(game: &GameState) -> Vec<CardId> {
+    let mut result = game.artifacts().collect::<Vec<_>>();
+    result.sort();
+    result.iter().map(|c| c.id).collect()
+}
+
+fn artifact_names(g: &TestSession) -> Vec<CardName> {
+    g.game().artifacts().map(|c| c.name).collect()
+}
+
+fn card_id(game: &GameState, name: CardName) -> CardId {
+    game.all_cards().find(|c| c.name == name).expect("card").id
+}
@@ -0,0 +1,200 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use cards::initialize;
+use core_ui::actions::InterfaceAction;
+use data::adventure::Coins;
+use data::campaign::CampaignProgress;
+use data::cosmetics::PlayerCosmetics;
+use data::crafting::Dust;
+use data::draft::{DraftAction, NewDraftAction, DRAFT_PICKS};
+use data::player_data::{AutomationPreferences, PlayerData, PlayerSettings, PlayerState};
+use data::player_name::{NamedPlayer, PlayerId};
+use data::primitives::Side;
+use data::quests::QuestLog;
+use data::tutorial::TutorialData;
+use data::user_actions::UserAction;
+use maplit::hashmap;
+use test_utils::client::TestSession;
+use test_utils::client_interface::HasText;
+use test_utils::fake_database::FakeDatabase;
+use test_utils::*;
+
+#[test]
+fn request_draft_waits_for_opponent() {
+    let mut session = new_draft_session();
+    let (user_id, opponent_id) = (session.user_id(), session.opponent_id());
+    request_draft(&mut session, user_id, Side::Overlord, opponent_id);
+    assert!(session.user.interface.top_panel().has_text("Waiting for opponent"));
+    assert!(matches!(
+        session.player_data(session.user_id()).state,
+        Some(PlayerState::RequestedDraft(_))
+    ));
+}
+
+#[test]
+fn opponent_joins_draft() {
+    let mut session = new_draft_session();
+    start_draft(&mut session);
+    assert!(session.user.interface.top_panel().has_text(format!("Pick 1 of {DRAFT_PICKS}")));
+    assert!(session.opponent.interface.top_panel().has_text("Waiting for opponent to pick"));
+    assert!(session.player_data(session.user_id()).draft().is_some());
+    assert!(session.player_data(session.opponent_id()).draft().is_some());
+}
+
+#[test]
+fn pick_alternates_turns() {
+    let mut session = new_draft_session();
+    start_draft(&mut session);
+    let user_id = session.user_id();
+    pick(&mut session, user_id);
+    assert!(session.user.interface.top_panel().has_text("Waiting for opponent to pick"));
+    assert!(session.opponent.interface.top_panel().has_text(format!("Pick 1 of {DRAFT_PICKS}")));
+    let draft = session.player_data(session.opponent_id()).draft().unwrap();
+    assert_eq!(1, draft.overlord.picks.len());
+}
+
+#[test]
+fn cannot_pick_out_of_turn() {
+    let mut session = new_draft_session();
+    start_draft(&mut session);
+    assert!(session
+        .perform_action(
+            UserAction::DraftAction(DraftAction::PickCard(0)).as_client_action(),
+            session.opponent_id()
+        )
+        .is_err());
+}
+
+#[test]
+fn reconnect_during_draft() {
+    let mut session = new_draft_session();
+    start_draft(&mut session);
+    session.connect(session.user_id()).unwrap();
+    assert!(session.user.interface.top_panel().has_text(format!("Pick 1 of {DRAFT_PICKS}")));
+}
+
+#[test]
+fn completed_draft_starts_game() {
+    let mut session = new_draft_session();
+    start_draft(&mut session);
+    let (user_id, opponent_id) = (session.user_id(), session.opponent_id());
+    let draft_id = session.player_data(user_id).draft().unwrap().id;
+    for _ in 0..DRAFT_PICKS {
+        pick(&mut session, user_id);
+        pick(&mut session, opponent_id);
+    }
+
+    assert_eq!(draft_id, session.game_id());
+    assert!(matches!(
+        session.player_data(user_id).state,
+        Some(PlayerState::Playing(id)) if id == draft_id
+    ));
+    assert!(matches!(
+        session.player_data(opponent_id).state,
+        Some(PlayerState::Playing(id)) if id == draft_id
+    ));
+    assert_eq!(5, session.user.cards.revealed_cards().len());
+    assert_eq!(5, session.opponent.cards.revealed_cards().len());
+}
+
+#[test]
+fn draft_against_named_player() {
+    let mut session = new_draft_session();
+    let user_id = session.user_id();
+    request_draft(
+        &mut session,
+        user_id,
+        Side::Champion,
+        PlayerId::Named(NamedPlayer::TestNoAction),
+    );
+    assert!(session.user.interface.top_panel().has_text(format!("Pick 1 of {DRAFT_PICKS}")));
+    assert_eq!(1, session.player_data(session.user_id()).draft().unwrap().overlord.picks.len());
+
+    for _ in 0..DRAFT_PICKS {
+        pick(&mut session, user_id);
+    }
+
+    assert!(matches!(session.player_data(session.user_id()).state, Some(PlayerState::Playing(_))));
+    assert_eq!(5, session.user.cards.revealed_cards().len());
+}
+
+fn new_draft_session() -> TestSession {
+    initialize::run();
+    let (game_id, user_id, opponent_id) = generate_ids();
+    let database = FakeDatabase {
+        generated_game_id: Some(game_id),
+        game: None,
+        players: hashmap! {
+            user_id => new_player(user_id),
+            opponent_id => new_player(opponent_id),
+        },
+        signing_key: None,
+        player_secrets: hashmap! {},
+        leaderboards: hashmap! {},
+        matches: hashmap! {},
+        ratings: hashmap! {},
+        card_statistics: hashmap! {},
+        card_positions: hashmap! {},
+    };
+    TestSession::new(database, user_id, opponent_id)
+}
+
+fn new_player(id: PlayerId) -> PlayerData {
+    PlayerData {
+        id,
+        state: None,
+        decks: vec![],
+        adventure: None,
+        collection: hashmap! {},
+        tutorial: TutorialData::default(),
+        cosmetics: HashSet::new(),
+        achievements: HashSet::new(),
+        settings: PlayerSettings::default(),
+        match_history: vec![],
+        completed_puzzles: HashSet::new(),
+        quests: QuestLog::default(),
+        coins: Coins::default(),
+        player_cosmetics: PlayerCosmetics::default(),
+        revision: 0,
+        notifications: vec![],
+        campaign: CampaignProgress::default(),
+        current_match: None,
+        dust: Dust::default(),
+        automation: AutomationPreferences::default(),
+    }
+}
+
+/// Starts a draft with the user as the Overlord player
+fn start_draft(session: &mut TestSession) {
+    let (user_id, opponent_id) = (session.user_id(), session.opponent_id());
+    request_draft(session, user_id, Side::Overlord, opponent_id);
+    request_draft(session, opponent_id, Side::Champion, user_id);
+}
+
+fn request_draft(session: &mut TestSession, player_id: PlayerId, side: Side, opponent: PlayerId) {
+    session.perform(
+        UserAction::NewDraft(NewDraftAction { side, opponent }).as_client_action(),
+        player_id,
+    );
+}
+
+fn pick(session: &mut TestSession, player_id: PlayerId) {
+    let client = if player_id == session.user_id() { &session.user } else { &session.opponent };
+    let handlers = client.interface.top_panel().find_handlers("Pick");
+    let action = handlers.expect("Button not found").on_click.expect("OnClick not found");
+    session.perform(action.action.expect("Action"), player_id);
+}
@@ -0,0 +1,111 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use card_helpers::{simple_ability, text, this_card};
+use data::card_name::CardName;
+use data::delegates::{Delegate, QueryDelegate};
+use data::primitives::Side;
+use protos::spelldawn::{CardIdentifier, PlayerName};
+use test_utils::client::TestSession;
+use test_utils::client_interface::HasText;
+use test_utils::test_card_builder::TestCardBuilder;
+use test_utils::*;
+
+/// Plays a minion and a scheme in [ROOM_ID], then plays the provided Champion
+/// `weapon` and raids the room, returning the weapon's identifier.
+fn raid_with_weapon(weapon: CardName) -> (TestSession, CardIdentifier) {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let minion = TestCardBuilder::minion().health(5).combat_end_raid().build();
+    g.play_from_hand(minion);
+    g.play_from_hand(CardName::TestScheme31);
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    let id = g.play_from_hand(weapon);
+    g.initiate_raid(ROOM_ID);
+    (g, id)
+}
+
+fn use_weapon(g: &mut TestSession, weapon: CardName) {
+    g.click_on(g.opponent_id(), weapon.displayed_name());
+    assert!(g.opponent.interface.card_anchor_nodes().has_text("Score!"));
+}
+
+#[test]
+fn durability_shown_as_arena_icon() {
+    let weapon = TestCardBuilder::weapon().base_attack(5).durability(2).build();
+    let (g, id) = raid_with_weapon(weapon);
+    assert_eq!("2", g.opponent.get_card(id).arena_icon());
+}
+
+#[test]
+fn using_weapon_spends_durability() {
+    let weapon = TestCardBuilder::weapon().base_attack(5).durability(2).build();
+    let (mut g, id) = raid_with_weapon(weapon);
+    use_weapon(&mut g, weapon);
+    assert_eq!("1", g.opponent.get_card(id).arena_icon());
+    assert!(g.opponent.cards.discard_pile(PlayerName::User).is_empty());
+}
+
+#[test]
+fn weapon_discarded_at_zero_durability() {
+    let weapon = TestCardBuilder::weapon().base_attack(5).durability(1).build();
+    let (mut g, _) = raid_with_weapon(weapon);
+    use_weapon(&mut g, weapon);
+    assert_eq!(vec![weapon.displayed_name()], g.opponent.cards.discard_pile(PlayerName::User));
+}
+
+#[test]
+fn weapon_without_durability_not_discarded() {
+    let weapon = TestCardBuilder::weapon().base_attack(5).build();
+    let (mut g, _) = raid_with_weapon(weapon);
+    use_weapon(&mut g, weapon);
+    assert!(g.opponent.cards.discard_pile(PlayerName::User).is_empty());
+}
+
+#[test]
+fn query_restores_durability() {
+    let weapon = TestCardBuilder::weapon()
+        .base_attack(5)
+        .durability(1)
+        .ability(simple_ability(
+            text!["+1 durability."],
+            Delegate::Durability(QueryDelegate {
+                requirement: this_card,
+                transformation: |_, _, _, durability| durability + 1,
+            }),
+        ))
+        .build();
+    let (mut g, id) = raid_with_weapon(weapon);
+    use_weapon(&mut g, weapon);
+    assert_eq!("1", g.opponent.get_card(id).arena_icon());
+    assert!(g.opponent.cards.discard_pile(PlayerName::User).is_empty());
+}
+
+#[test]
+fn query_ignores_durability() {
+    let weapon = TestCardBuilder::weapon()
+        .base_attack(5)
+        .durability(1)
+        .ability(simple_ability(
+            text!["Using this weapon does not spend durability."],
+            Delegate::ConsumesDurability(QueryDelegate {
+                requirement: this_card,
+                transformation: |_, _, _, current| current.with_override(false),
+            }),
+        ))
+        .build();
+    let (mut g, id) = raid_with_weapon(weapon);
+    use_weapon(&mut g, weapon);
+    assert_eq!("1", g.opponent.get_card(id).arena_icon());
+    assert!(g.opponent.cards.discard_pile(PlayerName::User).is_empty());
+}
@@ -0,0 +1,48 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::actions::InterfaceAction;
+use data::emotes::Emote;
+use data::primitives::Side;
+use data::user_actions::UserAction;
+use test_utils::client_interface::HasText;
+use test_utils::*;
+
+#[test]
+fn send_emote() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.perform(UserAction::SendEmote(Emote::Greetings).as_client_action(), g.user_id());
+    assert_eq!(1, g.user.interface.emotes().len());
+    assert_eq!(1, g.opponent.interface.emotes().len());
+    assert!(g.opponent.interface.emotes()[0].has_text("Greetings!"));
+}
+
+#[test]
+fn emotes_are_rate_limited() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.perform(UserAction::SendEmote(Emote::Greetings).as_client_action(), g.user_id());
+    g.perform(UserAction::SendEmote(Emote::Oops).as_client_action(), g.user_id());
+    assert_eq!(1, g.opponent.interface.emotes().len());
+    assert!(g.opponent.interface.emotes()[0].has_text("Greetings!"));
+}
+
+#[test]
+fn mute_emotes() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.perform(UserAction::SetMuteEmotes(true).as_client_action(), g.opponent_id());
+    assert!(g.player_data(g.opponent_id()).settings.mute_emotes);
+    g.perform(UserAction::SendEmote(Emote::Threaten).as_client_action(), g.user_id());
+    assert_eq!(1, g.user.interface.emotes().len());
+    assert!(g.opponent.interface.emotes().is_empty());
+}
@@ -0,0 +1,110 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use card_helpers::{abilities, encounter_cost};
+use cards::test_cards;
+use data::card_name::CardName;
+use data::game_actions::{EncounterAction, GameAction, PromptAction};
+use data::primitives::Side;
+use rules::queries;
+use test_utils::client::TestSession;
+use test_utils::client_interface::HasText;
+use test_utils::test_card_builder::TestCardBuilder;
+use test_utils::*;
+
+const ABILITY_COST: u32 = 2;
+
+fn artifact() -> CardName {
+    TestCardBuilder::artifact()
+        .ability(abilities::weapon_boost_this_encounter::<2>(encounter_cost(ABILITY_COST)))
+        .build()
+}
+
+/// Plays a minion with 5 health and a scheme in [ROOM_ID], then plays the
+/// provided Champion cards and raids the room.
+fn raid_with_cards(cards: &[CardName]) -> TestSession {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let minion = TestCardBuilder::minion().health(5).combat_end_raid().build();
+    g.play_from_hand(minion);
+    g.play_from_hand(CardName::TestScheme31);
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    for card in cards {
+        g.play_from_hand(*card);
+    }
+    g.initiate_raid(ROOM_ID);
+    g
+}
+
+fn ability_actions(g: &TestSession) -> Vec<GameAction> {
+    g.legal_actions(Side::Champion)
+        .into_iter()
+        .filter(|action| {
+            matches!(
+                action,
+                GameAction::PromptAction(PromptAction::EncounterAction(
+                    EncounterAction::ActivateAbility(..)
+                ))
+            )
+        })
+        .collect()
+}
+
+fn ability_label(artifact: CardName, weapon: CardName) -> String {
+    format!("{}: {}", artifact.displayed_name(), weapon.displayed_name())
+}
+
+#[test]
+fn ability_offered_alongside_weapons() {
+    let (artifact, weapon) = (artifact(), TestCardBuilder::weapon().base_attack(3).build());
+    let g = raid_with_cards(&[artifact, weapon]);
+    assert_eq!(1, ability_actions(&g).len());
+    assert!(g.opponent.interface.controls().has_text(ability_label(artifact, weapon)));
+    assert!(!g.legal_actions(Side::Champion).iter().any(|action| matches!(
+        action,
+        GameAction::PromptAction(PromptAction::EncounterAction(EncounterAction::UseWeaponAbility(
+            ..
+        )))
+    )));
+}
+
+#[test]
+fn not_offered_without_weapons() {
+    let g = raid_with_cards(&[artifact()]);
+    assert!(ability_actions(&g).is_empty());
+}
+
+#[test]
+fn activate_ability_boosts_weapon() {
+    let (artifact, weapon) = (artifact(), TestCardBuilder::weapon().base_attack(3).build());
+    let mut g = raid_with_cards(&[artifact, weapon]);
+    g.click_on(g.opponent_id(), ability_label(artifact, weapon));
+    assert_eq!(
+        STARTING_MANA - test_cards::ARTIFACT_COST - test_cards::WEAPON_COST - ABILITY_COST,
+        g.opponent.this_player.mana()
+    );
+    assert!(ability_actions(&g).is_empty());
+    g.click_on(g.opponent_id(), weapon.displayed_name());
+    assert!(g.opponent.interface.card_anchor_nodes().has_text("Score!"));
+}
+
+#[test]
+fn boost_ends_with_encounter() {
+    let (artifact, weapon) = (artifact(), TestCardBuilder::weapon().base_attack(3).build());
+    let mut g = raid_with_cards(&[artifact, weapon]);
+    g.click_on(g.opponent_id(), ability_label(artifact, weapon));
+    let weapon_id = g.game().weapons().next().expect("weapon").id;
+    assert_eq!(5, queries::attack(g.game(), weapon_id));
+    g.click_on(g.opponent_id(), weapon.displayed_name());
+    assert_eq!(3, queries::attack(g.game(), weapon_id));
+}
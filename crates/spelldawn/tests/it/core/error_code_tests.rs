@@ -0,0 +1,66 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::actions::InterfaceAction;
+use data::card_name::CardName;
+use data::primitives::Side;
+use data::user_actions::DebugAction;
+use protos::spelldawn::client_action::Action;
+use protos::spelldawn::{DrawCardAction, ErrorCode, PlayCardAction};
+use server::errors;
+use test_utils::*;
+use tonic::Code;
+
+#[test]
+fn not_your_turn() {
+    let mut g = new_game(Side::Overlord, Args { turn: Some(Side::Champion), ..Args::default() });
+    let error = g.perform_action(Action::DrawCard(DrawCardAction {}), g.user_id()).unwrap_err();
+    assert_eq!(ErrorCode::NotYourTurn, errors::server_error(&error).code());
+}
+
+#[test]
+fn insufficient_mana() {
+    let mut g = new_game(Side::Champion, Args { mana: 0, ..Args::default() });
+    let card_id = g.add_to_hand(CardName::ArcaneRecovery);
+    let error = g
+        .perform_action(
+            Action::PlayCard(PlayCardAction { card_id: Some(card_id), target: None }),
+            g.user_id(),
+        )
+        .unwrap_err();
+    assert_eq!(ErrorCode::InsufficientMana, errors::server_error(&error).code());
+}
+
+#[test]
+fn illegal_action() {
+    let mut g = new_game(Side::Overlord, Args { actions: 0, ..Args::default() });
+    let error = g.perform_action(Action::DrawCard(DrawCardAction {}), g.user_id()).unwrap_err();
+    assert_eq!(ErrorCode::IllegalAction, errors::server_error(&error).code());
+}
+
+#[test]
+fn uncoded_errors_are_internal() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.perform(DebugAction::Panic.as_client_action(), g.user_id());
+    let error = g.perform_action(Action::DrawCard(DrawCardAction {}), g.user_id()).unwrap_err();
+    assert_eq!(ErrorCode::Internal, errors::server_error(&error).code());
+    assert_eq!(Code::Internal, errors::status(&error).code());
+}
+
+#[test]
+fn coded_errors_are_failed_preconditions() {
+    let mut g = new_game(Side::Overlord, Args { turn: Some(Side::Champion), ..Args::default() });
+    let error = g.perform_action(Action::DrawCard(DrawCardAction {}), g.user_id()).unwrap_err();
+    assert_eq!(Code::FailedPrecondition, errors::status(&error).code());
+}
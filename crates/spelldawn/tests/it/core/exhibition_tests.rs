@@ -0,0 +1,133 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use cards::{decklists, initialize};
+use core_ui::actions::InterfaceAction;
+use data::adventure::Coins;
+use data::campaign::CampaignProgress;
+use data::card_name::CardName;
+use data::cosmetics::PlayerCosmetics;
+use data::crafting::Dust;
+use data::player_data::{AutomationPreferences, PlayerData, PlayerSettings};
+use data::player_name::{NamedPlayer, PlayerId};
+use data::primitives::Side;
+use data::quests::QuestLog;
+use data::tutorial::TutorialData;
+use data::user_actions::{DebugAction, UserAction};
+use display::render;
+use maplit::hashmap;
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::{CardIdentifier, GameView};
+use server::exhibitions;
+use test_utils::client::TestSession;
+use test_utils::fake_database::FakeDatabase;
+use test_utils::*;
+
+#[test]
+fn spectators_only_see_public_cards() {
+    let mut g = new_game(Side::Overlord, Args { opponent_hand_size: 2, ..Args::default() });
+    let minion = g.play_from_hand(CardName::TestMinionEndRaid);
+    let view =
+        game_view(render::spectate(g.game(), Side::Overlord, &PlayerSettings::default()).unwrap());
+    assert!(!card(&view, minion).revealed_to_viewer);
+    assert!(view.cards.iter().all(|c| c.revealed_to_viewer || c.revealed_card.is_none()));
+    assert!(g.user.cards.get(minion).revealed_to_me());
+}
+
+#[test]
+fn spectators_have_no_game_controls() {
+    let g = new_game(Side::Overlord, Args::default());
+    let view =
+        game_view(render::spectate(g.game(), Side::Overlord, &PlayerSettings::default()).unwrap());
+    assert!(view.main_controls.is_none());
+}
+
+#[test]
+fn start_and_leave_exhibition() {
+    let mut g = new_spectator_session();
+    let response = g
+        .perform_action(
+            DebugAction::StartExhibition(NamedPlayer::TestNoAction, NamedPlayer::TestNoAction)
+                .as_client_action(),
+            g.user_id(),
+        )
+        .expect("Request failed");
+    assert!(response
+        .command_list
+        .commands
+        .iter()
+        .any(|c| matches!(&c.command, Some(Command::LoadScene(s)) if s.scene_name == "Game")));
+    assert_eq!(Some(g.game_id()), exhibitions::spectated_game(g.user_id()));
+    assert_eq!(PlayerId::Named(NamedPlayer::TestNoAction), g.game().overlord.id);
+
+    g.perform(UserAction::LeaveGame.as_client_action(), g.user_id());
+    assert_eq!(None, exhibitions::spectated_game(g.user_id()));
+}
+
+fn game_view(commands: Vec<Command>) -> GameView {
+    commands
+        .into_iter()
+        .find_map(|c| match c {
+            Command::UpdateGameView(update) => update.game,
+            _ => None,
+        })
+        .expect("GameView")
+}
+
+fn card(view: &GameView, id: CardIdentifier) -> &protos::spelldawn::CardView {
+    view.cards.iter().find(|c| c.card_id == Some(id)).expect("Card")
+}
+
+fn new_spectator_session() -> TestSession {
+    initialize::run();
+    let (game_id, user_id, opponent_id) = generate_ids();
+    let database = FakeDatabase {
+        generated_game_id: Some(game_id),
+        game: None,
+        players: hashmap! {
+            user_id => PlayerData {
+                id: user_id,
+                state: None,
+                decks: vec![decklists::canonical_deck(user_id, Side::Champion)],
+                adventure: None,
+                collection: hashmap! {},
+                tutorial: TutorialData::default(),
+                cosmetics: HashSet::new(),
+                achievements: HashSet::new(),
+                settings: PlayerSettings::default(),
+                match_history: vec![],
+                completed_puzzles: HashSet::new(),
+                quests: QuestLog::default(),
+                coins: Coins::default(),
+                player_cosmetics: PlayerCosmetics::default(),
+                revision: 0,
+                notifications: vec![],
+                campaign: CampaignProgress::default(),
+                current_match: None,
+                dust: Dust::default(),
+                automation: AutomationPreferences::default(),
+            }
+        },
+        signing_key: None,
+        player_secrets: hashmap! {},
+        leaderboards: hashmap! {},
+        matches: hashmap! {},
+        ratings: hashmap! {},
+        card_statistics: hashmap! {},
+        card_positions: hashmap! {},
+    };
+    TestSession::new(database, user_id, opponent_id)
+}
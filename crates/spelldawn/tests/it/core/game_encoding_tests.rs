@@ -0,0 +1,87 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use cards::decklists;
+use data::player_data::PlayerState;
+use data::player_name::PlayerId;
+use data::primitives::GameId;
+use database::game_encoding;
+use protos::spelldawn::game_command::Command;
+use server::requests;
+use test_utils::fake_database::FakeDatabase;
+
+#[test]
+fn round_trip() {
+    cards::initialize::run();
+    let game = decklists::canonical_game().unwrap();
+    let decoded = game_encoding::decode(&game_encoding::encode(&game).unwrap()).unwrap();
+    assert_eq!(game.id, decoded.id);
+    assert_eq!(game.all_card_ids().count(), decoded.all_card_ids().count());
+    for card_id in game.all_card_ids() {
+        assert_eq!(game.card(card_id).name, decoded.card(card_id).name);
+        assert_eq!(game.card(card_id).position(), decoded.card(card_id).position());
+    }
+}
+
+#[test]
+fn decode_legacy_json() {
+    cards::initialize::run();
+    let game = decklists::canonical_game().unwrap();
+    let decoded = game_encoding::decode(&serde_json::to_vec(&game).unwrap()).unwrap();
+    assert_eq!(game.id, decoded.id);
+    assert_eq!(game.all_card_ids().count(), decoded.all_card_ids().count());
+}
+
+#[test]
+fn decode_game_missing_defaulted_field() {
+    cards::initialize::run();
+    let game = decklists::canonical_game().unwrap();
+    let mut value = serde_json::to_value(&game).unwrap();
+    value.as_object_mut().unwrap().remove("delegate_trace").unwrap();
+    let mut bytes = vec![game_encoding::FORMAT_VERSION];
+    bytes.extend(rmp_serde::to_vec_named(&value).unwrap());
+    let decoded = game_encoding::decode(&bytes).unwrap();
+    assert_eq!(game.id, decoded.id);
+    assert!(decoded.delegate_trace.is_empty());
+}
+
+#[test]
+fn encoding_smaller_than_json() {
+    cards::initialize::run();
+    let game = decklists::canonical_game().unwrap();
+    assert!(game_encoding::encode(&game).unwrap().len() < serde_json::to_vec(&game).unwrap().len());
+}
+
+#[test]
+fn unknown_version_fails() {
+    assert!(game_encoding::decode(&[u8::MAX, 0, 0]).is_err());
+    assert!(game_encoding::decode(&[]).is_err());
+}
+
+#[test]
+fn unloadable_game_returns_player_to_main_menu() {
+    cards::initialize::run();
+    let player_id = PlayerId::Database(1);
+    let mut database = FakeDatabase::default();
+    requests::handle_connect(&mut database, player_id).unwrap();
+    database.players.get_mut(&player_id).unwrap().state =
+        Some(PlayerState::Playing(GameId::new(7)));
+
+    let commands = requests::handle_connect(&mut database, player_id).unwrap();
+    assert!(database.players[&player_id].state.is_none());
+    assert!(commands.commands.iter().any(|c| matches!(
+        &c.command,
+        Some(Command::LoadScene(load)) if load.scene_name == "Main"
+    )));
+}
@@ -0,0 +1,66 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::game::GameModifier;
+use data::primitives::Side;
+use test_utils::test_card_builder::TestCardBuilder;
+use test_utils::*;
+
+#[test]
+fn minion_health_modifier() {
+    let mut g = new_game(
+        Side::Overlord,
+        Args { modifiers: vec![GameModifier::MinionHealth(2)], ..Args::default() },
+    );
+    let id = g.play_from_hand(TestCardBuilder::minion().health(5).build());
+    assert_eq!(7, rules::queries::health(g.game(), server_card_id(id)));
+}
+
+#[test]
+fn multiple_minion_health_modifiers() {
+    let mut g = new_game(
+        Side::Overlord,
+        Args {
+            modifiers: vec![GameModifier::MinionHealth(2), GameModifier::MinionHealth(1)],
+            ..Args::default()
+        },
+    );
+    let id = g.play_from_hand(TestCardBuilder::minion().health(5).build());
+    assert_eq!(8, rules::queries::health(g.game(), server_card_id(id)));
+}
+
+#[test]
+fn bonus_starting_mana_modifier() {
+    let mut g = new_game(
+        Side::Overlord,
+        Args {
+            modifiers: vec![GameModifier::BonusStartingMana(Side::Overlord, 5)],
+            ..Args::default()
+        },
+    );
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    assert_eq!(STARTING_MANA + 5, g.me().mana());
+    assert_eq!(STARTING_MANA, g.you().mana());
+}
+
+#[test]
+fn no_modifiers_by_default() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let id = g.play_from_hand(TestCardBuilder::minion().health(5).build());
+    assert_eq!(5, rules::queries::health(g.game(), server_card_id(id)));
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    assert_eq!(STARTING_MANA, g.me().mana());
+}
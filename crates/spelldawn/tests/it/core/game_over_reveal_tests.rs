@@ -0,0 +1,44 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::actions::InterfaceAction;
+use data::game_actions::GameAction;
+use data::primitives::Side;
+use data::user_actions::UserAction;
+use protos::spelldawn::PlayerName;
+use test_utils::*;
+
+#[test]
+fn reveals_hands_and_decks_on_game_over() {
+    let mut g = new_game(
+        Side::Overlord,
+        Args { opponent_hand_size: 2, reveal_on_game_over: true, ..Args::default() },
+    );
+    assert_eq!(vec![HIDDEN_CARD, HIDDEN_CARD], g.user.cards.hand(PlayerName::Opponent));
+    assert!(g.user.cards.revealed_cards().is_empty());
+
+    g.perform(UserAction::GameAction(GameAction::Resign).as_client_action(), g.opponent_id());
+    assert!(!g.user.cards.hand(PlayerName::Opponent).iter().any(|name| name == HIDDEN_CARD));
+    let deck_size = g.game().deck(Side::Overlord).count() + g.game().deck(Side::Champion).count();
+    assert_eq!(deck_size, g.user.cards.revealed_cards().len());
+    assert_eq!(deck_size, g.opponent.cards.revealed_cards().len());
+}
+
+#[test]
+fn hands_stay_hidden_without_reveal_option() {
+    let mut g = new_game(Side::Overlord, Args { opponent_hand_size: 2, ..Args::default() });
+    g.perform(UserAction::GameAction(GameAction::Resign).as_client_action(), g.opponent_id());
+    assert_eq!(vec![HIDDEN_CARD, HIDDEN_CARD], g.user.cards.hand(PlayerName::Opponent));
+    assert!(g.user.cards.revealed_cards().is_empty());
+}
@@ -0,0 +1,64 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_name::CardName;
+use data::game_actions::GameAction;
+use data::primitives::{RoomId, Side};
+use protos::spelldawn::client_action::Action;
+use protos::spelldawn::{PlayerName, SpendActionPointAction};
+use test_utils::*;
+
+#[test]
+fn global_modifier_visible_to_both_players() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.play_from_hand(CardName::TestGlobalModifierHandSize);
+    let name = CardName::TestGlobalModifierHandSize.displayed_name();
+    assert_eq!(vec![name.clone()], g.user.cards.global_modifier());
+    assert_eq!(vec![name], g.opponent.cards.global_modifier());
+    assert_eq!(STARTING_MANA - 1, g.me().mana());
+}
+
+#[test]
+fn new_modifier_replaces_existing() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.play_from_hand(CardName::TestGlobalModifierRaidCost);
+    g.play_from_hand(CardName::TestGlobalModifierRaidCost);
+    let name = CardName::TestGlobalModifierRaidCost.displayed_name();
+    assert_eq!(vec![name.clone()], g.user.cards.global_modifier());
+    assert_eq!(vec![name.clone()], g.opponent.cards.global_modifier());
+    assert_eq!(vec![name], g.user.cards.discard_pile(PlayerName::User));
+}
+
+#[test]
+fn modifier_increases_raid_cost() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.play_from_hand(CardName::TestGlobalModifierRaidCost);
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    g.initiate_raid(RoomId::Sanctum);
+    assert_eq!(1, g.you().actions());
+}
+
+#[test]
+fn cannot_raid_without_enough_actions() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.play_from_hand(CardName::TestGlobalModifierRaidCost);
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    g.perform(Action::SpendActionPoint(SpendActionPointAction {}), g.opponent_id());
+    g.perform(Action::SpendActionPoint(SpendActionPointAction {}), g.opponent_id());
+    assert_eq!(1, g.you().actions());
+    assert!(!g
+        .legal_actions(Side::Champion)
+        .iter()
+        .any(|action| matches!(action, GameAction::InitiateRaid(_))));
+}
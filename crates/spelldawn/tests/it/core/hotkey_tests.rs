@@ -0,0 +1,75 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::actions::InterfaceAction;
+use core_ui::icons;
+use data::card_name::CardName;
+use data::primitives::Side;
+use data::user_actions::UserAction;
+use protos::spelldawn::Node;
+use test_utils::client::TestSession;
+use test_utils::client_interface::HasText;
+use test_utils::*;
+
+/// Returns the (hotkey label, button text) pairs for all nodes in `nodes`
+fn hotkeys(nodes: Vec<&Node>) -> Vec<(String, Vec<String>)> {
+    let mut result = vec![];
+    for node in nodes {
+        if let Some(hotkey) = &node.hotkey {
+            result.push((hotkey.label.clone(), node.get_text()));
+        }
+        result.extend(hotkeys(node.children.iter().collect()));
+    }
+    result
+}
+
+fn start_encounter(g: &mut TestSession) {
+    g.play_from_hand(CardName::TestWeapon3Attack12Boost3Cost);
+    setup_raid_target(g, CardName::TestMinionEndRaid);
+    g.initiate_raid(ROOM_ID);
+}
+
+#[test]
+fn encounter_prompt_hotkeys() {
+    let mut g = new_game(Side::Champion, Args::default());
+    start_encounter(&mut g);
+    let labels = hotkeys(g.user.interface.controls())
+        .into_iter()
+        .map(|(label, text)| (label, text.concat()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            ("1".to_string(), format!("Test Weapon 3 Attack 12 Boost 3 Cost\n1{}", icons::MANA)),
+            ("Space".to_string(), "Continue".to_string()),
+            ("2".to_string(), "Retreat".to_string()),
+        ],
+        labels
+    );
+}
+
+#[test]
+fn no_hotkeys_without_prompt() {
+    let g = new_game(Side::Champion, Args::default());
+    assert!(hotkeys(g.user.interface.controls()).is_empty());
+}
+
+#[test]
+fn disable_hotkeys() {
+    let mut g = new_game(Side::Champion, Args::default());
+    start_encounter(&mut g);
+    g.perform(UserAction::SetDisableHotkeys(true).as_client_action(), g.user_id());
+    assert!(g.player_data(g.user_id()).settings.disable_hotkeys);
+    assert!(hotkeys(g.user.interface.controls()).is_empty());
+    assert!(g.user.interface.controls().has_text("Continue"));
+}
@@ -0,0 +1,173 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use axum::body::Body;
+use axum::http::{HeaderMap, Request, StatusCode};
+use data::card_name::CardName;
+use data::card_stats::CardStatistics;
+use data::player_data::PlayerData;
+use data::player_name::PlayerId;
+use http_gateway::{
+    ADMIN_TOKEN_HEADER, DEVICE_IDENTIFIER_HEADER, SESSION_TOKEN_HEADER, SOCIAL_IDENTIFIER_HEADER,
+};
+use maplit::hashmap;
+use protos::spelldawn::player_identifier::PlayerIdentifierType;
+use serde_json::{json, Value};
+use test_utils::fake_database::{self, FakeDatabase};
+use tower::ServiceExt;
+
+const PLAYER: PlayerId = PlayerId::Database(1);
+const ADMIN_TOKEN: &str = "admin";
+
+#[tokio::test]
+async fn profile() {
+    let (database, token) = database();
+    let (status, body) = get(database, "/v1/profile", &player_headers(&token)).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(json!(PLAYER), body["id"]);
+    assert_eq!(json!(false), body["in_game"]);
+    assert_eq!(json!(PLAYER), body["rating"]["player_id"]);
+}
+
+#[tokio::test]
+async fn decks() {
+    let (database, token) = database();
+    let expected = json!(database.players[&PLAYER].decks);
+    let (status, body) = get(database, "/v1/decks", &player_headers(&token)).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(expected, body);
+}
+
+#[tokio::test]
+async fn collection() {
+    let (database, token) = database();
+    let (status, body) = get(database, "/v1/collection", &player_headers(&token)).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(json!({ "TestChampionSpell": 2 }), body);
+}
+
+#[tokio::test]
+async fn match_history() {
+    let (database, token) = database();
+    let (status, body) = get(database, "/v1/match_history", &player_headers(&token)).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(json!([]), body);
+}
+
+#[tokio::test]
+async fn adventure_journal() {
+    let (database, token) = database();
+    let (status, body) = get(database, "/v1/adventure_journal", &player_headers(&token)).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(json!([]), body);
+}
+
+#[tokio::test]
+async fn missing_player_identifier() {
+    let (database, token) = database();
+    let headers = [(SESSION_TOKEN_HEADER, token.as_str())];
+    let (status, _) = get(database, "/v1/profile", &headers).await;
+    assert_eq!(StatusCode::UNAUTHORIZED, status);
+}
+
+#[tokio::test]
+async fn invalid_session_token() {
+    let (database, _) = database();
+    let (status, _) = get(database, "/v1/decks", &player_headers("invalid")).await;
+    assert_eq!(StatusCode::UNAUTHORIZED, status);
+}
+
+#[tokio::test]
+async fn card_stats() {
+    let (database, _) = database();
+    let (status, body) =
+        get(database, "/admin/card_stats", &[(ADMIN_TOKEN_HEADER, ADMIN_TOKEN)]).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(json!("TestChampionSpell"), body[0]["card_name"]);
+    assert_eq!(json!(3), body[0]["times_played"]);
+}
+
+#[tokio::test]
+async fn invalid_admin_token() {
+    let (database, _) = database();
+    let (status, _) = get(database, "/admin/card_stats", &[(ADMIN_TOKEN_HEADER, "admim")]).await;
+    assert_eq!(StatusCode::FORBIDDEN, status);
+    let (database, _) = self::database();
+    let (status, _) = get(database, "/admin/card_stats", &[]).await;
+    assert_eq!(StatusCode::FORBIDDEN, status);
+}
+
+#[tokio::test]
+async fn admin_routes_disabled() {
+    let (database, _) = database();
+    let response = http_gateway::router(database, None)
+        .oneshot(request("/admin/card_stats", &[(ADMIN_TOKEN_HEADER, ADMIN_TOKEN)]))
+        .await
+        .unwrap();
+    assert_eq!(StatusCode::NOT_FOUND, response.status());
+}
+
+#[test]
+fn gateway_player_identifier() {
+    let mut headers = HeaderMap::new();
+    assert!(http_gateway::player_identifier(&headers).is_none());
+
+    headers.insert(DEVICE_IDENTIFIER_HEADER, "device".parse().unwrap());
+    assert_eq!(
+        Some(PlayerIdentifierType::DeviceIdentifier("device".to_string())),
+        http_gateway::player_identifier(&headers).unwrap().player_identifier_type
+    );
+
+    headers.insert(SOCIAL_IDENTIFIER_HEADER, "social".parse().unwrap());
+    assert_eq!(
+        Some(PlayerIdentifierType::SocialIdentifier("social".to_string())),
+        http_gateway::player_identifier(&headers).unwrap().player_identifier_type
+    );
+}
+
+fn database() -> (FakeDatabase, String) {
+    let mut player = PlayerData::new(PLAYER);
+    player.collection = hashmap! { CardName::TestChampionSpell => 2 };
+    let mut statistics = CardStatistics::new(CardName::TestChampionSpell);
+    statistics.times_played = 3;
+    let mut database = FakeDatabase {
+        players: hashmap! { PLAYER => player },
+        card_statistics: hashmap! { CardName::TestChampionSpell => statistics },
+        ..FakeDatabase::default()
+    };
+    let token = fake_database::authenticate(&mut database, PLAYER);
+    (database, token)
+}
+
+fn player_headers(token: &str) -> [(&'static str, &str); 2] {
+    [(DEVICE_IDENTIFIER_HEADER, "1"), (SESSION_TOKEN_HEADER, token)]
+}
+
+fn request(uri: &str, headers: &[(&str, &str)]) -> Request<Body> {
+    let mut builder = Request::builder().uri(uri);
+    for (name, value) in headers {
+        builder = builder.header(*name, *value);
+    }
+    builder.body(Body::empty()).unwrap()
+}
+
+async fn get(database: FakeDatabase, uri: &str, headers: &[(&str, &str)]) -> (StatusCode, Value) {
+    let response = http_gateway::router(database, Some(ADMIN_TOKEN.to_string()))
+        .oneshot(request(uri, headers))
+        .await
+        .unwrap();
+    let status = response.status();
+    let bytes = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    (status, serde_json::from_slice(&bytes).unwrap_or(Value::Null))
+}
@@ -0,0 +1,77 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::actions::InterfaceAction;
+use data::card_name::CardName;
+use data::player_data::IconMode;
+use data::primitives::Side;
+use data::user_actions::UserAction;
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::CardIcon;
+use server::requests::GameResponse;
+use test_utils::*;
+
+#[test]
+fn defaults_to_standard_icons() {
+    let g = new_game(Side::Overlord, Args::default());
+    assert_eq!(IconMode::Standard, g.player_data(g.user_id()).settings.icon_mode);
+}
+
+#[test]
+fn set_icon_mode() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.perform(UserAction::SetIconMode(IconMode::HighContrast).as_client_action(), g.user_id());
+    assert_eq!(IconMode::HighContrast, g.player_data(g.user_id()).settings.icon_mode);
+}
+
+#[test]
+fn high_contrast_icons_rendered_in_game() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.add_to_hand(CardName::TestOverlordSpell);
+    let response = g
+        .perform_action(
+            UserAction::SetIconMode(IconMode::HighContrast).as_client_action(),
+            g.user_id(),
+        )
+        .expect("Request failed");
+    let icon = mana_icon(&response).expect("Mana icon");
+    assert!(icon.background.expect("background").address.contains("Icon_Mana_Color_04"));
+    assert!(icon.text_color.is_some());
+}
+
+#[test]
+fn standard_icons_have_default_text_color() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.add_to_hand(CardName::TestOverlordSpell);
+    let response = g
+        .perform_action(UserAction::SetIconMode(IconMode::Standard).as_client_action(), g.user_id())
+        .expect("Request failed");
+    let icon = mana_icon(&response).expect("Mana icon");
+    assert!(icon.background.expect("background").address.contains("Icon_Mana_Color_01"));
+    assert!(icon.text_color.is_none());
+}
+
+/// Finds the top left (mana cost) icon of the first card with one in the
+/// provided response.
+fn mana_icon(response: &GameResponse) -> Option<CardIcon> {
+    response.command_list.commands.iter().find_map(|c| match &c.command {
+        Some(Command::UpdateGameView(update)) => update
+            .game
+            .as_ref()?
+            .cards
+            .iter()
+            .find_map(|card| card.card_icons.as_ref()?.top_left_icon.clone()),
+        _ => None,
+    })
+}
@@ -0,0 +1,106 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::node_diff;
+use data::card_name::CardName;
+use data::primitives::{RoomId, Side};
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::node_patch::PatchType;
+use protos::spelldawn::{CommandList, InterfaceMainControls, Node};
+use test_utils::client_interface::HasText;
+use test_utils::*;
+
+fn node(name: &str, children: Vec<Node>) -> Node {
+    Node { name: name.to_string(), children, ..Node::default() }
+}
+
+fn large_tree() -> Node {
+    node("Root", (0..10).map(|i| node(&format!("Row{}", i), vec![node("Cell", vec![])])).collect())
+}
+
+fn main_controls(commands: &CommandList) -> Option<InterfaceMainControls> {
+    commands.commands.iter().rev().find_map(|c| match &c.command {
+        Some(Command::UpdateGameView(update)) => update.game.as_ref()?.main_controls.clone(),
+        _ => None,
+    })
+}
+
+#[test]
+fn identical_trees() {
+    assert_eq!(Some(vec![]), node_diff::diff(&large_tree(), &large_tree()));
+}
+
+#[test]
+fn replace_changed_descendant() {
+    let old = large_tree();
+    let mut new = large_tree();
+    new.children[3].children[0].name = "Changed".to_string();
+    let patches = node_diff::diff(&old, &new).expect("patches");
+    assert_eq!(1, patches.len());
+    assert_eq!(vec![3, 0], patches[0].path);
+
+    let mut patched = old;
+    node_diff::apply(&mut patched, &patches).unwrap();
+    assert_eq!(new, patched);
+}
+
+#[test]
+fn add_and_remove_children() {
+    let old = large_tree();
+    let mut new = large_tree();
+    new.children.truncate(6);
+    new.children[2].children.push(node("Extra", vec![]));
+    let patches = node_diff::diff(&old, &new).expect("patches");
+    assert!(matches!(patches[0].patch_type, Some(PatchType::AppendChild(_))));
+    assert!(matches!(patches[1].patch_type, Some(PatchType::TruncateChildren(6))));
+
+    let mut patched = old;
+    node_diff::apply(&mut patched, &patches).unwrap();
+    assert_eq!(new, patched);
+}
+
+#[test]
+fn send_full_node_when_smaller() {
+    let mut new = large_tree();
+    new.name = "NewRoot".to_string();
+    assert_eq!(None, node_diff::diff(&large_tree(), &new));
+}
+
+#[test]
+fn patch_main_controls() {
+    let mut g = new_game(
+        Side::Champion,
+        Args {
+            turn: Some(Side::Overlord),
+            actions: 1,
+            opponent_deck_top: Some(CardName::TestScheme31),
+            ..Args::default()
+        },
+    );
+
+    g.play_with_target_room(CardName::TestMinionEndRaid, RoomId::Vault);
+    g.play_from_hand(CardName::TestWeapon3Attack12Boost3Cost);
+    g.initiate_raid(RoomId::Vault);
+    let response = g.click_on(g.user_id(), "Test Weapon");
+    let controls = main_controls(&response.command_list).expect("main controls");
+    assert!(controls.node.is_none());
+    assert!(controls.node_patches.is_some());
+    assert!(g.user.interface.controls().has_text("Score"));
+
+    let patched = g.user.interface.main_controls().clone();
+    let commands = g.connect(g.user_id()).unwrap();
+    let controls = main_controls(&commands).expect("main controls");
+    assert!(controls.node_patches.is_none());
+    assert_eq!(Some(patched), controls.node);
+}
@@ -0,0 +1,94 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use card_helpers::{on_cast, simple_ability, text};
+use cards::test_cards::ARTIFACT_COST;
+use core_ui::icons;
+use data::card_name::CardName;
+use data::game::ManaPool;
+use data::primitives::{RaidId, Side};
+use rules::mana;
+use rules::mana::ManaPurpose;
+use test_utils::test_card_builder::TestCardBuilder;
+use test_utils::*;
+
+/// Spell which grants 3 mana that can only be spent on artifacts.
+fn gain_artifact_mana() -> CardName {
+    TestCardBuilder::champion_spell()
+        .mana_cost(0)
+        .ability(simple_ability(
+            text!["Gain 3 mana to spend on artifacts."],
+            on_cast(|g, s, _| {
+                mana::add_to_pool(g, s.side(), ManaPool::Artifacts, 3);
+                Ok(())
+            }),
+        ))
+        .build()
+}
+
+#[test]
+fn pool_mana_displayed_with_icon() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.play_from_hand(gain_artifact_mana());
+    assert_eq!(STARTING_MANA, g.me().mana());
+    assert_eq!(3, g.user.this_player.bonus_mana());
+    let pools = g.user.this_player.mana_pools();
+    assert_eq!(1, pools.len());
+    assert_eq!(3, pools[0].mana);
+    assert_eq!(icons::ARTIFACT, pools[0].icon);
+    assert_eq!(3, g.opponent.other_player.bonus_mana());
+}
+
+#[test]
+fn artifact_mana_spent_before_base_mana() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.play_from_hand(gain_artifact_mana());
+    g.play_from_hand(CardName::TestActivatedAbilityCharges);
+    assert_eq!(STARTING_MANA, g.me().mana());
+    assert_eq!(3 - ARTIFACT_COST, g.user.this_player.bonus_mana());
+}
+
+#[test]
+fn artifact_mana_not_used_for_other_cards() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.play_from_hand(gain_artifact_mana());
+    g.play_from_hand(CardName::Test1CostChampionSpell);
+    assert_eq!(STARTING_MANA - 1, g.me().mana());
+    assert_eq!(3, g.user.this_player.bonus_mana());
+}
+
+#[test]
+fn raid_pool_only_usable_during_raid() {
+    let g = new_game(Side::Champion, Args::default());
+    let mut game = g.game().clone();
+    mana::add_to_pool(&mut game, Side::Champion, ManaPool::AnyRaid, 2);
+    assert_eq!(STARTING_MANA, mana::get(&game, Side::Champion, ManaPurpose::BaseMana));
+    assert_eq!(
+        STARTING_MANA,
+        mana::get(&game, Side::Champion, ManaPurpose::PayForTriggeredAbility)
+    );
+    assert_eq!(STARTING_MANA + 2, mana::get(&game, Side::Champion, ManaPurpose::AllSources));
+}
+
+#[test]
+fn new_raid_pool_replaces_previous_raid_pool() {
+    let g = new_game(Side::Champion, Args::default());
+    let mut game = g.game().clone();
+    mana::add_to_pool(&mut game, Side::Champion, ManaPool::Raid(RaidId(1)), 2);
+    mana::add_to_pool(&mut game, Side::Champion, ManaPool::Raid(RaidId(2)), 3);
+    mana::add_to_pool(&mut game, Side::Champion, ManaPool::Raid(RaidId(2)), 1);
+    let pools = &game.player(Side::Champion).mana_state.pools;
+    assert_eq!(1, pools.len());
+    assert_eq!(Some(&4), pools.get(&ManaPool::Raid(RaidId(2))));
+}
@@ -0,0 +1,97 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::actions::InterfaceAction;
+use data::adventure::Coins;
+use data::card_name::CardName;
+use data::game_actions::GameAction;
+use data::primitives::Side;
+use data::quests::{Quest, QuestKind};
+use data::user_actions::UserAction;
+use panel_address::{GameOverData, PanelAddress};
+use test_utils::client_interface::HasText;
+use test_utils::*;
+
+#[test]
+fn records_match_result() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.perform(UserAction::GameAction(GameAction::Resign).as_client_action(), g.user_id());
+
+    let user = &g.player_data(g.user_id()).match_history;
+    assert_eq!(1, user.len());
+    assert!(!user[0].won);
+    assert_eq!(Side::Overlord, user[0].side);
+    assert_eq!(g.opponent_id(), user[0].opponent);
+    assert_eq!(g.game_id(), user[0].game_id);
+
+    let opponent = &g.player_data(g.opponent_id()).match_history;
+    assert_eq!(1, opponent.len());
+    assert!(opponent[0].won);
+    assert_eq!(Side::Champion, opponent[0].side);
+}
+
+#[test]
+fn records_match_summary() {
+    let mut g = new_game(
+        Side::Champion,
+        Args { in_play: vec![TestCard::new(CardName::TestScheme31)], ..Args::default() },
+    );
+    g.play_from_hand(CardName::TestChampionSpell);
+    g.initiate_raid(ROOM_ID);
+    g.click_on(g.user_id(), "Score");
+    g.click_on(g.user_id(), "End Raid");
+    g.perform(UserAction::GameAction(GameAction::Resign).as_client_action(), g.user_id());
+
+    let summary = g.player_data(g.user_id()).match_history[0].summary;
+    assert_eq!(1, summary.raids);
+    assert_eq!(1, summary.schemes_scored);
+    assert_eq!(0, summary.damage_dealt);
+    assert_eq!(1, summary.mana_spent);
+    assert_eq!(Coins(0), summary.coins_earned);
+
+    let opponent = g.player_data(g.opponent_id()).match_history[0].summary;
+    assert_eq!(1, opponent.raids);
+    assert_eq!(0, opponent.schemes_scored);
+    assert_eq!(0, opponent.mana_spent);
+}
+
+#[test]
+fn match_summary_includes_quest_rewards() {
+    let mut g = new_game(
+        Side::Overlord,
+        Args { quests: vec![Quest::new(QuestKind::WinGames, 1, Coins(75))], ..Args::default() },
+    );
+    g.perform(UserAction::GameAction(GameAction::Resign).as_client_action(), g.opponent_id());
+    assert_eq!(Coins(75), g.player_data(g.user_id()).match_history[0].summary.coins_earned);
+}
+
+#[test]
+fn game_over_panel_shows_summary() {
+    let mut g = new_game(
+        Side::Overlord,
+        Args { quests: vec![Quest::new(QuestKind::WinGames, 1, Coins(75))], ..Args::default() },
+    );
+    g.perform(UserAction::GameAction(GameAction::Resign).as_client_action(), g.opponent_id());
+    let address =
+        PanelAddress::GameOver(GameOverData { game_id: g.game_id(), winner: g.user_id() });
+    let panel = routing::render_panel(g.player_data(g.user_id()), address.into())
+        .expect("Render error")
+        .panels
+        .remove(0)
+        .node
+        .expect("Node");
+    assert!(panel.has_text("Victory"));
+    assert!(panel.has_text("Schemes Scored"));
+    assert!(panel.has_text("Coins Earned"));
+}
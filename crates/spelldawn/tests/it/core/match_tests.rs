@@ -0,0 +1,174 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use cards::{decklists, initialize};
+use core_ui::actions::InterfaceAction;
+use data::adventure::Coins;
+use data::campaign::CampaignProgress;
+use data::cosmetics::PlayerCosmetics;
+use data::crafting::Dust;
+use data::game_actions::GameAction;
+use data::player_data::{AutomationPreferences, PlayerData, PlayerSettings};
+use data::player_name::{NamedPlayer, PlayerId};
+use data::primitives::{DeckIndex, Side};
+use data::quests::QuestLog;
+use data::tutorial::TutorialData;
+use data::user_actions::{GamePreset, NewGameAction, NewGameDebugOptions, UserAction};
+use maplit::hashmap;
+use panel_address::{GameOverData, PanelAddress};
+use test_utils::client::TestSession;
+use test_utils::client_interface::HasText;
+use test_utils::fake_database::FakeDatabase;
+use test_utils::*;
+
+#[test]
+fn creates_match() {
+    let session = new_match();
+    let match_id = session.game().data.config.match_id.expect("match_id");
+    assert_eq!(Some(match_id), session.player_data(session.user_id()).current_match);
+    let state = session.match_state(match_id);
+    assert_eq!(vec![session.game_id()], state.games);
+    assert_eq!(0, state.player(session.user_id()).unwrap().wins);
+    assert!(session.user.interface.screen_overlay().has_text("Match 0 - 0"));
+}
+
+#[test]
+fn records_game_win() {
+    let mut session = new_match();
+    let match_id = session.game().data.config.match_id.expect("match_id");
+    resign(&mut session);
+    assert_eq!(1, session.match_state(match_id).player(session.opponent_id()).unwrap().wins);
+    assert!(!session.match_state(match_id).is_complete());
+    assert!(session.user.interface.screen_overlay().has_text("Match 0 - 1"));
+    assert!(game_over_panel(&session).has_text("Next Game"));
+}
+
+#[test]
+fn next_game_swaps_sides() {
+    let mut session = new_match();
+    let match_id = session.game().data.config.match_id.expect("match_id");
+    assert_eq!(session.user_id(), session.game().player(Side::Champion).id);
+    resign(&mut session);
+    session.perform(UserAction::LeaveGame.as_client_action(), session.user_id());
+
+    assert_eq!(session.user_id(), session.game().player(Side::Overlord).id);
+    assert_eq!(Some(match_id), session.game().data.config.match_id);
+    assert_eq!(2, session.match_state(match_id).games.len());
+}
+
+#[test]
+fn match_ends_after_two_wins() {
+    let mut session = new_match();
+    let match_id = session.game().data.config.match_id.expect("match_id");
+    resign(&mut session);
+    session.perform(UserAction::LeaveGame.as_client_action(), session.user_id());
+    resign(&mut session);
+
+    let state = session.match_state(match_id);
+    assert_eq!(Some(session.opponent_id()), state.winner());
+    assert_eq!(None, session.player_data(session.user_id()).current_match);
+    assert!(game_over_panel(&session).has_text("Main Menu"));
+
+    session.perform(UserAction::LeaveGame.as_client_action(), session.user_id());
+    assert!(session.player_data(session.user_id()).state.is_none());
+    assert_eq!(2, session.match_state(match_id).games.len());
+}
+
+#[test]
+fn single_game_has_no_match() {
+    let session = new_game(Side::Overlord, Args::default());
+    assert_eq!(None, session.game().data.config.match_id);
+    assert_eq!(None, session.player_data(session.user_id()).current_match);
+}
+
+fn resign(session: &mut TestSession) {
+    session.perform(GameAction::Resign.as_client_action(), session.user_id());
+}
+
+fn game_over_panel(session: &TestSession) -> protos::spelldawn::Node {
+    let address = PanelAddress::GameOver(GameOverData {
+        game_id: session.game_id(),
+        winner: session.opponent_id(),
+    });
+    routing::render_panel(session.player_data(session.user_id()), address.into())
+        .expect("Render error")
+        .panels
+        .remove(0)
+        .node
+        .expect("Node")
+}
+
+/// Starts a best-of-three match between a player with decks for both sides and
+/// an AI opponent which takes no actions.
+fn new_match() -> TestSession {
+    initialize::run();
+    let (game_id, user_id, _) = generate_ids();
+    let opponent_id = PlayerId::Named(NamedPlayer::TestNoAction);
+    let database = FakeDatabase {
+        generated_game_id: Some(game_id),
+        game: None,
+        players: hashmap! {
+            user_id => PlayerData {
+                id: user_id,
+                state: None,
+                decks: vec![
+                    decklists::canonical_deck(user_id, Side::Champion),
+                    decklists::canonical_deck(user_id, Side::Overlord),
+                ],
+                adventure: None,
+                collection: hashmap! {},
+                tutorial: TutorialData::default(),
+                cosmetics: HashSet::new(),
+                achievements: HashSet::new(),
+                settings: PlayerSettings::default(),
+                match_history: vec![],
+                completed_puzzles: HashSet::new(),
+                quests: QuestLog::default(),
+                coins: Coins::default(),
+                player_cosmetics: PlayerCosmetics::default(),
+                revision: 0,
+                notifications: vec![],
+                campaign: CampaignProgress::default(),
+                current_match: None,
+                dust: Dust::default(),
+                automation: AutomationPreferences::default(),
+            }
+        },
+        signing_key: None,
+        player_secrets: hashmap! {},
+        leaderboards: hashmap! {},
+        matches: hashmap! {},
+        ratings: hashmap! {},
+        card_statistics: hashmap! {},
+        card_positions: hashmap! {},
+    };
+
+    let mut session = TestSession::new(database, user_id, opponent_id);
+    session.perform(
+        UserAction::NewGame(NewGameAction {
+            deck_index: DeckIndex::new(0),
+            opponent: opponent_id,
+            debug_options: Some(NewGameDebugOptions {
+                deterministic: true,
+                ..NewGameDebugOptions::default()
+            }),
+            preset: GamePreset { best_of_three: true, ..GamePreset::default() },
+        })
+        .as_client_action(),
+        user_id,
+    );
+    session
+}
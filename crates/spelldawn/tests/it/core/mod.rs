@@ -12,7 +12,82 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod abandonment_tests;
+mod ability_limit_tests;
+mod access_modifier_tests;
+mod achievement_tests;
 mod action_tests;
+mod agent_log_tests;
+mod ally_tests;
+mod animation_speed_tests;
+mod auth_tests;
+mod automation_tests;
+mod bonus_action_tests;
+mod canonical_replay_tests;
+mod card_catalog_tests;
+mod card_info_tests;
+mod cli_tests;
+mod combined_weapons_tests;
+mod confirmation_tests;
+mod connection_tests;
+mod control_tests;
+mod copy_tests;
+mod cosmetics_tests;
+mod cost_adjustment_tests;
+mod crafting_tests;
 mod create_game_tests;
+mod deck_editor_drag_tests;
+mod definition_index_tests;
+mod delegate_trace_tests;
+mod destroy_tests;
+mod draft_mode_tests;
+mod durability_tests;
+mod emote_tests;
+mod encounter_ability_tests;
+mod error_code_tests;
+mod exhibition_tests;
+mod fuzz_tests;
+mod game_encoding_tests;
+mod game_modifier_tests;
+mod game_over_reveal_tests;
+mod global_modifier_tests;
+mod hotkey_tests;
+mod http_gateway_tests;
+mod icon_mode_tests;
+mod interface_diff_tests;
 mod leave_game_tests;
+mod mana_pool_tests;
+mod match_history_tests;
+mod match_tests;
+mod modal_tests;
+mod named_opponent_tests;
+mod notification_tests;
+mod onboarding_tests;
+mod overlord_scoring_tests;
+mod panel_cache_tests;
+mod puzzle_tests;
+mod quarantine_tests;
+mod quest_tests;
+mod queued_raid_tests;
+mod raid_cost_tests;
+mod raid_info_tests;
+mod raid_step_tests;
 mod raid_tests;
+mod rating_tests;
+mod reaction_tests;
+mod recording_tests;
+mod retreat_tests;
+mod scenario_tests;
+mod scheme_progress_tests;
+mod settings_tests;
+mod targeting_tests;
+mod telemetry_tests;
+mod test_card_builder_tests;
+mod trap_tests;
+mod turn_structure_tests;
+mod tutor_tests;
+mod unveil_window_tests;
+mod update_coalescing_tests;
+mod variable_cost_tests;
+mod virtual_list_tests;
+mod visibility_tests;
@@ -0,0 +1,83 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use card_helpers::{mode, on_cast, simple_ability, text};
+use data::card_name::CardName;
+use data::card_state::CardPosition;
+use data::game_actions::{CardPromptAction, CardTarget, GameAction, PromptAction};
+use data::primitives::{AbilityId, Side};
+use rules::{card_prompt, mana};
+use test_utils::client_interface::HasText;
+use test_utils::test_card_builder::TestCardBuilder;
+use test_utils::*;
+
+#[test]
+fn modal_card_waits_for_mode_choice() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let id = g.play_from_hand(CardName::Crossroads);
+    let card_id = server_card_id(id);
+    assert_eq!(
+        CardPosition::Played(Side::Champion, CardTarget::None),
+        g.game().card(card_id).position()
+    );
+    assert_eq!(
+        vec![choose_mode(AbilityId::new(card_id, 0)), choose_mode(AbilityId::new(card_id, 1)),],
+        g.legal_actions(Side::Champion)
+    );
+    assert!(g.user.interface.controls().has_text("Choose one"));
+}
+
+#[test]
+fn chosen_mode_recorded_in_play_event() {
+    let spell = TestCardBuilder::champion_spell()
+        .ability(mode("First", text!["Gain 1 mana"], |g, s, _| {
+            mana::gain(g, s.side(), 1);
+            Ok(())
+        }))
+        .ability(mode("Second", text!["Gain 2 mana"], |g, s, _| {
+            mana::gain(g, s.side(), 2);
+            Ok(())
+        }))
+        .ability(simple_ability(
+            text!["Gain 10 mana per mode index"],
+            on_cast(|g, s, played| {
+                let index = played.mode.expect("mode").index.value() as u32;
+                mana::gain(g, s.side(), 10 * index);
+                Ok(())
+            }),
+        ))
+        .build();
+    let mut g = new_game(Side::Champion, Args::default());
+    g.play_from_hand(spell);
+    let mana = g.me().mana();
+    g.click_on(g.user_id(), "Second");
+    assert_eq!(mana + 2 + 10, g.me().mana());
+}
+
+#[test]
+fn cannot_choose_mode_of_card_not_being_played() {
+    let g = new_game(Side::Champion, Args { hand: vec![CardName::Crossroads], ..Args::default() });
+    let mut game = g.game().clone();
+    let card_id =
+        game.hand(Side::Champion).find(|c| c.name == CardName::Crossroads).expect("card").id;
+    let action = CardPromptAction::ChooseMode(AbilityId::new(card_id, 0), CardTarget::None);
+    assert!(card_prompt::handle(&mut game, Side::Champion, action).is_err());
+}
+
+fn choose_mode(mode: AbilityId) -> GameAction {
+    GameAction::PromptAction(PromptAction::CardAction(CardPromptAction::ChooseMode(
+        mode,
+        CardTarget::None,
+    )))
+}
@@ -0,0 +1,126 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use cards::{decklists, initialize};
+use core_ui::actions::InterfaceAction;
+use data::adventure::Coins;
+use data::campaign::CampaignProgress;
+use data::card_name::CardName;
+use data::cosmetics::PlayerCosmetics;
+use data::crafting::Dust;
+use data::player_data::{AutomationPreferences, PlayerData, PlayerSettings};
+use data::player_name::{NamedPlayer, PlayerId};
+use data::primitives::{DeckIndex, Side};
+use data::quests::QuestLog;
+use data::tutorial::TutorialData;
+use data::user_actions::{GamePreset, NewGameAction, NewGameDebugOptions, UserAction};
+use maplit::hashmap;
+use test_utils::client::TestSession;
+use test_utils::client_interface::HasText;
+use test_utils::fake_database::FakeDatabase;
+use test_utils::*;
+
+#[test]
+fn boss_decks_use_custom_identities() {
+    initialize::run();
+    let warden = decklists::deck_for_player(NamedPlayer::AdventureBossWarden, Side::Overlord);
+    assert_eq!(CardName::TheWarden, warden.identity);
+    assert_eq!(PlayerId::Named(NamedPlayer::AdventureBossWarden), warden.owner_id);
+    let raider = decklists::deck_for_player(NamedPlayer::AdventureBossRaider, Side::Champion);
+    assert_eq!(CardName::TheRaider, raider.identity);
+    assert_eq!(warden.cards.values().sum::<u32>(), raider.cards.values().sum::<u32>());
+}
+
+#[test]
+fn other_players_use_canonical_deck() {
+    initialize::run();
+    let deck = decklists::deck_for_player(NamedPlayer::AdventureBossWarden, Side::Champion);
+    assert_eq!(decklists::CANONICAL_CHAMPION.cards, deck.cards);
+    assert_eq!(PlayerId::Named(NamedPlayer::AdventureBossWarden), deck.owner_id);
+}
+
+#[test]
+fn intro_displayed_against_named_opponent() {
+    let session = new_game_against(NamedPlayer::AdventureBossWarden);
+    assert!(session.user.interface.top_panel().has_text("Adventure Boss Warden"));
+    assert!(session.user.interface.top_panel().has_text("guarded these vaults"));
+    let identity = session.game().first_identity(Side::Overlord).expect("identity");
+    assert_eq!(CardName::TheWarden, session.game().card(identity).name);
+    assert!(session.user.interface.top_panel().find_handlers("Begin").is_some());
+}
+
+#[test]
+fn no_intro_for_test_opponent() {
+    let session = new_game_against(NamedPlayer::TestNoAction);
+    assert_eq!(0, session.user.interface.panel_count());
+}
+
+fn new_game_against(opponent: NamedPlayer) -> TestSession {
+    initialize::run();
+    let (game_id, user_id, _) = generate_ids();
+    let opponent_id = PlayerId::Named(opponent);
+    let database = FakeDatabase {
+        generated_game_id: Some(game_id),
+        game: None,
+        players: hashmap! {
+            user_id => PlayerData {
+                id: user_id,
+                state: None,
+                decks: vec![decklists::canonical_deck(user_id, Side::Champion)],
+                adventure: None,
+                collection: hashmap! {},
+                tutorial: TutorialData::default(),
+                cosmetics: HashSet::new(),
+                achievements: HashSet::new(),
+                settings: PlayerSettings::default(),
+                match_history: vec![],
+                completed_puzzles: HashSet::new(),
+                quests: QuestLog::default(),
+                coins: Coins::default(),
+                player_cosmetics: PlayerCosmetics::default(),
+                revision: 0,
+                notifications: vec![],
+                campaign: CampaignProgress::default(),
+                current_match: None,
+                dust: Dust::default(),
+                automation: AutomationPreferences::default(),
+            }
+        },
+        signing_key: None,
+        player_secrets: hashmap! {},
+        leaderboards: hashmap! {},
+        matches: hashmap! {},
+        ratings: hashmap! {},
+        card_statistics: hashmap! {},
+        card_positions: hashmap! {},
+    };
+
+    let mut session = TestSession::new(database, user_id, opponent_id);
+    session.perform(
+        UserAction::NewGame(NewGameAction {
+            deck_index: DeckIndex::new(0),
+            opponent: opponent_id,
+            debug_options: Some(NewGameDebugOptions {
+                deterministic: true,
+                ..NewGameDebugOptions::default()
+            }),
+            preset: GamePreset::default(),
+        })
+        .as_client_action(),
+        user_id,
+    );
+    session
+}
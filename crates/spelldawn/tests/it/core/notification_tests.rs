@@ -0,0 +1,74 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::actions::InterfaceAction;
+use data::adventure::Coins;
+use data::game_actions::GameAction;
+use data::notification::Notification;
+use data::primitives::Side;
+use data::quests::{Quest, QuestKind};
+use data::user_actions::UserAction;
+use test_utils::client_interface::HasText;
+use test_utils::*;
+
+#[test]
+fn concede_notifies_opponent() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.perform(UserAction::Concede.as_client_action(), g.user_id());
+    assert!(g.opponent.interface.toasts().iter().any(|t| t.has_text("Opponent Conceded")));
+    assert!(!g.user.interface.toasts().iter().any(|t| t.has_text("Opponent Conceded")));
+    assert!(g.player_data(g.opponent_id()).notifications.is_empty());
+}
+
+#[test]
+fn concede_queues_notification_for_disconnected_opponent() {
+    let mut g =
+        new_game(Side::Overlord, Args { disconnect_grace_period: Some(60), ..Args::default() });
+    g.disconnect(g.opponent_id(), 1000).unwrap();
+    g.perform(UserAction::Concede.as_client_action(), g.user_id());
+    assert_eq!(vec![Notification::OpponentConceded], g.player_data(g.opponent_id()).notifications);
+}
+
+#[test]
+fn queued_notifications_delivered_on_connect() {
+    let mut g =
+        new_game(Side::Overlord, Args { disconnect_grace_period: Some(60), ..Args::default() });
+    g.disconnect(g.opponent_id(), 1000).unwrap();
+    g.perform(UserAction::Concede.as_client_action(), g.user_id());
+    g.connect(g.opponent_id()).unwrap();
+    assert!(g.opponent.interface.toasts().iter().any(|t| t.has_text("Opponent Conceded")));
+    assert!(g.player_data(g.opponent_id()).notifications.is_empty());
+}
+
+#[test]
+fn quest_completed_while_disconnected() {
+    let mut g = new_game(
+        Side::Overlord,
+        Args {
+            disconnect_grace_period: Some(60),
+            quests: vec![Quest::new(QuestKind::WinGames, 1, Coins(75))],
+            ..Args::default()
+        },
+    );
+    g.disconnect(g.user_id(), 1000).unwrap();
+    g.perform(UserAction::GameAction(GameAction::Resign).as_client_action(), g.opponent_id());
+    assert!(matches!(
+        g.player_data(g.user_id()).notifications[..],
+        [Notification::QuestCompleted(quest)] if quest.reward == Coins(75)
+    ));
+
+    g.connect(g.user_id()).unwrap();
+    assert!(g.user.interface.toasts().iter().any(|t| t.has_text("Quest Complete")));
+    assert!(g.player_data(g.user_id()).notifications.is_empty());
+}
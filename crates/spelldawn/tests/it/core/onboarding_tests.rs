@@ -0,0 +1,121 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::panels;
+use data::player_data::PlayerData;
+use data::player_name::{NamedPlayer, PlayerId};
+use data::primitives::Side;
+use data::tutorial::TutorialFlag;
+use panel_address::PanelAddress;
+use protos::spelldawn::game_command::Command;
+use server::onboarding;
+use test_utils::client::TestSession;
+use test_utils::fake_database::FakeDatabase;
+use test_utils::*;
+
+#[test]
+fn new_player_receives_starter_decks() {
+    let session = connect_new_player();
+    let player = session.player_data(session.user_id());
+    assert_eq!(2, player.decks.len());
+    assert_eq!(Side::Overlord, player.decks[0].side);
+    assert_eq!(Side::Champion, player.decks[1].side);
+    for deck in &player.decks {
+        assert_eq!(session.user_id(), deck.owner_id);
+        for (card, count) in &deck.cards {
+            assert!(player.owned_copies(*card) >= *count, "Missing {card:?}");
+        }
+    }
+    assert!(player.tutorial.has_flag(TutorialFlag::ReceivedStarterCollection));
+}
+
+#[test]
+fn new_player_starts_tutorial_adventure() {
+    let session = connect_new_player();
+    let player = session.player_data(session.user_id());
+    let adventure = player.adventure.as_ref().expect("adventure");
+    assert_eq!(onboarding::TUTORIAL_SIDE, adventure.side);
+    assert_eq!(None, adventure.config.seed);
+    assert!(player.tutorial.has_flag(TutorialFlag::StartedTutorialAdventure));
+}
+
+#[test]
+fn new_player_connect_loads_world_scene() {
+    let (mut session, user_id) = new_session();
+    let response = session.connect(user_id).expect("Connection error");
+    assert!(response.commands.iter().any(|c| matches!(
+        &c.command,
+        Some(Command::LoadScene(load)) if load.scene_name == "World"
+    )));
+    assert!(response
+        .commands
+        .iter()
+        .any(|c| matches!(&c.command, Some(Command::UpdateWorldMap(_)))));
+    let disclaimer = panels::open(PanelAddress::Disclaimer);
+    assert!(response.commands.iter().any(|c| c.command.as_ref() == Some(&disclaimer)));
+}
+
+#[test]
+fn tutorial_adventure_is_deterministic() {
+    let first = connect_new_player();
+    let second = connect_new_player();
+    let tiles = |session: &TestSession| {
+        let adventure = session.player_data(session.user_id()).adventure.clone().unwrap();
+        let mut tiles = adventure
+            .tiles
+            .iter()
+            .map(|(position, tile)| format!("{position:?}{:?}", tile.entity))
+            .collect::<Vec<_>>();
+        tiles.sort();
+        tiles
+    };
+    assert_eq!(tiles(&first), tiles(&second));
+}
+
+#[test]
+fn onboarding_is_not_repeated() {
+    let mut player = PlayerData::new(PlayerId::Database(1));
+    onboarding::onboard(&mut player);
+    player.adventure = None;
+    let collection = player.collection.clone();
+    onboarding::onboard(&mut player);
+    assert_eq!(2, player.decks.len());
+    assert_eq!(collection, player.collection);
+    assert!(player.adventure.is_none());
+}
+
+#[test]
+fn existing_player_is_not_onboarded() {
+    let mut session = new_game(Side::Overlord, Args::default());
+    session.connect(session.user_id()).expect("Connection error");
+    let player = session.player_data(session.user_id());
+    assert!(player.decks.is_empty());
+    assert!(player.adventure.is_none());
+}
+
+fn new_session() -> (TestSession, PlayerId) {
+    let (_, user_id, _) = generate_ids();
+    let session = TestSession::new(
+        FakeDatabase::default(),
+        user_id,
+        PlayerId::Named(NamedPlayer::TestNoAction),
+    );
+    (session, user_id)
+}
+
+fn connect_new_player() -> TestSession {
+    let (mut session, user_id) = new_session();
+    session.connect(user_id).expect("Connection error");
+    session
+}
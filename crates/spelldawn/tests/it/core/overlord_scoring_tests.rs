@@ -0,0 +1,96 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use card_helpers::{on_overlord_score, simple_ability, text};
+use data::card_name::CardName;
+use data::card_state::CardPosition;
+use data::primitives::Side;
+use protos::spelldawn::ClientRoomLocation;
+use rules::{flags, mana};
+use test_utils::test_card_builder::TestCardBuilder;
+use test_utils::*;
+
+#[test]
+fn scheme_at_level_requirement_scored_at_start_of_turn() {
+    cards::initialize::run();
+    let mut g = new_game(
+        Side::Champion,
+        Args {
+            in_play: vec![TestCard::new(CardName::TestScheme31).card_level(3)],
+            ..Args::default()
+        },
+    );
+    assert_eq!(0, g.opponent.this_player.score());
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    assert_eq!(1, g.opponent.this_player.score());
+    assert_eq!(1, g.user.other_player.score());
+    assert!(g.user.cards.room_cards(ROOM_ID, ClientRoomLocation::Back).is_empty());
+}
+
+#[test]
+fn scheme_below_level_requirement_not_scored() {
+    cards::initialize::run();
+    let mut g = new_game(
+        Side::Champion,
+        Args {
+            in_play: vec![TestCard::new(CardName::TestScheme31).card_level(2)],
+            ..Args::default()
+        },
+    );
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    assert_eq!(0, g.opponent.this_player.score());
+    assert_eq!(1, g.user.cards.room_cards(ROOM_ID, ClientRoomLocation::Back).len());
+}
+
+#[test]
+fn cannot_score_scheme_from_hand() {
+    cards::initialize::run();
+    let g = new_game(
+        Side::Champion,
+        Args {
+            in_play: vec![TestCard::new(CardName::TestScheme31).card_level(3)],
+            ..Args::default()
+        },
+    );
+    let mut game = g.game().clone();
+    let card_id = game
+        .cards(Side::Overlord)
+        .iter()
+        .find(|c| c.name == CardName::TestScheme31)
+        .expect("scheme")
+        .id;
+    assert!(flags::can_overlord_score_card(&game, card_id));
+    game.move_card_internal(card_id, CardPosition::Hand(Side::Overlord));
+    assert!(!flags::can_overlord_score_card(&game, card_id));
+}
+
+#[test]
+fn start_of_turn_scoring_fires_score_event() {
+    let scheme = TestCardBuilder::scheme()
+        .scheme_points(1, 1)
+        .ability(simple_ability(
+            text!["When you score this scheme, gain 3 mana."],
+            on_overlord_score(|g, s, _| {
+                mana::gain(g, s.side(), 3);
+                Ok(())
+            }),
+        ))
+        .build();
+    let mut g = new_game(
+        Side::Champion,
+        Args { in_play: vec![TestCard::new(scheme).card_level(1)], ..Args::default() },
+    );
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    assert_eq!(STARTING_MANA + 3, g.opponent.this_player.mana());
+}
@@ -0,0 +1,85 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::actions::InterfaceAction;
+use data::leaderboard::RunSeed;
+use data::player_data::PlayerData;
+use data::primitives::Side;
+use data::user_actions::UserAction;
+use panel_address::{PanelAddress, PanelDependency};
+use protos::spelldawn::UpdatePanelsCommand;
+use test_utils::*;
+
+fn render_settings(player: &PlayerData) -> UpdatePanelsCommand {
+    routing::render_panel(player, PanelAddress::Settings.into()).expect("Render error")
+}
+
+fn new_player() -> PlayerData {
+    let (_, player_id, _) = generate_ids();
+    PlayerData::new(player_id)
+}
+
+#[test]
+fn reuses_panel_for_same_revision() {
+    let mut player = new_player();
+    let rendered = render_settings(&player);
+    player.settings.mute_audio = !player.settings.mute_audio;
+    assert_eq!(rendered, render_settings(&player));
+}
+
+#[test]
+fn rerenders_panel_for_new_revision() {
+    let mut player = new_player();
+    let rendered = render_settings(&player);
+    player.settings.mute_audio = !player.settings.mute_audio;
+    player.revision += 1;
+    assert_ne!(rendered, render_settings(&player));
+}
+
+#[test]
+fn invalidate_discards_cached_panels() {
+    let mut player = new_player();
+    let rendered = render_settings(&player);
+    player.settings.mute_audio = !player.settings.mute_audio;
+    routing::invalidate(player.id);
+    assert_ne!(rendered, render_settings(&player));
+}
+
+#[test]
+fn disconnect_discards_cached_panels() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let mut player = g.player_data(g.user_id()).clone();
+    let rendered = render_settings(&player);
+    player.settings.mute_audio = !player.settings.mute_audio;
+    g.disconnect(g.user_id(), 1000).unwrap();
+    assert_ne!(rendered, render_settings(&player));
+}
+
+#[test]
+fn player_action_increments_revision() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let revision = g.player_data(g.user_id()).revision;
+    g.perform(UserAction::SetMuteAudio(true).as_client_action(), g.user_id());
+    assert_eq!(revision + 1, g.player_data(g.user_id()).revision);
+}
+
+#[test]
+fn game_panels_are_not_cached() {
+    assert_eq!(PanelDependency::Game, PanelAddress::RaidInfo.dependency());
+    assert_eq!(PanelDependency::Game, PanelAddress::AgentLog.dependency());
+    assert_eq!(PanelDependency::None, PanelAddress::MainMenu.dependency());
+    assert_eq!(PanelDependency::Player, PanelAddress::Settings.dependency());
+    assert_eq!(PanelDependency::Server, PanelAddress::Leaderboard(RunSeed::Daily).dependency());
+    assert_eq!(PanelDependency::Server, PanelAddress::Rankings(0).dependency());
+}
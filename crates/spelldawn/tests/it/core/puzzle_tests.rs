@@ -0,0 +1,121 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use cards::initialize;
+use core_ui::actions::InterfaceAction;
+use data::adventure::Coins;
+use data::campaign::CampaignProgress;
+use data::cosmetics::PlayerCosmetics;
+use data::crafting::Dust;
+use data::player_data::{AutomationPreferences, PlayerData, PlayerSettings};
+use data::player_name::{NamedPlayer, PlayerId};
+use data::puzzles::PuzzleId;
+use data::quests::QuestLog;
+use data::tutorial::TutorialData;
+use data::user_actions::UserAction;
+use maplit::hashmap;
+use protos::spelldawn::client_action::Action;
+use protos::spelldawn::{DrawCardAction, LevelUpRoomAction, SpendActionPointAction};
+use test_utils::client::TestSession;
+use test_utils::fake_database::FakeDatabase;
+use test_utils::*;
+
+#[test]
+fn solve_final_scheme() {
+    let mut session = start_puzzle(PuzzleId::FinalScheme);
+    let level_up = Action::LevelUpRoom(LevelUpRoomAction { room_id: CLIENT_ROOM_ID.into() });
+    session.perform(level_up.clone(), session.user_id());
+    assert!(session.player_data(session.user_id()).completed_puzzles.is_empty());
+    session.perform(level_up, session.user_id());
+
+    let player = session.player_data(session.user_id());
+    assert!(player.completed_puzzles.contains(&PuzzleId::FinalScheme));
+    assert!(player.match_history[0].won);
+}
+
+#[test]
+fn fail_final_scheme() {
+    let mut session = start_puzzle(PuzzleId::FinalScheme);
+    session.perform(Action::SpendActionPoint(SpendActionPointAction {}), session.user_id());
+    session.perform(Action::SpendActionPoint(SpendActionPointAction {}), session.user_id());
+
+    let player = session.player_data(session.user_id());
+    assert!(player.completed_puzzles.is_empty());
+    assert!(!player.match_history[0].won);
+}
+
+#[test]
+fn solve_desperate_raid() {
+    let mut session = start_puzzle(PuzzleId::DesperateRaid);
+    session.initiate_raid(ROOM_ID);
+    session.click_on(session.user_id(), "Score");
+    assert!(session
+        .player_data(session.user_id())
+        .completed_puzzles
+        .contains(&PuzzleId::DesperateRaid));
+}
+
+#[test]
+fn desperate_raid_cannot_draw() {
+    let mut session = start_puzzle(PuzzleId::DesperateRaid);
+    assert!(session
+        .perform_action(Action::DrawCard(DrawCardAction {}), session.user_id())
+        .is_err());
+}
+
+fn start_puzzle(puzzle: PuzzleId) -> TestSession {
+    initialize::run();
+    let (game_id, user_id, _) = generate_ids();
+    let database = FakeDatabase {
+        generated_game_id: Some(game_id),
+        game: None,
+        players: hashmap! {
+            user_id => PlayerData {
+                id: user_id,
+                state: None,
+                decks: vec![],
+                adventure: None,
+                collection: hashmap! {},
+                tutorial: TutorialData::default(),
+                cosmetics: HashSet::new(),
+                achievements: HashSet::new(),
+                settings: PlayerSettings::default(),
+                match_history: vec![],
+                completed_puzzles: HashSet::new(),
+                quests: QuestLog::default(),
+                coins: Coins::default(),
+                player_cosmetics: PlayerCosmetics::default(),
+                revision: 0,
+                notifications: vec![],
+                campaign: CampaignProgress::default(),
+                current_match: None,
+                dust: Dust::default(),
+                automation: AutomationPreferences::default(),
+            }
+        },
+        signing_key: None,
+        player_secrets: hashmap! {},
+        leaderboards: hashmap! {},
+        matches: hashmap! {},
+        ratings: hashmap! {},
+        card_statistics: hashmap! {},
+        card_positions: hashmap! {},
+    };
+    let mut session =
+        TestSession::new(database, user_id, PlayerId::Named(NamedPlayer::TestNoAction));
+    session.perform(UserAction::StartPuzzle(puzzle).as_client_action(), user_id);
+    session
+}
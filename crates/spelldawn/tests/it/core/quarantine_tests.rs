@@ -0,0 +1,96 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use card_helpers::{simple_ability, text};
+use core_ui::actions::InterfaceAction;
+use data::delegates::{Delegate, QueryDelegate};
+use data::primitives::Side;
+use data::user_actions::DebugAction;
+use protos::spelldawn::client_action::Action;
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::{CommandList, DrawCardAction};
+use server::quarantine;
+use test_utils::test_card_builder::TestCardBuilder;
+use test_utils::*;
+
+/// Causes the card created in [panic_on_connect_quarantines_game] to panic
+/// when its mana cost is queried.
+static PANIC_ON_RENDER: AtomicBool = AtomicBool::new(false);
+
+#[test]
+fn panic_quarantines_game() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.perform(DebugAction::Panic.as_client_action(), g.user_id());
+    assert_eq!(Some("Debug panic".to_string()), g.game().data.quarantined);
+    assert!(g.player_data(g.user_id()).state.is_none());
+    assert!(g.player_data(g.opponent_id()).state.is_none());
+}
+
+#[test]
+fn panic_returns_players_to_main_menu() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let response = g.perform_action(DebugAction::Panic.as_client_action(), g.user_id()).unwrap();
+    assert!(loads_main_scene(&response.command_list));
+    let (opponent_id, opponent_commands) = response.opponent_response.expect("opponent response");
+    assert_eq!(g.opponent_id(), opponent_id);
+    assert!(loads_main_scene(&opponent_commands));
+}
+
+#[test]
+fn game_actions_fail_after_quarantine() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.perform(DebugAction::Panic.as_client_action(), g.user_id());
+    assert!(g.perform_action(Action::DrawCard(DrawCardAction {}), g.user_id()).is_err());
+}
+
+#[test]
+fn panic_on_connect_quarantines_game() {
+    let card = TestCardBuilder::champion_spell()
+        .ability(simple_ability(
+            text!["Panics while rendering"],
+            Delegate::ManaCost(QueryDelegate {
+                requirement: |_, _, _| {
+                    assert!(!PANIC_ON_RENDER.load(Ordering::SeqCst), "Render panic");
+                    false
+                },
+                transformation: |_, _, _, cost| cost,
+            }),
+        ))
+        .build();
+    let mut g = new_game(Side::Champion, Args::default());
+    g.add_to_hand(card);
+
+    PANIC_ON_RENDER.store(true, Ordering::SeqCst);
+    let commands = g.connect(g.user_id());
+    PANIC_ON_RENDER.store(false, Ordering::SeqCst);
+    assert!(loads_main_scene(&commands.unwrap()));
+    assert_eq!(Some("Render panic".to_string()), g.game().data.quarantined);
+    assert!(g.player_data(g.opponent_id()).state.is_none());
+}
+
+#[test]
+fn panic_message() {
+    assert_eq!("boom", quarantine::panic_message(&"boom"));
+    assert_eq!("boom", quarantine::panic_message(&"boom".to_string()));
+    assert_eq!("Unknown panic", quarantine::panic_message(&5));
+}
+
+fn loads_main_scene(commands: &CommandList) -> bool {
+    commands
+        .commands
+        .iter()
+        .any(|c| matches!(&c.command, Some(Command::LoadScene(load)) if load.scene_name == "Main"))
+}
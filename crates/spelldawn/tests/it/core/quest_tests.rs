@@ -0,0 +1,81 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::actions::InterfaceAction;
+use data::adventure::Coins;
+use data::card_name::CardName;
+use data::game_actions::GameAction;
+use data::primitives::Side;
+use data::quests;
+use data::quests::{Quest, QuestKind, DAILY_QUEST_COUNT};
+use data::user_actions::UserAction;
+use test_utils::client_interface::HasText;
+use test_utils::*;
+
+#[test]
+fn win_game_completes_quest() {
+    let mut g = new_game(
+        Side::Overlord,
+        Args { quests: vec![Quest::new(QuestKind::WinGames, 1, Coins(75))], ..Args::default() },
+    );
+    g.perform(UserAction::GameAction(GameAction::Resign).as_client_action(), g.opponent_id());
+
+    let player = g.player_data(g.user_id());
+    assert!(player.quests.quests[0].is_complete());
+    assert_eq!(Coins(75), player.coins);
+    assert!(g.user.interface.toasts().iter().any(|toast| toast.has_text("Quest Complete")));
+}
+
+#[test]
+fn partial_quest_progress() {
+    let mut g = new_game(
+        Side::Overlord,
+        Args { quests: vec![Quest::new(QuestKind::WinGames, 2, Coins(75))], ..Args::default() },
+    );
+    g.perform(UserAction::GameAction(GameAction::Resign).as_client_action(), g.opponent_id());
+
+    let player = g.player_data(g.user_id());
+    assert_eq!(1, player.quests.quests[0].progress);
+    assert!(!player.quests.quests[0].is_complete());
+    assert_eq!(Coins(0), player.coins);
+    assert!(!g.user.interface.toasts().iter().any(|toast| toast.has_text("Quest Complete")));
+}
+
+#[test]
+fn raid_quest_counts_successful_raids() {
+    let mut g = new_game(
+        Side::Champion,
+        Args {
+            in_play: vec![TestCard::new(CardName::TestScheme31)],
+            quests: vec![Quest::new(QuestKind::WinRaids, 1, Coins(50))],
+            ..Args::default()
+        },
+    );
+    g.initiate_raid(ROOM_ID);
+    g.click_on(g.user_id(), "Score");
+    g.click_on(g.user_id(), "End Raid");
+    g.perform(UserAction::GameAction(GameAction::Resign).as_client_action(), g.user_id());
+
+    let player = g.player_data(g.user_id());
+    assert!(player.quests.quests[0].is_complete());
+    assert_eq!(Coins(50), player.coins);
+}
+
+#[test]
+fn generate_daily_quests() {
+    let generated = quests::generate(1234);
+    assert_eq!(DAILY_QUEST_COUNT, generated.len());
+    assert_eq!(generated, quests::generate(1234));
+    assert!(generated.iter().all(|quest| quest.progress == 0));
+}
@@ -0,0 +1,72 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use card_helpers::{initiate_raid, on_cast, on_raid_start, simple_ability, text};
+use data::card_name::CardName;
+use data::game_actions::CardTarget;
+use data::primitives::{RoomId, Side};
+use rules::{mana, mutations};
+use test_utils::test_card_builder::TestCardBuilder;
+use test_utils::*;
+
+/// Spell which raids the vault and then the sanctum, gaining 1 mana when the
+/// sanctum raid begins.
+fn raid_vault_then_sanctum() -> CardName {
+    TestCardBuilder::champion_spell()
+        .ability(simple_ability(
+            text!["Raid the Vault, then raid the Sanctum."],
+            on_cast(|g, s, _| {
+                mutations::queue_raid(g, RoomId::Sanctum);
+                initiate_raid(g, s, CardTarget::Room(RoomId::Vault))
+            }),
+        ))
+        .ability(simple_ability(
+            text!["When you raid the Sanctum, gain 1 mana."],
+            on_raid_start(
+                |_, _, raid| raid.target == RoomId::Sanctum,
+                |g, _, _| {
+                    mana::gain(g, Side::Champion, 1);
+                    Ok(())
+                },
+            ),
+        ))
+        .build()
+}
+
+#[test]
+fn queued_raid_starts_after_first_raid() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let spell = raid_vault_then_sanctum();
+    g.play_from_hand(spell);
+    assert!(g.user.data.raid_active());
+    assert_eq!(STARTING_MANA - 1, g.me().mana());
+    click_on_end_raid(&mut g);
+    assert!(g.user.data.raid_active());
+    assert_eq!(STARTING_MANA, g.me().mana());
+    click_on_end_raid(&mut g);
+    assert!(!g.user.data.raid_active());
+}
+
+#[test]
+fn turn_does_not_end_before_queued_raid() {
+    let mut g = new_game(Side::Champion, Args { actions: 1, ..Args::default() });
+    let spell = raid_vault_then_sanctum();
+    g.play_from_hand(spell);
+    click_on_end_raid(&mut g);
+    assert!(g.user.data.raid_active());
+    assert!(g.me().can_take_action());
+    click_on_end_raid(&mut g);
+    assert!(!g.user.data.raid_active());
+    assert!(g.dusk());
+}
@@ -0,0 +1,138 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use card_helpers::{face_up_in_play, global_modifier_active, simple_ability, text};
+use data::card_definition::{Cost, CustomCost};
+use data::card_name::CardName;
+use data::card_state::CardPosition;
+use data::delegates::{Delegate, QueryDelegate};
+use data::game_actions::GameAction;
+use data::primitives::{RoomId, Side};
+use protos::spelldawn::PlayerName;
+use rules::mutations;
+use test_utils::test_card_builder::TestCardBuilder;
+use test_utils::*;
+
+/// Global modifier which makes raids on the Sanctum cost 2 mana.
+fn sanctum_mana_tax() -> CardName {
+    TestCardBuilder::global_modifier()
+        .ability(simple_ability(
+            text!["Raids on the Sanctum cost 2 additional mana."],
+            Delegate::InitiateRaidCost(QueryDelegate {
+                requirement: global_modifier_active,
+                transformation: |_, _, room_id, current| {
+                    if *room_id == RoomId::Sanctum {
+                        Cost { mana: Some(current.mana.unwrap_or_default() + 2), ..current }
+                    } else {
+                        current
+                    }
+                },
+            }),
+        ))
+        .build()
+}
+
+#[test]
+fn mana_tax_is_paid() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.play_from_hand(sanctum_mana_tax());
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    g.initiate_raid(RoomId::Sanctum);
+    assert_eq!(STARTING_MANA - 2, g.you().mana());
+    assert_eq!(2, g.you().actions());
+}
+
+#[test]
+fn mana_tax_only_applies_to_target_room() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.play_from_hand(sanctum_mana_tax());
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    g.initiate_raid(RoomId::Vault);
+    assert_eq!(STARTING_MANA, g.you().mana());
+}
+
+#[test]
+fn cannot_raid_without_enough_mana() {
+    let mut g = new_game(Side::Overlord, Args { opponent_mana: 1, ..Args::default() });
+    g.play_from_hand(sanctum_mana_tax());
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    let actions = g.legal_actions(Side::Champion);
+    assert!(!actions.contains(&GameAction::InitiateRaid(RoomId::Sanctum)));
+    assert!(actions.contains(&GameAction::InitiateRaid(RoomId::Vault)));
+}
+
+#[test]
+fn taxed_raid_has_lower_prior() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.play_from_hand(sanctum_mana_tax());
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    let priors = g.legal_actions_with_priors(Side::Champion);
+    let prior = |room_id| {
+        priors
+            .iter()
+            .find(|(action, _)| *action == GameAction::InitiateRaid(room_id))
+            .map(|(_, prior)| *prior)
+            .expect("Raid action")
+    };
+    assert!(prior(RoomId::Sanctum) < prior(RoomId::Vault));
+}
+
+#[test]
+fn champion_cost_reducer() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let artifact = TestCardBuilder::artifact()
+        .ability(simple_ability(
+            text!["Raids cost no actions."],
+            Delegate::InitiateRaidCost(QueryDelegate {
+                requirement: face_up_in_play,
+                transformation: |_, _, _, current| Cost { actions: 0, ..current },
+            }),
+        ))
+        .build();
+    g.play_from_hand(artifact);
+    g.initiate_raid(RoomId::Sanctum);
+    assert_eq!(2, g.me().actions());
+}
+
+#[test]
+fn custom_cost_is_paid() {
+    let mut g = new_game(Side::Overlord, Args { opponent_hand_size: 5, ..Args::default() });
+    let modifier = TestCardBuilder::global_modifier()
+        .ability(simple_ability(
+            text!["As an additional cost to raid, discard a card."],
+            Delegate::InitiateRaidCost(QueryDelegate {
+                requirement: global_modifier_active,
+                transformation: |_, _, _, current| Cost {
+                    custom_cost: Some(CustomCost {
+                        can_pay: |game, _| game.hand(Side::Champion).next().is_some(),
+                        pay: |game, _| {
+                            let card_id = game.hand(Side::Champion).next().expect("card").id;
+                            mutations::move_card(
+                                game,
+                                card_id,
+                                CardPosition::DiscardPile(Side::Champion),
+                            )
+                        },
+                    }),
+                    ..current
+                },
+            }),
+        ))
+        .build();
+    g.play_from_hand(modifier);
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    let hand_size = g.opponent.cards.hand(PlayerName::User).len();
+    g.initiate_raid(RoomId::Vault);
+    assert_eq!(hand_size - 1, g.opponent.cards.hand(PlayerName::User).len());
+}
@@ -0,0 +1,113 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use card_helpers::{simple_ability, text};
+use cards::test_cards::WEAPON_COST;
+use core_ui::panels;
+use data::card_name::CardName;
+use data::delegates::{CardEncounter, Delegate, QueryDelegate};
+use data::primitives::Side;
+use panel_address::PanelAddress;
+use protos::spelldawn::client_action::Action;
+use protos::spelldawn::FetchPanelAction;
+use test_utils::client::TestSession;
+use test_utils::client_interface::HasText;
+use test_utils::test_card_builder::TestCardBuilder;
+use test_utils::*;
+
+#[test]
+fn raid_info_button_during_encounter() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.play_from_hand(CardName::TestWeapon3Attack12Boost3Cost);
+    setup_raid_target(&mut g, CardName::TestMinionEndRaid);
+    g.initiate_raid(ROOM_ID);
+    assert!(g.user.interface.controls().has_text("Raid Info"));
+}
+
+#[test]
+fn raid_info_shows_combat_math() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.play_from_hand(CardName::TestWeapon3Attack12Boost3Cost);
+    setup_raid_target(&mut g, CardName::TestMinionEndRaid);
+    g.initiate_raid(ROOM_ID);
+    open_raid_info(&mut g);
+
+    let panel = g.user.interface.top_panel();
+    assert!(panel.has_text("Health: 5, Shield: 0"));
+    assert!(panel.has_text("Attack: 3"));
+    assert!(panel.has_text("+2 attack for 1"));
+    assert!(panel.has_text("Boosts: 1, Cost: 1"));
+    assert!(panel.has_text(format!("Remaining: {}", STARTING_MANA - WEAPON_COST - 1)));
+}
+
+#[test]
+fn raid_info_shows_mana_shortfall() {
+    let mut g = new_game(Side::Champion, Args { mana: WEAPON_COST, ..Args::default() });
+    g.play_from_hand(CardName::TestWeapon3Attack12Boost3Cost);
+    setup_raid_target(&mut g, CardName::TestMinionEndRaid);
+    g.initiate_raid(ROOM_ID);
+    open_raid_info(&mut g);
+    assert!(g.user.interface.top_panel().has_text("Short: 1"));
+}
+
+#[test]
+fn lineage_mismatch_hint_in_encounter_prompt() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.play_from_hand(CardName::TestWeapon3Attack12Boost3Cost);
+    setup_raid_target(&mut g, CardName::TestAbyssalMinion);
+    g.initiate_raid(ROOM_ID);
+    assert!(g.user.interface.controls().has_text(format!(
+        "{} cannot damage Abyssal minions",
+        CardName::TestWeapon3Attack12Boost3Cost.displayed_name()
+    )));
+    open_raid_info(&mut g);
+    assert!(g.user.interface.top_panel().has_text("Wrong lineage"));
+}
+
+#[test]
+fn no_lineage_hint_for_matching_weapon() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.play_from_hand(CardName::TestWeapon3Attack12Boost3Cost);
+    setup_raid_target(&mut g, CardName::TestInfernalMinion);
+    g.initiate_raid(ROOM_ID);
+    assert!(!g.user.interface.controls().has_text("cannot damage"));
+}
+
+#[test]
+fn encounter_query_overrides_lineage_hint() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let weapon = TestCardBuilder::weapon()
+        .base_attack(5)
+        .ability(simple_ability(
+            text!["Can damage minions of any lineage."],
+            Delegate::CanEncounterTarget(QueryDelegate {
+                requirement: |_, s, encounter: &CardEncounter| encounter.source == s.card_id(),
+                transformation: |_, _, _, current| current.with_override(true),
+            }),
+        ))
+        .build();
+    g.play_from_hand(weapon);
+    setup_raid_target(&mut g, CardName::TestAbyssalMinion);
+    g.initiate_raid(ROOM_ID);
+    assert!(!g.user.interface.controls().has_text("cannot damage"));
+    assert!(g.user.interface.controls().has_text(weapon.displayed_name()));
+}
+
+fn open_raid_info(g: &mut TestSession) {
+    g.perform(
+        Action::FetchPanel(FetchPanelAction { panel_address: Some(PanelAddress::RaidInfo.into()) }),
+        g.user_id(),
+    );
+    g.user.interface.update(panels::open(PanelAddress::RaidInfo));
+}
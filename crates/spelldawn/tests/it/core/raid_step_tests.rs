@@ -0,0 +1,88 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use card_helpers::{
+    after_raid_step, approaching_outermost_defender, before_raid_step, simple_ability, text,
+};
+use data::delegates::{RaidOutcome, RaidStep};
+use data::primitives::Side;
+use rules::{mana, mutations};
+use test_utils::test_card_builder::TestCardBuilder;
+use test_utils::*;
+
+#[test]
+fn approach_outermost_defender_before_summon() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let minion = TestCardBuilder::minion()
+        .mana_cost(3)
+        .health(5)
+        .ability(simple_ability(
+            text!["When the Champion approaches the outermost defender, end the raid."],
+            before_raid_step(approaching_outermost_defender, |g, _, _| {
+                mutations::end_raid(g, RaidOutcome::Failure)
+            }),
+        ))
+        .build();
+    g.play_from_hand(minion);
+    set_up_minion_combat(&mut g);
+    assert!(!g.user.data.raid_active());
+    assert_eq!(STARTING_MANA, g.user.this_player.mana());
+}
+
+#[test]
+fn after_access_card_step() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let scheme = TestCardBuilder::scheme()
+        .ability(simple_ability(
+            text!["When accessed, the Overlord gains 2 mana."],
+            after_raid_step(
+                |_, scope, data| data.step == RaidStep::AccessCard(scope.card_id()),
+                |g, _, _| {
+                    mana::gain(g, Side::Overlord, 2);
+                    Ok(())
+                },
+            ),
+        ))
+        .build();
+    g.play_from_hand(scheme);
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    let mana = g.user.this_player.mana();
+    g.initiate_raid(ROOM_ID);
+    assert!(g.user.data.raid_active());
+    assert_eq!(mana + 2, g.user.this_player.mana());
+}
+
+#[test]
+fn before_end_step_sees_outcome() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let scheme = TestCardBuilder::scheme()
+        .ability(simple_ability(
+            text!["When a raid succeeds, the Overlord gains 1 mana."],
+            before_raid_step(
+                |_, _, data| data.step == RaidStep::End(RaidOutcome::Success),
+                |g, _, _| {
+                    mana::gain(g, Side::Overlord, 1);
+                    Ok(())
+                },
+            ),
+        ))
+        .build();
+    g.play_from_hand(scheme);
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    let mana = g.user.this_player.mana();
+    g.initiate_raid(ROOM_ID);
+    click_on_end_raid(&mut g);
+    assert!(!g.user.data.raid_active());
+    assert_eq!(mana + 1, g.user.this_player.mana());
+}
@@ -26,6 +26,7 @@ use protos::spelldawn::{
     ObjectPositionDiscardPile, ObjectPositionIdentity, ObjectPositionIdentityContainer,
     ObjectPositionRaid, ObjectPositionRoom, PlayerName, SpendActionPointAction,
 };
+use server::recordings::RecordingName;
 use test_utils::client_interface::HasText;
 use test_utils::summarize::Summary;
 use test_utils::*;
@@ -82,7 +83,8 @@ fn initiate_raid() {
                     server_card_id(minion_id)
                 )
             )),
-            GameAction::PromptAction(PromptAction::EncounterAction(EncounterAction::NoWeapon))
+            GameAction::PromptAction(PromptAction::EncounterAction(EncounterAction::NoWeapon)),
+            GameAction::PromptAction(PromptAction::EncounterAction(EncounterAction::Retreat))
         ]
     );
 
@@ -265,7 +267,7 @@ fn complete_raid() {
     );
 
     assert_snapshot!(Summary::summarize(&response));
-    create_test_recording(&g, "complete_raid");
+    create_test_recording(&g, RecordingName::CompleteRaid);
 }
 
 #[test]
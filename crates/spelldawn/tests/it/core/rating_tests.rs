@@ -0,0 +1,218 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use cards::{decklists, initialize};
+use core_ui::actions::InterfaceAction;
+use core_ui::panels;
+use data::adventure::Coins;
+use data::campaign::CampaignProgress;
+use data::cosmetics::PlayerCosmetics;
+use data::crafting::Dust;
+use data::game::GameRules;
+use data::game_actions::GameAction;
+use data::player_data::{AutomationPreferences, PlayerData, PlayerSettings};
+use data::player_name::{NamedPlayer, PlayerId};
+use data::primitives::{DeckIndex, Side};
+use data::quests::QuestLog;
+use data::ratings::{self, PlayerRating, STARTING_RATING};
+use data::tutorial::TutorialData;
+use data::user_actions::{GamePreset, NewGameAction, NewGameDebugOptions, UserAction};
+use database::Database;
+use maplit::hashmap;
+use panel_address::PanelAddress;
+use protos::spelldawn::client_action::Action;
+use protos::spelldawn::{FetchLeaderboardRequest, FetchPanelAction};
+use server::ratings as server_ratings;
+use test_utils::client::TestSession;
+use test_utils::client_interface::HasText;
+use test_utils::fake_database::FakeDatabase;
+use test_utils::*;
+
+#[test]
+fn equal_ratings_exchange_half_k_factor() {
+    let (mut winner, mut loser) = (rated(1, STARTING_RATING), rated(2, STARTING_RATING));
+    ratings::record_result(&mut winner, &mut loser);
+    assert_eq!(STARTING_RATING + 16, winner.rating);
+    assert_eq!(STARTING_RATING - 16, loser.rating);
+    assert_eq!((1, 1), (winner.wins, winner.games_played));
+    assert_eq!((0, 1), (loser.wins, loser.games_played));
+}
+
+#[test]
+fn upset_win_gains_more_points() {
+    let (mut winner, mut loser) = (rated(1, 1000), rated(2, 1400));
+    ratings::record_result(&mut winner, &mut loser);
+    assert_eq!(1029, winner.rating);
+    assert_eq!(1371, loser.rating);
+}
+
+#[test]
+fn ranked_game_updates_ratings() {
+    let (mut session, opponent_id) = new_game(GamePreset { ranked: true, ..GamePreset::default() });
+    assert!(session.game().data.config.ranked);
+    session.perform(GameAction::Resign.as_client_action(), session.user_id());
+
+    let user = session.rating(session.user_id()).expect("rating");
+    assert_eq!(STARTING_RATING - 16, user.rating);
+    assert_eq!((0, 1), (user.wins, user.games_played));
+    let opponent = session.rating(opponent_id).expect("rating");
+    assert_eq!(STARTING_RATING + 16, opponent.rating);
+    assert_eq!((1, 1), (opponent.wins, opponent.games_played));
+}
+
+#[test]
+fn unranked_game_does_not_update_ratings() {
+    let (mut session, opponent_id) = new_game(GamePreset::default());
+    session.perform(GameAction::Resign.as_client_action(), session.user_id());
+    assert!(session.rating(session.user_id()).is_none());
+    assert!(session.rating(opponent_id).is_none());
+}
+
+#[test]
+fn panels_display_ratings() {
+    let (mut session, _) = new_game(GamePreset { ranked: true, ..GamePreset::default() });
+    session.perform(GameAction::Resign.as_client_action(), session.user_id());
+
+    open_panel(&mut session, PanelAddress::Rankings(0));
+    assert!(session.user.interface.top_panel().has_text("#1 Test No Action"));
+    assert!(session.user.interface.top_panel().has_text("Rating 1184"));
+    open_panel(&mut session, PanelAddress::CustomGame(GamePreset::default()));
+    assert!(session.user.interface.top_panel().has_text("Your Rating: 1184"));
+}
+
+#[test]
+fn ranked_game_requires_standard_rules() {
+    let (_, user_id, _) = generate_ids();
+    let mut session = TestSession::new(
+        player_database(user_id),
+        user_id,
+        PlayerId::Named(NamedPlayer::TestNoAction),
+    );
+    let result = session.perform_action(
+        new_game_action(GamePreset {
+            ranked: true,
+            rules: GameRules { points_to_win: 3, ..GameRules::default() },
+            ..GamePreset::default()
+        }),
+        user_id,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn leaderboard_orders_by_rating() {
+    let mut database = FakeDatabase::default();
+    for (id, rating) in [(1, 1100), (2, 1300), (3, 1200)] {
+        database.write_rating(&rated(id, rating)).unwrap();
+    }
+    let (entries, total) = server_ratings::leaderboard(&database, 0, 10).unwrap();
+    assert_eq!(3, total);
+    assert_eq!(
+        vec![(1, 1300), (2, 1200), (3, 1100)],
+        entries.iter().map(|(rank, r)| (*rank, r.rating)).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn fetch_leaderboard_paginates() {
+    let mut database = FakeDatabase::default();
+    for id in 0..15 {
+        database.write_rating(&rated(id, 1000 + id as i32)).unwrap();
+    }
+
+    let first =
+        server_ratings::fetch_leaderboard(&database, &FetchLeaderboardRequest::default()).unwrap();
+    assert_eq!(15, first.total_count);
+    assert_eq!(ratings::LEADERBOARD_PAGE_SIZE, first.players.len());
+    assert_eq!(1, first.players[0].rank);
+    assert_eq!("Player 14", first.players[0].name);
+
+    let second = server_ratings::fetch_leaderboard(
+        &database,
+        &FetchLeaderboardRequest { offset: 10, limit: 10 },
+    )
+    .unwrap();
+    assert_eq!(5, second.players.len());
+    assert_eq!(11, second.players[0].rank);
+    assert_eq!(1004, second.players[0].rating);
+}
+
+fn open_panel(session: &mut TestSession, address: PanelAddress) {
+    session.perform(
+        Action::FetchPanel(FetchPanelAction { panel_address: Some(address.into()) }),
+        session.user_id(),
+    );
+    session.user.interface.update(panels::open(address));
+}
+
+fn rated(id: u64, rating: i32) -> PlayerRating {
+    PlayerRating { rating, ..PlayerRating::new(PlayerId::Database(id)) }
+}
+
+fn new_game_action(preset: GamePreset) -> Action {
+    UserAction::NewGame(NewGameAction {
+        deck_index: DeckIndex::new(0),
+        opponent: PlayerId::Named(NamedPlayer::TestNoAction),
+        debug_options: Some(NewGameDebugOptions {
+            deterministic: true,
+            ..NewGameDebugOptions::default()
+        }),
+        preset,
+    })
+    .as_client_action()
+}
+
+/// Starts a game using `preset` between a new player and an AI opponent which
+/// takes no actions.
+fn new_game(preset: GamePreset) -> (TestSession, PlayerId) {
+    initialize::run();
+    let (game_id, user_id, _) = generate_ids();
+    let opponent_id = PlayerId::Named(NamedPlayer::TestNoAction);
+    let database = FakeDatabase { generated_game_id: Some(game_id), ..player_database(user_id) };
+    let mut session = TestSession::new(database, user_id, opponent_id);
+    session.perform(new_game_action(preset), user_id);
+    (session, opponent_id)
+}
+
+fn player_database(user_id: PlayerId) -> FakeDatabase {
+    FakeDatabase {
+        players: hashmap! {
+            user_id => PlayerData {
+                id: user_id,
+                state: None,
+                decks: vec![decklists::canonical_deck(user_id, Side::Champion)],
+                adventure: None,
+                collection: hashmap! {},
+                tutorial: TutorialData::default(),
+                cosmetics: HashSet::new(),
+                achievements: HashSet::new(),
+                settings: PlayerSettings::default(),
+                match_history: vec![],
+                completed_puzzles: HashSet::new(),
+                quests: QuestLog::default(),
+                coins: Coins::default(),
+                player_cosmetics: PlayerCosmetics::default(),
+                revision: 0,
+                notifications: vec![],
+                campaign: CampaignProgress::default(),
+                current_match: None,
+                dust: Dust::default(),
+                automation: AutomationPreferences::default(),
+            }
+        },
+        ..FakeDatabase::default()
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_name::CardName;
+use data::game_actions::{CardTarget, GameAction};
+use data::primitives::Side;
+use protos::spelldawn::client_action::Action;
+use protos::spelldawn::{LevelUpRoomAction, PlayCardAction};
+use test_utils::client::TestSession;
+use test_utils::client_interface::HasText;
+use test_utils::*;
+
+fn overlord_turn(actions: u32) -> TestSession {
+    let mut g =
+        new_game(Side::Champion, Args { turn: Some(Side::Overlord), actions, ..Args::default() });
+    g.play_from_hand(CardName::TestScheme31);
+    g
+}
+
+fn level_up(g: &mut TestSession) {
+    g.perform(
+        Action::LevelUpRoom(LevelUpRoomAction { room_id: CLIENT_ROOM_ID.into() }),
+        g.opponent_id(),
+    );
+}
+
+#[test]
+fn no_window_without_reaction() {
+    let mut g = overlord_turn(3);
+    level_up(&mut g);
+    assert!(g.game().data.reaction_window.is_none());
+    assert!(g.opponent.this_player.can_take_action());
+    assert!(!g.user.interface.controls().has_text("Pass"));
+}
+
+#[test]
+fn level_up_opens_window() {
+    let mut g = overlord_turn(3);
+    let reaction_id = g.add_to_hand(CardName::TestReaction);
+    level_up(&mut g);
+    assert!(g.game().data.reaction_window.is_some());
+    assert!(g.user.interface.controls().has_text("Pass"));
+    assert!(g.user.this_player.can_take_action());
+    assert!(!g.opponent.this_player.can_take_action());
+    assert!(g
+        .legal_actions(Side::Champion)
+        .contains(&GameAction::PlayCard(server_card_id(reaction_id), CardTarget::None)));
+    assert_error(g.perform_action(
+        Action::LevelUpRoom(LevelUpRoomAction { room_id: CLIENT_ROOM_ID.into() }),
+        g.opponent_id(),
+    ));
+}
+
+#[test]
+fn play_reaction() {
+    let mut g = overlord_turn(3);
+    let reaction_id = g.add_to_hand(CardName::TestReaction);
+    level_up(&mut g);
+    let mana = g.user.this_player.mana();
+    g.play_card(reaction_id, g.user_id(), None);
+    assert_eq!(mana + 1, g.user.this_player.mana());
+    assert!(g.game().data.reaction_window.is_none());
+    assert!(!g.user.interface.controls().has_text("Pass"));
+    assert!(g.opponent.this_player.can_take_action());
+    assert_eq!(1, g.opponent.this_player.actions());
+}
+
+#[test]
+fn pass_reaction() {
+    let mut g = overlord_turn(3);
+    g.add_to_hand(CardName::TestReaction);
+    level_up(&mut g);
+    g.click_on(g.user_id(), "Pass");
+    assert!(g.game().data.reaction_window.is_none());
+    assert!(g.opponent.this_player.can_take_action());
+    level_up(&mut g);
+    assert!(g.user.interface.controls().has_text("Pass"));
+}
+
+#[test]
+fn pass_on_last_action_ends_turn() {
+    let mut g = overlord_turn(2);
+    g.add_to_hand(CardName::TestReaction);
+    level_up(&mut g);
+    assert_eq!(Side::Overlord, g.game().data.turn.side);
+    g.click_on(g.user_id(), "Pass");
+    assert!(g.dawn());
+    assert_eq!(Side::Champion, g.game().data.turn.side);
+}
+
+#[test]
+fn cannot_play_non_reaction_in_window() {
+    let mut g = overlord_turn(3);
+    g.add_to_hand(CardName::TestReaction);
+    let spell_id = g.add_to_hand(CardName::TestChampionSpell);
+    level_up(&mut g);
+    assert_error(g.perform_action(
+        Action::PlayCard(PlayCardAction { card_id: Some(spell_id), target: None }),
+        g.user_id(),
+    ));
+}
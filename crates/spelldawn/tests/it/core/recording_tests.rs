@@ -0,0 +1,90 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::PathBuf;
+use std::{env, fs};
+
+use data::primitives::Side;
+use protos::spelldawn::{CommandList, FetchRecordingsRequest, PlayerSide};
+use server::recordings;
+use server::recordings::RecordingName;
+
+#[test]
+fn registered_names() {
+    assert_eq!(
+        vec!["complete_raid_overlord".to_string(), "complete_raid_champion".to_string()],
+        recordings::all_names()
+    );
+}
+
+#[test]
+fn write_and_fetch() {
+    let directory = temp_directory("write_and_fetch");
+    let recording = recordings::new(
+        RecordingName::CompleteRaid,
+        Side::Champion,
+        CommandList { commands: vec![] },
+    );
+    recordings::write(&directory, &recording).unwrap();
+
+    let list = recordings::fetch(
+        &directory,
+        &FetchRecordingsRequest { names: vec!["complete_raid_champion".to_string()] },
+    )
+    .unwrap();
+    fs::remove_dir_all(&directory).unwrap();
+
+    assert_eq!(vec![recording], list.recordings);
+    let fetched = &list.recordings[0];
+    assert_eq!(recordings::FORMAT_VERSION, fetched.format_version);
+    assert_eq!(PlayerSide::Champion, fetched.side());
+    assert_eq!(RecordingName::CompleteRaid.description(), fetched.description);
+    assert!(!list.card_set_hash.is_empty());
+    assert_eq!(list.card_set_hash, fetched.card_set_hash);
+}
+
+#[test]
+fn fetch_unknown_recording() {
+    let result = recordings::fetch(
+        &env::temp_dir(),
+        &FetchRecordingsRequest { names: vec!["not_a_recording".to_string()] },
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn fetch_rejects_other_versions() {
+    let directory = temp_directory("fetch_rejects_other_versions");
+    let mut recording = recordings::new(
+        RecordingName::CompleteRaid,
+        Side::Overlord,
+        CommandList { commands: vec![] },
+    );
+    recording.format_version = recordings::FORMAT_VERSION + 1;
+    recordings::write(&directory, &recording).unwrap();
+
+    let result = recordings::fetch(
+        &directory,
+        &FetchRecordingsRequest { names: vec!["complete_raid_overlord".to_string()] },
+    );
+    fs::remove_dir_all(&directory).unwrap();
+    assert!(result.is_err());
+}
+
+fn temp_directory(name: &str) -> PathBuf {
+    let directory =
+        env::temp_dir().join(format!("spelldawn_recordings_{}_{}", name, std::process::id()));
+    fs::create_dir_all(&directory).unwrap();
+    directory
+}
@@ -0,0 +1,88 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use card_helpers::{on_champion_retreat, simple_ability, text};
+use data::primitives::Side;
+use rules::mana;
+use test_utils::client::TestSession;
+use test_utils::client_interface::HasText;
+use test_utils::test_card_builder::TestCardBuilder;
+use test_utils::*;
+
+fn retreat(g: &mut TestSession) {
+    let champion = g.player_id_for_side(Side::Champion);
+    g.click_on(champion, "Retreat");
+    assert!(g.opponent.interface.controls().has_text("Retreat from raid?"));
+    g.click_on(champion, "Retreat");
+}
+
+#[test]
+fn retreat_from_outermost_defender() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.play_from_hand(TestCardBuilder::minion().health(5).build());
+    g.play_from_hand(TestCardBuilder::minion().health(5).build());
+    set_up_minion_combat(&mut g);
+    assert!(g.user.data.raid_active());
+    retreat(&mut g);
+    assert!(!g.user.data.raid_active());
+    assert!(!g.opponent.interface.controls().has_text("Score"));
+}
+
+#[test]
+fn retreat_from_innermost_defender() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.play_from_hand(TestCardBuilder::minion().health(5).build());
+    g.play_from_hand(TestCardBuilder::minion().health(5).build());
+    set_up_minion_combat(&mut g);
+    click_on_continue(&mut g);
+    assert!(g.user.data.raid_active());
+    retreat(&mut g);
+    assert!(!g.user.data.raid_active());
+    assert!(!g.opponent.interface.controls().has_text("Score"));
+}
+
+#[test]
+fn cancel_retreat() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.play_from_hand(TestCardBuilder::minion().health(5).build());
+    set_up_minion_combat(&mut g);
+    g.click_on(g.opponent_id(), "Retreat");
+    g.click_on(g.opponent_id(), "Cancel");
+    assert!(g.user.data.raid_active());
+    click_on_continue(&mut g);
+    assert!(g.opponent.interface.controls().has_text("Score"));
+}
+
+#[test]
+fn overlord_rewarded_for_retreat() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let minion = TestCardBuilder::minion()
+        .health(5)
+        .ability(simple_ability(
+            text!["When the Champion retreats, gain 3 mana."],
+            on_champion_retreat(
+                |_, scope, retreat| retreat.defender_id == scope.card_id(),
+                |g, _, _| {
+                    mana::gain(g, Side::Overlord, 3);
+                    Ok(())
+                },
+            ),
+        ))
+        .build();
+    g.play_from_hand(minion);
+    set_up_minion_combat(&mut g);
+    let mana = g.user.this_player.mana();
+    retreat(&mut g);
+    assert_eq!(mana + 3, g.user.this_player.mana());
+}
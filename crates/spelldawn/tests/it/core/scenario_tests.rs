@@ -0,0 +1,47 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::icons;
+use data::card_name::CardName;
+use data::primitives::Side;
+use test_utils::scenario::Scenario;
+use test_utils::*;
+
+#[test]
+fn time_golem_defeat() {
+    Scenario::new()
+        .overlord_plays(CardName::TimeGolem)
+        .overlord_plays(CardName::TestScheme31)
+        .end_turn(Side::Overlord)
+        .champion_plays(CardName::TestWeapon5Attack)
+        .champion_raids(ROOM_ID)
+        .expect_prompt(Side::Champion, "End Raid")
+        .click(Side::Champion, format!("Pay 5{}", icons::MANA))
+        .click(Side::Champion, "Test Weapon")
+        .expect_raid_active(true)
+        .expect_prompt(Side::Champion, "Score");
+}
+
+#[test]
+fn stormcaller_end_raid() {
+    Scenario::with_args(Side::Overlord, Args { opponent_hand_size: 5, ..Args::default() })
+        .overlord_plays(CardName::Stormcaller)
+        .overlord_plays(CardName::TestScheme31)
+        .end_turn(Side::Overlord)
+        .champion_raids(ROOM_ID)
+        .expect_prompt(Side::Champion, "Take 4")
+        .click(Side::Champion, "End Raid, Take 2")
+        .expect_raid_active(false)
+        .expect_no_prompt(Side::Champion, "Take 4");
+}
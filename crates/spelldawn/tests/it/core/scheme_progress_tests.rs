@@ -0,0 +1,48 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_name::CardName;
+use data::primitives::Side;
+use protos::spelldawn::SchemeProgress;
+use test_utils::*;
+
+#[test]
+fn scheme_progress_tracks_level() {
+    let mut g = new_game(Side::Overlord, Args { mana: 10, ..Args::default() });
+    let id = g.play_from_hand(CardName::TestScheme31);
+    assert_eq!(
+        Some(&SchemeProgress { current_level: 0, required_level: 3 }),
+        g.user.cards.get(id).scheme_progress()
+    );
+    level_up_room(&mut g, 2);
+    assert_eq!(
+        Some(&SchemeProgress { current_level: 2, required_level: 3 }),
+        g.user.cards.get(id).scheme_progress()
+    );
+}
+
+#[test]
+fn hidden_schemes_have_no_progress() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let id = g.play_from_hand(CardName::TestScheme31);
+    assert!(g.user.cards.get(id).scheme_progress().is_some());
+    assert!(g.opponent.cards.get(id).scheme_progress().is_none());
+}
+
+#[test]
+fn non_scheme_cards_have_no_progress() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let id = g.play_from_hand(CardName::TestMinionEndRaid);
+    assert!(g.user.cards.get(id).scheme_progress().is_none());
+}
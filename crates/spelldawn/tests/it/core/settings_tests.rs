@@ -0,0 +1,60 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::actions::InterfaceAction;
+use data::player_data::DEFAULT_MUSIC_VOLUME;
+use data::primitives::Side;
+use data::user_actions::UserAction;
+use test_utils::*;
+
+#[test]
+fn connect_sends_audio_settings() {
+    let g = new_game(Side::Overlord, Args::default());
+    assert_eq!(
+        Some(DEFAULT_MUSIC_VOLUME as f32 / 100.0),
+        g.user.interface.float_preference("MusicVolume")
+    );
+    assert_eq!(Some(1.0), g.user.interface.float_preference("SoundEffectsVolume"));
+    assert_eq!(Some(false), g.user.interface.boolean_preference("MuteAudio"));
+}
+
+#[test]
+fn set_music_volume() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.perform(UserAction::SetMusicVolume(70).as_client_action(), g.user_id());
+    assert_eq!(70, g.player_data(g.user_id()).settings.music_volume);
+    assert_eq!(Some(0.7), g.user.interface.float_preference("MusicVolume"));
+}
+
+#[test]
+fn cannot_exceed_maximum_volume() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    assert!(g
+        .perform_action(UserAction::SetSoundEffectsVolume(101).as_client_action(), g.user_id())
+        .is_err());
+}
+
+#[test]
+fn audio_settings_persist_across_connections() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let user_id = g.user_id();
+    g.perform(UserAction::SetSoundEffectsVolume(40).as_client_action(), user_id);
+    g.perform(UserAction::SetMuteAudio(true).as_client_action(), user_id);
+    assert!(g.player_data(user_id).settings.mute_audio);
+
+    g.user.interface = Default::default();
+    g.connect(user_id).expect("Connection failed");
+    assert_eq!(Some(0.4), g.user.interface.float_preference("SoundEffectsVolume"));
+    assert_eq!(Some(true), g.user.interface.boolean_preference("MuteAudio"));
+}
@@ -0,0 +1,85 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_name::CardName;
+use data::primitives::Side;
+use protos::spelldawn::client_action::Action;
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::game_object_identifier::Id;
+use protos::spelldawn::{
+    CardIdentifier, CommandList, DisplayedArrow, GainManaAction, GameObjectIdentifier,
+    TargetingArrow,
+};
+use test_utils::*;
+
+fn arrows(commands: &CommandList) -> Vec<DisplayedArrow> {
+    commands
+        .commands
+        .iter()
+        .filter_map(|c| match &c.command {
+            Some(Command::DisplayArrows(display)) => Some(display.arrows.clone()),
+            _ => None,
+        })
+        .flatten()
+        .collect()
+}
+
+fn card_object(card_id: CardIdentifier) -> Option<GameObjectIdentifier> {
+    Some(GameObjectIdentifier { id: Some(Id::CardId(card_id)) })
+}
+
+#[test]
+fn minion_highlighted_rooms() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let id = g.add_to_hand(CardName::TestMinionEndRaid);
+    let card = g.user.cards.get(id);
+    assert!(card.highlighted_rooms().contains(&CLIENT_ROOM_ID));
+    assert_eq!(card.valid_rooms(), card.highlighted_rooms());
+}
+
+#[test]
+fn untargeted_card_has_no_highlighted_rooms() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let id = g.add_to_hand(CardName::TestWeapon3Attack12Boost3Cost);
+    let card = g.user.cards.get(id);
+    assert!(card.can_play());
+    assert!(card.highlighted_rooms().is_empty());
+}
+
+#[test]
+fn weapon_arrows_during_encounter() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let weapon_id = g.play_from_hand(CardName::TestWeapon3Attack12Boost3Cost);
+    let (_, minion_id) = setup_raid_target(&mut g, CardName::TestMinionEndRaid);
+    let response = g.initiate_raid(ROOM_ID);
+    assert_eq!(
+        vec![DisplayedArrow {
+            source: card_object(weapon_id),
+            target: card_object(minion_id),
+            color: TargetingArrow::Green.into(),
+        }],
+        arrows(&response.command_list)
+    );
+    let (_, opponent_commands) = response.opponent_response.expect("opponent_response");
+    assert!(arrows(&opponent_commands).is_empty());
+}
+
+#[test]
+fn no_arrows_outside_raid() {
+    let mut g = new_game(Side::Champion, Args::default());
+    g.play_from_hand(CardName::TestWeapon3Attack12Boost3Cost);
+    let response =
+        g.perform_action(Action::GainMana(GainManaAction {}), g.user_id()).expect("gain_mana");
+    assert!(arrows(&response.command_list).is_empty());
+}
@@ -0,0 +1,70 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::actions::InterfaceAction;
+use data::card_name::CardName;
+use data::card_stats::{CardStatistics, CardUsage};
+use data::game_actions::GameAction;
+use data::primitives::Side;
+use data::user_actions::UserAction;
+use protos::spelldawn::client_action::Action;
+use protos::spelldawn::DrawCardAction;
+use test_utils::*;
+
+#[test]
+fn records_statistics_for_opted_in_player() {
+    let mut g = new_game(Side::Champion, Args { actions: 3, ..Args::default() });
+    g.perform(UserAction::SetShareCardStatistics(true).as_client_action(), g.user_id());
+    let turn = g.game().data.turn.turn_number;
+    g.perform(Action::DrawCard(DrawCardAction {}), g.user_id());
+    g.play_from_hand(CardName::TestChampionSpell);
+    g.perform(GameAction::Resign.as_client_action(), g.user_id());
+
+    let statistics = g.card_statistics(CardName::TestChampionSpell).expect("statistics");
+    assert_eq!(1, statistics.games_drawn);
+    assert_eq!(0, statistics.wins_when_drawn);
+    assert_eq!(1, statistics.times_played);
+    assert_eq!(Some(turn as f64), statistics.average_turn_played());
+}
+
+#[test]
+fn does_not_record_statistics_by_default() {
+    let mut g = new_game(Side::Champion, Args { actions: 3, ..Args::default() });
+    assert!(!g.player_data(g.user_id()).settings.share_card_statistics);
+    g.perform(Action::DrawCard(DrawCardAction {}), g.user_id());
+    g.play_from_hand(CardName::TestChampionSpell);
+    g.perform(GameAction::Resign.as_client_action(), g.user_id());
+    assert!(g.card_statistics(CardName::TestChampionSpell).is_none());
+}
+
+#[test]
+fn only_records_opted_in_side() {
+    let mut g = new_game(Side::Champion, Args { actions: 3, ..Args::default() });
+    g.perform(UserAction::SetShareCardStatistics(true).as_client_action(), g.opponent_id());
+    g.perform(Action::DrawCard(DrawCardAction {}), g.user_id());
+    g.perform(GameAction::Resign.as_client_action(), g.user_id());
+    assert!(g.card_statistics(CardName::TestChampionSpell).is_none());
+}
+
+#[test]
+fn aggregates_win_rate_and_average_turn() {
+    let mut statistics = CardStatistics::new(CardName::TestChampionSpell);
+    assert_eq!(None, statistics.win_rate_when_drawn());
+    assert_eq!(None, statistics.average_turn_played());
+    statistics.record(&CardUsage { drawn: true, turns_played: vec![2, 4] }, true);
+    statistics.record(&CardUsage { drawn: true, turns_played: vec![] }, false);
+    statistics.record(&CardUsage { drawn: false, turns_played: vec![6] }, true);
+    assert_eq!(Some(0.5), statistics.win_rate_when_drawn());
+    assert_eq!(Some(4.0), statistics.average_turn_played());
+}
@@ -0,0 +1,66 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use cards::test_cards;
+use data::card_name::CardName;
+use data::primitives::Side;
+use test_utils::client_interface::HasText;
+use test_utils::test_card_builder::TestCardBuilder;
+use test_utils::*;
+
+#[test]
+fn built_minion_ends_raid() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let minion = TestCardBuilder::minion().health(5).shield(2).combat_end_raid().build();
+    g.play_from_hand(minion);
+    set_up_minion_combat(&mut g);
+    assert!(g.user.data.raid_active());
+    click_on_continue(&mut g);
+    assert!(!g.user.data.raid_active());
+}
+
+#[test]
+fn built_weapon_defeats_built_minion() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let minion = TestCardBuilder::minion().health(3).combat_end_raid().build();
+    let weapon = TestCardBuilder::weapon().base_attack(1).attack_boost(1, 1).build();
+    g.play_from_hand(minion);
+    g.play_from_hand(CardName::TestScheme31);
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    g.play_from_hand(weapon);
+    g.initiate_raid(ROOM_ID);
+    let label = weapon.displayed_name();
+    assert!(g.opponent.interface.controls().has_text(&label));
+    g.click_on(g.opponent_id(), label);
+    assert_eq!(STARTING_MANA - test_cards::WEAPON_COST - 2, g.opponent.this_player.mana());
+    assert!(g.opponent.interface.card_anchor_nodes().has_text("Score!"));
+}
+
+#[test]
+fn built_cards_have_distinct_names() {
+    let first = TestCardBuilder::project().build();
+    let second = TestCardBuilder::champion_spell().build();
+    assert_ne!(first, second);
+    assert!(first.is_synthetic() && second.is_synthetic());
+    assert_eq!(Side::Overlord, rules::get(first).side);
+    assert_eq!(Side::Champion, rules::get(second).side);
+}
+
+#[test]
+#[should_panic(expected = "Synthetic card names exhausted")]
+fn registering_too_many_cards_panics() {
+    for _ in 0..9 {
+        TestCardBuilder::project().build();
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use card_helpers::abilities;
+use data::primitives::Side;
+use protos::spelldawn::PlayerName;
+use test_utils::client_interface::HasText;
+use test_utils::test_card_builder::TestCardBuilder;
+use test_utils::*;
+
+#[test]
+fn trap_damage_before_score() {
+    let mut g = new_game(Side::Overlord, Args { opponent_hand_size: 5, ..Args::default() });
+    g.play_from_hand(TestCardBuilder::scheme().ability(abilities::trap_deal_damage::<2>()).build());
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    assert!(g.dawn());
+    let hand_size = g.user.cards.hand(PlayerName::Opponent).len();
+    g.initiate_raid(ROOM_ID);
+    assert_eq!(hand_size - 2, g.user.cards.hand(PlayerName::Opponent).len());
+    assert!(g.opponent.interface.controls().has_text("Score"));
+    click_on_score(&mut g);
+    assert_eq!(1, g.opponent.this_player.score());
+}
+
+#[test]
+fn lethal_trap_prevents_score() {
+    let mut g = new_game(Side::Overlord, Args { opponent_hand_size: 5, ..Args::default() });
+    g.play_from_hand(
+        TestCardBuilder::scheme().ability(abilities::trap_deal_damage::<10>()).build(),
+    );
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    assert!(g.dawn());
+    g.initiate_raid(ROOM_ID);
+    assert!(g.is_victory_for_player(Side::Overlord));
+    assert_eq!(0, g.opponent.this_player.score());
+}
+
+#[test]
+fn trap_fires_on_each_access() {
+    let mut g = new_game(Side::Overlord, Args { opponent_hand_size: 5, ..Args::default() });
+    g.play_from_hand(TestCardBuilder::scheme().ability(abilities::trap_deal_damage::<1>()).build());
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    assert!(g.dawn());
+    let hand_size = g.user.cards.hand(PlayerName::Opponent).len();
+    g.initiate_raid(ROOM_ID);
+    click_on_end_raid(&mut g);
+    g.initiate_raid(ROOM_ID);
+    assert_eq!(hand_size - 2, g.user.cards.hand(PlayerName::Opponent).len());
+}
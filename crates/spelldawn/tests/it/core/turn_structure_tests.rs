@@ -0,0 +1,123 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use card_helpers::{at_end_of_turn, at_main_phase_begin, face_up_in_play, simple_ability, text};
+use cards::test_cards;
+use data::delegates::{Delegate, EventDelegate};
+use data::game::TurnStep;
+use data::primitives::Side;
+use rules::mana;
+use test_utils::test_card_builder::TestCardBuilder;
+use test_utils::*;
+
+#[test]
+fn game_starts_in_main_step() {
+    let g = new_game(Side::Champion, Args::default());
+    assert_eq!(TurnStep::Main, g.game().data.turn_step);
+}
+
+#[test]
+fn turn_step_events_fire_in_order() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let artifact = TestCardBuilder::artifact()
+        .ability(simple_ability(
+            text!["At the start of each turn, gain 1 mana."],
+            Delegate::TurnBegin(EventDelegate {
+                requirement: face_up_in_play,
+                mutation: |g, s, turn| {
+                    assert_eq!(TurnStep::Begin, g.data.turn_step);
+                    assert_eq!(0, g.player(turn.side).actions);
+                    mana::gain(g, s.side(), 1);
+                    Ok(())
+                },
+            }),
+        ))
+        .ability(simple_ability(
+            text!["At the start of each draw phase, gain 1 mana."],
+            Delegate::DrawPhase(EventDelegate {
+                requirement: face_up_in_play,
+                mutation: |g, s, turn| {
+                    assert_eq!(TurnStep::Draw, g.data.turn_step);
+                    assert!(g.player(turn.side).actions > 0);
+                    mana::gain(g, s.side(), 1);
+                    Ok(())
+                },
+            }),
+        ))
+        .ability(simple_ability(
+            text!["At the start of each main phase, gain 1 mana."],
+            Delegate::MainPhaseBegin(EventDelegate {
+                requirement: face_up_in_play,
+                mutation: |g, s, _| {
+                    assert_eq!(TurnStep::Main, g.data.turn_step);
+                    mana::gain(g, s.side(), 1);
+                    Ok(())
+                },
+            }),
+        ))
+        .ability(simple_ability(
+            text!["At the end of each turn, gain 1 mana."],
+            Delegate::TurnEnd(EventDelegate {
+                requirement: face_up_in_play,
+                mutation: |g, s, turn| {
+                    assert_eq!(TurnStep::End, g.data.turn_step);
+                    assert_eq!(0, g.player(turn.side).actions);
+                    mana::gain(g, s.side(), 1);
+                    Ok(())
+                },
+            }),
+        ))
+        .build();
+    g.play_from_hand(artifact);
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    assert_eq!(STARTING_MANA - test_cards::ARTIFACT_COST + 4, g.me().mana());
+}
+
+#[test]
+fn end_of_turn_trigger_only_on_own_turn() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let artifact = TestCardBuilder::artifact()
+        .ability(simple_ability(
+            text!["At the end of your turn, gain 1 mana."],
+            at_end_of_turn(|g, s, _| {
+                mana::gain(g, s.side(), 1);
+                Ok(())
+            }),
+        ))
+        .build();
+    g.play_from_hand(artifact);
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    assert_eq!(STARTING_MANA - test_cards::ARTIFACT_COST + 1, g.me().mana());
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    assert_eq!(STARTING_MANA - test_cards::ARTIFACT_COST + 1, g.me().mana());
+}
+
+#[test]
+fn main_phase_trigger_only_on_own_turn() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let artifact = TestCardBuilder::artifact()
+        .ability(simple_ability(
+            text!["At the start of your main phase, gain 2 mana."],
+            at_main_phase_begin(|g, s, _| {
+                mana::gain(g, s.side(), 2);
+                Ok(())
+            }),
+        ))
+        .build();
+    g.play_from_hand(artifact);
+    spend_actions_until_turn_over(&mut g, Side::Champion);
+    assert_eq!(STARTING_MANA - test_cards::ARTIFACT_COST, g.me().mana());
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    assert_eq!(STARTING_MANA - test_cards::ARTIFACT_COST + 2, g.me().mana());
+}
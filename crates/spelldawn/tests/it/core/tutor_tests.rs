@@ -0,0 +1,98 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::actions::InterfaceAction;
+use data::card_name::CardName;
+use data::game_actions::{CardPromptAction, GameAction, PromptAction};
+use data::primitives::Side;
+use protos::spelldawn::PlayerName;
+use test_utils::client::TestSession;
+use test_utils::client_interface::HasText;
+use test_utils::*;
+
+#[test]
+fn tutor_displays_deck_in_browser() {
+    let mut g = new_game(
+        Side::Champion,
+        Args { deck_top: Some(CardName::TestWeapon2Attack), ..Args::default() },
+    );
+    g.play_from_hand(CardName::TestChampionTutor);
+    assert!(g.user.cards.revealed_cards().contains(&CardName::TestWeapon2Attack.displayed_name()));
+    assert!(g.user.interface.controls().has_text("Choose a card"));
+    assert!(g.user.interface.controls().has_text("Choose"));
+    assert!(g.opponent.cards.revealed_cards().is_empty());
+    assert!(g.legal_actions(Side::Champion).iter().all(|action| matches!(
+        action,
+        GameAction::PromptAction(PromptAction::CardAction(CardPromptAction::TutorCard(
+            Side::Champion,
+            _
+        )))
+    )));
+}
+
+#[test]
+fn tutor_card_to_hand() {
+    let mut g = new_game(
+        Side::Champion,
+        Args { deck_top: Some(CardName::TestWeapon2Attack), ..Args::default() },
+    );
+    g.play_from_hand(CardName::TestChampionTutor);
+    choose(&mut g, Side::Champion, CardName::TestWeapon2Attack);
+
+    assert_eq!(
+        vec![CardName::TestWeapon2Attack.displayed_name()],
+        g.user.cards.hand(PlayerName::User)
+    );
+    assert_eq!(vec![HIDDEN_CARD], g.opponent.cards.hand(PlayerName::Opponent));
+    assert!(g.user.cards.revealed_cards().is_empty());
+    assert!(!g.user.interface.controls().has_text("Choose a card"));
+}
+
+#[test]
+fn overlord_tutor() {
+    let mut g = new_game(
+        Side::Overlord,
+        Args { deck_top: Some(CardName::TestScheme31), ..Args::default() },
+    );
+    g.play_from_hand(CardName::TestOverlordTutor);
+    choose(&mut g, Side::Overlord, CardName::TestScheme31);
+    assert_eq!(vec![CardName::TestScheme31.displayed_name()], g.user.cards.hand(PlayerName::User));
+}
+
+#[test]
+fn cannot_tutor_unoffered_card() {
+    let mut g = new_game(Side::Champion, Args::default());
+    let spell_id = g.add_to_hand(CardName::TestChampionSpell);
+    g.play_from_hand(CardName::TestChampionTutor);
+    let action = PromptAction::CardAction(CardPromptAction::TutorCard(
+        Side::Champion,
+        server_card_id(spell_id),
+    ));
+    assert!(g.perform_action(action.as_client_action(), g.user_id()).is_err());
+}
+
+/// Selects the `name` card from the current tutor prompt
+fn choose(g: &mut TestSession, side: Side, name: CardName) {
+    let card_id = g
+        .user
+        .cards
+        .card_map
+        .values()
+        .find(|card| card.title_option() == Some(name.displayed_name()))
+        .expect("Card not found")
+        .id();
+    let action =
+        PromptAction::CardAction(CardPromptAction::TutorCard(side, server_card_id(card_id)));
+    g.perform(action.as_client_action(), g.user_id());
+}
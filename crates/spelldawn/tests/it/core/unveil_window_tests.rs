@@ -0,0 +1,123 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::actions::InterfaceAction;
+use data::card_name::CardName;
+use data::game_actions::{CardPromptAction, GameAction, PromptAction};
+use data::primitives::Side;
+use protos::spelldawn::PlayerName;
+use test_utils::client::TestSession;
+use test_utils::client_interface::HasText;
+use test_utils::*;
+
+#[test]
+fn unveil_window_offers_face_down_projects() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let id = g.play_from_hand(CardName::TestProject2Cost);
+    pass_turn(&mut g);
+    assert!(g.user.interface.controls().has_text("Unveil projects?"));
+    assert!(g.user.interface.controls().has_text("Unveil"));
+    assert_eq!(
+        vec![
+            GameAction::PromptAction(PromptAction::CardAction(CardPromptAction::UnveilProject(
+                server_card_id(id)
+            ))),
+            GameAction::PromptAction(PromptAction::CardAction(CardPromptAction::EndUnveilWindow)),
+        ],
+        g.legal_actions(Side::Overlord)
+    );
+}
+
+#[test]
+fn unveil_project_from_window() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let id = g.play_from_hand(CardName::TestProject2Cost);
+    pass_turn(&mut g);
+    respond(&mut g, CardPromptAction::UnveilProject(server_card_id(id)));
+    assert!(g.opponent.cards.get(id).is_face_up());
+    assert_eq!(STARTING_MANA - 2, g.me().mana());
+    assert!(!g.user.interface.controls().has_text("Unveil projects?"));
+}
+
+#[test]
+fn end_unveil_window() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let id = g.play_from_hand(CardName::TestProject2Cost);
+    pass_turn(&mut g);
+    respond(&mut g, CardPromptAction::EndUnveilWindow);
+    assert!(!g.user.cards.get(id).is_face_up());
+    assert_eq!(STARTING_MANA, g.me().mana());
+    assert!(g.legal_actions(Side::Overlord).contains(&GameAction::GainMana));
+}
+
+#[test]
+fn cannot_unveil_project_not_offered() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    g.play_from_hand(CardName::Coinery);
+    pass_turn(&mut g);
+    assert!(!g.user.interface.controls().has_text("Unveil projects?"));
+}
+
+#[test]
+fn pay_upkeep() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let id = g.play_from_hand(CardName::TestProjectUpkeep);
+    pass_turn(&mut g);
+    respond(&mut g, CardPromptAction::UnveilProject(server_card_id(id)));
+    pass_turn(&mut g);
+    assert!(g.user.interface.controls().has_text("Pay upkeep?"));
+    respond(&mut g, CardPromptAction::PayUpkeep(server_card_id(id), 1));
+    assert_eq!(STARTING_MANA - 2, g.me().mana());
+    assert!(g.user.cards.get(id).is_face_up());
+    assert!(g.legal_actions(Side::Overlord).contains(&GameAction::GainMana));
+}
+
+#[test]
+fn discard_instead_of_paying_upkeep() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let id = g.play_from_hand(CardName::TestProjectUpkeep);
+    pass_turn(&mut g);
+    respond(&mut g, CardPromptAction::UnveilProject(server_card_id(id)));
+    pass_turn(&mut g);
+    respond(&mut g, CardPromptAction::DiscardProject(server_card_id(id)));
+    assert_eq!(
+        vec![CardName::TestProjectUpkeep.displayed_name()],
+        g.user.cards.discard_pile(PlayerName::User)
+    );
+    assert_eq!(STARTING_MANA - 1, g.me().mana());
+}
+
+#[test]
+fn unpaid_upkeep_discards_project() {
+    let mut g = new_game(Side::Overlord, Args { mana: 1, ..Args::default() });
+    let id = g.play_from_hand(CardName::TestProjectUpkeep);
+    pass_turn(&mut g);
+    respond(&mut g, CardPromptAction::UnveilProject(server_card_id(id)));
+    pass_turn(&mut g);
+    assert!(!g.user.interface.controls().has_text("Pay upkeep?"));
+    assert_eq!(
+        vec![CardName::TestProjectUpkeep.displayed_name()],
+        g.user.cards.discard_pile(PlayerName::User)
+    );
+}
+
+/// Ends the Overlord's current turn and the Champion's following turn.
+fn pass_turn(g: &mut TestSession) {
+    spend_actions_until_turn_over(g, Side::Overlord);
+    spend_actions_until_turn_over(g, Side::Champion);
+}
+
+fn respond(g: &mut TestSession, action: CardPromptAction) {
+    g.perform(PromptAction::CardAction(action).as_client_action(), g.user_id());
+}
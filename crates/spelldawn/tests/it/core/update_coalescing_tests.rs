@@ -0,0 +1,57 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use card_helpers::{on_cast, simple_ability, text};
+use data::primitives::Side;
+use protos::spelldawn::client_action::Action;
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::{MoveGameObjectsCommand, PlayCardAction};
+use rules::mutations;
+use test_utils::test_card_builder::TestCardBuilder;
+use test_utils::*;
+
+#[test]
+fn separate_draws_render_as_single_move() {
+    let spell = TestCardBuilder::champion_spell()
+        .ability(simple_ability(
+            text!["Draw a card, then draw another card"],
+            on_cast(|g, s, _| {
+                mutations::draw_cards(g, s.side(), 1)?;
+                mutations::draw_cards(g, s.side(), 1).map(|_| ())
+            }),
+        ))
+        .build();
+    let mut g = new_game(Side::Champion, Args::default());
+    let card_id = g.add_to_hand(spell);
+    let response = g
+        .perform_action(
+            Action::PlayCard(PlayCardAction { card_id: Some(card_id), target: None }),
+            g.user_id(),
+        )
+        .unwrap();
+
+    let moves = response
+        .command_list
+        .commands
+        .iter()
+        .filter_map(|c| match &c.command {
+            Some(Command::MoveGameObjects(command)) => Some(command),
+            _ => None,
+        })
+        .collect::<Vec<&MoveGameObjectsCommand>>();
+    assert_eq!(1, moves.len());
+    let sorting_keys =
+        moves[0].moves.iter().map(|m| m.position.as_ref().unwrap().sorting_key).collect::<Vec<_>>();
+    assert_eq!(vec![1, 2], sorting_keys);
+}
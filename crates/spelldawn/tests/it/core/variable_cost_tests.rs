@@ -0,0 +1,63 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_name::CardName;
+use data::card_state::CardPosition;
+use data::game_actions::{CardPromptAction, CardTarget, GameAction, PromptAction};
+use data::primitives::{CardId, Side};
+use rules::card_prompt;
+use test_utils::client_interface::HasText;
+use test_utils::*;
+
+#[test]
+fn variable_cost_prompts_for_x_up_to_available_mana() {
+    let mut g = new_game(Side::Overlord, Args { mana: 4, ..Args::default() });
+    let card_id = server_card_id(g.play_from_hand(CardName::Torment));
+    assert_eq!(
+        CardPosition::Played(Side::Overlord, CardTarget::None),
+        g.game().card(card_id).position()
+    );
+    assert_eq!(3, g.me().mana());
+    assert_eq!(
+        (0..=3).map(|x| choose_x(card_id, x)).collect::<Vec<_>>(),
+        g.legal_actions(Side::Overlord)
+    );
+    assert!(g.user.interface.controls().has_text("Choose X"));
+}
+
+#[test]
+fn cannot_choose_x_above_available_mana() {
+    let mut g = new_game(Side::Overlord, Args { mana: 4, ..Args::default() });
+    let card_id = server_card_id(g.play_from_hand(CardName::Torment));
+    let mut game = g.game().clone();
+    let action = CardPromptAction::ChooseX(card_id, CardTarget::None, 4);
+    assert!(card_prompt::handle(&mut game, Side::Overlord, action).is_err());
+}
+
+#[test]
+fn choosing_zero_resolves_card() {
+    let mut g = new_game(Side::Overlord, Args { mana: 4, ..Args::default() });
+    let card_id = server_card_id(g.play_from_hand(CardName::Torment));
+    g.click_on(g.user_id(), "X = 0");
+    assert_eq!(3, g.me().mana());
+    assert_eq!(CardPosition::DiscardPile(Side::Overlord), g.game().card(card_id).position());
+}
+
+fn choose_x(card_id: CardId, x: u32) -> GameAction {
+    GameAction::PromptAction(PromptAction::CardAction(CardPromptAction::ChooseX(
+        card_id,
+        CardTarget::None,
+        x,
+    )))
+}
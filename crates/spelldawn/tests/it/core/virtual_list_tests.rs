@@ -0,0 +1,89 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core_ui::actions::NoAction;
+use core_ui::prelude::*;
+use core_ui::text::Text;
+use core_ui::virtual_list::VirtualList;
+use protos::spelldawn::Node;
+use test_utils::client_interface::HasText;
+
+fn render(len: usize, offset: usize) -> Node {
+    VirtualList::new("List", len)
+        .offset(offset)
+        .page_size(8)
+        .item(|index| Text::new(format!("Item {}", index)))
+        .page_action(|_| NoAction {})
+        .build()
+        .expect("Node")
+}
+
+fn find<'a>(node: &'a Node, name: &str) -> Option<&'a Node> {
+    if node.name == name {
+        Some(node)
+    } else {
+        node.children.iter().find_map(|child| find(child, name))
+    }
+}
+
+#[test]
+fn renders_current_window() {
+    let node = render(20, 8);
+    assert!(!node.has_text("Item 7"));
+    assert!(node.has_text("Item 8"));
+    assert!(node.has_text("Item 15"));
+    assert!(!node.has_text("Item 16"));
+}
+
+#[test]
+fn item_names_based_on_position() {
+    let node = render(20, 8);
+    assert!(find(&node, "ListItem8").expect("Item").has_text("Item 8"));
+    assert!(find(&node, "ListItem0").is_none());
+}
+
+#[test]
+fn page_controls() {
+    let first = render(20, 0);
+    assert!(find(&first, "ListPreviousPage").expect("Previous").children.is_empty());
+    assert!(!find(&first, "ListNextPage").expect("Next").children.is_empty());
+
+    let last = render(20, 16);
+    assert!(!find(&last, "ListPreviousPage").expect("Previous").children.is_empty());
+    assert!(find(&last, "ListNextPage").expect("Next").children.is_empty());
+}
+
+#[test]
+fn clamps_offset_to_last_page() {
+    let node = render(10, 50);
+    assert!(node.has_text("Item 8"));
+    assert!(node.has_text("Item 9"));
+    assert!(!node.has_text("Item 7"));
+}
+
+#[test]
+fn fills_rows_with_placeholders() {
+    let node = VirtualList::new("List", 6)
+        .page_size(8)
+        .row_size(4)
+        .item(|index| Text::new(format!("Item {}", index)))
+        .placeholder(|| Text::new("Empty"))
+        .build()
+        .expect("Node");
+    assert_eq!(4, find(&node, "ListRow0").expect("Row").children.len());
+    let second_row = find(&node, "ListRow1").expect("Row");
+    assert_eq!(4, second_row.children.len());
+    assert!(second_row.has_text("Empty"));
+    assert!(find(&node, "ListRow2").is_none());
+}
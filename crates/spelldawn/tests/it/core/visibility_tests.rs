@@ -0,0 +1,124 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::card_name::CardName;
+use data::primitives::Side;
+use protos::spelldawn::client_action::Action;
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::{
+    card_target, CardIdentifier, CardTarget, CardView, CommandList, PlayCardAction,
+};
+use test_utils::client::TestSession;
+use test_utils::test_card_builder::TestCardBuilder;
+use test_utils::*;
+
+/// All [CardView]s for `card_id` in the provided commands
+fn card_views(commands: &CommandList, card_id: CardIdentifier) -> Vec<CardView> {
+    commands
+        .commands
+        .iter()
+        .flat_map(|command| match command.command.as_ref() {
+            Some(Command::UpdateGameView(update)) => {
+                update.game.as_ref().map_or(vec![], |game| game.cards.clone())
+            }
+            Some(Command::CreateTokenCard(create)) => create.card.iter().cloned().collect(),
+            _ => vec![],
+        })
+        .filter(|view| view.card_id == Some(card_id))
+        .collect()
+}
+
+/// Asserts that `commands` contain no identifying information about `name`.
+fn assert_hidden(commands: &CommandList, card_id: CardIdentifier, name: CardName) {
+    assert!(!format!("{:?}", commands).contains(&name.displayed_name()));
+    let views = card_views(commands, card_id);
+    assert!(!views.is_empty());
+    for view in views {
+        assert!(!view.revealed_to_viewer);
+        assert!(view.revealed_card.is_none());
+        assert!(view.arena_frame.is_none());
+        let icons = view.card_icons.unwrap_or_default();
+        assert!(icons.top_left_icon.is_none());
+        assert!(icons.top_right_icon.is_none());
+        assert!(icons.bottom_left_icon.is_none());
+        assert!(icons.bottom_right_icon.is_none());
+    }
+}
+
+/// Plays `name` face-down into [ROOM_ID] as the Overlord, returning the
+/// commands sent to the Champion.
+fn play_face_down(g: &mut TestSession, name: CardName) -> (CardIdentifier, CommandList) {
+    let card_id = g.add_to_hand(name);
+    let response = g
+        .perform_action(
+            Action::PlayCard(PlayCardAction {
+                card_id: Some(card_id),
+                target: Some(CardTarget {
+                    card_target: Some(card_target::CardTarget::RoomId(adapters::room_identifier(
+                        ROOM_ID,
+                    ))),
+                }),
+            }),
+            g.user_id(),
+        )
+        .expect("Server Error");
+    let (_, commands) = response.opponent_response.expect("Opponent response");
+    (card_id, commands)
+}
+
+#[test]
+fn face_down_minion_hidden_from_champion() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let minion = TestCardBuilder::minion().health(5).shield(2).combat_end_raid().build();
+    let (card_id, commands) = play_face_down(&mut g, minion);
+    assert_hidden(&commands, card_id, minion);
+}
+
+#[test]
+fn face_down_scheme_hidden_from_champion() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let (card_id, commands) = play_face_down(&mut g, CardName::TestScheme31);
+    assert_hidden(&commands, card_id, CardName::TestScheme31);
+}
+
+#[test]
+fn face_down_cards_hidden_on_reconnect() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let minion = TestCardBuilder::minion().health(5).combat_end_raid().build();
+    let (minion_id, _) = play_face_down(&mut g, minion);
+    let (scheme_id, _) = play_face_down(&mut g, CardName::TestScheme31);
+    let commands = g.connect(g.opponent_id()).expect("Connect");
+    assert_hidden(&commands, minion_id, minion);
+    assert_hidden(&commands, scheme_id, CardName::TestScheme31);
+}
+
+#[test]
+fn face_down_card_visible_to_owner() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let minion = TestCardBuilder::minion().health(5).combat_end_raid().build();
+    g.play_from_hand(minion);
+    let commands = g.connect(g.user_id()).expect("Connect");
+    assert!(format!("{:?}", commands).contains(&minion.displayed_name()));
+}
+
+#[test]
+fn summoned_minion_revealed_to_champion() {
+    let mut g = new_game(Side::Overlord, Args::default());
+    let minion = TestCardBuilder::minion().health(5).combat_end_raid().build();
+    g.play_from_hand(minion);
+    g.play_from_hand(CardName::TestScheme31);
+    spend_actions_until_turn_over(&mut g, Side::Overlord);
+    let response = g.initiate_raid(ROOM_ID);
+    assert!(format!("{:?}", response.command_list).contains(&minion.displayed_name()));
+}
@@ -0,0 +1,86 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! GRPC client for the Spelldawn service.
+//!
+//! The client generated by tonic-build can't be used here because its
+//! `connect` constructor conflicts with the `Connect` RPC method.
+
+use anyhow::Result;
+use protos::spelldawn::{
+    AuthenticateRequest, AuthenticateResponse, CommandList, ConnectRequest, GameRequest,
+};
+use tonic::client::Grpc;
+use tonic::codec::{ProstCodec, Streaming};
+use tonic::codegen::http::uri::PathAndQuery;
+use tonic::transport::{Channel, Endpoint};
+use tonic::{IntoRequest, Status};
+
+pub struct GameClient {
+    inner: Grpc<Channel>,
+}
+
+impl GameClient {
+    /// Opens a connection to the server at `address`, e.g.
+    /// `http://localhost:80`.
+    pub async fn new(address: String) -> Result<Self> {
+        let channel = Endpoint::new(address)?.connect().await?;
+        Ok(Self { inner: Grpc::new(channel).accept_gzip() })
+    }
+
+    pub async fn authenticate(
+        &mut self,
+        request: AuthenticateRequest,
+    ) -> Result<AuthenticateResponse, Status> {
+        self.ready().await?;
+        let path = PathAndQuery::from_static("/spelldawn.Spelldawn/Authenticate");
+        Ok(self
+            .inner
+            .unary(request.into_request(), path, ProstCodec::default())
+            .await?
+            .into_inner())
+    }
+
+    /// Returns a stream of updates for the requesting player, beginning with
+    /// the current state of their game.
+    pub async fn connect(
+        &mut self,
+        request: ConnectRequest,
+    ) -> Result<Streaming<CommandList>, Status> {
+        self.ready().await?;
+        let path = PathAndQuery::from_static("/spelldawn.Spelldawn/Connect");
+        Ok(self
+            .inner
+            .server_streaming(request.into_request(), path, ProstCodec::default())
+            .await?
+            .into_inner())
+    }
+
+    pub async fn perform_action(&mut self, request: GameRequest) -> Result<CommandList, Status> {
+        self.ready().await?;
+        let path = PathAndQuery::from_static("/spelldawn.Spelldawn/PerformAction");
+        Ok(self
+            .inner
+            .unary(request.into_request(), path, ProstCodec::default())
+            .await?
+            .into_inner())
+    }
+
+    async fn ready(&mut self) -> Result<(), Status> {
+        self.inner
+            .ready()
+            .await
+            .map_err(|e| Status::unknown(format!("Service was not ready: {}", e)))
+    }
+}
@@ -0,0 +1,269 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tracks the state of the user interface as described by the commands received
+//! from the server.
+
+use std::collections::HashMap;
+
+use core_ui::node_diff;
+use protos::spelldawn::client_debug_command::DebugCommand;
+use protos::spelldawn::game_command::Command;
+use protos::spelldawn::object_position::Position;
+use protos::spelldawn::toggle_panel_command::ToggleCommand;
+use protos::spelldawn::{
+    node_type, CardView, ClientAction, CommandList, GameMessageType, GameView,
+    InterfacePanelAddress, Node, PlayerName,
+};
+
+/// A clickable interface element, such as a prompt response.
+#[derive(Debug, Clone)]
+pub struct Button {
+    /// Text displayed within this button
+    pub label: String,
+    /// Action to perform when this button is clicked
+    pub action: ClientAction,
+}
+
+/// Client state built up by applying the server's command stream
+#[derive(Debug, Clone, Default)]
+pub struct ClientState {
+    /// Name of the most recently loaded scene
+    pub scene: Option<String>,
+    /// Current view of the ongoing game, if any
+    pub game: Option<GameView>,
+    /// Most recent main controls node received, used as the base for patches
+    main_controls: Option<Node>,
+    panels: HashMap<InterfacePanelAddress, Node>,
+    open_panels: Vec<InterfacePanelAddress>,
+    messages: Vec<String>,
+    pending_actions: Vec<ClientAction>,
+}
+
+impl ClientState {
+    /// Updates this state by applying each command in `commands`.
+    pub fn apply(&mut self, commands: CommandList) {
+        for command in commands.commands.into_iter().filter_map(|c| c.command) {
+            self.apply_command(command);
+        }
+    }
+
+    /// Interface panels which are currently open, in the order they were
+    /// opened.
+    pub fn open_panels(&self) -> &Vec<InterfacePanelAddress> {
+        &self.open_panels
+    }
+
+    /// Removes and returns messages which should be displayed to the user,
+    /// such as notifications and turn changes.
+    pub fn take_messages(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.messages)
+    }
+
+    /// Removes and returns actions which the server requested the client
+    /// perform on its behalf.
+    pub fn take_pending_actions(&mut self) -> Vec<ClientAction> {
+        std::mem::take(&mut self.pending_actions)
+    }
+
+    /// Cards in the indicated player's hand, in display order.
+    pub fn hand(&self, player: PlayerName) -> Vec<&CardView> {
+        self.cards_matching(
+            |position| matches!(position, Position::Hand(hand) if hand.owner() == player),
+        )
+    }
+
+    /// Cards in the current game whose position matches `predicate`, in
+    /// display order.
+    pub fn cards_matching(&self, predicate: impl Fn(&Position) -> bool) -> Vec<&CardView> {
+        let Some(game) = &self.game else {
+            return vec![];
+        };
+        let mut cards = game
+            .cards
+            .iter()
+            .filter(|card| {
+                card.card_position
+                    .as_ref()
+                    .and_then(|p| p.position.as_ref())
+                    .is_some_and(&predicate)
+            })
+            .collect::<Vec<_>>();
+        cards.sort_by_key(|card| {
+            card.card_position.as_ref().map(|p| (p.sorting_key, p.sorting_subkey))
+        });
+        cards
+    }
+
+    /// Buttons which can currently be clicked, from the game's main controls,
+    /// card controls, and the topmost open panel.
+    pub fn buttons(&self) -> Vec<Button> {
+        let mut result = vec![];
+        if let Some(controls) = self.game.as_ref().and_then(|g| g.main_controls.as_ref()) {
+            if let Some(node) = &controls.node {
+                find_buttons(node, &mut result);
+            }
+            for anchor in &controls.card_anchor_nodes {
+                if let Some(node) = &anchor.node {
+                    find_buttons(node, &mut result);
+                }
+            }
+        }
+        if let Some(panel) = self.open_panels.last().and_then(|a| self.panels.get(a)) {
+            find_buttons(panel, &mut result);
+        }
+        result
+    }
+
+    fn apply_command(&mut self, command: Command) {
+        match command {
+            Command::UpdateGameView(mut update) => {
+                if let Some(controls) = update.game.as_mut().and_then(|g| g.main_controls.as_mut())
+                {
+                    let patches = controls.node_patches.take();
+                    match node_diff::receive(&mut self.main_controls, controls.node.take(), patches)
+                    {
+                        Ok(node) => controls.node = node,
+                        Err(error) => self.messages.push(format!("Error: {:#}", error)),
+                    }
+                }
+                self.game = update.game;
+            }
+            Command::LoadScene(load) => {
+                if load.scene_name != "Game" {
+                    self.game = None;
+                }
+                self.scene = Some(load.scene_name);
+            }
+            Command::UpdatePanels(update) => {
+                for panel in update.panels {
+                    let Some(address) = panel.address else {
+                        continue;
+                    };
+                    let mut previous = self.panels.remove(&address);
+                    if let Err(error) =
+                        node_diff::receive(&mut previous, panel.node, panel.node_patches)
+                    {
+                        self.messages.push(format!("Error: {:#}", error));
+                    }
+                    if let Some(node) = previous {
+                        self.panels.insert(address, node);
+                    }
+                }
+            }
+            Command::TogglePanel(toggle) => {
+                if let Some(toggle) = toggle.toggle_command {
+                    self.toggle_panel(toggle);
+                }
+            }
+            Command::DisplayGameMessage(message) => {
+                self.messages.push(
+                    match message.message_type() {
+                        GameMessageType::Dawn => "Dawn: the Champion's turn begins",
+                        GameMessageType::Dusk => "Dusk: the Overlord's turn begins",
+                        GameMessageType::Victory => "Victory!",
+                        GameMessageType::Defeat => "Defeat",
+                        GameMessageType::Unspecified => "Unknown message",
+                    }
+                    .to_string(),
+                );
+            }
+            Command::ShowToast(toast) => {
+                if let Some(content) = toast.content {
+                    self.messages.push(text(&content));
+                }
+            }
+            Command::Debug(debug) => {
+                if let Some(DebugCommand::InvokeAction(action)) = debug.debug_command {
+                    self.pending_actions.push(action);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn toggle_panel(&mut self, toggle: ToggleCommand) {
+        match toggle {
+            ToggleCommand::Transition(transition) => {
+                if let Some(close) = transition.close {
+                    self.open_panels.retain(|a| *a != close);
+                }
+                if let Some(open) = transition.open {
+                    self.open(open);
+                }
+            }
+            ToggleCommand::LoadPanel(load) => {
+                if let Some(address) = load.open_panel {
+                    self.open(address);
+                }
+            }
+            ToggleCommand::WaitFor(wait_for) => {
+                if let Some(address) = wait_for.open_panel {
+                    self.open(address);
+                }
+            }
+            ToggleCommand::SetPanel(address) => {
+                self.open_panels.clear();
+                self.open_panels.push(address);
+            }
+            ToggleCommand::OpenPanel(address) | ToggleCommand::OpenExistingPanel(address) => {
+                self.open(address);
+            }
+            ToggleCommand::ClosePanel(address) => {
+                self.open_panels.retain(|a| *a != address);
+            }
+            ToggleCommand::CloseAll(_) => {
+                self.open_panels.clear();
+            }
+            _ => {}
+        }
+    }
+
+    fn open(&mut self, address: InterfacePanelAddress) {
+        if !self.open_panels.contains(&address) {
+            self.open_panels.push(address);
+        }
+    }
+}
+
+/// Returns the combined contents of all text nodes within `node`.
+pub fn text(node: &Node) -> String {
+    let mut result = vec![];
+    collect_text(node, &mut result);
+    result.join(" ")
+}
+
+fn collect_text(node: &Node, output: &mut Vec<String>) {
+    if let Some(node_type::NodeType::Text(text)) =
+        node.node_type.as_ref().and_then(|t| t.node_type.as_ref())
+    {
+        if !text.label.is_empty() {
+            output.push(text.label.clone());
+        }
+    }
+    for child in &node.children {
+        collect_text(child, output);
+    }
+}
+
+fn find_buttons(node: &Node, output: &mut Vec<Button>) {
+    let on_click = node.event_handlers.as_ref().and_then(|h| h.on_click.as_ref());
+    if let Some(action) = on_click.filter(|a| a.action.is_some()) {
+        output.push(Button { label: text(node), action: action.clone() });
+    } else {
+        for child in &node.children {
+            find_buttons(child, output);
+        }
+    }
+}
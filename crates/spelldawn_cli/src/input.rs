@@ -0,0 +1,129 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Parses commands typed by the user
+
+use anyhow::Result;
+use core_ui::actions::InterfaceAction;
+use data::primitives::Side;
+use data::user_actions::DebugAction;
+use protos::spelldawn::client_action::Action;
+use protos::spelldawn::{
+    card_target, CardTarget, ClientAction, DrawCardAction, GainManaAction, InitiateRaidAction,
+    LevelUpRoomAction, PlayCardAction, PlayerName, RoomIdentifier,
+};
+use with_error::{fail, WithError};
+
+use crate::client_state::ClientState;
+
+/// Description of the available commands
+pub const HELP: &str = "Commands:
+  draw                 Draw a card
+  mana                 Gain one mana
+  play <n> [room]      Play the nth card in your hand, optionally targeting a room
+  level <room>         Level up a room
+  raid <room>          Initiate a raid on a room
+  click <n>            Click the nth button
+  new <side>           Start a new game as 'overlord' or 'champion'
+  join                 Join the game started by another player via 'new'
+  show                 Display the current game
+  help                 Display this message
+  quit                 Exit
+
+Rooms are 'vault', 'sanctum', 'crypts', or 'a' through 'e'.";
+
+/// A command entered by the user
+#[derive(Debug, Clone)]
+pub enum Input {
+    /// Send an action to the server
+    Action(ClientAction),
+    Show,
+    Help,
+    Quit,
+}
+
+/// Parses a line of user input. Card and button numbers refer to the values
+/// displayed for the current `state`.
+pub fn parse(state: &ClientState, line: &str) -> Result<Input> {
+    let words = line.split_whitespace().collect::<Vec<_>>();
+    let action = match words.as_slice() {
+        ["draw"] => Action::DrawCard(DrawCardAction {}),
+        ["mana"] => Action::GainMana(GainManaAction {}),
+        ["play", card] => play_card(state, card, None)?,
+        ["play", card, room] => play_card(state, card, Some(parse_room(room)?))?,
+        ["level", room] => {
+            Action::LevelUpRoom(LevelUpRoomAction { room_id: parse_room(room)?.into() })
+        }
+        ["raid", room] => {
+            Action::InitiateRaid(InitiateRaidAction { room_id: parse_room(room)?.into() })
+        }
+        ["click", button] => {
+            let buttons = state.buttons();
+            let button = buttons
+                .get(parse_index(button)?)
+                .with_error(|| format!("No button numbered {}", button))?;
+            return Ok(Input::Action(button.action.clone()));
+        }
+        ["new", side] => DebugAction::NewGame(parse_side(side)?).as_client_action(),
+        ["join"] => DebugAction::JoinGame.as_client_action(),
+        ["show"] => return Ok(Input::Show),
+        ["help"] => return Ok(Input::Help),
+        ["quit"] | ["exit"] => return Ok(Input::Quit),
+        _ => fail!("Unrecognized command '{}', type 'help' for a list of commands", line.trim()),
+    };
+    Ok(Input::Action(ClientAction { action: Some(action) }))
+}
+
+/// Parses a room name such as 'vault' or 'a'.
+pub fn parse_room(name: &str) -> Result<RoomIdentifier> {
+    Ok(match name.to_lowercase().as_str() {
+        "vault" => RoomIdentifier::Vault,
+        "sanctum" => RoomIdentifier::Sanctum,
+        "crypts" => RoomIdentifier::Crypts,
+        "a" => RoomIdentifier::RoomA,
+        "b" => RoomIdentifier::RoomB,
+        "c" => RoomIdentifier::RoomC,
+        "d" => RoomIdentifier::RoomD,
+        "e" => RoomIdentifier::RoomE,
+        _ => fail!("Unknown room '{}'", name),
+    })
+}
+
+fn parse_side(name: &str) -> Result<Side> {
+    Ok(match name.to_lowercase().as_str() {
+        "overlord" => Side::Overlord,
+        "champion" => Side::Champion,
+        _ => fail!("Unknown side '{}'", name),
+    })
+}
+
+/// Parses a 1-based number as displayed to the user into a 0-based index.
+fn parse_index(value: &str) -> Result<usize> {
+    match value.parse::<usize>() {
+        Ok(n) if n > 0 => Ok(n - 1),
+        _ => fail!("Expected a positive number, got '{}'", value),
+    }
+}
+
+fn play_card(state: &ClientState, card: &str, room: Option<RoomIdentifier>) -> Result<Action> {
+    let hand = state.hand(PlayerName::User);
+    let card_view =
+        hand.get(parse_index(card)?).with_error(|| format!("No card numbered {} in hand", card))?;
+    Ok(Action::PlayCard(PlayCardAction {
+        card_id: card_view.card_id,
+        target: room.map(|room| CardTarget {
+            card_target: Some(card_target::CardTarget::RoomId(room.into())),
+        }),
+    }))
+}
@@ -0,0 +1,158 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Renders the client state as text
+
+use std::fmt::Write;
+
+use protos::spelldawn::object_position::Position;
+use protos::spelldawn::{CardView, PlayerName, PlayerSide, PlayerView, RoomIdentifier};
+
+use crate::client_state::ClientState;
+
+/// Rooms in the order they are displayed
+pub const ROOMS: [RoomIdentifier; 8] = [
+    RoomIdentifier::Vault,
+    RoomIdentifier::Sanctum,
+    RoomIdentifier::Crypts,
+    RoomIdentifier::RoomA,
+    RoomIdentifier::RoomB,
+    RoomIdentifier::RoomC,
+    RoomIdentifier::RoomD,
+    RoomIdentifier::RoomE,
+];
+
+/// Returns a description of the current game and the available buttons.
+pub fn render(state: &ClientState) -> String {
+    let mut output = String::new();
+    if let Some(game) = &state.game {
+        render_player(&mut output, state, "Opponent", game.opponent.as_ref(), PlayerName::Opponent);
+        render_rooms(&mut output, state);
+        render_player(&mut output, state, "You", game.user.as_ref(), PlayerName::User);
+        if game.raid_active {
+            writeln!(output, "A raid is in progress").unwrap();
+        }
+        let revealed = state.cards_matching(|position| {
+            matches!(position, Position::Browser(_) | Position::Revealed(_) | Position::Raid(_))
+        });
+        if !revealed.is_empty() {
+            writeln!(output, "Revealed: {}", names(&revealed)).unwrap();
+        }
+    } else if let Some(scene) = &state.scene {
+        writeln!(output, "Scene: {}", scene).unwrap();
+    }
+
+    let buttons = state.buttons();
+    if !buttons.is_empty() {
+        writeln!(output, "Buttons:").unwrap();
+        for (i, button) in buttons.iter().enumerate() {
+            writeln!(output, "  [{}] {}", i + 1, button.label).unwrap();
+        }
+    }
+    output
+}
+
+/// Returns the displayed name of a card, or "Hidden Card" if the viewer cannot
+/// see it.
+pub fn card_name(card: &CardView) -> String {
+    card.revealed_card
+        .as_ref()
+        .and_then(|revealed| revealed.title.as_ref())
+        .map_or_else(|| "Hidden Card".to_string(), |title| title.text.clone())
+}
+
+/// Returns the displayed name of a room.
+pub fn room_name(room: RoomIdentifier) -> &'static str {
+    match room {
+        RoomIdentifier::Unspecified => "Unknown Room",
+        RoomIdentifier::Vault => "Vault",
+        RoomIdentifier::Sanctum => "Sanctum",
+        RoomIdentifier::Crypts => "Crypts",
+        RoomIdentifier::RoomA => "Room A",
+        RoomIdentifier::RoomB => "Room B",
+        RoomIdentifier::RoomC => "Room C",
+        RoomIdentifier::RoomD => "Room D",
+        RoomIdentifier::RoomE => "Room E",
+    }
+}
+
+fn render_player(
+    output: &mut String,
+    state: &ClientState,
+    label: &str,
+    view: Option<&PlayerView>,
+    player: PlayerName,
+) {
+    let Some(view) = view else {
+        return;
+    };
+    let side = match view.side() {
+        PlayerSide::Overlord => "Overlord",
+        PlayerSide::Champion => "Champion",
+        PlayerSide::Unspecified => "Unknown",
+    };
+    writeln!(output, "== {} ({}) ==", label, side).unwrap();
+    writeln!(
+        output,
+        "Score: {}  Mana: {}  Actions: {}{}",
+        view.score.as_ref().map_or(0, |s| s.score),
+        view.mana.as_ref().map_or(0, |m| m.base_mana + m.bonus_mana),
+        view.action_tracker.as_ref().map_or(0, |a| a.available_action_count),
+        if view.can_take_action { "  (can act)" } else { "" }
+    )
+    .unwrap();
+
+    // Only the Champion player has items
+    let items = state.cards_matching(|position| matches!(position, Position::Item(_)));
+    if view.side() == PlayerSide::Champion && !items.is_empty() {
+        writeln!(output, "Items: {}", names(&items)).unwrap();
+    }
+
+    let hand = state.hand(player);
+    match player {
+        PlayerName::User => {
+            writeln!(output, "Hand:").unwrap();
+            for (i, card) in hand.iter().enumerate() {
+                writeln!(output, "  {}. {}", i + 1, card_name(card)).unwrap();
+            }
+        }
+        _ => writeln!(output, "Hand: {} cards", hand.len()).unwrap(),
+    }
+}
+
+fn render_rooms(output: &mut String, state: &ClientState) {
+    writeln!(output, "== Rooms ==").unwrap();
+    for room in ROOMS {
+        let cards = state.cards_matching(
+            |position| matches!(position, Position::Room(r) if r.room_id() == room),
+        );
+        if !cards.is_empty() {
+            let names = cards
+                .iter()
+                .map(|card| {
+                    if card.revealed_to_viewer && !card.is_face_up {
+                        format!("{} (face down)", card_name(card))
+                    } else {
+                        card_name(card)
+                    }
+                })
+                .collect::<Vec<_>>();
+            writeln!(output, "{}: {}", room_name(room), names.join(", ")).unwrap();
+        }
+    }
+}
+
+fn names(cards: &[&CardView]) -> String {
+    cards.iter().map(|card| card_name(card)).collect::<Vec<_>>().join(", ")
+}
@@ -0,0 +1,197 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Connects to a server and runs the interactive command loop
+
+use anyhow::Result;
+use clap::Parser;
+use prost::Message;
+use protos::spelldawn::player_identifier::PlayerIdentifierType;
+use protos::spelldawn::{
+    AuthenticateRequest, ClientAction, ConnectRequest, GameRequest, PlayerIdentifier, ServerError,
+};
+use tokio::io::{self, AsyncBufReadExt, BufReader};
+use tonic::Status;
+use with_error::fail;
+
+use crate::client::GameClient;
+use crate::client_state::ClientState;
+use crate::input::{Input, HELP};
+use crate::render;
+
+#[derive(Parser)]
+#[clap(author, version, about, long_about = None)]
+pub struct Args {
+    /// Address of the server to connect to
+    #[clap(long, value_parser, default_value = "http://localhost:80")]
+    pub address: String,
+    /// Device identifier to authenticate with. Each distinct identifier is a
+    /// separate player.
+    #[clap(long, value_parser, default_value = "spelldawn_cli")]
+    pub player: String,
+    /// Secret printed when this player first authenticated. Omit for a new
+    /// player.
+    #[clap(long, value_parser, default_value = "")]
+    pub secret: String,
+    /// Whether to exit with an error if the server rejects an action, e.g.
+    /// when running a smoke test script.
+    #[clap(long, value_parser, default_value_t = false)]
+    pub fail_on_error: bool,
+}
+
+/// An authenticated connection to the server
+struct Session {
+    client: GameClient,
+    player_id: PlayerIdentifier,
+    session_token: String,
+    client_id: String,
+    fail_on_error: bool,
+}
+
+pub async fn main() -> Result<()> {
+    let args: Args = Args::parse();
+    let mut client = GameClient::new(args.address.clone()).await?;
+    let player_id = PlayerIdentifier {
+        player_identifier_type: Some(PlayerIdentifierType::DeviceIdentifier(args.player)),
+    };
+    let authentication = client
+        .authenticate(AuthenticateRequest {
+            player_id: Some(player_id.clone()),
+            player_secret: args.secret.clone(),
+        })
+        .await?;
+    if !authentication.player_secret.is_empty() {
+        println!("Created new player, reconnect with --secret {}", authentication.player_secret);
+    }
+    let session_token = authentication.session_token;
+    let mut session = Session {
+        client,
+        player_id,
+        session_token,
+        client_id: format!("spelldawn_cli_{}", std::process::id()),
+        fail_on_error: args.fail_on_error,
+    };
+
+    let mut updates = session
+        .client
+        .connect(ConnectRequest {
+            player_id: Some(session.player_id.clone()),
+            session_token: session.session_token.clone(),
+            client_id: session.client_id.clone(),
+        })
+        .await?;
+    let mut lines = BufReader::new(io::stdin()).lines();
+    let mut state = ClientState::default();
+    println!("Connected to {}. Type 'help' for a list of commands.", args.address);
+
+    // Apply the initial state before reading any input, so that it does not
+    // overwrite the results of the user's first actions.
+    let Some(initial) = updates.message().await? else {
+        fail!("Server closed the connection");
+    };
+    state.apply(initial);
+    session.perform_pending(&mut state).await?;
+    display(&mut state);
+
+    loop {
+        tokio::select! {
+            update = updates.message() => {
+                let Some(commands) = update? else {
+                    fail!("Server closed the connection");
+                };
+                state.apply(commands);
+                session.perform_pending(&mut state).await?;
+                display(&mut state);
+            }
+            line = lines.next_line() => {
+                let Some(line) = line? else {
+                    return Ok(());
+                };
+                if line.trim().is_empty() {
+                    continue;
+                }
+                match crate::input::parse(&state, &line) {
+                    Ok(Input::Action(action)) => {
+                        session.perform(&mut state, action).await?;
+                        session.perform_pending(&mut state).await?;
+                        display(&mut state);
+                    }
+                    Ok(Input::Show) => display(&mut state),
+                    Ok(Input::Help) => println!("{}", HELP),
+                    Ok(Input::Quit) => return Ok(()),
+                    Err(error) => println!("{:#}", error),
+                }
+            }
+        }
+    }
+}
+
+impl Session {
+    /// Sends `action` to the server and applies the response to `state`.
+    ///
+    /// Actions rejected by the server are reported to the user, and cause an
+    /// error to be returned only if `fail_on_error` is set.
+    async fn perform(&mut self, state: &mut ClientState, action: ClientAction) -> Result<()> {
+        let request = GameRequest {
+            action: Some(action),
+            player_id: Some(self.player_id.clone()),
+            open_panels: state.open_panels().clone(),
+            session_token: self.session_token.clone(),
+            client_id: self.client_id.clone(),
+        };
+        match self.client.perform_action(request).await {
+            Ok(response) => {
+                state.apply(response);
+                Ok(())
+            }
+            Err(status) => {
+                let message = error_message(&status);
+                if self.fail_on_error {
+                    fail!("{}", message);
+                }
+                println!("{}", message);
+                Ok(())
+            }
+        }
+    }
+
+    /// Performs actions the server has asked the client to invoke.
+    async fn perform_pending(&mut self, state: &mut ClientState) -> Result<()> {
+        loop {
+            let actions = state.take_pending_actions();
+            if actions.is_empty() {
+                return Ok(());
+            }
+            for action in actions {
+                self.perform(state, action).await?;
+            }
+        }
+    }
+}
+
+fn display(state: &mut ClientState) {
+    for message in state.take_messages() {
+        println!("* {}", message);
+    }
+    print!("{}", render::render(state));
+}
+
+/// Describes a failed request, using the [ServerError] attached to `status`
+/// if one is present.
+fn error_message(status: &Status) -> String {
+    match ServerError::decode(status.details()) {
+        Ok(error) if !status.details().is_empty() => format!("Error: {}", error.message),
+        _ => format!("Error ({:?}): {}", status.code(), status.message()),
+    }
+}
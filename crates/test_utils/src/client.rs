@@ -20,31 +20,38 @@ use std::collections::HashMap;
 
 use actions::legal_actions;
 use adapters;
+use adapters::response_builder::CardPositions;
 use anyhow::Result;
 use data::card_name::CardName;
 use data::card_state::{CardPosition, CardState};
+use data::card_stats::CardStatistics;
 use data::game::GameState;
 use data::game_actions::GameAction;
+use data::match_state::MatchState;
+use data::player_data::PlayerData;
 use data::player_name::PlayerId;
 use data::primitives::{
-    ActionCount, CardId, CardType, GameId, ManaValue, PointsValue, RoomId, Side,
+    ActionCount, CardId, CardType, GameId, ManaValue, MatchId, PointsValue, RoomId, Side,
 };
+use data::ratings::PlayerRating;
+use database::Database;
 use protos::spelldawn::card_targeting::Targeting;
 use protos::spelldawn::client_action::Action;
 use protos::spelldawn::game_command::Command;
 use protos::spelldawn::game_object_identifier::Id;
 use protos::spelldawn::object_position::Position;
 use protos::spelldawn::{
-    card_target, ArrowTargetRoom, CardIdentifier, CardTarget, CardView, ClientAction,
+    card_target, ArrowTargetRoom, CardIdentifier, CardInfoView, CardTarget, CardView, ClientAction,
     ClientItemLocation, ClientRoomLocation, CommandList, GameMessageType, GameObjectIdentifier,
-    GameRequest, InitiateRaidAction, NoTargeting, ObjectPosition, ObjectPositionBrowser,
-    ObjectPositionDiscardPile, ObjectPositionHand, ObjectPositionItem, ObjectPositionRevealedCards,
-    ObjectPositionRoom, PlayCardAction, PlayInRoom, PlayerName, PlayerView, RevealedCardView,
-    RevealedCardsBrowserSize, RoomIdentifier,
+    GameRequest, InitiateRaidAction, KeywordTooltip, ManaPoolView, NoTargeting, ObjectPosition,
+    ObjectPositionBrowser, ObjectPositionDiscardPile, ObjectPositionGlobalModifier,
+    ObjectPositionHand, ObjectPositionItem, ObjectPositionRevealedCards, ObjectPositionRoom,
+    PlayCardAction, PlayInRoom, PlayerName, PlayerView, RevealedCardView, RevealedCardsBrowserSize,
+    RoomIdentifier, SchemeProgress,
 };
 use rules::dispatch;
-use server::requests;
 use server::requests::GameResponse;
+use server::{abandonment, quarantine};
 use with_error::WithError;
 
 use crate::client_interface::{ClientInterface, HasText};
@@ -71,6 +78,8 @@ pub struct TestSession {
     /// parameter to [Self::new].
     pub opponent: TestClient,
     database: FakeDatabase,
+    /// Session tokens issued to each non-AI player in this game.
+    session_tokens: HashMap<PlayerId, String>,
 }
 
 impl TestSession {
@@ -80,14 +89,57 @@ impl TestSession {
     /// state via the action methods on this struct instead of putting a bunch
     /// of information into the [GameState] here, because this helps avoid
     /// coupling tests to the specific implementation details of [GameState].
-    pub fn new(database: FakeDatabase, user_id: PlayerId, opponent_id: PlayerId) -> Self {
-        Self { user: TestClient::new(user_id), opponent: TestClient::new(opponent_id), database }
+    pub fn new(mut database: FakeDatabase, user_id: PlayerId, opponent_id: PlayerId) -> Self {
+        let session_tokens = [user_id, opponent_id]
+            .into_iter()
+            .filter(|id| matches!(id, PlayerId::Database(_)))
+            .map(|id| (id, fake_database::authenticate(&mut database, id)))
+            .collect();
+        Self {
+            user: TestClient::new(user_id),
+            opponent: TestClient::new(opponent_id),
+            database,
+            session_tokens,
+        }
+    }
+
+    /// Returns the persisted [PlayerData] for the provided player.
+    pub fn player_data(&self, player_id: PlayerId) -> &PlayerData {
+        self.database.players.get(&player_id).expect("PlayerData not found")
+    }
+
+    /// Returns the persisted [MatchState] for the provided match.
+    pub fn match_state(&self, match_id: MatchId) -> &MatchState {
+        self.database.matches.get(&match_id).expect("MatchState not found")
+    }
+
+    /// Returns the persisted [PlayerRating] for the provided player, if they
+    /// have played a ranked game.
+    pub fn rating(&self, player_id: PlayerId) -> Option<&PlayerRating> {
+        self.database.ratings.get(&player_id)
+    }
+
+    /// Returns the persisted [CardStatistics] for the provided card, if any
+    /// have been recorded.
+    pub fn card_statistics(&self, card_name: CardName) -> Option<&CardStatistics> {
+        self.database.card_statistics.get(&card_name)
     }
 
     pub fn game_id(&self) -> GameId {
         self.database.game().id
     }
 
+    /// Returns the current state of this session's game.
+    pub fn game(&self) -> &GameState {
+        self.database.game()
+    }
+
+    /// Client card positions most recently recorded for the `player_id`
+    /// player in the current game.
+    pub fn card_positions(&self, player_id: PlayerId) -> CardPositions {
+        self.database.card_positions(player_id, self.game_id()).expect("card_positions")
+    }
+
     pub fn user_id(&self) -> PlayerId {
         self.user.id
     }
@@ -112,22 +164,40 @@ impl TestSession {
     ///
     /// Returns the commands which would be sent to the client when connected.
     pub fn connect(&mut self, user_id: PlayerId) -> Result<CommandList> {
-        let result = requests::handle_connect(&mut self.database, user_id)?;
-        let to_update = match () {
-            _ if user_id == self.user.id => &mut self.user,
-            _ if user_id == self.opponent.id => &mut self.opponent,
-            _ => panic!("Unknown user id: {:?}", user_id),
-        };
+        let response = quarantine::handle_connect(&mut self.database, user_id)?;
+        let (_, to_update, opponent) = self.opponent_local_remote(user_id);
 
         // Clear all previous state
         *to_update = TestClient::new(user_id);
 
-        for command in result.commands.iter() {
+        for command in response.command_list.commands.iter() {
             let c = command.command.as_ref().with_error(|| "command")?;
             to_update.handle_command(c);
         }
 
-        Ok(result)
+        if let Some((_, list)) = &response.opponent_response {
+            for command in &list.commands {
+                opponent.handle_command(command.command.as_ref().expect("Empty command"));
+            }
+        }
+
+        Ok(response.command_list)
+    }
+
+    /// Simulates the server detecting that `player_id` is disconnected as of
+    /// `timestamp`, delivering any resulting updates to their opponent.
+    ///
+    /// Returns true if the opponent was sent an update.
+    pub fn disconnect(&mut self, player_id: PlayerId, timestamp: u64) -> Result<bool> {
+        let response = abandonment::handle_disconnect(&mut self.database, player_id, timestamp)?;
+        let updated = response.is_some();
+        if let Some((opponent_id, list)) = response {
+            let (_, local, _) = self.opponent_local_remote(opponent_id);
+            for command in &list.commands {
+                local.handle_command(command.command.as_ref().expect("Empty command"));
+            }
+        }
+        Ok(updated)
     }
 
     /// Execute a simulated client request for this game as a specific user,
@@ -135,12 +205,14 @@ impl TestSession {
     /// Returns the [GameResponse] for this action or an error if the server
     /// request failed.
     pub fn perform_action(&mut self, action: Action, player_id: PlayerId) -> Result<GameResponse> {
-        let response = requests::handle_request(
+        let response = quarantine::handle_request(
             &mut self.database,
             &GameRequest {
                 action: Some(ClientAction { action: Some(action) }),
                 player_id: Some(fake_database::to_player_identifier(player_id)),
                 open_panels: vec![],
+                session_token: self.session_tokens.get(&player_id).cloned().unwrap_or_default(),
+                client_id: String::new(),
             },
         )?;
 
@@ -357,6 +429,14 @@ impl TestSession {
             .collect()
     }
 
+    /// Evaluates legal actions for the [Side] player in the current game state
+    /// along with their heuristic prior weights.
+    pub fn legal_actions_with_priors(&self, side: Side) -> Vec<(GameAction, f64)> {
+        legal_actions::evaluate_with_priors(self.database.game.as_ref().expect("game"), side)
+            .expect("Error evaluating legal actions")
+            .collect()
+    }
+
     fn activate_ability_impl(
         &mut self,
         card_id: CardIdentifier,
@@ -568,9 +648,14 @@ pub struct ClientPlayer {
     name: PlayerName,
     mana: Option<ManaValue>,
     bonus_mana: Option<ManaValue>,
+    mana_pools: Vec<ManaPoolView>,
     actions: Option<ActionCount>,
+    bonus_actions: Option<ActionCount>,
+    next_turn_actions: Option<ActionCount>,
     score: Option<PointsValue>,
     can_take_action: Option<bool>,
+    card_back: Option<String>,
+    board_skin: Option<String>,
 }
 
 impl ClientPlayer {
@@ -579,9 +664,14 @@ impl ClientPlayer {
             name,
             mana: None,
             bonus_mana: None,
+            mana_pools: vec![],
             actions: None,
+            bonus_actions: None,
+            next_turn_actions: None,
             score: None,
             can_take_action: None,
+            card_back: None,
+            board_skin: None,
         }
     }
 
@@ -593,10 +683,25 @@ impl ClientPlayer {
         self.bonus_mana.expect("BonusMana")
     }
 
+    /// Restricted-purpose mana pools currently displayed for this player
+    pub fn mana_pools(&self) -> &[ManaPoolView] {
+        &self.mana_pools
+    }
+
     pub fn actions(&self) -> ActionCount {
         self.actions.expect("Actions")
     }
 
+    /// Number of available actions which were granted by card effects
+    pub fn bonus_actions(&self) -> ActionCount {
+        self.bonus_actions.expect("BonusActions")
+    }
+
+    /// Number of additional actions to be granted at the start of next turn
+    pub fn next_turn_actions(&self) -> ActionCount {
+        self.next_turn_actions.expect("NextTurnActions")
+    }
+
     pub fn score(&self) -> PointsValue {
         self.score.expect("Points")
     }
@@ -605,6 +710,16 @@ impl ClientPlayer {
         self.can_take_action.expect("can_take_action")
     }
 
+    /// Address of the card back sprite for this player's cards
+    pub fn card_back(&self) -> &str {
+        self.card_back.as_deref().expect("card_back")
+    }
+
+    /// Address of this player's board skin sprite, if any
+    pub fn board_skin(&self) -> Option<&str> {
+        self.board_skin.as_deref()
+    }
+
     fn update(&mut self, command: Command) {
         if let Command::UpdateGameView(update) = command {
             self.update_with_player(if self.name == PlayerName::User {
@@ -619,9 +734,17 @@ impl ClientPlayer {
         if let Some(p) = player {
             self.mana = Some(p.mana.clone().expect("mana").base_mana);
             self.bonus_mana = Some(p.mana.clone().expect("mana").bonus_mana);
-            self.actions = Some(p.action_tracker.clone().expect("actions").available_action_count);
+            self.mana_pools = p.mana.clone().expect("mana").pools;
+            let action_tracker = p.action_tracker.clone().expect("actions");
+            self.actions = Some(action_tracker.available_action_count);
+            self.bonus_actions = Some(action_tracker.bonus_action_count);
+            self.next_turn_actions = Some(action_tracker.next_turn_action_count);
             self.score = Some(p.score.clone().expect("score").score);
             self.can_take_action = Some(p.can_take_action);
+            if let Some(info) = p.player_info {
+                self.card_back = info.card_back.map(|sprite| sprite.address);
+                self.board_skin = info.board_skin.map(|sprite| sprite.address);
+            }
         }
     }
 }
@@ -663,6 +786,12 @@ impl ClientCards {
         result
     }
 
+    /// Returns a vec containing the name of the card in the shared global
+    /// modifier slot, if any
+    pub fn global_modifier(&self) -> Vec<String> {
+        self.names_in_position(Position::GlobalModifier(ObjectPositionGlobalModifier {}))
+    }
+
     /// Returns a player's discard pile in the same manner as [Self::hand]
     pub fn discard_pile(&self, player: PlayerName) -> Vec<String> {
         self.names_in_position(Position::DiscardPile(ObjectPositionDiscardPile {
@@ -684,6 +813,13 @@ impl ClientCards {
         }))
     }
 
+    /// Returns allies in play
+    pub fn allies(&self) -> Vec<String> {
+        self.names_in_position(Position::Item(ObjectPositionItem {
+            item_location: ClientItemLocation::Allies.into(),
+        }))
+    }
+
     /// Returns a vector containing the card titles in the provided `location`
     /// of a given room, Titles are structured in the same manner described
     /// in [Self::hand].
@@ -751,16 +887,21 @@ impl ClientCards {
 pub struct ClientCard {
     id: Option<CardIdentifier>,
     title: Option<String>,
+    rules_text: Option<String>,
     position: Option<ObjectPosition>,
     revealed_to_me: Option<bool>,
     is_face_up: Option<bool>,
     can_play: Option<bool>,
     valid_rooms: Option<Vec<RoomIdentifier>>,
+    highlighted_rooms: Vec<RoomIdentifier>,
     arena_icon: Option<String>,
     top_left_icon: Option<String>,
     top_right_icon: Option<String>,
     bottom_left_icon: Option<String>,
     bottom_right_icon: Option<String>,
+    keyword_tooltips: Vec<KeywordTooltip>,
+    info_zoom: Option<CardInfoView>,
+    scheme_progress: Option<SchemeProgress>,
 }
 
 impl ClientCard {
@@ -768,6 +909,21 @@ impl ClientCard {
         self.id.expect("card_id")
     }
 
+    /// Returns the keyword explanations for this card
+    pub fn keyword_tooltips(&self) -> &[KeywordTooltip] {
+        &self.keyword_tooltips
+    }
+
+    /// Returns the card inspector information for this card, if any
+    pub fn info_zoom(&self) -> Option<&CardInfoView> {
+        self.info_zoom.as_ref()
+    }
+
+    /// Returns this card's progress toward its level requirement, if any
+    pub fn scheme_progress(&self) -> Option<&SchemeProgress> {
+        self.scheme_progress.as_ref()
+    }
+
     /// Returns the game object position for this card
     pub fn position(&self) -> Position {
         self.position.clone().expect("CardPosition").position.expect("Position")
@@ -785,6 +941,12 @@ impl ClientCard {
         self.title.clone()
     }
 
+    /// Returns the user-visible rules text for this card. Panics if no rules
+    /// text is available.
+    pub fn rules_text(&self) -> String {
+        self.rules_text.clone().expect("No rules text found")
+    }
+
     pub fn revealed_to_me(&self) -> bool {
         self.revealed_to_me.expect("revealed_to_me")
     }
@@ -801,6 +963,10 @@ impl ClientCard {
         self.valid_rooms.as_ref().expect("valid_rooms").clone()
     }
 
+    pub fn highlighted_rooms(&self) -> &[RoomIdentifier] {
+        &self.highlighted_rooms
+    }
+
     pub fn arena_icon(&self) -> String {
         self.arena_icon.clone().expect("arena_icon")
     }
@@ -821,6 +987,21 @@ impl ClientCard {
         self.bottom_right_icon.clone().expect("bottom_right_icon")
     }
 
+    /// Returns (name, text) pairs for each icon currently displayed on this
+    /// card.
+    pub fn icons(&self) -> Vec<(&'static str, String)> {
+        [
+            ("arena", &self.arena_icon),
+            ("top_left", &self.top_left_icon),
+            ("top_right", &self.top_right_icon),
+            ("bottom_left", &self.bottom_left_icon),
+            ("bottom_right", &self.bottom_right_icon),
+        ]
+        .into_iter()
+        .filter_map(|(name, icon)| icon.clone().map(|text| (name, text)))
+        .collect()
+    }
+
     pub fn set_position(&mut self, position: ObjectPosition) {
         self.position = Some(position);
     }
@@ -836,6 +1017,8 @@ impl ClientCard {
         self.position = view.card_position.clone();
         self.revealed_to_me = Some(view.revealed_to_viewer);
         self.is_face_up = Some(view.is_face_up);
+        self.info_zoom = view.info_zoom.clone();
+        self.scheme_progress = view.scheme_progress.clone();
         if let Some(revealed) = &view.revealed_card {
             self.update_revealed_card(revealed);
         }
@@ -867,9 +1050,23 @@ impl ClientCard {
                 Some(valid_rooms.iter().map(|i| RoomIdentifier::from_i32(*i).unwrap()).collect())
         }
 
+        if let Some(targeting) = &revealed.targeting {
+            self.highlighted_rooms = targeting
+                .highlighted_rooms
+                .iter()
+                .map(|i| RoomIdentifier::from_i32(*i).unwrap())
+                .collect();
+        }
+
         if let Some(title) = revealed.clone().title.map(|title| title.text) {
             self.title = Some(title);
         }
+
+        if let Some(rules_text) = revealed.clone().rules_text.map(|text| text.text) {
+            self.rules_text = Some(rules_text);
+        }
+
+        self.keyword_tooltips = revealed.keyword_tooltips.clone();
     }
 }
 
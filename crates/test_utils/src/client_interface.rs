@@ -14,6 +14,7 @@
 
 use std::collections::HashMap;
 
+use core_ui::node_diff;
 use protos::spelldawn::game_command::Command;
 use protos::spelldawn::toggle_panel_command::ToggleCommand;
 use protos::spelldawn::{
@@ -24,10 +25,16 @@ use protos::spelldawn::{
 #[derive(Debug, Clone, Default)]
 pub struct ClientInterface {
     main_controls: Option<Node>,
+    /// Most recent main controls node received, used as the base for patches
+    last_main_controls: Option<Node>,
     card_anchors: Vec<CardAnchorNode>,
     panels: HashMap<InterfacePanelAddress, Node>,
     open_panels: Vec<InterfacePanelAddress>,
     screen_overlay: Option<Node>,
+    toasts: Vec<Node>,
+    emotes: Vec<Node>,
+    float_preferences: HashMap<String, f32>,
+    boolean_preferences: HashMap<String, bool>,
 }
 
 impl ClientInterface {
@@ -64,6 +71,26 @@ impl ClientInterface {
         self.screen_overlay.as_ref().expect("ScreenOverlayNode")
     }
 
+    /// Returns all toast notifications which have been displayed
+    pub fn toasts(&self) -> &Vec<Node> {
+        &self.toasts
+    }
+
+    /// Returns all emotes which have been displayed
+    pub fn emotes(&self) -> &Vec<Node> {
+        &self.emotes
+    }
+
+    /// Returns the value of a float player preference set by the server
+    pub fn float_preference(&self, key: &str) -> Option<f32> {
+        self.float_preferences.get(key).copied()
+    }
+
+    /// Returns the value of a boolean player preference set by the server
+    pub fn boolean_preference(&self, key: &str) -> Option<bool> {
+        self.boolean_preferences.get(key).copied()
+    }
+
     pub fn panel_count(&self) -> usize {
         self.open_panels.len()
     }
@@ -71,13 +98,21 @@ impl ClientInterface {
     pub fn update(&mut self, command: Command) {
         match command {
             Command::UpdateGameView(update) => {
-                let controls = update.game.as_ref().expect("game").main_controls.as_ref();
-                self.main_controls = controls.and_then(|c| c.node.clone());
-                self.card_anchors = controls.map_or(vec![], |c| c.card_anchor_nodes.clone());
+                let controls = update.game.expect("game").main_controls;
+                self.card_anchors =
+                    controls.as_ref().map_or(vec![], |c| c.card_anchor_nodes.clone());
+                self.main_controls = controls.and_then(|c| {
+                    node_diff::receive(&mut self.last_main_controls, c.node, c.node_patches)
+                        .expect("Error patching main controls")
+                });
             }
             Command::UpdatePanels(panels) => {
                 for panel in panels.panels {
-                    self.panels.insert(panel.address.expect("address"), panel.node.expect("node"));
+                    let address = panel.address.expect("address");
+                    let mut previous = self.panels.remove(&address);
+                    node_diff::receive(&mut previous, panel.node, panel.node_patches)
+                        .expect("Error patching panel");
+                    self.panels.insert(address, previous.expect("node"));
                 }
             }
             Command::TogglePanel(toggle) => {
@@ -86,6 +121,20 @@ impl ClientInterface {
             Command::RenderScreenOverlay(overlay) => {
                 self.screen_overlay = overlay.node;
             }
+            Command::ShowToast(toast) => {
+                self.toasts.extend(toast.content);
+            }
+            Command::DisplayEmote(emote) => {
+                self.emotes.extend(emote.content);
+            }
+            Command::UpdatePreferences(preferences) => {
+                for preference in preferences.float_preferences {
+                    self.float_preferences.insert(preference.key, preference.value);
+                }
+                for preference in preferences.boolean_preferences {
+                    self.boolean_preferences.insert(preference.key, preference.value);
+                }
+            }
             _ => {}
         }
     }
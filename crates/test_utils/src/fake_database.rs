@@ -14,20 +14,34 @@
 
 use std::collections::HashMap;
 
+use adapters::response_builder::CardPositions;
 use anyhow::Result;
+use data::card_name::CardName;
+use data::card_stats::CardStatistics;
 use data::game::GameState;
+use data::leaderboard::LeaderboardEntry;
+use data::match_state::MatchState;
 use data::player_data::PlayerData;
 use data::player_name::PlayerId;
-use data::primitives::GameId;
+use data::primitives::{GameId, MatchId};
+use data::ratings::PlayerRating;
 use database::Database;
 use protos::spelldawn::player_identifier::PlayerIdentifierType;
-use protos::spelldawn::PlayerIdentifier;
+use protos::spelldawn::{AuthenticateRequest, PlayerIdentifier};
+use server::auth;
 
 #[derive(Clone, Debug, Default)]
 pub struct FakeDatabase {
     pub generated_game_id: Option<GameId>,
     pub game: Option<GameState>,
     pub players: HashMap<PlayerId, PlayerData>,
+    pub signing_key: Option<Vec<u8>>,
+    pub player_secrets: HashMap<PlayerId, Vec<u8>>,
+    pub leaderboards: HashMap<u64, Vec<LeaderboardEntry>>,
+    pub matches: HashMap<MatchId, MatchState>,
+    pub ratings: HashMap<PlayerId, PlayerRating>,
+    pub card_statistics: HashMap<CardName, CardStatistics>,
+    pub card_positions: HashMap<PlayerId, (GameId, CardPositions)>,
 }
 
 impl FakeDatabase {
@@ -59,7 +73,7 @@ impl Database for FakeDatabase {
     }
 
     fn player(&self, player_id: PlayerId) -> Result<Option<PlayerData>> {
-        Ok(Some(self.players[&player_id].clone()))
+        Ok(self.players.get(&player_id).cloned())
     }
 
     fn write_player(&mut self, player: &PlayerData) -> Result<()> {
@@ -69,12 +83,94 @@ impl Database for FakeDatabase {
 
     fn adapt_player_identifier(&mut self, identifier: &PlayerIdentifier) -> Result<PlayerId> {
         match identifier.player_identifier_type.clone().unwrap() {
-            PlayerIdentifierType::ServerIdentifier(bytes) => {
-                Ok(PlayerId::Database(u64::from_be_bytes(bytes.try_into().unwrap())))
+            PlayerIdentifierType::DeviceIdentifier(id) => {
+                Ok(PlayerId::Database(id.parse().expect("Expected numeric device identifier")))
             }
+            PlayerIdentifierType::ServerIdentifier(bytes) => adapters::named_player_id(&bytes),
             _ => panic!("Unsupported identifier type"),
         }
     }
+
+    fn signing_key(&self) -> Result<Option<Vec<u8>>> {
+        Ok(self.signing_key.clone())
+    }
+
+    fn create_signing_key(&mut self, key: &[u8]) -> Result<Vec<u8>> {
+        Ok(self.signing_key.get_or_insert_with(|| key.to_vec()).clone())
+    }
+
+    fn player_secret(&self, player_id: PlayerId) -> Result<Option<Vec<u8>>> {
+        Ok(self.player_secrets.get(&player_id).cloned())
+    }
+
+    fn create_player_secret(&mut self, player_id: PlayerId, hash: &[u8]) -> Result<bool> {
+        if self.player_secrets.contains_key(&player_id) {
+            return Ok(false);
+        }
+        self.player_secrets.insert(player_id, hash.to_vec());
+        Ok(true)
+    }
+
+    fn leaderboard(&self, seed: u64) -> Result<Vec<LeaderboardEntry>> {
+        Ok(self.leaderboards.get(&seed).cloned().unwrap_or_default())
+    }
+
+    fn write_leaderboard(&mut self, seed: u64, entries: &[LeaderboardEntry]) -> Result<()> {
+        self.leaderboards.insert(seed, entries.to_vec());
+        Ok(())
+    }
+
+    fn match_state(&self, id: MatchId) -> Result<MatchState> {
+        Ok(self.matches[&id].clone())
+    }
+
+    fn write_match(&mut self, state: &MatchState) -> Result<()> {
+        self.matches.insert(state.id, state.clone());
+        Ok(())
+    }
+
+    fn rating(&self, player_id: PlayerId) -> Result<Option<PlayerRating>> {
+        Ok(self.ratings.get(&player_id).copied())
+    }
+
+    fn ratings(&self) -> Result<Vec<PlayerRating>> {
+        Ok(self.ratings.values().copied().collect())
+    }
+
+    fn write_rating(&mut self, rating: &PlayerRating) -> Result<()> {
+        self.ratings.insert(rating.player_id, *rating);
+        Ok(())
+    }
+
+    fn card_statistics(&self, card_name: CardName) -> Result<Option<CardStatistics>> {
+        Ok(self.card_statistics.get(&card_name).copied())
+    }
+
+    fn all_card_statistics(&self) -> Result<Vec<CardStatistics>> {
+        Ok(self.card_statistics.values().copied().collect())
+    }
+
+    fn write_card_statistics(&mut self, statistics: &CardStatistics) -> Result<()> {
+        self.card_statistics.insert(statistics.card_name, *statistics);
+        Ok(())
+    }
+
+    fn card_positions(&self, player_id: PlayerId, game_id: GameId) -> Result<CardPositions> {
+        Ok(match self.card_positions.get(&player_id) {
+            Some((id, positions)) if *id == game_id => positions.clone(),
+            _ => CardPositions::default(),
+        })
+    }
+
+    fn write_card_positions(
+        &mut self,
+        player_id: PlayerId,
+        game_id: GameId,
+        positions: &CardPositions,
+    ) -> Result<()> {
+        self.card_positions.insert(player_id, (game_id, positions.clone()));
+        Ok(())
+    }
 }
 
 pub fn to_player_identifier(id: PlayerId) -> PlayerIdentifier {
@@ -84,8 +180,22 @@ pub fn to_player_identifier(id: PlayerId) -> PlayerIdentifier {
     };
 
     PlayerIdentifier {
-        player_identifier_type: Some(PlayerIdentifierType::ServerIdentifier(
-            value.to_be_bytes().to_vec(),
-        )),
+        player_identifier_type: Some(PlayerIdentifierType::DeviceIdentifier(value.to_string())),
     }
 }
+
+/// Issues a session token for the provided player, creating the signing key if
+/// needed and replacing any existing player secret.
+pub fn authenticate(database: &mut FakeDatabase, id: PlayerId) -> String {
+    auth::initialize(database).expect("Error initializing auth");
+    database.player_secrets.remove(&id);
+    auth::authenticate(
+        database,
+        &AuthenticateRequest {
+            player_id: Some(to_player_identifier(id)),
+            player_secret: String::new(),
+        },
+    )
+    .expect("Error authenticating")
+    .session_token
+}
@@ -0,0 +1,107 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Plays random games to check that rules engine invariants hold.
+
+use actions::legal_actions;
+use cards::{decklists, initialize};
+use data::game::{GameConfiguration, GamePhase, GameState};
+use data::primitives::{GameId, Side};
+use rand::prelude::IteratorRandom;
+use rand::SeedableRng;
+use rand_xoshiro::Xoshiro256StarStar;
+use rules::{dispatch, mutations};
+
+/// Maximum number of actions to take in a single fuzzed game before giving up
+/// on reaching a conclusion.
+pub const MAX_ACTIONS: usize = 2_000;
+
+/// Mana values above this are assumed to be the result of an underflow.
+const MAX_REASONABLE_MANA: u32 = 1_000_000;
+
+/// Plays `count` random games with seeds `0..count`. See [fuzz_game].
+pub fn fuzz_games(count: u64) {
+    for seed in 0..count {
+        fuzz_game(seed);
+    }
+}
+
+/// Plays a single game between the canonical decks, selecting uniformly among
+/// legal actions using a random number generator seeded from `seed`. The game
+/// itself also uses this seed for its own random choices.
+///
+/// Panics with the seed and action count if an invariant is violated:
+///
+/// - Legal actions can always be executed without error
+/// - Some player can always act while the game is ongoing
+/// - Mana is never negative
+/// - The total number of cards in the game never changes
+/// - Prompts always have at least one response
+pub fn fuzz_game(seed: u64) {
+    initialize::run();
+    let mut game = GameState::new(
+        GameId::new(seed),
+        decklists::CANONICAL_OVERLORD.clone(),
+        decklists::CANONICAL_CHAMPION.clone(),
+        GameConfiguration { deterministic: true, simulation: true, ..GameConfiguration::default() },
+    );
+    game.rng = Some(Xoshiro256StarStar::seed_from_u64(seed));
+    dispatch::populate_delegate_cache(&mut game);
+    mutations::deal_opening_hands(&mut game)
+        .unwrap_or_else(|e| panic!("Seed {seed}: error dealing opening hands: {e:?}"));
+
+    let card_count = game.all_cards().count();
+    let mut rng = Xoshiro256StarStar::seed_from_u64(seed);
+
+    for step in 0..MAX_ACTIONS {
+        check_invariants(&game, card_count, seed, step);
+        if matches!(game.data.phase, GamePhase::GameOver { .. }) {
+            return;
+        }
+
+        let side = enum_iterator::all::<Side>()
+            .find(|side| actions::can_take_action(&game, *side))
+            .unwrap_or_else(|| panic!("Seed {seed}, step {step}: no player can act"));
+        let action = legal_actions::evaluate(&game, side)
+            .unwrap_or_else(|e| panic!("Seed {seed}, step {step}: legal actions error: {e:?}"))
+            .choose(&mut rng)
+            .unwrap_or_else(|| panic!("Seed {seed}, step {step}: no legal actions for {side:?}"));
+        actions::handle_game_action(&mut game, side, action).unwrap_or_else(|e| {
+            panic!("Seed {seed}, step {step}: error performing {action:?} for {side:?}: {e:?}")
+        });
+    }
+}
+
+fn check_invariants(game: &GameState, card_count: usize, seed: u64, step: usize) {
+    assert_eq!(
+        card_count,
+        game.all_cards().count(),
+        "Seed {seed}, step {step}: card count changed"
+    );
+
+    for side in enum_iterator::all::<Side>() {
+        let player = game.player(side);
+        assert!(
+            player.mana_state.base_mana < MAX_REASONABLE_MANA,
+            "Seed {seed}, step {step}: negative mana for {side:?}"
+        );
+
+        if let Some(prompt) = &player.prompt {
+            assert!(
+                !prompt.responses.is_empty(),
+                "Seed {seed}, step {step}: empty prompt for {side:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,168 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A higher-level builder for scripting multi-turn test scenarios.
+//!
+//! Each method on [Scenario] immediately performs its step against the same
+//! black-box API used by [TestSession] and panics if the step fails, so tests
+//! read as a game script:
+//!
+//! ```ignore
+//! Scenario::new()
+//!     .overlord_plays(CardName::TimeGolem)
+//!     .end_turn(Side::Overlord)
+//!     .champion_raids(ROOM_ID)
+//!     .expect_prompt(Side::Champion, "End Raid");
+//! ```
+
+use std::collections::HashMap;
+
+use data::card_name::CardName;
+use data::primitives::{ManaValue, RoomId, Side};
+use protos::spelldawn::CardIdentifier;
+
+use crate::client::TestSession;
+use crate::client_interface::HasText;
+use crate::Args;
+
+pub struct Scenario {
+    session: TestSession,
+    cards: HashMap<CardName, CardIdentifier>,
+}
+
+impl Default for Scenario {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Scenario {
+    /// Starts a new scenario from the user's perspective as the Overlord, on
+    /// the Overlord's first turn.
+    pub fn new() -> Self {
+        Self::with_args(Side::Overlord, Args::default())
+    }
+
+    /// Starts a new scenario for the `user_side` player, configured via the
+    /// provided [Args]. See [crate::new_game].
+    pub fn with_args(user_side: Side, args: Args) -> Self {
+        Self { session: crate::new_game(user_side, args), cards: HashMap::new() }
+    }
+
+    /// The Overlord plays a card from their hand. Minions, projects, and
+    /// schemes are played into [crate::ROOM_ID].
+    pub fn overlord_plays(self, card_name: CardName) -> Self {
+        self.plays(Side::Overlord, card_name, None)
+    }
+
+    /// The Overlord plays a card from their hand into the provided room.
+    pub fn overlord_plays_in(self, card_name: CardName, room_id: RoomId) -> Self {
+        self.plays(Side::Overlord, card_name, Some(room_id))
+    }
+
+    /// The Champion plays a card from their hand.
+    pub fn champion_plays(self, card_name: CardName) -> Self {
+        self.plays(Side::Champion, card_name, None)
+    }
+
+    /// The `side` player spends their remaining action points, ending their
+    /// turn.
+    pub fn end_turn(mut self, side: Side) -> Self {
+        crate::spend_actions_until_turn_over(&mut self.session, side);
+        self
+    }
+
+    /// The Champion initiates a raid on the provided room.
+    pub fn champion_raids(mut self, room_id: RoomId) -> Self {
+        self.session.initiate_raid(room_id);
+        self
+    }
+
+    /// The `side` player clicks a button containing `text` in their interface
+    /// controls.
+    pub fn click(mut self, side: Side, text: impl Into<String>) -> Self {
+        let id = self.session.player_id_for_side(side);
+        self.session.click_on(id, text);
+        self
+    }
+
+    /// Asserts that the `side` player's interface controls contain `text`.
+    pub fn expect_prompt(self, side: Side, text: impl Into<String>) -> Self {
+        let text = text.into();
+        assert!(
+            self.session.player_for_side(side).interface.controls().has_text(text.clone()),
+            "Expected {:?} prompt containing {:?}",
+            side,
+            text
+        );
+        self
+    }
+
+    /// Asserts that the `side` player's interface controls do not contain
+    /// `text`.
+    pub fn expect_no_prompt(self, side: Side, text: impl Into<String>) -> Self {
+        let text = text.into();
+        assert!(
+            !self.session.player_for_side(side).interface.controls().has_text(text.clone()),
+            "Unexpected {:?} prompt containing {:?}",
+            side,
+            text
+        );
+        self
+    }
+
+    /// Asserts whether a raid is currently active.
+    pub fn expect_raid_active(self, active: bool) -> Self {
+        assert_eq!(active, self.session.user.data.raid_active(), "Unexpected raid state");
+        self
+    }
+
+    /// Asserts the mana currently available to the `side` player.
+    pub fn expect_mana(self, side: Side, mana: ManaValue) -> Self {
+        assert_eq!(
+            mana,
+            self.session.player_for_side(side).this_player.mana(),
+            "Unexpected mana for {:?}",
+            side
+        );
+        self
+    }
+
+    /// Returns the [CardIdentifier] of the most recent card with this name
+    /// played in this scenario. Panics if no such card has been played.
+    pub fn card_id(&self, card_name: CardName) -> CardIdentifier {
+        *self.cards.get(&card_name).expect("Card not played in this scenario")
+    }
+
+    /// Returns the underlying [TestSession] for assertions not covered by
+    /// this builder.
+    pub fn session(&self) -> &TestSession {
+        &self.session
+    }
+
+    /// Consumes this scenario, returning the underlying [TestSession].
+    pub fn into_session(self) -> TestSession {
+        self.session
+    }
+
+    fn plays(mut self, side: Side, card_name: CardName, room_id: Option<RoomId>) -> Self {
+        assert_eq!(side, rules::get(card_name).side, "{:?} is not a {:?} card", card_name, side);
+        let card_id = match room_id {
+            Some(room_id) => self.session.play_with_target_room(card_name, room_id),
+            None => self.session.play_from_hand(card_name),
+        };
+        self.cards.insert(card_name, card_id);
+        self
+    }
+}
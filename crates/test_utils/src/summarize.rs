@@ -14,28 +14,30 @@
 
 use std::fmt::{Debug, Write as _};
 
-use adapters;
-use adapters::ServerCardId;
+use adapters::{self, ServerCardId};
 use anyhow::Result;
 use protos::spelldawn::card_targeting::Targeting;
 use protos::spelldawn::game_command::Command;
 use protos::spelldawn::game_object_identifier::Id;
+use protos::spelldawn::node_patch::PatchType;
 use protos::spelldawn::object_position::Position;
 use protos::spelldawn::play_effect_position::EffectPosition;
 use protos::spelldawn::{
     node_type, ActionTrackerView, AnchorCorner, ArrowTargetRoom, AudioClipAddress, CardAnchor,
     CardAnchorNode, CardCreationAnimation, CardIcon, CardIcons, CardIdentifier, CardTargeting,
     CardTitle, CardView, CommandList, ConditionalCommand, CreateTokenCardCommand, DelayCommand,
-    DisplayGameMessageCommand, DisplayRewardsCommand, EffectAddress, FireProjectileCommand,
-    GameCommand, GameMessageType, GameObjectIdentifier, GameObjectMove, GameObjectPositions,
-    GameView, InterfaceMainControls, InterfacePanel, InterfacePanelAddress, LoadSceneCommand,
-    ManaView, MapPosition, MoveGameObjectsCommand, MusicState, NoTargeting, Node, NodeType,
+    DisplayArrowsCommand, DisplayEmoteCommand, DisplayGameMessageCommand, DisplayRewardsCommand,
+    DisplayedArrow, EffectAddress, FireProjectileCommand, GameCommand, GameMessageType,
+    GameObjectIdentifier, GameObjectMove, GameObjectPositions, GameView, InterfaceMainControls,
+    InterfacePanel, InterfacePanelAddress, LoadSceneCommand, ManaView, MapPosition,
+    MoveGameObjectsCommand, MusicState, NoTargeting, Node, NodePatch, NodePatchList, NodeType,
     ObjectPosition, PlayEffectCommand, PlayEffectPosition, PlayInRoom, PlaySoundCommand,
     PlayerInfo, PlayerName, PlayerSide, PlayerView, ProjectileAddress, RenderScreenOverlayCommand,
     RevealedCardView, RoomIdentifier, RoomVisitType, RulesText, RunInParallelCommand,
-    SceneLoadMode, ScoreView, SetGameObjectsEnabledCommand, SetMusicCommand, SpriteAddress,
-    TimeValue, TogglePanelCommand, UpdateGameViewCommand, UpdateInterfaceCommand,
-    UpdatePanelsCommand, UpdateWorldMapCommand, VisitRoomCommand, WorldMapSprite, WorldMapTile,
+    SceneLoadMode, ScoreView, SetGameObjectsEnabledCommand, SetMusicCommand, ShowToastCommand,
+    SpriteAddress, TargetingArrow, TimeValue, TogglePanelCommand, UpdateGameViewCommand,
+    UpdateInterfaceCommand, UpdatePanelsCommand, UpdatePreferencesCommand, UpdateWorldMapCommand,
+    VisitRoomCommand, WorldMapSprite, WorldMapTile,
 };
 use server::requests::GameResponse;
 
@@ -167,6 +169,12 @@ impl Summarize for PlayerName {
     }
 }
 
+impl Summarize for TargetingArrow {
+    fn summarize(self, summary: &mut Summary) {
+        summary.primitive(self)
+    }
+}
+
 impl Summarize for PlayerSide {
     fn summarize(self, summary: &mut Summary) {
         summary.primitive(self)
@@ -283,6 +291,10 @@ impl Summarize for Command {
             Self::RenderScreenOverlay(v) => summary.child_node("RenderScreenOverlay", v),
             Self::UpdateInterface(v) => summary.child_node("UpdateInterface", v),
             Self::Conditional(v) => summary.child_node("Conditional", v),
+            Self::ShowToast(v) => summary.child_node("ShowToast", v),
+            Self::DisplayEmote(v) => summary.child_node("DisplayEmote", v),
+            Self::UpdatePreferences(v) => summary.child_node("UpdatePreferences", v),
+            Self::DisplayArrows(v) => summary.child_node("DisplayArrows", v),
         }
     }
 }
@@ -320,10 +332,31 @@ impl Summarize for InterfacePanel {
 impl Summarize for InterfaceMainControls {
     fn summarize(self, summary: &mut Summary) {
         summary.child("node", self.node);
+        summary.child("node_patches", self.node_patches);
         summary.children("card_anchor_nodes", self.card_anchor_nodes);
     }
 }
 
+impl Summarize for NodePatchList {
+    fn summarize(self, summary: &mut Summary) {
+        summary.values(self.patches)
+    }
+}
+
+impl Summarize for NodePatch {
+    fn summarize(self, summary: &mut Summary) {
+        summary.child_node("path", format!("{:?}", self.path));
+        match self.patch_type {
+            Some(PatchType::Replace(node)) => summary.child_node("replace", node),
+            Some(PatchType::TruncateChildren(length)) => {
+                summary.child_node("truncate_children", length)
+            }
+            Some(PatchType::AppendChild(node)) => summary.child_node("append_child", node),
+            None => {}
+        }
+    }
+}
+
 impl Summarize for CardAnchorNode {
     fn summarize(self, summary: &mut Summary) {
         summary.child("card_id", self.card_id);
@@ -565,6 +598,7 @@ impl Summarize for Position {
             Self::IdentityContainer(v) => summary.primitive(v),
             Self::IntoCard(v) => summary.primitive(v),
             Self::Revealed(v) => summary.primitive(v),
+            Self::GlobalModifier(v) => summary.primitive(v),
         }
     }
 }
@@ -709,6 +743,38 @@ impl Summarize for UpdateInterfaceCommand {
     }
 }
 
+impl Summarize for ShowToastCommand {
+    fn summarize(self, summary: &mut Summary) {
+        summary.primitive("<Toast>");
+    }
+}
+
+impl Summarize for DisplayEmoteCommand {
+    fn summarize(self, summary: &mut Summary) {
+        summary.value(PlayerName::from_i32(self.player));
+    }
+}
+
+impl Summarize for DisplayArrowsCommand {
+    fn summarize(self, summary: &mut Summary) {
+        summary.values(self.arrows);
+    }
+}
+
+impl Summarize for DisplayedArrow {
+    fn summarize(self, summary: &mut Summary) {
+        summary.child("source", self.source);
+        summary.child("target", self.target);
+        summary.child("color", TargetingArrow::from_i32(self.color));
+    }
+}
+
+impl Summarize for UpdatePreferencesCommand {
+    fn summarize(self, summary: &mut Summary) {
+        summary.primitive("<UpdatePreferencesCommand>");
+    }
+}
+
 impl Summarize for ConditionalCommand {
     fn summarize(self, summary: &mut Summary) {
         summary.primitive("<ConditionalCommand>");
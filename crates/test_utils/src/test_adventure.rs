@@ -12,19 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use cards::initialize;
 use core_ui::actions::InterfaceAction;
-use data::player_data::PlayerData;
+use data::adventure::{AdventureState, Coins};
+use data::campaign::CampaignProgress;
+use data::cosmetics::PlayerCosmetics;
+use data::crafting::Dust;
+use data::player_data::{AutomationPreferences, PlayerData, PlayerSettings};
 use data::player_name::PlayerId;
 use data::primitives::Side;
+use data::quests::QuestLog;
 use data::tutorial::TutorialData;
 use data::user_actions::UserAction;
 use maplit::hashmap;
 use protos::spelldawn::client_action::Action;
 use protos::spelldawn::game_command::Command;
-use protos::spelldawn::{ClientAction, CommandList, GameRequest, WorldMapTile};
+use protos::spelldawn::{ClientAction, CommandList, GameRequest, MapTileType, WorldMapTile};
 use server::requests;
 use server::requests::GameResponse;
 
@@ -33,6 +38,9 @@ use crate::fake_database;
 use crate::fake_database::FakeDatabase;
 
 pub const EXPLORE_ICON: &str = "icon_app_198";
+pub const DRAFT_ICON: &str = "icon_store_167";
+pub const EVENT_ICON: &str = "icon_app_110";
+pub const CAMP_ICON: &str = "icon_architecture_12";
 
 pub struct TestAdventure {
     pub side: Side,
@@ -40,6 +48,7 @@ pub struct TestAdventure {
     pub interface: ClientInterface,
     pub map: TestWorldMap,
     pub database: FakeDatabase,
+    pub session_token: String,
 }
 
 impl TestAdventure {
@@ -62,12 +71,36 @@ impl TestAdventure {
                         decks: vec![],
                         adventure: None,
                         collection: hashmap! {},
-                        tutorial: TutorialData::default()
+                        tutorial: TutorialData::default(),
+                        cosmetics: HashSet::new(),
+                        achievements: HashSet::new(),
+                        settings: PlayerSettings::default(),
+                        match_history: vec![],
+                        completed_puzzles: HashSet::new(),
+                        quests: QuestLog::default(),
+                        coins: Coins::default(),
+                        player_cosmetics: PlayerCosmetics::default(),
+                        revision: 0,
+                        notifications: vec![],
+                        campaign: CampaignProgress::default(),
+                        current_match: None,
+                        dust: Dust::default(),
+                        automation: AutomationPreferences::default(),
                     }
                 },
+                signing_key: None,
+                player_secrets: hashmap! {},
+                leaderboards: hashmap! {},
+                matches: hashmap! {},
+                ratings: hashmap! {},
+                card_statistics: hashmap! {},
+                card_positions: hashmap! {},
             },
+            session_token: String::new(),
         };
 
+        result.session_token = fake_database::authenticate(&mut result.database, player_id);
+
         result.perform(UserAction::NewAdventure(side));
         result.connect();
 
@@ -100,10 +133,13 @@ impl TestAdventure {
 
         let response = requests::handle_request(
             &mut self.database,
+            self.player_id,
             &GameRequest {
                 action: Some(action),
                 player_id: Some(fake_database::to_player_identifier(self.player_id)),
                 open_panels: vec![],
+                session_token: self.session_token.clone(),
+                client_id: String::new(),
             },
         )
         .expect("Error handling game request");
@@ -113,6 +149,20 @@ impl TestAdventure {
         response
     }
 
+    /// Returns the current [AdventureState] for this player.
+    pub fn state(&self) -> &AdventureState {
+        self.database.players[&self.player_id].adventure.as_ref().expect("adventure")
+    }
+
+    /// Mutable equivalent of [Self::state].
+    pub fn state_mut(&mut self) -> &mut AdventureState {
+        self.database
+            .players
+            .get_mut(&self.player_id)
+            .and_then(|player| player.adventure.as_mut())
+            .expect("adventure")
+    }
+
     /// Attempts to find a tile with a sprite containing the substring 'icon'
     /// and then invokes the 'on visit' action for that tile.
     pub fn visit_tile_with_icon(&mut self, icon: impl Into<String>) -> GameResponse {
@@ -179,4 +229,8 @@ impl TestMapTile {
             sprite.sprite_address.as_ref().expect("sprite_address").address.contains(substring)
         })
     }
+
+    pub fn tile_type(&self) -> MapTileType {
+        self.tile.tile_type()
+    }
 }
@@ -0,0 +1,186 @@
+// Copyright © Spelldawn 2021-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Builder for bespoke card definitions in tests, see [TestCardBuilder].
+
+use card_helpers::{abilities, cost, scheme_cost};
+use cards::test_cards;
+use data::card_definition::{Ability, AttackBoost, CardDefinition, SchemePoints};
+use data::card_name::CardName;
+use data::primitives::{
+    ActionCount, AttackValue, BreachValue, CardType, DurabilityValue, HealthValue, LevelValue,
+    Lineage, ManaValue, PointsValue, Rarity, ShieldValue, Side,
+};
+use data::set_name::SetName;
+use rules::synthetic_cards;
+
+/// Creates a card definition inline within a test, without needing to add a
+/// permanent [CardName] variant and definition function to `test_cards`.
+///
+/// ```ignore
+/// let name = TestCardBuilder::minion().health(5).shield(2).combat_end_raid().build();
+/// g.play_from_hand(name);
+/// ```
+///
+/// Cards are registered via [synthetic_cards] and are only visible to the
+/// thread which built them. At most 8 synthetic cards can exist at once in a
+/// single test.
+pub struct TestCardBuilder {
+    definition: CardDefinition,
+}
+
+impl TestCardBuilder {
+    /// An Overlord minion with [test_cards::MINION_COST] and
+    /// [test_cards::TEST_LINEAGE], with no health or abilities.
+    pub fn minion() -> Self {
+        Self::new(Side::Overlord, CardType::Minion)
+            .mana_cost(test_cards::MINION_COST)
+            .lineage(test_cards::TEST_LINEAGE)
+    }
+
+    /// An Overlord project which costs 0 mana to unveil.
+    pub fn project() -> Self {
+        Self::new(Side::Overlord, CardType::Project)
+    }
+
+    /// An Overlord scheme requiring 3 levels to score 1 point.
+    pub fn scheme() -> Self {
+        let mut result = Self::new(Side::Overlord, CardType::Scheme).scheme_points(3, 1);
+        result.definition.cost = scheme_cost();
+        result
+    }
+
+    /// An Overlord spell which costs 1 mana.
+    pub fn overlord_spell() -> Self {
+        Self::new(Side::Overlord, CardType::OverlordSpell).mana_cost(1)
+    }
+
+    /// An Overlord global modifier which costs 1 mana.
+    pub fn global_modifier() -> Self {
+        Self::new(Side::Overlord, CardType::GlobalModifier).mana_cost(1)
+    }
+
+    /// A Champion weapon with [test_cards::WEAPON_COST] and
+    /// [test_cards::TEST_LINEAGE], with no attack.
+    pub fn weapon() -> Self {
+        Self::new(Side::Champion, CardType::Weapon)
+            .mana_cost(test_cards::WEAPON_COST)
+            .lineage(test_cards::TEST_LINEAGE)
+    }
+
+    /// A Champion artifact with [test_cards::ARTIFACT_COST].
+    pub fn artifact() -> Self {
+        Self::new(Side::Champion, CardType::Artifact).mana_cost(test_cards::ARTIFACT_COST)
+    }
+
+    /// A Champion spell which costs 1 mana.
+    pub fn champion_spell() -> Self {
+        Self::new(Side::Champion, CardType::ChampionSpell).mana_cost(1)
+    }
+
+    fn new(side: Side, card_type: CardType) -> Self {
+        let base = match side {
+            Side::Overlord => test_cards::test_overlord_identity(),
+            Side::Champion => test_cards::test_champion_identity(),
+        };
+        Self { definition: CardDefinition { cost: cost(0), card_type, ..base } }
+    }
+
+    pub fn mana_cost(mut self, mana: ManaValue) -> Self {
+        self.definition.cost.mana = Some(mana);
+        self
+    }
+
+    pub fn action_cost(mut self, actions: ActionCount) -> Self {
+        self.definition.cost.actions = actions;
+        self
+    }
+
+    pub fn rarity(mut self, rarity: Rarity) -> Self {
+        self.definition.rarity = rarity;
+        self
+    }
+
+    /// Adds this card to a [SetName], e.g. to make it available as an adventure
+    /// reward.
+    pub fn set(mut self, set: SetName) -> Self {
+        self.definition.sets.push(set);
+        self
+    }
+
+    pub fn lineage(mut self, lineage: Lineage) -> Self {
+        self.definition.config.lineage = Some(lineage);
+        self
+    }
+
+    pub fn health(mut self, health: HealthValue) -> Self {
+        self.definition.config.stats.health = Some(health);
+        self
+    }
+
+    pub fn shield(mut self, shield: ShieldValue) -> Self {
+        self.definition.config.stats.shield = Some(shield);
+        self
+    }
+
+    pub fn breach(mut self, breach: BreachValue) -> Self {
+        self.definition.config.stats.breach = Some(breach);
+        self
+    }
+
+    pub fn base_attack(mut self, attack: AttackValue) -> Self {
+        self.definition.config.stats.base_attack = Some(attack);
+        self
+    }
+
+    /// Sets the weapon attack boost and adds the standard encounter ability
+    /// which activates it.
+    pub fn attack_boost(mut self, cost: ManaValue, bonus: AttackValue) -> Self {
+        self.definition.config.stats.attack_boost = Some(AttackBoost { cost, bonus });
+        self.ability(abilities::encounter_boost())
+    }
+
+    pub fn durability(mut self, durability: DurabilityValue) -> Self {
+        self.definition.config.stats.durability = Some(durability);
+        self
+    }
+
+    pub fn scheme_points(mut self, level_requirement: LevelValue, points: PointsValue) -> Self {
+        self.definition.config.stats.scheme_points =
+            Some(SchemePoints { level_requirement, points });
+        self
+    }
+
+    /// Adds an arbitrary [Ability] to this card.
+    pub fn ability(mut self, ability: Ability) -> Self {
+        self.definition.abilities.push(ability);
+        self
+    }
+
+    /// Adds a minion combat ability which ends the raid.
+    pub fn combat_end_raid(self) -> Self {
+        self.ability(abilities::end_raid())
+    }
+
+    /// Adds a minion combat ability which deals `N` damage.
+    pub fn combat_deal_damage<const N: u32>(self) -> Self {
+        self.ability(abilities::combat_deal_damage::<N>())
+    }
+
+    /// Registers this card definition for the current test and returns the
+    /// [CardName] to use to refer to it.
+    pub fn build(self) -> CardName {
+        synthetic_cards::register(self.definition)
+    }
+}
@@ -20,37 +20,52 @@
 pub mod client;
 pub mod client_interface;
 pub mod fake_database;
+pub mod fuzz;
+pub mod scenario;
 pub mod summarize;
 pub mod test_adventure;
+pub mod test_card_builder;
 pub mod test_games;
 
 use std::collections::HashSet;
 use std::fmt::Debug;
-use std::fs;
 use std::hash::Hash;
+use std::path::Path;
 use std::sync::atomic::{AtomicU64, Ordering};
 
 use adapters::ServerCardId;
 use anyhow::Result;
 use cards::initialize;
+use core_ui::actions::InterfaceAction;
+use data::adventure::Coins;
+use data::campaign::CampaignProgress;
 use data::card_name::CardName;
 use data::card_state::{CardPosition, CardPositionKind};
+use data::cosmetics::{DeckCosmetics, PlayerCosmetics};
+use data::crafting::Dust;
 use data::deck::Deck;
-use data::game::{GameConfiguration, GamePhase, GameState, InternalRaidPhase, RaidData, TurnData};
-use data::player_data::{PlayerData, PlayerState};
+use data::game::{
+    GameConfiguration, GameModifier, GamePhase, GameState, InternalRaidPhase, RaidData, TurnData,
+    TurnStep,
+};
+use data::game_actions::{CardPromptAction, CardTarget, PromptAction};
+use data::player_data::{AutomationPreferences, PlayerData, PlayerSettings, PlayerState};
 use data::player_name::PlayerId;
 use data::primitives::{
-    ActionCount, CardId, DeckIndex, GameId, Lineage, ManaValue, PointsValue, RaidId, RoomId, Side,
+    ActionCount, CardId, CardType, DeckIndex, GameId, LevelValue, Lineage, ManaValue, PointsValue,
+    RaidId, RoomId, Side,
 };
+use data::quests::{Quest, QuestLog};
 use data::tutorial::TutorialData;
 use maplit::hashmap;
-use prost::Message;
 use protos::spelldawn::client_action::Action;
 use protos::spelldawn::{
     CardIdentifier, CommandList, GameCommand, LevelUpRoomAction, RoomIdentifier,
     SpendActionPointAction,
 };
-use rules::{dispatch, mana};
+use rules::{dispatch, mana, queries};
+use server::recordings;
+use server::recordings::RecordingName;
 
 use crate::client::TestSession;
 use crate::fake_database::FakeDatabase;
@@ -88,6 +103,7 @@ pub fn new_game(user_side: Side, args: Args) -> TestSession {
         side: Side::Overlord,
         identity: CardName::TestOverlordIdentity,
         cards: hashmap! {CardName::TestOverlordSpell => 45},
+        cosmetics: DeckCosmetics::default(),
     };
     let champion_deck = Deck {
         index: DeckIndex { value: 1 },
@@ -96,19 +112,27 @@ pub fn new_game(user_side: Side, args: Args) -> TestSession {
         side: Side::Champion,
         identity: CardName::TestChampionIdentity,
         cards: hashmap! {CardName::TestChampionSpell => 45},
+        cosmetics: DeckCosmetics::default(),
     };
 
     let mut game = GameState::new(
         game_id,
         overlord_deck,
         champion_deck,
-        GameConfiguration { deterministic: true, ..GameConfiguration::default() },
+        GameConfiguration {
+            deterministic: true,
+            disconnect_grace_period: args.disconnect_grace_period,
+            modifiers: args.modifiers.clone(),
+            reveal_on_game_over: args.reveal_on_game_over,
+            ..GameConfiguration::default()
+        },
     );
     dispatch::populate_delegate_cache(&mut game);
 
     let turn_side = args.turn.unwrap_or(user_side);
     game.data.phase = GamePhase::Play;
     game.data.turn = TurnData { side: turn_side, turn_number: 0 };
+    game.data.turn_step = TurnStep::Main;
     mana::set(&mut game, user_side, args.mana);
     game.player_mut(user_side).score = args.score;
     mana::set(&mut game, user_side.opponent(), args.opponent_mana);
@@ -119,18 +143,28 @@ pub fn new_game(user_side: Side, args: Args) -> TestSession {
     set_deck_top(&mut game, user_side.opponent(), args.opponent_deck_top);
     set_discard_pile(&mut game, user_side, args.discard);
     set_discard_pile(&mut game, user_side.opponent(), args.opponent_discard);
+    for card in &args.in_play {
+        add_to_play(&mut game, card);
+    }
 
-    if args.add_raid {
+    let raid = if args.add_raid { Some(args.raid.unwrap_or_default()) } else { args.raid };
+    if let Some(raid) = raid {
         game.data.raid = Some(RaidData {
             raid_id: RAID_ID,
-            target: ROOM_ID,
-            internal_phase: InternalRaidPhase::Begin,
-            encounter: None,
+            target: raid.target,
+            internal_phase: raid.phase,
+            encounter: raid.encounter,
             accessed: vec![],
             jump_request: None,
         })
     }
 
+    let today = server::quests::today();
+    let quest_log = |id| QuestLog {
+        day: today,
+        quests: if id == user_id { args.quests.clone() } else { vec![] },
+    };
+
     let database = FakeDatabase {
         generated_game_id: None,
         game: Some(game),
@@ -141,7 +175,21 @@ pub fn new_game(user_side: Side, args: Args) -> TestSession {
                 decks: vec![],
                 adventure: None,
                 collection: hashmap! {},
-                tutorial: TutorialData::default()
+                tutorial: TutorialData::default(),
+                cosmetics: HashSet::new(),
+                achievements: HashSet::new(),
+                settings: PlayerSettings::default(),
+                match_history: vec![],
+                completed_puzzles: HashSet::new(),
+                quests: quest_log(overlord_user),
+                coins: Coins::default(),
+                player_cosmetics: PlayerCosmetics::default(),
+                revision: 0,
+                notifications: vec![],
+                campaign: CampaignProgress::default(),
+                current_match: None,
+                dust: Dust::default(),
+                automation: AutomationPreferences::default(),
             },
             champion_user => PlayerData {
                 id: champion_user,
@@ -149,9 +197,30 @@ pub fn new_game(user_side: Side, args: Args) -> TestSession {
                 decks: vec![],
                 adventure: None,
                 collection: hashmap! {},
-                tutorial: TutorialData::default()
+                tutorial: TutorialData::default(),
+                cosmetics: HashSet::new(),
+                achievements: HashSet::new(),
+                settings: PlayerSettings::default(),
+                match_history: vec![],
+                completed_puzzles: HashSet::new(),
+                quests: quest_log(champion_user),
+                coins: Coins::default(),
+                player_cosmetics: PlayerCosmetics::default(),
+                revision: 0,
+                notifications: vec![],
+                campaign: CampaignProgress::default(),
+                current_match: None,
+                dust: Dust::default(),
+                automation: AutomationPreferences::default(),
             }
         },
+        signing_key: None,
+        player_secrets: hashmap! {},
+        leaderboards: hashmap! {},
+        matches: hashmap! {},
+        ratings: hashmap! {},
+        card_statistics: hashmap! {},
+        card_positions: hashmap! {},
     };
 
     let mut session = TestSession::new(database, user_id, opponent_id);
@@ -167,6 +236,19 @@ pub fn new_game(user_side: Side, args: Args) -> TestSession {
     for _ in 0..args.opponent_hand_size {
         session.add_to_hand(opponent_hand_card);
     }
+    for card_name in args.hand {
+        assert_eq!(user_side, rules::get(card_name).side, "Invalid hand card {:?}", card_name);
+        session.add_to_hand(card_name);
+    }
+    for card_name in args.opponent_hand {
+        assert_eq!(
+            user_side.opponent(),
+            rules::get(card_name).side,
+            "Invalid opponent hand card {:?}",
+            card_name
+        );
+        session.add_to_hand(card_name);
+    }
 
     if args.connect {
         session.connect(user_id).expect("Connection failed");
@@ -217,9 +299,33 @@ pub struct Args {
     pub discard: Option<CardName>,
     /// Card to be inserted into the opponent player's discard pile.
     pub opponent_discard: Option<CardName>,
+    /// Specific cards to add to the `user_side` player's hand, in addition to
+    /// any added via [Self::hand_size].
+    pub hand: Vec<CardName>,
+    /// Specific cards to add to the opponent player's hand, in addition to any
+    /// added via [Self::opponent_hand_size].
+    pub opponent_hand: Vec<CardName>,
+    /// Cards to place directly into play for their owners. No costs are paid
+    /// and no game events are fired.
+    pub in_play: Vec<TestCard>,
     /// Set up an active raid within the created game using [ROOM_ID] as the
     /// target and [RAID_ID] as the ID.
     pub add_raid: bool,
+    /// Set up an active raid with the provided configuration and [RAID_ID] as
+    /// the ID. Takes precedence over the default configuration from
+    /// [Self::add_raid].
+    pub raid: Option<TestRaid>,
+    /// Number of seconds a disconnected player has to reconnect before
+    /// forfeiting. Disconnected players never forfeit by default.
+    pub disconnect_grace_period: Option<u64>,
+    /// Rule changes to apply to this game. Defaults to none.
+    pub modifiers: Vec<GameModifier>,
+    /// Whether to reveal both players' hands and decks when the game ends.
+    /// Defaults to false.
+    pub reveal_on_game_over: bool,
+    /// Daily quests for the `user_side` player, treated as having been
+    /// generated today. Defaults to no quests for either player.
+    pub quests: Vec<Quest>,
     /// If false, will not attempt to automatically connect to this game.
     /// Defaults to true.
     pub connect: bool,
@@ -240,12 +346,113 @@ impl Default for Args {
             opponent_deck_top: None,
             discard: None,
             opponent_discard: None,
+            hand: vec![],
+            opponent_hand: vec![],
+            in_play: vec![],
             add_raid: false,
+            raid: None,
+            disconnect_grace_period: None,
+            modifiers: vec![],
+            reveal_on_game_over: false,
+            quests: vec![],
             connect: true,
         }
     }
 }
 
+/// A card to place directly into play via [Args::in_play]
+#[derive(Clone, Debug)]
+pub struct TestCard {
+    pub name: CardName,
+    /// Room for minion, project, and scheme cards. Defaults to [ROOM_ID].
+    pub room_id: RoomId,
+    /// Whether the card should be face up. Defaults to true for Champion cards
+    /// and false for Overlord cards.
+    pub face_up: bool,
+    /// Level counters to place on this card. Defaults to 0.
+    pub card_level: LevelValue,
+    /// Mana to store in this card. Defaults to 0.
+    pub stored_mana: ManaValue,
+}
+
+impl TestCard {
+    pub fn new(name: CardName) -> Self {
+        Self {
+            name,
+            room_id: ROOM_ID,
+            face_up: rules::get(name).side == Side::Champion,
+            card_level: 0,
+            stored_mana: 0,
+        }
+    }
+
+    pub fn room_id(mut self, room_id: RoomId) -> Self {
+        self.room_id = room_id;
+        self
+    }
+
+    pub fn face_up(mut self, face_up: bool) -> Self {
+        self.face_up = face_up;
+        self
+    }
+
+    pub fn card_level(mut self, card_level: LevelValue) -> Self {
+        self.card_level = card_level;
+        self
+    }
+
+    pub fn stored_mana(mut self, stored_mana: ManaValue) -> Self {
+        self.stored_mana = stored_mana;
+        self
+    }
+}
+
+/// Configuration for an active raid via [Args::raid]
+#[derive(Clone, Debug)]
+pub struct TestRaid {
+    /// Room being raided. Defaults to [ROOM_ID].
+    pub target: RoomId,
+    /// Current phase of the raid. Defaults to [InternalRaidPhase::Begin].
+    pub phase: InternalRaidPhase,
+    /// Index of the defender currently being encountered, if any.
+    pub encounter: Option<usize>,
+}
+
+impl Default for TestRaid {
+    fn default() -> Self {
+        Self { target: ROOM_ID, phase: InternalRaidPhase::Begin, encounter: None }
+    }
+}
+
+fn add_to_play(game: &mut GameState, card: &TestCard) {
+    let definition = rules::get(card.name);
+    let side = definition.side;
+    let target_id = game
+        .cards(side)
+        .iter()
+        .filter(|c| c.position().kind() == CardPositionKind::DeckUnknown)
+        .last() // Take last to avoid overwriting deck top
+        .expect("No cards in deck")
+        .id;
+    client::overwrite_card(game, target_id, card.name);
+
+    let target = match definition.card_type {
+        CardType::Minion | CardType::Project | CardType::Scheme => CardTarget::Room(card.room_id),
+        _ => CardTarget::None,
+    };
+    let position =
+        queries::played_position(game, side, target_id, target).expect("Invalid card position");
+    game.move_card_internal(target_id, position);
+
+    let state = game.card_mut(target_id);
+    state.data.card_level = card.card_level;
+    state.data.stored_mana = card.stored_mana;
+    state.set_revealed_to(side, true);
+    if card.face_up {
+        state.turn_face_up();
+    }
+}
+
 fn set_deck_top(game: &mut GameState, side: Side, deck_top: Option<CardName>) {
     if let Some(deck_top) = deck_top {
         let target_id = game
@@ -281,6 +488,19 @@ pub fn spend_actions_until_turn_over(session: &mut TestSession, side: Side) {
     }
 }
 
+/// Resolves an end-of-turn discard prompt for the `side` player, if any, by
+/// discarding the first offered card until they reach their maximum hand
+/// size.
+pub fn discard_to_hand_size(session: &mut TestSession, side: Side) {
+    let id = session.player_id_for_side(side);
+    while let Some(card_id) =
+        session.game().player(side).prompt.as_ref().and_then(|p| p.card_choices().next())
+    {
+        let action = PromptAction::CardAction(CardPromptAction::DiscardToHandSize(side, card_id));
+        session.perform(action.as_client_action(), id);
+    }
+}
+
 /// Levels up the [CLIENT_ROOM_ID] room a specified number of `times`. If this
 /// requires multiple turns, spends the Champion turns doing nothing.
 ///
@@ -437,12 +657,18 @@ pub fn server_card_id(card_id: CardIdentifier) -> CardId {
     }
 }
 
-pub fn create_test_recording(session: &TestSession, name: &str) {
-    record_output_for_side(session, format!("{}_overlord", name), Side::Overlord).unwrap();
-    record_output_for_side(session, format!("{}_champion", name), Side::Champion).unwrap();
+/// Saves the commands sent to each player in this session as the recordings
+/// for `scenario`, for playback in client visual tests.
+pub fn create_test_recording(session: &TestSession, scenario: RecordingName) {
+    record_output_for_side(session, scenario, Side::Overlord).unwrap();
+    record_output_for_side(session, scenario, Side::Champion).unwrap();
 }
 
-pub fn record_output_for_side(session: &TestSession, name: String, side: Side) -> Result<()> {
+pub fn record_output_for_side(
+    session: &TestSession,
+    scenario: RecordingName,
+    side: Side,
+) -> Result<()> {
     let commands = CommandList {
         commands: session
             .player_for_side(side)
@@ -451,8 +677,8 @@ pub fn record_output_for_side(session: &TestSession, name: String, side: Side) -
             .map(|c| GameCommand { command: Some(c.clone()) })
             .collect(),
     };
-    let encoded = commands.encode_length_delimited_to_vec();
-    fs::write(format!("../../Assets/Resources/TestRecordings/test_{}.bytes", name), encoded)?;
-
-    Ok(())
+    recordings::write(
+        Path::new(&format!("../../{}", recordings::DEFAULT_DIRECTORY)),
+        &recordings::new(scenario, side, commands),
+    )
 }
@@ -16,7 +16,7 @@
 
 use std::convert::Infallible;
 use std::error;
-use std::fmt::Display;
+use std::fmt::{self, Display, Formatter};
 
 use anyhow::{Context, Error};
 
@@ -52,6 +52,72 @@ macro_rules! fail {
     }
 }
 
+/// Category of a failure, used to show a meaningful message to the user
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ErrorCode {
+    /// The requested action is not permitted in the current game state
+    IllegalAction,
+    /// The requested action can only be taken during the player's own turn
+    NotYourTurn,
+    /// The player does not have enough mana to pay for the requested action
+    InsufficientMana,
+    /// The selected deck cannot be used to play a game
+    DeckInvalid,
+    /// An unexpected server error. All errors without a more specific code use
+    /// this value.
+    Internal,
+}
+
+/// An error which has been assigned an [ErrorCode]. Can be retrieved from an
+/// [anyhow::Error] via [error_code].
+#[derive(Debug, Clone)]
+pub struct CodedError {
+    pub code: ErrorCode,
+    pub message: String,
+}
+
+impl Display for CodedError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}: {}", self.code, self.message)
+    }
+}
+
+impl error::Error for CodedError {}
+
+/// Returns the [ErrorCode] for an error, searching through any added context.
+/// Returns [ErrorCode::Internal] if no code was assigned.
+pub fn error_code(error: &Error) -> ErrorCode {
+    error
+        .chain()
+        .find_map(|e| e.downcast_ref::<CodedError>())
+        .map_or(ErrorCode::Internal, |e| e.code)
+}
+
+/// Equivalent to [verify] which fails with the provided [ErrorCode], e.g.
+/// `verify_code!(can_play, ErrorCode::IllegalAction, "Cannot play {:?}", id)`
+#[macro_export]
+macro_rules! verify_code {
+    ($cond:expr, $code:expr, $($tts:tt)*) => {
+        if with_error::ERROR_PANIC {
+            assert!($cond, $($tts)*);
+        } else if !$cond {
+            return Err(with_error::CodedError { code: $code, message: format!($($tts)*) }.into());
+        }
+    }
+}
+
+/// Equivalent to [fail] which fails with the provided [ErrorCode].
+#[macro_export]
+macro_rules! fail_code {
+    ($code:expr, $($tts:tt)*) => {
+        if with_error::ERROR_PANIC {
+            panic!($($tts)*);
+        } else {
+            return Err(with_error::CodedError { code: $code, message: format!($($tts)*) }.into());
+        }
+    }
+}
+
 pub trait WithError<T, E> {
     /// Wrapper around anyhow::with_context. Wraps the error value with
     /// additional context that is evaluated lazily only once an error does